@@ -1,15 +1,27 @@
 use crate::audio;
 use crate::audio::music_player::MusicPlayer;
+use crate::network::checksum::{game_checksum, game_desync_message, is_checksum_period};
+use crate::network::constants::STATE_CHECKSUM_INTERVAL;
 use crate::network::handler::NetworkHandler;
-use crate::store::{get_world_size, load_world, reset, save_world};
+use crate::settings::Settings;
+use crate::status_server::{
+    run_status_server, SharedStatus, StatusSnapshot, STATUS_UPDATE_INTERVAL,
+};
+use crate::store::{
+    delete_world, get_world_size, load_address_book, load_settings, load_world, reset,
+    save_stream_overlay, save_world,
+};
+use crate::stream_overlay::{StreamOverlay, STREAM_OVERLAY_UPDATE_INTERVAL};
 use crate::tui::{EventHandler, TerminalEvent};
 use crate::tui::{Tui, WriterProxy};
 use crate::types::{AppResult, ResourceMap, SystemTimeTick, Tick};
 use crate::ui::popup_message::PopupMessage;
 use crate::ui::ui::{Ui, UiState};
 use crate::ui::utils::SwarmPanelEvent;
+use crate::world::catch_up::{CatchUp, CATCH_UP_CHUNK};
 use crate::world::constants::{TickInterval, SECONDS};
-use crate::world::types::TeamLocation;
+use crate::world::hooks::HookRegistry;
+use crate::world::types::{TeamLocation, TimeSpeed};
 use crate::world::world::World;
 use crossterm::event::{KeyCode, KeyModifiers};
 use futures::StreamExt;
@@ -40,6 +52,16 @@ pub struct App {
     seed_ip: Option<String>,
     network_port: Option<u16>,
     store_prefix: String,
+    pub settings: Settings,
+    last_world_tick: Tick,
+    last_world_clock_update: Tick,
+    last_network_sync: Tick,
+    last_state_checksum_sync: Tick,
+    last_stream_overlay_update: Tick,
+    last_stream_overlay: Option<StreamOverlay>,
+    last_status_update: Tick,
+    status: Option<SharedStatus>,
+    hooks: HookRegistry,
 }
 
 impl App {
@@ -54,20 +76,26 @@ impl App {
             (Tick::now() - self.world.last_tick_short_interval).formatted()
         );
 
-        // If team is on a space adventure, bring it back to base planet.
-        // This is an ad-hoc fix to avoid problems when the game is closed during a space adventure,
-        // since the space property of the world is not serialized and stored.
+        // If team is on a space adventure, resume it from the stored snapshot (paused).
+        // If there is no snapshot, bring it back to base planet.
         let mut own_team = self
             .world
             .get_own_team()
             .expect("There should be an own team.")
             .clone();
+        let mut is_space_adventure_resumed = false;
         match own_team.current_location {
+            TeamLocation::OnSpaceAdventure { .. }
+                if self.world.resume_space_adventure().is_ok() =>
+            {
+                is_space_adventure_resumed = true;
+            }
             TeamLocation::OnSpaceAdventure { around } => {
                 // The team loses all resources and fuel, tough shit.
                 own_team.resources = ResourceMap::default();
                 own_team.spaceship.set_current_durability(0);
                 own_team.current_location = TeamLocation::OnPlanet { planet_id: around };
+                self.world.space_adventure_snapshot = None;
 
                 self.world.teams.insert(own_team.id, own_team);
                 self.ui
@@ -77,8 +105,35 @@ impl App {
             _ => {}
         }
 
+        // Time away is simulated up to MAX_CATCH_UP_TIME, the rest is skipped.
+        let skipped = self.world.cap_catch_up_time();
+        if skipped > 0 {
+            info!("Skipped {} of simulation", skipped.formatted());
+        }
+        match CatchUp::new(
+            &self.world,
+            self.world.last_tick_short_interval,
+            self.world.now(),
+            skipped,
+        ) {
+            Ok(catch_up) => self.world.catch_up = Some(catch_up),
+            Err(e) => error!("Cannot track simulation progress: {e}"),
+        }
+
         while self.world.is_simulating() {
-            // Give a visual feedback by drawing.
+            let chunk_end = self.world.last_tick_short_interval + CATCH_UP_CHUNK;
+            while self.world.is_simulating() && self.world.last_tick_short_interval < chunk_end {
+                let mut cb = match self
+                    .world
+                    .handle_tick_events(self.world.last_tick_short_interval + TickInterval::SHORT)
+                {
+                    Ok(callbacks) => callbacks,
+                    Err(e) => panic!("Failed to simulate world: {}", e),
+                };
+                callbacks.append(&mut cb);
+            }
+
+            // Give a visual feedback by drawing the progress between chunks.
             let now = Tick::now();
             if now - last_tui_update > tui.simulation_update_interval() {
                 last_tui_update = now;
@@ -92,22 +147,31 @@ impl App {
                     error!("Error drawing TUI during simulation: {e}")
                 };
             }
+        }
 
-            let mut cb = match self
-                .world
-                .handle_tick_events(self.world.last_tick_short_interval + TickInterval::SHORT)
-            {
-                Ok(callbacks) => callbacks,
-                Err(e) => panic!("Failed to simulate world: {}", e),
-            };
-            callbacks.append(&mut cb);
+        if let Some(catch_up) = self.world.catch_up.take() {
+            // Short absences are not worth a summary.
+            if catch_up.to - catch_up.from >= CATCH_UP_CHUNK || catch_up.skipped > 0 {
+                match catch_up.summary(&self.world) {
+                    Ok(message) => self.ui.push_popup(PopupMessage::Ok {
+                        message,
+                        is_skippable: false,
+                        tick: Tick::now(),
+                    }),
+                    Err(e) => error!("Cannot summarize simulation: {e}"),
+                }
+            }
         }
 
         self.world.serialized_size =
             get_world_size(&self.store_prefix).expect("Failed to get world size");
 
         self.state = AppState::Started;
-        self.ui.set_state(UiState::Main);
+        if is_space_adventure_resumed {
+            self.ui.set_state(UiState::SpaceAdventure);
+        } else {
+            self.ui.set_state(UiState::Main);
+        }
 
         for callback in callbacks.iter() {
             match callback.call(self) {
@@ -146,7 +210,11 @@ impl App {
 
     pub fn initialize_network_handler(&mut self) -> AppResult<()> {
         if let Some(tcp_port) = self.network_port {
-            let handler = NetworkHandler::new(self.seed_ip.clone(), tcp_port)?;
+            let mut handler = NetworkHandler::new(self.seed_ip.clone(), tcp_port)?;
+            handler.address_book = load_address_book().unwrap_or_default();
+            self.ui
+                .swarm_panel
+                .update_address_book(&handler.address_book);
             self.network_handler = Some(handler);
         } else {
             error!("Cannot initialize network handler: TCP port not set.")
@@ -171,7 +239,7 @@ impl App {
 
         let store_prefix = store_prefix.unwrap_or("local");
 
-        let ui = Ui::new(store_prefix, disable_network);
+        let mut ui = Ui::new(store_prefix, disable_network);
         let audio_player = if disable_audio {
             None
         } else {
@@ -184,6 +252,18 @@ impl App {
             }
         };
 
+        let hooks = HookRegistry::from_factories().unwrap_or_else(|e| {
+            error!("Could not register hooks: {}", e);
+            HookRegistry::default()
+        });
+
+        let settings = load_settings().unwrap_or_default();
+        ui.set_theme(settings.ui_theme);
+        ui.set_accessible_mode(settings.accessible_mode);
+        ui.set_keyboard_only(settings.accessible_mode);
+        ui.my_team_panel.set_players_sort(settings.roster_sort);
+        ui.player_panel.set_players_sort(settings.player_list_sort);
+
         Self {
             world: World::new(seed),
             state: AppState::Started,
@@ -194,6 +274,89 @@ impl App {
             seed_ip,
             network_port,
             store_prefix: store_prefix.to_string(),
+            settings,
+            last_world_tick: 0,
+            last_world_clock_update: 0,
+            last_network_sync: 0,
+            last_state_checksum_sync: 0,
+            last_stream_overlay_update: 0,
+            last_stream_overlay: None,
+            last_status_update: 0,
+            status: None,
+            hooks,
+        }
+    }
+
+    fn dispatch_hook_events(&mut self) {
+        let events = std::mem::take(&mut self.world.hook_events);
+        if events.is_empty() || self.hooks.is_empty() {
+            return;
+        }
+
+        for callback in self.hooks.dispatch(&events, &self.world) {
+            match callback.call(self) {
+                Ok(Some(message)) => {
+                    self.ui.push_popup(PopupMessage::Ok {
+                        message,
+                        is_skippable: true,
+                        tick: Tick::now(),
+                    });
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    self.ui.push_popup(PopupMessage::Error {
+                        message: e.to_string(),
+                        tick: Tick::now(),
+                    });
+                }
+            }
+        }
+    }
+
+    // Sends the checksum of the own network games at every break, so that
+    // the opponent can verify that both simulations are still in sync.
+    fn sync_game_checksums(&mut self) {
+        let Some(network_handler) = self.network_handler.as_mut() else {
+            return;
+        };
+        network_handler
+            .game_checksums
+            .retain(|game_id, _| self.world.games.contains_key(game_id));
+
+        let own_team_id = self.world.own_team_id;
+        for game in self.world.games.values() {
+            let period = game.timer.period();
+            if !game.is_network()
+                || !is_checksum_period(period)
+                || (game.home_team_in_game.team_id != own_team_id
+                    && game.away_team_in_game.team_id != own_team_id)
+            {
+                continue;
+            }
+
+            let log = network_handler.game_checksums.entry(game.id).or_default();
+            if log.has_local(period) {
+                continue;
+            }
+            let checksum = game_checksum(game);
+            log.record_local(period, checksum);
+            let desynced_at = log.check();
+
+            if let Err(e) = network_handler.send_game_checksum(game.id, period, checksum) {
+                self.ui.swarm_panel.push_log_event(SwarmPanelEvent {
+                    timestamp: Tick::now(),
+                    peer_id: None,
+                    text: format!("Failed to send game checksum to peers: {}", e),
+                });
+            }
+
+            if let Some(period) = desynced_at {
+                self.ui.push_popup(PopupMessage::Ok {
+                    message: game_desync_message(game, period),
+                    is_skippable: false,
+                    tick: Tick::now(),
+                });
+            }
         }
     }
 
@@ -203,6 +366,16 @@ impl App {
     ) -> AppResult<()> {
         let mut last_network_handler_init = 0;
 
+        if let Some(port) = self.settings.status_port {
+            let status = SharedStatus::default();
+            self.status = Some(status.clone());
+            tokio::spawn(async move {
+                if let Err(e) = run_status_server(port, status).await {
+                    error!("Status endpoint stopped: {}", e);
+                }
+            });
+        }
+
         while self.state != AppState::Quitting {
             let now = Tick::now();
 
@@ -216,10 +389,15 @@ impl App {
                 && self.world.has_own_team()
                 && now - last_network_handler_init > NETWORK_HANDLER_INIT_INTERVAL
             {
-                info!("Initializing network handler...");
-                if let Err(e) = self.initialize_network_handler() {
-                    error!("Could not initialize network handler: {}", e);
+                if let Err(e) = self.world.can_go_online() {
+                    info!("Network handler not initialized: {}", e);
                     last_network_handler_init = now;
+                } else {
+                    info!("Initializing network handler...");
+                    if let Err(e) = self.initialize_network_handler() {
+                        error!("Could not initialize network handler: {}", e);
+                        last_network_handler_init = now;
+                    }
                 }
             }
 
@@ -276,9 +454,15 @@ impl App {
     pub fn quit(&mut self) -> AppResult<()> {
         self.state = AppState::Quitting;
 
-        // save world and backup
+        // save world and backup. Ironman worlds have a single save and are gone once over.
         if self.world.has_own_team() {
-            save_world(&self.world, true, &self.store_prefix)?;
+            let is_ironman = self.world.generation_options.ironman;
+            if is_ironman && self.world.game_over.is_some() {
+                delete_world(&self.store_prefix)?;
+            } else {
+                self.world.store_space_adventure()?;
+                save_world(&self.world, !is_ironman, &self.store_prefix)?;
+            }
         }
 
         // close network connections
@@ -289,6 +473,12 @@ impl App {
         Ok(())
     }
 
+    pub fn is_network_active(&self) -> bool {
+        self.network_handler
+            .as_ref()
+            .map_or(false, |handler| handler.swarm.connected_peers().count() > 0)
+    }
+
     pub fn render(
         ui: &mut Ui,
         world: &World,
@@ -300,8 +490,35 @@ impl App {
 
     /// Handles the tick event of the terminal.
     pub fn handle_tick_events(&mut self, current_tick: Tick) -> AppResult<()> {
-        if self.world.has_own_team() {
-            match self.world.handle_tick_events(current_tick) {
+        // Pausing and fast-forwarding are dropped as soon as they stop being allowed,
+        // for example when a peer connects.
+        if self
+            .world
+            .can_set_time_speed(
+                self.world.time_speed,
+                self.is_network_active(),
+                self.network_handler.is_some(),
+            )
+            .is_err()
+        {
+            self.world.time_speed = TimeSpeed::Normal;
+        }
+        // Network games and challenges are timestamped on the shared real clock.
+        if self.is_network_active() {
+            self.world.resync_clock();
+        }
+        self.world
+            .advance_clock(current_tick.saturating_sub(self.last_world_clock_update));
+        self.last_world_clock_update = current_tick;
+
+        // The space adventure needs to be updated every frame to move smoothly.
+        let world_tick_due = current_tick.saturating_sub(self.last_world_tick)
+            >= self.settings.tick().world_tick_interval
+            || self.world.space_adventure.is_some();
+
+        if self.world.has_own_team() && world_tick_due {
+            self.last_world_tick = current_tick;
+            match self.world.handle_tick_events(self.world.now()) {
                 Ok(callbacks) => {
                     for callback in callbacks.iter() {
                         match callback.call(self) {
@@ -331,6 +548,9 @@ impl App {
             }
         }
 
+        // Events from both world ticks and player actions are handled here.
+        self.dispatch_hook_events();
+
         match self.ui.update(&self.world, self.audio_player.as_ref()) {
             Ok(_) => {}
             Err(e) => {
@@ -344,22 +564,16 @@ impl App {
         }
         self.world.dirty_ui = false;
 
-        if self.world.dirty && self.world.has_own_team() {
-            self.world.dirty = false;
-            save_world(&self.world, false, &self.store_prefix).expect("Failed to save world");
-            self.world.serialized_size =
-                get_world_size(&self.store_prefix).expect("Failed to get world size");
-
-            self.ui.swarm_panel.push_log_event(SwarmPanelEvent {
-                timestamp: Tick::now(),
-                peer_id: None,
-                text: format!("World saved, size: {} bytes", self.world.serialized_size),
-            });
-        }
+        self.save_dirty_world();
 
         // Send own team to peers if dirty
-        if self.world.dirty_network && self.world.has_own_team() {
+        if self.world.dirty_network
+            && self.world.has_own_team()
+            && current_tick.saturating_sub(self.last_network_sync)
+                >= self.settings.tick().network_sync_interval
+        {
             self.world.dirty_network = false;
+            self.last_network_sync = current_tick;
             if let Some(network_handler) = &mut self.network_handler {
                 if network_handler.swarm.connected_peers().count() > 0 {
                     if let Err(e) = network_handler.send_own_team(&self.world) {
@@ -369,6 +583,14 @@ impl App {
                             text: format!("Failed to send own team to peers: {}", e),
                         });
                     }
+                    // Keep our status in the peers' lobby up to date.
+                    if let Err(e) = network_handler.send_lobby_entry(&self.world) {
+                        self.ui.swarm_panel.push_log_event(SwarmPanelEvent {
+                            timestamp: Tick::now(),
+                            peer_id: None,
+                            text: format!("Failed to send lobby entry to peers: {}", e),
+                        });
+                    }
                 } else {
                     if let Err(e) = network_handler.dial_seed() {
                         self.ui.swarm_panel.push_log_event(SwarmPanelEvent {
@@ -381,9 +603,102 @@ impl App {
             }
         }
 
+        // Let peers detect teams out of sync, even when nothing changed locally.
+        if self.world.has_own_team()
+            && current_tick.saturating_sub(self.last_state_checksum_sync) >= STATE_CHECKSUM_INTERVAL
+        {
+            self.last_state_checksum_sync = current_tick;
+            if let Some(network_handler) = &mut self.network_handler {
+                if network_handler.swarm.connected_peers().count() > 0 {
+                    if let Err(e) = network_handler.send_state_checksum(&self.world) {
+                        self.ui.swarm_panel.push_log_event(SwarmPanelEvent {
+                            timestamp: Tick::now(),
+                            peer_id: None,
+                            text: format!("Failed to send state checksum to peers: {}", e),
+                        });
+                    }
+                }
+            }
+        }
+
+        self.sync_game_checksums();
+
+        if self.settings.stream_overlay()
+            && self.world.has_own_team()
+            && current_tick.saturating_sub(self.last_stream_overlay_update)
+                >= STREAM_OVERLAY_UPDATE_INTERVAL
+        {
+            self.last_stream_overlay_update = current_tick;
+            self.update_stream_overlay();
+        }
+
+        if self.status.is_some()
+            && self.world.has_own_team()
+            && current_tick.saturating_sub(self.last_status_update) >= STATUS_UPDATE_INTERVAL
+        {
+            self.last_status_update = current_tick;
+            self.update_status();
+        }
+
         Ok(())
     }
 
+    fn update_status(&mut self) {
+        let Some(status) = self.status.as_ref() else {
+            return;
+        };
+        let snapshot = match StatusSnapshot::from_world(&self.world) {
+            Ok(snapshot) => snapshot,
+            Err(_) => return,
+        };
+        if let Ok(mut status) = status.write() {
+            *status = Some(snapshot);
+        }
+    }
+
+    fn update_stream_overlay(&mut self) {
+        let overlay = match StreamOverlay::from_world(&self.world) {
+            Ok(overlay) => overlay,
+            Err(_) => return,
+        };
+
+        // Only touch the files when something changed.
+        if self.last_stream_overlay.as_ref() == Some(&overlay) {
+            return;
+        }
+
+        if let Err(e) = save_stream_overlay(&overlay) {
+            self.ui.swarm_panel.push_log_event(SwarmPanelEvent {
+                timestamp: Tick::now(),
+                peer_id: None,
+                text: format!("Failed to write stream overlay: {}", e),
+            });
+        }
+        self.last_stream_overlay = Some(overlay);
+    }
+
+    fn save_dirty_world(&mut self) {
+        if self.world.dirty && self.world.has_own_team() {
+            self.world.dirty = false;
+            save_world(&self.world, false, &self.store_prefix).expect("Failed to save world");
+            self.world.serialized_size =
+                get_world_size(&self.store_prefix).expect("Failed to get world size");
+
+            self.ui.swarm_panel.push_log_event(SwarmPanelEvent {
+                timestamp: Tick::now(),
+                peer_id: None,
+                text: format!("World saved, size: {} bytes", self.world.serialized_size),
+            });
+        }
+    }
+
+    // Ironman worlds are saved right after every action, so that there is no going back.
+    fn autosave_ironman_world(&mut self) {
+        if self.world.generation_options.ironman && self.state == AppState::Started {
+            self.save_dirty_world();
+        }
+    }
+
     pub fn handle_key_events(&mut self, key_event: crossterm::event::KeyEvent) -> AppResult<()> {
         match key_event.code {
             // Exit application directly on `Ctrl-C`. `Esc` asks for confirmation first.
@@ -410,6 +725,7 @@ impl App {
                             });
                         }
                     }
+                    self.autosave_ironman_world();
                 }
             }
         }
@@ -437,6 +753,7 @@ impl App {
                     });
                 }
             }
+            self.autosave_ironman_world();
         }
         Ok(())
     }
@@ -0,0 +1,153 @@
+use crate::store::ASSETS_DIR;
+use crate::types::AppResult;
+use anyhow::anyhow;
+use itertools::Itertools;
+use once_cell::sync::{Lazy, OnceCell};
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
+
+static ASSET_HEALTH: Lazy<Mutex<AssetHealth>> = Lazy::new(|| Mutex::new(AssetHealth::default()));
+
+// Files from the overlay directory, keyed by their path relative to it.
+static ASSETS_OVERLAY: OnceCell<HashMap<String, Vec<u8>>> = OnceCell::new();
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AssetFailure {
+    pub path: String,
+    pub error: String,
+}
+
+/// Keeps track of the assets that could not be loaded, so that missing or corrupt
+/// files can be reported instead of crashing the game.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AssetHealth {
+    pub failures: Vec<AssetFailure>,
+}
+
+impl AssetHealth {
+    pub fn is_healthy(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    pub fn summary(&self) -> String {
+        if self.is_healthy() {
+            "Assets ok".to_string()
+        } else {
+            format!("Assets {} failed", self.failures.len())
+        }
+    }
+}
+
+pub fn asset_health() -> AssetHealth {
+    ASSET_HEALTH.lock().unwrap().clone()
+}
+
+pub fn report_asset_failure(path: &str, error: &anyhow::Error) {
+    log::error!("Failed to load asset {}: {}", path, error);
+    let mut health = ASSET_HEALTH.lock().unwrap();
+    // Lazy assets are loaded only once, but images can be requested repeatedly.
+    if !health.failures.iter().any(|f| f.path == path) {
+        health.failures.push(AssetFailure {
+            path: path.to_string(),
+            error: error.to_string(),
+        });
+    }
+}
+
+/// Reads all files in `dir` into memory, so that they take precedence over the
+/// embedded assets with the same relative path. Must be called before any asset is loaded.
+pub fn load_assets_overlay(dir: &Path) -> AppResult<usize> {
+    let mut files = HashMap::new();
+    read_overlay_dir(dir, dir, &mut files)?;
+    let count = files.len();
+    ASSETS_OVERLAY
+        .set(files)
+        .map_err(|_| anyhow!("Assets overlay already loaded"))?;
+    Ok(count)
+}
+
+fn read_overlay_dir(
+    root: &Path,
+    dir: &Path,
+    files: &mut HashMap<String, Vec<u8>>,
+) -> AppResult<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            read_overlay_dir(root, &path, files)?;
+        } else {
+            // Embedded asset paths always use forward slashes.
+            let key = path
+                .strip_prefix(root)?
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .join("/");
+            files.insert(key, std::fs::read(&path)?);
+        }
+    }
+    Ok(())
+}
+
+pub fn asset_contents(path: &str) -> Option<&'static [u8]> {
+    if let Some(data) = ASSETS_OVERLAY.get().and_then(|files| files.get(path)) {
+        return Some(data.as_slice());
+    }
+    ASSETS_DIR.get_file(path).map(|file| file.contents())
+}
+
+fn parse_json_asset<T: DeserializeOwned>(path: &str) -> AppResult<T> {
+    let contents = asset_contents(path).ok_or(anyhow!("Could not find {}", path))?;
+    let data = std::str::from_utf8(contents).map_err(|_| anyhow!("Could not read {}", path))?;
+    Ok(serde_json::from_str(data)?)
+}
+
+pub fn load_json_asset<T: DeserializeOwned>(path: &str) -> AppResult<T> {
+    parse_json_asset(path).map_err(|e| {
+        report_asset_failure(path, &e);
+        e
+    })
+}
+
+pub fn load_json_asset_or_default<T: DeserializeOwned + Default>(path: &str) -> T {
+    load_json_asset(path).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{asset_health, load_json_asset, load_json_asset_or_default, read_overlay_dir};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_missing_asset_is_reported() {
+        let data: Vec<String> = load_json_asset_or_default("data/does_not_exist.json");
+        assert!(data.is_empty());
+        assert!(asset_health()
+            .failures
+            .iter()
+            .any(|f| f.path == "data/does_not_exist.json"));
+
+        // Valid json with the wrong shape is reported as corrupt.
+        let result: Result<HashMap<String, u8>, _> = load_json_asset("data/teams_data.json");
+        assert!(result.is_err());
+        assert!(asset_health()
+            .failures
+            .iter()
+            .any(|f| f.path == "data/teams_data.json"));
+    }
+
+    #[test]
+    fn test_read_overlay_dir() {
+        let root = std::env::temp_dir().join("rebels_test_assets_overlay");
+        std::fs::create_dir_all(root.join("data")).unwrap();
+        std::fs::write(root.join("data").join("stream_data.json"), "[]").unwrap();
+
+        let mut files = HashMap::new();
+        read_overlay_dir(&root, &root, &mut files).unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files["data/stream_data.json"], b"[]".to_vec());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
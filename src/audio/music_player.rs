@@ -1,4 +1,4 @@
-use crate::store::ASSETS_DIR;
+use crate::assets::load_json_asset_or_default;
 use crate::types::AppResult;
 use anyhow::anyhow;
 use rodio::OutputStream;
@@ -55,7 +55,10 @@ impl Debug for MusicPlayer {
 
 impl MusicPlayer {
     fn current_url(&self) -> AppResult<Url> {
-        Ok(self.streams[self.index].url()?)
+        self.streams
+            .get(self.index)
+            .ok_or(anyhow!("No streams available"))?
+            .url()
     }
 
     pub fn new() -> AppResult<MusicPlayer> {
@@ -65,15 +68,8 @@ impl MusicPlayer {
 
         let (sender, receiver) = mpsc::channel();
 
-        let file = ASSETS_DIR
-            .get_file("data/stream_data.json")
-            .expect("Could not find stream_data.json");
-        let data = file
-            .contents_utf8()
-            .expect("Could not read stream_data.json");
-        let streams = serde_json::from_str(&data).unwrap_or_else(|e| {
-            panic!("Could not parse stream_data.json: {}", e);
-        });
+        // Without streams the radio just stays silent.
+        let streams: Vec<Stream> = load_json_asset_or_default("data/stream_data.json");
 
         Ok(MusicPlayer {
             _stream,
@@ -172,6 +168,8 @@ impl MusicPlayer {
     }
 
     pub fn currently_playing(&self) -> Option<String> {
-        Some(self.streams[self.index].name.clone())
+        self.streams
+            .get(self.index)
+            .map(|stream| stream.name.clone())
     }
 }
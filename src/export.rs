@@ -0,0 +1,444 @@
+use crate::game_engine::game::{Game, GameMVPSummary, GameSummary};
+use crate::game_engine::types::GameStats;
+use crate::types::{AppResult, GameId, SystemTimeTick, Tick};
+use crate::world::skill::{Rated, SKILL_NAMES};
+use crate::world::world::World;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+pub const EXPORT_JSON_FILENAME: &str = "rebels_export.json";
+pub const EXPORT_ROSTER_CSV_FILENAME: &str = "rebels_roster.csv";
+pub const EXPORT_BOX_SCORES_CSV_FILENAME: &str = "rebels_box_scores.csv";
+pub const EXPORT_GAME_SUMMARY_FILENAME: &str = "rebels_game_summary.md";
+
+const MAX_GAME_SUMMARY_HIGHLIGHTS: usize = 3;
+
+const BOX_SCORE_HEADER: [&str; 14] = [
+    "minutes",
+    "points",
+    "2pt_made",
+    "2pt_att",
+    "3pt_made",
+    "3pt_att",
+    "ft_made",
+    "ft_att",
+    "rebounds",
+    "assists",
+    "steals",
+    "blocks",
+    "turnovers",
+    "fouls",
+];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct StatLine {
+    pub games: u32,
+    pub seconds_played: u32,
+    pub points: u32,
+    pub made_2pt: u32,
+    pub attempted_2pt: u32,
+    pub made_3pt: u32,
+    pub attempted_3pt: u32,
+    pub made_ft: u32,
+    pub attempted_ft: u32,
+    pub rebounds: u32,
+    pub assists: u32,
+    pub steals: u32,
+    pub blocks: u32,
+    pub turnovers: u32,
+    pub fouls: u32,
+}
+
+impl StatLine {
+    pub fn add(&mut self, stats: &GameStats) {
+        self.games += 1;
+        self.seconds_played += stats.seconds_played as u32;
+        self.points += stats.points as u32;
+        self.made_2pt += stats.made_2pt as u32;
+        self.attempted_2pt += stats.attempted_2pt as u32;
+        self.made_3pt += stats.made_3pt as u32;
+        self.attempted_3pt += stats.attempted_3pt as u32;
+        self.made_ft += stats.made_ft as u32;
+        self.attempted_ft += stats.attempted_ft as u32;
+        self.rebounds += stats.offensive_rebounds as u32 + stats.defensive_rebounds as u32;
+        self.assists += stats.assists as u32;
+        self.steals += stats.steals as u32;
+        self.blocks += stats.blocks as u32;
+        self.turnovers += stats.turnovers as u32;
+        self.fouls += stats.fouls as u32;
+    }
+
+    fn csv_fields(&self) -> Vec<String> {
+        vec![
+            (self.seconds_played / 60).to_string(),
+            self.points.to_string(),
+            self.made_2pt.to_string(),
+            self.attempted_2pt.to_string(),
+            self.made_3pt.to_string(),
+            self.attempted_3pt.to_string(),
+            self.made_ft.to_string(),
+            self.attempted_ft.to_string(),
+            self.rebounds.to_string(),
+            self.assists.to_string(),
+            self.steals.to_string(),
+            self.blocks.to_string(),
+            self.turnovers.to_string(),
+            self.fouls.to_string(),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PlayerExport {
+    pub name: String,
+    pub age: f32,
+    pub crew_role: String,
+    pub rating: u8,
+    // In the same order as SKILL_NAMES.
+    pub skills: Vec<f32>,
+    pub career: StatLine,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct BoxScoreLine {
+    pub game_id: GameId,
+    pub starting_at: Tick,
+    pub home_team: String,
+    pub away_team: String,
+    pub home_score: u16,
+    pub away_score: u16,
+    pub team: String,
+    pub player: String,
+    pub stats: StatLine,
+}
+
+/// Own roster and past box scores, written to disk for analysis in spreadsheets.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct TeamExport {
+    pub team: String,
+    pub exported_at: Tick,
+    pub roster: Vec<PlayerExport>,
+    pub box_scores: Vec<BoxScoreLine>,
+}
+
+impl TeamExport {
+    // Past games are passed in since they are loaded from the store separately.
+    pub fn from_world(world: &World, past_games: &[Game], tick: Tick) -> AppResult<Self> {
+        let own_team = world.get_own_team()?;
+
+        let mut box_scores = vec![];
+        for game in past_games.iter().sorted_by_key(|game| game.starting_at) {
+            let (home_score, away_score) = game.get_score();
+            for team in [&game.home_team_in_game, &game.away_team_in_game] {
+                for (player_id, stats) in team.stats.iter() {
+                    let player_name = team
+                        .players
+                        .get(player_id)
+                        .map(|player| player.info.full_name())
+                        .unwrap_or_default();
+                    let mut line = StatLine::default();
+                    line.add(stats);
+                    box_scores.push(BoxScoreLine {
+                        game_id: game.id,
+                        starting_at: game.starting_at,
+                        home_team: game.home_team_in_game.name.clone(),
+                        away_team: game.away_team_in_game.name.clone(),
+                        home_score,
+                        away_score,
+                        team: team.name.clone(),
+                        player: player_name,
+                        stats: line,
+                    });
+                }
+            }
+        }
+
+        let mut roster = vec![];
+        for player_id in own_team.player_ids.iter() {
+            let player = world.get_player_or_err(*player_id)?;
+            let mut career = StatLine::default();
+            for game in past_games.iter() {
+                for team in [&game.home_team_in_game, &game.away_team_in_game] {
+                    if let Some(stats) = team.stats.get(player_id) {
+                        career.add(stats);
+                    }
+                }
+            }
+            roster.push(PlayerExport {
+                name: player.info.full_name(),
+                age: player.info.age,
+                crew_role: player.info.crew_role.to_string(),
+                rating: player.rating(),
+                skills: player.current_skill_array().to_vec(),
+                career,
+            });
+        }
+
+        Ok(Self {
+            team: own_team.name.clone(),
+            exported_at: tick,
+            roster,
+            box_scores,
+        })
+    }
+
+    pub fn roster_csv(&self) -> String {
+        let mut header = vec!["name", "age", "role", "rating"];
+        header.extend(SKILL_NAMES.iter());
+        header.push("games");
+        header.extend(BOX_SCORE_HEADER.iter());
+        let mut rows = vec![header.iter().map(|h| csv_field(h)).collect::<Vec<_>>()];
+
+        for player in self.roster.iter() {
+            let mut row = vec![
+                csv_field(&player.name),
+                format!("{:.1}", player.age),
+                csv_field(&player.crew_role),
+                player.rating.to_string(),
+            ];
+            row.extend(player.skills.iter().map(|skill| format!("{:.2}", skill)));
+            row.push(player.career.games.to_string());
+            row.extend(player.career.csv_fields());
+            rows.push(row);
+        }
+
+        to_csv(rows)
+    }
+
+    pub fn box_scores_csv(&self) -> String {
+        let mut header = vec![
+            "game_id",
+            "date",
+            "home_team",
+            "away_team",
+            "home_score",
+            "away_score",
+            "team",
+            "player",
+        ];
+        header.extend(BOX_SCORE_HEADER.iter());
+        let mut rows = vec![header.iter().map(|h| csv_field(h)).collect::<Vec<_>>()];
+
+        for line in self.box_scores.iter() {
+            let mut row = vec![
+                line.game_id.to_string(),
+                csv_field(&line.starting_at.formatted_as_date()),
+                csv_field(&line.home_team),
+                csv_field(&line.away_team),
+                line.home_score.to_string(),
+                line.away_score.to_string(),
+                csv_field(&line.team),
+                csv_field(&line.player),
+            ];
+            row.extend(line.stats.csv_fields());
+            rows.push(row);
+        }
+
+        to_csv(rows)
+    }
+}
+
+fn mvps_line(team_name: &str, mvps: &[GameMVPSummary]) -> String {
+    let mvps = mvps
+        .iter()
+        .map(|mvp| {
+            let stats = mvp
+                .best_stats
+                .iter()
+                .filter(|(_, value, _)| *value > 0)
+                .map(|(stat, value, _)| format!("{} {}", value, stat))
+                .join(", ");
+            format!("{} ({})", mvp.name, stats)
+        })
+        .join(", ");
+    format!("{}: {}", team_name, mvps)
+}
+
+/// Game recap in Discord-flavored markdown, which also reads fine as plain text.
+/// The summary provides the score, the full game the MVPs and the highlights.
+pub fn game_summary_markdown(summary: &GameSummary, game: &Game, location: &str) -> String {
+    let (home_score, away_score) = summary.score();
+    let mut lines = vec![
+        format!(
+            "**{} {} - {} {}**",
+            summary.home_team_name, home_score, away_score, summary.away_team_name
+        ),
+        format!(
+            "{}, attendance {}, {}",
+            location,
+            summary.attendance,
+            summary
+                .ended_at
+                .unwrap_or(summary.starting_at)
+                .formatted_as_date()
+        ),
+    ];
+
+    let name_width = summary
+        .home_team_name
+        .len()
+        .max(summary.away_team_name.len());
+    let overtime = summary.overtime_score.is_some();
+    lines.push("```".to_string());
+    lines.push(format!(
+        "{:name_width$}  Q1  Q2  Q3  Q4{}   T",
+        "",
+        if overtime { "  OT" } else { "" }
+    ));
+    for (name, quarters, overtime_score, score) in [
+        (
+            &summary.home_team_name,
+            summary.home_quarters_score,
+            summary.overtime_score.map(|(home, _)| home),
+            home_score,
+        ),
+        (
+            &summary.away_team_name,
+            summary.away_quarters_score,
+            summary.overtime_score.map(|(_, away)| away),
+            away_score,
+        ),
+    ] {
+        lines.push(format!(
+            "{:name_width$}{}{} {:>3}",
+            name,
+            quarters.iter().map(|q| format!(" {:>3}", q)).join(""),
+            overtime_score
+                .map(|s| format!(" {:>3}", s))
+                .unwrap_or_default(),
+            score
+        ));
+    }
+    lines.push("```".to_string());
+
+    if let (Some(home_mvps), Some(away_mvps)) =
+        (game.home_team_mvps.as_ref(), game.away_team_mvps.as_ref())
+    {
+        lines.push("**MVPs**".to_string());
+        lines.push(mvps_line(&summary.home_team_name, home_mvps));
+        lines.push(mvps_line(&summary.away_team_name, away_mvps));
+    }
+
+    // The last three pointers of the game make for the highlights.
+    let highlights = game
+        .action_results
+        .iter()
+        .filter(|action| action.score_change == 3)
+        .rev()
+        .take(MAX_GAME_SUMMARY_HIGHLIGHTS)
+        .collect::<Vec<_>>();
+    if !highlights.is_empty() {
+        lines.push("**Highlights**".to_string());
+        for action in highlights.iter().rev() {
+            lines.push(format!(
+                "- {} {}",
+                action.start_at.format().trim(),
+                action.description.split_whitespace().join(" ")
+            ));
+        }
+    }
+
+    lines.join("\n") + "\n"
+}
+
+// Quotes the field if it contains a separator, a quote or a newline.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn to_csv(rows: Vec<Vec<String>>) -> String {
+    rows.iter()
+        .map(|row| row.join(","))
+        .collect::<Vec<_>>()
+        .join("\n")
+        + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{csv_field, game_summary_markdown, PlayerExport, StatLine, TeamExport};
+    use crate::game_engine::action::ActionOutput;
+    use crate::game_engine::game::{Game, GameMVPSummary, GameSummary};
+    use crate::game_engine::types::GameStats;
+    use crate::world::skill::SKILL_NAMES;
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("Rebels"), "Rebels");
+        assert_eq!(csv_field("Smith, Jr."), "\"Smith, Jr.\"");
+        assert_eq!(csv_field("The \"Rock\""), "\"The \"\"Rock\"\"\"");
+    }
+
+    #[test]
+    fn test_roster_csv() {
+        let mut career = StatLine::default();
+        career.add(&GameStats {
+            points: 12,
+            offensive_rebounds: 2,
+            defensive_rebounds: 3,
+            seconds_played: 600,
+            ..Default::default()
+        });
+        let export = TeamExport {
+            team: "Rebels".to_string(),
+            roster: vec![PlayerExport {
+                name: "Joe Smith".to_string(),
+                age: 25.0,
+                crew_role: "Captain".to_string(),
+                rating: 3,
+                skills: vec![10.0; SKILL_NAMES.len()],
+                career,
+            }],
+            ..Default::default()
+        };
+
+        let csv = export.roster_csv();
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].split(',').count(), lines[1].split(',').count());
+        assert!(lines[1].starts_with("Joe Smith,25.0,Captain,3,10.00"));
+        assert!(lines[1].ends_with(",1,10,12,0,0,0,0,0,0,5,0,0,0,0,0"));
+    }
+
+    #[test]
+    fn test_game_summary_markdown() {
+        let summary = GameSummary {
+            home_team_name: "Rebels".to_string(),
+            away_team_name: "Pirates".to_string(),
+            home_quarters_score: [20, 22, 18, 25],
+            away_quarters_score: [18, 20, 24, 19],
+            attendance: 1200,
+            ..Default::default()
+        };
+        let mvp = GameMVPSummary {
+            name: "J. Smith".to_string(),
+            score: 30,
+            best_stats: [
+                ("pts".to_string(), 24, 24),
+                ("reb".to_string(), 8, 8),
+                ("blk".to_string(), 0, 0),
+            ],
+        };
+        let mut game = Game::default();
+        game.home_team_mvps = Some(vec![mvp.clone()]);
+        game.away_team_mvps = Some(vec![mvp]);
+        game.action_results = vec![ActionOutput {
+            description: "Smith hits\nfrom deep!".to_string(),
+            score_change: 3,
+            ..Default::default()
+        }];
+
+        let text = game_summary_markdown(&summary, &game, "Ganymede");
+        let lines = text.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "**Rebels 85 - 81 Pirates**");
+        assert!(lines[1].starts_with("Ganymede, attendance 1200"));
+        assert_eq!(lines[4], "Rebels   20  22  18  25  85");
+        assert!(text.contains("Pirates: J. Smith (24 pts, 8 reb)"));
+        assert!(text.ends_with("from deep!\n"));
+        assert!(text.contains("Smith hits from deep!"));
+    }
+}
@@ -1,6 +1,7 @@
 use super::{
     brawl::Brawl,
     end_of_quarter::EndOfQuarter,
+    foul::Foul,
     game::Game,
     isolation::Isolation,
     jump_ball::JumpBall,
@@ -9,6 +10,7 @@ use super::{
     post::Post,
     rebound::Rebound,
     shot::{CloseShot, LongShot, MediumShot},
+    shot_clock::ShotClockViolation,
     start_of_quarter::StartOfQuarter,
     substitution::Substitution,
     timer::Timer,
@@ -84,6 +86,8 @@ pub enum Action {
     LongShot,
     Substitution,
     Brawl,
+    ShotClockViolation,
+    Foul,
 }
 
 impl Action {
@@ -107,6 +111,8 @@ impl Action {
             Action::LongShot => LongShot::execute(input, game, rng),
             Action::Substitution => Substitution::execute(input, game, rng),
             Action::Brawl => Brawl::execute(input, game, rng),
+            Action::ShotClockViolation => ShotClockViolation::execute(input, game, rng),
+            Action::Foul => Foul::execute(input, game, rng),
         };
         output.as_mut()?.random_seed = rng.get_seed();
         output
@@ -5,8 +5,8 @@ use super::{
 };
 use crate::world::{
     constants::{MoraleModifier, TirednessCost},
-    player::Trait,
     skill::GameSkill,
+    traits,
 };
 use rand::Rng;
 use rand_chacha::ChaCha8Rng;
@@ -24,10 +24,8 @@ impl EngineAction for Brawl {
             .map(|p| {
                 if p.is_knocked_out() {
                     0
-                } else if p.special_trait == Some(Trait::Killer) {
-                    p.mental.aggression.value() * 2
                 } else {
-                    p.mental.aggression.value()
+                    traits::brawl_weight(p)
                 }
             })
             .collect::<Vec<u8>>()
@@ -42,10 +40,8 @@ impl EngineAction for Brawl {
             .map(|p| {
                 if p.is_knocked_out() {
                     0
-                } else if p.special_trait == Some(Trait::Killer) {
-                    p.mental.aggression.value() * 2
                 } else {
-                    p.mental.aggression.value()
+                    traits::brawl_weight(p)
                 }
             })
             .collect::<Vec<u8>>()
@@ -66,23 +62,17 @@ impl EngineAction for Brawl {
         let mut defender_update = GameStats::default();
         defender_update.extra_tiredness = TirednessCost::MEDIUM;
 
-        let mut atk_result = attacker.roll(rng)
-            + attacker.athletics.strength.value() / 2
+        let atk_result = attacker.roll(rng)
+            + game.venue.athletics(attacker.athletics.strength.value()) / 2
             + attacker.mental.aggression.value() / 2
-            + attacker.offense.brawl.value();
+            + attacker.offense.brawl.value()
+            + traits::brawl_bonus(attacker);
 
-        if attacker.special_trait == Some(Trait::Killer) {
-            atk_result += attacker.reputation.value();
-        }
-
-        let mut def_result = defender.roll(rng)
-            + defender.athletics.strength.value() / 2
+        let def_result = defender.roll(rng)
+            + game.venue.athletics(defender.athletics.strength.value()) / 2
             + defender.mental.aggression.value() / 2
-            + defender.offense.brawl.value();
-
-        if defender.special_trait == Some(Trait::Killer) {
-            def_result += defender.reputation.value();
-        }
+            + defender.offense.brawl.value()
+            + traits::brawl_bonus(defender);
 
         let description = match atk_result as i16 - def_result as i16 {
             x if x > 0 => {
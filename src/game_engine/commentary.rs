@@ -0,0 +1,206 @@
+use crate::{assets::load_json_asset_or_default, world::player::Player};
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+// Margin of points above which a game is considered out of reach.
+pub const BLOWOUT_MARGIN: i32 = 15;
+pub const CLOSE_GAME_MARGIN: i32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlayKind {
+    MadeTwo,
+    MadeThree,
+    Block,
+    Steal,
+}
+
+/// Score of the team making the play, after the play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScoreSituation {
+    Tied,
+    Leading,
+    Trailing,
+    Blowout,
+}
+
+impl ScoreSituation {
+    pub fn from_margin(margin: i32) -> Self {
+        match margin {
+            0 => Self::Tied,
+            m if m.abs() >= BLOWOUT_MARGIN => Self::Blowout,
+            m if m > 0 => Self::Leading,
+            _ => Self::Trailing,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FinalResult {
+    Tie,
+    Close,
+    Regular,
+    Blowout,
+}
+
+impl FinalResult {
+    pub fn from_margin(margin: i32) -> Self {
+        match margin.abs() {
+            0 => Self::Tie,
+            m if m <= CLOSE_GAME_MARGIN => Self::Close,
+            m if m >= BLOWOUT_MARGIN => Self::Blowout,
+            _ => Self::Regular,
+        }
+    }
+}
+
+/// Commentary templates, loaded from the assets so that they can be replaced
+/// through the assets overlay. Templates can use the {player}, {team} and
+/// {opponent} placeholders. Trait lines are keyed by the trait name.
+#[derive(Debug, Default, Deserialize)]
+pub struct CommentaryData {
+    #[serde(default)]
+    pub plays: HashMap<PlayKind, HashMap<ScoreSituation, Vec<String>>>,
+    #[serde(default)]
+    pub traits: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    pub post_game: HashMap<FinalResult, Vec<String>>,
+}
+
+// Without commentary data the game descriptions are simply left as they are.
+pub static COMMENTARY_DATA: Lazy<CommentaryData> =
+    Lazy::new(|| load_json_asset_or_default("data/commentary_data.json"));
+
+// Templates are picked without touching the game rng, so that different
+// commentary data cannot change the outcome of a game.
+fn pick(templates: Option<&Vec<String>>, key: usize) -> Option<&String> {
+    let templates = templates?;
+    if templates.is_empty() {
+        return None;
+    }
+    Some(&templates[key % templates.len()])
+}
+
+fn fill(template: &str, player: Option<&Player>, team: &str, opponent: &str) -> String {
+    let text = template
+        .replace("{team}", team)
+        .replace("{opponent}", opponent);
+    match player {
+        Some(player) => text.replace("{player}", &player.info.shortened_name()),
+        None => text,
+    }
+}
+
+impl CommentaryData {
+    pub fn play(
+        &self,
+        kind: PlayKind,
+        situation: ScoreSituation,
+        player: &Player,
+        team: &str,
+        opponent: &str,
+        key: usize,
+    ) -> Option<String> {
+        let mut lines = vec![];
+        if let Some(template) = pick(
+            self.plays
+                .get(&kind)
+                .and_then(|plays| plays.get(&situation)),
+            key,
+        ) {
+            lines.push(fill(template, Some(player), team, opponent));
+        }
+
+        // Pirates with a trait get some extra color every other play.
+        if let Some(special_trait) = player.special_trait {
+            if key % 2 == 0 {
+                if let Some(template) = pick(self.traits.get(&special_trait.to_string()), key / 2) {
+                    lines.push(fill(template, Some(player), team, opponent));
+                }
+            }
+        }
+
+        if lines.is_empty() {
+            return None;
+        }
+        Some(lines.join(" "))
+    }
+
+    pub fn post_game(
+        &self,
+        result: FinalResult,
+        winner: &str,
+        loser: &str,
+        key: usize,
+    ) -> Option<String> {
+        pick(self.post_game.get(&result), key).map(|template| fill(template, None, winner, loser))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommentaryData, FinalResult, PlayKind, ScoreSituation};
+    use crate::types::PlayerId;
+    use crate::world::{player::Player, traits::Trait, utils::PLANET_DATA};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_commentary_templates() {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let mut player = Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 0.0);
+        player.special_trait = Some(Trait::Killer);
+
+        let data = CommentaryData {
+            plays: HashMap::from([(
+                PlayKind::MadeThree,
+                HashMap::from([(
+                    ScoreSituation::Trailing,
+                    vec!["{player} keeps {team} alive.".to_string()],
+                )]),
+            )]),
+            traits: HashMap::from([("Killer".to_string(), vec!["Ruthless.".to_string()])]),
+            post_game: HashMap::from([(
+                FinalResult::Blowout,
+                vec!["{team} crushed {opponent}.".to_string()],
+            )]),
+        };
+
+        assert_eq!(ScoreSituation::from_margin(-2), ScoreSituation::Trailing);
+        assert_eq!(
+            data.play(
+                PlayKind::MadeThree,
+                ScoreSituation::Trailing,
+                &player,
+                "Rebels",
+                "Pirates",
+                0
+            ),
+            Some(format!(
+                "{} keeps Rebels alive. Ruthless.",
+                player.info.shortened_name()
+            ))
+        );
+        assert!(data
+            .play(
+                PlayKind::Block,
+                ScoreSituation::Tied,
+                &player,
+                "Rebels",
+                "Pirates",
+                1
+            )
+            .is_none());
+        assert_eq!(
+            data.post_game(FinalResult::from_margin(-20), "Rebels", "Pirates", 3),
+            Some("Rebels crushed Pirates.".to_string())
+        );
+        assert!(data
+            .post_game(FinalResult::Close, "Rebels", "Pirates", 0)
+            .is_none());
+    }
+}
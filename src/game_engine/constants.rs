@@ -1,3 +1,8 @@
+use crate::{
+    types::Tick,
+    world::constants::{MoraleModifier, SECONDS},
+};
+
 pub const ADV_ATTACK_LIMIT: i16 = 16;
 pub const ADV_NEUTRAL_LIMIT: i16 = 0;
 pub const ADV_DEFENSE_LIMIT: i16 = -20;
@@ -14,4 +19,29 @@ pub const MIN_TIREDNESS_FOR_SUB: f32 = 10.0;
 pub const MIN_TIREDNESS_FOR_ROLL_DECLINE: f32 = 10.0;
 
 pub const BASE_ATTENDANCE: u32 = 60;
+// The home crowd cheers its team at every break, louder in a full stadium.
+pub const HOME_CROWD_FULL_ATTENDANCE: u32 = 500;
+pub const HOME_CROWD_MORALE_BONUS: f32 = MoraleModifier::MEDIUM_BONUS;
 pub const BRAWL_ACTION_PROBABILITY: f32 = 0.06;
+
+// Scoring runs build momentum, which gives a bonus to the rolls of the team riding it.
+pub const MAX_MOMENTUM: u8 = 10;
+pub const MOMENTUM_LOSS_PER_OPPONENT_POINT: u8 = 2;
+
+// Fouls are more likely against aggressive defenders, see foul::aggression_modifier.
+pub const FOUL_ACTION_PROBABILITY: f32 = 0.04;
+pub const SHOOTING_FOUL_PROBABILITY: f32 = 0.06;
+pub const MAX_PERSONAL_FOULS: u8 = 5;
+// From this many team fouls in a quarter, every foul is sent to the line.
+pub const TEAM_FOULS_FOR_BONUS: u8 = 4;
+pub const FREE_THROW_BASE_PROBABILITY: f32 = 0.5;
+pub const FREE_THROW_SKILL_PROBABILITY: f32 = 0.4;
+
+// Spectators of a network game can send short reactions, shown as crowd chatter.
+pub const CROWD_CHATTER_MIN_INTERVAL: Tick = 10 * SECONDS;
+pub const CROWD_CHATTER_MAX_LENGTH: usize = 80;
+pub const MAX_CROWD_CHATTER_PER_GAME: usize = 100;
+
+// Coaching commands the own team can issue during a local game.
+pub const MAX_TIMEOUTS_PER_GAME: u8 = 2;
+pub const TIMEOUT_TIREDNESS_RECOVERY: f32 = 5.0;
@@ -1,7 +1,7 @@
 use super::{
     action::{ActionOutput, ActionSituation, EngineAction},
     game::Game,
-    timer::{Period, Timer},
+    timer::Period,
 };
 use rand_chacha::ChaCha8Rng;
 
@@ -39,8 +39,10 @@ impl EngineAction for EndOfQuarter {
             possession: input.possession,
             situation: ActionSituation::EndOfQuarter,
             description,
-            start_at: Timer::from(game.timer.period().previous().end()),
-            end_at: Timer::from(game.timer.period().end()),
+            start_at: game
+                .timer
+                .at(game.timer.period_end(game.timer.period().previous())),
+            end_at: game.timer.at(game.timer.period_end(game.timer.period())),
             home_score: input.home_score,
             away_score: input.away_score,
             ..Default::default()
@@ -0,0 +1,200 @@
+use super::{
+    action::{ActionOutput, ActionSituation, EngineAction},
+    constants::*,
+    game::Game,
+    types::{GameStats, GameStatsMap, Possession},
+};
+use crate::world::{
+    constants::MoraleModifier,
+    player::Player,
+    skill::{GameSkill, MAX_SKILL},
+};
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+pub struct Foul;
+
+// Aggressive defenders foul more, between 0.5 and 1.5 times the base probability.
+pub fn aggression_modifier(defenders: &[&Player]) -> f32 {
+    if defenders.is_empty() {
+        return 1.0;
+    }
+    let aggression = defenders
+        .iter()
+        .map(|p| p.mental.aggression.value() as f32)
+        .sum::<f32>()
+        / defenders.len() as f32;
+    0.5 + aggression / MAX_SKILL
+}
+
+// Returns how many of the free throws the shooter makes.
+pub fn free_throws(shooter: &Player, attempts: u8, rng: &mut ChaCha8Rng) -> u8 {
+    let probability = FREE_THROW_BASE_PROBABILITY
+        + FREE_THROW_SKILL_PROBABILITY * shooter.offense.medium_range.value() as f32 / MAX_SKILL;
+    (0..attempts)
+        .filter(|_| rng.gen_bool(probability as f64))
+        .count() as u8
+}
+
+pub fn free_throws_description(shooter: &Player, attempts: u8, made: u8) -> String {
+    match (attempts, made) {
+        (1, 1) => format!("{} converts the free throw.", shooter.info.shortened_name()),
+        (1, _) => format!("{} misses the free throw.", shooter.info.shortened_name()),
+        (_, 0) => format!(
+            "{} misses all {} free throws.",
+            shooter.info.shortened_name(),
+            attempts
+        ),
+        _ => format!(
+            "{} makes {} of {} free throws.",
+            shooter.info.shortened_name(),
+            made,
+            attempts
+        ),
+    }
+}
+
+// Counts the new foul of the defender, who might be fouling out.
+pub fn commit_foul(
+    defender: &Player,
+    defending_stats: &GameStatsMap,
+    update: &mut GameStats,
+) -> Option<String> {
+    update.fouls += 1;
+    update.extra_morale += MoraleModifier::SMALL_MALUS;
+    let fouls = defending_stats
+        .get(&defender.id)
+        .map(|stats| stats.fouls)
+        .unwrap_or_default()
+        + update.fouls;
+    if fouls == MAX_PERSONAL_FOULS {
+        Some(format!(
+            "That's foul number {} for {}, who fouls out of the game!",
+            fouls,
+            defender.info.shortened_name()
+        ))
+    } else {
+        None
+    }
+}
+
+impl EngineAction for Foul {
+    fn execute(input: &ActionOutput, game: &Game, rng: &mut ChaCha8Rng) -> Option<ActionOutput> {
+        let attacking_players = game.attacking_players();
+        let defending_players = game.defending_players();
+
+        let mut weights = [0; 5];
+        for (idx, player) in defending_players.iter().enumerate() {
+            if !player.is_knocked_out() {
+                weights[idx] = player.mental.aggression.value().max(1);
+            }
+        }
+        let foul_idx = Self::sample(rng, weights)?;
+        let defender = defending_players[foul_idx];
+        let fouled = attacking_players[foul_idx];
+
+        let mut defender_update = GameStats::default();
+        let foul_out = commit_foul(defender, game.defending_stats(), &mut defender_update);
+        let mut description = format!(
+            "{} commits a foul on {}.",
+            defender.info.shortened_name(),
+            fouled.info.shortened_name()
+        );
+
+        let defending_team = game.defending_team();
+        let mut result = if defending_team.team_fouls >= TEAM_FOULS_FOR_BONUS {
+            let attempts = 2;
+            let made = free_throws(fouled, attempts, rng);
+            let mut fouled_update = GameStats::default();
+            fouled_update.attempted_ft = attempts;
+            fouled_update.made_ft = made;
+            fouled_update.points = made;
+            description = format!(
+                "{} {} is over the limit, free throws. {}",
+                description,
+                defending_team.name,
+                free_throws_description(fouled, attempts, made)
+            );
+
+            let mut attack_stats_update = HashMap::new();
+            attack_stats_update.insert(fouled.id, fouled_update);
+            ActionOutput {
+                score_change: made,
+                home_score: match input.possession {
+                    Possession::Home => input.home_score + made as u16,
+                    Possession::Away => input.home_score,
+                },
+                away_score: match input.possession {
+                    Possession::Home => input.away_score,
+                    Possession::Away => input.away_score + made as u16,
+                },
+                possession: !input.possession,
+                situation: ActionSituation::BallInBackcourt,
+                attack_stats_update: Some(attack_stats_update),
+                start_at: input.end_at,
+                end_at: input.end_at.plus(4 + rng.gen_range(0..=2)),
+                ..Default::default()
+            }
+        } else {
+            // The attack keeps the ball and inbounds it.
+            ActionOutput {
+                possession: input.possession,
+                situation: ActionSituation::BallInBackcourt,
+                start_at: input.end_at,
+                end_at: input.end_at.plus(2),
+                home_score: input.home_score,
+                away_score: input.away_score,
+                ..Default::default()
+            }
+        };
+
+        if let Some(foul_out) = foul_out {
+            description = format!("{} {}", description, foul_out);
+        }
+        let mut defense_stats_update = HashMap::new();
+        defense_stats_update.insert(defender.id, defender_update);
+        result.description = description;
+        result.foul_from = Some(foul_idx);
+        result.foul_on = Some(foul_idx);
+        result.defense_stats_update = Some(defense_stats_update);
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{commit_foul, free_throws};
+    use crate::game_engine::constants::MAX_PERSONAL_FOULS;
+    use crate::game_engine::types::{GameStats, GameStatsMap};
+    use crate::types::PlayerId;
+    use crate::world::{player::Player, utils::PLANET_DATA};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_fouls_and_free_throws() {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let shooter = Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 0.0);
+        let defender = Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 0.0);
+
+        for attempts in 1..=3 {
+            assert!(free_throws(&shooter, attempts, rng) <= attempts);
+        }
+
+        let mut stats = GameStatsMap::new();
+        stats.insert(defender.id, GameStats::default());
+        for _ in 1..MAX_PERSONAL_FOULS {
+            let mut update = GameStats::default();
+            assert!(commit_foul(&defender, &stats, &mut update).is_none());
+            stats.get_mut(&defender.id).unwrap().update(&update);
+            assert!(!stats[&defender.id].is_fouled_out());
+        }
+
+        let mut update = GameStats::default();
+        assert!(commit_foul(&defender, &stats, &mut update).is_some());
+        stats.get_mut(&defender.id).unwrap().update(&update);
+        assert!(stats[&defender.id].is_fouled_out());
+    }
+}
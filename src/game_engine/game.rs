@@ -1,28 +1,39 @@
 use super::{
     action::{Action, ActionOutput, ActionSituation, EngineAction},
+    commentary::{FinalResult, PlayKind, ScoreSituation, COMMENTARY_DATA},
     constants::*,
     end_of_quarter::EndOfQuarter,
+    foul,
+    rules::{MatchRules, OVERTIME_MINUTES},
     substitution::Substitution,
     timer::{Period, Timer},
-    types::{GameStatsMap, Possession, TeamInGame},
+    types::{
+        CrowdChatter, GameCommand, GameStats, GameStatsMap, Possession, SpeechTone, TeamInGame,
+    },
+    venue::Venue,
 };
 use crate::{
-    types::{GameId, PlanetId, PlayerId, SortablePlayerMap, TeamId, Tick},
+    types::{AppResult, GameId, PlanetId, PlayerId, SortablePlayerMap, TeamId, Tick},
     world::{
         constants::{MoraleModifier, TirednessCost},
+        injury::InjuryKind,
         planet::Planet,
-        player::{Player, Trait},
+        player::Player,
         position::MAX_POSITION,
+        role::CrewRole,
         skill::GameSkill,
+        traits::{self, CLUTCH_SCORE_MARGIN, CLUTCH_TIME_SECONDS},
+        utils::is_default,
     },
 };
+use anyhow::anyhow;
 use itertools::Itertools;
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
 pub struct GameSummary {
     pub id: GameId,
     pub home_team_id: TeamId,
@@ -35,6 +46,9 @@ pub struct GameSummary {
     pub away_team_knocked_out: bool,
     pub home_quarters_score: [u16; 4],
     pub away_quarters_score: [u16; 4],
+    // Home and away points scored in overtime, if the game went to overtime.
+    #[serde(default)]
+    pub overtime_score: Option<(u16, u16)>,
     pub location: PlanetId,
     pub attendance: u32,
     pub starting_at: Tick,
@@ -46,6 +60,7 @@ impl GameSummary {
     pub fn from_game(game: &Game) -> GameSummary {
         let mut home_quarters_score = [0 as u16; 4];
         let mut away_quarters_score = [0 as u16; 4];
+        let mut overtime_score = None;
         for action in game.action_results.iter() {
             // We need to loop over every action to cover the case in which the game ends abrutly because one team is knocked out.
             // For quarters>1, we need to remove previous quarters score to get only the partial score of the quarter.
@@ -74,6 +89,12 @@ impl GameSummary {
                         - away_quarters_score[1]
                         - away_quarters_score[2];
                 }
+                Period::OT => {
+                    overtime_score = Some((
+                        action.home_score - home_quarters_score.iter().sum::<u16>(),
+                        action.away_score - away_quarters_score.iter().sum::<u16>(),
+                    ));
+                }
                 _ => continue,
             }
         }
@@ -88,6 +109,7 @@ impl GameSummary {
             away_team_knocked_out: game.is_team_knocked_out(Possession::Away),
             home_quarters_score,
             away_quarters_score,
+            overtime_score,
             location: game.location,
             attendance: game.attendance,
             starting_at: game.starting_at,
@@ -95,6 +117,14 @@ impl GameSummary {
             winner: game.winner,
         }
     }
+
+    pub fn score(&self) -> (u16, u16) {
+        let (home_overtime, away_overtime) = self.overtime_score.unwrap_or_default();
+        (
+            self.home_quarters_score.iter().sum::<u16>() + home_overtime,
+            self.away_quarters_score.iter().sum::<u16>() + away_overtime,
+        )
+    }
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
@@ -122,6 +152,17 @@ pub struct Game {
     pub winner: Option<TeamId>,
     pub home_team_mvps: Option<Vec<GameMVPSummary>>,
     pub away_team_mvps: Option<Vec<GameMVPSummary>>,
+    #[serde(skip)]
+    pub crowd_chatter: Vec<CrowdChatter>,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub pending_commands: Vec<(TeamId, GameCommand)>,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub rules: MatchRules,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub venue: Venue,
 }
 
 impl<'game> Game {
@@ -131,6 +172,7 @@ impl<'game> Game {
         away_team_in_game: TeamInGame,
         starting_at: Tick,
         planet: &Planet,
+        rules: MatchRules,
     ) -> Self {
         let total_reputation = home_team_in_game.reputation + away_team_in_game.reputation;
         let home_name = home_team_in_game.name.clone();
@@ -139,25 +181,13 @@ impl<'game> Game {
         let bonus_attendance = home_team_in_game
             .players
             .iter()
-            .map(|(_, player)| {
-                if player.special_trait == Some(Trait::Showpirate) {
-                    player.reputation.value()
-                } else {
-                    0
-                }
-            })
+            .map(|(_, player)| traits::attendance_bonus(player))
             .sum::<u8>() as f32
             / 100.0
             + away_team_in_game
                 .players
                 .iter()
-                .map(|(_, player)| {
-                    if player.special_trait == Some(Trait::Showpirate) {
-                        player.reputation.value()
-                    } else {
-                        0
-                    }
-                })
+                .map(|(_, player)| traits::attendance_bonus(player))
                 .sum::<u8>() as f32
                 / 100.0;
 
@@ -172,12 +202,16 @@ impl<'game> Game {
             action_results: vec![], // We start from default empty output
             won_jump_ball: Possession::default(),
             possession: Possession::default(),
-            timer: Timer::default(),
+            timer: Timer::with_quarter_length(rules.quarter_length),
             next_step: 0,
             current_action: Action::JumpBall,
             winner: None,
             home_team_mvps: None,
             away_team_mvps: None,
+            crowd_chatter: vec![],
+            pending_commands: vec![],
+            rules,
+            venue: Venue::default(),
         };
         let seed = game.get_rng_seed();
         let mut rng = ChaCha8Rng::from_seed(seed);
@@ -187,12 +221,19 @@ impl<'game> Game {
                 * rng.gen_range(0.75..1.25)
                 * (1.0 + bonus_attendance);
         game.attendance = attendance as u32;
+        game.venue = Venue::new(planet, game.attendance);
         let mut default_output = ActionOutput::default();
         default_output.description = format!(
-            "{} vs {}. Game is about to start here on {}! There are {} people in the stadium.",
-            home_name, away_name, planet.name, game.attendance
+            "{} vs {}. Game is about to start here on {}! There are {} people in the stadium, cheering for {}.",
+            home_name, away_name, planet.name, game.attendance, home_name
         );
+        let conditions = Venue::conditions(planet);
+        if !conditions.is_empty() {
+            default_output.description = format!("{} {}", default_output.description, conditions);
+        }
         default_output.random_seed = seed;
+        default_output.start_at = game.timer;
+        default_output.end_at = game.timer;
         game.action_results.push(default_output);
         game
     }
@@ -272,6 +313,10 @@ impl<'game> Game {
             .situation
             .clone();
 
+        if self.is_shot_clock_expired(situation) {
+            return Action::ShotClockViolation;
+        }
+
         match situation {
             ActionSituation::JumpBall => Action::JumpBall,
             ActionSituation::AfterOffensiveRebound => Action::CloseShot,
@@ -284,8 +329,12 @@ impl<'game> Game {
                 let brawl_probability = BRAWL_ACTION_PROBABILITY
                     * (self.home_team_in_game.tactic.brawl_probability_modifier()
                         + self.away_team_in_game.tactic.brawl_probability_modifier());
+                let foul_probability =
+                    FOUL_ACTION_PROBABILITY * foul::aggression_modifier(&self.defending_players());
                 if rng.gen_bool(brawl_probability as f64) {
                     Action::Brawl
+                } else if rng.gen_bool(foul_probability as f64) {
+                    Action::Foul
                 } else {
                     match self.possession {
                         Possession::Home => self
@@ -327,11 +376,13 @@ impl<'game> Game {
         };
 
         if let Some(updates) = &mut home_stats {
+            self.home_team_in_game.team_fouls += updates.values().map(|s| s.fouls).sum::<u8>();
+            let stamina = 1.0 + self.home_team_in_game.cook_bonus;
             for (id, player_stats) in self.home_team_in_game.stats.iter_mut() {
                 let player = self.home_team_in_game.players.get_mut(&id).unwrap();
                 if let Some(stats) = updates.get_mut(&id) {
                     player_stats.update(stats);
-                    player.add_tiredness(stats.extra_tiredness);
+                    player.add_tiredness(stats.extra_tiredness / stamina);
                     player.add_morale(stats.extra_morale);
                 }
                 // Add morale if team scored
@@ -345,12 +396,14 @@ impl<'game> Game {
             }
         }
         if let Some(updates) = &mut away_stats {
+            self.away_team_in_game.team_fouls += updates.values().map(|s| s.fouls).sum::<u8>();
+            let stamina = 1.0 + self.away_team_in_game.cook_bonus;
             for (id, player_stats) in self.away_team_in_game.stats.iter_mut() {
                 let player = self.away_team_in_game.players.get_mut(&id).unwrap();
 
                 if let Some(stats) = updates.get_mut(&id) {
                     player_stats.update(stats);
-                    player.add_tiredness(stats.extra_tiredness);
+                    player.add_tiredness(stats.extra_tiredness / stamina);
                     player.add_morale(stats.extra_morale);
                 }
                 // Add morale if team scored
@@ -365,6 +418,99 @@ impl<'game> Game {
         }
     }
 
+    fn update_momentum(&mut self, score_change: u8) {
+        // A loud home crowd gets hyped by the runs of its team.
+        let crowd_hype = (self.possession == Possession::Home
+            && self.venue.home_crowd_bonus >= HOME_CROWD_MORALE_BONUS / 2.0)
+            as u8;
+        let (attack, defense) = match self.possession {
+            Possession::Home => (&mut self.home_team_in_game, &mut self.away_team_in_game),
+            Possession::Away => (&mut self.away_team_in_game, &mut self.home_team_in_game),
+        };
+        if score_change > 0 {
+            attack.momentum = (attack.momentum + score_change + crowd_hype).min(MAX_MOMENTUM);
+            defense.momentum = defense
+                .momentum
+                .saturating_sub(MOMENTUM_LOSS_PER_OPPONENT_POINT * score_change);
+        }
+        defense.momentum = defense
+            .momentum
+            .saturating_sub(attack.tactic.momentum_break());
+    }
+
+    // Added to the attack rolls, negative if the defense is riding the momentum.
+    pub fn momentum_bonus(&self) -> i16 {
+        let (attack, defense) = match self.possession {
+            Possession::Home => (&self.home_team_in_game, &self.away_team_in_game),
+            Possession::Away => (&self.away_team_in_game, &self.home_team_in_game),
+        };
+        (attack.momentum as i16 - defense.momentum as i16) / 2
+    }
+
+    // The shot clock resets on every change of possession and on offensive rebounds.
+    fn shot_clock_started_at(&self) -> u16 {
+        let mut started_at = 0;
+        for result in self.action_results.iter().rev() {
+            if result.possession != self.possession
+                || result.situation == ActionSituation::EndOfQuarter
+            {
+                break;
+            }
+            started_at = result.end_at.value;
+            if matches!(
+                result.situation,
+                ActionSituation::JumpBall
+                    | ActionSituation::AfterOffensiveRebound
+                    | ActionSituation::AfterLongOffensiveRebound
+            ) {
+                break;
+            }
+        }
+        started_at
+    }
+
+    // Checked while the attack is still looking for a shot.
+    fn is_shot_clock_expired(&self, situation: ActionSituation) -> bool {
+        let Some(seconds) = self.rules.shot_clock.seconds() else {
+            return false;
+        };
+        matches!(
+            situation,
+            ActionSituation::BallInBackcourt
+                | ActionSituation::BallInMidcourt
+                | ActionSituation::AfterLongOffensiveRebound
+                | ActionSituation::AfterDefensiveRebound
+                | ActionSituation::Turnover
+        ) && self.timer.value >= self.shot_clock_started_at() + seconds
+    }
+
+    // The end of a close game, when made shots count as clutch.
+    fn is_clutch_time(&self) -> bool {
+        let (home, away) = self.get_score();
+        let period = self.timer.period();
+        matches!(period, Period::Q4 | Period::OT)
+            && self.timer.value + CLUTCH_TIME_SECONDS >= self.timer.period_end(period)
+            && home.abs_diff(away) <= CLUTCH_SCORE_MARGIN
+    }
+
+    fn record_clutch_shots(&mut self, attack_stats: &Option<GameStatsMap>) {
+        let updates = match attack_stats {
+            Some(updates) => updates,
+            None => return,
+        };
+        let team_stats = match self.possession {
+            Possession::Home => &mut self.home_team_in_game.stats,
+            Possession::Away => &mut self.away_team_in_game.stats,
+        };
+        for (id, update) in updates.iter() {
+            if matches!(update.last_action_shot, Some((_, _, true))) {
+                if let Some(stats) = team_stats.get_mut(id) {
+                    stats.clutch_shots += 1;
+                }
+            }
+        }
+    }
+
     fn apply_sub_update(
         &mut self,
         attack_stats: Option<GameStatsMap>,
@@ -393,8 +539,75 @@ impl<'game> Game {
         assert!(self.home_team_in_game.stats.len() == self.home_team_in_game.players.len());
     }
 
+    fn apply_injuries(&mut self, result: &ActionOutput, rng: &mut ChaCha8Rng) -> Option<String> {
+        // Ids are sorted so that the rolls are the same when the game is simulated by a peer.
+        let involved_ids = result
+            .attack_stats_update
+            .iter()
+            .chain(result.defense_stats_update.iter())
+            .flat_map(|updates| updates.keys().copied())
+            .sorted()
+            .dedup()
+            .collect::<Vec<PlayerId>>();
+
+        let mut descriptions = vec![];
+        for player_id in involved_ids {
+            let team = if self.home_team_in_game.players.contains_key(&player_id) {
+                &mut self.home_team_in_game
+            } else {
+                &mut self.away_team_in_game
+            };
+            let player = match team.players.get(&player_id) {
+                Some(player) => player,
+                None => continue,
+            };
+            let stats = match team.stats.get_mut(&player_id) {
+                Some(stats) => stats,
+                None => continue,
+            };
+
+            if !stats.is_playing()
+                || stats.injury.is_some()
+                || player.is_injured()
+                || player.is_knocked_out()
+            {
+                continue;
+            }
+
+            if rng.gen_bool(player.injury_probability().min(1.0)) {
+                let kind = InjuryKind::random(rng);
+                stats.injury = Some(kind);
+                descriptions.push(format!(
+                    "{} suffered a {}!",
+                    player.info.shortened_name(),
+                    kind.to_string().to_lowercase()
+                ));
+            }
+        }
+
+        if descriptions.is_empty() {
+            return None;
+        }
+        Some(descriptions.join(" "))
+    }
+
+    fn apply_home_crowd_bonus(&mut self) -> Option<String> {
+        if self.venue.home_crowd_bonus <= 0.0 {
+            return None;
+        }
+
+        for player in self.home_team_in_game.players.values_mut() {
+            player.add_morale(self.venue.home_crowd_bonus);
+        }
+        Some(format!(
+            "The home crowd roars for {}!",
+            self.home_team_in_game.name
+        ))
+    }
+
     fn apply_tiredness_update(&mut self) {
         for team in [&mut self.home_team_in_game, &mut self.away_team_in_game] {
+            let stamina = 1.0 + team.cook_bonus;
             for (id, player) in team.players.iter_mut() {
                 let stats = team.stats.get_mut(&id).expect("Player should have stats");
                 if stats.is_playing() && !self.timer.is_break() {
@@ -404,7 +617,7 @@ impl<'game> Game {
                             .position
                             .expect("Playing player should have a position")
                             as usize] += 1;
-                        player.add_tiredness(TirednessCost::LOW);
+                        player.add_tiredness(TirednessCost::LOW / stamina);
                     }
                 } else if player.tiredness > RECOVERING_TIREDNESS_PER_SHORT_TICK
                     && !player.is_knocked_out()
@@ -471,6 +684,13 @@ impl<'game> Game {
         }
     }
 
+    pub fn defending_team(&self) -> &TeamInGame {
+        match self.possession {
+            Possession::Home => &self.away_team_in_game,
+            Possession::Away => &self.home_team_in_game,
+        }
+    }
+
     fn get_rng_seed(&self) -> [u8; 32] {
         let mut seed = [0; 32];
         seed[0..16].copy_from_slice(self.id.as_bytes());
@@ -506,13 +726,15 @@ impl<'game> Game {
 
     fn game_end_description(&self, winner: Option<&str>) -> String {
         let (home, away) = self.get_score();
+        let result = FinalResult::from_margin(home as i32 - away as i32);
+        let key = self.timer.value as usize;
         if let Some(winner_name) = winner {
             let loser_name = if winner_name.to_string() == self.home_team_in_game.name {
                 self.away_team_in_game.name.clone()
             } else {
                 self.home_team_in_game.name.clone()
             };
-            format!(
+            let description = format!(
                 "{} won this nice game over {}. The final score is {} {}-{} {}.",
                 winner_name,
                 loser_name,
@@ -520,13 +742,390 @@ impl<'game> Game {
                 home,
                 away,
                 self.away_team_in_game.name,
-            )
+            );
+            match COMMENTARY_DATA.post_game(result, winner_name, &loser_name, key) {
+                Some(commentary) => format!("{} {}", description, commentary),
+                None => description,
+            }
         } else {
-            format!(
+            let description = format!(
                 "It's a tie! The final score is {} {}-{} {}.",
                 self.home_team_in_game.name, home, away, self.away_team_in_game.name
-            )
+            );
+            match COMMENTARY_DATA.post_game(
+                result,
+                &self.home_team_in_game.name,
+                &self.away_team_in_game.name,
+                key,
+            ) {
+                Some(commentary) => format!("{} {}", description, commentary),
+                None => description,
+            }
+        }
+    }
+
+    // Color commentary on made shots, blocks and steals. Must be called
+    // before the possession is updated with the result.
+    fn play_commentary(&self, result: &ActionOutput) -> Option<String> {
+        fn find_player(
+            stats: &Option<GameStatsMap>,
+            condition: impl Fn(&GameStats) -> bool,
+        ) -> Option<PlayerId> {
+            stats
+                .as_ref()?
+                .iter()
+                .find(|(_, stats)| condition(stats))
+                .map(|(id, _)| *id)
+        }
+
+        let (attacking_team, defending_team) = match self.possession {
+            Possession::Home => (&self.home_team_in_game, &self.away_team_in_game),
+            Possession::Away => (&self.away_team_in_game, &self.home_team_in_game),
+        };
+        let attacking_margin = match self.possession {
+            Possession::Home => result.home_score as i32 - result.away_score as i32,
+            Possession::Away => result.away_score as i32 - result.home_score as i32,
+        };
+
+        let (kind, player_id, team, opponent, margin) =
+            if let Some(id) = find_player(&result.attack_stats_update, |s| s.made_3pt > 0) {
+                (
+                    PlayKind::MadeThree,
+                    id,
+                    attacking_team,
+                    defending_team,
+                    attacking_margin,
+                )
+            } else if let Some(id) = find_player(&result.attack_stats_update, |s| s.made_2pt > 0) {
+                (
+                    PlayKind::MadeTwo,
+                    id,
+                    attacking_team,
+                    defending_team,
+                    attacking_margin,
+                )
+            } else if let Some(id) = find_player(&result.defense_stats_update, |s| s.blocks > 0) {
+                (
+                    PlayKind::Block,
+                    id,
+                    defending_team,
+                    attacking_team,
+                    -attacking_margin,
+                )
+            } else if let Some(id) = find_player(&result.defense_stats_update, |s| s.steals > 0) {
+                (
+                    PlayKind::Steal,
+                    id,
+                    defending_team,
+                    attacking_team,
+                    -attacking_margin,
+                )
+            } else {
+                return None;
+            };
+
+        let player = team.players.get(&player_id)?;
+        COMMENTARY_DATA.play(
+            kind,
+            ScoreSituation::from_margin(margin),
+            player,
+            &team.name,
+            &opponent.name,
+            self.timer.value as usize,
+        )
+    }
+
+    pub fn add_crowd_chatter(&mut self, chatter: CrowdChatter) -> AppResult<()> {
+        if self.crowd_chatter.len() >= MAX_CROWD_CHATTER_PER_GAME {
+            return Err(anyhow!("The crowd has already said enough"));
+        }
+
+        if self.crowd_chatter.iter().any(|c| {
+            c.peer_id == chatter.peer_id
+                && chatter.received_at < c.received_at + CROWD_CHATTER_MIN_INTERVAL
+        }) {
+            return Err(anyhow!("Too much chatter, wait a bit"));
+        }
+
+        self.crowd_chatter.push(chatter);
+        Ok(())
+    }
+
+    pub fn is_network(&self) -> bool {
+        self.home_team_in_game.peer_id.is_some() || self.away_team_in_game.peer_id.is_some()
+    }
+
+    fn team_in_game(&self, team_id: TeamId) -> AppResult<&TeamInGame> {
+        if self.home_team_in_game.team_id == team_id {
+            Ok(&self.home_team_in_game)
+        } else if self.away_team_in_game.team_id == team_id {
+            Ok(&self.away_team_in_game)
+        } else {
+            Err(anyhow!("Team is not playing this game"))
+        }
+    }
+
+    fn team_in_game_mut(&mut self, team_id: TeamId) -> AppResult<&mut TeamInGame> {
+        if self.home_team_in_game.team_id == team_id {
+            Ok(&mut self.home_team_in_game)
+        } else if self.away_team_in_game.team_id == team_id {
+            Ok(&mut self.away_team_in_game)
+        } else {
+            Err(anyhow!("Team is not playing this game"))
+        }
+    }
+
+    pub fn can_queue_command(&self, team_id: TeamId, command: &GameCommand) -> AppResult<()> {
+        if self.has_ended() {
+            return Err(anyhow!("Game has ended"));
+        }
+        // Peers simulate network games independently, so they cannot be altered.
+        if self.is_network() {
+            return Err(anyhow!("Cannot coach during network games"));
+        }
+
+        let team = self.team_in_game(team_id)?;
+        let queued = self
+            .pending_commands
+            .iter()
+            .filter(|(id, _)| *id == team_id)
+            .map(|(_, c)| c)
+            .collect_vec();
+
+        match command {
+            GameCommand::Timeout => {
+                let queued_timeouts = queued
+                    .iter()
+                    .filter(|&&c| *c == GameCommand::Timeout)
+                    .count() as u8;
+                if team.timeouts_used + queued_timeouts >= MAX_TIMEOUTS_PER_GAME {
+                    return Err(anyhow!("No timeouts left"));
+                }
+            }
+            GameCommand::Substitution {
+                player_out,
+                player_in,
+            } => {
+                let out_stats = team
+                    .stats
+                    .get(player_out)
+                    .ok_or(anyhow!("Player is not in the team"))?;
+                if !out_stats.is_playing() {
+                    return Err(anyhow!("Player is not on the court"));
+                }
+                let in_stats = team
+                    .stats
+                    .get(player_in)
+                    .ok_or(anyhow!("Player is not in the team"))?;
+                if in_stats.is_playing() {
+                    return Err(anyhow!("Player is already on the court"));
+                }
+                let player = team
+                    .players
+                    .get(player_in)
+                    .ok_or(anyhow!("Player is not in the team"))?;
+                if player.is_injured() || in_stats.injury.is_some() {
+                    return Err(anyhow!("Player is injured"));
+                }
+                if player.is_knocked_out() {
+                    return Err(anyhow!("Player is knocked out"));
+                }
+                if queued.iter().any(|c| match c {
+                    GameCommand::Substitution {
+                        player_out: o,
+                        player_in: i,
+                    } => o == player_out || i == player_in,
+                    _ => false,
+                }) {
+                    return Err(anyhow!("Substitution already requested"));
+                }
+            }
+            GameCommand::SetTactic { tactic } => {
+                if team.tactic == *tactic {
+                    return Err(anyhow!("Tactic already in use"));
+                }
+            }
+            GameCommand::CaptainSpeech { .. } => {
+                // Queued speeches are applied right before the jump ball.
+                if self.timer.value > self.timer.period_start(Period::Q1) {
+                    return Err(anyhow!("The game has already started"));
+                }
+                if team.speech_given
+                    || queued
+                        .iter()
+                        .any(|c| matches!(c, GameCommand::CaptainSpeech { .. }))
+                {
+                    return Err(anyhow!("The captain already gave a speech"));
+                }
+                if Self::captain(team).is_none() {
+                    return Err(anyhow!("The team has no captain"));
+                }
+            }
         }
+
+        Ok(())
+    }
+
+    pub fn queue_command(&mut self, team_id: TeamId, command: GameCommand) -> AppResult<()> {
+        self.can_queue_command(team_id, &command)?;
+        self.pending_commands.push((team_id, command));
+        Ok(())
+    }
+
+    fn apply_command(&mut self, team_id: TeamId, command: GameCommand) -> AppResult<String> {
+        // Commands are validated again since the game state could have changed since queueing.
+        self.can_queue_command(team_id, &command)?;
+        let rng = &mut ChaCha8Rng::from_seed(self.get_rng_seed());
+        let opponent_name = if self.home_team_in_game.team_id == team_id {
+            self.away_team_in_game.name.clone()
+        } else {
+            self.home_team_in_game.name.clone()
+        };
+        let mut opponent_morale = MoraleModifier::NONE;
+        let mut break_opponent_momentum = false;
+        let team = self.team_in_game_mut(team_id)?;
+
+        let description = match command {
+            GameCommand::Timeout => {
+                team.timeouts_used += 1;
+                break_opponent_momentum = true;
+                for (id, player) in team.players.iter_mut() {
+                    if team
+                        .stats
+                        .get(id)
+                        .map(|s| s.is_playing())
+                        .unwrap_or_default()
+                    {
+                        player.tiredness = (player.tiredness - TIMEOUT_TIREDNESS_RECOVERY).max(0.0);
+                        player.add_morale(MoraleModifier::SMALL_BONUS);
+                    }
+                }
+                format!(
+                    "{} called a timeout. The players catch their breath.",
+                    team.name
+                )
+            }
+            GameCommand::Substitution {
+                player_out,
+                player_in,
+            } => {
+                let position = team
+                    .stats
+                    .get(&player_out)
+                    .and_then(|s| s.position)
+                    .ok_or(anyhow!("Player is not on the court"))?;
+                if let Some(stats) = team.stats.get_mut(&player_out) {
+                    stats.position = None;
+                }
+                if let Some(stats) = team.stats.get_mut(&player_in) {
+                    stats.position = Some(position);
+                }
+                let name = |id: &PlayerId| {
+                    team.players
+                        .get(id)
+                        .map(|p| p.info.shortened_name())
+                        .unwrap_or_default()
+                };
+                format!(
+                    "Coach's call: {} is substituted by {}.",
+                    name(&player_out),
+                    name(&player_in)
+                )
+            }
+            GameCommand::SetTactic { tactic } => {
+                team.tactic = tactic;
+                format!("{} switched to the {} tactic.", team.name, tactic)
+            }
+            GameCommand::CaptainSpeech { tone } => {
+                let captain = Self::captain(team).ok_or(anyhow!("The team has no captain"))?;
+                let captain_name = captain.info.shortened_name();
+                let success = rng.gen_bool(tone.success_probability(captain));
+                let (own_morale, other_morale) = tone.morale_shift(success);
+                opponent_morale = other_morale;
+                team.speech_given = true;
+                for player in team.players.values_mut() {
+                    player.add_morale(own_morale);
+                }
+                match (tone, success) {
+                    (SpeechTone::Fiery, true) => format!(
+                        "{} fires up {} with a thunderous speech!",
+                        captain_name, team.name
+                    ),
+                    (SpeechTone::Fiery, false) => format!(
+                        "{} shouts at the crew until nobody is listening anymore.",
+                        captain_name
+                    ),
+                    (SpeechTone::Calm, true) => format!(
+                        "{} calmly reminds {} of the game plan.",
+                        captain_name, team.name
+                    ),
+                    (SpeechTone::Calm, false) => {
+                        format!("{} mumbles a few words before tip-off.", captain_name)
+                    }
+                    (SpeechTone::Mocking, true) => format!(
+                        "{} mocks {}, who look rattled.",
+                        captain_name, opponent_name
+                    ),
+                    (SpeechTone::Mocking, false) => {
+                        format!("{}'s taunts only fire up {}.", captain_name, opponent_name)
+                    }
+                }
+            }
+        };
+
+        let opponent = if self.home_team_in_game.team_id == team_id {
+            &mut self.away_team_in_game
+        } else {
+            &mut self.home_team_in_game
+        };
+        if opponent_morale != MoraleModifier::NONE {
+            for player in opponent.players.values_mut() {
+                player.add_morale(opponent_morale);
+            }
+        }
+        if break_opponent_momentum {
+            opponent.momentum = 0;
+        }
+
+        Ok(description)
+    }
+
+    fn captain(team: &TeamInGame) -> Option<&Player> {
+        team.players
+            .values()
+            .find(|player| player.info.crew_role == CrewRole::Captain)
+    }
+
+    fn apply_pending_commands(&mut self) {
+        if self.pending_commands.is_empty() {
+            return;
+        }
+
+        let commands = std::mem::take(&mut self.pending_commands);
+        let descriptions = commands
+            .into_iter()
+            .filter_map(|(team_id, command)| self.apply_command(team_id, command).ok())
+            .collect_vec();
+        if descriptions.is_empty() {
+            return;
+        }
+
+        // The game flow continues from the last action, only the description is new.
+        let last_result = &self.action_results[self.action_results.len() - 1];
+        let result = ActionOutput {
+            description: descriptions.join(" "),
+            attack_stats_update: None,
+            defense_stats_update: None,
+            score_change: 0,
+            ..last_result.clone()
+        };
+        self.action_results.push(result);
+    }
+
+    // A single overtime is played if the rules allow it, a tie after it stands.
+    fn should_go_to_overtime(&self) -> bool {
+        let (home, away) = self.get_score();
+        self.rules.overtime && !self.timer.overtime && home == away
     }
 
     pub fn has_started(&self, timestamp: Tick) -> bool {
@@ -544,6 +1143,27 @@ impl<'game> Game {
 
         self.timer.tick();
 
+        if self.timer.has_ended() && self.should_go_to_overtime() {
+            self.timer.overtime = true;
+            self.next_step = self.timer.value;
+            let (home_score, away_score) = self.get_score();
+            self.action_results.push(ActionOutput {
+                situation: ActionSituation::JumpBall,
+                // The jump ball is resolved from the home team point of view.
+                possession: Possession::Home,
+                description: format!(
+                    "It's a tie at the end of regulation! We go to {} minutes of overtime, starting with a jump ball.",
+                    OVERTIME_MINUTES
+                ),
+                start_at: self.timer,
+                end_at: self.timer,
+                home_score,
+                away_score,
+                ..Default::default()
+            });
+            return;
+        }
+
         if self.timer.has_ended() {
             self.ended_at = Some(current_tick);
             self.home_team_mvps = Some(self.team_mvps(Possession::Home));
@@ -580,16 +1200,27 @@ impl<'game> Game {
 
         let seed = self.get_rng_seed();
         let rng = &mut ChaCha8Rng::from_seed(seed);
-        let action_input = &self.action_results[self.action_results.len() - 1];
 
         if !self.timer.reached(self.next_step) {
             return;
         }
 
+        self.apply_pending_commands();
+        let action_input = &self.action_results[self.action_results.len() - 1];
+
         // If next tick is at a break, we are at the end of the quarter and should stop.
         if self.timer.is_break() {
-            if let Some(eoq) = EndOfQuarter::execute(action_input, self, rng) {
-                self.next_step = self.timer.period().next().start();
+            if let Some(mut eoq) = EndOfQuarter::execute(action_input, self, rng) {
+                if let Some(cheer) = self.apply_home_crowd_bonus() {
+                    eoq.description = format!("{} {}", eoq.description, cheer);
+                }
+                self.next_step = self.timer.next_period_start();
+                // Team fouls are counted per quarter.
+                self.home_team_in_game.team_fouls = 0;
+                self.away_team_in_game.team_fouls = 0;
+                // Momentum cools down during the break.
+                self.home_team_in_game.momentum /= 2;
+                self.away_team_in_game.momentum /= 2;
                 self.action_results.push(eoq);
                 return;
             }
@@ -603,6 +1234,17 @@ impl<'game> Game {
                 result.defense_stats_update.clone(),
                 result.score_change,
             );
+            self.update_momentum(result.score_change);
+
+            // Slowing down the game burns extra seconds while keeping the ball.
+            if result.possession == self.possession {
+                let extra_seconds = match self.possession {
+                    Possession::Home => self.home_team_in_game.tactic,
+                    Possession::Away => self.away_team_in_game.tactic,
+                }
+                .extra_possession_seconds();
+                result.end_at = result.end_at.plus(extra_seconds);
+            }
 
             if result.score_change > 0 {
                 let home_plus_minus: i16 = if self.possession == Possession::Home {
@@ -620,6 +1262,10 @@ impl<'game> Game {
                         stats.plus_minus -= home_plus_minus;
                     }
                 }
+
+                if self.is_clutch_time() {
+                    self.record_clutch_shots(&result.attack_stats_update);
+                }
                 result.description = format!(
                     "{} [{}-{}]",
                     result.description.clone(),
@@ -628,6 +1274,14 @@ impl<'game> Game {
                 );
             }
 
+            if let Some(commentary) = self.play_commentary(&result) {
+                result.description = format!("{} {}", result.description, commentary);
+            }
+
+            if let Some(injuries) = self.apply_injuries(&result, rng) {
+                result.description = format!("{} {}", result.description, injuries);
+            }
+
             self.possession = result.possession;
 
             // If this was the first action (JumpBall),
@@ -635,7 +1289,7 @@ impl<'game> Game {
             if self.next_step == 0 {
                 self.won_jump_ball = self.possession;
             }
-            self.next_step = result.end_at.value.min(self.timer.period().next().start());
+            self.next_step = result.end_at.value.min(self.timer.next_period_start());
 
             self.action_results.push(result);
 
@@ -739,11 +1393,22 @@ impl<'game> Game {
 #[cfg(test)]
 mod tests {
     use super::Game;
-    use crate::game_engine::types::TeamInGame;
-    use crate::types::GameId;
+    use crate::game_engine::constants::{
+        CROWD_CHATTER_MAX_LENGTH, CROWD_CHATTER_MIN_INTERVAL, MAX_MOMENTUM, MAX_TIMEOUTS_PER_GAME,
+        MOMENTUM_LOSS_PER_OPPONENT_POINT,
+    };
+    use crate::game_engine::rules::MatchRules;
+    use crate::game_engine::tactic::Tactic;
+    use crate::game_engine::timer::Timer;
+    use crate::game_engine::types::{
+        CrowdChatter, GameCommand, Possession, SpeechTone, TeamInGame,
+    };
+    use crate::types::{AppResult, GameId};
     use crate::types::{SystemTimeTick, Tick};
     use crate::world::constants::DEFAULT_PLANET_ID;
+    use crate::world::position::MAX_POSITION;
     use crate::world::world::World;
+    use libp2p::PeerId;
     use rand::SeedableRng;
     use rand_chacha::ChaCha8Rng;
 
@@ -792,6 +1457,7 @@ mod tests {
             away_team_in_game.unwrap(),
             Tick::now(),
             &world.get_planet(DEFAULT_PLANET_ID.clone()).unwrap(),
+            MatchRules::default(),
         );
 
         game.home_team_in_game
@@ -828,4 +1494,169 @@ mod tests {
             .clone();
         println!("{} {}", quickness_before, quickness_after);
     }
+
+    #[test]
+    fn test_crowd_chatter_rate_limit() {
+        let mut game = Game::default();
+        let peer_id = Some(PeerId::random());
+
+        let mut chatter = CrowdChatter::new(
+            game.id,
+            "Spectators".into(),
+            Timer::default(),
+            "What a shot!",
+        )
+        .unwrap();
+        chatter.peer_id = peer_id;
+        let now = chatter.received_at;
+        assert!(game.add_crowd_chatter(chatter.clone()).is_ok());
+        // Same peer cannot chatter again too soon.
+        assert!(game.add_crowd_chatter(chatter.clone()).is_err());
+
+        // Another peer can chatter at the same time.
+        let mut other = chatter.clone();
+        other.peer_id = Some(PeerId::random());
+        assert!(game.add_crowd_chatter(other).is_ok());
+
+        chatter.received_at = now + CROWD_CHATTER_MIN_INTERVAL;
+        assert!(game.add_crowd_chatter(chatter).is_ok());
+        assert_eq!(game.crowd_chatter.len(), 3);
+
+        assert!(CrowdChatter::new(game.id, "Spectators".into(), Timer::default(), "   ").is_err());
+        let long_text = "a".repeat(2 * CROWD_CHATTER_MAX_LENGTH);
+        let chatter =
+            CrowdChatter::new(game.id, "Spectators".into(), Timer::default(), &long_text).unwrap();
+        assert_eq!(chatter.text.len(), CROWD_CHATTER_MAX_LENGTH);
+    }
+
+    #[test]
+    fn test_momentum() {
+        let mut game = Game::default();
+        game.possession = Possession::Home;
+
+        for _ in 0..10 {
+            game.update_momentum(2);
+        }
+        assert_eq!(game.home_team_in_game.momentum, MAX_MOMENTUM);
+        assert!(game.momentum_bonus() > 0);
+
+        // The run is answered by the opponent.
+        game.possession = Possession::Away;
+        game.update_momentum(2);
+        assert_eq!(game.away_team_in_game.momentum, 2);
+        assert_eq!(
+            game.home_team_in_game.momentum,
+            MAX_MOMENTUM - 2 * MOMENTUM_LOSS_PER_OPPONENT_POINT
+        );
+        assert!(game.momentum_bonus() < 0);
+
+        // Slowing down the game breaks the opponent momentum.
+        game.away_team_in_game.tactic = Tactic::Bonaccia;
+        let momentum = game.home_team_in_game.momentum;
+        game.update_momentum(0);
+        assert!(game.home_team_in_game.momentum < momentum);
+    }
+
+    #[test]
+    fn test_game_commands() -> AppResult<()> {
+        let mut world = World::new(None);
+        let rng = &mut ChaCha8Rng::seed_from_u64(world.seed);
+        let id0 = world.generate_random_team(
+            rng,
+            DEFAULT_PLANET_ID.clone(),
+            "Testen".to_string(),
+            "Tosten".to_string(),
+        )?;
+        let id1 = world.generate_random_team(
+            rng,
+            DEFAULT_PLANET_ID.clone(),
+            "Holalo".to_string(),
+            "Halley".to_string(),
+        )?;
+
+        let mut game = Game::new(
+            GameId::new_v4(),
+            TeamInGame::from_team_id(id0, &world.teams, &world.players).unwrap(),
+            TeamInGame::from_team_id(id1, &world.teams, &world.players).unwrap(),
+            Tick::now(),
+            &world.get_planet(DEFAULT_PLANET_ID.clone()).unwrap(),
+            MatchRules::default(),
+        );
+
+        for _ in 0..MAX_TIMEOUTS_PER_GAME {
+            game.queue_command(id0, GameCommand::Timeout)?;
+        }
+        assert!(game.queue_command(id0, GameCommand::Timeout).is_err());
+        // Timeouts are counted per team.
+        game.queue_command(id1, GameCommand::Timeout)?;
+
+        let tactic = game.home_team_in_game.tactic.next();
+        game.queue_command(id0, GameCommand::SetTactic { tactic })?;
+
+        let player_out = game.home_team_in_game.initial_positions[0];
+        let player_in = game.home_team_in_game.initial_positions[MAX_POSITION as usize];
+        let on_court = game.home_team_in_game.initial_positions[1];
+        assert!(game
+            .queue_command(
+                id0,
+                GameCommand::Substitution {
+                    player_out,
+                    player_in: on_court,
+                },
+            )
+            .is_err());
+        game.queue_command(
+            id0,
+            GameCommand::Substitution {
+                player_out,
+                player_in,
+            },
+        )?;
+
+        game.queue_command(
+            id0,
+            GameCommand::CaptainSpeech {
+                tone: SpeechTone::Calm,
+            },
+        )?;
+        assert!(game
+            .queue_command(
+                id0,
+                GameCommand::CaptainSpeech {
+                    tone: SpeechTone::Fiery,
+                },
+            )
+            .is_err());
+
+        let results = game.action_results.len();
+        game.apply_pending_commands();
+        assert!(game.pending_commands.is_empty());
+        assert!(game.home_team_in_game.speech_given);
+        let speech = GameCommand::CaptainSpeech {
+            tone: SpeechTone::Mocking,
+        };
+        // The speech can be given only once and only before tip-off.
+        assert!(game.can_queue_command(id0, &speech).is_err());
+        assert!(game.can_queue_command(id1, &speech).is_ok());
+        game.timer.tick_by(10);
+        assert!(game.can_queue_command(id1, &speech).is_err());
+        assert_eq!(game.action_results.len(), results + 1);
+        assert_eq!(game.home_team_in_game.timeouts_used, MAX_TIMEOUTS_PER_GAME);
+        assert_eq!(game.away_team_in_game.timeouts_used, 1);
+        assert_eq!(game.home_team_in_game.tactic, tactic);
+        assert_eq!(game.home_team_in_game.stats[&player_in].position, Some(0));
+        assert!(!game.home_team_in_game.stats[&player_out].is_playing());
+
+        // Network games are simulated by peers and cannot be coached.
+        game.away_team_in_game.peer_id = Some(PeerId::random());
+        assert!(game
+            .queue_command(
+                id0,
+                GameCommand::SetTactic {
+                    tactic: tactic.next()
+                }
+            )
+            .is_err());
+        Ok(())
+    }
 }
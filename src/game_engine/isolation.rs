@@ -62,14 +62,15 @@ impl EngineAction for Isolation {
         let mut defender_update = GameStats::default();
         defender_update.extra_tiredness = TirednessCost::MEDIUM;
 
-        let atk_result =
-            iso.roll(rng) + iso.technical.ball_handling.value() + iso.athletics.quickness.value();
+        let atk_result = iso.roll(rng)
+            + iso.technical.ball_handling.value()
+            + game.venue.athletics(iso.athletics.quickness.value());
 
         let def_result = defender.roll(rng)
             + defender.defense.perimeter_defense.value()
-            + defender.athletics.quickness.value();
+            + game.venue.athletics(defender.athletics.quickness.value());
 
-        let mut result = match atk_result as i16 - def_result as i16 {
+        let mut result = match atk_result as i16 - def_result as i16 + game.momentum_bonus() {
             x if x > ADV_ATTACK_LIMIT => ActionOutput {
                 possession: input.possession,
                 advantage: Advantage::Attack,
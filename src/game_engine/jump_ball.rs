@@ -16,7 +16,8 @@ impl EngineAction for JumpBall {
         let defending_players = game.defending_players();
 
         let jump_ball = |player: &Player| {
-            player.athletics.vertical.value() + ((player.info.height as u8).max(150) - 150) / 4
+            game.venue.athletics(player.athletics.vertical.value())
+                + ((player.info.height as u8).max(150) - 150) / 4
         };
         let home_jumper = attacking_players.iter().max_by_key(|&p| jump_ball(p));
         let away_jumper = defending_players.iter().max_by_key(|&p| jump_ball(p));
@@ -1,7 +1,9 @@
 pub mod action;
 mod brawl;
+pub mod commentary;
 pub mod constants;
 mod end_of_quarter;
+mod foul;
 pub mod game;
 mod isolation;
 mod jump_ball;
@@ -9,9 +11,12 @@ mod off_the_screen;
 mod pick_and_roll;
 mod post;
 mod rebound;
+pub mod rules;
 mod shot;
+mod shot_clock;
 mod start_of_quarter;
 mod substitution;
 pub mod tactic;
 pub mod timer;
 pub mod types;
+pub mod venue;
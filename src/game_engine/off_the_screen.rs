@@ -57,9 +57,11 @@ impl EngineAction for OffTheScreen {
 
         let def_result = playmaker_defender.roll(rng)
             + target_defender.defense.perimeter_defense.value()
-            + target_defender.athletics.quickness.value();
+            + game
+                .venue
+                .athletics(target_defender.athletics.quickness.value());
 
-        let mut result = match atk_result as i16 - def_result as i16 {
+        let mut result = match atk_result as i16 - def_result as i16 + game.momentum_bonus() {
             x if x > ADV_ATTACK_LIMIT => ActionOutput {
                 possession: input.possession,
                 advantage: Advantage::Attack,
@@ -53,14 +53,14 @@ impl EngineAction for PickAndRoll {
         if play_idx == target_idx {
             let atk_result = playmaker.roll(rng)
                 + playmaker.technical.ball_handling.value()
-                + playmaker.athletics.quickness.value()
+                + game.venue.athletics(playmaker.athletics.quickness.value())
                 + target.mental.vision.value();
 
             let def_result = playmaker_defender.roll(rng)
                 + playmaker_defender.defense.perimeter_defense.value()
                 + playmaker_defender.mental.vision.value();
 
-            result = match atk_result as i16 - def_result as i16 {
+            result = match atk_result as i16 - def_result as i16 + game.momentum_bonus() {
                 x if x > ADV_ATTACK_LIMIT => ActionOutput {
                     possession: input.possession,
                     advantage: Advantage::Attack,
@@ -138,9 +138,11 @@ impl EngineAction for PickAndRoll {
 
             let def_result = playmaker_defender.roll(rng)
                 + playmaker_defender.defense.perimeter_defense.value()
-                + target_defender.athletics.quickness.value();
+                + game
+                    .venue
+                    .athletics(target_defender.athletics.quickness.value());
 
-            result = match atk_result as i16 - def_result as i16 {
+            result = match atk_result as i16 - def_result as i16 + game.momentum_bonus() {
             x if x > ADV_ATTACK_LIMIT => ActionOutput {
                 possession: input.possession,
                 advantage: Advantage::Attack,
@@ -42,13 +42,13 @@ impl EngineAction for Post {
 
         let atk_result = poster.roll(rng)
             + poster.technical.post_moves.value()
-            + poster.athletics.strength.value();
+            + game.venue.athletics(poster.athletics.strength.value());
 
         let def_result = defender.roll(rng)
             + defender.defense.interior_defense.value()
-            + defender.athletics.strength.value();
+            + game.venue.athletics(defender.athletics.strength.value());
 
-        let mut result = match atk_result as i16 - def_result as i16 {
+        let mut result = match atk_result as i16 - def_result as i16 + game.momentum_bonus() {
             x if x > ADV_ATTACK_LIMIT => ActionOutput {
                 possession: input.possession,
                 advantage: Advantage::Attack,
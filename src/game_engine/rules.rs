@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use strum_macros::{Display, EnumIter};
+
+pub const OVERTIME_MINUTES: u16 = 5;
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    Serialize_repr,
+    Deserialize_repr,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumIter,
+    Display,
+)]
+#[repr(u8)]
+pub enum QuarterLength {
+    #[strum(to_string = "5 min")]
+    Short,
+    #[default]
+    #[strum(to_string = "10 min")]
+    Standard,
+    #[strum(to_string = "12 min")]
+    Long,
+}
+
+impl QuarterLength {
+    pub fn minutes(&self) -> u16 {
+        match self {
+            Self::Short => 5,
+            Self::Standard => 10,
+            Self::Long => 12,
+        }
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    Serialize_repr,
+    Deserialize_repr,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumIter,
+    Display,
+)]
+#[repr(u8)]
+pub enum ShotClock {
+    #[default]
+    Off,
+    #[strum(to_string = "24 sec")]
+    Standard,
+    #[strum(to_string = "14 sec")]
+    Short,
+}
+
+impl ShotClock {
+    pub fn seconds(&self) -> Option<u16> {
+        match self {
+            Self::Off => None,
+            Self::Standard => Some(24),
+            Self::Short => Some(14),
+        }
+    }
+}
+
+/// Rules of the games played in the world. The defaults are the historical ones:
+/// 10 minutes quarters, ties allowed and no shot clock.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub struct MatchRules {
+    pub quarter_length: QuarterLength,
+    pub overtime: bool,
+    pub shot_clock: ShotClock,
+}
+
+impl MatchRules {
+    pub fn description(&self) -> String {
+        let mut description = format!("{} quarters", self.quarter_length);
+        if self.overtime {
+            description.push_str(&format!(", {} min overtime", OVERTIME_MINUTES));
+        }
+        if let Some(seconds) = self.shot_clock.seconds() {
+            description.push_str(&format!(", {} sec shot clock", seconds));
+        }
+        description
+    }
+}
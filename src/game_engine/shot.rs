@@ -1,6 +1,7 @@
 use super::{
     action::{ActionOutput, ActionSituation, Advantage, EngineAction},
     constants::ShotDifficulty,
+    foul,
     game::Game,
     types::GameStats,
 };
@@ -8,6 +9,7 @@ use crate::{
     game_engine::{constants::*, types::*},
     world::{
         constants::{MoraleModifier, TirednessCost},
+        epithet::epithet_commentary,
         player::Player,
         skill::GameSkill,
     },
@@ -52,7 +54,7 @@ fn description(
     advantage: Advantage,
     success: bool,
 ) -> Option<String> {
-    let text = match (shot, advantage, success) {
+    let mut text = match (shot, advantage, success) {
         (ShotDifficulty::Close, Advantage::Attack, true) => vec![
             format!("{} scores an easy layup.", shooter.info.shortened_name()),
             format!(
@@ -175,6 +177,12 @@ fn description(
         )],
     };
 
+    if success {
+        if let Some(line) = epithet_commentary(shooter) {
+            text.push(line);
+        }
+    }
+
     let mut description = text.choose(rng)?.to_string();
     if let Some(passer) = assist {
         description.push_str(format!(" Assist from {}.", passer.info.shortened_name()).as_str());
@@ -221,15 +229,16 @@ fn execute_shot(
         })
         .sum::<u8>();
 
-    let roll = match input.advantage {
-        Advantage::Attack => (shooter.roll(rng) + atk_skill) as i16 - (shot as u8) as i16,
-        Advantage::Neutral => {
-            (shooter.roll(rng) + atk_skill) as i16 - (shot as u8 + def_skill / 2) as i16
-        }
-        Advantage::Defense => {
-            (shooter.roll(rng) + atk_skill) as i16 - (shot as u8 + def_skill) as i16
-        }
-    };
+    let roll = game.momentum_bonus()
+        + match input.advantage {
+            Advantage::Attack => (shooter.roll(rng) + atk_skill) as i16 - (shot as u8) as i16,
+            Advantage::Neutral => {
+                (shooter.roll(rng) + atk_skill) as i16 - (shot as u8 + def_skill / 2) as i16
+            }
+            Advantage::Defense => {
+                (shooter.roll(rng) + atk_skill) as i16 - (shot as u8 + def_skill) as i16
+            }
+        };
 
     let success = roll > 0;
     let mut result = match success {
@@ -402,6 +411,83 @@ fn execute_shot(
         }
         defense_stats_update.insert(defender.id, defender_update);
     }
+
+    // Contact on the shot sends the shooter to the line, unless the shot was cleanly blocked.
+    let contact = match shot {
+        ShotDifficulty::Close => 1.5,
+        ShotDifficulty::Medium => 1.0,
+        ShotDifficulty::Long => 0.5,
+    };
+    let foul_probability =
+        SHOOTING_FOUL_PROBABILITY * contact * foul::aggression_modifier(&defenders);
+    if defenders.len() > 0
+        && roll > ADV_DEFENSE_LIMIT
+        && rng.gen_bool(foul_probability.min(1.0) as f64)
+    {
+        let fouler = defenders[0];
+        let attempts = match (success, shot) {
+            (true, _) => 1,
+            (false, ShotDifficulty::Long) => 3,
+            (false, _) => 2,
+        };
+        let made = foul::free_throws(shooter, attempts, rng);
+
+        let shooter_update = attack_stats_update.get_mut(&shooter.id)?;
+        shooter_update.attempted_ft = attempts;
+        shooter_update.made_ft = made;
+        shooter_update.points += made;
+        if !success {
+            // A missed shot on a foul does not count as a field goal attempt.
+            shooter_update.attempted_2pt = 0;
+            shooter_update.attempted_3pt = 0;
+            shooter_update.last_action_shot = None;
+        }
+
+        let fouler_update = defense_stats_update.entry(fouler.id).or_default();
+        let foul_out = foul::commit_foul(fouler, game.defending_stats(), fouler_update);
+
+        let mut description = if success {
+            format!(
+                "{} And one! {} fouled {} on the shot.",
+                result.description,
+                fouler.info.shortened_name(),
+                shooter.info.shortened_name()
+            )
+        } else {
+            format!(
+                "{} {} fouled {} on the shot.",
+                result.description,
+                fouler.info.shortened_name(),
+                shooter.info.shortened_name()
+            )
+        };
+        description = format!(
+            "{} {}",
+            description,
+            foul::free_throws_description(shooter, attempts, made)
+        );
+        if let Some(foul_out) = foul_out {
+            description = format!("{} {}", description, foul_out);
+        }
+
+        result.score_change += made;
+        match input.possession {
+            Possession::Home => result.home_score += made as u16,
+            Possession::Away => result.away_score += made as u16,
+        }
+        if !success {
+            result.possession = !input.possession;
+            result.situation = ActionSituation::BallInBackcourt;
+            result.advantage = Advantage::default();
+            result.attackers = vec![];
+            result.defenders = vec![];
+            result.end_at = input.end_at.plus(4 + rng.gen_range(0..=2));
+        }
+        result.description = description;
+        result.foul_from = Some(input.defenders[0]);
+        result.foul_on = Some(shooter_idx);
+    }
+
     result.attack_stats_update = Some(attack_stats_update);
     result.defense_stats_update = Some(defense_stats_update);
     return Some(result);
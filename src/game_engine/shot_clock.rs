@@ -0,0 +1,32 @@
+use super::{
+    action::{ActionOutput, ActionSituation, EngineAction},
+    game::Game,
+    types::Possession,
+};
+use rand_chacha::ChaCha8Rng;
+
+#[derive(Debug, Default)]
+pub struct ShotClockViolation;
+
+impl EngineAction for ShotClockViolation {
+    fn execute(input: &ActionOutput, game: &Game, _rng: &mut ChaCha8Rng) -> Option<ActionOutput> {
+        let name = match input.possession {
+            Possession::Home => &game.home_team_in_game.name,
+            Possession::Away => &game.away_team_in_game.name,
+        };
+
+        Some(ActionOutput {
+            situation: ActionSituation::Turnover,
+            possession: !input.possession,
+            description: format!(
+                "The shot clock runs out! {} couldn't get a shot off in time.",
+                name
+            ),
+            start_at: input.end_at,
+            end_at: input.end_at.plus(1),
+            home_score: input.home_score,
+            away_score: input.away_score,
+            ..Default::default()
+        })
+    }
+}
@@ -25,7 +25,11 @@ fn get_subs<'a>(players: Vec<&'a Player>, team_stats: &GameStatsMap) -> Vec<&'a
         .skip(5)
         .filter(|&p| {
             let stats = team_stats.get(&p.id).unwrap();
-            !stats.is_playing() && !p.is_knocked_out()
+            !stats.is_playing()
+                && !p.is_knocked_out()
+                && !p.is_injured()
+                && stats.injury.is_none()
+                && !stats.is_fouled_out()
         })
         .map(|&p| p)
         .collect();
@@ -39,9 +43,12 @@ fn get_subs<'a>(players: Vec<&'a Player>, team_stats: &GameStatsMap) -> Vec<&'a
         .take(5)
         .filter(|&p| {
             let stats = team_stats.get(&p.id).unwrap();
-            return stats.is_playing() == true && p.tiredness > MIN_TIREDNESS_FOR_SUB;
+            return stats.is_playing() == true
+                && (p.tiredness > MIN_TIREDNESS_FOR_SUB
+                    || stats.injury.is_some()
+                    || stats.is_fouled_out());
         })
-        //Sort from less to most skilled*tired
+        //Sort injured and fouled out players first, then from less to most skilled*tired
         .sorted_by(|&a, &b| {
             let a_stats = team_stats
                 .get(&a.id)
@@ -58,7 +65,9 @@ fn get_subs<'a>(players: Vec<&'a Player>, team_stats: &GameStatsMap) -> Vec<&'a
                 .expect("Playing player should have a position");
             let v2 = b.tiredness_weighted_rating_at_position(b_position) as u16;
 
-            v1.cmp(&v2)
+            let a_can_play = a_stats.injury.is_none() && !a_stats.is_fouled_out();
+            let b_can_play = b_stats.injury.is_none() && !b_stats.is_fouled_out();
+            (a_can_play, v1).cmp(&(b_can_play, v2))
         })
         .map(|&p| p)
         .collect();
@@ -100,6 +109,8 @@ fn make_substitution(
     let player_out = subs[1];
     let tiredness = player_out.tiredness;
     let position = stats.get(&player_out.id)?.position?;
+    let injury = stats.get(&player_out.id)?.injury;
+    let fouled_out = stats.get(&player_out.id)?.is_fouled_out();
 
     let mut description = format!(
         "{} is substituted by {}. ",
@@ -107,7 +118,24 @@ fn make_substitution(
         player_in.info.shortened_name()
     );
 
-    if tiredness > MIN_TIREDNESS_FOR_SUB {
+    if let Some(injury) = injury {
+        description.push_str(
+            format!(
+                "{} cannot go on with that {}. ",
+                player_out.info.pronouns.as_subject(),
+                injury.to_string().to_lowercase()
+            )
+            .as_str(),
+        );
+    } else if fouled_out {
+        description.push_str(
+            format!(
+                "{} fouled out and must leave the court. ",
+                player_out.info.pronouns.as_subject()
+            )
+            .as_str(),
+        );
+    } else if tiredness > MIN_TIREDNESS_FOR_SUB {
         description.push_str(
             format!(
                 "{} looked very tired. ",
@@ -16,6 +16,7 @@ pub enum Tactic {
     Balanced,
     BigPirates,
     Arrembaggio,
+    Bonaccia,
 }
 
 impl Display for Tactic {
@@ -24,6 +25,7 @@ impl Display for Tactic {
             Tactic::Balanced => write!(f, "Balanced"),
             Tactic::BigPirates => write!(f, "Big Pirates"),
             Tactic::Arrembaggio => write!(f, "Arrembaggio"),
+            Tactic::Bonaccia => write!(f, "Bonaccia"),
         }
     }
 }
@@ -38,7 +40,8 @@ impl Tactic {
         match self {
             Self::Balanced => Self::BigPirates,
             Self::BigPirates => Self::Arrembaggio,
-            Self::Arrembaggio => Self::Balanced,
+            Self::Arrembaggio => Self::Bonaccia,
+            Self::Bonaccia => Self::Balanced,
         }
     }
 
@@ -49,6 +52,9 @@ impl Tactic {
             Self::Arrembaggio => {
                 "Aggressive tactic focusing on sharing the ball, very high chance of brawl."
             }
+            Self::Bonaccia => {
+                "Slow down the game to break the opponent momentum, lower chance of brawls."
+            }
         }
     }
 
@@ -57,6 +63,7 @@ impl Tactic {
             Self::Balanced => [2, 2, 2, 2],
             Self::BigPirates => [1, 1, 1, 3],
             Self::Arrembaggio => [2, 4, 4, 1],
+            Self::Bonaccia => [3, 1, 1, 3],
         };
         let action = match WeightedIndex::new(&weights)?.sample(rng) {
             0 => Action::Isolation,
@@ -73,6 +80,7 @@ impl Tactic {
             Self::Balanced => 1.0,
             Self::BigPirates => 1.25,
             Self::Arrembaggio => 2.0,
+            Self::Bonaccia => 0.5,
         }
     }
 
@@ -81,6 +89,23 @@ impl Tactic {
             Self::Balanced => 1.0,
             Self::BigPirates => 0.25,
             Self::Arrembaggio => 2.0,
+            Self::Bonaccia => 1.0,
+        }
+    }
+
+    // Extra seconds spent on each action while attacking.
+    pub fn extra_possession_seconds(&self) -> u16 {
+        match self {
+            Self::Bonaccia => 3,
+            _ => 0,
+        }
+    }
+
+    // Momentum taken from the opponent on each action while attacking.
+    pub fn momentum_break(&self) -> u8 {
+        match self {
+            Self::Bonaccia => 1,
+            _ => 0,
         }
     }
 }
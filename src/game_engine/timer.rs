@@ -1,14 +1,13 @@
+use super::rules::{QuarterLength, OVERTIME_MINUTES};
+use crate::{types::Tick, world::constants::TickInterval, world::utils::is_default};
 use serde::{Deserialize, Serialize};
 use strum::Display;
 
-use crate::{types::Tick, world::constants::TickInterval};
-
 const MINUTES_PER_QUARTER: u16 = 10;
 const MINUTES_PER_BREAK: u16 = 2;
 // const HALFTIME_BREAK_DURATION: u16 = 10;
 // const QUARTERS: u16 = 4;
 const SECONDS_PER_MINUTE: u16 = 60;
-const MAX_TIME: u16 = SECONDS_PER_MINUTE * (MINUTES_PER_QUARTER * 4 + MINUTES_PER_BREAK * 3);
 
 #[derive(Debug, Display, Default, PartialEq, Clone, Copy, Serialize, Deserialize)]
 pub enum Period {
@@ -22,6 +21,7 @@ pub enum Period {
     B3,
     Q4,
     B4,
+    OT,
 }
 
 impl Period {
@@ -36,6 +36,7 @@ impl Period {
             Self::B3 => Self::Q4,
             Self::Q4 => Self::B4,
             Self::B4 => Self::B4,
+            Self::OT => Self::B4,
         }
     }
 
@@ -50,49 +51,56 @@ impl Period {
             Self::B3 => Self::Q3,
             Self::Q4 => Self::B3,
             Self::B4 => Self::Q4,
+            Self::OT => Self::Q4,
         }
     }
+
+    // Start and end of the period with the default rules.
     pub fn start(&self) -> u16 {
-        match self {
-            Self::NotStarted => 0,
-            Self::Q1 => 1,
-            Self::B1 | Self::B2 | Self::B3 | Self::B4 => {
-                &self.previous().start() + SECONDS_PER_MINUTE * MINUTES_PER_QUARTER
-            }
-            Self::Q2 | Self::Q3 | Self::Q4 => {
-                &self.previous().start() + SECONDS_PER_MINUTE * MINUTES_PER_BREAK
-            }
-        }
+        Timer::default().period_start(*self)
     }
 
     pub fn end(&self) -> u16 {
-        match self {
-            Self::NotStarted => 0,
-            Self::Q1 | Self::Q2 | Self::Q3 | Self::Q4 => {
-                &self.previous().end() + SECONDS_PER_MINUTE * MINUTES_PER_QUARTER
-            }
-            Self::B1 | Self::B2 | Self::B3 => {
-                &self.previous().end() + SECONDS_PER_MINUTE * MINUTES_PER_BREAK
-            }
-            Self::B4 => MAX_TIME,
-        }
+        Timer::default().period_end(*self)
     }
 }
 
+fn default_quarter_minutes() -> u16 {
+    MINUTES_PER_QUARTER
+}
+
+fn is_default_quarter_minutes(minutes: &u16) -> bool {
+    *minutes == MINUTES_PER_QUARTER
+}
+
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Serialize, Deserialize)]
 pub struct Timer {
     pub value: u16,
+    #[serde(skip_serializing_if = "is_default_quarter_minutes")]
+    #[serde(default = "default_quarter_minutes")]
+    pub quarter_minutes: u16,
+    // Set when the game goes to overtime, adding the OT period after Q4.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub overtime: bool,
 }
 
 impl Default for Timer {
     fn default() -> Self {
-        Self { value: 0 }
+        Self {
+            value: 0,
+            quarter_minutes: MINUTES_PER_QUARTER,
+            overtime: false,
+        }
     }
 }
 
 impl From<u16> for Timer {
     fn from(value: u16) -> Self {
-        Self { value }
+        Self {
+            value,
+            ..Default::default()
+        }
     }
 }
 
@@ -101,35 +109,92 @@ impl Timer {
         Self::default()
     }
 
+    pub fn with_quarter_length(quarter_length: QuarterLength) -> Self {
+        Self {
+            quarter_minutes: quarter_length.minutes(),
+            ..Default::default()
+        }
+    }
+
+    // Same clock, at a different value.
+    pub fn at(&self, value: u16) -> Self {
+        Self { value, ..*self }
+    }
+
     pub fn as_tick(&self) -> Tick {
         // Returns how many ticks have elapsed since timer started.
         // Value ticks once per TickInterval::Short.
         self.value as Tick * TickInterval::SHORT
     }
 
+    fn period_seconds(&self, period: Period) -> u16 {
+        match period {
+            Period::NotStarted | Period::B4 => 0,
+            Period::Q1 | Period::Q2 | Period::Q3 | Period::Q4 => {
+                SECONDS_PER_MINUTE * self.quarter_minutes
+            }
+            Period::B1 | Period::B2 | Period::B3 => SECONDS_PER_MINUTE * MINUTES_PER_BREAK,
+            Period::OT => SECONDS_PER_MINUTE * OVERTIME_MINUTES,
+        }
+    }
+
+    // The overtime is only played if the game went to overtime.
+    pub fn next_period(&self, period: Period) -> Period {
+        match period {
+            Period::Q4 if self.overtime => Period::OT,
+            _ => period.next(),
+        }
+    }
+
+    fn previous_period(&self, period: Period) -> Period {
+        match period {
+            Period::B4 if self.overtime => Period::OT,
+            _ => period.previous(),
+        }
+    }
+
+    pub fn period_start(&self, period: Period) -> u16 {
+        match period {
+            Period::NotStarted => 0,
+            Period::Q1 => 1,
+            _ => {
+                let previous = self.previous_period(period);
+                self.period_start(previous) + self.period_seconds(previous)
+            }
+        }
+    }
+
+    pub fn next_period_start(&self) -> u16 {
+        self.period_start(self.next_period(self.period()))
+    }
+
+    pub fn period_end(&self, period: Period) -> u16 {
+        match period {
+            Period::NotStarted => 0,
+            // The game ends when the last period does.
+            Period::B4 => self.period_end(self.previous_period(period)),
+            _ => self.period_start(period) + self.period_seconds(period) - 1,
+        }
+    }
+
     pub fn period(&self) -> Period {
-        match self.value {
-            x if x < Period::Q1.start() => Period::Q1.previous(),
-            x if x < Period::B1.start() => Period::B1.previous(),
-            x if x < Period::Q2.start() => Period::Q2.previous(),
-            x if x < Period::B2.start() => Period::B2.previous(),
-            x if x < Period::Q3.start() => Period::Q3.previous(),
-            x if x < Period::B3.start() => Period::B3.previous(),
-            x if x < Period::Q4.start() => Period::Q4.previous(),
-            x if x < Period::B4.start() => Period::B4.previous(),
-            _ => Period::B4,
+        let mut period = Period::NotStarted;
+        while period != Period::B4 {
+            let next = self.next_period(period);
+            if self.value < self.period_start(next) {
+                return period;
+            }
+            period = next;
         }
+        Period::B4
     }
 
     pub fn minutes(&self) -> u16 {
-        (self.period().end() - self.value) / SECONDS_PER_MINUTE
+        self.period_end(self.period()).saturating_sub(self.value) / SECONDS_PER_MINUTE
     }
 
     pub fn seconds(&self) -> u16 {
-        if self.value > MAX_TIME {
-            return 0;
-        }
-        (MAX_TIME - self.value) % SECONDS_PER_MINUTE
+        self.period_end(self.period()).saturating_sub(self.value) % SECONDS_PER_MINUTE
     }
 
     pub fn is_break(&self) -> bool {
@@ -142,6 +207,7 @@ impl Timer {
     pub fn plus(&self, seconds: u16) -> Self {
         Self {
             value: self.value + seconds,
+            ..*self
         }
     }
 
@@ -151,15 +217,20 @@ impl Timer {
 
     pub fn format(&self) -> String {
         if self.has_ended() {
-            return "Q4 00:00".to_string();
+            return format!("{:2} 00:00", self.previous_period(Period::B4));
         }
 
         // if !self.has_started() {
         //     return "Q1 10:00".to_string();
         // }
 
-        if self.is_break() && self.value == self.period().end() {
-            format!("{:2} 10:00", self.period().next(),)
+        if self.is_break() && self.value == self.period_end(self.period()) {
+            let next = self.next_period(self.period());
+            format!(
+                "{:2} {:02}:00",
+                next,
+                self.period_seconds(next) / SECONDS_PER_MINUTE
+            )
         } else {
             format!(
                 "{:2} {:02}:{:02}",
@@ -222,7 +293,10 @@ impl Timer {
 
 #[cfg(test)]
 mod tests {
-    use crate::game_engine::timer::{self, Period, Timer, MINUTES_PER_BREAK, MINUTES_PER_QUARTER};
+    use crate::game_engine::rules::{QuarterLength, OVERTIME_MINUTES};
+    use crate::game_engine::timer::{
+        self, Period, Timer, MINUTES_PER_BREAK, MINUTES_PER_QUARTER, SECONDS_PER_MINUTE,
+    };
     use std::io::{stdout, Write};
 
     #[test]
@@ -431,4 +505,27 @@ mod tests {
         assert_eq!(Timer::from(Period::Q4.end()).format(), "Q4 00:00");
         assert_eq!(Timer::from(Period::B4.start()).format(), "Q4 00:00");
     }
+
+    #[test]
+    fn test_quarter_length_and_overtime() {
+        let mut timer = Timer::with_quarter_length(QuarterLength::Short);
+        assert_eq!(timer.format(), "Q1 05:00");
+        timer.tick();
+        assert_eq!(timer.format(), "Q1 04:59");
+
+        let end = timer.period_end(Period::Q4);
+        assert_eq!(end, SECONDS_PER_MINUTE * (4 * 5 + 3 * MINUTES_PER_BREAK));
+        timer.tick_by(end - timer.value);
+        assert_eq!(timer.format(), "Q4 00:00");
+        timer.tick();
+        assert!(timer.has_ended());
+
+        timer.overtime = true;
+        assert_eq!(timer.period(), Period::OT);
+        assert!(!timer.has_ended());
+        assert_eq!(timer.format(), "OT 04:59");
+        timer.tick_by(SECONDS_PER_MINUTE * OVERTIME_MINUTES);
+        assert!(timer.has_ended());
+        assert_eq!(timer.format(), "OT 00:00");
+    }
 }
@@ -1,21 +1,31 @@
-use super::{action::Action, tactic::Tactic};
+use super::{
+    action::Action,
+    constants::{CROWD_CHATTER_MAX_LENGTH, MAX_PERSONAL_FOULS},
+    tactic::Tactic,
+    timer::Timer,
+};
 use crate::{
     image::game::PitchStyle,
-    types::{AppResult, GameId, PlayerId, PlayerMap, TeamId, TeamMap},
+    types::{AppResult, GameId, PlayerId, PlayerMap, SystemTimeTick, TeamId, TeamMap, Tick},
     world::{
+        constants::{MoraleModifier, EXPERIENCE_PER_GAME_ACTION},
+        injury::InjuryKind,
         player::{InfoStats, Player},
         position::{Position, MAX_POSITION},
-        skill::{Athletics, Defense, Mental, Offense, Technical},
+        skill::{Athletics, Defense, Mental, Offense, Technical, MAX_SKILL},
         team::Team,
-        types::TrainingFocus,
+        types::{TeamBonus, TrainingFocus},
         utils::is_default,
     },
 };
+use anyhow::anyhow;
+use itertools::Itertools;
 use libp2p::PeerId;
 use once_cell::sync::Lazy;
 use rand_chacha::ChaCha8Rng;
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, ops::Not};
+use strum_macros::{Display, EnumIter};
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
 pub struct GameStats {
@@ -90,6 +100,14 @@ pub struct GameStats {
     #[serde(skip_serializing_if = "is_default")]
     #[serde(default)]
     pub experience_at_position: [u16; 5],
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    // Injury suffered during the game, applied to the player at the end of the game.
+    pub injury: Option<InjuryKind>,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    // Shots made at the end of a close game.
+    pub clutch_shots: u8,
 }
 
 impl GameStats {
@@ -122,11 +140,161 @@ impl GameStats {
             self.experience_at_position[idx] += exp;
             assert!(*exp == 0);
         }
+        if stats.injury.is_some() {
+            self.injury = stats.injury;
+        }
     }
 
     pub fn is_playing(&self) -> bool {
         self.position.is_some()
     }
+
+    pub fn is_fouled_out(&self) -> bool {
+        self.fouls >= MAX_PERSONAL_FOULS
+    }
+
+    pub fn made_fg(&self) -> u8 {
+        self.made_2pt + self.made_3pt
+    }
+
+    pub fn attempted_fg(&self) -> u8 {
+        self.attempted_2pt + self.attempted_3pt
+    }
+
+    pub fn rebounds(&self) -> u8 {
+        self.offensive_rebounds + self.defensive_rebounds
+    }
+
+    // Experience gained by each skill through the actions of the game:
+    // shooters improve their shooting, defenders their defense and so on.
+    pub fn action_experience(&self) -> [f32; 20] {
+        // Pairs of (number of actions, skill indexes trained by the action).
+        let actions: [(u8, &[usize]); 10] = [
+            // Two pointers are taken both close to the basket and from the mid range.
+            (self.attempted_2pt, &[5, 6]),
+            (self.attempted_3pt, &[7]),
+            (self.attempted_ft, &[6]),
+            (self.assists, &[12, 16]),
+            (self.turnovers, &[13]),
+            (self.offensive_rebounds, &[1, 15]),
+            (self.defensive_rebounds, &[11, 15]),
+            (self.steals, &[8, 10, 18]),
+            (self.blocks, &[1, 9, 11]),
+            (self.fouls, &[17]),
+        ];
+
+        let mut experience = [0.0; 20];
+        for (count, skills) in actions {
+            for &idx in skills {
+                experience[idx] += count as f32 * EXPERIENCE_PER_GAME_ACTION;
+            }
+        }
+        experience
+    }
+}
+
+// Short reaction sent by a peer spectating a network game.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CrowdChatter {
+    pub game_id: GameId,
+    pub author: String,
+    pub timer: Timer,
+    pub text: String,
+    // These are set by the receiver.
+    #[serde(skip)]
+    pub peer_id: Option<PeerId>,
+    #[serde(skip)]
+    pub received_at: Tick,
+}
+
+impl CrowdChatter {
+    pub fn new(game_id: GameId, author: String, timer: Timer, text: &str) -> AppResult<Self> {
+        let text = text
+            .trim()
+            .chars()
+            .take(CROWD_CHATTER_MAX_LENGTH)
+            .collect::<String>();
+        if text.is_empty() {
+            return Err(anyhow!("Crowd chatter cannot be empty"));
+        }
+
+        Ok(Self {
+            game_id,
+            author,
+            timer,
+            text,
+            peer_id: None,
+            received_at: Tick::now(),
+        })
+    }
+}
+
+// Commands issued by a team during a game. They are queued and applied
+// at the next action boundary.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum GameCommand {
+    Timeout,
+    Substitution {
+        player_out: PlayerId,
+        player_in: PlayerId,
+    },
+    SetTactic {
+        tactic: Tactic,
+    },
+    CaptainSpeech {
+        tone: SpeechTone,
+    },
+}
+
+// Tone of the captain speech before tip-off. Bolder tones have a larger
+// effect but are more likely to backfire.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Display, EnumIter)]
+pub enum SpeechTone {
+    Fiery,
+    Calm,
+    Mocking,
+}
+
+impl SpeechTone {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Fiery => "Fire up the crew. Can be too much for them.",
+            Self::Calm => "Remind the crew of the game plan. Safe but mild.",
+            Self::Mocking => "Taunt the opponents. Might fire them up instead.",
+        }
+    }
+
+    pub fn success_probability(&self, captain: &Player) -> f64 {
+        let (base, skill) = match self {
+            Self::Fiery => (
+                0.3,
+                0.7 * captain.mental.charisma + 0.3 * captain.mental.aggression,
+            ),
+            Self::Calm => (
+                0.6,
+                0.5 * captain.mental.charisma + 0.5 * captain.mental.intuition,
+            ),
+            Self::Mocking => (
+                0.2,
+                0.5 * captain.mental.charisma
+                    + 0.3 * captain.mental.aggression
+                    + 0.2 * captain.mental.vision,
+            ),
+        };
+        (base + 0.6 * skill / MAX_SKILL).min(0.95) as f64
+    }
+
+    // Morale added to the own team and to the opponent.
+    pub fn morale_shift(&self, success: bool) -> (f32, f32) {
+        match (self, success) {
+            (Self::Fiery, true) => (MoraleModifier::MEDIUM_BONUS, MoraleModifier::NONE),
+            (Self::Fiery, false) => (MoraleModifier::SMALL_MALUS, MoraleModifier::NONE),
+            (Self::Calm, true) => (MoraleModifier::SMALL_BONUS, MoraleModifier::NONE),
+            (Self::Calm, false) => (MoraleModifier::NONE, MoraleModifier::NONE),
+            (Self::Mocking, true) => (MoraleModifier::SMALL_BONUS, MoraleModifier::MEDIUM_MALUS),
+            (Self::Mocking, false) => (MoraleModifier::NONE, MoraleModifier::SMALL_BONUS),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -147,13 +315,43 @@ pub struct TeamInGame {
     pub tactic: Tactic,
     pub training_focus: Option<TrainingFocus>,
     pub momentum: u8,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub timeouts_used: u8,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub speech_given: bool,
+    // Extra stamina granted by the cook, between 0 and 1. Tiredness gained
+    // in the game is divided by 1 + cook_bonus.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub cook_bonus: f32,
+    // Fouls committed in the current quarter.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub team_fouls: u8,
 }
 
 impl<'game> TeamInGame {
-    pub fn new(team: &Team, players: PlayerMap) -> Self {
+    pub fn new(team: &Team, mut players: PlayerMap) -> Self {
         let mut stats = HashMap::new();
 
-        for (idx, player_id) in team.player_ids.iter().enumerate() {
+        // Kartoffel companions boost the skills of their players for the game.
+        for player in players.values_mut() {
+            if let Some(bonus) = player.companion_bonus {
+                bonus.apply(player);
+            }
+        }
+
+        // Injured players cannot start the game, so they are moved to the bench.
+        let player_ids = team
+            .player_ids
+            .iter()
+            .sorted_by_key(|&&id| players.get(&id).map(|p| p.is_injured()).unwrap_or_default())
+            .copied()
+            .collect::<Vec<PlayerId>>();
+
+        for (idx, player_id) in player_ids.iter().enumerate() {
             let mut player_stats = GameStats::default();
             if (idx as Position) < MAX_POSITION {
                 player_stats.position = Some(idx as Position);
@@ -161,22 +359,27 @@ impl<'game> TeamInGame {
             stats.insert(player_id.clone(), player_stats.clone());
         }
 
-        let initial_tiredness = team
-            .player_ids
+        let initial_tiredness = player_ids
             .iter()
             .map(|id| players.get(id).unwrap().tiredness)
             .collect();
-        let initial_morale = team
-            .player_ids
+        let initial_morale = player_ids
             .iter()
             .map(|id| players.get(id).unwrap().morale)
             .collect();
+        let cook_bonus = team
+            .crew_roles
+            .cook
+            .and_then(|id| players.get(&id))
+            .and_then(|cook| TeamBonus::GameStamina.current_player_bonus(cook).ok())
+            .map(|bonus| bonus - 1.0)
+            .unwrap_or_default();
         Self {
             team_id: team.id,
             peer_id: team.peer_id,
             reputation: team.reputation,
             name: team.name.clone(),
-            initial_positions: team.player_ids.clone(),
+            initial_positions: player_ids,
             initial_tiredness,
             initial_morale,
             version: team.version,
@@ -184,6 +387,7 @@ impl<'game> TeamInGame {
             stats,
             tactic: team.game_tactic,
             training_focus: team.training_focus,
+            cook_bonus,
             ..Default::default()
         }
     }
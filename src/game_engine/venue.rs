@@ -0,0 +1,114 @@
+use super::constants::{HOME_CROWD_FULL_ATTENDANCE, HOME_CROWD_MORALE_BONUS};
+use crate::world::planet::{Planet, PlanetType};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+// Gravity is expressed relative to the Earth one, which is 10.
+const LOW_GRAVITY: usize = 5;
+const HIGH_GRAVITY: usize = 12;
+const CRUSHING_GRAVITY: usize = 30;
+
+fn gravity_effect(gravity: usize) -> (f32, Option<&'static str>) {
+    match gravity {
+        g if g < LOW_GRAVITY => (0.15, Some("The low gravity makes for spectacular jumps.")),
+        g if g <= HIGH_GRAVITY => (0.0, None),
+        g if g <= CRUSHING_GRAVITY => (
+            -0.1,
+            Some("The strong gravity weighs on the players' legs."),
+        ),
+        _ => (
+            -0.2,
+            Some("The crushing gravity makes every step a struggle."),
+        ),
+    }
+}
+
+fn planet_type_effect(planet_type: &PlanetType) -> (f32, Option<&'static str>) {
+    match planet_type {
+        PlanetType::Ice => (-0.05, Some("The court is slippery with frost.")),
+        PlanetType::Lava => (-0.05, Some("The heat of the lava flows is exhausting.")),
+        _ => (0.0, None),
+    }
+}
+
+/// Playing conditions on the planet hosting a game.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Venue {
+    // Multiplier to the athletics skills used in the game rolls.
+    pub athletics_modifier: f32,
+    // Morale given by the crowd to the home team players at every break.
+    pub home_crowd_bonus: f32,
+}
+
+impl Default for Venue {
+    fn default() -> Self {
+        Self {
+            athletics_modifier: 1.0,
+            home_crowd_bonus: 0.0,
+        }
+    }
+}
+
+impl Venue {
+    pub fn new(planet: &Planet, attendance: u32) -> Self {
+        let (gravity_modifier, _) = gravity_effect(planet.gravity);
+        let (planet_type_modifier, _) = planet_type_effect(&planet.planet_type);
+        let crowd = (attendance as f32 / HOME_CROWD_FULL_ATTENDANCE as f32).min(1.0);
+
+        Self {
+            athletics_modifier: 1.0 + gravity_modifier + planet_type_modifier,
+            home_crowd_bonus: HOME_CROWD_MORALE_BONUS * crowd,
+        }
+    }
+
+    // The conditions the players will face, for the pre-game summary.
+    pub fn conditions(planet: &Planet) -> String {
+        [
+            gravity_effect(planet.gravity).1,
+            planet_type_effect(&planet.planet_type).1,
+        ]
+        .iter()
+        .flatten()
+        .join(" ")
+    }
+
+    pub fn athletics(&self, value: u8) -> u8 {
+        (value as f32 * self.athletics_modifier).round() as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Venue;
+    use crate::game_engine::constants::{HOME_CROWD_FULL_ATTENDANCE, HOME_CROWD_MORALE_BONUS};
+    use crate::world::planet::{Planet, PlanetType};
+
+    #[test]
+    fn test_venue_modifiers() {
+        let earth = Planet {
+            gravity: 10,
+            planet_type: PlanetType::Earth,
+            ..Default::default()
+        };
+        let venue = Venue::new(&earth, HOME_CROWD_FULL_ATTENDANCE * 2);
+        assert_eq!(venue.athletics(10), 10);
+        assert_eq!(venue.home_crowd_bonus, HOME_CROWD_MORALE_BONUS);
+        assert!(Venue::conditions(&earth).is_empty());
+
+        let icy_moon = Planet {
+            gravity: 2,
+            planet_type: PlanetType::Ice,
+            ..Default::default()
+        };
+        let venue = Venue::new(&icy_moon, HOME_CROWD_FULL_ATTENDANCE / 2);
+        assert_eq!(venue.athletics(10), 11);
+        assert_eq!(venue.home_crowd_bonus, HOME_CROWD_MORALE_BONUS / 2.0);
+        assert!(Venue::conditions(&icy_moon).contains("slippery"));
+
+        let giant = Planet {
+            gravity: 100,
+            ..Default::default()
+        };
+        assert!(Venue::new(&giant, 0).athletics(20) < 20);
+    }
+}
@@ -167,6 +167,21 @@ impl ColorPreset {
         }
     }
 
+    // Random team colors do not necessarily match a preset.
+    pub fn closest(rgb: Rgb<u8>) -> Self {
+        Self::iter()
+            .min_by_key(|preset| {
+                preset
+                    .to_rgb()
+                    .0
+                    .iter()
+                    .zip(rgb.0.iter())
+                    .map(|(a, b)| (*a as i32 - *b as i32).pow(2))
+                    .sum::<i32>()
+            })
+            .unwrap_or_default()
+    }
+
     pub fn to_rgb(&self) -> Rgb<u8> {
         match self {
             ColorPreset::Red => Rgb([200, 50, 50]),
@@ -0,0 +1,123 @@
+use crate::world::emblem::{Emblem, EmblemPattern, EmblemSymbol};
+use image::{Rgb, Rgba, RgbaImage};
+
+pub const EMBLEM_SIZE: u32 = 12;
+pub const FLAG_WIDTH: u32 = EMBLEM_SIZE + 2;
+pub const FLAG_HEIGHT: u32 = 2 * EMBLEM_SIZE;
+const SYMBOL_SIZE: u32 = 8;
+const SYMBOL_OFFSET: u32 = (EMBLEM_SIZE - SYMBOL_SIZE) / 2;
+const POLE_COLOR: Rgba<u8> = Rgba([120, 90, 60, 255]);
+
+fn symbol_rows(symbol: EmblemSymbol) -> [&'static str; SYMBOL_SIZE as usize] {
+    match symbol {
+        EmblemSymbol::Skull => [
+            "..####..", ".######.", "##.##.##", "##.##.##", ".######.", "..####..", "..#..#..",
+            "........",
+        ],
+        EmblemSymbol::Crossbones => [
+            "##....##", ".##..##.", "..####..", "...##...", "..####..", ".##..##.", "##....##",
+            "........",
+        ],
+        EmblemSymbol::Star => [
+            "...##...", "...##...", "########", ".######.", "..####..", ".##..##.", "##....##",
+            "........",
+        ],
+        EmblemSymbol::Anchor => [
+            "...##...", "..#..#..", "...##...", ".######.", "...##...", "#..##..#", "##.##.##",
+            ".######.",
+        ],
+        EmblemSymbol::Moon => [
+            "..####..", ".##.....", "##......", "##......", "##......", "##......", ".##.....",
+            "..####..",
+        ],
+    }
+}
+
+fn is_pattern_pixel(pattern: EmblemPattern, x: u32, y: u32) -> bool {
+    match pattern {
+        EmblemPattern::Plain => false,
+        EmblemPattern::Stripes => y % 4 < 2,
+        EmblemPattern::Checkered => (x / 3 + y / 3) % 2 == 0,
+        EmblemPattern::Halved => x >= EMBLEM_SIZE / 2,
+        EmblemPattern::Border => x == 0 || y == 0 || x == EMBLEM_SIZE - 1 || y == EMBLEM_SIZE - 1,
+    }
+}
+
+fn opaque(rgb: Rgb<u8>) -> Rgba<u8> {
+    let [r, g, b] = rgb.0;
+    Rgba([r, g, b, 255])
+}
+
+fn is_symbol_pixel(symbol: EmblemSymbol, x: u32, y: u32) -> bool {
+    if x < SYMBOL_OFFSET || y < SYMBOL_OFFSET {
+        return false;
+    }
+    let (sx, sy) = (x - SYMBOL_OFFSET, y - SYMBOL_OFFSET);
+    if sx >= SYMBOL_SIZE || sy >= SYMBOL_SIZE {
+        return false;
+    }
+    symbol_rows(symbol)[sy as usize].as_bytes()[sx as usize] == b'#'
+}
+
+pub fn emblem_image(emblem: &Emblem) -> RgbaImage {
+    RgbaImage::from_fn(EMBLEM_SIZE, EMBLEM_SIZE, |x, y| {
+        if is_symbol_pixel(emblem.symbol, x, y) {
+            opaque(emblem.color.blue)
+        } else if is_pattern_pixel(emblem.pattern, x, y) {
+            opaque(emblem.color.green)
+        } else {
+            opaque(emblem.color.red)
+        }
+    })
+}
+
+// The emblem hoisted on a pole, as shown next to the crew.
+pub fn flag_image(emblem: &Emblem) -> RgbaImage {
+    let emblem = emblem_image(emblem);
+    RgbaImage::from_fn(FLAG_WIDTH, FLAG_HEIGHT, |x, y| {
+        if x < FLAG_WIDTH - EMBLEM_SIZE {
+            POLE_COLOR
+        } else if y < EMBLEM_SIZE {
+            *emblem.get_pixel(x - (FLAG_WIDTH - EMBLEM_SIZE), y)
+        } else {
+            Rgba([0, 0, 0, 0])
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{emblem_image, flag_image, symbol_rows, EMBLEM_SIZE, FLAG_HEIGHT, FLAG_WIDTH};
+    use crate::world::emblem::{Emblem, EmblemPattern, EmblemSymbol};
+    use image::Rgba;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_emblem_image() {
+        for symbol in EmblemSymbol::iter() {
+            assert!(symbol_rows(symbol).iter().all(|row| row.len() == 8));
+        }
+
+        let mut emblem = Emblem {
+            symbol: EmblemSymbol::Moon,
+            pattern: EmblemPattern::Border,
+            ..Default::default()
+        };
+        let img = emblem_image(&emblem);
+        assert_eq!(img.dimensions(), (EMBLEM_SIZE, EMBLEM_SIZE));
+        assert_eq!(*img.get_pixel(0, 0), Rgba([0, 255, 0, 255]));
+        assert_eq!(*img.get_pixel(2, 4), Rgba([0, 0, 255, 255]));
+        assert_eq!(*img.get_pixel(8, 8), Rgba([255, 0, 0, 255]));
+
+        emblem.pattern = EmblemPattern::Plain;
+        assert_eq!(
+            *emblem_image(&emblem).get_pixel(0, 0),
+            Rgba([255, 0, 0, 255])
+        );
+
+        let flag = flag_image(&emblem);
+        assert_eq!(flag.dimensions(), (FLAG_WIDTH, FLAG_HEIGHT));
+        assert_eq!(flag.get_pixel(FLAG_WIDTH - 1, FLAG_HEIGHT - 1)[3], 0);
+        assert_eq!(flag.get_pixel(0, FLAG_HEIGHT - 1)[3], 255);
+    }
+}
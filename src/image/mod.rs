@@ -1,5 +1,6 @@
 pub mod color_map;
 pub mod components;
+pub mod emblem;
 pub mod game;
 pub mod player;
 pub mod spaceship;
@@ -260,7 +260,7 @@ impl PlayerImage {
                     self.set_hat(Some(HatImage::Mask));
                 }
             },
-            CrewRole::Mozzo => self.set_hat(None),
+            CrewRole::Mozzo | CrewRole::Engineer | CrewRole::Cook => self.set_hat(None),
         }
 
         self.jersey_color_map = Some(jersey.color);
@@ -40,6 +40,10 @@ impl SpaceshipImage {
         hasher.finalize().to_vec()
     }
 
+    pub fn color_map(&self) -> ColorMap {
+        self.color_map
+    }
+
     pub fn set_color_map(&mut self, color_map: ColorMap) {
         self.color_map = color_map;
     }
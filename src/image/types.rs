@@ -1,4 +1,9 @@
-use crate::{store::ASSETS_DIR, types::AppResult, ui::utils::img_to_lines};
+use super::utils::placeholder_image;
+use crate::{
+    assets::{asset_contents, report_asset_failure},
+    types::AppResult,
+    ui::utils::img_to_lines,
+};
 use anyhow::anyhow;
 use image::{ImageBuffer, RgbaImage};
 use ratatui::text::Line;
@@ -9,30 +14,43 @@ pub type GifLines = Vec<ImageLines>;
 
 pub trait PrintableGif: Sized {
     fn open(filename: String) -> AppResult<Self>;
+    fn open_or_placeholder(filename: String) -> Self;
     fn to_lines(&self) -> GifLines;
 }
 
+fn decode_gif(filename: String) -> AppResult<Gif> {
+    let mut decoder = gif::DecodeOptions::new();
+    // Configure the decoder such that it will expand the image to RGBA.
+    decoder.set_color_output(gif::ColorOutput::RGBA);
+    let file = asset_contents(&filename).ok_or(anyhow!("Unable to open file {}", filename))?;
+    let mut decoder = decoder.read_info(file)?;
+    let mut gif: Gif = vec![];
+    while let Some(frame) = decoder.read_next_frame()? {
+        let img = ImageBuffer::from_raw(
+            frame.width as u32,
+            frame.height as u32,
+            frame.buffer.to_vec(),
+        )
+        .ok_or(anyhow!("Unable to decode file {} into gif", filename))?;
+        gif.push(img);
+    }
+    if gif.is_empty() {
+        return Err(anyhow!("File {} has no frames", filename));
+    }
+    Ok(gif)
+}
+
 impl PrintableGif for Gif {
     fn open(filename: String) -> AppResult<Gif> {
-        let mut decoder = gif::DecodeOptions::new();
-        // Configure the decoder such that it will expand the image to RGBA.
-        decoder.set_color_output(gif::ColorOutput::RGBA);
-        let file = ASSETS_DIR
-            .get_file(filename.clone())
-            .ok_or(anyhow!("Unable to open file {}", filename))?
-            .contents();
-        let mut decoder = decoder.read_info(file)?;
-        let mut gif: Gif = vec![];
-        while let Some(frame) = decoder.read_next_frame().unwrap() {
-            let img = ImageBuffer::from_raw(
-                frame.width as u32,
-                frame.height as u32,
-                frame.buffer.to_vec(),
-            )
-            .ok_or(anyhow!("Unable to decode file {} into gif", filename))?;
-            gif.push(img);
-        }
-        Ok(gif)
+        decode_gif(filename.clone()).map_err(|e| {
+            report_asset_failure(&filename, &e);
+            e
+        })
+    }
+
+    // A single transparent frame, so that animations can still cycle through it.
+    fn open_or_placeholder(filename: String) -> Gif {
+        Self::open(filename).unwrap_or_else(|_| vec![placeholder_image(1, 1)])
     }
 
     fn to_lines(&self) -> GifLines {
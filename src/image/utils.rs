@@ -1,5 +1,5 @@
 use super::color_map::ColorMap;
-use crate::store::ASSETS_DIR;
+use crate::assets::{asset_contents, report_asset_failure};
 use crate::types::AppResult;
 use anyhow::anyhow;
 use image::error::{ParameterError, ParameterErrorKind};
@@ -9,10 +9,9 @@ use once_cell::sync::Lazy;
 use std::io::Cursor;
 
 pub static UNIVERSE_BACKGROUND: Lazy<RgbaImage> =
-    Lazy::new(|| open_image("planets/background.png").expect("Cannot open background.png."));
-pub static TRAVELLING_BACKGROUND: Lazy<RgbaImage> = Lazy::new(|| {
-    open_image("planets/travelling_background.png").expect("Cannot open travelling_background.png.")
-});
+    Lazy::new(|| open_image_or_placeholder("planets/background.png", 400, 400));
+pub static TRAVELLING_BACKGROUND: Lazy<RgbaImage> =
+    Lazy::new(|| open_image_or_placeholder("planets/travelling_background.png", 160, 56));
 
 pub trait ExtraImageUtils {
     fn copy_non_trasparent_from(&mut self, other: &RgbaImage, x: u32, y: u32) -> ImageResult<()>;
@@ -144,14 +143,30 @@ impl ExtraImageUtils for RgbaImage {
     }
 }
 
-pub fn open_image(path: &str) -> AppResult<RgbaImage> {
-    let file = ASSETS_DIR.get_file(path);
+fn decode_image(path: &str) -> AppResult<RgbaImage> {
+    let file = asset_contents(path);
     if file.is_none() {
         return Err(anyhow!("File {} not found", path));
     }
-    let img = ImageReader::new(Cursor::new(file.unwrap().contents()))
+    let img = ImageReader::new(Cursor::new(file.unwrap()))
         .with_guessed_format()?
         .decode()?
         .into_rgba8();
     Ok(img)
 }
+
+pub fn open_image(path: &str) -> AppResult<RgbaImage> {
+    decode_image(path).map_err(|e| {
+        report_asset_failure(path, &e);
+        e
+    })
+}
+
+/// Fully transparent image, used in place of assets that cannot be loaded.
+pub fn placeholder_image(width: u32, height: u32) -> RgbaImage {
+    RgbaImage::new(width, height)
+}
+
+pub fn open_image_or_placeholder(path: &str, width: u32, height: u32) -> RgbaImage {
+    open_image(path).unwrap_or_else(|_| placeholder_image(width, height))
+}
@@ -1,13 +1,20 @@
 pub mod app;
+pub mod assets;
 pub mod audio;
 pub mod crossterm_event_handler;
+pub mod export;
 pub mod game_engine;
 pub mod image;
 pub mod network;
 pub mod relayer;
+pub mod settings;
 pub mod space_adventure;
 pub mod ssh;
+pub mod status_server;
 pub mod store;
+pub mod stream_overlay;
+#[cfg(test)]
+pub mod test_harness;
 pub mod tui;
 pub mod types;
 pub mod ui;
@@ -4,13 +4,19 @@ use log4rs::append::file::FileAppender;
 use log4rs::config::{Appender, Config, Root};
 use log4rs::encode::pattern::PatternEncoder;
 use rebels::app::App;
+use rebels::assets::load_assets_overlay;
 use rebels::crossterm_event_handler::CrosstermEventHandler;
 use rebels::network::constants::DEFAULT_PORT;
 use rebels::relayer::Relayer;
+use rebels::settings::SettingsOverrides;
 use rebels::ssh::AppServer;
-use rebels::store::store_path;
+use rebels::store::{
+    load_settings, store_path, ASSETS_OVERLAY_DIRNAME, STREAM_OVERLAY_TEXT_FILENAME,
+};
 use rebels::tui::Tui;
-use rebels::types::AppResult;
+use rebels::types::{AppResult, Tick};
+use rebels::world::constants::MILLISECONDS;
+use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
 #[clap(name="Rebels in the sky", about = "P(lanet)2P(lanet) basketball", author, version, long_about = None)]
@@ -35,6 +41,20 @@ struct Args {
     network_port: Option<u16>,
     #[clap(long,  action=ArgAction::Set, help = "Set target FPS")]
     target_fps: Option<u8>,
+    #[clap(long,  action=ArgAction::Set, help = "Set minimum milliseconds between world updates")]
+    world_tick_ms: Option<u64>,
+    #[clap(long,  action=ArgAction::Set, help = "Set minimum milliseconds between network syncs")]
+    network_sync_ms: Option<u64>,
+    #[clap(long, action=ArgAction::SetTrue, help = "Use low power tick rates")]
+    low_power: bool,
+    #[clap(long, action=ArgAction::Set, help = "Enable or disable writing the stream overlay files")]
+    stream_overlay: Option<bool>,
+    #[clap(long, action=ArgAction::Set, help = "Serve the JSON status endpoint on this local port for this session")]
+    status_port: Option<u16>,
+    #[clap(long, action=ArgAction::Set, help = "Set directory for exported team data")]
+    export_dir: Option<PathBuf>,
+    #[clap(long, action=ArgAction::Set, help = "Set assets overlay directory (defaults to the assets folder in the config directory)")]
+    assets_dir: Option<PathBuf>,
 }
 
 #[tokio::main(flavor = "multi_thread")]
@@ -61,6 +81,26 @@ async fn main() -> AppResult<()> {
         }
     }
 
+    // Overlay assets must be in place before any asset is loaded.
+    let assets_dir = match args.assets_dir {
+        Some(dir) => dir,
+        None => store_path(ASSETS_OVERLAY_DIRNAME)?,
+    };
+    if assets_dir.is_dir() {
+        match load_assets_overlay(&assets_dir) {
+            Ok(count) => log::info!(
+                "Loaded {} overlay assets from {}",
+                count,
+                assets_dir.display()
+            ),
+            Err(e) => log::error!(
+                "Could not load overlay assets from {}: {}",
+                assets_dir.display(),
+                e
+            ),
+        }
+    }
+
     if args.ssh_server {
         // tokio::runtime::Builder::new_multi_thread()
         //     .enable_all()
@@ -76,10 +116,27 @@ async fn main() -> AppResult<()> {
             Some(args.network_port.unwrap_or(DEFAULT_PORT))
         };
 
-        let events = CrosstermEventHandler::new(args.target_fps);
+        // Settings given on the command line only apply to this session.
+        let mut settings = load_settings().unwrap_or_default();
+        settings.overrides = SettingsOverrides {
+            low_power: args.low_power,
+            ui_fps: args.target_fps,
+            world_tick_interval: args.world_tick_ms.map(|ms| ms as Tick * MILLISECONDS),
+            network_sync_interval: args.network_sync_ms.map(|ms| ms as Tick * MILLISECONDS),
+            stream_overlay: args.stream_overlay,
+            export_dir: args.export_dir,
+        };
+        if settings.stream_overlay() {
+            log::info!(
+                "Writing stream overlay to {}",
+                store_path(STREAM_OVERLAY_TEXT_FILENAME)?.display()
+            );
+        }
+
+        let events = CrosstermEventHandler::new(Some(settings.tick().ui_fps));
         let tui = Tui::new_local(events)?;
 
-        App::new(
+        let mut app = App::new(
             args.seed,
             args.disable_network,
             args.disable_audio,
@@ -88,9 +145,11 @@ async fn main() -> AppResult<()> {
             args.seed_ip,
             network_port,
             None,
-        )
-        .run(tui)
-        .await?;
+        );
+        // The status endpoint is only served when asked for on the command line.
+        app.settings.status_port = args.status_port;
+        app.settings.overrides = settings.overrides;
+        app.run(tui).await?;
     }
 
     Ok(())
@@ -0,0 +1,259 @@
+use super::constants::DEFAULT_PORT;
+use crate::types::{AppResult, Tick};
+use crate::world::skill::Skill;
+use anyhow::anyhow;
+use libp2p::{multiaddr::Protocol, Multiaddr, PeerId};
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, SocketAddr};
+
+pub const MAX_ADDRESS_BOOK_ENTRIES: usize = 64;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct AddressBookEntry {
+    // Only known for peers that have been dialed directly.
+    #[serde(default)]
+    pub address: Option<Multiaddr>,
+    #[serde(default)]
+    pub peer_id: Option<PeerId>,
+    #[serde(default)]
+    pub last_connected: Option<Tick>,
+    // Team name and rating, as last seen on the network.
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub rating: Option<Skill>,
+    #[serde(default)]
+    pub last_seen: Option<Tick>,
+    #[serde(default)]
+    pub favorite: bool,
+}
+
+/// Known peers, most recently seen first, and blocked peers.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct AddressBook {
+    pub entries: Vec<AddressBookEntry>,
+    #[serde(default)]
+    pub blocked: Vec<PeerId>,
+}
+
+impl AddressBook {
+    // Moves the entry of the peer to the front, creating it if needed.
+    fn entry_to_front(&mut self, peer_id: PeerId) -> &mut AddressBookEntry {
+        let entry = match self
+            .entries
+            .iter()
+            .position(|entry| entry.peer_id == Some(peer_id))
+        {
+            Some(index) => self.entries.remove(index),
+            None => AddressBookEntry {
+                peer_id: Some(peer_id),
+                ..Default::default()
+            },
+        };
+        self.entries.insert(0, entry);
+        self.prune();
+        &mut self.entries[0]
+    }
+
+    // Favorites and the front entry are never dropped to make room for new peers.
+    fn prune(&mut self) {
+        while self.entries.len() > MAX_ADDRESS_BOOK_ENTRIES {
+            match self
+                .entries
+                .iter()
+                .skip(1)
+                .rposition(|entry| !entry.favorite)
+            {
+                Some(index) => {
+                    self.entries.remove(index + 1);
+                }
+                None => break,
+            }
+        }
+    }
+
+    pub fn record_connection(&mut self, address: Multiaddr, peer_id: PeerId, tick: Tick) {
+        // The address might have been used by another peer before.
+        self.entries.retain(|entry| {
+            entry.address.as_ref() != Some(&address) || entry.peer_id == Some(peer_id)
+        });
+        let entry = self.entry_to_front(peer_id);
+        entry.address = Some(address);
+        entry.last_connected = Some(tick);
+        entry.last_seen = Some(tick);
+    }
+
+    // Returns true if the peer was not known yet.
+    pub fn record_seen(
+        &mut self,
+        peer_id: PeerId,
+        name: String,
+        rating: Skill,
+        tick: Tick,
+    ) -> bool {
+        let is_new = self.get(&peer_id).is_none();
+        let entry = self.entry_to_front(peer_id);
+        entry.name = Some(name);
+        entry.rating = Some(rating);
+        entry.last_seen = Some(tick);
+        is_new
+    }
+
+    pub fn get(&self, peer_id: &PeerId) -> Option<&AddressBookEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.peer_id.as_ref() == Some(peer_id))
+    }
+
+    pub fn address_of(&self, peer_id: &PeerId) -> Option<&Multiaddr> {
+        self.get(peer_id).and_then(|entry| entry.address.as_ref())
+    }
+
+    pub fn is_favorite(&self, peer_id: &PeerId) -> bool {
+        self.get(peer_id)
+            .map(|entry| entry.favorite)
+            .unwrap_or_default()
+    }
+
+    pub fn set_favorite(&mut self, peer_id: PeerId, favorite: bool) {
+        if favorite {
+            self.blocked.retain(|id| *id != peer_id);
+        }
+        self.entry_to_front(peer_id).favorite = favorite;
+    }
+
+    pub fn is_blocked(&self, peer_id: &PeerId) -> bool {
+        self.blocked.contains(peer_id)
+    }
+
+    pub fn set_blocked(&mut self, peer_id: PeerId, blocked: bool) {
+        self.blocked.retain(|id| *id != peer_id);
+        if blocked {
+            if let Some(entry) = self
+                .entries
+                .iter_mut()
+                .find(|entry| entry.peer_id == Some(peer_id))
+            {
+                entry.favorite = false;
+            }
+            self.blocked.push(peer_id);
+        }
+    }
+
+    // Favorites first, then by most recently seen.
+    pub fn sorted_entries(&self) -> Vec<&AddressBookEntry> {
+        let mut entries = self.entries.iter().collect::<Vec<_>>();
+        entries.sort_by_key(|entry| !entry.favorite);
+        entries
+    }
+}
+
+// Accepts a multiaddr, an ip with an optional port, or the peer ID of a peer
+// already in the address book.
+pub fn parse_dial_target(target: &str, address_book: &AddressBook) -> AppResult<Multiaddr> {
+    let target = target.trim();
+    if target.starts_with('/') {
+        return target
+            .parse::<Multiaddr>()
+            .map_err(|e| anyhow!("Invalid address {}: {}", target, e));
+    }
+
+    let socket_address = match target.parse::<SocketAddr>() {
+        Ok(socket_address) => Some(socket_address),
+        Err(_) => target
+            .parse::<IpAddr>()
+            .ok()
+            .map(|ip| SocketAddr::new(ip, DEFAULT_PORT)),
+    };
+    if let Some(socket_address) = socket_address {
+        let ip = match socket_address.ip() {
+            IpAddr::V4(ip) => Protocol::Ip4(ip),
+            IpAddr::V6(ip) => Protocol::Ip6(ip),
+        };
+        return Ok(Multiaddr::empty()
+            .with(ip)
+            .with(Protocol::Tcp(socket_address.port())));
+    }
+
+    let peer_id = target
+        .parse::<PeerId>()
+        .map_err(|_| anyhow!("{} is not an address nor a peer ID", target))?;
+    address_book
+        .address_of(&peer_id)
+        .cloned()
+        .ok_or(anyhow!("No known address for peer {}", peer_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_dial_target, AddressBook, MAX_ADDRESS_BOOK_ENTRIES};
+    use crate::network::constants::DEFAULT_PORT;
+    use crate::types::AppResult;
+    use libp2p::{Multiaddr, PeerId};
+
+    #[test]
+    fn test_parse_dial_target() -> AppResult<()> {
+        let mut address_book = AddressBook::default();
+        assert_eq!(
+            parse_dial_target("1.2.3.4", &address_book)?,
+            format!("/ip4/1.2.3.4/tcp/{}", DEFAULT_PORT).parse::<Multiaddr>()?
+        );
+        assert_eq!(
+            parse_dial_target("1.2.3.4:4000", &address_book)?,
+            "/ip4/1.2.3.4/tcp/4000".parse::<Multiaddr>()?
+        );
+        assert_eq!(
+            parse_dial_target("/ip6/::1/tcp/4000", &address_book)?,
+            "/ip6/::1/tcp/4000".parse::<Multiaddr>()?
+        );
+        assert!(parse_dial_target("not an address", &address_book).is_err());
+
+        let peer_id = PeerId::random();
+        assert!(parse_dial_target(&peer_id.to_base58(), &address_book).is_err());
+        let address: Multiaddr = "/ip4/5.6.7.8/tcp/37202".parse()?;
+        address_book.record_connection(address.clone(), peer_id, 10);
+        assert_eq!(
+            parse_dial_target(&peer_id.to_base58(), &address_book)?,
+            address
+        );
+
+        for port in 0..=MAX_ADDRESS_BOOK_ENTRIES {
+            let address = format!("/ip4/5.6.7.8/tcp/{}", 4000 + port).parse()?;
+            address_book.record_connection(address, PeerId::random(), 20);
+        }
+        assert_eq!(address_book.entries.len(), MAX_ADDRESS_BOOK_ENTRIES);
+        assert!(address_book.address_of(&peer_id).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_favorites_and_blocklist() {
+        let mut address_book = AddressBook::default();
+        let rival = PeerId::random();
+        let troll = PeerId::random();
+
+        assert!(address_book.record_seen(rival, "Rivals".to_string(), 3.0, 10));
+        assert!(!address_book.record_seen(rival, "Rivals".to_string(), 3.5, 20));
+        address_book.set_favorite(rival, true);
+        assert!(address_book.is_favorite(&rival));
+        assert_eq!(address_book.get(&rival).unwrap().rating, Some(3.5));
+
+        // Favorites survive the pruning of old entries.
+        for tick in 0..MAX_ADDRESS_BOOK_ENTRIES {
+            address_book.record_seen(PeerId::random(), "Crew".to_string(), 1.0, tick as u128);
+        }
+        assert_eq!(address_book.entries.len(), MAX_ADDRESS_BOOK_ENTRIES);
+        assert!(address_book.is_favorite(&rival));
+        assert_eq!(address_book.sorted_entries()[0].peer_id, Some(rival));
+
+        address_book.set_blocked(troll, true);
+        assert!(address_book.is_blocked(&troll));
+        address_book.set_blocked(rival, true);
+        assert!(!address_book.is_favorite(&rival));
+        address_book.set_favorite(rival, true);
+        assert!(!address_book.is_blocked(&rival));
+        address_book.set_blocked(troll, false);
+        assert!(address_book.blocked.is_empty());
+    }
+}
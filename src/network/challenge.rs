@@ -1,8 +1,29 @@
 use super::types::NetworkRequestState;
 use crate::game_engine::types::TeamInGame;
-use crate::types::Tick;
+use crate::types::{AppResult, Tick};
+use anyhow::anyhow;
 use libp2p::PeerId;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+pub type ChallengeSeed = u64;
+
+fn hash_seeds(seeds: &[ChallengeSeed]) -> u64 {
+    let mut hasher = Sha256::new();
+    for seed in seeds {
+        hasher.update(seed.to_le_bytes());
+    }
+    let digest = hasher.finalize();
+    u64::from_le_bytes(
+        digest[..8]
+            .try_into()
+            .expect("Digest should have at least 8 bytes"),
+    )
+}
+
+pub fn seed_commitment(seed: ChallengeSeed) -> u64 {
+    hash_seeds(&[seed])
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Challenge {
@@ -12,6 +33,19 @@ pub struct Challenge {
     pub home_team_in_game: TeamInGame,
     pub away_team_in_game: TeamInGame,
     pub starting_at: Option<Tick>,
+    // Both peers contribute to the seed of the game. The proposer sends the hash
+    // of its seed with the challenge and reveals the seed only after receiving
+    // the one of the target, so that neither of them can pick the game seed.
+    #[serde(default)]
+    pub home_seed_commitment: Option<u64>,
+    #[serde(default)]
+    pub home_seed: Option<ChallengeSeed>,
+    #[serde(default)]
+    pub away_seed: Option<ChallengeSeed>,
+    // Only known to the proposer until the challenge is accepted. Like pending
+    // challenges, it is not saved: a challenge accepted after a restart fails.
+    #[serde(skip)]
+    pub home_seed_secret: Option<ChallengeSeed>,
 }
 
 impl Challenge {
@@ -21,6 +55,7 @@ impl Challenge {
         home_team_in_game: TeamInGame,
         away_team_in_game: TeamInGame,
     ) -> Self {
+        let home_seed = rand::random();
         Self {
             state: NetworkRequestState::Syn,
             proposer_peer_id,
@@ -28,9 +63,32 @@ impl Challenge {
             home_team_in_game,
             away_team_in_game,
             starting_at: None,
+            home_seed_commitment: Some(seed_commitment(home_seed)),
+            home_seed: None,
+            away_seed: None,
+            home_seed_secret: Some(home_seed),
         }
     }
 
+    // Seed shared by the peers, available once the proposer revealed its own.
+    pub fn game_seed(&self) -> AppResult<ChallengeSeed> {
+        let commitment = self
+            .home_seed_commitment
+            .ok_or(anyhow!("Challenge has no seed commitment"))?;
+        let home_seed = self
+            .home_seed
+            .ok_or(anyhow!("Challenge seed has not been revealed"))?;
+        let away_seed = self
+            .away_seed
+            .ok_or(anyhow!("Challenge has no opponent seed"))?;
+
+        if seed_commitment(home_seed) != commitment {
+            return Err(anyhow!("Challenge seed does not match its commitment"));
+        }
+
+        Ok(hash_seeds(&[home_seed, away_seed]))
+    }
+
     pub fn format(&self) -> String {
         format!(
             "Challenge: {} {} {} - {} vs {} ",
@@ -42,3 +100,40 @@ impl Challenge {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Challenge;
+    use crate::game_engine::types::TeamInGame;
+    use crate::types::AppResult;
+    use libp2p::PeerId;
+
+    #[test]
+    fn test_challenge_seed_agreement() -> AppResult<()> {
+        let mut challenge = Challenge::new(
+            PeerId::random(),
+            PeerId::random(),
+            TeamInGame::default(),
+            TeamInGame::default(),
+        );
+        let home_seed = challenge
+            .home_seed_secret
+            .expect("Proposer should have a seed");
+
+        // The secret seed is not sent with the challenge.
+        let mut received: Challenge = serde_json::from_str(&serde_json::to_string(&challenge)?)?;
+        assert!(received.home_seed_secret.is_none());
+        assert!(received.game_seed().is_err());
+
+        received.away_seed = Some(7);
+        received.home_seed = Some(home_seed.wrapping_add(1));
+        assert!(received.game_seed().is_err());
+
+        received.home_seed = Some(home_seed);
+        challenge.away_seed = Some(7);
+        challenge.home_seed = Some(home_seed);
+        assert_eq!(received.game_seed()?, challenge.game_seed()?);
+
+        Ok(())
+    }
+}
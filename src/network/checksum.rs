@@ -0,0 +1,259 @@
+use crate::game_engine::{game::Game, timer::Period};
+use crate::types::{AppResult, GameId, TeamId};
+use crate::world::{team::Team, world::World};
+use itertools::Itertools;
+use libp2p::PeerId;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+pub type TeamChecksum = u64;
+
+/// Fingerprint of a team as shared over the network. Only the identity and
+/// version of the team and its players are hashed, so that the local simulation
+/// of network teams does not make the checksum diverge.
+pub fn team_checksum(world: &World, team: &Team) -> TeamChecksum {
+    let mut hasher = Sha256::new();
+    hasher.update(team.id.as_bytes());
+    hasher.update(team.version.to_le_bytes());
+    for player_id in team.player_ids.iter() {
+        hasher.update(player_id.as_bytes());
+        if let Some(player) = world.get_player(*player_id) {
+            hasher.update(player.version.to_le_bytes());
+        }
+    }
+
+    let digest = hasher.finalize();
+    TeamChecksum::from_le_bytes(
+        digest[..8]
+            .try_into()
+            .expect("Digest should have at least 8 bytes"),
+    )
+}
+
+/// Periodically broadcast by each peer: the checksum of its own team,
+/// which is the reference version, and of the network teams it has stored.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StateChecksum {
+    pub sender_peer_id: PeerId,
+    pub team_id: TeamId,
+    pub checksum: TeamChecksum,
+    pub known_teams: HashMap<TeamId, TeamChecksum>,
+}
+
+impl StateChecksum {
+    pub fn from_world(world: &World, sender_peer_id: PeerId) -> AppResult<Self> {
+        let own_team = world.get_own_team()?;
+        let known_teams = world
+            .teams
+            .values()
+            .filter(|team| team.peer_id.is_some())
+            .map(|team| (team.id, team_checksum(world, team)))
+            .collect();
+
+        Ok(Self {
+            sender_peer_id,
+            team_id: own_team.id,
+            checksum: team_checksum(world, own_team),
+            known_teams,
+        })
+    }
+
+    // Returns true if the local copy of the sender team is missing or differs from the sender one.
+    pub fn is_sender_team_outdated(&self, world: &World) -> bool {
+        match world.get_team(self.team_id) {
+            Some(team) => team_checksum(world, team) != self.checksum,
+            None => true,
+        }
+    }
+
+    // Returns true if the sender has stored a different version of the own team.
+    pub fn is_own_team_outdated(&self, world: &World) -> AppResult<bool> {
+        let own_team = world.get_own_team()?;
+        Ok(self
+            .known_teams
+            .get(&own_team.id)
+            .is_some_and(|&checksum| checksum != team_checksum(world, own_team)))
+    }
+}
+
+pub type GameStateChecksum = u64;
+
+/// Fingerprint of the box score of a game. Peers simulate network games on their
+/// own from the seed agreed in the challenge, and compare it at every break.
+pub fn game_checksum(game: &Game) -> GameStateChecksum {
+    let mut hasher = Sha256::new();
+    hasher.update(game.id.as_bytes());
+    let (home_score, away_score) = game.get_score();
+    hasher.update(home_score.to_le_bytes());
+    hasher.update(away_score.to_le_bytes());
+    for team in [&game.home_team_in_game, &game.away_team_in_game] {
+        for (player_id, stats) in team.stats.iter().sorted_by_key(|(id, _)| **id) {
+            hasher.update(player_id.as_bytes());
+            hasher.update([
+                stats.points,
+                stats.attempted_ft,
+                stats.made_ft,
+                stats.attempted_2pt,
+                stats.made_2pt,
+                stats.attempted_3pt,
+                stats.made_3pt,
+                stats.offensive_rebounds,
+                stats.defensive_rebounds,
+                stats.assists,
+                stats.steals,
+                stats.blocks,
+                stats.turnovers,
+                stats.fouls,
+            ]);
+            hasher.update(stats.plus_minus.to_le_bytes());
+            hasher.update(stats.seconds_played.to_le_bytes());
+        }
+    }
+
+    let digest = hasher.finalize();
+    GameStateChecksum::from_le_bytes(
+        digest[..8]
+            .try_into()
+            .expect("Digest should have at least 8 bytes"),
+    )
+}
+
+// Breaks at which the peers playing a network game exchange their checksums.
+pub fn is_checksum_period(period: Period) -> bool {
+    matches!(period, Period::B1 | Period::B2 | Period::B3 | Period::B4)
+}
+
+/// Sent by the peers playing a network game at the end of every quarter.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GameChecksum {
+    pub sender_peer_id: PeerId,
+    pub game_id: GameId,
+    pub period: Period,
+    pub checksum: GameStateChecksum,
+}
+
+/// Checksums of a network game, computed locally and received from the opponent.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GameChecksumLog {
+    local: Vec<(Period, GameStateChecksum)>,
+    remote: Vec<(Period, GameStateChecksum)>,
+    desynced_at: Option<Period>,
+}
+
+impl GameChecksumLog {
+    pub fn has_local(&self, period: Period) -> bool {
+        self.local.iter().any(|(p, _)| *p == period)
+    }
+
+    pub fn record_local(&mut self, period: Period, checksum: GameStateChecksum) {
+        if !self.has_local(period) {
+            self.local.push((period, checksum));
+        }
+    }
+
+    pub fn record_remote(&mut self, period: Period, checksum: GameStateChecksum) {
+        if !self.remote.iter().any(|(p, _)| *p == period) {
+            self.remote.push((period, checksum));
+        }
+    }
+
+    // Returns the first period at which the simulations diverged.
+    // A desync is only reported once per game.
+    pub fn check(&mut self) -> Option<Period> {
+        if self.desynced_at.is_some() {
+            return None;
+        }
+        self.desynced_at = self.local.iter().find_map(|(period, checksum)| {
+            self.remote
+                .iter()
+                .any(|(p, c)| p == period && c != checksum)
+                .then_some(*period)
+        });
+        self.desynced_at
+    }
+}
+
+pub fn game_desync_message(game: &Game, period: Period) -> String {
+    format!(
+        "{} vs {} went out of sync during {}.\nThe result might differ from the opponent's one.",
+        game.home_team_in_game.name,
+        game.away_team_in_game.name,
+        period.previous()
+    )
+}
+
+/// Asks a peer to send again its own team.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TeamRequest {
+    pub sender_peer_id: PeerId,
+    pub target_peer_id: PeerId,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{team_checksum, GameChecksumLog, StateChecksum};
+    use crate::game_engine::timer::Period;
+    use crate::types::{AppResult, TeamId};
+    use crate::world::world::World;
+    use libp2p::PeerId;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_state_checksum() -> AppResult<()> {
+        let mut world = World::new(None);
+        world.initialize(false)?;
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+
+        let home_planet_id = *world.planets.keys().next().unwrap();
+        world.own_team_id =
+            world.generate_random_team(rng, home_planet_id, "own".into(), "ship".into())?;
+        let other_team_id =
+            world.generate_random_team(rng, home_planet_id, "other".into(), "ship".into())?;
+
+        let mut other_team = world.get_team_or_err(other_team_id)?.clone();
+        other_team.peer_id = Some(PeerId::random());
+        world.teams.insert(other_team.id, other_team.clone());
+
+        // The checksum only depends on the team and player versions.
+        let checksum = team_checksum(&world, &other_team);
+        let mut team = other_team.clone();
+        team.resources.clear();
+        assert_eq!(team_checksum(&world, &team), checksum);
+        team.version += 1;
+        assert_ne!(team_checksum(&world, &team), checksum);
+
+        let own_state = StateChecksum::from_world(&world, PeerId::random())?;
+        assert_eq!(own_state.known_teams.len(), 1);
+        assert!(!own_state.is_sender_team_outdated(&world));
+
+        // The peer owning the other team has a newer version, and an old version of ours.
+        let mut peer_state = own_state.clone();
+        peer_state.team_id = other_team_id;
+        peer_state.checksum = team_checksum(&world, &team);
+        peer_state.known_teams = [(world.own_team_id, 0)].into_iter().collect();
+        assert!(peer_state.is_sender_team_outdated(&world));
+        assert!(peer_state.is_own_team_outdated(&world)?);
+
+        peer_state.team_id = TeamId::new_v4();
+        assert!(peer_state.is_sender_team_outdated(&world));
+        Ok(())
+    }
+
+    #[test]
+    fn test_game_checksum_log() {
+        let mut log = GameChecksumLog::default();
+        log.record_local(Period::B1, 1);
+        log.record_remote(Period::B1, 1);
+        log.record_remote(Period::B2, 2);
+        assert_eq!(log.check(), None);
+
+        // The remote checksum can arrive before the local one.
+        log.record_local(Period::B2, 3);
+        assert_eq!(log.check(), Some(Period::B2));
+        log.record_local(Period::B3, 4);
+        log.record_remote(Period::B3, 5);
+        assert_eq!(log.check(), None);
+    }
+}
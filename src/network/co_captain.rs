@@ -0,0 +1,117 @@
+use super::types::NetworkRequestState;
+use crate::types::{AppResult, TeamId};
+use crate::world::{action_queue::QueuedAction, team::Team};
+use anyhow::anyhow;
+use libp2p::PeerId;
+use serde::{Deserialize, Serialize};
+
+/// Order given by a co-captain to a team owned by another peer.
+/// The owner peer is the only one applying orders, so that both captains
+/// end up with the same queue once the team is sent over the network.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CoCaptainOrder {
+    pub state: NetworkRequestState,
+    pub captain_peer_id: PeerId,
+    pub owner_peer_id: PeerId,
+    pub captain_team_id: TeamId,
+    pub captain_name: String,
+    pub team_id: TeamId,
+    pub action: QueuedAction,
+    // Version of the team action queue seen by the co-captain when giving the order.
+    pub queue_version: u64,
+}
+
+impl CoCaptainOrder {
+    pub fn new(
+        captain_peer_id: PeerId,
+        owner_peer_id: PeerId,
+        captain_team_id: TeamId,
+        captain_name: String,
+        team: &Team,
+        action: QueuedAction,
+    ) -> Self {
+        Self {
+            state: NetworkRequestState::Syn,
+            captain_peer_id,
+            owner_peer_id,
+            captain_team_id,
+            captain_name,
+            team_id: team.id,
+            action,
+            queue_version: team.action_queue_version,
+        }
+    }
+
+    pub fn format(&self) -> String {
+        format!(
+            "Co-captain order: {} {} {} - {:?}",
+            self.state, self.captain_peer_id, self.owner_peer_id, self.action
+        )
+    }
+
+    pub fn can_be_applied_to(&self, team: &Team) -> AppResult<()> {
+        if self.team_id != team.id {
+            return Err(anyhow!("Order is not for this team"));
+        }
+
+        if team.co_captain_id != Some(self.captain_team_id) {
+            return Err(anyhow!("{} is not a co-captain", self.captain_name));
+        }
+
+        // If the queue changed since the co-captain saw it, both captains gave orders
+        // at the same time. The same order given twice is applied only once.
+        if self.queue_version != team.action_queue_version
+            && team.action_queue.contains(&self.action)
+        {
+            return Err(anyhow!("Order already given by the other captain"));
+        }
+
+        team.can_queue_action(self.action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CoCaptainOrder;
+    use crate::types::TeamId;
+    use crate::world::{action_queue::QueuedAction, team::Team};
+    use libp2p::PeerId;
+
+    #[test]
+    fn test_co_captain_order_conflicts() {
+        let captain_team_id = TeamId::new_v4();
+        let mut team = Team::default();
+        team.id = TeamId::new_v4();
+
+        let order = CoCaptainOrder::new(
+            PeerId::random(),
+            PeerId::random(),
+            captain_team_id,
+            "Co-captain".to_string(),
+            &team,
+            QueuedAction::ReturnHome,
+        );
+
+        // Untrusted captains cannot give orders.
+        assert!(order.can_be_applied_to(&team).is_err());
+
+        team.co_captain_id = Some(captain_team_id);
+        assert!(order.can_be_applied_to(&team).is_ok());
+
+        // The owner gave the same order in the meantime.
+        team.action_queue.push(QueuedAction::ReturnHome);
+        team.action_queue_version += 1;
+        assert!(order.can_be_applied_to(&team).is_err());
+
+        // Repeating an order on an up to date queue is allowed.
+        let order = CoCaptainOrder::new(
+            PeerId::random(),
+            PeerId::random(),
+            captain_team_id,
+            "Co-captain".to_string(),
+            &team,
+            QueuedAction::ReturnHome,
+        );
+        assert!(order.can_be_applied_to(&team).is_ok());
+    }
+}
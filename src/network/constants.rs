@@ -1,4 +1,9 @@
+use crate::types::Tick;
+use crate::world::constants::MINUTES;
+
 pub(crate) const TOPIC: &'static str = "rebels-b2b";
 pub const DEFAULT_PORT: u16 = 37202;
 pub(crate) const DEFAULT_SEED_PORT: u16 = 37201;
 pub(crate) const DEFAULT_SEED_IP: &'static str = "85.214.130.204";
+// Interval between broadcasts of the state checksums, used to detect desynced teams.
+pub(crate) const STATE_CHECKSUM_INTERVAL: Tick = MINUTES;
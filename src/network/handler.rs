@@ -1,21 +1,34 @@
+use super::address_book::AddressBook;
 use super::challenge::Challenge;
+use super::checksum::{
+    GameChecksum, GameChecksumLog, GameStateChecksum, StateChecksum, TeamRequest,
+};
+use super::co_captain::CoCaptainOrder;
 use super::constants::*;
 use super::network_callback::NetworkCallback;
-use super::trade::Trade;
-use super::types::{NetworkData, NetworkGame, NetworkRequestState, NetworkTeam, SeedInfo};
-use crate::game_engine::types::TeamInGame;
+use super::resource_trade::ResourceTrade;
+use super::trade::{Trade, TradeBundle, TradeDraft};
+use super::types::{
+    DirectMessage, LobbyEntry, NetworkData, NetworkGame, NetworkRequestState, NetworkTeam, SeedInfo,
+};
+use crate::game_engine::timer::Period;
+use crate::game_engine::types::{CrowdChatter, TeamInGame};
 use crate::types::{AppResult, GameId};
-use crate::types::{PlayerId, TeamId};
+use crate::types::{KartoffelId, PlayerId, ResourceMap, TeamId};
 use crate::types::{SystemTimeTick, Tick};
+use crate::world::action_queue::QueuedAction;
+use crate::world::constants::NETWORK_GAME_START_DELAY;
+use crate::world::resources::Resource;
 use crate::world::world::World;
 use anyhow::anyhow;
 use libp2p::core::upgrade::Version;
 use libp2p::gossipsub::{self, IdentTopic, MessageId};
-use libp2p::swarm::{Config, SwarmEvent};
+use libp2p::swarm::{dial_opts::DialOpts, Config, ConnectionId, SwarmEvent};
 use libp2p::{identity, noise, tcp, yamux, PeerId, Transport};
 use libp2p::{Multiaddr, Swarm};
 use log::{error, info};
 use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::hash::{Hash, Hasher};
 use std::time::Duration;
@@ -25,6 +38,10 @@ pub struct NetworkHandler {
     pub swarm: Swarm<gossipsub::Behaviour>,
     pub address: Multiaddr,
     pub seed_address: Multiaddr,
+    pub address_book: AddressBook,
+    // Connections dialed by the player, to report back whether they succeeded.
+    direct_dials: HashMap<ConnectionId, Multiaddr>,
+    pub game_checksums: HashMap<GameId, GameChecksumLog>,
 }
 
 impl Debug for NetworkHandler {
@@ -95,6 +112,9 @@ impl NetworkHandler {
             swarm,
             address: Multiaddr::empty(),
             seed_address,
+            address_book: AddressBook::default(),
+            direct_dials: HashMap::new(),
+            game_checksums: HashMap::new(),
         })
     }
 
@@ -111,6 +131,17 @@ impl NetworkHandler {
         self.dial(self.seed_address.clone())
     }
 
+    pub fn dial_address(&mut self, address: Multiaddr) -> AppResult<()> {
+        if address == self.address {
+            return Err(anyhow!("Cannot dial own address"));
+        }
+        let opts = DialOpts::from(address.clone());
+        let connection_id = opts.connection_id();
+        self.swarm.dial(opts)?;
+        self.direct_dials.insert(connection_id, address);
+        Ok(())
+    }
+
     fn dial(&mut self, address: Multiaddr) -> AppResult<()> {
         if address != self.address {
             self.swarm.dial(address)?;
@@ -122,6 +153,19 @@ impl NetworkHandler {
         self._send(NetworkData::Message(Tick::now(), msg))
     }
 
+    pub fn send_direct_msg(
+        &mut self,
+        target_peer_id: PeerId,
+        text: String,
+    ) -> AppResult<MessageId> {
+        let message = DirectMessage {
+            sender_peer_id: self.swarm.local_peer_id().clone(),
+            target_peer_id,
+            text,
+        };
+        self._send(NetworkData::DirectMessage(Tick::now(), message))
+    }
+
     pub fn send_seed_info(&mut self, seed_info: SeedInfo) -> AppResult<MessageId> {
         self._send(NetworkData::SeedInfo(Tick::now(), seed_info))
     }
@@ -145,6 +189,39 @@ impl NetworkHandler {
         Ok(message_id)
     }
 
+    pub fn send_state_checksum(&mut self, world: &World) -> AppResult<MessageId> {
+        let state_checksum = StateChecksum::from_world(world, self.swarm.local_peer_id().clone())?;
+        self._send(NetworkData::StateChecksum(Tick::now(), state_checksum))
+    }
+
+    pub fn send_game_checksum(
+        &mut self,
+        game_id: GameId,
+        period: Period,
+        checksum: GameStateChecksum,
+    ) -> AppResult<MessageId> {
+        let game_checksum = GameChecksum {
+            sender_peer_id: self.swarm.local_peer_id().clone(),
+            game_id,
+            period,
+            checksum,
+        };
+        self._send(NetworkData::GameChecksum(Tick::now(), game_checksum))
+    }
+
+    pub fn send_team_request(&mut self, target_peer_id: PeerId) -> AppResult<MessageId> {
+        let request = TeamRequest {
+            sender_peer_id: self.swarm.local_peer_id().clone(),
+            target_peer_id,
+        };
+        self._send(NetworkData::TeamRequest(Tick::now(), request))
+    }
+
+    pub fn send_lobby_entry(&mut self, world: &World) -> AppResult<MessageId> {
+        let lobby_entry = LobbyEntry::from_own_team(world)?;
+        self._send(NetworkData::LobbyEntry(Tick::now(), lobby_entry))
+    }
+
     fn send_game(&mut self, world: &World, game_id: GameId) -> AppResult<MessageId> {
         let network_game = NetworkGame::from_game_id(&world, game_id)?;
         self._send(NetworkData::Game(Tick::now(), network_game))
@@ -167,6 +244,46 @@ impl NetworkHandler {
         self._send(NetworkData::Trade(Tick::now(), trade))
     }
 
+    pub fn send_resource_trade(&mut self, trade: ResourceTrade) -> AppResult<MessageId> {
+        self._send(NetworkData::ResourceTrade(Tick::now(), trade))
+    }
+
+    pub fn send_crowd_chatter(&mut self, chatter: CrowdChatter) -> AppResult<MessageId> {
+        self._send(NetworkData::CrowdChatter(Tick::now(), chatter))
+    }
+
+    pub fn send_co_captain_order(&mut self, order: CoCaptainOrder) -> AppResult<MessageId> {
+        self._send(NetworkData::CoCaptainOrder(Tick::now(), order))
+    }
+
+    pub fn send_new_co_captain_order(
+        &mut self,
+        world: &World,
+        team_id: TeamId,
+        action: QueuedAction,
+    ) -> AppResult<CoCaptainOrder> {
+        let own_team = world.get_own_team()?;
+        let team = world.get_team_or_err(team_id)?;
+        if team.co_captain_id != Some(own_team.id) {
+            return Err(anyhow!("Not a co-captain of {}", team.name));
+        }
+        let owner_peer_id = team
+            .peer_id
+            .ok_or(anyhow!("{} is not managed by a peer", team.name))?;
+
+        let order = CoCaptainOrder::new(
+            self.swarm.local_peer_id().clone(),
+            owner_peer_id,
+            own_team.id,
+            own_team.name.clone(),
+            team,
+            action,
+        );
+
+        self.send_co_captain_order(order.clone())?;
+        Ok(order)
+    }
+
     pub fn send_new_challenge(
         &mut self,
         world: &World,
@@ -199,24 +316,122 @@ impl NetworkHandler {
         target_peer_id: PeerId,
         proposer_player_id: PlayerId,
         target_player_id: PlayerId,
+        extra_satoshis: i64,
     ) -> AppResult<Trade> {
         self.send_own_team(world)?;
 
         let proposer_player = world.get_player_or_err(proposer_player_id)?.clone();
         let target_player = world.get_player_or_err(target_player_id)?.clone();
 
+        let trade = Trade::new(
+            self.swarm.local_peer_id().clone(),
+            target_peer_id,
+            proposer_player,
+            target_player,
+            extra_satoshis,
+        );
+
+        self.send_trade(trade.clone())?;
+        Ok(trade)
+    }
+
+    // The first player picked on each side of the draft leads the trade.
+    pub fn send_new_trade_bundle(
+        &mut self,
+        world: &World,
+        target_peer_id: PeerId,
+        draft: &TradeDraft,
+    ) -> AppResult<Trade> {
+        draft.is_valid()?;
+        self.send_own_team(world)?;
+
+        let bundle = |player_ids: &[PlayerId], resources: &ResourceMap| -> AppResult<TradeBundle> {
+            Ok(TradeBundle {
+                players: player_ids
+                    .iter()
+                    .map(|id| world.get_player_or_err(*id).cloned())
+                    .collect::<AppResult<Vec<_>>>()?,
+                resources: resources.clone(),
+            })
+        };
+
+        let proposer_player = world
+            .get_player_or_err(draft.proposer_player_ids[0])?
+            .clone();
+        let target_player = world.get_player_or_err(draft.target_player_ids[0])?.clone();
+
         let trade = Trade::new(
             self.swarm.local_peer_id().clone(),
             target_peer_id,
             proposer_player,
             target_player,
             0,
+        )
+        .with_bundles(
+            bundle(&draft.proposer_player_ids[1..], &draft.proposer_resources)?,
+            bundle(&draft.target_player_ids[1..], &draft.target_resources)?,
         );
 
         self.send_trade(trade.clone())?;
         Ok(trade)
     }
 
+    pub fn send_new_resource_trade(
+        &mut self,
+        world: &World,
+        target_team_id: TeamId,
+        resource: Resource,
+        amount: u32,
+        satoshi: u32,
+        proposer_sells: bool,
+    ) -> AppResult<ResourceTrade> {
+        self.send_own_team(world)?;
+
+        let own_team = world.get_own_team()?;
+        let target_team = world.get_team_or_err(target_team_id)?;
+        let trade = ResourceTrade::new(
+            self.swarm.local_peer_id().clone(),
+            own_team,
+            target_team,
+            resource,
+            amount,
+            satoshi,
+            proposer_sells,
+        )?;
+        trade.can_be_settled_by(own_team, target_team)?;
+
+        self.send_resource_trade(trade.clone())?;
+        Ok(trade)
+    }
+
+    pub fn send_new_kartoffel_trade(
+        &mut self,
+        world: &World,
+        target_team_id: TeamId,
+        kartoffel_id: KartoffelId,
+        satoshi: u32,
+    ) -> AppResult<ResourceTrade> {
+        self.send_own_team(world)?;
+
+        let own_team = world.get_own_team()?;
+        let target_team = world.get_team_or_err(target_team_id)?;
+        let kartoffel = world.get_kartoffel_or_err(kartoffel_id)?;
+        let trade = ResourceTrade::new(
+            self.swarm.local_peer_id().clone(),
+            own_team,
+            target_team,
+            Resource::SCRAPS,
+            0,
+            satoshi,
+            true,
+        )?
+        .with_kartoffel(kartoffel.clone());
+        trade.can_be_settled_by(own_team, target_team)?;
+
+        self.send_resource_trade(trade.clone())?;
+        Ok(trade)
+    }
+
     pub fn quit(&mut self) {
         if let Err(e) = self
             .swarm
@@ -261,6 +476,10 @@ impl NetworkHandler {
             let mut challenge = challenge.clone();
             challenge.away_team_in_game = away_team_in_game;
             challenge.state = NetworkRequestState::SynAck;
+            // The starting time is part of the game seed, hence it is picked
+            // by the target together with its seed.
+            challenge.away_seed = Some(rand::random());
+            challenge.starting_at = Some(Tick::now() + NETWORK_GAME_START_DELAY);
             self.send_challenge(challenge)?;
             Ok(())
         };
@@ -301,11 +520,16 @@ impl NetworkHandler {
             let mut trade = trade.clone();
             let target_player = world.get_player_or_err(trade.target_player.id)?.clone();
             trade.target_player = target_player;
+            for player in trade.target_bundle.players.iter_mut() {
+                *player = world.get_player_or_err(player.id)?.clone();
+            }
             proposer_team.can_trade_players(
                 &trade.proposer_player,
                 &trade.target_player,
                 own_team,
             )?;
+            trade.can_be_settled_by(own_team, proposer_team)?;
+            own_team.can_pay_trade_satoshis(-trade.extra_satoshis)?;
 
             trade.state = NetworkRequestState::SynAck;
             self.send_trade(trade)?;
@@ -332,6 +556,42 @@ impl NetworkHandler {
         Ok(())
     }
 
+    pub fn accept_resource_trade(&mut self, world: &World, trade: ResourceTrade) -> AppResult<()> {
+        self.send_own_team(world)?;
+        let mut handle_syn = || -> AppResult<()> {
+            let own_team = world.get_own_team()?;
+            let proposer_team = world.get_team_or_err(trade.proposer_team_id)?;
+
+            // Note: as for player trades, resources are not exchanged at this point
+            // but only when the proposer confirms the trade is still valid.
+            trade.can_be_settled_by(own_team, proposer_team)?;
+
+            let mut trade = trade.clone();
+            trade.state = NetworkRequestState::SynAck;
+            self.send_resource_trade(trade)?;
+            Ok(())
+        };
+
+        if let Err(err) = handle_syn() {
+            let mut trade = trade.clone();
+            trade.state = NetworkRequestState::Failed {
+                error_message: err.to_string(),
+            };
+            self.send_resource_trade(trade)?;
+            return Err(anyhow!(err.to_string()));
+        }
+        Ok(())
+    }
+
+    pub fn decline_resource_trade(&mut self, trade: ResourceTrade) -> AppResult<()> {
+        let mut trade = trade.clone();
+        trade.state = NetworkRequestState::Failed {
+            error_message: "Resource trade declined".to_string(),
+        };
+        self.send_resource_trade(trade)?;
+        Ok(())
+    }
+
     pub fn handle_network_events(
         &mut self,
         event: SwarmEvent<gossipsub::Event, Void>,
@@ -364,8 +624,26 @@ impl NetworkHandler {
                 timestamp: Tick::now(),
                 text: format!("Expired listen address: {}", address),
             }),
-            SwarmEvent::ConnectionEstablished { peer_id, .. } => {
-                Some(NetworkCallback::HandleConnectionEstablished { peer_id })
+            SwarmEvent::ConnectionEstablished {
+                peer_id,
+                connection_id,
+                ..
+            } => Some(NetworkCallback::HandleConnectionEstablished {
+                peer_id,
+                direct_dial: self.direct_dials.remove(&connection_id),
+            }),
+            SwarmEvent::OutgoingConnectionError {
+                connection_id,
+                error,
+                ..
+            } if self.direct_dials.contains_key(&connection_id) => {
+                Some(NetworkCallback::HandleDirectDialError {
+                    address: self
+                        .direct_dials
+                        .remove(&connection_id)
+                        .expect("Direct dial should exist"),
+                    error: error.to_string(),
+                })
             }
             SwarmEvent::ConnectionClosed { peer_id, .. } => {
                 Some(NetworkCallback::CloseConnection { peer_id })
@@ -381,7 +659,7 @@ impl NetworkHandler {
 #[cfg(test)]
 mod tests {
     use crate::{
-        network::types::{NetworkData, NetworkTeam},
+        network::types::{LobbyEntry, NetworkData, NetworkTeam},
         types::{AppResult, SystemTimeTick, Tick},
         world::world::World,
     };
@@ -423,4 +701,32 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_send_lobby_entry() -> AppResult<()> {
+        let mut world = World::new(None);
+        let rng = &mut ChaCha8Rng::from_entropy();
+        let home_planet = world.planets.keys().next().unwrap().clone();
+        let team_name = "Testen".to_string();
+        let ship_name = "Tosten".to_string();
+        world.own_team_id = world.generate_random_team(rng, home_planet, team_name, ship_name)?;
+        let lobby_entry = LobbyEntry::from_own_team(&world)?;
+        assert_eq!(lobby_entry.team_name, "Testen");
+
+        let timestamp = Tick::now();
+        let serialized_network_data =
+            serde_json::to_vec(&NetworkData::LobbyEntry(timestamp, lobby_entry.clone()))?;
+        let deserialized_network_data =
+            serde_json::from_slice::<NetworkData>(serialized_network_data.as_slice())?;
+
+        match deserialized_network_data {
+            NetworkData::LobbyEntry(deserialized_timestamp, deserialized_entry) => {
+                assert!(deserialized_timestamp == timestamp);
+                assert_eq!(deserialized_entry, lobby_entry);
+            }
+            _ => return Err(anyhow!("Invalid NetworkData deserialization")),
+        }
+
+        Ok(())
+    }
 }
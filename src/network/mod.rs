@@ -1,6 +1,11 @@
+pub mod address_book;
 pub mod challenge;
+pub mod checksum;
+pub mod co_captain;
 pub mod constants;
 pub mod handler;
 pub mod network_callback;
+pub mod resource_trade;
 pub mod trade;
 pub mod types;
+pub mod validation;
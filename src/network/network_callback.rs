@@ -1,11 +1,21 @@
 use super::challenge::Challenge;
+use super::checksum::{game_desync_message, GameChecksum, StateChecksum, TeamRequest};
+use super::co_captain::CoCaptainOrder;
+use super::resource_trade::ResourceTrade;
 use super::trade::Trade;
-use super::types::{NetworkData, NetworkGame, NetworkRequestState, NetworkTeam, SeedInfo};
-use crate::game_engine::types::TeamInGame;
+use super::types::{
+    DirectMessage, LobbyEntry, NetworkData, NetworkGame, NetworkRequestState, NetworkTeam, SeedInfo,
+};
+use super::validation::{validate_network_player, validate_network_team};
+use crate::game_engine::types::{CrowdChatter, TeamInGame};
+use crate::store::save_address_book;
 use crate::types::{AppResult, SystemTimeTick, Tick};
 use crate::ui::popup_message::PopupMessage;
-use crate::ui::utils::SwarmPanelEvent;
-use crate::world::constants::NETWORK_GAME_START_DELAY;
+use crate::ui::utils::{format_satoshi, SwarmPanelEvent};
+use crate::world::finances::FinanceCategory;
+use crate::world::hooks::HookEvent;
+use crate::world::notifications::NotificationCategory;
+use crate::world::types::KartoffelLocation;
 use crate::{app::App, types::AppCallback};
 use anyhow::anyhow;
 use libp2p::gossipsub::TopicHash;
@@ -38,6 +48,11 @@ pub enum NetworkCallback {
     },
     HandleConnectionEstablished {
         peer_id: PeerId,
+        direct_dial: Option<Multiaddr>,
+    },
+    HandleDirectDialError {
+        address: Multiaddr,
+        error: String,
     },
     HandleMessage {
         message: Message,
@@ -150,6 +165,28 @@ impl NetworkCallback {
             };
             app.ui.swarm_panel.push_log_event(event);
 
+            let mut network_team = network_team.clone();
+            let corrections = match validate_network_team(&mut network_team) {
+                Ok(corrections) => corrections,
+                Err(e) => {
+                    let event = SwarmPanelEvent {
+                        timestamp,
+                        peer_id,
+                        text: format!("Rejected team from peer {:?}: {}", peer_id, e),
+                    };
+                    app.ui.swarm_panel.push_log_event(event);
+                    return Ok(None);
+                }
+            };
+            for correction in corrections {
+                let event = SwarmPanelEvent {
+                    timestamp,
+                    peer_id,
+                    text: format!("Invalid team data: {}", correction),
+                };
+                app.ui.swarm_panel.push_log_event(event);
+            }
+
             let event = SwarmPanelEvent {
                 timestamp,
                 peer_id,
@@ -163,10 +200,183 @@ impl NetworkCallback {
                 app.ui.swarm_panel.add_peer_id(id, network_team.team.id);
             }
             app.world.add_network_team(network_team.clone())?;
+
+            if let (Some(id), Some(network_handler)) = (peer_id, app.network_handler.as_mut()) {
+                let rating = app
+                    .world
+                    .team_rating(network_team.team.id)
+                    .unwrap_or_default();
+                let is_new = network_handler.address_book.record_seen(
+                    id,
+                    network_team.team.name.clone(),
+                    rating,
+                    timestamp,
+                );
+                // Last seen times of known peers are saved with the next change.
+                if is_new {
+                    save_address_book(&network_handler.address_book)?;
+                }
+            }
+            Ok(None)
+        })
+    }
+
+    fn handle_state_checksum_topic(
+        peer_id: Option<PeerId>,
+        timestamp: Tick,
+        state_checksum: StateChecksum,
+    ) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let sender_team_outdated = state_checksum.is_sender_team_outdated(&app.world);
+            let own_team_outdated = state_checksum.is_own_team_outdated(&app.world)?;
+            if !sender_team_outdated && !own_team_outdated {
+                return Ok(None);
+            }
+
+            let network_handler = app
+                .network_handler
+                .as_mut()
+                .expect("The should be a network handler");
+
+            // The owner of a team always holds the reference version:
+            // we ask for theirs and resend ours when they differ.
+            if sender_team_outdated {
+                network_handler.send_team_request(state_checksum.sender_peer_id)?;
+                app.ui.swarm_panel.push_log_event(SwarmPanelEvent {
+                    timestamp,
+                    peer_id,
+                    text: "Team out of sync, requesting newer version".to_string(),
+                });
+            }
+
+            if own_team_outdated {
+                network_handler.send_own_team(&app.world)?;
+                app.ui.swarm_panel.push_log_event(SwarmPanelEvent {
+                    timestamp,
+                    peer_id,
+                    text: "Peer has an outdated version of own team, sending it again".to_string(),
+                });
+            }
+            Ok(None)
+        })
+    }
+
+    fn handle_game_checksum_topic(
+        peer_id: Option<PeerId>,
+        timestamp: Tick,
+        game_checksum: GameChecksum,
+    ) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let network_handler = app
+                .network_handler
+                .as_mut()
+                .expect("The should be a network handler");
+
+            // Only the opponent in a game we are playing is relevant.
+            let Some(game) = app.world.get_game(game_checksum.game_id) else {
+                return Ok(None);
+            };
+            let peer_ids = [
+                game.home_team_in_game.peer_id,
+                game.away_team_in_game.peer_id,
+            ];
+            let local_peer_id = *network_handler.swarm.local_peer_id();
+            if game_checksum.sender_peer_id == local_peer_id
+                || !peer_ids.contains(&Some(game_checksum.sender_peer_id))
+                || !peer_ids.contains(&Some(local_peer_id))
+            {
+                return Ok(None);
+            }
+
+            let log = network_handler.game_checksums.entry(game.id).or_default();
+            log.record_remote(game_checksum.period, game_checksum.checksum);
+            if let Some(period) = log.check() {
+                app.ui.swarm_panel.push_log_event(SwarmPanelEvent {
+                    timestamp,
+                    peer_id,
+                    text: format!("Game {} out of sync at {}", game.id, period),
+                });
+                return Ok(Some(game_desync_message(game, period)));
+            }
             Ok(None)
         })
     }
 
+    fn handle_team_request_topic(timestamp: Tick, request: TeamRequest) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let network_handler = app
+                .network_handler
+                .as_mut()
+                .expect("The should be a network handler");
+
+            // Not for us, we do nothing.
+            if request.target_peer_id != *network_handler.swarm.local_peer_id() {
+                return Ok(None);
+            }
+
+            network_handler.send_own_team(&app.world)?;
+            app.ui.swarm_panel.push_log_event(SwarmPanelEvent {
+                timestamp,
+                peer_id: Some(request.sender_peer_id),
+                text: "Own team requested by peer".to_string(),
+            });
+            Ok(None)
+        })
+    }
+
+    fn handle_lobby_entry_topic(
+        peer_id: Option<PeerId>,
+        timestamp: Tick,
+        lobby_entry: LobbyEntry,
+    ) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            if let Some(id) = peer_id {
+                app.ui
+                    .swarm_panel
+                    .update_lobby_entry(id, timestamp, lobby_entry.clone());
+            }
+            Ok(None)
+        })
+    }
+
+    fn handle_direct_message_topic(
+        peer_id: Option<PeerId>,
+        timestamp: Tick,
+        message: DirectMessage,
+    ) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let self_peer_id = app
+                .network_handler
+                .as_ref()
+                .expect("The should be a network handler")
+                .swarm
+                .local_peer_id()
+                .clone();
+
+            // Not for us, we do nothing.
+            if message.target_peer_id != self_peer_id {
+                return Ok(None);
+            }
+
+            if peer_id != Some(message.sender_peer_id) {
+                return Err(anyhow!("Direct message sender does not match source"));
+            }
+
+            let event = SwarmPanelEvent {
+                timestamp,
+                peer_id: Some(message.sender_peer_id),
+                text: message.text.clone(),
+            };
+            app.ui
+                .swarm_panel
+                .push_direct_message_event(message.sender_peer_id, event);
+
+            Ok(Some(
+                "Direct message received.\nCheck the swarm panel".to_string(),
+            ))
+        })
+    }
+
     fn handle_message_topic(peer_id: Option<PeerId>, timestamp: Tick, text: String) -> AppCallback {
         Box::new(move |app: &mut App| {
             let event = SwarmPanelEvent {
@@ -255,6 +465,29 @@ impl NetworkCallback {
         })
     }
 
+    fn handle_crowd_chatter_topic(
+        peer_id: Option<PeerId>,
+        timestamp: Tick,
+        chatter: CrowdChatter,
+    ) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let event = SwarmPanelEvent {
+                timestamp,
+                peer_id,
+                text: format!("Got crowd chatter for game: {}", chatter.game_id),
+            };
+            app.ui.swarm_panel.push_log_event(event);
+
+            // Rate limiting is based on the time of reception,
+            // so that peers cannot get around it by faking the timestamp.
+            let mut chatter = chatter.clone();
+            chatter.peer_id = peer_id;
+            chatter.received_at = Tick::now();
+            app.world.add_crowd_chatter(chatter)?;
+            Ok(None)
+        })
+    }
+
     fn handle_trade_topic(peer_id: Option<PeerId>, timestamp: Tick, trade: Trade) -> AppCallback {
         Box::new(move |app: &mut App| {
             let event = SwarmPanelEvent {
@@ -289,6 +522,25 @@ impl NetworkCallback {
 
                     let own_team = app.world.get_own_team_mut()?;
                     own_team.add_received_trade(trade.clone());
+                    app.world.notify(
+                        Tick::now(),
+                        NotificationCategory::Trades,
+                        format!(
+                            "{}: {} for {}{}.",
+                            if trade.is_counter_offer {
+                                "Counter-offer"
+                            } else {
+                                "Trade offer"
+                            },
+                            trade.offered_assets(),
+                            trade.requested_assets(),
+                            match trade.extra_satoshis {
+                                0 => "".to_string(),
+                                x if x > 0 => format!(" plus {}", format_satoshi(x as u32)),
+                                x => format!(" asking {}", format_satoshi(x.unsigned_abs() as u32)),
+                            }
+                        ),
+                    );
 
                     return Ok(Some("Trade received.\nCheck the swarm panel".to_string()));
                 }
@@ -315,9 +567,12 @@ impl NetworkCallback {
                         // Note: here there are no consistency problems, as the swap is performed before the other team.
                         //       We are the proposer team, so we should have the last updated version of proposer_player
                         //       and we do not update it. We do update target_player using the version from the trade.
-                        app.world
-                            .players
-                            .insert(trade.target_player.id, trade.target_player.clone());
+                        for player in trade.proposer_bundle.players.iter_mut() {
+                            *player = app.world.get_player_or_err(player.id)?.clone();
+                        }
+                        for player in trade.target_players() {
+                            app.world.players.insert(player.id, player.clone());
+                        }
 
                         let own_team = app.world.get_own_team()?;
                         let target_team = app.world.get_team_or_err(
@@ -332,9 +587,49 @@ impl NetworkCallback {
                             &trade.target_player,
                             target_team,
                         )?;
-
+                        trade.can_be_settled_by(own_team, target_team)?;
+                        own_team.can_pay_trade_satoshis(trade.extra_satoshis)?;
+                        let target_team_id = target_team.id;
+                        let target_team_name = target_team.name.clone();
+
+                        let proposer_player_ids = trade
+                            .proposer_players()
+                            .iter()
+                            .map(|player| player.id)
+                            .collect::<Vec<_>>();
+                        let target_player_ids = trade
+                            .target_players()
+                            .iter()
+                            .map(|player| player.id)
+                            .collect::<Vec<_>>();
                         app.world
-                            .swap_players_team(trade.proposer_player.id, trade.target_player.id)?;
+                            .swap_player_groups(&proposer_player_ids, &target_player_ids)?;
+                        let own_team_id = app.world.own_team_id;
+                        app.world.settle_trade_resources(
+                            own_team_id,
+                            &trade.proposer_bundle.resources,
+                            &trade.target_bundle.resources,
+                            Tick::now(),
+                            format!("Trade with {}", target_team_name),
+                        )?;
+                        app.world.settle_trade_satoshis(
+                            own_team_id,
+                            -trade.extra_satoshis,
+                            Tick::now(),
+                            format!(
+                                "Trade sweetener for {}",
+                                trade.target_player.info.shortened_name()
+                            ),
+                        )?;
+                        app.world.emit_hook_event(HookEvent::team_trade(
+                            own_team_id,
+                            target_team_id,
+                            &proposer_player_ids,
+                            &target_player_ids,
+                            &trade.proposer_bundle.resources,
+                            &trade.target_bundle.resources,
+                            trade.extra_satoshis,
+                        ));
 
                         let own_team = app.world.get_own_team_mut()?;
                         own_team.remove_trade(trade.proposer_player.id, trade.target_player.id);
@@ -399,9 +694,9 @@ impl NetworkCallback {
                         // To avoid these sort of problems, receiving a team over the network gives an error if the incoming team
                         // contains a player which is currently part of the own team.
 
-                        app.world
-                            .players
-                            .insert(trade.proposer_player.id, trade.proposer_player.clone());
+                        for player in trade.proposer_players() {
+                            app.world.players.insert(player.id, player.clone());
+                        }
 
                         let own_team = app.world.get_own_team()?;
                         let proposer_team = app.world.get_team_or_err(
@@ -416,9 +711,49 @@ impl NetworkCallback {
                             &trade.target_player,
                             own_team,
                         )?;
-
+                        trade.can_be_settled_by(own_team, proposer_team)?;
+                        own_team.can_pay_trade_satoshis(-trade.extra_satoshis)?;
+                        let proposer_team_id = proposer_team.id;
+                        let proposer_team_name = proposer_team.name.clone();
+
+                        let proposer_player_ids = trade
+                            .proposer_players()
+                            .iter()
+                            .map(|player| player.id)
+                            .collect::<Vec<_>>();
+                        let target_player_ids = trade
+                            .target_players()
+                            .iter()
+                            .map(|player| player.id)
+                            .collect::<Vec<_>>();
                         app.world
-                            .swap_players_team(trade.proposer_player.id, trade.target_player.id)?;
+                            .swap_player_groups(&proposer_player_ids, &target_player_ids)?;
+                        let own_team_id = app.world.own_team_id;
+                        app.world.settle_trade_resources(
+                            own_team_id,
+                            &trade.target_bundle.resources,
+                            &trade.proposer_bundle.resources,
+                            Tick::now(),
+                            format!("Trade with {}", proposer_team_name),
+                        )?;
+                        app.world.settle_trade_satoshis(
+                            own_team_id,
+                            trade.extra_satoshis,
+                            Tick::now(),
+                            format!(
+                                "Trade sweetener for {}",
+                                trade.proposer_player.info.shortened_name()
+                            ),
+                        )?;
+                        app.world.emit_hook_event(HookEvent::team_trade(
+                            proposer_team_id,
+                            own_team_id,
+                            &proposer_player_ids,
+                            &target_player_ids,
+                            &trade.proposer_bundle.resources,
+                            &trade.target_bundle.resources,
+                            trade.extra_satoshis,
+                        ));
 
                         let own_team = app.world.get_own_team_mut()?;
                         own_team.remove_trade(trade.proposer_player.id, trade.target_player.id);
@@ -471,6 +806,211 @@ impl NetworkCallback {
         })
     }
 
+    // Applies the own team side of a resource trade and records the transaction.
+    fn settle_resource_trade(app: &mut App, trade: &ResourceTrade) -> AppResult<()> {
+        let own_team = app.world.get_own_team()?;
+        let counterpart_team = app
+            .world
+            .get_team_or_err(trade.counterpart_team_id(own_team.id))?;
+        trade.can_be_settled_by(own_team, counterpart_team)?;
+
+        let counterpart_name = counterpart_team.name.clone();
+        let own_team = app.world.get_own_team_mut()?;
+        let own_team_id = own_team.id;
+        trade.settle(own_team)?;
+        own_team.remove_resource_trade(trade.proposer_team_id, trade.target_team_id);
+
+        let sells = (own_team_id == trade.proposer_team_id) == trade.proposer_sells;
+        if let Some(kartoffel) = trade.kartoffel.as_ref() {
+            if sells {
+                if let Some(player_id) = app
+                    .world
+                    .kartoffeln
+                    .remove(&kartoffel.id)
+                    .and_then(|kartoffel| kartoffel.assigned_to)
+                {
+                    if let Some(player) = app.world.players.get_mut(&player_id) {
+                        player.companion_bonus = None;
+                    }
+                }
+            } else {
+                let mut kartoffel = kartoffel.clone();
+                kartoffel.team = Some(own_team_id);
+                kartoffel.peer_id = None;
+                kartoffel.assigned_to = None;
+                kartoffel.current_location = KartoffelLocation::WithTeam;
+                app.world.kartoffeln.insert(kartoffel.id, kartoffel);
+            }
+        }
+
+        let (amount, description) = if sells {
+            (
+                trade.satoshi as i64,
+                format!("Sold {} to {}", trade.goods(), counterpart_name),
+            )
+        } else {
+            (
+                -(trade.satoshi as i64),
+                format!("Bought {} from {}", trade.goods(), counterpart_name),
+            )
+        };
+        app.world.record_transaction(
+            own_team_id,
+            Tick::now(),
+            FinanceCategory::Trading,
+            amount,
+            description.clone(),
+        );
+        app.world.emit_hook_event(trade.hook_event());
+        app.world.dirty = true;
+        app.world.dirty_network = true;
+        app.world.dirty_ui = true;
+
+        app.ui.push_popup(PopupMessage::Ok {
+            message: format!("Resource trade accepted: {}.", description),
+            is_skippable: false,
+            tick: Tick::now(),
+        });
+        Ok(())
+    }
+
+    fn handle_resource_trade_topic(
+        peer_id: Option<PeerId>,
+        timestamp: Tick,
+        trade: ResourceTrade,
+    ) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let event = SwarmPanelEvent {
+                timestamp,
+                peer_id,
+                text: format!("\n{}", trade.format()),
+            };
+            app.ui.swarm_panel.push_log_event(event);
+
+            let self_peer_id = app
+                .network_handler
+                .as_ref()
+                .expect("The should be a network handler")
+                .swarm
+                .local_peer_id()
+                .clone();
+
+            match &trade.state {
+                NetworkRequestState::Syn => {
+                    if trade.proposer_peer_id == self_peer_id {
+                        return Err(anyhow!("Team is trade sender (should be receiver)"));
+                    }
+
+                    if trade.target_peer_id != self_peer_id {
+                        return Err(anyhow!("Team is not trade receiver"));
+                    }
+
+                    let own_team = app.world.get_own_team_mut()?;
+                    own_team.add_received_resource_trade(trade.clone());
+                    app.world.notify(
+                        Tick::now(),
+                        NotificationCategory::Trades,
+                        format!(
+                            "Resource trade offer: {} {} {} for {} satoshi.",
+                            trade.proposer_team_name,
+                            if trade.proposer_sells {
+                                "sells"
+                            } else {
+                                "buys"
+                            },
+                            trade.goods(),
+                            trade.satoshi
+                        ),
+                    );
+
+                    return Ok(Some(
+                        "Resource trade received.\nCheck the team panel".to_string(),
+                    ));
+                }
+                NetworkRequestState::SynAck => {
+                    if trade.target_peer_id == self_peer_id {
+                        return Err(anyhow!(
+                            "Invalid trade: team is trade receiver (should be sender)"
+                        ));
+                    }
+
+                    if trade.proposer_peer_id != self_peer_id {
+                        return Err(anyhow!("Invalid trade: team is not trade sender"));
+                    }
+
+                    // The proposer settles first and then lets the target settle its side.
+                    if let Err(err) = Self::settle_resource_trade(app, &trade) {
+                        let mut trade = trade.clone();
+                        trade.state = NetworkRequestState::Failed {
+                            error_message: err.to_string(),
+                        };
+                        app.network_handler
+                            .as_mut()
+                            .expect("The should be a network handler")
+                            .send_resource_trade(trade)?;
+                        return Err(anyhow!(err.to_string()));
+                    }
+
+                    let mut trade = trade.clone();
+                    trade.state = NetworkRequestState::Ack;
+                    app.network_handler
+                        .as_mut()
+                        .expect("The should be a network handler")
+                        .send_resource_trade(trade)?;
+                }
+                NetworkRequestState::Ack => {
+                    // Not team trade, we do nothing.
+                    if trade.proposer_peer_id != self_peer_id
+                        && trade.target_peer_id != self_peer_id
+                    {
+                        return Ok(None);
+                    }
+
+                    if trade.proposer_peer_id == self_peer_id {
+                        return Err(anyhow!("Team is trade sender (should be receiver)"));
+                    }
+
+                    // Only the proposer can confirm a trade, and only one we accepted.
+                    if peer_id != Some(trade.proposer_peer_id) {
+                        return Err(anyhow!("Invalid trade: not confirmed by the proposer"));
+                    }
+                    let is_accepted = app
+                        .world
+                        .get_own_team_mut()?
+                        .accepted_resource_trades
+                        .remove(&trade.proposer_team_id)
+                        .is_some_and(|accepted| accepted.is_same_offer(&trade));
+                    if !is_accepted {
+                        return Err(anyhow!("Invalid trade: it was not accepted"));
+                    }
+
+                    // Note: the proposer has already settled its side, so a failure here
+                    // leaves the two teams out of sync, as it happens for player trades.
+                    Self::settle_resource_trade(app, &trade)?;
+                }
+                NetworkRequestState::Failed { error_message } => {
+                    if trade.proposer_peer_id != self_peer_id
+                        && trade.target_peer_id != self_peer_id
+                    {
+                        return Err(anyhow!("Resource trade failed, but it's not our trade."));
+                    }
+
+                    let own_team = app.world.get_own_team_mut()?;
+                    own_team.remove_resource_trade(trade.proposer_team_id, trade.target_team_id);
+
+                    app.ui.push_popup(PopupMessage::Error {
+                        message: format!("Resource trade failed: {}", error_message),
+                        tick: Tick::now(),
+                    });
+
+                    return Err(anyhow!(format!("Resource trade failed: {}", error_message)))?;
+                }
+            }
+
+            Ok(None)
+        })
+    }
+
     fn handle_challenge_topic(
         peer_id: Option<PeerId>,
         timestamp: Tick,
@@ -489,6 +1029,24 @@ impl NetworkCallback {
             };
             app.ui.swarm_panel.push_log_event(event);
 
+            // Challenges carry the players that will take part in the game.
+            let mut challenge = challenge.clone();
+            for player in challenge
+                .home_team_in_game
+                .players
+                .values_mut()
+                .chain(challenge.away_team_in_game.players.values_mut())
+            {
+                for correction in validate_network_player(player) {
+                    let event = SwarmPanelEvent {
+                        timestamp,
+                        peer_id,
+                        text: format!("Invalid challenge data: {}", correction),
+                    };
+                    app.ui.swarm_panel.push_log_event(event);
+                }
+            }
+
             let self_peer_id = network_handler.swarm.local_peer_id();
             match &challenge.state {
                 NetworkRequestState::Syn => {
@@ -502,6 +1060,14 @@ impl NetworkCallback {
 
                     let own_team = app.world.get_own_team_mut()?;
                     own_team.add_received_challenge(challenge.clone());
+                    app.world.notify(
+                        Tick::now(),
+                        NotificationCategory::Challenges,
+                        format!(
+                            "{} challenged the crew to a game.",
+                            challenge.home_team_in_game.name
+                        ),
+                    );
 
                     return Ok(Some(
                         "Challenge received.\nCheck the swarm panel".to_string(),
@@ -531,9 +1097,25 @@ impl NetworkCallback {
 
                         let mut challenge = challenge.clone();
                         challenge.home_team_in_game = home_team_in_game;
-                        let starting_at = Tick::now() + NETWORK_GAME_START_DELAY;
                         challenge.state = NetworkRequestState::Ack;
-                        challenge.starting_at = Some(starting_at);
+                        let starting_at = challenge
+                            .starting_at
+                            .ok_or(anyhow!("Cannot generate game, starting_at not set"))?;
+
+                        // Reveal the seed committed to when sending the challenge.
+                        // Sent challenges are not saved, so after a restart the seed
+                        // is gone and the challenge fails instead of hanging.
+                        let home_seed_secret = app
+                            .world
+                            .get_own_team()?
+                            .sent_challenges
+                            .get(&challenge.away_team_in_game.team_id)
+                            .and_then(|sent_challenge| sent_challenge.home_seed_secret)
+                            .ok_or(anyhow!(
+                                "Challenge seed was lost (was the game restarted?), the challenge must be sent again"
+                            ))?;
+                        challenge.home_seed = Some(home_seed_secret);
+                        let seed = challenge.game_seed()?;
 
                         let event = SwarmPanelEvent {
                             timestamp,
@@ -546,6 +1128,7 @@ impl NetworkCallback {
                             challenge.home_team_in_game.clone(),
                             challenge.away_team_in_game.clone(),
                             starting_at,
+                            seed,
                         ) {
                             challenge.state = NetworkRequestState::Failed {
                                 error_message: err.to_string(),
@@ -598,6 +1181,7 @@ impl NetworkCallback {
                                 challenge.home_team_in_game.clone(),
                                 challenge.away_team_in_game.clone(),
                                 starting_at,
+                                challenge.game_seed()?,
                             )?;
                         } else {
                             return Err(anyhow!("Cannot generate game, starting_at not set"));
@@ -633,6 +1217,7 @@ impl NetworkCallback {
                                 challenge.home_team_in_game.clone(),
                                 challenge.away_team_in_game.clone(),
                                 starting_at,
+                                challenge.game_seed()?,
                             )?;
                         } else {
                             return Err(anyhow!("Cannot generate game, starting_at not set"));
@@ -682,6 +1267,86 @@ impl NetworkCallback {
         })
     }
 
+    fn handle_co_captain_order_topic(
+        peer_id: Option<PeerId>,
+        timestamp: Tick,
+        order: CoCaptainOrder,
+    ) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let network_handler = app
+                .network_handler
+                .as_mut()
+                .expect("The should be a network handler");
+
+            let event = SwarmPanelEvent {
+                timestamp,
+                peer_id,
+                text: order.format(),
+            };
+            app.ui.swarm_panel.push_log_event(event);
+
+            let self_peer_id = network_handler.swarm.local_peer_id();
+            match &order.state {
+                NetworkRequestState::Syn => {
+                    // Not our team, we do nothing.
+                    if order.owner_peer_id != *self_peer_id {
+                        return Ok(None);
+                    }
+
+                    if let Err(err) = app
+                        .world
+                        .apply_co_captain_order(&order, peer_id, Tick::now())
+                    {
+                        let mut order = order.clone();
+                        order.state = NetworkRequestState::Failed {
+                            error_message: err.to_string(),
+                        };
+                        network_handler.send_co_captain_order(order)?;
+                        return Err(anyhow!(err.to_string()));
+                    }
+
+                    let mut order = order.clone();
+                    order.state = NetworkRequestState::Ack;
+                    order.queue_version = app.world.get_own_team()?.action_queue_version;
+                    network_handler.send_co_captain_order(order)?;
+                    // Send the team right away so that the co-captain sees the updated queue.
+                    network_handler.send_own_team(&app.world)?;
+                }
+
+                NetworkRequestState::SynAck => {
+                    return Err(anyhow!("Invalid co-captain order state"));
+                }
+
+                NetworkRequestState::Ack => {
+                    if order.captain_peer_id != *self_peer_id {
+                        return Ok(None);
+                    }
+
+                    let description = order.action.description(&app.world);
+                    app.world.log_crew_event(
+                        Tick::now(),
+                        format!("Order accepted: {}", description.to_lowercase()),
+                    );
+                }
+
+                NetworkRequestState::Failed { error_message } => {
+                    if order.captain_peer_id != *self_peer_id {
+                        return Ok(None);
+                    }
+
+                    app.ui.push_popup(PopupMessage::Error {
+                        message: format!("Order refused: {}", error_message),
+                        tick: Tick::now(),
+                    });
+
+                    return Err(anyhow!("Order refused: {}", error_message));
+                }
+            }
+
+            Ok(None)
+        })
+    }
+
     pub fn call(&self, app: &mut App) -> AppResult<Option<String>> {
         match self {
             Self::PushSwarmPanelChat {
@@ -700,7 +1365,10 @@ impl NetworkCallback {
                 Self::unsubscribe(peer_id.clone(), topic.clone())(app)
             }
             Self::CloseConnection { peer_id } => Self::close_connection(peer_id.clone())(app),
-            Self::HandleConnectionEstablished { peer_id } => {
+            Self::HandleConnectionEstablished {
+                peer_id,
+                direct_dial,
+            } => {
                 let network_handler = app
                     .network_handler
                     .as_mut()
@@ -712,11 +1380,51 @@ impl NetworkCallback {
                     text: format!("Connected to peer: {}", peer_id),
                 };
                 app.ui.swarm_panel.push_log_event(event);
+
+                if let Some(address) = direct_dial {
+                    network_handler.address_book.record_connection(
+                        address.clone(),
+                        *peer_id,
+                        Tick::now(),
+                    );
+                    save_address_book(&network_handler.address_book)?;
+                    return Ok(Some(format!(
+                        "Connected to {}\nat {}\n\nThe address has been saved in the address book.",
+                        peer_id, address
+                    )));
+                }
                 Ok(None)
             }
+            Self::HandleDirectDialError { address, error } => Ok(Some(format!(
+                "Could not connect to {}\n\n{}",
+                address, error
+            ))),
             Self::HandleMessage { message } => {
                 let peer_id = message.source;
                 let network_data = serde_json::from_slice::<NetworkData>(&message.data)?;
+
+                // Blocked peers can still be seen in the world, but cannot reach the crew.
+                let is_blocked = match (peer_id, app.network_handler.as_ref()) {
+                    (Some(peer_id), Some(network_handler)) => {
+                        network_handler.address_book.is_blocked(&peer_id)
+                    }
+                    _ => false,
+                };
+                if is_blocked
+                    && matches!(
+                        network_data,
+                        NetworkData::Message(..)
+                            | NetworkData::DirectMessage(..)
+                            | NetworkData::Challenge(..)
+                            | NetworkData::Trade(..)
+                            | NetworkData::ResourceTrade(..)
+                            | NetworkData::CrowdChatter(..)
+                    )
+                {
+                    log::info!("Ignoring message from blocked peer {:?}", peer_id);
+                    return Ok(None);
+                }
+
                 match network_data {
                     NetworkData::Team(timestamp, team) => {
                         Self::handle_team_topic(peer_id, timestamp, team)(app)
@@ -736,6 +1444,30 @@ impl NetworkCallback {
                     NetworkData::SeedInfo(timestamp, seed_info) => {
                         Self::handle_seed_topic(peer_id, timestamp, seed_info)(app)
                     }
+                    NetworkData::CrowdChatter(timestamp, chatter) => {
+                        Self::handle_crowd_chatter_topic(peer_id, timestamp, chatter)(app)
+                    }
+                    NetworkData::CoCaptainOrder(timestamp, order) => {
+                        Self::handle_co_captain_order_topic(peer_id, timestamp, order)(app)
+                    }
+                    NetworkData::LobbyEntry(timestamp, lobby_entry) => {
+                        Self::handle_lobby_entry_topic(peer_id, timestamp, lobby_entry)(app)
+                    }
+                    NetworkData::DirectMessage(timestamp, message) => {
+                        Self::handle_direct_message_topic(peer_id, timestamp, message)(app)
+                    }
+                    NetworkData::ResourceTrade(timestamp, trade) => {
+                        Self::handle_resource_trade_topic(peer_id, timestamp, trade)(app)
+                    }
+                    NetworkData::StateChecksum(timestamp, state_checksum) => {
+                        Self::handle_state_checksum_topic(peer_id, timestamp, state_checksum)(app)
+                    }
+                    NetworkData::TeamRequest(timestamp, request) => {
+                        Self::handle_team_request_topic(timestamp, request)(app)
+                    }
+                    NetworkData::GameChecksum(timestamp, game_checksum) => {
+                        Self::handle_game_checksum_topic(peer_id, timestamp, game_checksum)(app)
+                    }
                 }
             }
         }
@@ -0,0 +1,287 @@
+use super::types::NetworkRequestState;
+use crate::types::{AppResult, ResourceMap, TeamId};
+use crate::world::{
+    hooks::HookEvent,
+    kartoffel::{Kartoffel, MAX_KARTOFFELN_PER_TEAM},
+    resources::Resource,
+    team::Team,
+};
+use anyhow::anyhow;
+use libp2p::PeerId;
+use serde::{Deserialize, Serialize};
+
+/// Offer to exchange an amount of a resource for satoshi with another team.
+/// Each side applies only its own part of the exchange when the trade is settled.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ResourceTrade {
+    pub state: NetworkRequestState,
+    pub proposer_peer_id: PeerId,
+    pub target_peer_id: PeerId,
+    pub proposer_team_id: TeamId,
+    pub proposer_team_name: String,
+    pub target_team_id: TeamId,
+    pub resource: Resource,
+    pub amount: u32,
+    pub satoshi: u32,
+    // If true, the proposer gives the resource and receives the satoshi.
+    pub proposer_sells: bool,
+    // Kartoffel sold by the proposer in place of the resource, if any.
+    #[serde(default)]
+    pub kartoffel: Option<Kartoffel>,
+}
+
+impl ResourceTrade {
+    pub fn new(
+        proposer_peer_id: PeerId,
+        proposer_team: &Team,
+        target_team: &Team,
+        resource: Resource,
+        amount: u32,
+        satoshi: u32,
+        proposer_sells: bool,
+    ) -> AppResult<Self> {
+        let target_peer_id = target_team
+            .peer_id
+            .ok_or(anyhow!("{} is not managed by a peer", target_team.name))?;
+
+        Ok(Self {
+            state: NetworkRequestState::Syn,
+            proposer_peer_id,
+            target_peer_id,
+            proposer_team_id: proposer_team.id,
+            proposer_team_name: proposer_team.name.clone(),
+            target_team_id: target_team.id,
+            resource,
+            amount,
+            satoshi,
+            proposer_sells,
+            kartoffel: None,
+        })
+    }
+
+    // Kartoffeln are sold by the proposer for satoshi, with no other resource involved.
+    pub fn with_kartoffel(mut self, kartoffel: Kartoffel) -> Self {
+        self.resource = Resource::SCRAPS;
+        self.amount = 0;
+        self.proposer_sells = true;
+        self.kartoffel = Some(kartoffel);
+        self
+    }
+
+    // Returns the description of the traded goods.
+    pub fn goods(&self) -> String {
+        match self.kartoffel.as_ref() {
+            Some(kartoffel) => format!("{} ({})", kartoffel.name, kartoffel.rarity),
+            None => format!("{} {}", self.amount, self.resource),
+        }
+    }
+
+    pub fn format(&self) -> String {
+        format!(
+            "Resource trade ({}): {} {} {} for {} satoshi",
+            self.state,
+            self.proposer_team_name,
+            if self.proposer_sells { "sells" } else { "buys" },
+            self.goods(),
+            self.satoshi
+        )
+    }
+
+    // True if the two trades carry the same offer, whatever their state.
+    pub fn is_same_offer(&self, other: &Self) -> bool {
+        let mut other = other.clone();
+        other.state = self.state.clone();
+        *self == other
+    }
+
+    pub fn counterpart_team_id(&self, team_id: TeamId) -> TeamId {
+        if team_id == self.proposer_team_id {
+            self.target_team_id
+        } else {
+            self.proposer_team_id
+        }
+    }
+
+    // Returns the resource and amount given and received by the team.
+    fn exchange(&self, team_id: TeamId) -> ((Resource, u32), (Resource, u32)) {
+        let goods = (self.resource, self.amount);
+        let payment = (Resource::SATOSHI, self.satoshi);
+        if (team_id == self.proposer_team_id) == self.proposer_sells {
+            (goods, payment)
+        } else {
+            (payment, goods)
+        }
+    }
+
+    pub fn is_valid(&self) -> AppResult<()> {
+        if self.resource == Resource::SATOSHI {
+            return Err(anyhow!("Cannot trade satoshi for satoshi"));
+        }
+
+        if let Some(kartoffel) = self.kartoffel.as_ref() {
+            if !self.proposer_sells || kartoffel.team != Some(self.proposer_team_id) {
+                return Err(anyhow!("Kartoffel can only be sold by its team"));
+            }
+        } else if self.amount == 0 {
+            return Err(anyhow!("Nothing to trade"));
+        }
+
+        Ok(())
+    }
+
+    pub fn can_be_settled_by(&self, team: &Team, counterpart_team: &Team) -> AppResult<()> {
+        self.is_valid()?;
+
+        if team.id != self.proposer_team_id && team.id != self.target_team_id {
+            return Err(anyhow!("Team is not part of the trade"));
+        }
+
+        if counterpart_team.id != self.counterpart_team_id(team.id) {
+            return Err(anyhow!("Wrong counterpart team"));
+        }
+
+        if let Some(kartoffel) = self.kartoffel.as_ref() {
+            if team.id == self.proposer_team_id {
+                if !team.kartoffel_ids.contains(&kartoffel.id) {
+                    return Err(anyhow!("{} is not in the team", kartoffel.name));
+                }
+            } else {
+                if team.kartoffel_ids.contains(&kartoffel.id) {
+                    return Err(anyhow!("{} is already in the team", kartoffel.name));
+                }
+                if team.kartoffel_ids.len() >= MAX_KARTOFFELN_PER_TEAM {
+                    return Err(anyhow!("Too many kartoffeln on board"));
+                }
+            }
+        }
+
+        let (given, received) = self.exchange(team.id);
+        team.can_exchange_resources(counterpart_team, given, received)
+    }
+
+    // Applies only the team side of the trade.
+    pub fn settle(&self, team: &mut Team) -> AppResult<()> {
+        let (given, received) = self.exchange(team.id);
+        team.exchange_resources(given, received)?;
+
+        if let Some(kartoffel) = self.kartoffel.as_ref() {
+            if team.id == self.proposer_team_id {
+                team.kartoffel_ids.retain(|&id| id != kartoffel.id);
+            } else {
+                team.kartoffel_ids.push(kartoffel.id);
+            }
+        }
+        Ok(())
+    }
+
+    // Kartoffeln are not resources, so only their price ends up in the event.
+    pub fn hook_event(&self) -> HookEvent {
+        let (given, received) = self.exchange(self.proposer_team_id);
+        let bundle = |(resource, amount): (Resource, u32)| -> ResourceMap {
+            (amount > 0)
+                .then_some((resource, amount))
+                .into_iter()
+                .collect()
+        };
+        HookEvent::team_trade(
+            self.proposer_team_id,
+            self.target_team_id,
+            &[],
+            &[],
+            &bundle(given),
+            &bundle(received),
+            0,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResourceTrade;
+    use crate::network::types::NetworkRequestState;
+    use crate::types::{AppResult, KartoffelId, PlanetId, StorableResourceMap, TeamId};
+    use crate::world::{kartoffel::Kartoffel, planet::Planet, resources::Resource, team::Team};
+    use libp2p::PeerId;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_resource_trade_settle() -> AppResult<()> {
+        let planet_id = PlanetId::new_v4();
+        let mut seller = Team::random(TeamId::new_v4(), planet_id, "A".into(), "A".into());
+        let mut buyer = Team::random(TeamId::new_v4(), planet_id, "B".into(), "B".into());
+        seller
+            .resources
+            .add(Resource::SCRAPS, 100, seller.storage_capacity())?;
+        buyer.peer_id = Some(PeerId::random());
+
+        let trade = ResourceTrade::new(
+            PeerId::random(),
+            &seller,
+            &buyer,
+            Resource::SCRAPS,
+            100,
+            3000,
+            true,
+        )?;
+        trade.can_be_settled_by(&seller, &buyer)?;
+        trade.can_be_settled_by(&buyer, &seller)?;
+
+        // A confirmation must carry the same offer that was accepted.
+        let mut ack = trade.clone();
+        ack.state = NetworkRequestState::Ack;
+        assert!(trade.is_same_offer(&ack));
+        ack.satoshi = 1;
+        assert!(!trade.is_same_offer(&ack));
+
+        let seller_balance = seller.balance();
+        let buyer_balance = buyer.balance();
+        trade.settle(&mut seller)?;
+        trade.settle(&mut buyer)?;
+        assert_eq!(seller.resources.value(&Resource::SCRAPS), 0);
+        assert_eq!(buyer.resources.value(&Resource::SCRAPS), 100);
+        assert_eq!(seller.balance(), seller_balance + 3000);
+        assert_eq!(buyer.balance(), buyer_balance - 3000);
+
+        // The seller has no scraps left, so the same trade cannot be settled again.
+        assert!(trade.can_be_settled_by(&seller, &buyer).is_err());
+        assert!(trade.settle(&mut seller).is_err());
+        assert_eq!(seller.balance(), seller_balance + 3000);
+        Ok(())
+    }
+
+    #[test]
+    fn test_kartoffel_trade_settle() -> AppResult<()> {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let planet = Planet::default();
+        let mut seller = Team::random(TeamId::new_v4(), planet.id, "A".into(), "A".into());
+        let mut buyer = Team::random(TeamId::new_v4(), planet.id, "B".into(), "B".into());
+        buyer.peer_id = Some(PeerId::random());
+
+        let mut kartoffel = Kartoffel::random(rng, KartoffelId::new_v4(), &planet);
+        kartoffel.team = Some(seller.id);
+        seller.kartoffel_ids.push(kartoffel.id);
+
+        let trade = ResourceTrade::new(
+            PeerId::random(),
+            &seller,
+            &buyer,
+            Resource::RUM,
+            0,
+            2000,
+            true,
+        )?
+        .with_kartoffel(kartoffel.clone());
+        trade.can_be_settled_by(&seller, &buyer)?;
+        trade.can_be_settled_by(&buyer, &seller)?;
+
+        trade.settle(&mut seller)?;
+        trade.settle(&mut buyer)?;
+        assert!(seller.kartoffel_ids.is_empty());
+        assert_eq!(buyer.kartoffel_ids, vec![kartoffel.id]);
+
+        // The kartoffel is not in the seller team anymore.
+        assert!(trade.can_be_settled_by(&seller, &buyer).is_err());
+        Ok(())
+    }
+}
@@ -1,8 +1,177 @@
 use super::types::NetworkRequestState;
-use crate::world::{player::Player, skill::Rated};
+use crate::types::{AppResult, PlayerId, ResourceMap, TeamId};
+use crate::world::{
+    constants::COST_PER_VALUE, player::Player, resources::Resource, skill::Rated, team::Team,
+};
+use anyhow::anyhow;
+use itertools::Itertools;
 use libp2p::PeerId;
 use serde::{Deserialize, Serialize};
 
+// Largest satoshi sweetener that can be asked in a counter-offer.
+pub const MAX_TRADE_SWEETENER: u32 = 250_000;
+// Largest number of players given by each side of a trade.
+pub const MAX_TRADE_BUNDLE_PLAYERS: usize = 3;
+// Share of the gap to the potential that is priced in for young players.
+const POTENTIAL_VALUE_WEIGHT: f32 = 0.5;
+
+// Estimated value of a player in a trade. The bare value already accounts
+// for age, young players are also worth part of their room for growth.
+pub fn trade_value(player: &Player) -> f32 {
+    let growth = (player.potential - player.average_skill()).max(0.0)
+        * (1.0 - player.info.relative_age()).clamp(0.0, 1.0);
+    player.bare_value() + POTENTIAL_VALUE_WEIGHT * growth
+}
+
+// Resources are valued at their base price, converted to player value.
+pub fn resources_value(resources: &ResourceMap) -> f32 {
+    resources
+        .iter()
+        .map(|(resource, amount)| resource.base_price() * *amount as f32)
+        .sum::<f32>()
+        / COST_PER_VALUE
+}
+
+// Fairness of the offered value against the requested one, between -1 and 1:
+// negative values mean the target gives away more than it gets.
+pub fn value_fairness(offered: f32, requested: f32) -> f32 {
+    let scale = offered.abs().max(requested.abs());
+    if scale == 0.0 {
+        return 0.0;
+    }
+    ((offered - requested) / scale).clamp(-1.0, 1.0)
+}
+
+// Satoshis to add to the offered value to match the requested one.
+pub fn value_sweetener(offered: f32, requested: f32) -> u32 {
+    ((requested - offered).max(0.0) * COST_PER_VALUE).ceil() as u32
+}
+
+// Fairness of the trade from the target point of view.
+// Positive extra satoshis are paid by the proposer to the target.
+pub fn trade_fairness(
+    proposer_player: &Player,
+    target_player: &Player,
+    extra_satoshis: i64,
+) -> f32 {
+    value_fairness(
+        trade_value(proposer_player) + extra_satoshis as f32 / COST_PER_VALUE,
+        trade_value(target_player),
+    )
+}
+
+// Satoshis the proposer should add to make the trade fair for the target.
+pub fn fair_sweetener(proposer_player: &Player, target_player: &Player) -> u32 {
+    value_sweetener(trade_value(proposer_player), trade_value(target_player))
+}
+
+/// Assets given by one side of a trade on top of its lead player.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TradeBundle {
+    pub players: Vec<Player>,
+    pub resources: ResourceMap,
+}
+
+impl TradeBundle {
+    pub fn is_empty(&self) -> bool {
+        self.players.is_empty() && self.resources.values().all(|amount| *amount == 0)
+    }
+
+    pub fn value(&self) -> f32 {
+        self.players.iter().map(trade_value).sum::<f32>() + resources_value(&self.resources)
+    }
+
+    pub fn format(&self) -> String {
+        self.players
+            .iter()
+            .map(|player| player.info.shortened_name())
+            .chain(
+                self.resources
+                    .iter()
+                    .filter(|(_, amount)| **amount > 0)
+                    .sorted_by_key(|(resource, _)| **resource as u8)
+                    .map(|(resource, amount)| format!("{} {}", amount, resource)),
+            )
+            .join(", ")
+    }
+}
+
+/// Assets picked on both sides of a trade before it is proposed.
+/// The first player of each side leads the trade.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TradeDraft {
+    pub target_team_id: TeamId,
+    pub proposer_player_ids: Vec<PlayerId>,
+    pub target_player_ids: Vec<PlayerId>,
+    pub proposer_resources: ResourceMap,
+    pub target_resources: ResourceMap,
+}
+
+impl TradeDraft {
+    pub fn new(target_team_id: TeamId) -> Self {
+        Self {
+            target_team_id,
+            proposer_player_ids: vec![],
+            target_player_ids: vec![],
+            proposer_resources: ResourceMap::new(),
+            target_resources: ResourceMap::new(),
+        }
+    }
+
+    pub fn toggle_player(&mut self, player_id: PlayerId, is_proposer_side: bool) -> AppResult<()> {
+        let player_ids = if is_proposer_side {
+            &mut self.proposer_player_ids
+        } else {
+            &mut self.target_player_ids
+        };
+
+        if let Some(index) = player_ids.iter().position(|id| *id == player_id) {
+            player_ids.remove(index);
+        } else if player_ids.len() >= MAX_TRADE_BUNDLE_PLAYERS {
+            return Err(anyhow!(
+                "Cannot trade more than {} players per side",
+                MAX_TRADE_BUNDLE_PLAYERS
+            ));
+        } else {
+            player_ids.push(player_id);
+        }
+        Ok(())
+    }
+
+    // Amounts never go below zero.
+    pub fn update_resource(&mut self, resource: Resource, amount: i32, is_proposer_side: bool) {
+        let resources = if is_proposer_side {
+            &mut self.proposer_resources
+        } else {
+            &mut self.target_resources
+        };
+        let current = resources.get(&resource).copied().unwrap_or_default();
+        let updated = (current as i64 + amount as i64).max(0) as u32;
+        if updated == 0 {
+            resources.remove(&resource);
+        } else {
+            resources.insert(resource, updated);
+        }
+    }
+
+    pub fn is_valid(&self) -> AppResult<()> {
+        if self.proposer_player_ids.is_empty() || self.target_player_ids.is_empty() {
+            return Err(anyhow!("Pick at least one player per side"));
+        }
+
+        if self.proposer_player_ids.len() > MAX_TRADE_BUNDLE_PLAYERS
+            || self.target_player_ids.len() > MAX_TRADE_BUNDLE_PLAYERS
+        {
+            return Err(anyhow!(
+                "Cannot trade more than {} players per side",
+                MAX_TRADE_BUNDLE_PLAYERS
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Trade {
     pub state: NetworkRequestState,
@@ -10,7 +179,16 @@ pub struct Trade {
     pub target_peer_id: PeerId,
     pub proposer_player: Player,
     pub target_player: Player,
+    // Paid by the proposer to the target if positive, by the target otherwise.
     pub extra_satoshis: i64,
+    // Set when the trade answers a previous offer with the players swapped.
+    #[serde(default)]
+    pub is_counter_offer: bool,
+    // Extra players and resources given on each side.
+    #[serde(default)]
+    pub proposer_bundle: TradeBundle,
+    #[serde(default)]
+    pub target_bundle: TradeBundle,
 }
 
 impl Trade {
@@ -28,17 +206,157 @@ impl Trade {
             proposer_player,
             target_player,
             extra_satoshis,
+            is_counter_offer: false,
+            proposer_bundle: TradeBundle::default(),
+            target_bundle: TradeBundle::default(),
+        }
+    }
+
+    pub fn with_bundles(
+        mut self,
+        proposer_bundle: TradeBundle,
+        target_bundle: TradeBundle,
+    ) -> Self {
+        self.proposer_bundle = proposer_bundle;
+        self.target_bundle = target_bundle;
+        self
+    }
+
+    pub fn is_bundle(&self) -> bool {
+        !self.proposer_bundle.is_empty() || !self.target_bundle.is_empty()
+    }
+
+    pub fn proposer_players(&self) -> Vec<&Player> {
+        std::iter::once(&self.proposer_player)
+            .chain(self.proposer_bundle.players.iter())
+            .collect()
+    }
+
+    pub fn target_players(&self) -> Vec<&Player> {
+        std::iter::once(&self.target_player)
+            .chain(self.target_bundle.players.iter())
+            .collect()
+    }
+
+    fn assets(player: &Player, bundle: &TradeBundle) -> String {
+        if bundle.is_empty() {
+            player.info.shortened_name()
+        } else {
+            format!("{}, {}", player.info.shortened_name(), bundle.format())
+        }
+    }
+
+    pub fn offered_assets(&self) -> String {
+        Self::assets(&self.proposer_player, &self.proposer_bundle)
+    }
+
+    pub fn requested_assets(&self) -> String {
+        Self::assets(&self.target_player, &self.target_bundle)
+    }
+
+    fn offered_value(&self) -> f32 {
+        trade_value(&self.proposer_player)
+            + self.proposer_bundle.value()
+            + self.extra_satoshis as f32 / COST_PER_VALUE
+    }
+
+    fn requested_value(&self) -> f32 {
+        trade_value(&self.target_player) + self.target_bundle.value()
+    }
+
+    pub fn fairness(&self) -> f32 {
+        value_fairness(self.offered_value(), self.requested_value())
+    }
+
+    // Satoshis the proposer should add to make the trade fair for the target.
+    pub fn fair_sweetener(&self) -> u32 {
+        value_sweetener(self.offered_value(), self.requested_value())
+    }
+
+    pub fn is_valid(&self) -> AppResult<()> {
+        let proposer_players = self.proposer_players();
+        let target_players = self.target_players();
+        if proposer_players.len() > MAX_TRADE_BUNDLE_PLAYERS
+            || target_players.len() > MAX_TRADE_BUNDLE_PLAYERS
+        {
+            return Err(anyhow!(
+                "Cannot trade more than {} players per side",
+                MAX_TRADE_BUNDLE_PLAYERS
+            ));
+        }
+
+        let all_players = proposer_players.iter().chain(target_players.iter());
+        if !all_players.map(|player| player.id).all_unique() {
+            return Err(anyhow!("Players can be traded only once"));
+        }
+
+        Ok(())
+    }
+
+    // Checks the side of the trade settled by the team, that is its roster
+    // and storage once the players and resources have been exchanged.
+    pub fn can_be_settled_by(&self, team: &Team, counterpart_team: &Team) -> AppResult<()> {
+        self.is_valid()?;
+
+        let (given_players, received_players, given_bundle, received_bundle) =
+            if self.proposer_player.team == Some(team.id) {
+                (
+                    self.proposer_players(),
+                    self.target_players(),
+                    &self.proposer_bundle,
+                    &self.target_bundle,
+                )
+            } else {
+                (
+                    self.target_players(),
+                    self.proposer_players(),
+                    &self.target_bundle,
+                    &self.proposer_bundle,
+                )
+            };
+
+        team.can_trade_bundle(
+            &given_players,
+            &received_players,
+            &given_bundle.resources,
+            &received_bundle.resources,
+            counterpart_team,
+        )
+    }
+
+    // Answers the trade from the target side, asking for a satoshi sweetener
+    // on top of the original players.
+    pub fn counter_offer(&self, sweetener: u32) -> Self {
+        Self {
+            state: NetworkRequestState::Syn,
+            proposer_peer_id: self.target_peer_id,
+            target_peer_id: self.proposer_peer_id,
+            proposer_player: self.target_player.clone(),
+            target_player: self.proposer_player.clone(),
+            extra_satoshis: -(sweetener.min(MAX_TRADE_SWEETENER) as i64),
+            is_counter_offer: true,
+            proposer_bundle: self.target_bundle.clone(),
+            target_bundle: self.proposer_bundle.clone(),
         }
     }
 
     pub fn format(&self) -> String {
+        let bundle_text = |bundle: &TradeBundle| {
+            if bundle.is_empty() {
+                "".to_string()
+            } else {
+                format!(" + {}", bundle.format())
+            }
+        };
         format!(
-            "Trade ({}): {} {} ⇄ {} {} {:+}",
+            "Trade ({}): {} {}{} ⇄ {} {}{} {:+}",
             self.state,
             self.proposer_player.info.shortened_name(),
             self.proposer_player.stars(),
+            bundle_text(&self.proposer_bundle),
             self.target_player.info.shortened_name(),
             self.target_player.stars(),
+            bundle_text(&self.target_bundle),
             self.extra_satoshis
         )
     }
@@ -46,12 +364,134 @@ impl Trade {
 
 #[cfg(test)]
 mod tests {
-    use super::Trade;
-    use crate::{app::App, types::AppResult};
+    use super::{
+        fair_sweetener, trade_fairness, Trade, TradeBundle, TradeDraft, MAX_TRADE_BUNDLE_PLAYERS,
+    };
+    use crate::{
+        app::App,
+        types::{AppResult, PlayerId, ResourceMap, TeamId},
+        world::{player::Player, resources::Resource, utils::PLANET_DATA},
+    };
     use libp2p::PeerId;
     use rand::SeedableRng;
     use rand_chacha::ChaCha8Rng;
 
+    #[test]
+    fn test_trade_fairness() -> AppResult<()> {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let weak_player = Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 0.0);
+        let strong_player = Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 10.0);
+
+        assert!(trade_fairness(&weak_player, &strong_player, 0) < 0.0);
+        assert!(trade_fairness(&strong_player, &weak_player, 0) > 0.0);
+        assert_eq!(trade_fairness(&weak_player, &weak_player, 0), 0.0);
+
+        let sweetener = fair_sweetener(&weak_player, &strong_player);
+        assert!(sweetener > 0);
+        assert!(trade_fairness(&weak_player, &strong_player, sweetener as i64) >= 0.0);
+        assert_eq!(fair_sweetener(&strong_player, &weak_player), 0);
+
+        let trade = Trade::new(
+            PeerId::random(),
+            PeerId::random(),
+            weak_player.clone(),
+            strong_player.clone(),
+            0,
+        );
+        let counter_offer = trade.counter_offer(sweetener);
+        assert!(counter_offer.is_counter_offer);
+        assert_eq!(counter_offer.proposer_peer_id, trade.target_peer_id);
+        assert_eq!(counter_offer.proposer_player.id, strong_player.id);
+        assert_eq!(counter_offer.extra_satoshis, -(sweetener as i64));
+        // The sweetener just covers the value gap for the original target.
+        assert!(counter_offer.fairness() <= 0.0);
+        assert!(counter_offer.fairness() > -0.01);
+        Ok(())
+    }
+
+    #[test]
+    fn test_trade_draft() -> AppResult<()> {
+        let mut draft = TradeDraft::new(TeamId::new_v4());
+        assert!(draft.is_valid().is_err());
+
+        let player_ids = (0..=MAX_TRADE_BUNDLE_PLAYERS)
+            .map(|_| PlayerId::new_v4())
+            .collect::<Vec<_>>();
+        for player_id in player_ids.iter().take(MAX_TRADE_BUNDLE_PLAYERS) {
+            draft.toggle_player(*player_id, true)?;
+        }
+        assert!(draft
+            .toggle_player(player_ids[MAX_TRADE_BUNDLE_PLAYERS], true)
+            .is_err());
+        draft.toggle_player(player_ids[0], true)?;
+        assert_eq!(
+            draft.proposer_player_ids.len(),
+            MAX_TRADE_BUNDLE_PLAYERS - 1
+        );
+        assert!(draft.is_valid().is_err());
+
+        draft.toggle_player(player_ids[0], false)?;
+        assert!(draft.is_valid().is_ok());
+
+        draft.update_resource(Resource::GOLD, 5, false);
+        draft.update_resource(Resource::GOLD, -10, false);
+        assert!(draft.target_resources.is_empty());
+        draft.update_resource(Resource::SATOSHI, 10_000, true);
+        assert_eq!(
+            draft.proposer_resources.get(&Resource::SATOSHI),
+            Some(&10_000)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_trade_bundle_fairness() -> AppResult<()> {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let player = Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 5.0);
+        let other_player = Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 5.0);
+
+        let trade = Trade::new(
+            PeerId::random(),
+            PeerId::random(),
+            player.clone(),
+            other_player.clone(),
+            0,
+        );
+        let satoshi = trade.fair_sweetener() + 100_000;
+        let trade = trade.with_bundles(
+            TradeBundle {
+                players: vec![],
+                resources: ResourceMap::from([(Resource::SATOSHI, satoshi)]),
+            },
+            TradeBundle::default(),
+        );
+        assert!(trade.is_bundle());
+        assert!(trade.fairness() > 0.0);
+        assert_eq!(trade.fair_sweetener(), 0);
+        assert!(trade.is_valid().is_ok());
+
+        let counter_offer = trade.counter_offer(0);
+        assert!(counter_offer.target_bundle == trade.proposer_bundle);
+        assert!(counter_offer.proposer_bundle.is_empty());
+
+        let invalid_trade = Trade::new(
+            PeerId::random(),
+            PeerId::random(),
+            player.clone(),
+            other_player.clone(),
+            0,
+        )
+        .with_bundles(
+            TradeBundle {
+                players: vec![other_player],
+                resources: ResourceMap::new(),
+            },
+            TradeBundle::default(),
+        );
+        assert!(invalid_trade.is_valid().is_err());
+        Ok(())
+    }
+
     #[ignore]
     #[test]
     fn test_trade() -> AppResult<()> {
@@ -1,11 +1,16 @@
 use super::challenge::Challenge;
+use super::checksum::{GameChecksum, StateChecksum, TeamRequest};
+use super::co_captain::CoCaptainOrder;
+use super::resource_trade::ResourceTrade;
 use super::trade::Trade;
 use crate::game_engine::timer::Timer;
-use crate::game_engine::types::GameStats;
+use crate::game_engine::types::{CrowdChatter, GameStats};
 use crate::types::{KartoffelId, PlanetId, Tick};
+use crate::world::constants::MIN_PLAYERS_PER_GAME;
 use crate::world::planet::{Planet, PlanetType};
 use crate::world::position::{Position, MAX_POSITION};
 use crate::world::skill::Skill;
+use crate::world::types::TeamLocation;
 use crate::{
     game_engine::types::TeamInGame,
     types::{AppResult, GameId, TeamId},
@@ -28,6 +33,14 @@ pub(crate) enum NetworkData {
     Message(Tick, String),
     Game(Tick, NetworkGame),
     SeedInfo(Tick, SeedInfo),
+    CrowdChatter(Tick, CrowdChatter),
+    CoCaptainOrder(Tick, CoCaptainOrder),
+    LobbyEntry(Tick, LobbyEntry),
+    DirectMessage(Tick, DirectMessage),
+    ResourceTrade(Tick, ResourceTrade),
+    StateChecksum(Tick, StateChecksum),
+    TeamRequest(Tick, TeamRequest),
+    GameChecksum(Tick, GameChecksum),
 }
 
 impl TryFrom<Vec<u8>> for NetworkData {
@@ -196,6 +209,61 @@ impl TeamRanking {
     }
 }
 
+/// Private message addressed to a single peer.
+/// Note: messages still travel on the shared topic and are only filtered by the receiver.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DirectMessage {
+    pub sender_peer_id: PeerId,
+    pub target_peer_id: PeerId,
+    pub text: String,
+}
+
+/// Short status of a team broadcast periodically to the network,
+/// used to populate the lobby of online teams.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LobbyEntry {
+    pub team_id: TeamId,
+    pub team_name: String,
+    pub rating: Skill,
+    pub reputation: Skill,
+    pub location: String,
+    pub accepts_challenges: bool,
+    #[serde(default)]
+    pub elo: Option<u32>,
+}
+
+impl LobbyEntry {
+    pub fn from_own_team(world: &World) -> AppResult<Self> {
+        let team = world.get_own_team()?;
+        let planet_name = |planet_id| {
+            world
+                .get_planet_or_err(planet_id)
+                .map(|planet| planet.name.clone())
+                .unwrap_or_default()
+        };
+        let location = match team.current_location {
+            TeamLocation::OnPlanet { planet_id } => planet_name(planet_id),
+            TeamLocation::Travelling { to, .. } => format!("Travelling to {}", planet_name(to)),
+            TeamLocation::Exploring { around, .. } | TeamLocation::OnSpaceAdventure { around } => {
+                format!("Exploring around {}", planet_name(around))
+            }
+        };
+
+        Ok(Self {
+            team_id: team.id,
+            team_name: team.name.clone(),
+            rating: world.team_rating(team.id)?,
+            reputation: team.reputation,
+            location,
+            accepts_challenges: team.current_game.is_none()
+                && team.is_on_planet().is_some()
+                && !team.is_on_strike()
+                && team.player_ids.len() >= MIN_PLAYERS_PER_GAME,
+            elo: team.elo,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SeedInfo {
     pub connected_peers_count: usize,
@@ -0,0 +1,196 @@
+use super::types::NetworkTeam;
+use crate::types::AppResult;
+use crate::world::{
+    constants::{
+        MAX_ENGINE_TUNING, MAX_MORALE, MAX_PLAYERS_PER_TEAM, MAX_TIREDNESS, MIN_ENGINE_TUNING,
+    },
+    player::Player,
+    resources::Resource,
+    skill::{MAX_SKILL, MIN_SKILL, SKILL_NAMES},
+};
+use anyhow::anyhow;
+
+// Returns the value clamped to the range, or None if it was already in it.
+fn clamped(value: f32, min: f32, max: f32) -> Option<f32> {
+    if value.is_nan() {
+        Some(min)
+    } else if value < min || value > max {
+        Some(value.clamp(min, max))
+    } else {
+        None
+    }
+}
+
+/// Clamps player stats received from the network to the values a player can
+/// reach in game. Returns a description of every correction.
+pub fn validate_network_player(player: &mut Player) -> Vec<String> {
+    let mut corrections = vec![];
+    let name = player.info.shortened_name();
+
+    for (idx, skill_name) in SKILL_NAMES.iter().enumerate() {
+        let value = player.skill_at_index(idx);
+        if let Some(bounded) = clamped(value, MIN_SKILL, MAX_SKILL) {
+            corrections.push(format!(
+                "{} {} clamped from {} to {}",
+                name, skill_name, value, bounded
+            ));
+            player.set_skill_at_index(idx, bounded);
+        }
+    }
+
+    for (stat_name, value, max) in [
+        ("potential", &mut player.potential, MAX_SKILL),
+        ("reputation", &mut player.reputation, MAX_SKILL),
+        ("tiredness", &mut player.tiredness, MAX_TIREDNESS),
+        ("morale", &mut player.morale, MAX_MORALE),
+    ] {
+        if let Some(bounded) = clamped(*value, MIN_SKILL, max) {
+            corrections.push(format!(
+                "{} {} clamped from {} to {}",
+                name, stat_name, value, bounded
+            ));
+            *value = bounded;
+        }
+    }
+
+    corrections
+}
+
+/// Sanity-checks a team received from the network. Values which cannot be
+/// reached in game are clamped, while inconsistent teams are rejected.
+/// Returns a description of every correction.
+pub fn validate_network_team(network_team: &mut NetworkTeam) -> AppResult<Vec<String>> {
+    let team = &mut network_team.team;
+
+    if network_team.players.len() > MAX_PLAYERS_PER_TEAM
+        || team.player_ids.len() > MAX_PLAYERS_PER_TEAM
+    {
+        return Err(anyhow!(
+            "Team {} has too many players ({})",
+            team.name,
+            network_team.players.len().max(team.player_ids.len())
+        ));
+    }
+
+    if network_team.players.len() != team.player_ids.len()
+        || network_team
+            .players
+            .iter()
+            .any(|player| !team.player_ids.contains(&player.id) || player.team != Some(team.id))
+    {
+        return Err(anyhow!(
+            "Team {} roster does not match its players",
+            team.name
+        ));
+    }
+
+    if team.used_storage_capacity() > team.storage_capacity() {
+        return Err(anyhow!(
+            "Team {} carries more than its storage capacity ({}/{})",
+            team.name,
+            team.used_storage_capacity(),
+            team.storage_capacity()
+        ));
+    }
+
+    let mut corrections = vec![];
+
+    if let Some(reputation) = clamped(team.reputation, MIN_SKILL, MAX_SKILL) {
+        corrections.push(format!(
+            "{} reputation clamped from {} to {}",
+            team.name, team.reputation, reputation
+        ));
+        team.reputation = reputation;
+    }
+
+    if team.fuel() > team.fuel_capacity() {
+        corrections.push(format!(
+            "{} fuel clamped from {} to {}",
+            team.name,
+            team.fuel(),
+            team.fuel_capacity()
+        ));
+        team.resources.insert(Resource::FUEL, team.fuel_capacity());
+    }
+
+    let spaceship = &mut team.spaceship;
+    if spaceship.current_durability() > spaceship.durability() {
+        corrections.push(format!(
+            "{} durability clamped from {} to {}",
+            spaceship.name,
+            spaceship.current_durability(),
+            spaceship.durability()
+        ));
+        spaceship.set_current_durability(spaceship.durability());
+    }
+
+    if !(MIN_ENGINE_TUNING..=MAX_ENGINE_TUNING).contains(&spaceship.engine_tuning) {
+        let engine_tuning = spaceship
+            .engine_tuning
+            .clamp(MIN_ENGINE_TUNING, MAX_ENGINE_TUNING);
+        corrections.push(format!(
+            "{} engine tuning clamped from {} to {}",
+            spaceship.name, spaceship.engine_tuning, engine_tuning
+        ));
+        spaceship.engine_tuning = engine_tuning;
+    }
+
+    for player in network_team.players.iter_mut() {
+        corrections.extend(validate_network_player(player));
+    }
+
+    Ok(corrections)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate_network_player, validate_network_team};
+    use crate::network::types::NetworkTeam;
+    use crate::types::{AppResult, PlanetId, PlayerId, TeamId};
+    use crate::world::{
+        constants::MAX_ENGINE_TUNING, player::Player, resources::Resource, skill::MAX_SKILL,
+        team::Team, utils::PLANET_DATA,
+    };
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_validate_network_team() -> AppResult<()> {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let mut team = Team::random(
+            TeamId::new_v4(),
+            PlanetId::new_v4(),
+            "Cheaters".into(),
+            "Ship".into(),
+        );
+        let mut player = Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 0.0);
+        player.team = Some(team.id);
+        team.player_ids.push(player.id);
+
+        let mut network_team = NetworkTeam::new(team.clone(), vec![player.clone()], None);
+        assert!(validate_network_team(&mut network_team)?.is_empty());
+
+        network_team.players[0].athletics.quickness = 99.0;
+        network_team.players[0].morale = f32::NAN;
+        network_team.team.spaceship.engine_tuning = 100;
+        let fuel_capacity = network_team.team.fuel_capacity();
+        network_team
+            .team
+            .resources
+            .insert(Resource::FUEL, fuel_capacity + 1);
+        assert_eq!(validate_network_team(&mut network_team)?.len(), 4);
+        assert_eq!(network_team.players[0].athletics.quickness, MAX_SKILL);
+        assert_eq!(network_team.players[0].morale, 0.0);
+        assert_eq!(network_team.team.spaceship.engine_tuning, MAX_ENGINE_TUNING);
+        assert_eq!(network_team.team.fuel(), fuel_capacity);
+        assert!(validate_network_player(&mut network_team.players[0]).is_empty());
+
+        // Players not in the roster cannot be smuggled in.
+        let mut stranger = Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 0.0);
+        stranger.team = Some(team.id);
+        let mut network_team = NetworkTeam::new(team.clone(), vec![player, stranger], None);
+        assert!(validate_network_team(&mut network_team).is_err());
+
+        Ok(())
+    }
+}
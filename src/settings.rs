@@ -0,0 +1,247 @@
+use crate::types::Tick;
+use crate::ui::constants::UiTheme;
+use crate::ui::player_sort::PlayerSort;
+use crate::world::constants::{TickInterval, MILLISECONDS, SECONDS};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+pub const MIN_UI_FPS: u8 = 1;
+pub const MAX_UI_FPS: u8 = 60;
+pub const DEFAULT_UI_FPS: u8 = 30;
+
+// The world can only advance by one TickInterval::SHORT per update,
+// so it must be updated at least that often to keep up with real time.
+pub const MIN_WORLD_TICK_INTERVAL: Tick = 1 * MILLISECONDS;
+pub const MAX_WORLD_TICK_INTERVAL: Tick = TickInterval::SHORT;
+pub const DEFAULT_WORLD_TICK_INTERVAL: Tick = 30 * MILLISECONDS;
+
+pub const MIN_NETWORK_SYNC_INTERVAL: Tick = 100 * MILLISECONDS;
+pub const MAX_NETWORK_SYNC_INTERVAL: Tick = 60 * SECONDS;
+pub const DEFAULT_NETWORK_SYNC_INTERVAL: Tick = 100 * MILLISECONDS;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Settings {
+    #[serde(flatten)]
+    pub tick: TickSettings,
+    // Periodically write the stream overlay files to the store directory.
+    #[serde(default)]
+    pub stream_overlay: bool,
+    // Directory where team data is exported. Defaults to the store directory.
+    #[serde(default)]
+    pub export_dir: Option<PathBuf>,
+    // Local port serving the JSON status endpoint for external dashboards.
+    // Only set from the command line, so that the endpoint is never opened unasked.
+    #[serde(skip)]
+    pub status_port: Option<u16>,
+    // Values given on the command line, which only last for the current session.
+    #[serde(skip)]
+    pub overrides: SettingsOverrides,
+    #[serde(default)]
+    pub ui_theme: UiTheme,
+    // Text descriptions instead of images and numbers instead of bars, for screen readers.
+    #[serde(default)]
+    pub accessible_mode: bool,
+    // Last sort chosen for the own team roster and for the players list.
+    #[serde(default)]
+    pub roster_sort: PlayerSort,
+    #[serde(default)]
+    pub player_list_sort: PlayerSort,
+}
+
+impl Settings {
+    pub fn bounded(&self) -> Self {
+        Self {
+            tick: self.tick.bounded(),
+            ..self.clone()
+        }
+    }
+
+    // Tick rates for this session, with the command line overrides applied.
+    pub fn tick(&self) -> TickSettings {
+        let mut tick = if self.overrides.low_power {
+            TickSettings::low_power()
+        } else {
+            self.tick
+        };
+        if let Some(ui_fps) = self.overrides.ui_fps {
+            tick.ui_fps = ui_fps;
+        }
+        if let Some(interval) = self.overrides.world_tick_interval {
+            tick.world_tick_interval = interval;
+        }
+        if let Some(interval) = self.overrides.network_sync_interval {
+            tick.network_sync_interval = interval;
+        }
+        tick.bounded()
+    }
+
+    pub fn stream_overlay(&self) -> bool {
+        self.overrides.stream_overlay.unwrap_or(self.stream_overlay)
+    }
+
+    pub fn export_dir(&self) -> Option<&Path> {
+        self.overrides
+            .export_dir
+            .as_deref()
+            .or(self.export_dir.as_deref())
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SettingsOverrides {
+    pub low_power: bool,
+    pub ui_fps: Option<u8>,
+    pub world_tick_interval: Option<Tick>,
+    pub network_sync_interval: Option<Tick>,
+    pub stream_overlay: Option<bool>,
+    pub export_dir: Option<PathBuf>,
+}
+
+// Flattened fields cannot be u128, so the intervals are stored as u64.
+mod tick_as_u64 {
+    use crate::types::Tick;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(tick: &Tick, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64((*tick).min(u64::MAX as Tick) as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Tick, D::Error> {
+        Ok(u64::deserialize(deserializer)? as Tick)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct TickSettings {
+    // Target frames per second for the UI refresh.
+    pub ui_fps: u8,
+    // Minimum time between two world updates.
+    #[serde(with = "tick_as_u64")]
+    pub world_tick_interval: Tick,
+    // Minimum time between two broadcasts of the own team to peers.
+    #[serde(with = "tick_as_u64")]
+    pub network_sync_interval: Tick,
+}
+
+impl Default for TickSettings {
+    fn default() -> Self {
+        Self {
+            ui_fps: DEFAULT_UI_FPS,
+            world_tick_interval: DEFAULT_WORLD_TICK_INTERVAL,
+            network_sync_interval: DEFAULT_NETWORK_SYNC_INTERVAL,
+        }
+    }
+}
+
+impl TickSettings {
+    pub fn low_power() -> Self {
+        Self {
+            ui_fps: 10,
+            world_tick_interval: 250 * MILLISECONDS,
+            network_sync_interval: 5 * SECONDS,
+        }
+    }
+
+    // Clamps all rates to safe bounds, so that a hand-edited settings file
+    // cannot stall the simulation or flood the network.
+    pub fn bounded(&self) -> Self {
+        Self {
+            ui_fps: self.ui_fps.clamp(MIN_UI_FPS, MAX_UI_FPS),
+            world_tick_interval: self
+                .world_tick_interval
+                .clamp(MIN_WORLD_TICK_INTERVAL, MAX_WORLD_TICK_INTERVAL),
+            network_sync_interval: self
+                .network_sync_interval
+                .clamp(MIN_NETWORK_SYNC_INTERVAL, MAX_NETWORK_SYNC_INTERVAL),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tick_settings_bounds() {
+        let settings = TickSettings {
+            ui_fps: 0,
+            world_tick_interval: 10 * SECONDS,
+            network_sync_interval: 0,
+        }
+        .bounded();
+
+        assert_eq!(settings.ui_fps, MIN_UI_FPS);
+        assert_eq!(settings.world_tick_interval, MAX_WORLD_TICK_INTERVAL);
+        assert_eq!(settings.network_sync_interval, MIN_NETWORK_SYNC_INTERVAL);
+
+        assert_eq!(TickSettings::default().bounded(), TickSettings::default());
+        assert_eq!(
+            TickSettings::low_power().bounded(),
+            TickSettings::low_power()
+        );
+    }
+
+    #[test]
+    fn test_settings_serialization() {
+        // Settings files written before the stream overlay was added must still load.
+        let settings: Settings = serde_json::from_str(
+            r#"{"ui_fps":20,"world_tick_interval":30,"network_sync_interval":100}"#,
+        )
+        .unwrap();
+        assert_eq!(settings.tick.ui_fps, 20);
+        assert!(!settings.stream_overlay);
+        assert!(!settings.accessible_mode);
+        assert!(settings.status_port.is_none());
+
+        let settings = Settings {
+            stream_overlay: true,
+            ..Default::default()
+        };
+        let serialized = serde_json::to_string(&settings).unwrap();
+        let deserialized: Settings = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, settings);
+
+        // The status endpoint is not persisted.
+        let settings = Settings {
+            status_port: Some(8080),
+            ..Default::default()
+        };
+        let serialized = serde_json::to_string(&settings).unwrap();
+        assert!(!serialized.contains("status_port"));
+        let settings: Settings =
+            serde_json::from_str(r#"{"ui_fps":20,"world_tick_interval":30,"network_sync_interval":100,"status_port":8080}"#)
+                .unwrap();
+        assert!(settings.status_port.is_none());
+    }
+
+    #[test]
+    fn test_settings_overrides() {
+        let mut settings = Settings {
+            stream_overlay: true,
+            ..Default::default()
+        };
+        settings.overrides = SettingsOverrides {
+            low_power: true,
+            ui_fps: Some(MAX_UI_FPS + 1),
+            stream_overlay: Some(false),
+            export_dir: Some(PathBuf::from("exports")),
+            ..Default::default()
+        };
+
+        assert_eq!(settings.tick().ui_fps, MAX_UI_FPS);
+        assert_eq!(
+            settings.tick().world_tick_interval,
+            TickSettings::low_power().world_tick_interval
+        );
+        assert!(!settings.stream_overlay());
+        assert_eq!(settings.export_dir(), Some(Path::new("exports")));
+
+        // Overrides are never written to the settings file.
+        let serialized = serde_json::to_string(&settings).unwrap();
+        let deserialized: Settings = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.tick, TickSettings::default());
+        assert!(deserialized.stream_overlay);
+        assert!(deserialized.export_dir.is_none());
+        assert_eq!(deserialized.overrides, SettingsOverrides::default());
+    }
+}
@@ -1,8 +1,9 @@
+use super::snapshot::EntitySnapshot;
 use super::space_callback::SpaceCallback;
 use super::visual_effects::VisualEffect;
 use super::{constants::*, traits::*};
 use crate::image::types::Gif;
-use crate::image::utils::open_image;
+use crate::image::utils::open_image_or_placeholder;
 use crate::register_impl;
 use crate::space_adventure::utils::{body_data_from_image, EntityState};
 use crate::world::resources::Resource;
@@ -13,6 +14,7 @@ use once_cell::sync::Lazy;
 use rand::seq::IteratorRandom;
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use strum::{Display, EnumIter, IntoEnumIterator};
 
@@ -33,7 +35,7 @@ static ASTEROID_IMAGE_DATA: Lazy<HashMap<(AsteroidSize, usize), (Gif, Vec<HitBox
                     size.to_string().to_ascii_lowercase(),
                     n_idx
                 );
-                let base_img = open_image(&path).expect("Should open asteroid image");
+                let base_img = open_image_or_placeholder(&path, 1, 1);
                 for rotation_idx in 0..MAX_ROTATION {
                     let image = match rotation_idx {
                         0 => base_img.clone(),
@@ -53,7 +55,9 @@ static ASTEROID_IMAGE_DATA: Lazy<HashMap<(AsteroidSize, usize), (Gif, Vec<HitBox
         data
     });
 
-#[derive(Default, Debug, Display, EnumIter, PartialEq, Eq, Clone, Copy, Hash)]
+#[derive(
+    Default, Debug, Display, EnumIter, PartialEq, Eq, Clone, Copy, Hash, Serialize, Deserialize,
+)]
 pub enum AsteroidSize {
     #[default]
     Huge,
@@ -176,6 +180,7 @@ impl Sprite for AsteroidEntity {
 
 register_impl!(!PlayerControlled for AsteroidEntity);
 register_impl!(!ResourceFragment for AsteroidEntity);
+register_impl!(!AiControlled for AsteroidEntity);
 
 impl Collider for AsteroidEntity {
     fn collision_damage(&self) -> f32 {
@@ -345,6 +350,15 @@ impl Entity for AsteroidEntity {
 
         vec![]
     }
+
+    fn snapshot(&self) -> Option<EntitySnapshot> {
+        Some(EntitySnapshot::Asteroid {
+            position: self.position.to_array(),
+            velocity: self.velocity.to_array(),
+            size: self.size,
+            durability: self.durability,
+        })
+    }
 }
 
 impl AsteroidEntity {
@@ -393,6 +407,11 @@ impl AsteroidEntity {
         self.durability
     }
 
+    pub fn with_durability(mut self, durability: f32) -> Self {
+        self.durability = durability.min(self.size.durability());
+        self
+    }
+
     pub fn add_damage(&mut self, damage: f32) {
         self.durability = (self.durability - damage).max(0.0);
     }
@@ -0,0 +1,372 @@
+use super::snapshot::EntitySnapshot;
+use super::space_callback::SpaceCallback;
+use super::utils::{body_data_from_image, EntityState};
+use super::visual_effects::VisualEffect;
+use super::{constants::*, traits::*};
+use crate::image::types::Gif;
+use crate::register_impl;
+use crate::world::resources::Resource;
+use crate::world::spaceship::SpaceshipPrefab;
+use glam::{I16Vec2, Vec2};
+use image::imageops::{resize, rotate270, FilterType};
+use image::{Rgba, RgbaImage};
+use once_cell::sync::Lazy;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashMap;
+
+const HIT_POINTS: f32 = 300.0;
+const THRUST: f32 = 12.0;
+const MAX_SPEED: f32 = 10.0;
+const VOLLEY_INTERVAL: f32 = 3.0;
+const VOLLEY_SIZE: usize = 5;
+// Angle between two projectiles of a volley, in radians.
+const VOLLEY_SPREAD: f32 = 0.2;
+const PROJECTILE_DAMAGE: f32 = 2.5;
+const PROJECTILE_SPEED: f32 = 35.0;
+// Distance kept from the right edge of the screen while patrolling.
+const PATROL_MARGIN: f32 = 10.0;
+const PATROL_AMPLITUDE: f32 = 0.3 * SCREEN_HEIGHT as f32;
+const PATROL_FREQUENCY: f32 = 0.25;
+
+// The mothership is drawn twice as big as a regular spaceship, facing left.
+static BOSS_IMAGE_DATA: Lazy<(Gif, Vec<HitBox>)> = Lazy::new(|| {
+    let base_gif = SpaceshipPrefab::Ragnarok
+        .spaceship("Kartoffel mothership".into())
+        .compose_image()
+        .unwrap_or_else(|_| vec![RgbaImage::from_pixel(16, 12, Rgba([125, 95, 35, 255]))]);
+
+    let mut gif = vec![];
+    let mut hit_boxes = vec![];
+    for base_image in base_gif.iter() {
+        let image = rotate270(base_image);
+        let image = resize(
+            &image,
+            2 * image.width(),
+            2 * image.height(),
+            FilterType::Nearest,
+        );
+        let (image, hit_box) = body_data_from_image(&image);
+        gif.push(image);
+        hit_boxes.push(hit_box);
+    }
+
+    (gif, hit_boxes)
+});
+
+/// The Kartoffel mothership, met only after collecting enough rumors while exploring.
+/// It patrols the right side of the screen, firing volleys at the player.
+#[derive(Debug)]
+pub struct BossEntity {
+    id: usize,
+    previous_position: Vec2,
+    position: Vec2,
+    velocity: Vec2,
+    acceleration: Vec2,
+    hit_points: f32,
+    volley_cooldown: f32,
+    volley_count: usize,
+    patrol_time: f32,
+    tick: usize,
+    visual_effects: VisualEffectMap,
+}
+
+impl Body for BossEntity {
+    fn previous_position(&self) -> I16Vec2 {
+        self.previous_position.as_i16vec2()
+    }
+
+    fn position(&self) -> I16Vec2 {
+        self.position.as_i16vec2()
+    }
+
+    fn velocity(&self) -> I16Vec2 {
+        self.velocity.as_i16vec2()
+    }
+
+    fn update_body(&mut self, deltatime: f32) -> Vec<SpaceCallback> {
+        self.tick += 1;
+        self.previous_position = self.position;
+
+        self.acceleration = self.acceleration - FRICTION_COEFF * self.velocity;
+        self.velocity += self.acceleration * deltatime;
+        self.velocity = self.velocity.clamp_length_max(MAX_SPEED);
+        self.position += self.velocity * deltatime;
+        self.acceleration = Vec2::ZERO;
+
+        let max_position =
+            Vec2::new(MAX_SCREEN_WIDTH as f32, SCREEN_HEIGHT as f32) - self.size().as_vec2();
+        self.position = self.position.clamp(Vec2::ZERO, max_position);
+
+        vec![]
+    }
+}
+
+impl Sprite for BossEntity {
+    fn layer(&self) -> usize {
+        1
+    }
+
+    fn image(&self) -> &RgbaImage {
+        &BOSS_IMAGE_DATA.0[self.frame()]
+    }
+
+    fn hit_box(&self) -> &HitBox {
+        &BOSS_IMAGE_DATA.1[self.frame()]
+    }
+
+    fn should_apply_visual_effects<'a>(&self) -> bool {
+        self.visual_effects.len() > 0
+    }
+
+    fn apply_visual_effects<'a>(&'a self, image: &'a RgbaImage) -> RgbaImage {
+        let mut image = image.clone();
+        for (effect, time) in self.visual_effects.iter() {
+            effect.apply(self, &mut image, *time);
+        }
+        image
+    }
+
+    fn add_visual_effect(&mut self, duration: f32, effect: VisualEffect) {
+        self.visual_effects.insert(effect, duration);
+    }
+
+    fn remove_visual_effect(&mut self, effect: &VisualEffect) {
+        self.visual_effects.remove(&effect);
+    }
+
+    fn update_sprite(&mut self, deltatime: f32) -> Vec<SpaceCallback> {
+        for (_, lifetime) in self.visual_effects.iter_mut() {
+            *lifetime -= deltatime;
+        }
+
+        self.visual_effects.retain(|_, lifetime| *lifetime > 0.0);
+
+        vec![]
+    }
+}
+
+impl Collider for BossEntity {
+    fn collision_damage(&self) -> f32 {
+        8.0
+    }
+
+    fn collider_type(&self) -> ColliderType {
+        ColliderType::Enemy
+    }
+}
+
+register_impl!(!PlayerControlled for BossEntity);
+register_impl!(!ResourceFragment for BossEntity);
+register_impl!(AiControlled for BossEntity);
+
+impl AiControlled for BossEntity {
+    fn update_ai(&mut self, target: I16Vec2, deltatime: f32) -> Vec<SpaceCallback> {
+        let center = self.center().as_vec2();
+        let target = target.as_vec2();
+
+        // Patrol up and down close to the right edge.
+        self.patrol_time += deltatime;
+        let patrol_position = Vec2::new(
+            SCREEN_WIDTH as f32 - PATROL_MARGIN - self.size().x as f32 / 2.0,
+            SCREEN_HEIGHT as f32 / 2.0
+                + PATROL_AMPLITUDE
+                    * (std::f32::consts::TAU * PATROL_FREQUENCY * self.patrol_time).sin(),
+        );
+        self.acceleration = (patrol_position - center).normalize_or_zero() * THRUST;
+
+        self.volley_cooldown = (self.volley_cooldown - deltatime).max(0.0);
+        if self.volley_cooldown > 0.0 {
+            return vec![];
+        }
+        self.volley_cooldown = VOLLEY_INTERVAL;
+        self.volley_count += 1;
+
+        // Volleys alternate between aiming at the target and sweeping straight ahead.
+        let direction = if self.volley_count % 2 == 0 {
+            (target - center).normalize_or(Vec2::NEG_X)
+        } else {
+            Vec2::NEG_X
+        };
+        let position = Vec2::new(self.position.x, center.y);
+
+        (0..VOLLEY_SIZE)
+            .map(|idx| {
+                let angle = (idx as f32 - (VOLLEY_SIZE - 1) as f32 / 2.0) * VOLLEY_SPREAD;
+                SpaceCallback::GenerateProjectile {
+                    shot_by_id: self.id(),
+                    position,
+                    velocity: Vec2::from_angle(angle).rotate(direction) * PROJECTILE_SPEED,
+                    color: Rgba([245, 185, 35, 255]),
+                    damage: PROJECTILE_DAMAGE,
+                }
+            })
+            .collect()
+    }
+}
+
+impl Entity for BossEntity {
+    fn set_id(&mut self, id: usize) {
+        self.id = id;
+    }
+
+    fn id(&self) -> usize {
+        self.id
+    }
+
+    fn handle_space_callback(&mut self, callback: SpaceCallback) -> Vec<SpaceCallback> {
+        match callback {
+            SpaceCallback::DamageEntity { damage, .. } => {
+                self.hit_points = (self.hit_points - damage).max(0.0);
+                if self.hit_points == 0.0 {
+                    return vec![SpaceCallback::DestroyEntity { id: self.id() }];
+                }
+
+                self.add_visual_effect(
+                    VisualEffect::COLOR_MASK_LIFETIME,
+                    VisualEffect::ColorMask {
+                        color: Rgba([255, 0, 0, 0]),
+                    },
+                );
+            }
+
+            SpaceCallback::DestroyEntity { .. } => {
+                let mut callbacks = self.loot();
+                callbacks.push(SpaceCallback::BossDefeated);
+                return callbacks;
+            }
+
+            _ => {}
+        }
+
+        vec![]
+    }
+
+    fn snapshot(&self) -> Option<EntitySnapshot> {
+        Some(EntitySnapshot::Boss {
+            position: self.position.to_array(),
+            hit_points: self.hit_points,
+        })
+    }
+}
+
+impl BossEntity {
+    fn frame(&self) -> usize {
+        self.tick % BOSS_IMAGE_DATA.0.len()
+    }
+
+    pub fn hit_points(&self) -> f32 {
+        self.hit_points
+    }
+
+    fn loot(&self) -> Vec<SpaceCallback> {
+        let position = self.center().as_vec2();
+        let rng = &mut ChaCha8Rng::from_entropy();
+        let mut callbacks = vec![];
+
+        for _ in 0..24 {
+            callbacks.push(SpaceCallback::GenerateParticle {
+                position,
+                velocity: Vec2::new(rng.gen_range(-5.5..5.5), rng.gen_range(-5.5..5.5)),
+                color: Rgba([
+                    205 + rng.gen_range(0..50),
+                    55 + rng.gen_range(0..150),
+                    rng.gen_range(0..55),
+                    255,
+                ]),
+                particle_state: EntityState::Decaying {
+                    lifetime: 2.5 + rng.gen_range(0.0..2.0),
+                },
+                layer: rng.gen_range(0..=2),
+            });
+        }
+
+        for _ in 0..12 {
+            callbacks.push(SpaceCallback::GenerateFragment {
+                position,
+                velocity: Vec2::new(rng.gen_range(-4.5..4.5), rng.gen_range(-4.5..4.5)),
+                resource: Resource::SCRAPS,
+                amount: rng.gen_range(2..=6),
+            });
+        }
+
+        for _ in 0..3 {
+            callbacks.push(SpaceCallback::GenerateFragment {
+                position,
+                velocity: Vec2::new(rng.gen_range(-3.5..3.5), rng.gen_range(-3.5..3.5)),
+                resource: Resource::GOLD,
+                amount: 1,
+            });
+        }
+
+        callbacks
+    }
+
+    pub fn new(position: Vec2) -> Self {
+        Self {
+            id: 0,
+            previous_position: position,
+            position,
+            velocity: Vec2::new(-MAX_SPEED, 0.0),
+            acceleration: Vec2::ZERO,
+            hit_points: HIT_POINTS,
+            volley_cooldown: VOLLEY_INTERVAL,
+            volley_count: 0,
+            patrol_time: 0.0,
+            tick: 0,
+            visual_effects: HashMap::new(),
+        }
+    }
+
+    pub fn with_hit_points(mut self, hit_points: f32) -> Self {
+        self.hit_points = hit_points;
+        self
+    }
+
+    pub fn new_at_screen_edge() -> Self {
+        Self::new(Vec2::new(
+            (SCREEN_WIDTH + 2) as f32,
+            SCREEN_HEIGHT as f32 / 3.0,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BossEntity, VOLLEY_INTERVAL, VOLLEY_SIZE};
+    use crate::space_adventure::{space_callback::SpaceCallback, traits::*};
+    use glam::I16Vec2;
+
+    #[test]
+    fn test_boss_volleys_and_defeat() {
+        let mut boss = BossEntity::new_at_screen_edge();
+        let target = I16Vec2::new(10, 40);
+
+        assert!(boss.update_ai(target, 0.1).is_empty());
+        let volley = boss.update_ai(target, VOLLEY_INTERVAL);
+        assert_eq!(volley.len(), VOLLEY_SIZE);
+        assert!(volley.iter().all(|callback| matches!(
+            callback,
+            SpaceCallback::GenerateProjectile { velocity, .. } if velocity.x < 0.0
+        )));
+
+        let callbacks = boss.handle_space_callback(SpaceCallback::DamageEntity {
+            id: 0,
+            damage: boss.hit_points() / 2.0,
+        });
+        assert!(callbacks.is_empty());
+
+        let callbacks = boss.handle_space_callback(SpaceCallback::DamageEntity {
+            id: 0,
+            damage: boss.hit_points(),
+        });
+        assert!(matches!(
+            callbacks[..],
+            [SpaceCallback::DestroyEntity { id: 0 }]
+        ));
+        let callbacks = boss.handle_space_callback(SpaceCallback::DestroyEntity { id: 0 });
+        assert!(callbacks
+            .iter()
+            .any(|callback| matches!(callback, SpaceCallback::BossDefeated)));
+    }
+}
@@ -0,0 +1,374 @@
+use super::snapshot::EntitySnapshot;
+use super::space_callback::SpaceCallback;
+use super::visual_effects::VisualEffect;
+use super::{constants::*, traits::*};
+use crate::image::utils::open_image_or_placeholder;
+use crate::register_impl;
+use crate::space_adventure::utils::{body_data_from_image, EntityState};
+use crate::world::resources::Resource;
+use glam::{I16Vec2, Vec2};
+use image::{Rgba, RgbaImage};
+use once_cell::sync::Lazy;
+use rand::distributions::WeightedIndex;
+use rand::prelude::Distribution;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+// Deposits are drawn once, veins and pockets as asteroids streaked with their resource.
+static DEPOSIT_IMAGE_DATA: Lazy<HashMap<DepositType, (RgbaImage, HitBox)>> = Lazy::new(|| {
+    let mut data = HashMap::new();
+
+    for deposit_type in DepositType::iter() {
+        let color = deposit_type.resource().color();
+        let image = match deposit_type {
+            DepositType::GoldVein | DepositType::FuelPocket => {
+                let mut image =
+                    open_image_or_placeholder("space_adventure/asteroid_big1.png", 1, 1);
+                for (x, y, pixel) in image.enumerate_pixels_mut() {
+                    if pixel[3] > 0 && (2 * x + 3 * y) % 7 == 0 {
+                        *pixel = color;
+                    }
+                }
+                image
+            }
+            DepositType::RumCrate => {
+                let border = Rgba([110, 70, 30, 255]);
+                let wood = Rgba([150, 100, 50, 255]);
+                RgbaImage::from_fn(7, 5, |x, y| {
+                    if x == 0 || x == 6 || y == 0 || y == 4 {
+                        border
+                    } else if y == 2 {
+                        color
+                    } else {
+                        wood
+                    }
+                })
+            }
+        };
+        data.insert(deposit_type, body_data_from_image(&image));
+    }
+
+    data
+});
+
+#[derive(Debug, Display, EnumIter, PartialEq, Eq, Clone, Copy, Hash, Serialize, Deserialize)]
+pub enum DepositType {
+    GoldVein,
+    FuelPocket,
+    RumCrate,
+}
+
+impl DepositType {
+    pub fn resource(&self) -> Resource {
+        match self {
+            Self::GoldVein => Resource::GOLD,
+            Self::FuelPocket => Resource::FUEL,
+            Self::RumCrate => Resource::RUM,
+        }
+    }
+
+    // Damage needed to mine the deposit out.
+    pub fn durability(&self) -> f32 {
+        match self {
+            Self::GoldVein => 30.0,
+            Self::FuelPocket => 10.0,
+            Self::RumCrate => 2.0,
+        }
+    }
+
+    // Rum crates float close to the planets, gold is found in the deep.
+    pub fn spawn_weight(&self, difficulty_level: u32) -> f32 {
+        let level = difficulty_level as f32;
+        match self {
+            Self::GoldVein => 0.5 * level,
+            Self::FuelPocket => 3.0,
+            Self::RumCrate => (6.0 - 0.5 * level).max(1.0),
+        }
+    }
+
+    pub fn random(difficulty_level: u32) -> Self {
+        let rng = &mut ChaCha8Rng::from_entropy();
+        let types = Self::iter().collect::<Vec<Self>>();
+        let weights = types
+            .iter()
+            .map(|deposit_type| deposit_type.spawn_weight(difficulty_level))
+            .collect::<Vec<f32>>();
+
+        match WeightedIndex::new(&weights) {
+            Ok(distribution) => types[distribution.sample(rng)],
+            Err(_) => Self::RumCrate,
+        }
+    }
+
+    // Fragments released once the deposit is mined out, as (count, amount range).
+    fn yield_fragments(&self) -> (usize, std::ops::RangeInclusive<u32>) {
+        match self {
+            Self::GoldVein => (3, 1..=1),
+            Self::FuelPocket => (4, 2..=5),
+            Self::RumCrate => (3, 1..=2),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DepositEntity {
+    id: usize,
+    previous_position: Vec2,
+    position: Vec2,
+    velocity: Vec2,
+    deposit_type: DepositType,
+    durability: f32,
+    visual_effects: VisualEffectMap,
+}
+
+impl Body for DepositEntity {
+    fn previous_position(&self) -> I16Vec2 {
+        self.previous_position.as_i16vec2()
+    }
+
+    fn position(&self) -> I16Vec2 {
+        self.position.as_i16vec2()
+    }
+
+    fn velocity(&self) -> I16Vec2 {
+        self.velocity.as_i16vec2()
+    }
+
+    fn update_body(&mut self, deltatime: f32) -> Vec<SpaceCallback> {
+        self.previous_position = self.position;
+        self.position = self.position + self.velocity * deltatime;
+
+        if self.position.x < 0.0 || self.position.x > MAX_SCREEN_WIDTH as f32 {
+            return vec![SpaceCallback::DestroyEntity { id: self.id() }];
+        }
+        if self.position.y < 0.0 || self.position.y > MAX_SCREEN_HEIGHT as f32 {
+            return vec![SpaceCallback::DestroyEntity { id: self.id() }];
+        }
+
+        vec![]
+    }
+}
+
+impl Sprite for DepositEntity {
+    fn layer(&self) -> usize {
+        1
+    }
+
+    fn image(&self) -> &RgbaImage {
+        &DEPOSIT_IMAGE_DATA
+            .get(&self.deposit_type)
+            .expect("Deposit image data should be available")
+            .0
+    }
+
+    fn hit_box(&self) -> &HitBox {
+        &DEPOSIT_IMAGE_DATA
+            .get(&self.deposit_type)
+            .expect("Deposit image data should be available")
+            .1
+    }
+
+    fn should_apply_visual_effects<'a>(&self) -> bool {
+        self.visual_effects.len() > 0
+    }
+
+    fn apply_visual_effects<'a>(&'a self, image: &'a RgbaImage) -> RgbaImage {
+        let mut image = image.clone();
+        for (effect, time) in self.visual_effects.iter() {
+            effect.apply(self, &mut image, *time);
+        }
+        image
+    }
+
+    fn add_visual_effect(&mut self, duration: f32, effect: VisualEffect) {
+        self.visual_effects.insert(effect, duration);
+    }
+
+    fn remove_visual_effect(&mut self, effect: &VisualEffect) {
+        self.visual_effects.remove(&effect);
+    }
+
+    fn update_sprite(&mut self, deltatime: f32) -> Vec<SpaceCallback> {
+        for (_, lifetime) in self.visual_effects.iter_mut() {
+            *lifetime -= deltatime;
+        }
+
+        self.visual_effects.retain(|_, lifetime| *lifetime > 0.0);
+
+        vec![]
+    }
+}
+
+register_impl!(!PlayerControlled for DepositEntity);
+register_impl!(!ResourceFragment for DepositEntity);
+register_impl!(!AiControlled for DepositEntity);
+
+impl Collider for DepositEntity {
+    fn collision_damage(&self) -> f32 {
+        match self.deposit_type {
+            DepositType::RumCrate => 0.2,
+            _ => 2.0,
+        }
+    }
+
+    // Deposits are mined like asteroids, by shooting or ramming them.
+    fn collider_type(&self) -> ColliderType {
+        ColliderType::Asteroid
+    }
+}
+
+impl Entity for DepositEntity {
+    fn set_id(&mut self, id: usize) {
+        self.id = id;
+    }
+
+    fn id(&self) -> usize {
+        self.id
+    }
+
+    fn handle_space_callback(&mut self, callback: SpaceCallback) -> Vec<SpaceCallback> {
+        match callback {
+            SpaceCallback::DamageEntity { damage, .. } => {
+                self.durability = (self.durability - damage).max(0.0);
+                if self.durability == 0.0 {
+                    return vec![SpaceCallback::DestroyEntity { id: self.id() }];
+                }
+
+                self.add_visual_effect(
+                    VisualEffect::COLOR_MASK_LIFETIME,
+                    VisualEffect::ColorMask {
+                        color: Rgba([255, 0, 0, 0]),
+                    },
+                );
+
+                // Chips of resource show the mining progress.
+                let rng = &mut ChaCha8Rng::from_entropy();
+                return vec![SpaceCallback::GenerateParticle {
+                    position: self.center().as_vec2(),
+                    velocity: Vec2::new(rng.gen_range(-3.5..3.5), rng.gen_range(-3.5..3.5)),
+                    color: self.deposit_type.resource().color(),
+                    particle_state: EntityState::Decaying {
+                        lifetime: 1.0 + rng.gen_range(0.0..1.0),
+                    },
+                    layer: rng.gen_range(0..=2),
+                }];
+            }
+
+            // Leaving the screen is not mining it out.
+            SpaceCallback::DestroyEntity { .. } if self.durability > 0.0 => {}
+
+            SpaceCallback::DestroyEntity { .. } => {
+                let position = self.center().as_vec2();
+                let rng = &mut ChaCha8Rng::from_entropy();
+                let (count, amount) = self.deposit_type.yield_fragments();
+                return (0..count)
+                    .map(|_| SpaceCallback::GenerateFragment {
+                        position,
+                        velocity: Vec2::new(rng.gen_range(-3.5..3.5), rng.gen_range(-3.5..3.5)),
+                        resource: self.deposit_type.resource(),
+                        amount: rng.gen_range(amount.clone()),
+                    })
+                    .collect();
+            }
+
+            _ => {}
+        }
+
+        vec![]
+    }
+
+    fn snapshot(&self) -> Option<EntitySnapshot> {
+        Some(EntitySnapshot::Deposit {
+            position: self.position.to_array(),
+            velocity: self.velocity.to_array(),
+            deposit_type: self.deposit_type,
+            durability: self.durability,
+        })
+    }
+}
+
+impl DepositEntity {
+    pub fn new(position: Vec2, velocity: Vec2, deposit_type: DepositType) -> Self {
+        Self {
+            id: 0,
+            previous_position: position,
+            position,
+            velocity,
+            deposit_type,
+            durability: deposit_type.durability(),
+            visual_effects: HashMap::new(),
+        }
+    }
+
+    pub fn new_at_screen_edge(difficulty_level: u32) -> Self {
+        let rng = &mut ChaCha8Rng::from_entropy();
+        let x = (SCREEN_WIDTH + 2) as f32;
+        let y = rng.gen_range(0.15 * SCREEN_HEIGHT as f32..0.85 * SCREEN_HEIGHT as f32);
+        let velocity = Vec2::new(rng.gen_range(-1.0..-0.4), rng.gen_range(-0.1..0.1));
+
+        Self::new(
+            Vec2::new(x, y),
+            velocity,
+            DepositType::random(difficulty_level),
+        )
+    }
+
+    pub fn with_durability(mut self, durability: f32) -> Self {
+        self.durability = durability.min(self.deposit_type.durability());
+        self
+    }
+
+    pub fn deposit_type(&self) -> DepositType {
+        self.deposit_type
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DepositEntity, DepositType};
+    use crate::space_adventure::{space_callback::SpaceCallback, traits::*};
+    use glam::Vec2;
+
+    #[test]
+    fn test_deposit_mining() {
+        let mut deposit =
+            DepositEntity::new(Vec2::new(10.0, 10.0), Vec2::ZERO, DepositType::FuelPocket);
+
+        // Leaving the screen before being mined does not release anything.
+        let callbacks = deposit.handle_space_callback(SpaceCallback::DestroyEntity { id: 0 });
+        assert!(callbacks.is_empty());
+
+        let callbacks =
+            deposit.handle_space_callback(SpaceCallback::DamageEntity { id: 0, damage: 1.0 });
+        assert!(matches!(
+            callbacks[..],
+            [SpaceCallback::GenerateParticle { .. }]
+        ));
+
+        let callbacks = deposit.handle_space_callback(SpaceCallback::DamageEntity {
+            id: 0,
+            damage: DepositType::FuelPocket.durability(),
+        });
+        assert!(matches!(
+            callbacks[..],
+            [SpaceCallback::DestroyEntity { id: 0 }]
+        ));
+
+        let callbacks = deposit.handle_space_callback(SpaceCallback::DestroyEntity { id: 0 });
+        assert!(!callbacks.is_empty());
+        assert!(callbacks.iter().all(|callback| matches!(
+            callback,
+            SpaceCallback::GenerateFragment { resource, .. } if *resource == deposit.deposit_type().resource()
+        )));
+    }
+
+    #[test]
+    fn test_deposit_spawn_distribution() {
+        // Gold is rare close to the planet, and gets more common further away.
+        assert!(DepositType::GoldVein.spawn_weight(1) < DepositType::RumCrate.spawn_weight(1));
+        assert!(DepositType::GoldVein.spawn_weight(10) > DepositType::RumCrate.spawn_weight(10));
+    }
+}
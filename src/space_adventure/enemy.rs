@@ -0,0 +1,373 @@
+use super::snapshot::EntitySnapshot;
+use super::space_callback::SpaceCallback;
+use super::utils::{body_data_from_image, EntityState};
+use super::visual_effects::VisualEffect;
+use super::{constants::*, traits::*};
+use crate::image::types::Gif;
+use crate::register_impl;
+use crate::world::resources::Resource;
+use crate::world::spaceship::SpaceshipPrefab;
+use glam::{I16Vec2, Vec2};
+use image::imageops::rotate270;
+use image::{Rgba, RgbaImage};
+use once_cell::sync::Lazy;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashMap;
+
+const BASE_HIT_POINTS: f32 = 12.0;
+const BASE_THRUST: f32 = 18.0;
+const BASE_MAX_SPEED: f32 = 14.0;
+const BASE_SHOOTING_INTERVAL: f32 = 2.5;
+const BASE_PROJECTILE_DAMAGE: f32 = 1.0;
+const PROJECTILE_SPEED: f32 = 45.0;
+// Horizontal distance kept from the target while engaging it.
+const ENGAGE_DISTANCE: f32 = 70.0;
+// Vertical distance from the target within which the enemy opens fire.
+const FIRING_WINDOW: f32 = 20.0;
+
+// Pirate ships are facing left, towards the player.
+static ENEMY_IMAGE_DATA: Lazy<(Gif, Vec<HitBox>)> = Lazy::new(|| {
+    let base_gif = SpaceshipPrefab::Pincher
+        .spaceship("Pirate".into())
+        .compose_image()
+        .unwrap_or_else(|_| vec![RgbaImage::from_pixel(8, 6, Rgba([155, 25, 25, 255]))]);
+
+    let mut gif = vec![];
+    let mut hit_boxes = vec![];
+    for base_image in base_gif.iter() {
+        let (image, hit_box) = body_data_from_image(&rotate270(base_image));
+        gif.push(image);
+        hit_boxes.push(hit_box);
+    }
+
+    (gif, hit_boxes)
+});
+
+/// Pirate ship hunting the player. Its strength grows with the difficulty level.
+#[derive(Debug)]
+pub struct EnemyShipEntity {
+    id: usize,
+    level: u32,
+    previous_position: Vec2,
+    position: Vec2,
+    velocity: Vec2,
+    acceleration: Vec2,
+    hit_points: f32,
+    shooting_cooldown: f32,
+    tick: usize,
+    visual_effects: VisualEffectMap,
+}
+
+impl Body for EnemyShipEntity {
+    fn previous_position(&self) -> I16Vec2 {
+        self.previous_position.as_i16vec2()
+    }
+
+    fn position(&self) -> I16Vec2 {
+        self.position.as_i16vec2()
+    }
+
+    fn velocity(&self) -> I16Vec2 {
+        self.velocity.as_i16vec2()
+    }
+
+    fn update_body(&mut self, deltatime: f32) -> Vec<SpaceCallback> {
+        self.tick += 1;
+        self.previous_position = self.position;
+
+        self.acceleration = self.acceleration - FRICTION_COEFF * self.velocity;
+        self.velocity += self.acceleration * deltatime;
+        self.velocity = self.velocity.clamp_length_max(self.max_speed());
+        self.position += self.velocity * deltatime;
+        self.acceleration = Vec2::ZERO;
+
+        // Enemies enter from the right edge, and then stay on screen until destroyed.
+        let max_position =
+            Vec2::new(MAX_SCREEN_WIDTH as f32, SCREEN_HEIGHT as f32) - self.size().as_vec2();
+        self.position = self.position.clamp(Vec2::ZERO, max_position);
+
+        vec![]
+    }
+}
+
+impl Sprite for EnemyShipEntity {
+    fn layer(&self) -> usize {
+        1
+    }
+
+    fn image(&self) -> &RgbaImage {
+        &ENEMY_IMAGE_DATA.0[self.frame()]
+    }
+
+    fn hit_box(&self) -> &HitBox {
+        &ENEMY_IMAGE_DATA.1[self.frame()]
+    }
+
+    fn should_apply_visual_effects<'a>(&self) -> bool {
+        self.visual_effects.len() > 0
+    }
+
+    fn apply_visual_effects<'a>(&'a self, image: &'a RgbaImage) -> RgbaImage {
+        let mut image = image.clone();
+        for (effect, time) in self.visual_effects.iter() {
+            effect.apply(self, &mut image, *time);
+        }
+        image
+    }
+
+    fn add_visual_effect(&mut self, duration: f32, effect: VisualEffect) {
+        self.visual_effects.insert(effect, duration);
+    }
+
+    fn remove_visual_effect(&mut self, effect: &VisualEffect) {
+        self.visual_effects.remove(&effect);
+    }
+
+    fn update_sprite(&mut self, deltatime: f32) -> Vec<SpaceCallback> {
+        for (_, lifetime) in self.visual_effects.iter_mut() {
+            *lifetime -= deltatime;
+        }
+
+        self.visual_effects.retain(|_, lifetime| *lifetime > 0.0);
+
+        vec![]
+    }
+}
+
+impl Collider for EnemyShipEntity {
+    fn collision_damage(&self) -> f32 {
+        2.0 * self.level_multiplier()
+    }
+
+    fn collider_type(&self) -> ColliderType {
+        ColliderType::Enemy
+    }
+}
+
+register_impl!(!PlayerControlled for EnemyShipEntity);
+register_impl!(!ResourceFragment for EnemyShipEntity);
+register_impl!(AiControlled for EnemyShipEntity);
+
+impl AiControlled for EnemyShipEntity {
+    fn update_ai(&mut self, target: I16Vec2, deltatime: f32) -> Vec<SpaceCallback> {
+        let center = self.center().as_vec2();
+        let target = target.as_vec2();
+
+        // Steer towards a spot in front of the target, so to face it while shooting.
+        let engage_position = Vec2::new(target.x + ENGAGE_DISTANCE, target.y);
+        self.acceleration = (engage_position - center).normalize_or_zero() * self.thrust();
+
+        self.shooting_cooldown = (self.shooting_cooldown - deltatime).max(0.0);
+        if self.shooting_cooldown > 0.0
+            || target.x > center.x
+            || (target.y - center.y).abs() > FIRING_WINDOW
+        {
+            return vec![];
+        }
+
+        self.shooting_cooldown = self.shooting_interval();
+        let position = Vec2::new(self.position.x, center.y);
+        vec![SpaceCallback::GenerateProjectile {
+            shot_by_id: self.id(),
+            position,
+            velocity: (target - position).normalize_or_zero() * PROJECTILE_SPEED,
+            color: Rgba([225, 35, 35, 255]),
+            damage: BASE_PROJECTILE_DAMAGE * self.level_multiplier(),
+        }]
+    }
+}
+
+impl Entity for EnemyShipEntity {
+    fn set_id(&mut self, id: usize) {
+        self.id = id;
+    }
+
+    fn id(&self) -> usize {
+        self.id
+    }
+
+    fn handle_space_callback(&mut self, callback: SpaceCallback) -> Vec<SpaceCallback> {
+        match callback {
+            SpaceCallback::DamageEntity { damage, .. } => {
+                self.hit_points = (self.hit_points - damage).max(0.0);
+                if self.hit_points == 0.0 {
+                    return vec![SpaceCallback::DestroyEntity { id: self.id() }];
+                }
+
+                self.add_visual_effect(
+                    VisualEffect::COLOR_MASK_LIFETIME,
+                    VisualEffect::ColorMask {
+                        color: Rgba([255, 0, 0, 0]),
+                    },
+                );
+            }
+
+            SpaceCallback::DestroyEntity { .. } => return self.loot(),
+
+            _ => {}
+        }
+
+        vec![]
+    }
+
+    fn snapshot(&self) -> Option<EntitySnapshot> {
+        Some(EntitySnapshot::Enemy {
+            position: self.position.to_array(),
+            level: self.level,
+            hit_points: self.hit_points,
+        })
+    }
+}
+
+impl EnemyShipEntity {
+    fn frame(&self) -> usize {
+        self.tick % ENEMY_IMAGE_DATA.0.len()
+    }
+
+    fn level_multiplier(&self) -> f32 {
+        1.0 + 0.5 * (self.level.max(1) - 1) as f32
+    }
+
+    fn thrust(&self) -> f32 {
+        BASE_THRUST * self.level_multiplier().sqrt()
+    }
+
+    fn max_speed(&self) -> f32 {
+        BASE_MAX_SPEED * self.level_multiplier().sqrt()
+    }
+
+    fn shooting_interval(&self) -> f32 {
+        BASE_SHOOTING_INTERVAL / self.level_multiplier().sqrt()
+    }
+
+    pub fn hit_points(&self) -> f32 {
+        self.hit_points
+    }
+
+    // Wreckage left behind when the ship is destroyed. Stronger pirates carry more gold.
+    fn loot(&self) -> Vec<SpaceCallback> {
+        let position = self.center().as_vec2();
+        let rng = &mut ChaCha8Rng::from_entropy();
+        let mut callbacks = vec![];
+
+        for _ in 0..6 {
+            callbacks.push(SpaceCallback::GenerateParticle {
+                position,
+                velocity: Vec2::new(rng.gen_range(-3.5..3.5), rng.gen_range(-3.5..3.5)),
+                color: Rgba([
+                    205 + rng.gen_range(0..50),
+                    55 + rng.gen_range(0..100),
+                    rng.gen_range(0..55),
+                    255,
+                ]),
+                particle_state: EntityState::Decaying {
+                    lifetime: 2.0 + rng.gen_range(0.0..1.5),
+                },
+                layer: rng.gen_range(0..=2),
+            });
+        }
+
+        for _ in 0..2 + self.level {
+            callbacks.push(SpaceCallback::GenerateFragment {
+                position,
+                velocity: Vec2::new(rng.gen_range(-3.5..3.5), rng.gen_range(-3.5..3.5)),
+                resource: Resource::SCRAPS,
+                amount: rng.gen_range(1..=4),
+            });
+        }
+
+        if rng.gen_bool((0.1 * self.level as f64).min(0.8)) {
+            callbacks.push(SpaceCallback::GenerateFragment {
+                position,
+                velocity: Vec2::new(rng.gen_range(-2.5..2.5), rng.gen_range(-2.5..2.5)),
+                resource: Resource::GOLD,
+                amount: 1,
+            });
+        }
+
+        callbacks
+    }
+
+    pub fn new(position: Vec2, level: u32) -> Self {
+        let mut enemy = Self {
+            id: 0,
+            level: level.max(1),
+            previous_position: position,
+            position,
+            velocity: Vec2::new(-BASE_MAX_SPEED, 0.0),
+            acceleration: Vec2::ZERO,
+            hit_points: 0.0,
+            shooting_cooldown: BASE_SHOOTING_INTERVAL,
+            tick: 0,
+            visual_effects: HashMap::new(),
+        };
+        enemy.hit_points = BASE_HIT_POINTS * enemy.level_multiplier();
+        enemy
+    }
+
+    pub fn with_hit_points(mut self, hit_points: f32) -> Self {
+        self.hit_points = hit_points;
+        self
+    }
+
+    pub fn new_at_screen_edge(level: u32) -> Self {
+        let rng = &mut ChaCha8Rng::from_entropy();
+        let x = (SCREEN_WIDTH + 2) as f32;
+        let y = rng.gen_range(0.15 * SCREEN_HEIGHT as f32..0.85 * SCREEN_HEIGHT as f32);
+
+        Self::new(Vec2::new(x, y), level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EnemyShipEntity;
+    use crate::space_adventure::{space_callback::SpaceCallback, traits::*};
+    use crate::world::resources::Resource;
+    use glam::{I16Vec2, Vec2};
+
+    #[test]
+    fn test_enemy_ship_combat() {
+        let weak = EnemyShipEntity::new(Vec2::new(100.0, 40.0), 1);
+        let strong = EnemyShipEntity::new(Vec2::new(100.0, 40.0), 5);
+        assert!(strong.hit_points() > weak.hit_points());
+        assert!(strong.shooting_interval() < weak.shooting_interval());
+
+        // The enemy only shoots once its cooldown is over and the target is in front of it.
+        let mut enemy = EnemyShipEntity::new(Vec2::new(100.0, 40.0), 1);
+        // Far enough for the enemy to close in up to its engage distance.
+        let target = enemy.center() - I16Vec2::new(100, 0);
+        assert!(enemy.update_ai(target, 0.1).is_empty());
+        assert!(enemy.acceleration.x < 0.0);
+        let callbacks = enemy.update_ai(target, super::BASE_SHOOTING_INTERVAL);
+        assert!(matches!(
+            callbacks[..],
+            [SpaceCallback::GenerateProjectile { shot_by_id: 0, velocity, .. }] if velocity.x < 0.0
+        ));
+        assert!(enemy.update_ai(target, 0.1).is_empty());
+
+        let behind = enemy.center() + I16Vec2::new(50, 0);
+        assert!(enemy
+            .update_ai(behind, super::BASE_SHOOTING_INTERVAL)
+            .is_empty());
+
+        // Destroyed enemies drop scraps.
+        let callbacks = enemy.handle_space_callback(SpaceCallback::DamageEntity {
+            id: 0,
+            damage: 1000.0,
+        });
+        assert!(matches!(
+            callbacks[..],
+            [SpaceCallback::DestroyEntity { id: 0 }]
+        ));
+        let loot = enemy.handle_space_callback(SpaceCallback::DestroyEntity { id: 0 });
+        assert!(loot.iter().any(|callback| matches!(
+            callback,
+            SpaceCallback::GenerateFragment {
+                resource: Resource::SCRAPS,
+                ..
+            }
+        )));
+    }
+}
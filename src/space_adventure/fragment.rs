@@ -88,6 +88,7 @@ impl Collider for FragmentEntity {
 
 register_impl!(!PlayerControlled for FragmentEntity);
 register_impl!(ResourceFragment for FragmentEntity);
+register_impl!(!AiControlled for FragmentEntity);
 impl ResourceFragment for FragmentEntity {
     fn resource(&self) -> Resource {
         self.resource
@@ -1,8 +1,12 @@
 mod asteroid;
+mod boss;
 mod constants;
+mod deposit;
+mod enemy;
 mod fragment;
 mod particle;
 mod projectile;
+mod snapshot;
 mod space;
 mod space_callback;
 mod spaceship;
@@ -10,6 +14,7 @@ mod traits;
 mod utils;
 mod visual_effects;
 
+pub use snapshot::SpaceAdventureSnapshot;
 pub use space::SpaceAdventure;
 pub use space_callback::SpaceCallback;
 pub use spaceship::{ShooterState, SpaceshipEntity};
@@ -75,6 +75,7 @@ impl Collider for ParticleEntity {}
 
 register_impl!(!PlayerControlled for ParticleEntity);
 register_impl!(!ResourceFragment for ParticleEntity);
+register_impl!(!AiControlled for ParticleEntity);
 
 impl Entity for ParticleEntity {
     fn set_id(&mut self, id: usize) {
@@ -89,6 +89,10 @@ impl Collider for ProjectileEntity {
     fn collider_type(&self) -> ColliderType {
         ColliderType::Projectile
     }
+
+    fn shot_by_id(&self) -> Option<usize> {
+        Some(self.shot_by_id)
+    }
 }
 
 impl Entity for ProjectileEntity {
@@ -102,6 +106,7 @@ impl Entity for ProjectileEntity {
 
 register_impl!(!PlayerControlled for ProjectileEntity);
 register_impl!(!ResourceFragment for ProjectileEntity);
+register_impl!(!AiControlled for ProjectileEntity);
 
 impl ProjectileEntity {
     pub fn new(
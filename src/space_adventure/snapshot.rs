@@ -0,0 +1,128 @@
+use super::asteroid::{AsteroidEntity, AsteroidSize};
+use super::boss::BossEntity;
+use super::deposit::{DepositEntity, DepositType};
+use super::enemy::EnemyShipEntity;
+use super::space::BossEncounter;
+use super::traits::Entity;
+use crate::types::{PlayerId, ResourceMap};
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+// Particles, projectiles and fragments are short lived and are not stored.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum EntitySnapshot {
+    Asteroid {
+        position: [f32; 2],
+        velocity: [f32; 2],
+        size: AsteroidSize,
+        durability: f32,
+    },
+    Deposit {
+        position: [f32; 2],
+        velocity: [f32; 2],
+        deposit_type: DepositType,
+        durability: f32,
+    },
+    Enemy {
+        position: [f32; 2],
+        level: u32,
+        hit_points: f32,
+    },
+    Boss {
+        position: [f32; 2],
+        hit_points: f32,
+    },
+}
+
+impl EntitySnapshot {
+    pub fn into_entity(self) -> Box<dyn Entity> {
+        match self {
+            Self::Asteroid {
+                position,
+                velocity,
+                size,
+                durability,
+            } => Box::new(
+                AsteroidEntity::new(Vec2::from(position), Vec2::from(velocity), size)
+                    .with_durability(durability),
+            ),
+            Self::Deposit {
+                position,
+                velocity,
+                deposit_type,
+                durability,
+            } => Box::new(
+                DepositEntity::new(Vec2::from(position), Vec2::from(velocity), deposit_type)
+                    .with_durability(durability),
+            ),
+            Self::Enemy {
+                position,
+                level,
+                hit_points,
+            } => Box::new(
+                EnemyShipEntity::new(Vec2::from(position), level).with_hit_points(hit_points),
+            ),
+            Self::Boss {
+                position,
+                hit_points,
+            } => Box::new(BossEntity::new(Vec2::from(position)).with_hit_points(hit_points)),
+        }
+    }
+}
+
+/// State of an ongoing space adventure, stored in the save file
+/// so that the adventure can be resumed after a restart.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct SpaceAdventureSnapshot {
+    pub ship_position: [f32; 2],
+    pub ship_durability: u32,
+    pub ship_charge: u32,
+    pub resources: ResourceMap,
+    pub fuel: u32,
+    pub entities: Vec<EntitySnapshot>,
+    pub tick: usize,
+    pub oxygen: f32,
+    pub max_oxygen: f32,
+    pub low_oxygen_warning: bool,
+    pub distance: f32,
+    pub boss_encounter: BossEncounter,
+    pub pilot_id: Option<PlayerId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Entity, EntitySnapshot};
+    use crate::space_adventure::{asteroid::AsteroidSize, deposit::DepositType};
+
+    #[test]
+    fn test_entity_snapshot_roundtrip() {
+        let snapshots = vec![
+            EntitySnapshot::Asteroid {
+                position: [10.0, 12.0],
+                velocity: [-1.0, 0.1],
+                size: AsteroidSize::Big,
+                durability: 1.5,
+            },
+            EntitySnapshot::Deposit {
+                position: [20.0, 30.0],
+                velocity: [-0.5, 0.0],
+                deposit_type: DepositType::GoldVein,
+                durability: 12.0,
+            },
+            EntitySnapshot::Enemy {
+                position: [100.0, 40.0],
+                level: 3,
+                hit_points: 7.0,
+            },
+            EntitySnapshot::Boss {
+                position: [120.0, 20.0],
+                hit_points: 150.0,
+            },
+        ];
+
+        for snapshot in snapshots {
+            let entity = snapshot.into_entity();
+            assert_eq!(entity.snapshot(), Some(snapshot));
+        }
+    }
+}
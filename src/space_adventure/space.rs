@@ -1,11 +1,14 @@
 use super::{
-    asteroid::{AsteroidEntity, AsteroidSize}, constants::{MAX_SCREEN_HEIGHT, MAX_SCREEN_WIDTH}, fragment::FragmentEntity, particle::ParticleEntity, projectile::ProjectileEntity, spaceship::SpaceshipEntity, traits::{resolve_collision_between, Entity}, utils::EntityState, visual_effects::VisualEffect, PlayerControlled, PlayerInput
+    asteroid::{AsteroidEntity, AsteroidSize}, boss::BossEntity, constants::{MAX_SCREEN_HEIGHT, MAX_SCREEN_WIDTH}, deposit::DepositEntity, enemy::EnemyShipEntity, fragment::FragmentEntity, particle::ParticleEntity, projectile::ProjectileEntity, snapshot::SpaceAdventureSnapshot, space_callback::SpaceCallback, spaceship::SpaceshipEntity, traits::{resolve_collision_between, AiControlled, ColliderType, Entity}, utils::EntityState, visual_effects::VisualEffect, PlayerControlled, PlayerInput
 };
 use crate::{
     image::utils::{ExtraImageUtils, TRAVELLING_BACKGROUND},
-    types::{AppResult, ResourceMap, SystemTimeTick, Tick},
+    types::{AppResult, PlayerId, ResourceMap, SystemTimeTick, Tick},
     ui::{popup_message::PopupMessage, ui_callback::UiCallback},
-    world::{resources::Resource, spaceship::Spaceship},
+    world::{
+        constants::SECONDS, player::Player, resources::Resource, skill::Rated,
+        space_upgrades::SpaceAdventureProgression, spaceship::Spaceship,
+    },
 };
 use anyhow::anyhow;
 use glam::Vec2;
@@ -13,26 +16,47 @@ use image::{Rgba, RgbaImage};
 use itertools::Itertools;
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
 use strum::Display;
 use std::{
     collections::HashMap,
-    time::{Duration, Instant},
+    time::Duration,
 };
 
 const MAX_LAYER: usize = 5;
+const LOW_OXYGEN_FRACTION: f32 = 0.25;
+// The ship is assumed to cruise away from the planet at this speed, plus its own forward speed.
+const CRUISE_SPEED: f32 = 2.0;
+// Distance from the planet after which the difficulty level increases.
+const DISTANCE_PER_DIFFICULTY_LEVEL: f32 = 400.0;
+const MAX_DIFFICULTY_LEVEL: u32 = 10;
+const MAX_ENEMIES: usize = 4;
+const ENEMY_SPAWN_PROBABILITY: f64 = 0.0015;
+const DEPOSIT_SPAWN_PROBABILITY: f64 = 0.004;
+// The boss waits for the ship to be far enough from the planet and for the other enemies to be gone.
+const BOSS_MIN_DIFFICULTY_LEVEL: u32 = 3;
 
 #[derive(Default, Debug, Display,Clone, Copy, PartialEq)]
 enum SpaceState {
     Starting {
-        time: Instant,
+        elapsed: Duration,
     },
     #[default]
     Running,
     Ending {
-        time: Instant,
+        elapsed: Duration,
     },
 }
 
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum BossEncounter {
+    #[default]
+    None,
+    Pending,
+    Engaged,
+    Defeated,
+}
+
 impl SpaceState {
     pub const STARTING_DURATION:Duration = Duration::from_millis(2500);
     pub const ENDING_DURATION:Duration = Duration::from_millis(2500);
@@ -48,6 +72,15 @@ pub struct SpaceAdventure {
     entities: [HashMap<usize, Box<dyn Entity>>; MAX_LAYER],
     id_to_layer: HashMap<usize, usize>,
     player_id: Option<usize>,
+    // Seconds of breathable air left for the crew.
+    oxygen: f32,
+    max_oxygen: f32,
+    low_oxygen_warning: bool,
+    // Distance travelled from the planet, which determines the difficulty.
+    distance: f32,
+    boss_encounter: BossEncounter,
+    pilot_id: Option<PlayerId>,
+    paused: bool,
 }
 
 impl SpaceAdventure {
@@ -159,6 +192,42 @@ impl SpaceAdventure {
         self.insert_entity(Box::new(AsteroidEntity::new(position, velocity, size)))
     }
 
+    pub fn difficulty_level(&self) -> u32 {
+        (1 + (self.distance / DISTANCE_PER_DIFFICULTY_LEVEL) as u32).min(MAX_DIFFICULTY_LEVEL)
+    }
+
+    fn enemy_count(&self) -> usize {
+        (0..MAX_LAYER)
+            .map(|l| {
+                self.entities[l]
+                    .values()
+                    .filter(|entity| entity.collider_type() == ColliderType::Enemy)
+                    .count()
+            })
+            .sum()
+    }
+
+    // Enemies steer and shoot towards the player spaceship.
+    fn update_ai(&mut self, deltatime: f32) -> Vec<SpaceCallback> {
+        let target = if let Some(player) = self.get_player() {
+            player.center()
+        } else {
+            return vec![];
+        };
+
+        let mut callbacks = vec![];
+        for layer in 0..MAX_LAYER {
+            for (_, entity) in self.entities[layer].iter_mut() {
+                let ai_control: Option<&mut dyn AiControlled> = entity.as_trait_mut();
+                if let Some(ai_control) = ai_control {
+                    callbacks.append(&mut ai_control.update_ai(target, deltatime));
+                }
+            }
+        }
+
+        callbacks
+    }
+
     pub fn generate_particle(
         &mut self,
         position: Vec2,
@@ -211,7 +280,7 @@ impl SpaceAdventure {
 
         Ok(Self {
             background,
-            state: SpaceState::Starting { time: Instant::now() },
+            state: SpaceState::Starting { elapsed: Duration::ZERO },
             ..Default::default()
         })
     }
@@ -221,10 +290,12 @@ impl SpaceAdventure {
         spaceship: &Spaceship,
         resources: ResourceMap,
         fuel: u32,
+        progression: &SpaceAdventureProgression,
     ) -> AppResult<Self> {
-        let id = self.insert_entity(Box::new(SpaceshipEntity::from_spaceship(
-            spaceship, resources, fuel,
-        )?));
+        let id = self.insert_entity(Box::new(
+            SpaceshipEntity::from_spaceship(spaceship, resources, fuel)?
+                .with_progression(progression),
+        ));
         self.player_id = Some(id);
 
         for _ in 0..10 {
@@ -235,8 +306,140 @@ impl SpaceAdventure {
         Ok(self)
     }
 
+    pub fn with_oxygen(mut self, autonomy: Tick) -> Self {
+        self.max_oxygen = autonomy as f32 / SECONDS as f32;
+        self.oxygen = self.max_oxygen;
+        self
+    }
+
+    pub fn with_pilot(mut self, pilot: &Player) -> AppResult<Self> {
+        let player = self.get_player_mut().ok_or(anyhow!("No player set"))?;
+        let player_control: &mut dyn PlayerControlled = player
+            .as_trait_mut()
+            .expect("Player should implement PlayerControlled.");
+        player_control.set_pilot_skills(
+            pilot.athletics.rating() as f32,
+            pilot.technical.rating() as f32,
+        );
+        self.pilot_id = Some(pilot.id);
+        Ok(self)
+    }
+
+    pub fn with_hull_care(mut self, bonus: f32) -> AppResult<Self> {
+        let player = self.get_player_mut().ok_or(anyhow!("No player set"))?;
+        let player_control: &mut dyn PlayerControlled = player
+            .as_trait_mut()
+            .expect("Player should implement PlayerControlled.");
+        player_control.set_hull_care(bonus);
+        Ok(self)
+    }
+
+    pub fn pilot_id(&self) -> Option<PlayerId> {
+        self.pilot_id
+    }
+
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+
+    pub fn with_boss_encounter(mut self, pending: bool) -> Self {
+        if pending {
+            self.boss_encounter = BossEncounter::Pending;
+        }
+        self
+    }
+
+    pub fn is_boss_defeated(&self) -> bool {
+        self.boss_encounter == BossEncounter::Defeated
+    }
+
+    pub fn set_boss_defeated(&mut self) {
+        self.boss_encounter = BossEncounter::Defeated;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    pub fn snapshot(&self) -> AppResult<SpaceAdventureSnapshot> {
+        let player = self.get_player().ok_or(anyhow!("No player set"))?;
+        let player_control: &dyn PlayerControlled = player
+            .as_trait_ref()
+            .expect("Player should implement PlayerControlled.");
+
+        let entities = self
+            .entities
+            .iter()
+            .flat_map(|layer| layer.values())
+            .filter_map(|entity| entity.snapshot())
+            .collect_vec();
+
+        Ok(SpaceAdventureSnapshot {
+            ship_position: player.position().as_vec2().to_array(),
+            ship_durability: player_control.current_durability(),
+            ship_charge: player_control.charge(),
+            resources: player_control.resources().clone(),
+            fuel: player_control.fuel(),
+            entities,
+            tick: self.tick,
+            oxygen: self.oxygen,
+            max_oxygen: self.max_oxygen,
+            low_oxygen_warning: self.low_oxygen_warning,
+            distance: self.distance,
+            boss_encounter: self.boss_encounter,
+            pilot_id: self.pilot_id,
+        })
+    }
+
+    // Must be called after the spaceship has been set. The adventure is resumed paused.
+    pub fn with_snapshot(mut self, snapshot: SpaceAdventureSnapshot) -> AppResult<Self> {
+        let player_id = self.player_id.ok_or(anyhow!("No player set"))?;
+        for layer in self.entities.iter_mut() {
+            layer.retain(|id, _| *id == player_id);
+        }
+        self.id_to_layer.retain(|id, _| *id == player_id);
+
+        let player = self.get_player_mut().ok_or(anyhow!("No player set"))?;
+        let player_control: &mut dyn PlayerControlled = player
+            .as_trait_mut()
+            .expect("Player should implement PlayerControlled.");
+        player_control.restore_state(
+            Vec2::from(snapshot.ship_position),
+            snapshot.ship_durability,
+            snapshot.ship_charge,
+        );
+
+        for entity in snapshot.entities {
+            self.insert_entity(entity.into_entity());
+        }
+
+        self.tick = snapshot.tick;
+        self.oxygen = snapshot.oxygen;
+        self.max_oxygen = snapshot.max_oxygen;
+        self.low_oxygen_warning = snapshot.low_oxygen_warning;
+        self.distance = snapshot.distance;
+        self.boss_encounter = snapshot.boss_encounter;
+        self.pilot_id = snapshot.pilot_id;
+        self.state = SpaceState::Running;
+        self.paused = true;
+
+        Ok(self)
+    }
+
+    pub fn oxygen(&self) -> u32 {
+        self.oxygen.ceil() as u32
+    }
+
+    pub fn max_oxygen(&self) -> u32 {
+        self.max_oxygen.ceil() as u32
+    }
+
     pub fn handle_player_input(&mut self, input: PlayerInput) -> AppResult<()> {
-        if self.state != SpaceState::Running {
+        if self.state != SpaceState::Running || self.paused {
             return Ok(());
         }
 
@@ -250,19 +453,25 @@ impl SpaceAdventure {
     }
 
     pub fn stop_space_adventure(&mut self) {
+        self.paused = false;
         self.state = SpaceState::Ending {
-            time: Instant::now(),
+            elapsed: Duration::ZERO,
         };
     }
 
     pub fn update(&mut self, deltatime: f32) -> AppResult<Vec<UiCallback>> {
+        if self.paused {
+            return Ok(vec![]);
+        }
 
         match self.state {
-            SpaceState::Starting { time } => {
-                if time.elapsed() >= SpaceState::STARTING_DURATION {
+            SpaceState::Starting { elapsed } => {
+                let elapsed = elapsed + Duration::from_secs_f32(deltatime);
+                if elapsed >= SpaceState::STARTING_DURATION {
                     self.state = SpaceState::Running;
                     return Ok(vec![]);
                 }
+                self.state = SpaceState::Starting { elapsed };
 
             }
 
@@ -284,18 +493,52 @@ impl SpaceAdventure {
                     ]);
                     }
                 }
+
+                if self.max_oxygen > 0.0 {
+                    self.oxygen = (self.oxygen - deltatime).max(0.0);
+
+                    if self.oxygen == 0.0 {
+                        self.stop_space_adventure();
+
+                        return Ok(vec![
+                        UiCallback::PushUiPopup { popup_message:
+                            PopupMessage::Ok{
+                               message: "The oxygen is over!\nThe crew barely made it back to the base,\ngasping for air...".to_string()
+                                , is_skippable:true, tick:Tick::now()}
+                            }
+                    ]);
+                    }
+
+                    if !self.low_oxygen_warning && self.oxygen < self.max_oxygen * LOW_OXYGEN_FRACTION {
+                        self.low_oxygen_warning = true;
+                        return Ok(vec![
+                        UiCallback::PushUiPopup { popup_message:
+                            PopupMessage::Ok{
+                               message: "Oxygen levels are critical!\nThe crew is getting dizzy,\nbetter head back soon...".to_string()
+                                , is_skippable:true, tick:Tick::now()}
+                            }
+                    ]);
+                    }
+                }
             }
 
-            SpaceState::Ending { time } => {
-                if time.elapsed() >= SpaceState::ENDING_DURATION {
+            SpaceState::Ending { elapsed } => {
+                let elapsed = elapsed + Duration::from_secs_f32(deltatime);
+                if elapsed >= SpaceState::ENDING_DURATION {
                     return Ok(vec![UiCallback::ReturnFromSpaceAdventure]);
                 }
+                self.state = SpaceState::Ending { elapsed };
             }
         }
 
         self.tick += 1;
 
-        let mut callbacks = vec![];
+        if self.state == SpaceState::Running {
+            let forward_speed = self.get_player().map(|player| player.velocity().x as f32).unwrap_or_default();
+            self.distance += (CRUISE_SPEED + forward_speed).max(0.0) * deltatime;
+        }
+
+        let mut callbacks = self.update_ai(deltatime);
 
         // Update from lowest layer
         for layer in 0..MAX_LAYER {
@@ -325,18 +568,69 @@ impl SpaceAdventure {
         }
 
         // Execute callbacks
+        let boss_encounter = self.boss_encounter;
         for cb in callbacks {
             cb.call(self);
         }
 
-        // Generate asteroids
+        if boss_encounter == BossEncounter::Engaged && self.is_boss_defeated() {
+            return Ok(vec![
+                UiCallback::PushUiPopup { popup_message:
+                    PopupMessage::Ok{
+                       message: "The Kartoffel mothership is going down!\nBring the crew back to the base\nto claim the spoils...".to_string()
+                        , is_skippable:true, tick:Tick::now()}
+                    }
+            ]);
+        }
+
+        Ok(self.spawn_director())
+    }
+
+    // Decides which entities enter the screen: asteroids, enemies and the boss.
+    fn spawn_director(&mut self) -> Vec<UiCallback> {
         let rng = &mut ChaCha8Rng::from_entropy();
         if self.entity_count() < 50 && rng.gen_bool(0.01) {
             let asteroid = AsteroidEntity::new_at_screen_edge();
             self.insert_entity(Box::new(asteroid));
         }
 
-        Ok(vec![])
+        if self.state != SpaceState::Running {
+            return vec![];
+        }
+
+        let level = self.difficulty_level();
+        // Resource deposits drift in among the asteroids, what they hold depends on the distance.
+        if self.entity_count() < 50 && rng.gen_bool(DEPOSIT_SPAWN_PROBABILITY) {
+            let deposit = DepositEntity::new_at_screen_edge(level);
+            self.insert_entity(Box::new(deposit));
+        }
+
+        match self.boss_encounter {
+            BossEncounter::Pending if level >= BOSS_MIN_DIFFICULTY_LEVEL && self.enemy_count() == 0 => {
+                self.insert_entity(Box::new(BossEntity::new_at_screen_edge()));
+                self.boss_encounter = BossEncounter::Engaged;
+                return vec![
+                    UiCallback::PushUiPopup { popup_message:
+                        PopupMessage::Ok{
+                           message: "The rumors were true!\nThe Kartoffel mothership is approaching,\nget ready for battle!".to_string()
+                            , is_skippable:true, tick:Tick::now()}
+                        }
+                ];
+            }
+            // No other enemies interfere with the boss fight.
+            BossEncounter::Engaged => return vec![],
+            _ => {}
+        }
+
+        // Generate enemies, more frequently the further away from the planet.
+        if self.enemy_count() < MAX_ENEMIES.min(level as usize)
+            && rng.gen_bool(ENEMY_SPAWN_PROBABILITY * level as f64)
+        {
+            let enemy = EnemyShipEntity::new_at_screen_edge(level);
+            self.insert_entity(Box::new(enemy));
+        }
+
+        vec![]
     }
 
     pub fn image(&self, debug_view: bool) -> AppResult<RgbaImage> {
@@ -352,12 +646,12 @@ impl SpaceAdventure {
         
         match  self.state {
             // If adventure is starting, fade in.
-            SpaceState::Starting { time } => {
-                VisualEffect::FadeIn.apply_global_effect(&mut base, time.elapsed().as_millis() as f32/1000.0);
+            SpaceState::Starting { elapsed } => {
+                VisualEffect::FadeIn.apply_global_effect(&mut base, elapsed.as_millis() as f32/1000.0);
             }
              // If adventure is ending, fade out.
-            SpaceState::Ending { time } => {
-                VisualEffect::FadeOut.apply_global_effect(&mut base, time.elapsed().as_millis() as f32/1000.0);
+            SpaceState::Ending { elapsed } => {
+                VisualEffect::FadeOut.apply_global_effect(&mut base, elapsed.as_millis() as f32/1000.0);
             }
             SpaceState::Running=>{}
         }
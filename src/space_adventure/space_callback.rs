@@ -19,6 +19,8 @@ pub enum SpaceCallback {
         duration: f32,
     },
 
+    BossDefeated,
+
     CollectFragment {
         id: usize,
         resource: Resource,
@@ -84,6 +86,10 @@ impl SpaceCallback {
                 }
             }
 
+            Self::BossDefeated => {
+                space.set_boss_defeated();
+            }
+
             Self::CollectFragment { id, .. } => {
                 if let Some(entity) = space.get_entity_mut(&id) {
                     callbacks.append(&mut entity.handle_space_callback(*self));
@@ -9,6 +9,8 @@ use crate::space_adventure::Direction;
 use crate::types::*;
 use crate::world::constants::{FUEL_CONSUMPTION_PER_UNIT_STORAGE, SPEED_PENALTY_PER_UNIT_STORAGE};
 use crate::world::resources::Resource;
+use crate::world::skill::MAX_SKILL;
+use crate::world::space_upgrades::SpaceAdventureProgression;
 use crate::{image::types::Gif, types::AppResult, world::spaceship::Spaceship};
 use glam::{I16Vec2, Vec2};
 use image::imageops::rotate90;
@@ -60,6 +62,13 @@ pub struct SpaceshipEntity {
     shooters: Vec<I16Vec2>,       // Position of shooters in relative coords
     auto_shoot: bool,
     shooter_state: ShooterState,
+    max_charge: f32,
+    shooting_recoil: f32,
+    // Multiplier to the charge recovery speed, depending on the pilot.
+    charge_rate: f32,
+    damage_reduction: f32,
+    mining_multiplier: f32,
+    tractor_multiplier: f32,
     visual_effects: VisualEffectMap,
 }
 
@@ -214,7 +223,7 @@ impl Entity for SpaceshipEntity {
 
         match self.shooter_state {
             ShooterState::Shooting { charge, recoil } => {
-                if recoil == ShooterState::MAX_SHOOTING_RECOIL {
+                if recoil == self.shooting_recoil {
                     for shooter_position in self.shooters.iter() {
                         callbacks.push(SpaceCallback::GenerateProjectile {
                             shot_by_id: self.id(),
@@ -223,10 +232,12 @@ impl Entity for SpaceshipEntity {
                             color: Rgba([
                                 25,
                                 125,
-                                55 + (200.0 * charge / ShooterState::MAX_CHARGE) as u8,
+                                55 + (200.0 * charge / self.max_charge) as u8,
                                 255,
                             ]),
-                            damage: 1.5 * (charge / ShooterState::MAX_CHARGE).powf(0.25),
+                            damage: 1.5
+                                * self.mining_multiplier
+                                * (charge / self.max_charge).powf(0.25),
                         });
                     }
                     let new_charge = charge - ShooterState::SHOOTING_CHARGE_COST;
@@ -256,22 +267,24 @@ impl Entity for SpaceshipEntity {
                 if self.auto_shoot {
                     self.shooter_state = ShooterState::Shooting {
                         charge,
-                        recoil: ShooterState::MAX_SHOOTING_RECOIL,
+                        recoil: self.shooting_recoil,
                     };
-                } else if charge < ShooterState::MAX_CHARGE {
-                    let new_charge = (charge + ShooterState::CHARGE_RECOVERY_SPEED * deltatime)
-                        .min(ShooterState::MAX_CHARGE);
+                } else if charge < self.max_charge {
+                    let new_charge = (charge
+                        + ShooterState::CHARGE_RECOVERY_SPEED * self.charge_rate * deltatime)
+                        .min(self.max_charge);
                     self.shooter_state = ShooterState::Ready { charge: new_charge };
                 }
             }
 
             ShooterState::Recharging { charge } => {
-                let new_charge = charge + ShooterState::RECHARGE_RECOVERY_SPEED * deltatime;
-                if new_charge < ShooterState::MAX_CHARGE {
+                let new_charge =
+                    charge + ShooterState::RECHARGE_RECOVERY_SPEED * self.charge_rate * deltatime;
+                if new_charge < self.max_charge {
                     self.shooter_state = ShooterState::Recharging { charge: new_charge };
                 } else {
                     self.shooter_state = ShooterState::Ready {
-                        charge: ShooterState::MAX_CHARGE,
+                        charge: self.max_charge,
                     };
                 }
             }
@@ -283,7 +296,7 @@ impl Entity for SpaceshipEntity {
     fn handle_space_callback(&mut self, callback: SpaceCallback) -> Vec<SpaceCallback> {
         match callback {
             SpaceCallback::DamageEntity { damage, .. } => {
-                self.add_damage(damage);
+                self.add_damage(damage * (1.0 - self.damage_reduction));
                 self.add_visual_effect(
                     VisualEffect::COLOR_MASK_LIFETIME,
                     VisualEffect::ColorMask {
@@ -295,13 +308,15 @@ impl Entity for SpaceshipEntity {
             SpaceCallback::CollectFragment {
                 resource, amount, ..
             } => {
-                // FIXME: we need to figure out a way to handle fuel, since it cannot be handled as a normal resource
-                // because we want it to be an f32.
-                assert!(resource != Resource::FUEL);
-
-                self.resources
-                    .saturating_add(resource, amount, self.storage_capacity);
-                self.used_storage_capacity = self.resources.used_storage_capacity()
+                // Fuel goes straight to the tank rather than to the storage,
+                // since it is tracked separately as an f32.
+                if resource == Resource::FUEL {
+                    self.fuel = (self.fuel + amount as f32).min(self.fuel_capacity as f32);
+                } else {
+                    self.resources
+                        .saturating_add(resource, amount, self.storage_capacity);
+                    self.used_storage_capacity = self.resources.used_storage_capacity()
+                }
             }
 
             _ => {}
@@ -318,6 +333,7 @@ impl Collider for SpaceshipEntity {
 
 register_impl!(PlayerControlled for SpaceshipEntity);
 register_impl!(!ResourceFragment for SpaceshipEntity);
+register_impl!(!AiControlled for SpaceshipEntity);
 
 impl PlayerControlled for SpaceshipEntity {
     fn fuel(&self) -> u32 {
@@ -354,7 +370,11 @@ impl PlayerControlled for SpaceshipEntity {
     }
 
     fn max_charge(&self) -> u32 {
-        ShooterState::MAX_CHARGE.round() as u32
+        self.max_charge.round() as u32
+    }
+
+    fn tractor_multiplier(&self) -> f32 {
+        self.tractor_multiplier
     }
 
     fn thrust(&self) -> u32 {
@@ -373,6 +393,25 @@ impl PlayerControlled for SpaceshipEntity {
         self.durability.round() as u32
     }
 
+    fn set_pilot_skills(&mut self, athletics: f32, technical: f32) {
+        // An average pilot leaves the ship handling unchanged.
+        self.base_thrust *= 0.75 + 0.5 * athletics / MAX_SKILL;
+        self.charge_rate = 0.75 + 0.5 * technical / MAX_SKILL;
+    }
+
+    fn set_hull_care(&mut self, bonus: f32) {
+        self.damage_reduction = 1.0 - (1.0 - self.damage_reduction) / bonus.max(1.0);
+    }
+
+    fn restore_state(&mut self, position: Vec2, current_durability: u32, charge: u32) {
+        self.previous_position = position;
+        self.position = position;
+        self.current_durability = (current_durability as f32).min(self.durability);
+        self.shooter_state = ShooterState::Ready {
+            charge: (charge as f32).min(self.max_charge),
+        };
+    }
+
     fn handle_player_input(&mut self, input: PlayerInput) {
         match input {
             PlayerInput::MoveDown => self.accelerate(Direction::DOWN),
@@ -426,7 +465,7 @@ impl SpaceshipEntity {
             ShooterState::Ready { charge } => {
                 self.shooter_state = ShooterState::Shooting {
                     charge,
-                    recoil: ShooterState::MAX_SHOOTING_RECOIL,
+                    recoil: self.shooting_recoil,
                 }
             }
             _ => {}
@@ -484,6 +523,7 @@ impl SpaceshipEntity {
         }
 
         let used_storage_capacity = resources.used_storage_capacity();
+        let max_charge = ShooterState::MAX_CHARGE * spaceship.charge_multiplier();
 
         Ok(Self {
             id: 0,
@@ -509,10 +549,27 @@ impl SpaceshipEntity {
             velocity: Vec2::default(),
             acceleration: Vec2::default(),
             tick: 0,
-            shooter_state: ShooterState::Ready {
-                charge: ShooterState::MAX_CHARGE,
-            },
+            shooter_state: ShooterState::Ready { charge: max_charge },
+            max_charge,
+            shooting_recoil: ShooterState::MAX_SHOOTING_RECOIL,
+            charge_rate: 1.0,
+            damage_reduction: spaceship.damage_reduction(),
+            mining_multiplier: spaceship.mining_multiplier(),
+            tractor_multiplier: spaceship.tractor_multiplier(),
             visual_effects: HashMap::new(),
         })
     }
+
+    pub fn with_progression(mut self, progression: &SpaceAdventureProgression) -> Self {
+        self.max_charge *= progression.charge_multiplier();
+        self.shooter_state = ShooterState::Ready {
+            charge: self.max_charge,
+        };
+        self.shooting_recoil *= progression.recoil_multiplier();
+        self.damage_reduction =
+            1.0 - (1.0 - self.damage_reduction) * (1.0 - progression.damage_reduction());
+        self.base_thrust *= progression.thrust_multiplier();
+        self.tractor_multiplier *= progression.tractor_multiplier();
+        self
+    }
 }
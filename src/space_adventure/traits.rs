@@ -1,7 +1,10 @@
 use crate::{types::ResourceMap, world::resources::Resource};
 
-use super::{space_callback::SpaceCallback, spaceship::ShooterState, visual_effects::VisualEffect};
-use glam::I16Vec2;
+use super::{
+    snapshot::EntitySnapshot, space_callback::SpaceCallback, spaceship::ShooterState,
+    visual_effects::VisualEffect,
+};
+use glam::{I16Vec2, Vec2};
 use image::{Rgba, RgbaImage};
 use itertools::Itertools;
 use std::{
@@ -200,6 +203,7 @@ pub enum ColliderType {
     Asteroid,
     Projectile,
     Fragment,
+    Enemy,
 }
 pub trait Collider: Body {
     fn collision_damage(&self) -> f32 {
@@ -209,6 +213,11 @@ pub trait Collider: Body {
     fn collider_type(&self) -> ColliderType {
         ColliderType::None
     }
+
+    // Id of the entity which fired this one, so that shooters do not hit themselves.
+    fn shot_by_id(&self) -> Option<usize> {
+        None
+    }
 }
 
 fn check_physical_collision(one: &Box<dyn Entity>, other: &Box<dyn Entity>) -> bool {
@@ -349,6 +358,37 @@ pub fn resolve_collision_between(
             ];
         }
         (ColliderType::Asteroid, ColliderType::Projectile) => resolve_collision_between(other, one),
+        (ColliderType::Projectile, ColliderType::Spaceship | ColliderType::Enemy) => {
+            if one.shot_by_id() == Some(other.id()) || !are_colliding(one, other) {
+                return vec![];
+            }
+            return vec![
+                SpaceCallback::DestroyEntity { id: one.id() },
+                SpaceCallback::DamageEntity {
+                    id: other.id(),
+                    damage: one.collision_damage(),
+                },
+            ];
+        }
+        (ColliderType::Spaceship | ColliderType::Enemy, ColliderType::Projectile) => {
+            resolve_collision_between(other, one)
+        }
+        (ColliderType::Spaceship, ColliderType::Enemy) => {
+            if !are_colliding(one, other) {
+                return vec![];
+            }
+            return vec![
+                SpaceCallback::DamageEntity {
+                    id: one.id(),
+                    damage: other.collision_damage(),
+                },
+                SpaceCallback::DamageEntity {
+                    id: other.id(),
+                    damage: one.collision_damage(),
+                },
+            ];
+        }
+        (ColliderType::Enemy, ColliderType::Spaceship) => resolve_collision_between(other, one),
         (ColliderType::Spaceship, ColliderType::Asteroid) => {
             if !are_colliding(one, other) {
                 return vec![];
@@ -397,6 +437,10 @@ pub fn resolve_collision_between(
                 ];
             }
             // Else, it is accelerated towards the spaceship.
+            let player_control: Option<&dyn PlayerControlled> = one.as_trait_ref();
+            let tractor_multiplier = player_control
+                .map(|p| p.tractor_multiplier())
+                .unwrap_or(1.0);
             return vec![
                 SpaceCallback::AddVisualEffect {
                     id: other.id(),
@@ -407,7 +451,8 @@ pub fn resolve_collision_between(
                 },
                 SpaceCallback::AccelerateEntity {
                     id: other.id(),
-                    acceleration: one.center() - other.center(),
+                    acceleration: ((one.center() - other.center()).as_vec2() * tractor_multiplier)
+                        .as_i16vec2(),
                 },
             ];
         }
@@ -422,6 +467,7 @@ pub trait Entity:
     + Collider
     + MaybeImplements<dyn PlayerControlled>
     + MaybeImplements<dyn ResourceFragment>
+    + MaybeImplements<dyn AiControlled>
     + Debug
     + Send
     + Sync
@@ -439,6 +485,11 @@ pub trait Entity:
     fn handle_space_callback(&mut self, _callback: SpaceCallback) -> Vec<SpaceCallback> {
         vec![]
     }
+
+    // Entities which should survive a save and restore of the space adventure.
+    fn snapshot(&self) -> Option<EntitySnapshot> {
+        None
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -464,6 +515,15 @@ pub trait PlayerControlled {
     fn maneuverability(&self) -> u32;
     fn current_durability(&self) -> u32;
     fn durability(&self) -> u32;
+    fn tractor_multiplier(&self) -> f32 {
+        1.0
+    }
+    // Skills of the pirate at the controls, between 0 and MAX_SKILL.
+    fn set_pilot_skills(&mut self, athletics: f32, technical: f32);
+    // Damage taken is divided by the engineer hull care bonus.
+    fn set_hull_care(&mut self, bonus: f32);
+    // Used when resuming a saved space adventure.
+    fn restore_state(&mut self, position: Vec2, current_durability: u32, charge: u32);
     fn handle_player_input(&mut self, input: PlayerInput);
 }
 
@@ -472,6 +532,11 @@ pub trait ResourceFragment {
     fn amount(&self) -> u32;
 }
 
+pub trait AiControlled {
+    // Steers and shoots towards the target, usually the center of the player spaceship.
+    fn update_ai(&mut self, target: I16Vec2, deltatime: f32) -> Vec<SpaceCallback>;
+}
+
 #[cfg(test)]
 mod test {
 
@@ -0,0 +1,231 @@
+use crate::types::{AppResult, Tick};
+use crate::world::constants::SECONDS;
+use crate::world::skill::Skill;
+use crate::world::world::World;
+use anyhow::anyhow;
+use itertools::Itertools;
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, RwLock};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+pub const STATUS_UPDATE_INTERVAL: Tick = 1 * SECONDS;
+const MAX_REQUEST_SIZE: usize = 4096;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct StatusPlayer {
+    pub name: String,
+    pub rating: Skill,
+    pub morale: Skill,
+    pub tiredness: Skill,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct StatusTeam {
+    pub name: String,
+    pub ship: String,
+    pub reputation: f32,
+    pub record: [u32; 3],
+    pub treasury: u32,
+    pub players: Vec<StatusPlayer>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct StatusStanding {
+    pub name: String,
+    pub reputation: f32,
+    pub record: [u32; 3],
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct StatusGame {
+    pub home_team: String,
+    pub away_team: String,
+    pub home_score: u16,
+    pub away_score: u16,
+    pub timer: String,
+    pub description: Option<String>,
+}
+
+/// Read-only view of the world served to external dashboards.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct StatusSnapshot {
+    pub team: StatusTeam,
+    pub standings: Vec<StatusStanding>,
+    pub game: Option<StatusGame>,
+}
+
+// Written by the app on every update, read by the server task on every request.
+pub type SharedStatus = Arc<RwLock<Option<StatusSnapshot>>>;
+
+impl StatusSnapshot {
+    pub fn from_world(world: &World) -> AppResult<Self> {
+        let own_team = world.get_own_team()?;
+        let players = world
+            .get_players_by_team(own_team)?
+            .iter()
+            .map(|player| StatusPlayer {
+                name: player.info.shortened_name(),
+                rating: player.average_skill(),
+                morale: player.morale,
+                tiredness: player.tiredness,
+            })
+            .collect();
+
+        let standings = world
+            .teams
+            .values()
+            .sorted_by(|a, b| b.reputation.total_cmp(&a.reputation))
+            .map(|team| StatusStanding {
+                name: team.name.clone(),
+                reputation: team.reputation,
+                record: team.game_record,
+            })
+            .collect();
+
+        let game = own_team
+            .current_game
+            .and_then(|game_id| world.get_game(game_id))
+            .map(|game| {
+                let (home_score, away_score) = game.get_score();
+                StatusGame {
+                    home_team: game.home_team_in_game.name.clone(),
+                    away_team: game.away_team_in_game.name.clone(),
+                    home_score,
+                    away_score,
+                    timer: game.timer.format(),
+                    description: game
+                        .action_results
+                        .last()
+                        .map(|action| action.description.clone()),
+                }
+            });
+
+        Ok(Self {
+            team: StatusTeam {
+                name: own_team.name.clone(),
+                ship: own_team.spaceship.name.clone(),
+                reputation: own_team.reputation,
+                record: own_team.game_record,
+                treasury: own_team.balance(),
+                players,
+            },
+            standings,
+            game,
+        })
+    }
+}
+
+// Returns the status line and the JSON body for a request.
+fn route(method: &str, path: &str, snapshot: Option<&StatusSnapshot>) -> (&'static str, String) {
+    if method != "GET" {
+        return (
+            "405 Method Not Allowed",
+            r#"{"error":"method not allowed"}"#.into(),
+        );
+    }
+    let Some(snapshot) = snapshot else {
+        return (
+            "503 Service Unavailable",
+            r#"{"error":"no team yet"}"#.into(),
+        );
+    };
+    let body = match path.split('?').next().unwrap_or_default() {
+        "/" | "/status" => serde_json::to_string(snapshot),
+        "/team" => serde_json::to_string(&snapshot.team),
+        "/standings" => serde_json::to_string(&snapshot.standings),
+        "/game" => serde_json::to_string(&snapshot.game),
+        _ => return ("404 Not Found", r#"{"error":"not found"}"#.into()),
+    };
+    match body {
+        Ok(body) => ("200 OK", body),
+        Err(e) => (
+            "500 Internal Server Error",
+            serde_json::json!({ "error": e.to_string() }).to_string(),
+        ),
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream, status: SharedStatus) -> AppResult<()> {
+    let mut buffer = vec![0; MAX_REQUEST_SIZE];
+    let size = stream.read(&mut buffer).await?;
+    let request = String::from_utf8_lossy(&buffer[..size]);
+    let mut request_line = request
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .split_whitespace();
+    let method = request_line.next().unwrap_or_default();
+    let path = request_line.next().unwrap_or("/");
+
+    let (status_line, body) = {
+        let snapshot = status
+            .read()
+            .map_err(|e| anyhow!("Status lock poisoned: {}", e))?;
+        route(method, path, snapshot.as_ref())
+    };
+
+    // No CORS header: web pages open in the browser must not be able to read the
+    // crew status, while local tools and dashboards do not need it.
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status_line,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+/// Serves the status snapshot on localhost until the app quits.
+pub async fn run_status_server(port: u16, status: SharedStatus) -> AppResult<()> {
+    let listener = TcpListener::bind(("127.0.0.1", port)).await?;
+    info!("Status endpoint listening on http://127.0.0.1:{}", port);
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let status = status.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, status).await {
+                error!("Status endpoint request failed: {}", e);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{route, StatusGame, StatusSnapshot, StatusTeam};
+
+    #[test]
+    fn test_status_routes() {
+        let snapshot = StatusSnapshot {
+            team: StatusTeam {
+                name: "Rebels".to_string(),
+                ..Default::default()
+            },
+            standings: vec![],
+            game: Some(StatusGame {
+                home_score: 21,
+                ..Default::default()
+            }),
+        };
+
+        let (status, body) = route("GET", "/team", Some(&snapshot));
+        assert_eq!(status, "200 OK");
+        assert!(body.contains("\"name\":\"Rebels\""));
+
+        let (status, body) = route("GET", "/game?refresh=1", Some(&snapshot));
+        assert_eq!(status, "200 OK");
+        assert!(body.contains("\"home_score\":21"));
+
+        assert_eq!(route("GET", "/standings", Some(&snapshot)).1, "[]");
+        assert_eq!(route("GET", "/wallet", Some(&snapshot)).0, "404 Not Found");
+        assert_eq!(
+            route("POST", "/team", Some(&snapshot)).0,
+            "405 Method Not Allowed"
+        );
+        assert_eq!(route("GET", "/team", None).0, "503 Service Unavailable");
+    }
+}
@@ -1,35 +1,92 @@
 use crate::{
+    export::{
+        TeamExport, EXPORT_BOX_SCORES_CSV_FILENAME, EXPORT_GAME_SUMMARY_FILENAME,
+        EXPORT_JSON_FILENAME, EXPORT_ROSTER_CSV_FILENAME,
+    },
     game_engine::game::Game,
-    network::types::TeamRanking,
+    network::{address_book::AddressBook, types::TeamRanking},
+    settings::Settings,
+    stream_overlay::StreamOverlay,
     types::{AppResult, GameId, TeamId},
-    world::world::World,
+    world::{custom_names::CustomNames, world::World},
 };
 use anyhow::anyhow;
 use directories;
 use include_dir::{include_dir, Dir};
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, fs::File, path::PathBuf};
+use std::{
+    collections::HashMap,
+    fs::File,
+    path::{Path, PathBuf},
+};
 
 pub static ASSETS_DIR: Dir = include_dir!("$CARGO_MANIFEST_DIR/assets/");
 pub static PERSISTED_WORLD_FILENAME: &str = "world.json";
 pub static PERSISTED_GAMES_PREFIX: &str = "game_";
 pub static PERSISTED_TEAM_RANKING_FILENAME: &str = "team_ranking.json";
+pub static PERSISTED_SETTINGS_FILENAME: &str = "settings.json";
+pub static STREAM_OVERLAY_JSON_FILENAME: &str = "stream_overlay.json";
+pub static STREAM_OVERLAY_TEXT_FILENAME: &str = "stream_overlay.txt";
+pub static CUSTOM_NAMES_FILENAME: &str = "custom_names.json";
+pub static PERSISTED_ADDRESS_BOOK_FILENAME: &str = "address_book.json";
+pub static ASSETS_OVERLAY_DIRNAME: &str = "assets";
 
 fn path_from_prefix(store_prefix: &str) -> String {
     format!("{}_{}", store_prefix, PERSISTED_WORLD_FILENAME)
 }
 
-pub fn store_path(filename: &str) -> AppResult<PathBuf> {
+#[cfg(test)]
+thread_local! {
+    // Tests store their files in a temporary directory, see `TemporaryStore`.
+    static TEMPORARY_STORE_DIR: std::cell::RefCell<Option<PathBuf>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+fn store_dir() -> AppResult<PathBuf> {
+    #[cfg(test)]
+    if let Some(dir) = TEMPORARY_STORE_DIR.with(|dir| dir.borrow().clone()) {
+        return Ok(dir);
+    }
+
     let dirs = directories::ProjectDirs::from("org", "frittura", "rebels")
         .ok_or(anyhow!("Failed to get directories"))?;
-    let config_dirs = dirs.config_dir();
+    Ok(dirs.config_dir().to_path_buf())
+}
+
+pub fn store_path(filename: &str) -> AppResult<PathBuf> {
+    let config_dirs = store_dir()?;
     if !config_dirs.exists() {
-        std::fs::create_dir_all(config_dirs)?;
+        std::fs::create_dir_all(&config_dirs)?;
     }
     let path = config_dirs.join(filename);
     Ok(path)
 }
 
+/// Redirects the store of the current test thread to a fresh temporary directory,
+/// which is removed when dropped. Keeps tests away from the player's saves.
+#[cfg(test)]
+pub struct TemporaryStore {
+    pub path: PathBuf,
+}
+
+#[cfg(test)]
+impl TemporaryStore {
+    pub fn new() -> AppResult<Self> {
+        let path = std::env::temp_dir().join(format!("rebels_test_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&path)?;
+        TEMPORARY_STORE_DIR.with(|dir| *dir.borrow_mut() = Some(path.clone()));
+        Ok(Self { path })
+    }
+}
+
+#[cfg(test)]
+impl Drop for TemporaryStore {
+    fn drop(&mut self) {
+        TEMPORARY_STORE_DIR.with(|dir| *dir.borrow_mut() = None);
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
 pub fn save_world(world: &World, with_backup: bool, store_prefix: &str) -> AppResult<()> {
     let stored_world = world.to_store()?;
     let filename = path_from_prefix(store_prefix);
@@ -74,6 +131,85 @@ pub fn load_team_ranking() -> AppResult<HashMap<TeamId, TeamRanking>> {
     load_from_json(&PERSISTED_TEAM_RANKING_FILENAME)
 }
 
+pub fn save_address_book(address_book: &AddressBook) -> AppResult<()> {
+    save_to_json(&PERSISTED_ADDRESS_BOOK_FILENAME, address_book)
+}
+
+pub fn load_address_book() -> AppResult<AddressBook> {
+    load_from_json(&PERSISTED_ADDRESS_BOOK_FILENAME)
+}
+
+pub fn save_settings(settings: &Settings) -> AppResult<()> {
+    save_to_json(&PERSISTED_SETTINGS_FILENAME, settings)
+}
+
+pub fn load_settings() -> AppResult<Settings> {
+    let settings: Settings = load_from_json(&PERSISTED_SETTINGS_FILENAME)?;
+    Ok(settings.bounded())
+}
+
+// Custom names are optional: a missing file is not an error, an invalid one
+// is logged and the built-in names are used instead.
+pub fn load_custom_names() -> CustomNames {
+    let exists = store_path(CUSTOM_NAMES_FILENAME)
+        .map(|path| path.exists())
+        .unwrap_or_default();
+    if !exists {
+        return CustomNames::default();
+    }
+
+    match load_from_json::<CustomNames>(CUSTOM_NAMES_FILENAME) {
+        Ok(names) => names.validated(),
+        Err(e) => {
+            log::warn!("Could not load {}: {}", CUSTOM_NAMES_FILENAME, e);
+            CustomNames::default()
+        }
+    }
+}
+
+pub fn save_stream_overlay(overlay: &StreamOverlay) -> AppResult<()> {
+    write_atomically(
+        &STREAM_OVERLAY_JSON_FILENAME,
+        serde_json::to_string_pretty(overlay)?.as_bytes(),
+    )?;
+    write_atomically(&STREAM_OVERLAY_TEXT_FILENAME, overlay.to_text().as_bytes())
+}
+
+// Writes the export as JSON and CSV files into export_dir, or into the store
+// directory if none is given. Returns the directory the files were written to.
+pub fn save_team_export(export: &TeamExport, export_dir: Option<&Path>) -> AppResult<PathBuf> {
+    let dir = match export_dir {
+        Some(dir) => dir.to_path_buf(),
+        None => store_path("")?,
+    };
+    if !dir.exists() {
+        std::fs::create_dir_all(&dir)?;
+    }
+    std::fs::write(
+        dir.join(EXPORT_JSON_FILENAME),
+        serde_json::to_string_pretty(export)?,
+    )?;
+    std::fs::write(dir.join(EXPORT_ROSTER_CSV_FILENAME), export.roster_csv())?;
+    std::fs::write(
+        dir.join(EXPORT_BOX_SCORES_CSV_FILENAME),
+        export.box_scores_csv(),
+    )?;
+    Ok(dir)
+}
+
+pub fn save_game_summary_export(text: &str, export_dir: Option<&Path>) -> AppResult<PathBuf> {
+    let dir = match export_dir {
+        Some(dir) => dir.to_path_buf(),
+        None => store_path("")?,
+    };
+    if !dir.exists() {
+        std::fs::create_dir_all(&dir)?;
+    }
+    let path = dir.join(EXPORT_GAME_SUMMARY_FILENAME);
+    std::fs::write(&path, text)?;
+    Ok(path)
+}
+
 pub fn get_world_size(store_prefix: &str) -> AppResult<u64> {
     let size = world_file_data(store_prefix)?.len();
     Ok(size)
@@ -87,6 +223,16 @@ fn save_to_json<T: Serialize>(filename: &str, data: &T) -> AppResult<()> {
     Ok(())
 }
 
+// Write to a temporary file and rename it, so that readers polling the file
+// (e.g. OBS text sources) never see it half-written.
+fn write_atomically(filename: &str, data: &[u8]) -> AppResult<()> {
+    let path = store_path(filename)?;
+    let tmp_path = store_path(&format!("{}.tmp", filename))?;
+    std::fs::write(&tmp_path, data)?;
+    std::fs::rename(tmp_path, path)?;
+    Ok(())
+}
+
 fn load_from_json<T: for<'a> Deserialize<'a>>(filename: &str) -> AppResult<T> {
     let file = File::open(store_path(filename)?)?;
     let data: T = serde_json::from_reader(file)?;
@@ -104,6 +250,18 @@ pub fn reset() -> AppResult<()> {
     Ok(())
 }
 
+// Removes the world and its backup, used when an ironman world is over.
+pub fn delete_world(store_prefix: &str) -> AppResult<()> {
+    let filename = path_from_prefix(store_prefix);
+    for filename in [filename.clone(), format!("{}.back", filename)] {
+        let path = store_path(&filename)?;
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn world_exists(store_prefix: &str) -> bool {
     let filename = path_from_prefix(store_prefix);
     let path = store_path(&filename);
@@ -148,4 +306,33 @@ mod tests {
         let result = super::save_to_json("test", &world);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_save_team_export() {
+        let dir = super::store_path("test_export").unwrap();
+        let export = crate::export::TeamExport {
+            team: "Rebels".to_string(),
+            ..Default::default()
+        };
+        let result = super::save_team_export(&export, Some(&dir));
+        assert!(result.is_ok());
+        assert!(dir.join(super::EXPORT_JSON_FILENAME).is_file());
+        assert!(dir.join(super::EXPORT_ROSTER_CSV_FILENAME).is_file());
+        assert!(dir.join(super::EXPORT_BOX_SCORES_CSV_FILENAME).is_file());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_atomically() {
+        let store = super::TemporaryStore::new().unwrap();
+        let result = super::write_atomically("test_atomic.txt", b"first");
+        assert!(result.is_ok());
+        let result = super::write_atomically("test_atomic.txt", b"second");
+        assert!(result.is_ok());
+
+        let path = super::store_path("test_atomic.txt").unwrap();
+        assert!(path.starts_with(&store.path));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "second");
+        assert!(!super::store_path("test_atomic.txt.tmp").unwrap().exists());
+    }
 }
@@ -0,0 +1,117 @@
+use crate::types::{AppResult, Tick};
+use crate::ui::utils::format_satoshi;
+use crate::world::constants::SECONDS;
+use crate::world::world::World;
+use serde::{Deserialize, Serialize};
+
+// OBS text sources poll the file, so there is no point in writing it more often.
+pub const STREAM_OVERLAY_UPDATE_INTERVAL: Tick = 1 * SECONDS;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct StreamOverlayGame {
+    pub home_team: String,
+    pub away_team: String,
+    pub home_score: u16,
+    pub away_score: u16,
+    pub timer: String,
+}
+
+/// Snapshot of the own team status, written to disk for streaming overlays.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct StreamOverlay {
+    pub team: String,
+    pub ship: String,
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    pub treasury: u32,
+    pub game: Option<StreamOverlayGame>,
+}
+
+impl StreamOverlay {
+    pub fn from_world(world: &World) -> AppResult<Self> {
+        let own_team = world.get_own_team()?;
+        let game = own_team
+            .current_game
+            .and_then(|game_id| world.get_game(game_id))
+            .map(|game| {
+                let (home_score, away_score) = game.get_score();
+                StreamOverlayGame {
+                    home_team: game.home_team_in_game.name.clone(),
+                    away_team: game.away_team_in_game.name.clone(),
+                    home_score,
+                    away_score,
+                    timer: game.timer.format(),
+                }
+            });
+
+        Ok(Self {
+            team: own_team.name.clone(),
+            ship: own_team.spaceship.name.clone(),
+            wins: own_team.game_record[0],
+            losses: own_team.game_record[1],
+            draws: own_team.game_record[2],
+            treasury: own_team.balance(),
+            game,
+        })
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = format!(
+            "{} ({})\nRecord {}-{}-{}\nTreasury {}\n",
+            self.team,
+            self.ship,
+            self.wins,
+            self.losses,
+            self.draws,
+            format_satoshi(self.treasury)
+        );
+        if let Some(game) = self.game.as_ref() {
+            text.push_str(
+                format!(
+                    "{} {} - {} {}  {}\n",
+                    game.home_team,
+                    game.home_score,
+                    game.away_score,
+                    game.away_team,
+                    game.timer.trim()
+                )
+                .as_str(),
+            );
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StreamOverlay, StreamOverlayGame};
+
+    #[test]
+    fn test_stream_overlay_text() {
+        let mut overlay = StreamOverlay {
+            team: "Rebels".to_string(),
+            ship: "Flyer".to_string(),
+            wins: 3,
+            losses: 1,
+            draws: 0,
+            treasury: 1200,
+            game: None,
+        };
+        assert_eq!(
+            overlay.to_text(),
+            "Rebels (Flyer)\nRecord 3-1-0\nTreasury 1200 sat\n"
+        );
+
+        overlay.game = Some(StreamOverlayGame {
+            home_team: "Rebels".to_string(),
+            away_team: "Pirates".to_string(),
+            home_score: 21,
+            away_score: 19,
+            timer: "Q2 03:12".to_string(),
+        });
+        assert!(overlay
+            .to_text()
+            .ends_with("Rebels 21 - 19 Pirates  Q2 03:12\n"));
+    }
+}
@@ -0,0 +1,376 @@
+use crate::app::App;
+use crate::store::TemporaryStore;
+use crate::types::{AppResult, SystemTimeTick, Tick};
+use crate::ui::ui::UiState;
+use crate::ui::ui_callback::UiCallback;
+use crate::world::constants::TickInterval;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+// Same size as the fixed viewport used for SSH clients.
+pub const HARNESS_SCREEN_WIDTH: u16 = 160;
+pub const HARNESS_SCREEN_HEIGHT: u16 = 48;
+const HARNESS_STORE_PREFIX: &str = "test_harness";
+
+/// Drives the whole app against an in-memory terminal.
+/// Events go through the same code paths as the main loop in `App::run`,
+/// so tests can assert both on the rendered buffer and on the world state.
+pub struct TestHarness {
+    pub app: App,
+    pub terminal: Terminal<TestBackend>,
+    // Saves go to a temporary directory, removed with the harness.
+    _store: TemporaryStore,
+}
+
+impl TestHarness {
+    pub fn new(seed: u64) -> AppResult<Self> {
+        let store = TemporaryStore::new()?;
+        let app = App::new(
+            Some(seed),
+            true,
+            true,
+            true,
+            false,
+            None,
+            None,
+            Some(HARNESS_STORE_PREFIX),
+        );
+        let terminal = Terminal::new(TestBackend::new(
+            HARNESS_SCREEN_WIDTH,
+            HARNESS_SCREEN_HEIGHT,
+        ))?;
+
+        let mut harness = Self {
+            app,
+            terminal,
+            _store: store,
+        };
+        harness.refresh()?;
+        Ok(harness)
+    }
+
+    /// Starts a new game from the splash screen and goes through the team creation,
+    /// picking the first available players.
+    pub fn with_new_team(seed: u64, team_name: &str, ship_name: &str) -> AppResult<Self> {
+        let mut harness = Self::new(seed)?;
+        harness.call(UiCallback::NewGame)?;
+        // Galaxy generation: keep the defaults.
+        harness.press(KeyCode::Enter)?;
+
+        harness.type_text(team_name)?;
+        harness.press(KeyCode::Enter)?;
+        harness.type_text(ship_name)?;
+        harness.press(KeyCode::Enter)?;
+        // Planet, jersey and ship model: keep the defaults.
+        for _ in 0..3 {
+            harness.press(KeyCode::Enter)?;
+        }
+
+        // The galaxy is generated anew, so hire the cheapest players
+        // to be sure that the team is affordable, then confirm.
+        let (players_to_select, hiring_costs) = harness.player_hiring_costs();
+        let mut cheapest = (0..hiring_costs.len()).collect::<Vec<usize>>();
+        cheapest.sort_by_key(|&index| hiring_costs[index]);
+        cheapest.truncate(players_to_select);
+        cheapest.sort();
+
+        let mut player_index = 0;
+        for index in cheapest {
+            while player_index < index {
+                harness.press(KeyCode::Down)?;
+                player_index += 1;
+            }
+            harness.press(KeyCode::Enter)?;
+        }
+        harness.press(KeyCode::Enter)?;
+        if !harness.app.world.has_own_team() {
+            return Err(anyhow::anyhow!("Could not complete team creation"));
+        }
+        harness.close_popups()?;
+
+        Ok(harness)
+    }
+
+    // Number of players to select and hiring cost of each player listed
+    // in the team creation screen, from the top of the list.
+    fn player_hiring_costs(&self) -> (usize, Vec<u32>) {
+        let lines = self.screen_lines();
+        let Some(title_row) = lines.iter().position(|line| line.contains(" players─")) else {
+            return (0, vec![]);
+        };
+        let players_to_select = lines[title_row]
+            .split("Select ")
+            .nth(1)
+            .and_then(|text| text.split(' ').next())
+            .and_then(|count| count.parse().ok())
+            .unwrap_or_default();
+
+        let hiring_costs = lines[title_row + 1..]
+            .iter()
+            .take_while(|line| !line.trim_start().starts_with('╰'))
+            .filter_map(|line| {
+                let row = line.split('│').nth(1)?.trim_end();
+                row.strip_suffix(" sat")?
+                    .split_whitespace()
+                    .last()?
+                    .parse()
+                    .ok()
+            })
+            .collect();
+        (players_to_select, hiring_costs)
+    }
+
+    pub fn ui_state(&self) -> &UiState {
+        self.app.ui.state()
+    }
+
+    /// Dismisses all popups, such as the tutorial shown after team creation.
+    pub fn close_popups(&mut self) -> AppResult<()> {
+        while self.app.ui.popup_count() > 0 {
+            self.app.ui.close_popup();
+        }
+        self.refresh()
+    }
+
+    /// Updates the ui and draws a new frame.
+    /// Clickable areas are registered during rendering, so this must run after every event.
+    pub fn refresh(&mut self) -> AppResult<()> {
+        self.app
+            .ui
+            .update(&self.app.world, self.app.audio_player.as_ref())?;
+        self.draw()
+    }
+
+    pub fn draw(&mut self) -> AppResult<()> {
+        let ui = &mut self.app.ui;
+        let world = &self.app.world;
+        self.terminal
+            .draw(|frame| App::render(ui, world, None, frame))?;
+        Ok(())
+    }
+
+    pub fn call(&mut self, callback: UiCallback) -> AppResult<Option<String>> {
+        let result = callback.call(&mut self.app);
+        self.refresh()?;
+        result
+    }
+
+    pub fn press(&mut self, key_code: KeyCode) -> AppResult<()> {
+        self.press_with_modifiers(key_code, KeyModifiers::NONE)
+    }
+
+    pub fn press_with_modifiers(
+        &mut self,
+        key_code: KeyCode,
+        modifiers: KeyModifiers,
+    ) -> AppResult<()> {
+        self.app
+            .handle_key_events(KeyEvent::new(key_code, modifiers))?;
+        self.refresh()
+    }
+
+    pub fn type_text(&mut self, text: &str) -> AppResult<()> {
+        for c in text.chars() {
+            self.press(KeyCode::Char(c))?;
+        }
+        Ok(())
+    }
+
+    pub fn mouse(&mut self, kind: MouseEventKind, column: u16, row: u16) -> AppResult<()> {
+        self.app.handle_mouse_events(MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })?;
+        self.refresh()
+    }
+
+    pub fn click(&mut self, column: u16, row: u16) -> AppResult<()> {
+        self.mouse(MouseEventKind::Down(MouseButton::Left), column, row)
+    }
+
+    /// Clicks on the first occurrence of the given text on screen.
+    pub fn click_text(&mut self, text: &str) -> AppResult<()> {
+        let (column, row) = self
+            .find_text(text)
+            .ok_or(anyhow::anyhow!("Text '{}' not found on screen", text))?;
+        self.click(column, row)
+    }
+
+    /// Advances the world simulation by the given amount of time.
+    /// The world runs on its own clock, which is moved forward one short tick at a time.
+    pub fn advance(&mut self, duration: Tick) -> AppResult<()> {
+        let target = self.app.world.last_tick_short_interval + duration;
+        while self.app.world.last_tick_short_interval < target {
+            let tick = self.app.world.last_tick_short_interval + TickInterval::SHORT;
+            self.app.world.time_offset = tick as i64 - Tick::now() as i64;
+            self.app.handle_tick_events(tick)?;
+        }
+        self.draw()
+    }
+
+    pub fn screen_lines(&self) -> Vec<String> {
+        let buffer = self.terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    pub fn screen_text(&self) -> String {
+        self.screen_lines().join("\n")
+    }
+
+    pub fn screen_contains(&self, text: &str) -> bool {
+        self.find_text(text).is_some()
+    }
+
+    pub fn find_text(&self, text: &str) -> Option<(u16, u16)> {
+        for (row, line) in self.screen_lines().iter().enumerate() {
+            if let Some(byte_index) = line.find(text) {
+                let column = line[..byte_index].chars().count();
+                return Some((column as u16, row as u16));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TestHarness;
+    use crate::types::{AppResult, StorableResourceMap};
+    use crate::ui::constants::UiKey;
+    use crate::ui::ui::{UiState, UiTab};
+    use crate::world::constants::{HOURS, MINUTES, SECONDS};
+    use crate::world::resources::Resource;
+    use crossterm::event::KeyCode;
+
+    #[test]
+    fn test_harness_team_creation() -> AppResult<()> {
+        let harness = TestHarness::with_new_team(0, "Harness", "Testship")?;
+        assert_eq!(*harness.ui_state(), UiState::Main);
+
+        let own_team = harness.app.world.get_own_team()?;
+        assert_eq!(own_team.name, "Harness");
+        assert!(own_team.player_ids.len() > 0);
+        assert!(harness.screen_contains("Harness"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_harness_cycle_my_team_views() -> AppResult<()> {
+        let mut harness = TestHarness::with_new_team(1, "Cyclers", "Loopship")?;
+        assert!(harness.screen_contains("View: Info"));
+        // Every view fits on screen, down to the last one.
+        assert!(harness.screen_contains("View: Advisor"));
+
+        harness.click_text("View: Finances")?;
+        assert!(harness.screen_contains("Weekly summary"));
+
+        harness.press(KeyCode::Char('V'))?;
+        assert!(!harness.screen_contains("Weekly summary"));
+        assert!(harness.screen_contains("View: Finances"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_harness_trade() -> AppResult<()> {
+        let mut harness = TestHarness::with_new_team(2, "Traders", "Cargoship")?;
+        // The own team starts with an empty treasury.
+        let mut own_team = harness.app.world.get_own_team()?.clone();
+        own_team
+            .resources
+            .saturating_add(Resource::SATOSHI, 10_000, u32::MAX);
+        harness.app.world.teams.insert(own_team.id, own_team);
+        let balance = harness.app.world.get_own_team()?.balance();
+        let scraps = harness
+            .app
+            .world
+            .get_own_team()?
+            .resources
+            .value(&Resource::SCRAPS);
+        let entries = harness.app.world.finances.entries.len();
+
+        // The market comes right after the games view.
+        harness.press(UiKey::CYCLE_VIEW)?;
+        harness.press(UiKey::CYCLE_VIEW)?;
+        assert!(harness.screen_contains("Buy/Sell"));
+        harness.press(UiKey::BUY_SCRAPS)?;
+
+        let own_team = harness.app.world.get_own_team()?;
+        assert!(own_team.balance() < balance);
+        assert_eq!(own_team.resources.value(&Resource::SCRAPS), scraps + 1);
+        assert_eq!(harness.app.world.finances.entries.len(), entries + 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_harness_challenge() -> AppResult<()> {
+        let mut harness = TestHarness::with_new_team(4, "Challengers", "Duelship")?;
+        let games_played: u32 = harness.app.world.get_own_team()?.game_record.iter().sum();
+
+        // Pick the first team in the list that can be challenged.
+        harness.press(UiKey::NEXT_TAB)?;
+        for _ in 0..harness.app.ui.team_panel.teams.len() {
+            let own_team = harness.app.world.get_own_team()?;
+            let team = harness
+                .app
+                .world
+                .get_team_or_err(harness.app.ui.team_panel.selected_team_id)?;
+            if own_team.can_challenge_team(team).is_ok() {
+                break;
+            }
+            harness.press(KeyCode::Down)?;
+        }
+        harness.press(UiKey::CHALLENGE_TEAM)?;
+        assert!(harness.app.world.get_own_team()?.current_game.is_some());
+        assert_eq!(harness.app.ui.active_tab(), UiTab::Games);
+
+        // Play the game until the end.
+        for _ in 0..2 * HOURS / MINUTES {
+            if harness.app.world.get_own_team()?.current_game.is_none() {
+                break;
+            }
+            harness.advance(MINUTES)?;
+        }
+        let own_team = harness.app.world.get_own_team()?;
+        assert!(own_team.current_game.is_none());
+        assert_eq!(own_team.game_record.iter().sum::<u32>(), games_played + 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_harness_space_adventure() -> AppResult<()> {
+        let mut harness = TestHarness::with_new_team(5, "Explorers", "Rocketship")?;
+
+        // The current pilot is proposed first in the pilot selection.
+        harness.press(UiKey::SPACE_ADVENTURE)?;
+        harness.press(UiKey::YES_TO_DIALOG)?;
+        assert_eq!(*harness.ui_state(), UiState::SpaceAdventure);
+        assert!(harness.app.world.space_adventure.is_some());
+
+        // Flying back to the base fades out over a few seconds of world time.
+        harness.press(UiKey::SPACE_BACK_TO_BASE)?;
+        harness.advance(5 * SECONDS)?;
+        assert!(harness.app.world.space_adventure.is_none());
+        assert_eq!(*harness.ui_state(), UiState::Main);
+        Ok(())
+    }
+
+    #[test]
+    fn test_harness_advance_time() -> AppResult<()> {
+        let mut harness = TestHarness::with_new_team(3, "Sleepers", "Napship")?;
+        let start = harness.app.world.last_tick_short_interval;
+        harness.advance(HOURS)?;
+        assert!(harness.app.world.last_tick_short_interval >= start + HOURS);
+        assert!(harness.app.world.last_tick_short_interval < start + HOURS + MINUTES);
+        assert_eq!(*harness.ui_state(), UiState::Main);
+        Ok(())
+    }
+}
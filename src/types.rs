@@ -24,6 +24,8 @@ pub type TeamId = uuid::Uuid;
 pub type PlanetId = uuid::Uuid;
 pub type GameId = uuid::Uuid;
 pub type KartoffelId = uuid::Uuid;
+pub type ContractId = uuid::Uuid;
+pub type TournamentId = uuid::Uuid;
 
 // pub type AppResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 pub type AppResult<T> = Result<T, anyhow::Error>;
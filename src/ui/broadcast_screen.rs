@@ -0,0 +1,281 @@
+use super::big_numbers::{hyphen, BigNumberFont};
+use super::constants::{UiKey, UiStyle};
+use super::game_panel::play_by_play_lines;
+use super::ui_callback::{CallbackRegistry, UiCallback};
+use super::widgets::default_block;
+use super::{traits::Screen, utils::big_text};
+use crate::game_engine::{game::Game, types::Possession};
+use crate::types::{AppResult, GameId};
+use crate::world::world::World;
+use core::fmt::Debug;
+use crossterm::event::KeyCode;
+use itertools::Itertools;
+use ratatui::layout::{Constraint, Layout, Margin};
+use ratatui::text::Line;
+use ratatui::widgets::Wrap;
+use ratatui::{prelude::Rect, widgets::Paragraph, Frame};
+use std::sync::{Arc, Mutex};
+
+const DIGIT_WIDTH: u16 = 8;
+const HYPHEN_WIDTH: u16 = 5;
+const BIG_TEXT_HEIGHT: u16 = 6;
+
+const WAITING: [&'static str; 6] = [
+    "██████╗ ███████╗██████╗ ███████╗██╗     ███████╗",
+    "██╔══██╗██╔════╝██╔══██╗██╔════╝██║     ██╔════╝",
+    "██████╔╝█████╗  ██████╔╝█████╗  ██║     ███████╗",
+    "██╔══██╗██╔══╝  ██╔══██╗██╔══╝  ██║     ╚════██║",
+    "██║  ██║███████╗██████╔╝███████╗███████╗███████║",
+    "╚═╝  ╚═╝╚══════╝╚═════╝ ╚══════╝╚══════╝╚══════╝",
+];
+
+/// Read-only full screen view of a single game, meant to be streamed or projected.
+/// When the followed game ends, the screen moves on to the next ongoing one.
+#[derive(Debug, Default)]
+pub struct BroadcastScreen {
+    game_id: Option<GameId>,
+    _callback_registry: Arc<Mutex<CallbackRegistry>>,
+}
+
+impl BroadcastScreen {
+    pub fn new(_callback_registry: Arc<Mutex<CallbackRegistry>>) -> Self {
+        Self {
+            _callback_registry,
+            ..Default::default()
+        }
+    }
+
+    pub fn set_game_id(&mut self, game_id: Option<GameId>) {
+        self.game_id = game_id;
+    }
+
+    // Ongoing games, with the own team ones first and then by starting time.
+    fn ongoing_games(world: &World) -> Vec<GameId> {
+        world
+            .games
+            .values()
+            .filter(|game| !game.timer.has_ended())
+            .sorted_by_key(|game| {
+                let is_own_game = game.home_team_in_game.team_id == world.own_team_id
+                    || game.away_team_in_game.team_id == world.own_team_id;
+                (!is_own_game, game.starting_at, game.id)
+            })
+            .map(|game| game.id)
+            .collect()
+    }
+
+    fn cycle_game(&mut self, world: &World, forward: bool) {
+        let games = Self::ongoing_games(world);
+        if games.is_empty() {
+            return;
+        }
+
+        let index = match self
+            .game_id
+            .and_then(|id| games.iter().position(|&game_id| game_id == id))
+        {
+            Some(index) if forward => (index + 1) % games.len(),
+            Some(index) => (index + games.len() - 1) % games.len(),
+            None => 0,
+        };
+        self.game_id = Some(games[index]);
+    }
+
+    fn render_score(&self, frame: &mut Frame, game: &Game, area: Rect) {
+        let action = &game.action_results[game.action_results.len() - 1];
+        let home_digits = action.home_score.to_string();
+        let away_digits = action.away_score.to_string();
+
+        // Home digits, hyphen and away digits, each followed by a separator.
+        let mut constraints = vec![Constraint::Min(0)];
+        for _ in home_digits.chars() {
+            constraints.push(Constraint::Length(DIGIT_WIDTH));
+            constraints.push(Constraint::Length(1));
+        }
+        constraints.push(Constraint::Length(HYPHEN_WIDTH));
+        constraints.push(Constraint::Length(1));
+        for _ in away_digits.chars() {
+            constraints.push(Constraint::Length(DIGIT_WIDTH));
+            constraints.push(Constraint::Length(1));
+        }
+        constraints.push(Constraint::Min(0));
+
+        let split = Layout::horizontal(constraints).split(area);
+        let mut idx = 1;
+        for digit in home_digits.chars() {
+            let digit = digit.to_digit(10).unwrap_or_default() as u8;
+            frame.render_widget(digit.big_font(), split[idx]);
+            idx += 2;
+        }
+        frame.render_widget(hyphen(), split[idx]);
+        idx += 2;
+        for digit in away_digits.chars() {
+            let digit = digit.to_digit(10).unwrap_or_default() as u8;
+            frame.render_widget(digit.big_font(), split[idx]);
+            idx += 2;
+        }
+    }
+
+    fn timer_line(world: &World, game: &Game) -> String {
+        let timer = game.timer;
+        if !timer.has_started() {
+            let starting_in_seconds = (game.starting_at - world.last_tick_short_interval) / 1000;
+            format!(
+                "Starting in {:02}:{:02}",
+                starting_in_seconds / 60,
+                starting_in_seconds % 60
+            )
+        } else if timer.has_ended() {
+            format!(
+                "{} - Final",
+                timer.at(timer.period_end(timer.period())).format()
+            )
+        } else if timer.is_break() {
+            format!(
+                "{} - Resuming in {:02}:{:02}",
+                timer.at(timer.period_end(timer.period())).format(),
+                timer.minutes(),
+                timer.seconds()
+            )
+        } else {
+            timer.format()
+        }
+    }
+
+    fn render_game(
+        &self,
+        frame: &mut Frame,
+        world: &World,
+        game: &Game,
+        area: Rect,
+    ) -> AppResult<()> {
+        let split = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1), // teams
+            Constraint::Length(1), // planet
+            Constraint::Length(1),
+            Constraint::Length(BIG_TEXT_HEIGHT), // score
+            Constraint::Length(1),
+            Constraint::Length(1), // timer
+            Constraint::Length(1),
+            Constraint::Min(3), // commentary
+        ])
+        .split(area.inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        }));
+
+        let action = &game.action_results[game.action_results.len() - 1];
+        let home_dot = if action.possession == Possession::Home {
+            "●"
+        } else {
+            " "
+        };
+        let away_dot = if action.possession == Possession::Away {
+            "●"
+        } else {
+            " "
+        };
+        frame.render_widget(
+            Paragraph::new(Line::from(format!(
+                "{} {} vs {} {}",
+                home_dot, game.home_team_in_game.name, game.away_team_in_game.name, away_dot
+            )))
+            .style(UiStyle::current().highlight)
+            .centered(),
+            split[1],
+        );
+
+        let planet = world.get_planet_or_err(game.location)?;
+        frame.render_widget(
+            Paragraph::new(format!("Playing on {}", planet.name)).centered(),
+            split[2],
+        );
+
+        self.render_score(frame, game, split[4]);
+
+        frame.render_widget(
+            Paragraph::new(Self::timer_line(world, game)).centered(),
+            split[6],
+        );
+
+        // The commentary follows the game: newest action first.
+        let mut lines = play_by_play_lines(&game.action_results);
+        lines.truncate(split[8].height as usize);
+        if let Some(line) = lines.first_mut() {
+            *line = line.clone().style(UiStyle::current().highlight);
+        }
+        frame.render_widget(
+            Paragraph::new(lines).centered().wrap(Wrap { trim: true }),
+            split[8],
+        );
+
+        Ok(())
+    }
+}
+
+impl Screen for BroadcastScreen {
+    fn update(&mut self, world: &World) -> AppResult<()> {
+        // Keep showing the final score of the followed game until another one is ongoing.
+        let ongoing_games = Self::ongoing_games(world);
+        let is_followed_game_over = match self.game_id.and_then(|id| world.get_game(id)) {
+            Some(game) => game.timer.has_ended() && !ongoing_games.is_empty(),
+            None => true,
+        };
+        if is_followed_game_over {
+            self.game_id = ongoing_games.first().copied();
+        }
+
+        Ok(())
+    }
+
+    fn render(
+        &mut self,
+        frame: &mut Frame,
+        world: &World,
+        area: Rect,
+        _debug_view: bool,
+    ) -> AppResult<()> {
+        let game = self.game_id.and_then(|id| world.get_game(id));
+        let block = default_block().title(format!(
+            " Broadcast - {} to exit, ↓/↑ to switch game ",
+            UiKey::BROADCAST_MODE.to_string()
+        ));
+
+        if let Some(game) = game {
+            frame.render_widget(block, area);
+            return self.render_game(frame, world, game, area);
+        }
+
+        let split = Layout::vertical([
+            Constraint::Min(0),
+            Constraint::Length(BIG_TEXT_HEIGHT),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(0),
+        ])
+        .split(area);
+        frame.render_widget(block, area);
+        frame.render_widget(big_text(&WAITING).centered(), split[1]);
+        frame.render_widget(
+            Paragraph::new("Waiting for the next game...").centered(),
+            split[3],
+        );
+
+        Ok(())
+    }
+
+    fn handle_key_events(
+        &mut self,
+        key_event: crossterm::event::KeyEvent,
+        world: &World,
+    ) -> Option<UiCallback> {
+        match key_event.code {
+            UiKey::BROADCAST_MODE => return Some(UiCallback::ToggleBroadcastMode),
+            KeyCode::Up | UiKey::PREVIOUS_SELECTION => self.cycle_game(world, false),
+            KeyCode::Down | UiKey::NEXT_SELECTION => self.cycle_game(world, true),
+            _ => {}
+        }
+        None
+    }
+}
@@ -54,8 +54,8 @@ impl<'a> Button<'a> {
             disabled: false,
             disabled_text: None,
             text_alignemnt: ratatui::layout::Alignment::Center,
-            style: UiStyle::UNSELECTED,
-            hover_style: UiStyle::SELECTED,
+            style: UiStyle::current().unselected,
+            hover_style: UiStyle::current().selected,
             box_style: Some(Style::default()),
             box_hover_style: Some(Style::default()),
             hover_text: None,
@@ -77,8 +77,8 @@ impl<'a> Button<'a> {
             disabled: false,
             disabled_text: None,
             text_alignemnt: ratatui::layout::Alignment::Center,
-            style: UiStyle::UNSELECTED,
-            hover_style: UiStyle::UNSELECTED,
+            style: UiStyle::current().unselected,
+            hover_style: UiStyle::current().unselected,
             box_style: None,
             box_hover_style: Some(Style::default()),
             hover_text: None,
@@ -100,8 +100,8 @@ impl<'a> Button<'a> {
             disabled: false,
             disabled_text: None,
             text_alignemnt: ratatui::layout::Alignment::Center,
-            style: UiStyle::UNSELECTED,
-            hover_style: UiStyle::SELECTED,
+            style: UiStyle::current().unselected,
+            hover_style: UiStyle::current().selected,
             box_style: None,
             box_hover_style: None,
             hover_text: None,
@@ -123,8 +123,8 @@ impl<'a> Button<'a> {
             disabled: false,
             disabled_text: None,
             text_alignemnt: ratatui::layout::Alignment::Left,
-            style: UiStyle::UNSELECTED,
-            hover_style: UiStyle::SELECTED,
+            style: UiStyle::current().unselected,
+            hover_style: UiStyle::current().selected,
             box_style: None,
             box_hover_style: None,
             hover_text: None,
@@ -226,7 +226,7 @@ impl<'a> Widget for Button<'a> {
             if split.len() > 1 {
                 Paragraph::new(Line::from(vec![
                     Span::raw(split[0].clone()),
-                    Span::styled(u.to_string(), UiStyle::DEFAULT.underlined()),
+                    Span::styled(u.to_string(), UiStyle::current().default.underlined()),
                     Span::raw(split[1].clone()),
                 ]))
                 .alignment(self.text_alignemnt)
@@ -239,7 +239,9 @@ impl<'a> Widget for Button<'a> {
 
         if area.height < 3 {
             if self.disabled {
-                paragraph.set_style(UiStyle::UNSELECTABLE).render(area, buf);
+                paragraph
+                    .set_style(UiStyle::current().unselectable)
+                    .render(area, buf);
             } else if self.is_hovered(inner) {
                 paragraph.set_style(self.hover_style).render(area, buf);
             } else {
@@ -248,12 +250,12 @@ impl<'a> Widget for Button<'a> {
         } else if self.disabled {
             if let Some(box_style) = self.box_style {
                 paragraph
-                    .set_style(UiStyle::UNSELECTABLE)
+                    .set_style(UiStyle::current().unselectable)
                     .block(default_block().border_style(box_style))
                     .render(area, buf);
             } else {
                 paragraph
-                    .set_style(UiStyle::UNSELECTABLE)
+                    .set_style(UiStyle::current().unselectable)
                     .render(inner, buf);
             }
         } else if self.is_hovered(inner) {
@@ -283,7 +285,7 @@ impl<'a> Widget for Button<'a> {
                 if let Some(text) = self.disabled_text.as_ref() {
                     spans.push(Span::styled(
                         format!("  Disabled: {}", text),
-                        UiStyle::ERROR,
+                        UiStyle::current().error,
                     ));
                 }
             }
@@ -351,8 +353,8 @@ impl<'a> RadioButton<'a> {
             disabled: false,
             linked_index,
             index,
-            style: UiStyle::UNSELECTED,
-            hover_style: UiStyle::SELECTED,
+            style: UiStyle::current().unselected,
+            hover_style: UiStyle::current().selected,
             box_style: Some(Style::default()),
             box_hover_style: Some(Style::default()),
             box_hover_title: None,
@@ -373,8 +375,8 @@ impl<'a> RadioButton<'a> {
             disabled: false,
             linked_index,
             index,
-            style: UiStyle::UNSELECTED,
-            hover_style: UiStyle::UNSELECTED,
+            style: UiStyle::current().unselected,
+            hover_style: UiStyle::current().unselected,
             box_style: None,
             box_hover_style: Some(Style::default()),
             box_hover_title: None,
@@ -396,8 +398,8 @@ impl<'a> RadioButton<'a> {
             disabled: false,
             linked_index,
             index,
-            style: UiStyle::UNSELECTED,
-            hover_style: UiStyle::SELECTED,
+            style: UiStyle::current().unselected,
+            hover_style: UiStyle::current().selected,
             box_style: None,
             box_hover_style: None,
             box_hover_title: None,
@@ -457,7 +459,7 @@ impl<'a> Widget for RadioButton<'a> {
         let paragraph = if self.disabled {
             Paragraph::new(self.text)
                 .centered()
-                .style(UiStyle::UNSELECTABLE)
+                .style(UiStyle::current().unselectable)
         } else {
             if *self.linked_index == self.index {
                 Paragraph::new(self.text).centered().style(self.hover_style)
@@ -0,0 +1,156 @@
+use super::clickable_list::ClickableListState;
+use super::constants::*;
+use super::traits::{Screen, SplitPanel};
+use super::ui_callback::{CallbackRegistry, UiCallback};
+use super::widgets::{default_block, selectable_list};
+use crate::types::{AppResult, SystemTimeTick, Tick};
+use crate::world::calendar::{local_timezone, CalendarEvent};
+use crate::world::constants::HOURS;
+use crate::world::world::World;
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Layout, Margin},
+    prelude::Rect,
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Default)]
+pub struct CalendarPanel {
+    index: usize,
+    events: Vec<CalendarEvent>,
+    now: Tick,
+    callback_registry: Arc<Mutex<CallbackRegistry>>,
+}
+
+impl CalendarPanel {
+    pub fn new(callback_registry: Arc<Mutex<CallbackRegistry>>) -> Self {
+        Self {
+            callback_registry,
+            ..Default::default()
+        }
+    }
+
+    fn render_event_list(&self, frame: &mut Frame, area: Rect) {
+        frame.render_widget(
+            default_block().title(format!("Upcoming events ({}) ", local_timezone())),
+            area,
+        );
+
+        let options = self
+            .events
+            .iter()
+            .map(|event| {
+                // Events in the next hour are highlighted.
+                let style = if event.tick < self.now + HOURS {
+                    UiStyle::current().warning
+                } else {
+                    UiStyle::current().default
+                };
+                (
+                    format!(
+                        " {:<20} {:<11} {}",
+                        event.tick.formatted_as_date(),
+                        event.kind.to_string(),
+                        event.description
+                    ),
+                    style,
+                )
+            })
+            .collect();
+
+        let list = selectable_list(options, &self.callback_registry);
+        frame.render_stateful_widget(
+            list,
+            area.inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+            &mut ClickableListState::default().with_selected(Some(self.index)),
+        );
+    }
+
+    fn render_event_details(&self, frame: &mut Frame, area: Rect) {
+        frame.render_widget(default_block().title("Details"), area);
+        let event = match self.events.get(self.index) {
+            Some(event) => event,
+            None => return,
+        };
+
+        let lines = vec![
+            Line::from(Span::styled(
+                event.description.clone(),
+                UiStyle::current().header,
+            )),
+            Line::from(""),
+            Line::from(format!("{:<10} {}", "Type", event.kind)),
+            Line::from(format!("{:<10} {}", "When", event.tick.formatted_as_date())),
+            Line::from(format!("{:<10} {}", "Time zone", local_timezone())),
+            Line::from(vec![
+                Span::raw(format!("{:<10} ", "Countdown")),
+                Span::styled(event.countdown(self.now), UiStyle::current().highlight),
+            ]),
+        ];
+
+        frame.render_widget(
+            Paragraph::new(lines),
+            area.inner(Margin {
+                horizontal: 2,
+                vertical: 1,
+            }),
+        );
+    }
+}
+
+impl Screen for CalendarPanel {
+    fn update(&mut self, world: &World) -> AppResult<()> {
+        self.now = world.last_tick_short_interval;
+        self.events = world.calendar_events()?;
+        if self.index >= self.events.len() {
+            self.index = self.events.len().saturating_sub(1);
+        }
+        Ok(())
+    }
+
+    fn render(
+        &mut self,
+        frame: &mut Frame,
+        _world: &World,
+        area: Rect,
+        _debug_view: bool,
+    ) -> AppResult<()> {
+        let split = Layout::horizontal([Constraint::Min(72), Constraint::Length(48)]).split(area);
+        self.render_event_list(frame, split[0]);
+        self.render_event_details(frame, split[1]);
+        Ok(())
+    }
+
+    fn handle_key_events(
+        &mut self,
+        key_event: crossterm::event::KeyEvent,
+        _world: &World,
+    ) -> Option<UiCallback> {
+        match key_event.code {
+            KeyCode::Up => self.next_index(),
+            KeyCode::Down => self.previous_index(),
+            _ => {}
+        }
+        None
+    }
+}
+
+impl SplitPanel for CalendarPanel {
+    fn index(&self) -> usize {
+        self.index
+    }
+
+    fn max_index(&self) -> usize {
+        self.events.len()
+    }
+
+    fn set_index(&mut self, index: usize) {
+        self.index = index;
+    }
+}
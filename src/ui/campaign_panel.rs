@@ -0,0 +1,204 @@
+use super::clickable_list::ClickableListState;
+use super::constants::*;
+use super::traits::{Screen, SplitPanel};
+use super::ui_callback::{CallbackRegistry, UiCallback};
+use super::widgets::{default_block, selectable_list};
+use crate::types::{AppResult, SystemTimeTick};
+use crate::world::campaign::{Campaign, CampaignChapter};
+use crate::world::world::World;
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Layout, Margin},
+    prelude::Rect,
+    text::{Line, Span},
+    widgets::{Paragraph, Wrap},
+    Frame,
+};
+use std::sync::{Arc, Mutex};
+use strum::IntoEnumIterator;
+
+#[derive(Debug, Default)]
+pub struct CampaignPanel {
+    index: usize,
+    chapters: Vec<CampaignChapter>,
+    campaign: Option<Campaign>,
+    rival_description: Option<String>,
+    callback_registry: Arc<Mutex<CallbackRegistry>>,
+}
+
+impl CampaignPanel {
+    pub fn new(callback_registry: Arc<Mutex<CallbackRegistry>>) -> Self {
+        Self {
+            callback_registry,
+            chapters: CampaignChapter::iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    fn render_chapter_list(&self, frame: &mut Frame, campaign: &Campaign, area: Rect) {
+        frame.render_widget(default_block().title("Campaign "), area);
+
+        let options = self
+            .chapters
+            .iter()
+            .map(|chapter| {
+                let (symbol, style) = if campaign
+                    .completed_chapters
+                    .iter()
+                    .any(|(completed, _)| completed == chapter)
+                    || (chapter.is_final() && campaign.is_completed())
+                {
+                    ("✔", UiStyle::current().ok)
+                } else if *chapter == campaign.chapter {
+                    ("▶", UiStyle::current().highlight)
+                } else {
+                    (" ", UiStyle::current().unselectable)
+                };
+                (
+                    format!(" {} {}. {}", symbol, *chapter as u8 + 1, chapter),
+                    style,
+                )
+            })
+            .collect();
+
+        let list = selectable_list(options, &self.callback_registry);
+        frame.render_stateful_widget(
+            list,
+            area.inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+            &mut ClickableListState::default().with_selected(Some(self.index)),
+        );
+    }
+
+    fn render_chapter_details(&self, frame: &mut Frame, campaign: &Campaign, area: Rect) {
+        frame.render_widget(default_block().title("Details"), area);
+        let chapter = match self.chapters.get(self.index) {
+            Some(chapter) => *chapter,
+            None => return,
+        };
+
+        let completed_at = campaign
+            .completed_chapters
+            .iter()
+            .find(|(completed, _)| *completed == chapter)
+            .map(|(_, tick)| *tick);
+        let is_reached = completed_at.is_some() || chapter == campaign.chapter;
+
+        let mut lines = vec![
+            Line::from(Span::styled(chapter.to_string(), UiStyle::current().header)),
+            Line::from(""),
+        ];
+
+        // Chapters ahead stay a mystery until they are reached.
+        if !is_reached {
+            lines.push(Line::from(Span::styled(
+                "Keep going to unlock this chapter.",
+                UiStyle::current().unselectable,
+            )));
+        } else {
+            lines.push(Line::from(chapter.story()));
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![
+                Span::raw(format!("{:<10} ", "Objective")),
+                Span::styled(chapter.objective(), UiStyle::current().highlight),
+            ]));
+            if !chapter.is_final() {
+                lines.push(Line::from(format!(
+                    "{:<10} {}",
+                    "Rewards",
+                    chapter.rewards_description()
+                )));
+            }
+            if let Some(tick) = completed_at {
+                lines.push(Line::from(Span::styled(
+                    format!("{:<10} {}", "Completed", tick.formatted_as_date()),
+                    UiStyle::current().ok,
+                )));
+            } else if let Some(rival) = self.rival_description.as_ref() {
+                lines.push(Line::from(format!("{:<10} {}", "Rival", rival)));
+            }
+        }
+
+        frame.render_widget(
+            Paragraph::new(lines).wrap(Wrap { trim: true }),
+            area.inner(Margin {
+                horizontal: 2,
+                vertical: 1,
+            }),
+        );
+    }
+}
+
+impl Screen for CampaignPanel {
+    fn update(&mut self, world: &World) -> AppResult<()> {
+        if world.dirty_ui || self.campaign != world.campaign {
+            self.campaign = world.campaign.clone();
+            self.rival_description = None;
+            if let Some(rival_id) = self.campaign.as_ref().and_then(|c| c.rival_id) {
+                if let Ok(rival) = world.get_team_or_err(rival_id) {
+                    let planet_name = world
+                        .get_planet_or_err(rival.home_planet_id)
+                        .map(|planet| planet.name.clone())
+                        .unwrap_or_default();
+                    self.rival_description = Some(format!("{} on {}", rival.name, planet_name));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn render(
+        &mut self,
+        frame: &mut Frame,
+        _world: &World,
+        area: Rect,
+        _debug_view: bool,
+    ) -> AppResult<()> {
+        let campaign = match self.campaign.as_ref() {
+            Some(campaign) => campaign,
+            None => {
+                frame.render_widget(
+                    Paragraph::new("Campaign mode can be selected when creating a new world.")
+                        .centered()
+                        .block(default_block().title("Campaign ")),
+                    area,
+                );
+                return Ok(());
+            }
+        };
+
+        let split = Layout::horizontal([Constraint::Length(36), Constraint::Min(48)]).split(area);
+        self.render_chapter_list(frame, campaign, split[0]);
+        self.render_chapter_details(frame, campaign, split[1]);
+        Ok(())
+    }
+
+    fn handle_key_events(
+        &mut self,
+        key_event: crossterm::event::KeyEvent,
+        _world: &World,
+    ) -> Option<UiCallback> {
+        match key_event.code {
+            KeyCode::Up => self.next_index(),
+            KeyCode::Down => self.previous_index(),
+            _ => {}
+        }
+        None
+    }
+}
+
+impl SplitPanel for CampaignPanel {
+    fn index(&self) -> usize {
+        self.index
+    }
+
+    fn max_index(&self) -> usize {
+        self.chapters.len()
+    }
+
+    fn set_index(&mut self, index: usize) {
+        self.index = index;
+    }
+}
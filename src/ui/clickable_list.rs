@@ -1,4 +1,7 @@
-use super::ui_callback::{CallbackRegistry, UiCallback};
+use super::{
+    scrollbar::render_scrollbar,
+    ui_callback::{CallbackRegistry, UiCallback},
+};
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
@@ -214,7 +217,7 @@ impl StatefulWidget for ClickableList<'_> {
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         buf.set_style(area, self.style);
         self.block.render(area, buf);
-        let list_area = self.block.inner_if_some(area);
+        let mut list_area = self.block.inner_if_some(area);
 
         if list_area.is_empty() {
             return;
@@ -250,6 +253,23 @@ impl StatefulWidget for ClickableList<'_> {
                 );
         }
 
+        // Reserve the rightmost column for the scrollbar if the items overflow.
+        let items_height = self.items.iter().map(|item| item.height()).sum::<usize>();
+        if items_height > list_area.height as usize && list_area.width > 1 {
+            list_area.width -= 1;
+            render_scrollbar(
+                Rect {
+                    x: list_area.right(),
+                    width: 1,
+                    ..list_area
+                },
+                buf,
+                self.items.len(),
+                state.selected.unwrap_or(state.offset),
+                &self.callback_registry,
+            );
+        }
+
         let list_height = list_area.height as usize;
 
         let (first_visible_index, last_visible_index) =
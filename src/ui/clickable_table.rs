@@ -9,7 +9,10 @@ use ratatui::{
 };
 use unicode_width::UnicodeWidthStr;
 
-use super::ui_callback::{CallbackRegistry, UiCallback};
+use super::{
+    scrollbar::render_scrollbar,
+    ui_callback::{CallbackRegistry, UiCallback},
+};
 
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct ClickableCell<'a> {
@@ -129,6 +132,8 @@ pub struct ClickableTable<'a> {
     highlight_symbol: Option<&'a str>,
     /// Optional header
     header: Option<ClickableRow<'a>>,
+    /// Callbacks triggered by clicking on the header cells, in column order
+    header_callbacks: Vec<UiCallback>,
     /// Data to display in each row
     rows: Vec<ClickableRow<'a>>,
     /// Decides when to allocate spacing for the row selection
@@ -150,6 +155,7 @@ impl<'a> ClickableTable<'a> {
             hovering_style: Style::default(),
             highlight_symbol: None,
             header: None,
+            header_callbacks: vec![],
             rows: rows.into_iter().collect(),
             highlight_spacing: HighlightSpacing::default(),
             callback_registry,
@@ -166,6 +172,11 @@ impl<'a> ClickableTable<'a> {
         self
     }
 
+    pub fn header_callbacks(mut self, header_callbacks: Vec<UiCallback>) -> Self {
+        self.header_callbacks = header_callbacks;
+        self
+    }
+
     pub fn widths(mut self, widths: &'a [Constraint]) -> Self {
         let between_0_and_100 = |&w| match w {
             Constraint::Percentage(p) => p <= 100,
@@ -336,7 +347,7 @@ impl<'a> StatefulWidget for ClickableTable<'a> {
             return;
         }
         buf.set_style(area, self.style);
-        let table_area = match self.block.take() {
+        let mut table_area = match self.block.take() {
             Some(b) => {
                 let inner_area = b.inner(area);
                 b.render(area, buf);
@@ -345,6 +356,33 @@ impl<'a> StatefulWidget for ClickableTable<'a> {
             None => area,
         };
 
+        // Reserve the rightmost column for the scrollbar if the rows overflow.
+        let header_height = self
+            .header
+            .as_ref()
+            .map_or(0, |header| table_area.height.min(header.total_height()));
+        let rows_total_height = self
+            .rows
+            .iter()
+            .fold(0u16, |acc, row| acc.saturating_add(row.total_height()));
+        if rows_total_height > table_area.height.saturating_sub(header_height)
+            && table_area.width > 1
+        {
+            table_area.width -= 1;
+            render_scrollbar(
+                Rect {
+                    x: table_area.right(),
+                    y: table_area.y + header_height,
+                    width: 1,
+                    height: table_area.height.saturating_sub(header_height),
+                },
+                buf,
+                self.rows.len(),
+                state.selected.unwrap_or(state.offset),
+                &self.callback_registry,
+            );
+        }
+
         let selection_width = if state.selected.is_some() {
             self.highlight_symbol.map_or(0, |s| s.width() as u16)
         } else {
@@ -368,17 +406,37 @@ impl<'a> StatefulWidget for ClickableTable<'a> {
                 header.style,
             );
             let inner_offset = table_area.left();
-            for ((x, width), cell) in columns_widths.iter().zip(header.cells.iter()) {
-                render_cell(
-                    buf,
-                    cell,
-                    Rect {
-                        x: inner_offset + x,
-                        y: table_area.top(),
-                        width: *width,
-                        height: max_header_height,
-                    },
-                );
+            for (index, ((x, width), cell)) in
+                columns_widths.iter().zip(header.cells.iter()).enumerate()
+            {
+                let cell_area = Rect {
+                    x: inner_offset + x,
+                    y: table_area.top(),
+                    width: *width,
+                    height: max_header_height,
+                };
+                render_cell(buf, cell, cell_area);
+
+                if let Some(callback) = self.header_callbacks.get(index) {
+                    if self
+                        .callback_registry
+                        .lock()
+                        .unwrap()
+                        .is_hovering(cell_area)
+                    {
+                        buf.set_style(cell_area, self.hovering_style);
+                    }
+                    self.callback_registry
+                        .lock()
+                        .unwrap()
+                        .register_mouse_callback(
+                            crossterm::event::MouseEventKind::Down(
+                                crossterm::event::MouseButton::Left,
+                            ),
+                            Some(cell_area),
+                            callback.clone(),
+                        );
+                }
             }
             current_height += max_header_height;
             rows_height = rows_height.saturating_sub(max_header_height);
@@ -0,0 +1,88 @@
+use crate::types::AppResult;
+use anyhow::anyhow;
+use arboard::Clipboard;
+use strum_macros::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Display)]
+pub enum ClipboardTarget {
+    #[strum(serialize = "peer ID")]
+    PeerId,
+    #[strum(serialize = "world seed")]
+    Seed,
+    #[strum(serialize = "team ID")]
+    TeamId,
+}
+
+impl ClipboardTarget {
+    pub fn from_command_arg(arg: &str) -> Option<Self> {
+        match arg {
+            "peer" | "id" => Some(Self::PeerId),
+            "seed" => Some(Self::Seed),
+            "team" => Some(Self::TeamId),
+            _ => None,
+        }
+    }
+}
+
+/// Handle to the system clipboard, opened on first use and kept for the whole
+/// session: on X11 and Wayland the copied text is only served as long as the
+/// handle that set it is alive.
+#[derive(Default)]
+pub struct SystemClipboard {
+    clipboard: Option<Clipboard>,
+}
+
+impl std::fmt::Debug for SystemClipboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SystemClipboard")
+            .field("is_open", &self.clipboard.is_some())
+            .finish()
+    }
+}
+
+impl SystemClipboard {
+    // The system clipboard is not available everywhere (e.g. over SSH or on
+    // headless machines), so errors are surfaced to the player instead.
+    fn clipboard(&mut self) -> AppResult<&mut Clipboard> {
+        if self.clipboard.is_none() {
+            let clipboard =
+                Clipboard::new().map_err(|e| anyhow!("Clipboard is not available: {}", e))?;
+            self.clipboard = Some(clipboard);
+        }
+        Ok(self.clipboard.as_mut().expect("Clipboard should be open"))
+    }
+
+    pub fn copy(&mut self, text: &str) -> AppResult<()> {
+        self.clipboard()?
+            .set_text(text)
+            .map_err(|e| anyhow!("Clipboard is not available: {}", e))
+    }
+
+    pub fn paste(&mut self) -> AppResult<String> {
+        let text = self
+            .clipboard()?
+            .get_text()
+            .map_err(|e| anyhow!("Clipboard is not available: {}", e))?;
+        // Inputs are single line, so only the first line is kept.
+        Ok(text.lines().next().unwrap_or_default().trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClipboardTarget;
+
+    #[test]
+    fn test_clipboard_target_from_command_arg() {
+        assert_eq!(
+            ClipboardTarget::from_command_arg("seed"),
+            Some(ClipboardTarget::Seed)
+        );
+        assert_eq!(
+            ClipboardTarget::from_command_arg("id"),
+            Some(ClipboardTarget::PeerId)
+        );
+        assert_eq!(ClipboardTarget::from_command_arg("wallet"), None);
+        assert_eq!(ClipboardTarget::TeamId.to_string(), "team ID");
+    }
+}
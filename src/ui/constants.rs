@@ -1,6 +1,10 @@
 use crate::world::position::Position;
 use crossterm::event::KeyCode;
 use ratatui::style::{Color, Modifier, Style};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::cell::Cell;
+use strum::Display;
+use strum_macros::EnumIter;
 
 pub const LEFT_PANEL_WIDTH: u16 = 36;
 pub const IMG_FRAME_WIDTH: u16 = 80;
@@ -8,6 +12,7 @@ pub const MIN_NAME_LENGTH: usize = 3;
 pub const MAX_NAME_LENGTH: usize = 12;
 
 pub const BARS_LENGTH: usize = 25;
+pub const LIST_PAGE_SIZE: usize = 10;
 
 #[derive(Debug, Clone, Copy)]
 pub struct UiKey;
@@ -19,9 +24,20 @@ impl UiKey {
     pub const NEXT_SELECTION: KeyCode = KeyCode::Char(']');
     pub const PREVIOUS_SELECTION: KeyCode = KeyCode::Char('[');
     pub const UI_DEBUG_MODE: KeyCode = KeyCode::Tab;
+    pub const KEYBOARD_ONLY_MODE: KeyCode = KeyCode::F(2);
+    pub const FOCUS_NEXT: KeyCode = KeyCode::PageDown;
+    pub const FOCUS_PREVIOUS: KeyCode = KeyCode::PageUp;
+    // Outside of keyboard-only mode, the focus keys scroll lists by a page.
+    pub const PAGE_DOWN: KeyCode = KeyCode::PageDown;
+    pub const PAGE_UP: KeyCode = KeyCode::PageUp;
+    pub const ACTIVATE_FOCUS: KeyCode = KeyCode::Enter;
     pub const TOGGLE_AUDIO: KeyCode = KeyCode::Char('|');
     pub const PREVIOUS_RADIO: KeyCode = KeyCode::Char('<');
     pub const NEXT_RADIO: KeyCode = KeyCode::Char('>');
+    pub const PAUSE_WORLD: KeyCode = KeyCode::F(5);
+    pub const FAST_FORWARD_WORLD: KeyCode = KeyCode::F(6);
+    pub const CYCLE_UI_THEME: KeyCode = KeyCode::F(3);
+    pub const ACCESSIBLE_MODE: KeyCode = KeyCode::F(4);
     pub const GO_TO_TEAM: KeyCode = KeyCode::Backspace;
     pub const GO_TO_TEAM_ALTERNATIVE: KeyCode = KeyCode::Char('t');
     pub const GO_TO_GAME: KeyCode = KeyCode::Char('g');
@@ -32,21 +48,82 @@ impl UiKey {
     pub const AUTO_ASSIGN: KeyCode = KeyCode::Char('a');
     pub const SET_TACTIC: KeyCode = KeyCode::Char('t');
     pub const CYCLE_VIEW: KeyCode = KeyCode::Char('V');
+    pub const SEARCH: KeyCode = KeyCode::Char('/');
+    pub const CYCLE_SORT: KeyCode = KeyCode::Char('o');
     pub const HIRE: KeyCode = KeyCode::Char('H');
+    pub const SCOUT_PLANET: KeyCode = KeyCode::Char('K');
+    pub const ENROLL_ACADEMY: KeyCode = KeyCode::Char('Y');
+    pub const PROMOTE_PROSPECT: KeyCode = KeyCode::Char('u');
+    pub const RELEASE_PROSPECT: KeyCode = KeyCode::Char('r');
+    pub const HIRE_STAFF: KeyCode = KeyCode::Char('u');
+    pub const FIRE_STAFF: KeyCode = KeyCode::Char('r');
+    pub const REGISTER_TOURNAMENT: KeyCode = KeyCode::Char('J');
+    pub const FILTER_NOTIFICATIONS: KeyCode = KeyCode::Char('f');
+    pub const CLEAR_NOTIFICATIONS: KeyCode = KeyCode::Char('X');
     pub const FIRE: KeyCode = KeyCode::Char('F');
     pub const LOCK_PLAYER: KeyCode = KeyCode::Char('L');
     pub const UNLOCK_PLAYER: KeyCode = KeyCode::Char('U');
     pub const SET_CAPTAIN: KeyCode = KeyCode::Char('c');
     pub const SET_DOCTOR: KeyCode = KeyCode::Char('d');
+    pub const SET_ENGINEER: KeyCode = KeyCode::Char('Y');
+    pub const SET_COOK: KeyCode = KeyCode::Char('K');
     pub const SET_PILOT: KeyCode = KeyCode::Char('p');
     pub const PITCH_VIEW: KeyCode = KeyCode::Char('v');
     pub const PLAYER_STATUS_VIEW: KeyCode = KeyCode::Char('s');
+    pub const BOX_SCORE_VIEW: KeyCode = KeyCode::Char('b');
+    pub const MUTE_CROWD_CHATTER: KeyCode = KeyCode::Char('m');
+    pub const BROADCAST_MODE: KeyCode = KeyCode::Char('B');
+    pub const PLAY_BY_PLAY_VIEW: KeyCode = KeyCode::Char('l');
+    pub const SCROLL_PLAY_BY_PLAY_DOWN: KeyCode = KeyCode::Char('j');
+    pub const SCROLL_PLAY_BY_PLAY_UP: KeyCode = KeyCode::Char('k');
+    pub const EXPORT_TEAM_DATA: KeyCode = KeyCode::Char('e');
+    pub const SHARE_GAME_SUMMARY: KeyCode = KeyCode::Char('P');
+    pub const CYCLE_JERSEY_STYLE: KeyCode = KeyCode::Char('z');
+    pub const CHANGE_APPEARANCE: KeyCode = KeyCode::Char('Z');
+    pub const EMBLEM_VIEW: KeyCode = KeyCode::Char('v');
+    pub const CYCLE_EMBLEM_SYMBOL: KeyCode = KeyCode::Char('m');
+    pub const CYCLE_EMBLEM_PATTERN: KeyCode = KeyCode::Char('b');
+    pub const CHANGE_EMBLEM: KeyCode = KeyCode::Char('M');
+    pub const RENAME_TEAM: KeyCode = KeyCode::Char('Q');
+    pub const RENAME_SPACESHIP: KeyCode = KeyCode::Char('W');
+    pub const NICKNAME_PLAYER: KeyCode = KeyCode::Char('L');
+    pub const CALL_TIMEOUT: KeyCode = KeyCode::Char('T');
+    pub const SPEECH_FIERY: KeyCode = KeyCode::Char('F');
+    pub const SPEECH_CALM: KeyCode = KeyCode::Char('C');
+    pub const SPEECH_MOCKING: KeyCode = KeyCode::Char('M');
+    pub const SUBSTITUTE: KeyCode = KeyCode::Char('S');
+    pub const CYCLE_SUB_OUT: KeyCode = KeyCode::Char('o');
+    pub const CYCLE_SUB_IN: KeyCode = KeyCode::Char('i');
     pub const TRAVEL: KeyCode = KeyCode::Char('T');
+    pub const TRAVEL_ROUTE: KeyCode = KeyCode::Char('W');
+    pub const QUEUE_TRAVEL: KeyCode = KeyCode::Char('Q');
     pub const EXPLORE: KeyCode = KeyCode::Char('x');
     pub const SPACE_ADVENTURE: KeyCode = KeyCode::Char('A');
+    pub const SCAN_PLANETS: KeyCode = KeyCode::Char('S');
+    pub const BUILD_WORMHOLE_GATE: KeyCode = KeyCode::Char('G');
     pub const DRINK: KeyCode = KeyCode::Char('D');
+    pub const DUEL: KeyCode = KeyCode::Char('E');
+    pub const FEED_KARTOFFEL: KeyCode = KeyCode::Char('f');
+    pub const ASSIGN_KARTOFFEL: KeyCode = KeyCode::Char('a');
     pub const UPGRADE_SPACESHIP: KeyCode = KeyCode::Char('U');
     pub const REPAIR_SPACESHIP: KeyCode = KeyCode::Char('R');
+    pub const SPACE_UPGRADES_VIEW: KeyCode = KeyCode::Char('v');
+    pub const SWITCH_SPACESHIP: KeyCode = KeyCode::Char('w');
+    pub const BUY_SPACESHIP: KeyCode = KeyCode::Char('N');
+    pub const CYCLE_SPACESHIP_PREFAB: KeyCode = KeyCode::Char('n');
+    pub const INSTALL_MODULE: KeyCode = KeyCode::Char('I');
+    pub const UNINSTALL_MODULE: KeyCode = KeyCode::Char('X');
+    pub const PAY_CREW_BONUS: KeyCode = KeyCode::Char('B');
+    pub const SHARE_RUM: KeyCode = KeyCode::Char('M');
+    pub const TAKE_LOAN: KeyCode = KeyCode::Char('b');
+    pub const REPAY_LOAN: KeyCode = KeyCode::Char('r');
+    pub const INSURE_HULL: KeyCode = KeyCode::Char('h');
+    pub const INSURE_CARGO: KeyCode = KeyCode::Char('i');
+    pub const FUEL_GRADE: KeyCode = KeyCode::Char('f');
+    pub const ENGINE_TUNING: KeyCode = KeyCode::Char('e');
+    pub const AUTO_REFUEL: KeyCode = KeyCode::Char('y');
+    pub const DECREASE_AUTO_REFUEL_TARGET: KeyCode = KeyCode::Char('-');
+    pub const INCREASE_AUTO_REFUEL_TARGET: KeyCode = KeyCode::Char('+');
     pub const BUY_SCRAPS: KeyCode = KeyCode::Char('s');
     pub const BUY_FUEL: KeyCode = KeyCode::Char('u');
     pub const BUY_GOLD: KeyCode = KeyCode::Char('g');
@@ -58,6 +135,12 @@ impl UiKey {
     pub const CREATE_TRADE: KeyCode = KeyCode::Char('P');
     pub const ACCEPT_TRADE: KeyCode = KeyCode::Char('A');
     pub const DECLINE_TRADE: KeyCode = KeyCode::Char('D');
+    pub const COUNTER_TRADE: KeyCode = KeyCode::Char('N');
+    pub const CYCLE_TRADE_RESOURCE: KeyCode = KeyCode::Char('R');
+    pub const OFFER_RESOURCE_SALE: KeyCode = KeyCode::Char('S');
+    pub const OFFER_RESOURCE_PURCHASE: KeyCode = KeyCode::Char('B');
+    pub const CYCLE_TRADE_KARTOFFEL: KeyCode = KeyCode::Char('k');
+    pub const OFFER_KARTOFFEL_SALE: KeyCode = KeyCode::Char('K');
     pub const SPACE_MOVE_LEFT: KeyCode = KeyCode::Left;
     pub const SPACE_MOVE_RIGHT: KeyCode = KeyCode::Right;
     pub const SPACE_MOVE_DOWN: KeyCode = KeyCode::Down;
@@ -65,8 +148,10 @@ impl UiKey {
     pub const SPACE_MAIN: KeyCode = KeyCode::Char('s');
     pub const SPACE_SECOND: KeyCode = KeyCode::Char('a');
     pub const SPACE_BACK_TO_BASE: KeyCode = KeyCode::Char('b');
+    pub const SPACE_PAUSE: KeyCode = KeyCode::Char('p');
     pub const YES_TO_DIALOG: KeyCode = KeyCode::Enter;
     pub const NO_TO_DIALOG: KeyCode = KeyCode::Backspace;
+    pub const CAMPAIGN_MODE: KeyCode = KeyCode::Char('c');
     pub const fn set_player_position(position: Position) -> KeyCode {
         match position {
             0 => KeyCode::Char('1'),
@@ -87,27 +172,251 @@ const DEFAULT_STYLE: Style = Style {
     sub_modifier: Modifier::empty(),
 };
 
-pub struct UiStyle;
+#[derive(
+    Debug, Default, Clone, Copy, Display, EnumIter, Serialize_repr, Deserialize_repr, PartialEq,
+)]
+#[repr(u8)]
+pub enum UiTheme {
+    #[default]
+    Default,
+    #[strum(to_string = "High contrast")]
+    HighContrast,
+    #[strum(to_string = "Colorblind safe")]
+    ColorblindSafe,
+    Monochrome,
+}
+
+impl UiTheme {
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Default => Self::HighContrast,
+            Self::HighContrast => Self::ColorblindSafe,
+            Self::ColorblindSafe => Self::Monochrome,
+            Self::Monochrome => Self::Default,
+        }
+    }
+}
+
+thread_local! {
+    // Theme of the Ui being updated or drawn on this thread. Every Ui (one per SSH
+    // session) keeps its own theme and installs it with `UiStyle::with_theme`.
+    static UI_THEME: Cell<UiTheme> = const { Cell::new(UiTheme::Default) };
+    // In accessible mode image panes are replaced by textual descriptions and bar
+    // glyphs by numeric readouts, for screen readers. Installed per Ui like the theme.
+    static ACCESSIBLE_MODE: Cell<bool> = const { Cell::new(false) };
+}
+
+pub fn is_accessible_mode() -> bool {
+    ACCESSIBLE_MODE.with(|current| current.get())
+}
+
+// Runs f with accessible mode set as given, restoring the previous mode after.
+pub fn with_accessible_mode<T>(enabled: bool, f: impl FnOnce() -> T) -> T {
+    let previous = ACCESSIBLE_MODE.with(|current| current.replace(enabled));
+    let result = f();
+    ACCESSIBLE_MODE.with(|current| current.set(previous));
+    result
+}
+
+/// Styles used by all panels. Panels must go through `UiStyle::current()`
+/// rather than hard-coding colors, so that the active theme is respected.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UiStyle {
+    pub default: Style,
+    pub unselected: Style,
+    pub selected: Style,
+    pub unselectable: Style,
+    pub error: Style,
+    pub own_team: Style,
+    pub header: Style,
+    pub network: Style,
+    pub direct_message: Style,
+    pub disconnected: Style,
+    pub shadow: Style,
+    pub highlight: Style,
+    pub ok: Style,
+    pub warning: Style,
+    pub storage_kartoffel: Style,
+    pub trait_killer: Style,
+    pub trait_showpirate: Style,
+    pub trait_relentless: Style,
+    pub trait_spugna: Style,
+    // Colors for ratings from 0 to 20, two ratings per color.
+    pub rating: [Color; 11],
+}
+
+static DEFAULT_UI_STYLE: UiStyle = UiStyle {
+    default: DEFAULT_STYLE,
+    unselected: DEFAULT_STYLE,
+    selected: DEFAULT_STYLE.bg(Color::Rgb(70, 70, 86)),
+    unselectable: DEFAULT_STYLE.fg(Color::DarkGray),
+    error: DEFAULT_STYLE.fg(Color::Red),
+    own_team: DEFAULT_STYLE.fg(Color::Green),
+    header: DEFAULT_STYLE.fg(Color::LightBlue),
+    network: DEFAULT_STYLE.fg(Color::Rgb(204, 144, 184)),
+    direct_message: DEFAULT_STYLE.fg(Color::Rgb(244, 196, 108)),
+    disconnected: DEFAULT_STYLE.fg(Color::DarkGray),
+    shadow: DEFAULT_STYLE.fg(Color::Rgb(244, 255, 232)),
+    highlight: DEFAULT_STYLE.fg(Color::Rgb(118, 213, 192)),
+    ok: DEFAULT_STYLE.fg(Color::Green),
+    warning: DEFAULT_STYLE.fg(Color::Yellow),
+    storage_kartoffel: DEFAULT_STYLE.fg(Color::Magenta),
+    trait_killer: DEFAULT_STYLE.fg(Color::Red),
+    trait_showpirate: DEFAULT_STYLE.fg(Color::Magenta),
+    trait_relentless: DEFAULT_STYLE.fg(Color::Blue),
+    trait_spugna: DEFAULT_STYLE.fg(Color::LightRed),
+    rating: [
+        Color::DarkGray,
+        Color::Red,
+        Color::LightRed,
+        Color::Yellow,
+        Color::LightYellow,
+        Color::White,
+        Color::White,
+        Color::LightGreen,
+        Color::Green,
+        Color::Cyan,
+        Color::LightBlue,
+    ],
+};
+
+static HIGH_CONTRAST_UI_STYLE: UiStyle = UiStyle {
+    default: DEFAULT_STYLE.fg(Color::White),
+    unselected: DEFAULT_STYLE.fg(Color::White),
+    selected: DEFAULT_STYLE
+        .fg(Color::Black)
+        .bg(Color::White)
+        .add_modifier(Modifier::BOLD),
+    unselectable: DEFAULT_STYLE.fg(Color::Gray),
+    error: DEFAULT_STYLE
+        .fg(Color::LightRed)
+        .add_modifier(Modifier::BOLD),
+    own_team: DEFAULT_STYLE
+        .fg(Color::LightGreen)
+        .add_modifier(Modifier::BOLD),
+    header: DEFAULT_STYLE
+        .fg(Color::LightCyan)
+        .add_modifier(Modifier::BOLD),
+    network: DEFAULT_STYLE.fg(Color::LightMagenta),
+    direct_message: DEFAULT_STYLE.fg(Color::LightYellow),
+    disconnected: DEFAULT_STYLE.fg(Color::Gray),
+    shadow: DEFAULT_STYLE.fg(Color::White),
+    highlight: DEFAULT_STYLE.fg(Color::LightCyan),
+    ok: DEFAULT_STYLE.fg(Color::LightGreen),
+    warning: DEFAULT_STYLE
+        .fg(Color::LightYellow)
+        .add_modifier(Modifier::BOLD),
+    storage_kartoffel: DEFAULT_STYLE.fg(Color::LightMagenta),
+    trait_killer: DEFAULT_STYLE.fg(Color::LightRed),
+    trait_showpirate: DEFAULT_STYLE.fg(Color::LightMagenta),
+    trait_relentless: DEFAULT_STYLE.fg(Color::LightBlue),
+    trait_spugna: DEFAULT_STYLE.fg(Color::LightYellow),
+    rating: [
+        Color::Gray,
+        Color::LightRed,
+        Color::LightRed,
+        Color::LightYellow,
+        Color::LightYellow,
+        Color::White,
+        Color::White,
+        Color::LightGreen,
+        Color::LightGreen,
+        Color::LightCyan,
+        Color::LightCyan,
+    ],
+};
+
+// Based on the Okabe-Ito palette: good and bad are told apart by blue and orange
+// instead of green and red.
+static COLORBLIND_SAFE_UI_STYLE: UiStyle = UiStyle {
+    default: DEFAULT_STYLE,
+    unselected: DEFAULT_STYLE,
+    selected: DEFAULT_STYLE.bg(Color::Rgb(70, 70, 86)),
+    unselectable: DEFAULT_STYLE.fg(Color::DarkGray),
+    error: DEFAULT_STYLE.fg(Color::Rgb(230, 159, 0)),
+    own_team: DEFAULT_STYLE.fg(Color::Rgb(86, 180, 233)),
+    header: DEFAULT_STYLE.fg(Color::Rgb(204, 121, 167)),
+    network: DEFAULT_STYLE.fg(Color::Rgb(204, 121, 167)),
+    direct_message: DEFAULT_STYLE.fg(Color::Rgb(240, 228, 66)),
+    disconnected: DEFAULT_STYLE.fg(Color::DarkGray),
+    shadow: DEFAULT_STYLE.fg(Color::Rgb(244, 255, 232)),
+    highlight: DEFAULT_STYLE.fg(Color::Rgb(0, 158, 115)),
+    ok: DEFAULT_STYLE.fg(Color::Rgb(86, 180, 233)),
+    warning: DEFAULT_STYLE.fg(Color::Rgb(240, 228, 66)),
+    storage_kartoffel: DEFAULT_STYLE.fg(Color::Rgb(204, 121, 167)),
+    trait_killer: DEFAULT_STYLE.fg(Color::Rgb(213, 94, 0)),
+    trait_showpirate: DEFAULT_STYLE.fg(Color::Rgb(204, 121, 167)),
+    trait_relentless: DEFAULT_STYLE.fg(Color::Rgb(0, 114, 178)),
+    trait_spugna: DEFAULT_STYLE.fg(Color::Rgb(230, 159, 0)),
+    rating: [
+        Color::DarkGray,
+        Color::Rgb(213, 94, 0),
+        Color::Rgb(230, 159, 0),
+        Color::Rgb(240, 228, 66),
+        Color::Rgb(240, 228, 66),
+        Color::White,
+        Color::White,
+        Color::Rgb(86, 180, 233),
+        Color::Rgb(86, 180, 233),
+        Color::Rgb(0, 114, 178),
+        Color::Rgb(0, 114, 178),
+    ],
+};
+
+// No colors at all: states are told apart by text modifiers only.
+static MONOCHROME_UI_STYLE: UiStyle = UiStyle {
+    default: DEFAULT_STYLE,
+    unselected: DEFAULT_STYLE,
+    selected: DEFAULT_STYLE.add_modifier(Modifier::REVERSED),
+    unselectable: DEFAULT_STYLE.add_modifier(Modifier::DIM),
+    error: DEFAULT_STYLE.add_modifier(Modifier::BOLD.union(Modifier::UNDERLINED)),
+    own_team: DEFAULT_STYLE.add_modifier(Modifier::BOLD),
+    header: DEFAULT_STYLE.add_modifier(Modifier::BOLD),
+    network: DEFAULT_STYLE.add_modifier(Modifier::ITALIC),
+    direct_message: DEFAULT_STYLE.add_modifier(Modifier::ITALIC),
+    disconnected: DEFAULT_STYLE.add_modifier(Modifier::DIM),
+    shadow: DEFAULT_STYLE,
+    highlight: DEFAULT_STYLE.add_modifier(Modifier::UNDERLINED),
+    ok: DEFAULT_STYLE.add_modifier(Modifier::BOLD),
+    warning: DEFAULT_STYLE.add_modifier(Modifier::ITALIC),
+    storage_kartoffel: DEFAULT_STYLE.add_modifier(Modifier::ITALIC),
+    trait_killer: DEFAULT_STYLE.add_modifier(Modifier::BOLD),
+    trait_showpirate: DEFAULT_STYLE.add_modifier(Modifier::ITALIC),
+    trait_relentless: DEFAULT_STYLE.add_modifier(Modifier::UNDERLINED),
+    trait_spugna: DEFAULT_STYLE.add_modifier(Modifier::DIM),
+    rating: [Color::Reset; 11],
+};
 
 impl UiStyle {
-    pub const DEFAULT: Style = DEFAULT_STYLE;
-    pub const UNSELECTED: Style = DEFAULT_STYLE;
-    pub const SELECTED: Style = DEFAULT_STYLE.bg(Color::Rgb(70, 70, 86));
-    pub const UNSELECTABLE: Style = DEFAULT_STYLE.fg(Color::DarkGray);
-    pub const ERROR: Style = DEFAULT_STYLE.fg(Color::Red);
-    pub const OWN_TEAM: Style = DEFAULT_STYLE.fg(Color::Green);
-    pub const HEADER: Style = DEFAULT_STYLE.fg(Color::LightBlue);
-    pub const NETWORK: Style = DEFAULT_STYLE.fg(Color::Rgb(204, 144, 184));
-    pub const DISCONNECTED: Style = DEFAULT_STYLE.fg(Color::DarkGray);
-    pub const SHADOW: Style = DEFAULT_STYLE.fg(Color::Rgb(244, 255, 232));
-    pub const HIGHLIGHT: Style = DEFAULT_STYLE.fg(Color::Rgb(118, 213, 192));
-    pub const OK: Style = DEFAULT_STYLE.fg(Color::Green);
-    pub const WARNING: Style = DEFAULT_STYLE.fg(Color::Yellow);
-    pub const STORAGE_KARTOFFEL: Style = DEFAULT_STYLE.fg(Color::Magenta);
-    pub const TRAIT_KILLER: Style = DEFAULT_STYLE.fg(Color::Red);
-    pub const TRAIT_SHOWPIRATE: Style = DEFAULT_STYLE.fg(Color::Magenta);
-    pub const TRAIT_RELENTLESS: Style = DEFAULT_STYLE.fg(Color::Blue);
-    pub const TRAIT_SPUGNA: Style = DEFAULT_STYLE.fg(Color::LightRed);
+    pub fn current() -> &'static UiStyle {
+        Self::for_theme(Self::theme())
+    }
+
+    pub fn for_theme(theme: UiTheme) -> &'static UiStyle {
+        match theme {
+            UiTheme::Default => &DEFAULT_UI_STYLE,
+            UiTheme::HighContrast => &HIGH_CONTRAST_UI_STYLE,
+            UiTheme::ColorblindSafe => &COLORBLIND_SAFE_UI_STYLE,
+            UiTheme::Monochrome => &MONOCHROME_UI_STYLE,
+        }
+    }
+
+    pub fn theme() -> UiTheme {
+        UI_THEME.with(|current| current.get())
+    }
+
+    // Runs f with the given theme as the current one, restoring the previous after.
+    pub fn with_theme<T>(theme: UiTheme, f: impl FnOnce() -> T) -> T {
+        let previous = UI_THEME.with(|current| current.replace(theme));
+        let result = f();
+        UI_THEME.with(|current| current.set(previous));
+        result
+    }
+
+    pub fn rating(&self, rating: u8) -> Style {
+        let index = ((rating as usize + 1) / 2).min(self.rating.len() - 1);
+        Style::default().fg(self.rating[index])
+    }
 }
 
 pub struct UiText;
@@ -116,3 +425,42 @@ impl UiText {
     pub const YES: &'static str = "Ayay";
     pub const NO: &'static str = "Nay!";
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{UiStyle, UiTheme};
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_ui_theme_roundtrip() {
+        for theme in UiTheme::iter() {
+            UiStyle::with_theme(theme, || {
+                assert_eq!(UiStyle::theme(), theme);
+                assert_eq!(UiStyle::current(), UiStyle::for_theme(theme));
+            });
+        }
+        assert_eq!(UiStyle::theme(), UiTheme::Default);
+    }
+
+    #[test]
+    fn test_accessible_mode_bars() {
+        use super::{is_accessible_mode, with_accessible_mode};
+        use crate::ui::utils::bar_string;
+
+        assert_eq!(bar_string(2, 4), "▰▰▱▱");
+        with_accessible_mode(true, || {
+            assert!(is_accessible_mode());
+            assert_eq!(bar_string(2, 4), "50%");
+            assert_eq!(bar_string(0, 0), "0%");
+        });
+        assert!(!is_accessible_mode());
+    }
+
+    #[test]
+    fn test_rating_style() {
+        let style = UiStyle::for_theme(UiTheme::Default);
+        assert_eq!(style.rating(0).fg, Some(style.rating[0]));
+        assert_eq!(style.rating(20).fg, Some(style.rating[10]));
+        assert_eq!(style.rating(13).fg, Some(style.rating[7]));
+    }
+}
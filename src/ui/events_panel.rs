@@ -0,0 +1,165 @@
+use super::clickable_list::ClickableListState;
+use super::constants::*;
+use super::traits::{Screen, SplitPanel};
+use super::ui_callback::{CallbackRegistry, UiCallback};
+use super::widgets::{default_block, selectable_list};
+use crate::types::{AppResult, SystemTimeTick, Tick};
+use crate::world::events::WorldEvent;
+use crate::world::world::World;
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Layout, Margin},
+    prelude::Rect,
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Default)]
+pub struct EventsPanel {
+    index: usize,
+    // Most recent events first.
+    events: Vec<WorldEvent>,
+    now: Tick,
+    callback_registry: Arc<Mutex<CallbackRegistry>>,
+}
+
+impl EventsPanel {
+    pub fn new(callback_registry: Arc<Mutex<CallbackRegistry>>) -> Self {
+        Self {
+            callback_registry,
+            ..Default::default()
+        }
+    }
+
+    fn render_event_list(&self, frame: &mut Frame, area: Rect) {
+        frame.render_widget(default_block().title("Recent events "), area);
+
+        let options = self
+            .events
+            .iter()
+            .map(|event| {
+                // Events whose effects are still ongoing are highlighted.
+                let style = if event.is_active(self.now) {
+                    UiStyle::current().warning
+                } else {
+                    UiStyle::current().default
+                };
+                (
+                    format!(
+                        " {:<20} {:<14} {}",
+                        event.tick.formatted_as_date(),
+                        event.kind.to_string(),
+                        event.message
+                    ),
+                    style,
+                )
+            })
+            .collect();
+
+        let list = selectable_list(options, &self.callback_registry);
+        frame.render_stateful_widget(
+            list,
+            area.inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+            &mut ClickableListState::default().with_selected(Some(self.index)),
+        );
+    }
+
+    fn render_event_details(&self, frame: &mut Frame, world: &World, area: Rect) {
+        frame.render_widget(default_block().title("Details"), area);
+        let event = match self.events.get(self.index) {
+            Some(event) => event,
+            None => return,
+        };
+
+        let planet_name = world
+            .get_planet_or_err(event.planet_id)
+            .map(|planet| planet.name.clone())
+            .unwrap_or_default();
+
+        let mut lines = vec![
+            Line::from(Span::styled(
+                event.message.clone(),
+                UiStyle::current().header,
+            )),
+            Line::from(""),
+            Line::from(format!("{:<10} {}", "Type", event.kind)),
+            Line::from(format!("{:<10} {}", "Planet", planet_name)),
+            Line::from(format!("{:<10} {}", "When", event.tick.formatted_as_date())),
+        ];
+        if event.is_active(self.now) {
+            lines.push(Line::from(vec![
+                Span::raw(format!("{:<10} ", "Ends in")),
+                Span::styled(
+                    (event.until - self.now).formatted(),
+                    UiStyle::current().highlight,
+                ),
+            ]));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(event.kind.description()));
+
+        frame.render_widget(
+            Paragraph::new(lines),
+            area.inner(Margin {
+                horizontal: 2,
+                vertical: 1,
+            }),
+        );
+    }
+}
+
+impl Screen for EventsPanel {
+    fn update(&mut self, world: &World) -> AppResult<()> {
+        self.now = world.last_tick_short_interval;
+        self.events = world.world_events.iter().rev().cloned().collect();
+        if self.index >= self.events.len() {
+            self.index = self.events.len().saturating_sub(1);
+        }
+        Ok(())
+    }
+
+    fn render(
+        &mut self,
+        frame: &mut Frame,
+        world: &World,
+        area: Rect,
+        _debug_view: bool,
+    ) -> AppResult<()> {
+        let split = Layout::horizontal([Constraint::Min(72), Constraint::Length(48)]).split(area);
+        self.render_event_list(frame, split[0]);
+        self.render_event_details(frame, world, split[1]);
+        Ok(())
+    }
+
+    fn handle_key_events(
+        &mut self,
+        key_event: crossterm::event::KeyEvent,
+        _world: &World,
+    ) -> Option<UiCallback> {
+        match key_event.code {
+            KeyCode::Up => self.next_index(),
+            KeyCode::Down => self.previous_index(),
+            _ => {}
+        }
+        None
+    }
+}
+
+impl SplitPanel for EventsPanel {
+    fn index(&self) -> usize {
+        self.index
+    }
+
+    fn max_index(&self) -> usize {
+        self.events.len()
+    }
+
+    fn set_index(&mut self, index: usize) {
+        self.index = index;
+    }
+}
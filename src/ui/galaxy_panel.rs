@@ -3,8 +3,10 @@ use super::constants::{UiStyle, LEFT_PANEL_WIDTH};
 use super::gif_map::{GifMap, ImageResizeInGalaxyGif};
 use super::traits::SplitPanel;
 use super::ui_callback::{CallbackRegistry, UiCallback};
-use super::utils::hover_text_target;
-use super::widgets::space_adventure_button;
+use super::utils::{dead_reckoning_path, format_satoshi, hover_text_target};
+use super::widgets::{
+    engine_tuning_button, fuel_grade_button, scan_button, space_adventure_button,
+};
 use super::{traits::Screen, widgets::default_block};
 use crate::types::{AppResult, PlayerId, SystemTimeTick, TeamId};
 use crate::ui::constants::*;
@@ -13,7 +15,15 @@ use crate::world::types::PlayerLocation;
 use crate::{
     types::{PlanetId, PlanetMap},
     world::{
-        constants::*, planet::Planet, types::TeamLocation, utils::ellipse_coords, world::World,
+        action_queue::QueuedAction,
+        constants::*,
+        planet::Planet,
+        route::TravelRoute,
+        spaceship::Scanner,
+        types::TeamLocation,
+        utils::ellipse_coords,
+        world::World,
+        wormhole::{MIN_WORMHOLE_DISTANCE, WORMHOLE_GATE_COST, WORMHOLE_TRANSIT_TIME},
     },
 };
 use core::fmt::Debug;
@@ -25,7 +35,7 @@ use ratatui::{
     layout::Layout,
     prelude::Rect,
     style::Style,
-    text::Span,
+    text::{Line, Span},
     widgets::{Clear, Paragraph},
     Frame,
 };
@@ -33,6 +43,16 @@ use std::sync::{Arc, Mutex};
 use std::{cmp::min, vec};
 
 const TICKS_PER_REVOLUTION: usize = 3;
+const UNCHARTED_NAME: &str = "Uncharted";
+
+// Uncharted planets keep their name hidden until the own team discovers them.
+fn charted_name(world: &World, planet: &Planet) -> String {
+    if world.is_planet_discovered(world.own_team_id, planet.id) {
+        planet.name.clone()
+    } else {
+        UNCHARTED_NAME.to_string()
+    }
+}
 
 #[derive(Debug, Default, PartialEq)]
 pub enum ZoomLevel {
@@ -49,6 +69,8 @@ pub struct GalaxyPanel {
     pub team_index: Option<usize>,
     tick: usize,
     pub zoom_level: ZoomLevel,
+    // Route planning is expensive, so the route to the selected planet is cached.
+    planned_route: Option<(PlanetId, Result<TravelRoute, String>)>,
     callback_registry: Arc<Mutex<CallbackRegistry>>,
     gif_map: Arc<Mutex<GifMap>>,
 }
@@ -86,6 +108,26 @@ impl GalaxyPanel {
 
     fn render_planet_gif(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
         let planet = world.get_planet_or_err(self.planet_id)?;
+        if self.zoom_level == ZoomLevel::In
+            && !world.is_planet_discovered(world.own_team_id, planet.id)
+        {
+            let split = Layout::vertical([
+                Constraint::Min(0),
+                Constraint::Length(2),
+                Constraint::Min(0),
+            ])
+            .split(area);
+            frame.render_widget(
+                Paragraph::new(vec![
+                    Line::from(Span::styled("?", UiStyle::current().unselectable)),
+                    Line::from("Uncharted world: travel here or explore nearby to reveal it."),
+                ])
+                .centered(),
+                split[1],
+            );
+            return Ok(());
+        }
+
         let mut lines = match self.zoom_level {
             ZoomLevel::In => self.gif_map.lock().unwrap().planet_zoom_in_frame_lines(
                 self.planet_id,
@@ -147,7 +189,7 @@ impl GalaxyPanel {
             let parent_id = world.get_planet_or_err(current_id)?.satellite_of.unwrap();
             let parent = world.get_planet_or_err(parent_id)?;
             let button = Button::new(
-                format!("{}", parent.name).into(),
+                charted_name(world, parent).into(),
                 UiCallback::GoToPlanetZoomOut {
                     planet_id: parent_id,
                 },
@@ -162,7 +204,7 @@ impl GalaxyPanel {
 
         let target_button = if target.satellites.len() > 0 {
             Button::new(
-                target.name.clone().into(),
+                charted_name(world, target).into(),
                 UiCallback::GoToPlanetZoomOut {
                     planet_id: target.id,
                 },
@@ -170,7 +212,7 @@ impl GalaxyPanel {
             )
         } else if let Some(parent_id) = target.satellite_of {
             Button::new(
-                target.name.clone().into(),
+                charted_name(world, target).into(),
                 UiCallback::GoToPlanetZoomOut {
                     planet_id: parent_id,
                 },
@@ -184,6 +226,7 @@ impl GalaxyPanel {
         if self.zoom_level == ZoomLevel::In {
             let own_team = world.get_own_team()?;
             let hover_text_target = hover_text_target(frame);
+            let planet_name = charted_name(world, planet);
 
             match own_team.current_location {
                 x if x
@@ -199,6 +242,13 @@ impl GalaxyPanel {
                     ) {
                         buttons.push(explore_button);
                     }
+                    if own_team.spaceship.scanner != Scanner::NoScanner {
+                        buttons.push(scan_button(
+                            own_team,
+                            &self.callback_registry,
+                            hover_text_target,
+                        ));
+                    }
                 }
                 _ => {
                     let travel_time = world.travel_time_to_planet(own_team.id, planet.id);
@@ -222,7 +272,7 @@ impl GalaxyPanel {
                                 time.formatted(),
                                 format!(
                                     "Travel to {}: {}Time {} - Fuel {}",
-                                    planet.name,
+                                    planet_name,
                                     distance_text,
                                     time.formatted(),
                                     (time as f32 * own_team.spaceship_fuel_consumption()) as u32,
@@ -232,7 +282,7 @@ impl GalaxyPanel {
                         Err(e) => (
                             Err(e),
                             "unavailable".to_string(),
-                            format!("Travel to {}", planet.name),
+                            format!("Travel to {}", planet_name),
                         ),
                     };
 
@@ -250,9 +300,86 @@ impl GalaxyPanel {
                         go_to_planet_button.disable(Some(can_travel.unwrap_err().to_string()));
                     }
 
+                    // Fuel and engine can only be set on a planet, before departing.
+                    if own_team.is_on_planet().is_some() {
+                        buttons.push(fuel_grade_button(
+                            own_team,
+                            &self.callback_registry,
+                            hover_text_target,
+                        ));
+                        buttons.push(engine_tuning_button(
+                            own_team,
+                            &self.callback_registry,
+                            hover_text_target,
+                        ));
+                    }
+
                     buttons.push(go_to_planet_button);
+
+                    let queued_action = QueuedAction::TravelTo {
+                        planet_id: planet.id,
+                    };
+                    let mut queue_travel_button = Button::new(
+                        format!("Queue travel ({})", own_team.action_queue.len()).into(),
+                        UiCallback::QueueAction {
+                            action: queued_action,
+                        },
+                        Arc::clone(&self.callback_registry),
+                    )
+                    .set_hover_text(
+                        format!(
+                            "Add travel to {} to the orders, see them in the team panel.",
+                            planet_name
+                        ),
+                        hover_text_target,
+                    )
+                    .set_hotkey(UiKey::QUEUE_TRAVEL);
+                    if let Err(e) = own_team.can_queue_action(queued_action) {
+                        queue_travel_button.disable(Some(e.to_string()));
+                    }
+                    buttons.push(queue_travel_button);
+
+                    if own_team.is_on_planet().is_some() {
+                        buttons.push(self.travel_route_button(world, planet.id, hover_text_target));
+                        if let Some(button) =
+                            self.wormhole_gate_button(world, planet.id, hover_text_target)
+                        {
+                            buttons.push(button);
+                        }
+                    }
                 }
             }
+
+            // Teams of other peers which made us co-captain.
+            for team in world
+                .teams
+                .values()
+                .filter(|team| team.co_captain_id == Some(own_team.id))
+            {
+                let action = QueuedAction::TravelTo {
+                    planet_id: planet.id,
+                };
+                let mut order_button = Button::new(
+                    format!("Order {} here", team.name).into(),
+                    UiCallback::SendCoCaptainOrder {
+                        team_id: team.id,
+                        action,
+                    },
+                    Arc::clone(&self.callback_registry),
+                )
+                .set_hover_text(
+                    format!(
+                        "Ask {} to add travel to {} to their orders.",
+                        team.name, planet_name
+                    ),
+                    hover_text_target,
+                )
+                .set_box_style(UiStyle::current().network);
+                if let Err(e) = team.can_queue_action(action) {
+                    order_button.disable(Some(e.to_string()));
+                }
+                buttons.push(order_button);
+            }
         }
 
         let mut constraints = vec![Constraint::Length(3)].repeat(buttons.len());
@@ -268,6 +395,116 @@ impl GalaxyPanel {
         Ok(())
     }
 
+    // Gates can only be built towards distant planets which are not linked yet.
+    fn wormhole_gate_button(
+        &self,
+        world: &World,
+        planet_id: PlanetId,
+        hover_text_target: Rect,
+    ) -> Option<Button<'static>> {
+        let own_team = world.get_own_team().ok()?;
+        let from_id = own_team.is_on_planet()?;
+        let distance = world.distance_between_planets(from_id, planet_id).ok()?;
+        if distance < MIN_WORMHOLE_DISTANCE || world.wormhole_between(from_id, planet_id).is_some()
+        {
+            return None;
+        }
+
+        let cost = WORMHOLE_GATE_COST
+            .iter()
+            .map(|(resource, amount)| format!("{} {}", amount, resource))
+            .join(", ");
+        let mut button = Button::new(
+            "Build gate".into(),
+            UiCallback::BuildWormholeGate { planet_id },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            format!(
+                "Open a wormhole gate to {}, crossed in {} by everyone. Cost: {}",
+                charted_name(world, world.get_planet_or_err(planet_id).ok()?),
+                WORMHOLE_TRANSIT_TIME.formatted(),
+                cost
+            ),
+            hover_text_target,
+        )
+        .set_hotkey(UiKey::BUILD_WORMHOLE_GATE);
+        if let Err(e) = world.can_build_wormhole_gate(own_team, planet_id) {
+            button.disable(Some(e.to_string()));
+        }
+
+        Some(button)
+    }
+
+    fn travel_route_button(
+        &mut self,
+        world: &World,
+        planet_id: PlanetId,
+        hover_text_target: Rect,
+    ) -> Button<'static> {
+        let is_cached = match self.planned_route.as_ref() {
+            Some((id, _)) => *id == planet_id,
+            None => false,
+        };
+        if !is_cached {
+            let route = world
+                .plan_travel_route(world.own_team_id, planet_id)
+                .map_err(|e| e.to_string());
+            self.planned_route = Some((planet_id, route));
+        }
+
+        let route = match self.planned_route.as_ref() {
+            Some((_, route)) => route.clone(),
+            None => Err("No route to this planet".to_string()),
+        };
+
+        match route {
+            Ok(route) => {
+                let own_fuel = world
+                    .get_own_team()
+                    .map(|team| team.fuel())
+                    .unwrap_or_default();
+                let stops = route
+                    .stops()
+                    .iter()
+                    .filter_map(|id| world.get_planet_or_err(*id).ok())
+                    .map(|planet| charted_name(world, planet))
+                    .join(" > ");
+                Button::new(
+                    format!(
+                        "Route ({} legs, {})",
+                        route.legs.len(),
+                        route.total_duration().formatted()
+                    )
+                    .into(),
+                    UiCallback::TravelRouteToPlanet { planet_id },
+                    Arc::clone(&self.callback_registry),
+                )
+                .set_hover_text(
+                    format!(
+                        "{}: Distance {:.2} AU - Time {} - Fuel {} (buy {})",
+                        stops,
+                        route.total_distance() as f32 / AU as f32,
+                        route.total_duration().formatted(),
+                        route.total_fuel(),
+                        route.fuel_to_buy(own_fuel)
+                    ),
+                    hover_text_target,
+                )
+                .set_hotkey(UiKey::TRAVEL_ROUTE)
+            }
+            Err(e) => {
+                let mut button = Button::new(
+                    "Route (unavailable)".into(),
+                    UiCallback::TravelRouteToPlanet { planet_id },
+                    Arc::clone(&self.callback_registry),
+                );
+                button.disable(Some(e));
+                button
+            }
+        }
+    }
+
     fn render_planet_lists(
         &mut self,
         frame: &mut Frame,
@@ -281,6 +518,23 @@ impl GalaxyPanel {
             world.get_planet_or_err(planet.satellites[self.planet_index - 1])?
         };
 
+        // Nothing is known about uncharted planets.
+        if !world.is_planet_discovered(world.own_team_id, target.id) {
+            let split = Layout::vertical([
+                Constraint::Length(15),
+                Constraint::Length(4),
+                Constraint::Min(0),
+            ])
+            .split(area);
+            frame.render_widget(Clear, split[1]);
+            frame.render_widget(
+                Paragraph::new(" No data on this world yet.")
+                    .block(default_block().title("Uncharted ")),
+                split[1],
+            );
+            return Ok(());
+        }
+
         let team_options = target
             .team_ids
             .iter()
@@ -296,11 +550,11 @@ impl GalaxyPanel {
                 let team = world
                     .get_team_or_err(team_id)
                     .expect("Team should be part of the world");
-                let mut style = UiStyle::DEFAULT;
+                let mut style = UiStyle::current().default;
                 if team_id == world.own_team_id {
-                    style = UiStyle::OWN_TEAM;
+                    style = UiStyle::current().own_team;
                 } else if team.peer_id.is_some() {
-                    style = UiStyle::NETWORK;
+                    style = UiStyle::current().network;
                 }
                 let text = format!(
                     "{:<MAX_NAME_LENGTH$} {}",
@@ -332,7 +586,7 @@ impl GalaxyPanel {
             .sorted_by(|a, b| b.rating().cmp(&a.rating()))
             .map(|player| {
                 let text = format!("{:<26} {}", player.info.full_name(), player.stars());
-                (player.id, text, UiStyle::DEFAULT)
+                (player.id, text, UiStyle::current().default)
             })
             .take(10)
             .collect::<Vec<(PlayerId, String, Style)>>();
@@ -343,10 +597,81 @@ impl GalaxyPanel {
             .sorted_by(|a, b| b.1.cmp(&a.1))
             .map(|(resource, &amount)| {
                 let text = format!("{:<7} {}", resource.to_string(), (amount as f32).stars(),);
-                (text, UiStyle::DEFAULT)
+                (text, UiStyle::current().default)
             })
             .collect::<Vec<(String, Style)>>();
 
+        // Standing of the own team with the planet faction, only for inhabited planets.
+        let own_team = world.get_own_team()?;
+        let standing_lines = if target.total_population() > 0 {
+            let standing = own_team
+                .planet_reputation
+                .get(&target.id)
+                .copied()
+                .unwrap_or_default();
+            let style = if standing < 0.0 {
+                UiStyle::current().warning
+            } else if standing > 0.0 {
+                UiStyle::current().ok
+            } else {
+                UiStyle::current().default
+            };
+            vec![
+                (
+                    format!(
+                        "{:<8} {} ({:+.1})",
+                        "Standing",
+                        own_team.reputation_on_planet(target.id).stars(),
+                        standing
+                    ),
+                    style,
+                ),
+                (
+                    format!(
+                        "{:<8} {}%",
+                        "Prices",
+                        (100.0 / own_team.merchant_modifier(target.id)).round()
+                    ),
+                    UiStyle::current().default,
+                ),
+                (
+                    format!(
+                        "{:<8} {}",
+                        "Docking",
+                        format_satoshi(own_team.docking_fee(target))
+                    ),
+                    UiStyle::current().default,
+                ),
+            ]
+        } else {
+            vec![]
+        };
+
+        // Latest scanner reading of the planet, which may be outdated by now.
+        let scan_result = world.scan_results.get(&target.id);
+
+        let wormhole_lines = world
+            .wormholes_at(target.id)
+            .iter()
+            .filter_map(|wormhole| {
+                let other_end = world
+                    .get_planet_or_err(wormhole.other_end(target.id)?)
+                    .ok()?;
+                let origin = match wormhole.builder_id {
+                    Some(team_id) => match world.get_team_or_err(team_id) {
+                        Ok(team) => format!("gate of {}", team.name),
+                        Err(_) => "gate".to_string(),
+                    },
+                    None => "natural".to_string(),
+                };
+                Some(format!(
+                    " ⇄ {} ({})",
+                    charted_name(world, other_end),
+                    origin
+                ))
+            })
+            .collect::<Vec<String>>();
+
         let team_list_height = if team_options.len() > 0 {
             team_options.len() as u16 + 2
         } else {
@@ -365,11 +690,24 @@ impl GalaxyPanel {
             0
         };
 
+        let standing_height = if standing_lines.len() > 0 {
+            standing_lines.len() as u16 + 2
+        } else {
+            0
+        };
+
         let split = Layout::vertical([
             Constraint::Length(15),
             Constraint::Length(team_list_height),
             Constraint::Length(player_list_height),
             Constraint::Length(resource_list_height),
+            Constraint::Length(standing_height),
+            Constraint::Length(if scan_result.is_some() { 4 } else { 0 }),
+            Constraint::Length(if wormhole_lines.len() > 0 {
+                wormhole_lines.len() as u16 + 2
+            } else {
+                0
+            }),
             Constraint::Min(0),
         ])
         .split(area);
@@ -393,7 +731,7 @@ impl GalaxyPanel {
                         },
                         Arc::clone(&self.callback_registry),
                     )
-                    .set_hover_style(UiStyle::HIGHLIGHT),
+                    .set_hover_style(UiStyle::current().highlight),
                     l_split[idx],
                 );
             }
@@ -419,7 +757,7 @@ impl GalaxyPanel {
                         },
                         Arc::clone(&self.callback_registry),
                     )
-                    .set_hover_style(UiStyle::HIGHLIGHT),
+                    .set_hover_style(UiStyle::current().highlight),
                     l_split[idx],
                 );
             }
@@ -442,9 +780,159 @@ impl GalaxyPanel {
             );
         }
 
+        if standing_lines.len() > 0 {
+            frame.render_widget(Clear, split[4]);
+            frame.render_widget(
+                List::new(
+                    standing_lines
+                        .iter()
+                        .map(|(text, style)| {
+                            ListItem::new(Span::styled(format!(" {}", text), *style))
+                        })
+                        .collect::<Vec<ListItem>>(),
+                )
+                .block(default_block().title("Standing ")),
+                split[4],
+            );
+        }
+
+        if let Some(scan_result) = scan_result {
+            frame.render_widget(Clear, split[5]);
+            frame.render_widget(
+                Paragraph::new(vec![
+                    Line::from(format!(
+                        " Scanned on {}",
+                        scan_result.tick.formatted_as_date()
+                    )),
+                    Line::from(format!(" {}", scan_result.description())),
+                ])
+                .block(default_block().title("Scan ")),
+                split[5],
+            );
+        }
+
+        if wormhole_lines.len() > 0 {
+            frame.render_widget(Clear, split[6]);
+            frame.render_widget(
+                Paragraph::new(
+                    wormhole_lines
+                        .iter()
+                        .map(|line| Line::from(line.as_str()))
+                        .collect::<Vec<Line>>(),
+                )
+                .block(default_block().title("Wormholes ")),
+                split[6],
+            );
+        }
+
         Ok(())
     }
 
+    fn render_travel_preview(
+        &self,
+        frame: &mut Frame,
+        planet: &Planet,
+        world: &World,
+        area: Rect,
+    ) -> AppResult<()> {
+        let own_team = world.get_own_team()?;
+        let from_id = match own_team.current_location {
+            TeamLocation::OnPlanet { planet_id } => planet_id,
+            _ => return Ok(()),
+        };
+
+        // Index of the planet in the current view: 0 is the central planet, then the satellites.
+        let view_index = |planet_id: PlanetId| {
+            if planet_id == planet.id {
+                Some(0)
+            } else {
+                planet
+                    .satellites
+                    .iter()
+                    .position(|&id| id == planet_id)
+                    .map(|idx| idx + 1)
+            }
+        };
+
+        let from_index = match view_index(from_id) {
+            Some(idx) => idx,
+            None => return Ok(()),
+        };
+        if from_index == self.planet_index {
+            return Ok(());
+        }
+
+        let to_id = if self.planet_index == 0 {
+            planet.id
+        } else {
+            planet.satellites[self.planet_index - 1]
+        };
+        let travel_time = world.travel_time_to_planet(own_team.id, to_id)?;
+
+        let center = |rect: Rect| (rect.x + rect.width / 2, rect.y + rect.height / 2);
+        let from = center(self.get_planet_info_rect(planet.id, from_index, world, area));
+        let to = center(self.get_planet_info_rect(planet.id, self.planet_index, world, area));
+
+        let frame_rect = frame.area();
+        for ((x, y), is_hourly_marker) in dead_reckoning_path(from, to, travel_time) {
+            if x < area.x + LEFT_PANEL_WIDTH || x >= frame_rect.width || y >= frame_rect.height {
+                continue;
+            }
+            let span = if is_hourly_marker {
+                Span::styled("•", UiStyle::current().highlight)
+            } else {
+                Span::styled("·", UiStyle::current().network)
+            };
+            frame.render_widget(Paragraph::new(span), Rect::new(x, y, 1, 1));
+        }
+
+        Ok(())
+    }
+
+    // Wormholes are drawn as links when both ends are in view,
+    // otherwise the end in view is marked as a wormhole mouth.
+    fn render_wormhole_links(&self, frame: &mut Frame, planet: &Planet, world: &World, area: Rect) {
+        let view_ids = std::iter::once(planet.id)
+            .chain(planet.satellites.iter().copied())
+            .collect::<Vec<PlanetId>>();
+        let center = |rect: Rect| (rect.x + rect.width / 2, rect.y + rect.height / 2);
+        let frame_rect = frame.area();
+        let is_visible = |x: u16, y: u16| {
+            x >= area.x + LEFT_PANEL_WIDTH && x < frame_rect.width && y < frame_rect.height
+        };
+
+        for wormhole in world.wormholes.iter() {
+            let from_index = view_ids.iter().position(|&id| id == wormhole.ends.0);
+            let to_index = view_ids.iter().position(|&id| id == wormhole.ends.1);
+            match (from_index, to_index) {
+                (Some(from_index), Some(to_index)) => {
+                    let from =
+                        center(self.get_planet_info_rect(planet.id, from_index, world, area));
+                    let to = center(self.get_planet_info_rect(planet.id, to_index, world, area));
+                    for ((x, y), _) in dead_reckoning_path(from, to, WORMHOLE_TRANSIT_TIME) {
+                        if is_visible(x, y) {
+                            frame.render_widget(
+                                Paragraph::new(Span::styled("≈", UiStyle::current().highlight)),
+                                Rect::new(x, y, 1, 1),
+                            );
+                        }
+                    }
+                }
+                (Some(index), None) | (None, Some(index)) => {
+                    let rect = self.get_planet_info_rect(planet.id, index, world, area);
+                    let (x, y) = (rect.x + rect.width.saturating_sub(1), rect.y);
+                    if is_visible(x, y) {
+                        frame.render_widget(
+                            Paragraph::new(Span::styled("◎", UiStyle::current().highlight)),
+                            Rect::new(x, y, 1, 1),
+                        );
+                    }
+                }
+                (None, None) => {}
+            }
+        }
+    }
+
     fn get_planet_info_rect(
         &self,
         central_planet_id: PlanetId,
@@ -536,6 +1024,7 @@ impl Screen for GalaxyPanel {
         self.tick += 1;
         if self.planets.len() < world.planets.len() || world.dirty_ui {
             self.planets = world.planets.clone();
+            self.planned_route = None;
         }
         Ok(())
     }
@@ -557,6 +1046,12 @@ impl Screen for GalaxyPanel {
 
         self.render_planet_gif(frame, world, area)?;
 
+        if self.zoom_level == ZoomLevel::Out {
+            // The preview is only a visual aid, so it is fine to skip it if travelling is not possible.
+            self.render_travel_preview(frame, planet, world, area).ok();
+            self.render_wormhole_links(frame, planet, world, area);
+        }
+
         let split =
             Layout::horizontal([Constraint::Max(LEFT_PANEL_WIDTH), Constraint::Min(0)]).split(area);
 
@@ -569,14 +1064,13 @@ impl Screen for GalaxyPanel {
                 .collect::<Vec<Rect>>();
 
             for idx in 0..rects.len() {
-                let planet_name = if idx == 0 {
-                    planet.name.clone()
+                let rect_planet = if idx == 0 {
+                    planet
                 } else {
-                    world
-                        .get_planet_or_err(planet.satellites[idx - 1])?
-                        .name
-                        .clone()
+                    world.get_planet_or_err(planet.satellites[idx - 1])?
                 };
+                let is_discovered = world.is_planet_discovered(world.own_team_id, rect_planet.id);
+                let planet_name = charted_name(world, rect_planet);
                 let button = RadioButton::box_on_hover(
                     "".into(),
                     UiCallback::ZoomInToPlanet {
@@ -586,13 +1080,24 @@ impl Screen for GalaxyPanel {
                     &mut self.planet_index,
                     idx,
                 )
-                .set_box_hover_style(UiStyle::NETWORK)
+                .set_box_hover_style(UiStyle::current().network)
                 .set_box_hover_title(planet_name);
                 let rect = rects[idx];
                 let frame_rect = frame.area();
                 if rect.x + rect.width <= frame_rect.width
                     && rect.y + rect.height <= frame_rect.height
                 {
+                    // Uncharted planets are hidden behind a question mark.
+                    if !is_discovered {
+                        let unknown_rect =
+                            Rect::new(rect.x, rect.y + rect.height / 2, rect.width, 1);
+                        frame.render_widget(Clear, rect);
+                        frame.render_widget(
+                            Paragraph::new(Span::styled("?", UiStyle::current().unselectable))
+                                .centered(),
+                            unknown_rect,
+                        );
+                    }
                     frame.render_widget(button, rect);
                 }
             }
@@ -0,0 +1,166 @@
+use super::button::Button;
+use super::constants::{UiKey, UiStyle};
+use super::traits::Screen;
+use super::ui_callback::{CallbackRegistry, UiCallback};
+use super::utils::{format_satoshi, hover_text_target};
+use super::widgets::default_block;
+use crate::types::{AppResult, SystemTimeTick};
+use crate::world::world::World;
+use core::fmt::Debug;
+use ratatui::layout::{Constraint, Layout, Margin};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Wrap;
+use ratatui::{prelude::Rect, widgets::Paragraph, Frame};
+use std::sync::{Arc, Mutex};
+
+const SUMMARY_WIDTH: u16 = 64;
+
+/// Final summary of the own team career, shown when the game is over.
+#[derive(Debug, Default)]
+pub struct GameOverScreen {
+    callback_registry: Arc<Mutex<CallbackRegistry>>,
+}
+
+impl GameOverScreen {
+    pub fn new(callback_registry: Arc<Mutex<CallbackRegistry>>) -> Self {
+        Self { callback_registry }
+    }
+}
+
+impl Screen for GameOverScreen {
+    fn update(&mut self, _world: &World) -> AppResult<()> {
+        Ok(())
+    }
+
+    fn render(
+        &mut self,
+        frame: &mut Frame,
+        world: &World,
+        area: Rect,
+        _debug_view: bool,
+    ) -> AppResult<()> {
+        frame.render_widget(default_block().title(" Game over "), area);
+        let game_over = match world.game_over.as_ref() {
+            Some(game_over) => game_over,
+            None => return Ok(()),
+        };
+
+        let width = SUMMARY_WIDTH.min(area.width);
+        let h_split = Layout::horizontal([
+            Constraint::Min(0),
+            Constraint::Length(width),
+            Constraint::Min(0),
+        ])
+        .split(area.inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        }));
+        let split = Layout::vertical([
+            Constraint::Min(0),
+            Constraint::Length(19),
+            Constraint::Length(3),
+            Constraint::Min(0),
+        ])
+        .split(h_split[1]);
+
+        let record = |record: [u32; 3]| format!("W{}/L{}/D{}", record[0], record[1], record[2]);
+        let mut lines = vec![
+            Line::from(Span::styled(
+                format!("{}: {}", game_over.team_name, game_over.reason),
+                UiStyle::current().error,
+            )),
+            Line::from(""),
+            Line::from(game_over.reason.description()),
+            Line::from(""),
+            Line::from(format!(
+                "{:<20}{}",
+                "Game over on",
+                game_over.tick.formatted_as_date()
+            )),
+            Line::from(format!(
+                "{:<20}{}",
+                "Game record",
+                record(game_over.game_record)
+            )),
+            Line::from(format!(
+                "{:<20}{}",
+                "Network record",
+                record(game_over.network_game_record)
+            )),
+            Line::from(format!("{:<20}{:.1}", "Reputation", game_over.reputation)),
+            Line::from(format!("{:<20}{}", "Last crew size", game_over.crew_size)),
+            Line::from(format!(
+                "{:<20}{} Km",
+                "Distance travelled", game_over.total_travelled
+            )),
+            Line::from(format!(
+                "{:<20}{}",
+                "Auction proceeds",
+                format_satoshi(game_over.auction_proceeds)
+            )),
+            Line::from(""),
+        ];
+        let is_ironman = world.generation_options.ironman;
+        if is_ironman {
+            lines.push(Line::from(Span::styled(
+                "This was an ironman world: it ends here and will be deleted when you quit.",
+                UiStyle::current().warning,
+            )));
+        } else {
+            lines.push(Line::from(
+                "You can keep playing in sandbox mode, without any further game over.",
+            ));
+        }
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), split[1]);
+
+        let button_split =
+            Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)]).split(split[2]);
+        let quit_button = Button::new(
+            "Quit".into(),
+            UiCallback::QuitGame,
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            "Leave the galaxy for good.".into(),
+            hover_text_target(frame),
+        )
+        .set_hotkey(if is_ironman {
+            UiKey::YES_TO_DIALOG
+        } else {
+            UiKey::NO_TO_DIALOG
+        });
+
+        if is_ironman {
+            frame.render_widget(quit_button, split[2]);
+        } else {
+            let sandbox_button = Button::new(
+                "Continue in sandbox".into(),
+                UiCallback::ContinueInSandbox,
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(
+                "Keep playing with what is left of the crew.".into(),
+                hover_text_target(frame),
+            )
+            .set_hotkey(UiKey::YES_TO_DIALOG);
+            frame.render_widget(sandbox_button, button_split[0]);
+            frame.render_widget(quit_button, button_split[1]);
+        }
+
+        Ok(())
+    }
+
+    fn handle_key_events(
+        &mut self,
+        key_event: crossterm::event::KeyEvent,
+        world: &World,
+    ) -> Option<UiCallback> {
+        let is_ironman = world.generation_options.ironman;
+        match key_event.code {
+            UiKey::YES_TO_DIALOG if is_ironman => Some(UiCallback::QuitGame),
+            UiKey::YES_TO_DIALOG => Some(UiCallback::ContinueInSandbox),
+            UiKey::NO_TO_DIALOG if !is_ironman => Some(UiCallback::QuitGame),
+            _ => None,
+        }
+    }
+}
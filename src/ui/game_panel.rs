@@ -1,9 +1,9 @@
 use super::button::Button;
 use super::clickable_list::ClickableListState;
-use super::constants::UiStyle;
+use super::constants::{is_accessible_mode, UiStyle};
 use super::gif_map::*;
 use super::ui_callback::{CallbackRegistry, UiCallback};
-use super::utils::hover_text_target;
+use super::utils::{bar_string, hover_text_target};
 use super::{
     big_numbers::{hyphen, BigNumberFont},
     constants::{IMG_FRAME_WIDTH, LEFT_PANEL_WIDTH},
@@ -11,15 +11,19 @@ use super::{
     utils::img_to_lines,
     widgets::{default_block, selectable_list, DOWN_ARROW_SPAN, SWITCH_ARROW_SPAN, UP_ARROW_SPAN},
 };
-use crate::game_engine::constants::MIN_TIREDNESS_FOR_ROLL_DECLINE;
-use crate::types::{AppResult, SystemTimeTick, Tick};
+use crate::game_engine::constants::{
+    MAX_MOMENTUM, MAX_TIMEOUTS_PER_GAME, MIN_TIREDNESS_FOR_ROLL_DECLINE,
+};
+use crate::types::{AppResult, PlayerId};
 use crate::world::constants::{MAX_MORALE, MORALE_THRESHOLD_FOR_LEAVING};
 use crate::{
     game_engine::{
         action::{ActionOutput, ActionSituation, Advantage},
         game::Game,
         timer::{Period, Timer},
-        types::{GameStatsMap, Possession},
+        types::{
+            CrowdChatter, GameCommand, GameStats, GameStatsMap, Possession, SpeechTone, TeamInGame,
+        },
     },
     image::game::{PitchStyle, PITCH_HEIGHT},
     image::player::{PLAYER_IMAGE_HEIGHT, PLAYER_IMAGE_WIDTH},
@@ -55,8 +59,15 @@ pub struct GamePanel {
     pitch_view: bool,
     pitch_view_filter: Option<Period>,
     player_status_view: bool,
+    box_score_view: bool,
+    play_by_play_view: bool,
+    play_by_play_offset: u16,
     commentary_index: usize,
     action_results: Vec<ActionOutput>,
+    crowd_chatter: Vec<CrowdChatter>,
+    mute_crowd_chatter: bool,
+    sub_out_index: usize,
+    sub_in_index: usize,
     tick: usize,
     callback_registry: Arc<Mutex<CallbackRegistry>>,
     gif_map: Arc<Mutex<GifMap>>,
@@ -74,6 +85,10 @@ impl GamePanel {
         }
     }
 
+    pub fn selected_game_id(&self) -> Option<GameId> {
+        self.games.get(self.index).copied()
+    }
+
     fn selected_game<'a>(&self, world: &'a World) -> Option<&'a Game> {
         if self.index >= self.games.len() {
             return None;
@@ -81,6 +96,187 @@ impl GamePanel {
         world.get_game(self.games[self.index].clone())
     }
 
+    // Returns the own team players on the court (sorted by position) and the ones that can come in.
+    fn substitution_candidates(game: &Game, world: &World) -> (Vec<PlayerId>, Vec<PlayerId>) {
+        let team = if game.home_team_in_game.team_id == world.own_team_id {
+            &game.home_team_in_game
+        } else {
+            &game.away_team_in_game
+        };
+
+        let on_court = team
+            .stats
+            .iter()
+            .filter(|(_, stats)| stats.is_playing())
+            .sorted_by_key(|(_, stats)| stats.position)
+            .map(|(&id, _)| id)
+            .collect_vec();
+        let bench = team
+            .players
+            .values()
+            .filter(|player| {
+                team.stats
+                    .get(&player.id)
+                    .map(|stats| {
+                        !stats.is_playing() && stats.injury.is_none() && !stats.is_fouled_out()
+                    })
+                    .unwrap_or_default()
+                    && !player.is_injured()
+                    && !player.is_knocked_out()
+            })
+            .sorted_by_key(|player| player.info.shortened_name())
+            .map(|player| player.id)
+            .collect_vec();
+
+        (on_court, bench)
+    }
+
+    fn is_coaching(&self, world: &World) -> bool {
+        if let Some(game) = self.selected_game(world) {
+            return (game.home_team_in_game.team_id == world.own_team_id
+                || game.away_team_in_game.team_id == world.own_team_id)
+                && !game.is_network()
+                && !game.has_ended();
+        }
+        false
+    }
+
+    fn build_speech_buttons(&self, frame: &mut Frame, world: &World, game: &Game, area: Rect) {
+        let split = Layout::horizontal([
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+        ])
+        .split(area);
+        let hover_text_target = hover_text_target(frame);
+
+        for (idx, (tone, hotkey)) in [
+            (SpeechTone::Fiery, UiKey::SPEECH_FIERY),
+            (SpeechTone::Calm, UiKey::SPEECH_CALM),
+            (SpeechTone::Mocking, UiKey::SPEECH_MOCKING),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let command = GameCommand::CaptainSpeech { tone: *tone };
+            let mut button = Button::new(
+                tone.to_string().into(),
+                UiCallback::GiveCaptainSpeech {
+                    game_id: game.id,
+                    tone: *tone,
+                },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(
+                format!("Captain speech before tip-off. {}", tone.description()),
+                hover_text_target,
+            )
+            .set_hotkey(*hotkey);
+            if let Err(err) = game.can_queue_command(world.own_team_id, &command) {
+                button.disable(Some(err.to_string()));
+            }
+            frame.render_widget(button, split[idx]);
+        }
+    }
+
+    fn build_coach_buttons(&self, frame: &mut Frame, world: &World, game: &Game, area: Rect) {
+        let split = Layout::vertical([Constraint::Length(3), Constraint::Length(3)]).split(area);
+        let b_split =
+            Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)]).split(split[0]);
+        let hover_text_target = hover_text_target(frame);
+        let own_team_id = world.own_team_id;
+        let team = if game.home_team_in_game.team_id == own_team_id {
+            &game.home_team_in_game
+        } else {
+            &game.away_team_in_game
+        };
+
+        let mut timeout_button = Button::new(
+            format!(
+                "Timeout ({})",
+                MAX_TIMEOUTS_PER_GAME.saturating_sub(team.timeouts_used)
+            )
+            .into(),
+            UiCallback::CallGameTimeout { game_id: game.id },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            "Call a timeout: players on the court recover some energy and morale.".into(),
+            hover_text_target,
+        )
+        .set_hotkey(UiKey::CALL_TIMEOUT);
+        if let Err(err) = game.can_queue_command(own_team_id, &GameCommand::Timeout) {
+            timeout_button.disable(Some(err.to_string()));
+        }
+        frame.render_widget(timeout_button, b_split[0]);
+
+        let tactic = team.tactic.next();
+        let mut tactic_button = Button::new(
+            format!("tactic: {}", team.tactic).into(),
+            UiCallback::SetGameTactic {
+                game_id: game.id,
+                tactic,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            format!("Switch to {}: {}", tactic, tactic.description()),
+            hover_text_target,
+        )
+        .set_hotkey(UiKey::SET_TACTIC);
+        if let Err(err) = game.can_queue_command(own_team_id, &GameCommand::SetTactic { tactic }) {
+            tactic_button.disable(Some(err.to_string()));
+        }
+        frame.render_widget(tactic_button, b_split[1]);
+
+        let (on_court, bench) = Self::substitution_candidates(game, world);
+        if on_court.is_empty() || bench.is_empty() {
+            let mut sub_button = Button::new(
+                "Sub: no players available".into(),
+                UiCallback::None,
+                Arc::clone(&self.callback_registry),
+            );
+            sub_button.disable(None);
+            frame.render_widget(sub_button, split[1]);
+            return;
+        }
+
+        let player_out = on_court[self.sub_out_index % on_court.len()];
+        let player_in = bench[self.sub_in_index % bench.len()];
+        let name = |id: PlayerId| {
+            team.players
+                .get(&id)
+                .map(|p| p.info.shortened_name())
+                .unwrap_or_default()
+        };
+        let command = GameCommand::Substitution {
+            player_out,
+            player_in,
+        };
+        let mut sub_button = Button::new(
+            format!("Sub: {} → {}", name(player_out), name(player_in)).into(),
+            UiCallback::SubstituteGamePlayer {
+                game_id: game.id,
+                player_out,
+                player_in,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            format!(
+                "Substitute at the next action. Press {} to change the player out, {} to change the player in.",
+                UiKey::CYCLE_SUB_OUT.to_string(),
+                UiKey::CYCLE_SUB_IN.to_string()
+            ),
+            hover_text_target,
+        )
+        .set_hotkey(UiKey::SUBSTITUTE);
+        if let Err(err) = game.can_queue_command(own_team_id, &command) {
+            sub_button.disable(Some(err.to_string()));
+        }
+        frame.render_widget(sub_button, split[1]);
+    }
+
     fn build_top_panel(&mut self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
         // Split into left and right panels
         let split = Layout::horizontal([
@@ -89,10 +285,29 @@ impl GamePanel {
         ])
         .split(area);
 
-        let game_button_split =
-            Layout::vertical([Constraint::Min(3), Constraint::Length(3)]).split(split[0]);
+        let coach_height = if self.is_coaching(world) { 6 } else { 0 };
+        // The captain can talk to the crew until tip-off.
+        let speech_height = match self.selected_game(world) {
+            Some(game) if coach_height > 0 && !game.timer.has_started() => 3,
+            _ => 0,
+        };
+        let game_button_split = Layout::vertical([
+            Constraint::Min(3),
+            Constraint::Length(3),
+            Constraint::Length(speech_height),
+            Constraint::Length(coach_height),
+        ])
+        .split(split[0]);
         self.build_game_list(frame, world, game_button_split[0]);
         self.build_game_buttons(frame, game_button_split[1]);
+        if coach_height > 0 {
+            if let Some(game) = self.selected_game(world) {
+                if speech_height > 0 {
+                    self.build_speech_buttons(frame, world, game, game_button_split[2]);
+                }
+                self.build_coach_buttons(frame, world, game, game_button_split[3]);
+            }
+        }
 
         if let Some(game) = self.selected_game(world) {
             self.build_score_panel(frame, world, game, split[1])?;
@@ -108,16 +323,16 @@ impl GamePanel {
             .filter(|&&id| world.get_game(id).is_some())
             .map(|&id| {
                 let game = world.get_game(id).unwrap();
-                let mut style = UiStyle::DEFAULT;
+                let mut style = UiStyle::current().default;
 
                 if game.home_team_in_game.team_id == world.own_team_id
                     || game.away_team_in_game.team_id == world.own_team_id
                 {
-                    style = UiStyle::OWN_TEAM
+                    style = UiStyle::current().own_team
                 } else if game.home_team_in_game.peer_id.is_some()
                     || game.away_team_in_game.peer_id.is_some()
                 {
-                    style = UiStyle::NETWORK
+                    style = UiStyle::current().network
                 }
 
                 (
@@ -242,10 +457,13 @@ impl GamePanel {
         .split(top_split[2]);
 
         frame.render_widget(
-            Paragraph::new(format!(
-                "Playing on {}",
-                world.get_planet_or_err(game.location).unwrap().name
-            ))
+            Paragraph::new(vec![
+                Line::from(format!(
+                    "Playing on {}",
+                    world.get_planet_or_err(game.location).unwrap().name
+                )),
+                Line::from(game.rules.description()),
+            ])
             .centered(),
             central_split[2],
         );
@@ -469,8 +687,14 @@ impl GamePanel {
 
         let line = Line::from(vec![
             Span::raw(format!("{:<16}", quarter)),
-            Span::styled(format!("{:<16}", "██ made shot"), UiStyle::OWN_TEAM),
-            Span::styled(format!("{:<16}", "██ missed shot"), UiStyle::ERROR),
+            Span::styled(
+                format!("{:<16}", "██ made shot"),
+                UiStyle::current().own_team,
+            ),
+            Span::styled(
+                format!("{:<16}", "██ missed shot"),
+                UiStyle::current().error,
+            ),
         ]);
 
         frame.render_widget(Paragraph::new(line).centered(), split[1]);
@@ -484,14 +708,20 @@ impl GamePanel {
         world: &World,
         area: Rect,
     ) -> AppResult<()> {
+        if self.box_score_view {
+            if let Some(game) = self.selected_game(world) {
+                Self::build_box_score(game, frame, area);
+            }
+            return Ok(());
+        }
+
         let split = Layout::horizontal([Constraint::Min(8), Constraint::Length(73)]).split(area);
         if let Some(game) = self.selected_game(world) {
             let mut shot_img = None;
             if let Some(last_action) = game.action_results.last() {
                 if last_action.score_change == 3 {
                     let shot_tick = game.starting_at + last_action.start_at.as_tick();
-                    let now = Tick::now();
-                    let shot_frame = (now - shot_tick) as usize / 140;
+                    let shot_frame = world.now().saturating_sub(shot_tick) as usize / 140;
                     if shot_frame < RIGHT_SHOT_GIF.len() {
                         // After scoring the possesion is flipped, so the opposite team scored.
                         if last_action.possession == Possession::Home {
@@ -507,6 +737,8 @@ impl GamePanel {
                 frame.render_widget(Paragraph::new(img).centered(), split[0]);
             } else if self.pitch_view {
                 self.build_pitch_panel(frame, world, game, split[0])?;
+            } else if self.play_by_play_view {
+                self.build_play_by_play(frame, game, split[0]);
             } else {
                 self.build_commentary(frame, split[0]);
             }
@@ -529,29 +761,43 @@ impl GamePanel {
         timer: Timer,
         switch_possession: bool,
     ) -> Line {
-        let arrow: Span<'_>;
-        if switch_possession {
-            arrow = SWITCH_ARROW_SPAN.clone();
-        } else {
-            arrow = match action_result.advantage {
-                Advantage::Attack => UP_ARROW_SPAN.clone(),
-                Advantage::Defense => DOWN_ARROW_SPAN.clone(),
-                Advantage::Neutral => Span::raw(""),
-            };
-        }
-        let timer = Span::styled(format!("[{}] ", timer.format()), UiStyle::HIGHLIGHT);
-        let text = Span::from(format!("{} ", action_result.description.clone()));
-        Line::from(vec![timer, text, arrow])
+        format_action_result(&action_result, timer, switch_possession)
+    }
+
+    fn format_crowd_chatter(&self, chatter: &CrowdChatter) -> Line {
+        let timer = Span::styled(
+            format!("[{}] ", chatter.timer.format()),
+            UiStyle::current().highlight,
+        );
+        let text = Span::styled(
+            format!("Crowd chatter - {}: {}", chatter.author, chatter.text),
+            UiStyle::current().network,
+        );
+        Line::from(vec![timer, text])
     }
 
     fn build_commentary(&mut self, frame: &mut Frame, area: Rect) {
         let mut commentary = vec![];
         let max_index = self.action_results.len() - self.commentary_index;
+        let mut chatter = if self.mute_crowd_chatter {
+            vec![]
+        } else {
+            self.crowd_chatter
+                .iter()
+                .sorted_by_key(|c| c.timer.value)
+                .collect::<Vec<&CrowdChatter>>()
+        }
+        .into_iter()
+        .peekable();
 
         for idx in 0..max_index {
             let result = self.action_results[idx].clone();
             let situation = result.situation.clone();
             let timer = self.action_results[idx].start_at;
+            // Crowd chatter is shown right before the first action that happened after it.
+            while let Some(c) = chatter.next_if(|c| c.timer.value < timer.value) {
+                commentary.push(self.format_crowd_chatter(c));
+            }
             let switch_possession = if idx > 0 {
                 result.possession != self.action_results[idx - 1].possession
             } else {
@@ -568,6 +814,12 @@ impl GamePanel {
             }
         }
 
+        if self.commentary_index == 0 {
+            for c in chatter {
+                commentary.push(self.format_crowd_chatter(c));
+            }
+        }
+
         commentary.reverse();
 
         frame.render_widget(
@@ -578,6 +830,32 @@ impl GamePanel {
         )
     }
 
+    fn build_play_by_play(&mut self, frame: &mut Frame, game: &Game, area: Rect) {
+        let lines = play_by_play_lines(&game.action_results);
+        self.play_by_play_offset = self
+            .play_by_play_offset
+            .min(lines.len().saturating_sub(1) as u16);
+
+        let mut callback_registry = self.callback_registry.lock().unwrap();
+        callback_registry.register_mouse_callback(
+            crossterm::event::MouseEventKind::ScrollDown,
+            Some(area),
+            UiCallback::ScrollPlayByPlay { lines: 1 },
+        );
+        callback_registry.register_mouse_callback(
+            crossterm::event::MouseEventKind::ScrollUp,
+            Some(area),
+            UiCallback::ScrollPlayByPlay { lines: -1 },
+        );
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .scroll((self.play_by_play_offset, 0))
+                .block(default_block().title("Play-by-play")),
+            area,
+        )
+    }
+
     fn build_stats_table<'a>(players_data: &'a GameStatsMap, players: Vec<&Player>) -> Table<'a> {
         let mut rows: Vec<Row<'_>> = vec![];
 
@@ -618,10 +896,10 @@ impl GamePanel {
 
             let name_span = {
                 let style = match player.tiredness {
-                    x if x < MIN_TIREDNESS_FOR_ROLL_DECLINE * 0.75 => UiStyle::DEFAULT,
-                    x if x < MIN_TIREDNESS_FOR_ROLL_DECLINE * 1.5 => UiStyle::WARNING,
-                    x if x < MAX_TIREDNESS => UiStyle::ERROR,
-                    _ => UiStyle::UNSELECTABLE,
+                    x if x < MIN_TIREDNESS_FOR_ROLL_DECLINE * 0.75 => UiStyle::current().default,
+                    x if x < MIN_TIREDNESS_FOR_ROLL_DECLINE * 1.5 => UiStyle::current().warning,
+                    x if x < MAX_TIREDNESS => UiStyle::current().error,
+                    _ => UiStyle::current().unselectable,
                 };
 
                 Span::styled(player.info.shortened_name(), style)
@@ -654,7 +932,12 @@ impl GamePanel {
                 )),
                 Cell::from(format!("{:^3}", players_data[&player.id].steals)),
                 Cell::from(format!("{:^3}", players_data[&player.id].blocks)),
-                Cell::from(format!("{:>2}", players_data[&player.id].fouls)),
+                if players_data[&player.id].is_fouled_out() {
+                    Cell::from(format!("{:>2}", players_data[&player.id].fouls))
+                        .style(UiStyle::current().error)
+                } else {
+                    Cell::from(format!("{:>2}", players_data[&player.id].fouls))
+                },
                 Cell::from(format!("{:>+3}", players_data[&player.id].plus_minus)),
             ];
             rows.push(Row::new(cells).height(1));
@@ -716,41 +999,33 @@ impl GamePanel {
 
             let name_span = {
                 let style = match player.tiredness {
-                    x if x < MIN_TIREDNESS_FOR_ROLL_DECLINE * 0.75 => UiStyle::DEFAULT,
-                    x if x < MIN_TIREDNESS_FOR_ROLL_DECLINE * 1.5 => UiStyle::WARNING,
-                    x if x < MAX_TIREDNESS => UiStyle::ERROR,
-                    _ => UiStyle::UNSELECTABLE,
+                    x if x < MIN_TIREDNESS_FOR_ROLL_DECLINE * 0.75 => UiStyle::current().default,
+                    x if x < MIN_TIREDNESS_FOR_ROLL_DECLINE * 1.5 => UiStyle::current().warning,
+                    x if x < MAX_TIREDNESS => UiStyle::current().error,
+                    _ => UiStyle::current().unselectable,
                 };
 
                 Span::styled(player.info.shortened_name(), style)
             };
 
             let morale_length = (player.morale / MAX_MORALE * bars_length as f32).round() as usize;
-            let morale_string = format!(
-                "{}{}",
-                "▰".repeat(morale_length),
-                "▱".repeat(bars_length - morale_length),
-            );
+            let morale_string = bar_string(morale_length, bars_length);
             let morale_style = match player.morale {
-                x if x > 1.75 * MORALE_THRESHOLD_FOR_LEAVING => UiStyle::OK,
-                x if x > MORALE_THRESHOLD_FOR_LEAVING => UiStyle::WARNING,
-                x if x > 0.0 => UiStyle::ERROR,
-                _ => UiStyle::UNSELECTABLE,
+                x if x > 1.75 * MORALE_THRESHOLD_FOR_LEAVING => UiStyle::current().ok,
+                x if x > MORALE_THRESHOLD_FOR_LEAVING => UiStyle::current().warning,
+                x if x > 0.0 => UiStyle::current().error,
+                _ => UiStyle::current().unselectable,
             };
             let morale_span = Span::styled(morale_string, morale_style);
 
             let tiredness_length =
                 (player.tiredness / MAX_TIREDNESS * bars_length as f32).round() as usize;
-            let energy_string = format!(
-                "{}{}",
-                "▰".repeat(bars_length - tiredness_length),
-                "▱".repeat(tiredness_length),
-            );
+            let energy_string = bar_string(bars_length - tiredness_length, bars_length);
             let energy_style = match player.tiredness {
-                x if x < MIN_TIREDNESS_FOR_ROLL_DECLINE * 0.75 => UiStyle::OK,
-                x if x < MIN_TIREDNESS_FOR_ROLL_DECLINE * 1.5 => UiStyle::WARNING,
-                x if x < MAX_TIREDNESS => UiStyle::ERROR,
-                _ => UiStyle::UNSELECTABLE,
+                x if x < MIN_TIREDNESS_FOR_ROLL_DECLINE * 0.75 => UiStyle::current().ok,
+                x if x < MIN_TIREDNESS_FOR_ROLL_DECLINE * 1.5 => UiStyle::current().warning,
+                x if x < MAX_TIREDNESS => UiStyle::current().error,
+                _ => UiStyle::current().unselectable,
             };
             let energy_span = Span::styled(energy_string, energy_style);
 
@@ -782,7 +1057,9 @@ impl GamePanel {
         };
         let mut timer_lines: Vec<Line> = vec![];
         if !timer.has_started() {
-            timer_lines.push(Line::from(Timer::from(timer.period().start()).format()));
+            timer_lines.push(Line::from(
+                timer.at(timer.period_start(timer.period())).format(),
+            ));
             let starting_in_seconds = (game.starting_at - world.last_tick_short_interval) / 1000;
             timer_lines.push(Line::from(format!(
                 "Starting in {:02}:{:02}",
@@ -790,9 +1067,13 @@ impl GamePanel {
                 starting_in_seconds % 60
             )));
         } else if timer.has_ended() {
-            timer_lines.push(Line::from(Timer::from(timer.period().end()).format()));
+            timer_lines.push(Line::from(
+                timer.at(timer.period_end(timer.period())).format(),
+            ));
         } else if timer.is_break() {
-            timer_lines.push(Line::from(Timer::from(timer.period().end()).format()));
+            timer_lines.push(Line::from(
+                timer.at(timer.period_end(timer.period())).format(),
+            ));
             timer_lines.push(Line::from(format!(
                 "Resuming in {:02}:{:02}",
                 timer.minutes(),
@@ -841,12 +1122,20 @@ impl GamePanel {
 
         let home_table =
             Self::build_player_status_table(&game.home_team_in_game.stats, home_players)
-                .header(Row::new(header_cells_home).style(UiStyle::HEADER).height(1))
+                .header(
+                    Row::new(header_cells_home)
+                        .style(UiStyle::current().header)
+                        .height(1),
+                )
                 .widths(constraint);
 
         let away_table =
             Self::build_player_status_table(&game.away_team_in_game.stats, away_players)
-                .header(Row::new(header_cells_away).style(UiStyle::HEADER).height(1))
+                .header(
+                    Row::new(header_cells_away)
+                        .style(UiStyle::current().header)
+                        .height(1),
+                )
                 .widths(constraint);
 
         let box_area = Layout::vertical([
@@ -861,9 +1150,48 @@ impl GamePanel {
         }));
 
         frame.render_widget(home_table, box_area[0]);
+        frame.render_widget(Self::build_momentum_line(game), box_area[1]);
         frame.render_widget(away_table, box_area[2]);
     }
 
+    // Each bar grows from the center towards the team riding the momentum.
+    fn build_momentum_line(game: &Game) -> Paragraph<'static> {
+        let home = game.home_team_in_game.momentum.min(MAX_MOMENTUM) as usize;
+        let away = game.away_team_in_game.momentum.min(MAX_MOMENTUM) as usize;
+        let style = |momentum: usize| match momentum {
+            x if x == MAX_MOMENTUM as usize => UiStyle::current().error,
+            x if x > MAX_MOMENTUM as usize / 2 => UiStyle::current().warning,
+            _ => UiStyle::current().default,
+        };
+
+        Paragraph::new(Line::from(vec![
+            Span::raw(format!("{} ", game.home_team_in_game.name)),
+            Span::styled(
+                if is_accessible_mode() {
+                    format!("{}/{}", home, MAX_MOMENTUM)
+                } else {
+                    format!(
+                        "{}{}",
+                        "▱".repeat(MAX_MOMENTUM as usize - home),
+                        "▰".repeat(home)
+                    )
+                },
+                style(home),
+            ),
+            Span::raw(" Momentum "),
+            Span::styled(
+                if is_accessible_mode() {
+                    format!("{}/{}", away, MAX_MOMENTUM)
+                } else {
+                    bar_string(away, MAX_MOMENTUM as usize)
+                },
+                style(away),
+            ),
+            Span::raw(format!(" {}", game.away_team_in_game.name)),
+        ]))
+        .centered()
+    }
+
     fn build_stats_box(game: &Game, frame: &mut Frame, area: Rect) {
         let header_cells_home = [
             "  ",
@@ -924,11 +1252,19 @@ impl GamePanel {
         ];
 
         let home_table = Self::build_stats_table(&game.home_team_in_game.stats, home_players)
-            .header(Row::new(header_cells_home).style(UiStyle::HEADER).height(1))
+            .header(
+                Row::new(header_cells_home)
+                    .style(UiStyle::current().header)
+                    .height(1),
+            )
             .widths(constraint);
 
         let away_table = Self::build_stats_table(&game.away_team_in_game.stats, away_players)
-            .header(Row::new(header_cells_away).style(UiStyle::HEADER).height(1))
+            .header(
+                Row::new(header_cells_away)
+                    .style(UiStyle::current().header)
+                    .height(1),
+            )
             .widths(constraint);
 
         let box_area = Layout::vertical([
@@ -946,6 +1282,148 @@ impl GamePanel {
         frame.render_widget(away_table, box_area[2]);
     }
 
+    fn build_box_score_table<'a>(team_in_game: &'a TeamInGame) -> Table<'a> {
+        let mut rows = vec![];
+        let mut totals = GameStats::default();
+
+        for player in team_in_game
+            .initial_positions
+            .iter()
+            .filter_map(|id| team_in_game.players.get(id))
+        {
+            let stats = match team_in_game.stats.get(&player.id) {
+                Some(stats) => stats,
+                None => continue,
+            };
+            totals.update(stats);
+            totals.plus_minus += stats.plus_minus;
+
+            let style = if stats.is_fouled_out() {
+                UiStyle::current().error
+            } else if stats.is_playing() {
+                UiStyle::current().default
+            } else {
+                UiStyle::current().unselectable
+            };
+            let role = match stats.position {
+                Some(p) => (p as Position).as_str().to_string(),
+                None => "".to_string(),
+            };
+
+            let mut cells = vec![
+                Cell::from(format!("{:<2}", role)),
+                Cell::from(player.info.shortened_name()),
+                Cell::from(format!("{:>3}", stats.seconds_played / 60)),
+            ];
+            cells.extend(Self::box_score_cells(stats));
+            cells.push(Cell::from(format!("{:>+4}", stats.plus_minus)));
+            rows.push(Row::new(cells).style(style));
+        }
+
+        let mut cells = vec![Cell::from(""), Cell::from("Total"), Cell::from("")];
+        cells.extend(Self::box_score_cells(&totals));
+        // Each point is counted for the 5 players on the court.
+        cells.push(Cell::from(format!("{:>+4}", totals.plus_minus / 5)));
+        rows.push(Row::new(cells).cyan());
+
+        let header = [
+            "  ",
+            &team_in_game.name,
+            "Min",
+            "Pts",
+            "  FG  ",
+            " FG% ",
+            "  2PT  ",
+            "  3PT  ",
+            "  FT  ",
+            "ORb",
+            "DRb",
+            "Reb",
+            "Ast",
+            "Stl",
+            "Blk",
+            "TO",
+            "PF",
+            " +/-",
+        ];
+
+        Table::new(
+            rows,
+            [
+                Constraint::Length(2), //role
+                Constraint::Min(16),   //player
+                Constraint::Length(3), //minutes
+                Constraint::Length(3), //points
+                Constraint::Length(6), //field goals
+                Constraint::Length(5), //field goals percentage
+                Constraint::Length(7), //2pt
+                Constraint::Length(7), //3pt
+                Constraint::Length(6), //free throws
+                Constraint::Length(3), //offensive rebounds
+                Constraint::Length(3), //defensive rebounds
+                Constraint::Length(3), //rebounds
+                Constraint::Length(3), //assists
+                Constraint::Length(3), //steals
+                Constraint::Length(3), //blocks
+                Constraint::Length(2), //turnovers
+                Constraint::Length(2), //personal fouls
+                Constraint::Length(4), //plus minus
+            ],
+        )
+        .header(Row::new(header).style(UiStyle::current().header))
+    }
+
+    fn box_score_cells(stats: &GameStats) -> Vec<Cell<'static>> {
+        vec![
+            Cell::from(format!("{:>3}", stats.points)),
+            Cell::from(format!(
+                "{:>2}/{:<3}",
+                stats.made_fg(),
+                stats.attempted_fg()
+            )),
+            Cell::from(format_percentage(stats.made_fg(), stats.attempted_fg())),
+            Cell::from(format!("{:>3}/{:<3}", stats.made_2pt, stats.attempted_2pt)),
+            Cell::from(format!("{:>3}/{:<3}", stats.made_3pt, stats.attempted_3pt)),
+            Cell::from(format!("{:>2}/{:<3}", stats.made_ft, stats.attempted_ft)),
+            Cell::from(format!("{:>3}", stats.offensive_rebounds)),
+            Cell::from(format!("{:>3}", stats.defensive_rebounds)),
+            Cell::from(format!("{:>3}", stats.rebounds())),
+            Cell::from(format!("{:>3}", stats.assists)),
+            Cell::from(format!("{:>3}", stats.steals)),
+            Cell::from(format!("{:>3}", stats.blocks)),
+            Cell::from(format!("{:>2}", stats.turnovers)),
+            Cell::from(format!("{:>2}", stats.fouls)),
+        ]
+    }
+
+    fn build_box_score(game: &Game, frame: &mut Frame, area: Rect) {
+        frame.render_widget(default_block().title("Box score"), area);
+
+        let split = Layout::vertical([
+            Constraint::Length(game.home_team_in_game.players.len() as u16 + 2),
+            Constraint::Length(1),
+            Constraint::Length(game.away_team_in_game.players.len() as u16 + 2),
+            Constraint::Min(0),
+        ])
+        .split(area.inner(Margin {
+            horizontal: 2,
+            vertical: 1,
+        }));
+
+        frame.render_widget(
+            Self::build_box_score_table(&game.home_team_in_game),
+            split[0],
+        );
+        frame.render_widget(
+            Self::build_box_score_table(&game.away_team_in_game),
+            split[2],
+        );
+    }
+
+    pub fn toggle_box_score_view(&mut self) {
+        self.box_score_view = !self.box_score_view;
+    }
+
     pub fn toggle_pitch_view(&mut self) {
         self.pitch_view = !self.pitch_view;
     }
@@ -953,6 +1431,19 @@ impl GamePanel {
     pub fn toggle_player_status_view(&mut self) {
         self.player_status_view = !self.player_status_view;
     }
+
+    pub fn toggle_crowd_chatter(&mut self) {
+        self.mute_crowd_chatter = !self.mute_crowd_chatter;
+    }
+
+    pub fn toggle_play_by_play_view(&mut self) {
+        self.play_by_play_view = !self.play_by_play_view;
+        self.play_by_play_offset = 0;
+    }
+
+    pub fn scroll_play_by_play(&mut self, lines: i16) {
+        self.play_by_play_offset = self.play_by_play_offset.saturating_add_signed(lines);
+    }
 }
 
 impl Screen for GamePanel {
@@ -990,6 +1481,7 @@ impl Screen for GamePanel {
         if let Some(game) = self.selected_game(world) {
             if self.commentary_index == 0 {
                 self.action_results = game.action_results.clone();
+                self.crowd_chatter = game.crowd_chatter.clone();
             }
         } else {
             self.set_index(0);
@@ -1043,6 +1535,16 @@ impl Screen for GamePanel {
                 }
             }
             KeyCode::Enter => self.commentary_index = 0,
+            UiKey::MUTE_CROWD_CHATTER => self.toggle_crowd_chatter(),
+            UiKey::BROADCAST_MODE => return Some(UiCallback::ToggleBroadcastMode),
+            UiKey::BOX_SCORE_VIEW => self.toggle_box_score_view(),
+            UiKey::PLAY_BY_PLAY_VIEW => self.toggle_play_by_play_view(),
+            UiKey::SCROLL_PLAY_BY_PLAY_DOWN if self.play_by_play_view => {
+                self.scroll_play_by_play(1)
+            }
+            UiKey::SCROLL_PLAY_BY_PLAY_UP if self.play_by_play_view => self.scroll_play_by_play(-1),
+            UiKey::CYCLE_SUB_OUT => self.sub_out_index += 1,
+            UiKey::CYCLE_SUB_IN => self.sub_in_index += 1,
 
             KeyCode::Char('0') => {
                 self.pitch_view_filter = None;
@@ -1065,7 +1567,34 @@ impl Screen for GamePanel {
     }
 
     fn footer_spans(&self) -> Vec<String> {
-        let mut v = vec![];
+        let mut v = vec![
+            format!(" {} ", UiKey::BROADCAST_MODE.to_string()),
+            " Broadcast ".to_string(),
+            format!(" {} ", UiKey::BOX_SCORE_VIEW.to_string()),
+            if self.box_score_view {
+                " Hide box score ".to_string()
+            } else {
+                " Box score ".to_string()
+            },
+        ];
+
+        if self.box_score_view {
+            return v;
+        }
+
+        if self.play_by_play_view && !self.pitch_view {
+            v.append(&mut vec![
+                format!(" {} ", UiKey::PLAY_BY_PLAY_VIEW.to_string()),
+                " Commentary ".to_string(),
+                format!(
+                    " {}/{} ",
+                    UiKey::SCROLL_PLAY_BY_PLAY_DOWN.to_string(),
+                    UiKey::SCROLL_PLAY_BY_PLAY_UP.to_string()
+                ),
+                " Scroll ".to_string(),
+            ]);
+            return v;
+        }
 
         if self.pitch_view {
             v.append(&mut vec![
@@ -1089,6 +1618,14 @@ impl Screen for GamePanel {
                 " Scroll commentary ".to_string(),
                 " Enter ".to_string(),
                 " Scroll commentary to top ".to_string(),
+                format!(" {} ", UiKey::PLAY_BY_PLAY_VIEW.to_string()),
+                " Play-by-play ".to_string(),
+                format!(" {} ", UiKey::MUTE_CROWD_CHATTER.to_string()),
+                if self.mute_crowd_chatter {
+                    " Unmute crowd chatter ".to_string()
+                } else {
+                    " Mute crowd chatter ".to_string()
+                },
             ])
         };
         v
@@ -1107,7 +1644,57 @@ impl SplitPanel for GamePanel {
     fn set_index(&mut self, index: usize) {
         self.index = index;
         self.commentary_index = 0;
+        self.play_by_play_offset = 0;
+    }
+}
+
+fn format_action_result(
+    action_result: &ActionOutput,
+    timer: Timer,
+    switch_possession: bool,
+) -> Line<'static> {
+    let arrow: Span<'_>;
+    if switch_possession {
+        arrow = SWITCH_ARROW_SPAN.clone();
+    } else {
+        arrow = match action_result.advantage {
+            Advantage::Attack => UP_ARROW_SPAN.clone(),
+            Advantage::Defense => DOWN_ARROW_SPAN.clone(),
+            Advantage::Neutral => Span::raw(""),
+        };
+    }
+    let timer = Span::styled(
+        format!("[{}] ", timer.format()),
+        UiStyle::current().highlight,
+    );
+    let text = Span::from(format!("{} ", action_result.description.clone()));
+    Line::from(vec![timer, text, arrow])
+}
+
+/// Full play-by-play of a game, latest action first.
+pub fn play_by_play_lines(action_results: &[ActionOutput]) -> Vec<Line<'static>> {
+    let mut lines = vec![];
+    for (idx, result) in action_results.iter().enumerate() {
+        // The first output is the empty default one the game starts from.
+        if result.description.is_empty() {
+            continue;
+        }
+        let switch_possession = idx > 0 && result.possession != action_results[idx - 1].possession;
+        lines.push(format_action_result(
+            result,
+            result.start_at,
+            switch_possession,
+        ));
     }
+    lines.reverse();
+    lines
+}
+
+fn format_percentage(made: u8, attempted: u8) -> String {
+    if attempted == 0 {
+        return format!("{:>5}", "-");
+    }
+    format!("{:>4.0}%", 100.0 * made as f32 / attempted as f32)
 }
 
 // Add test for timer formatting only
@@ -1115,12 +1702,42 @@ impl SplitPanel for GamePanel {
 #[cfg(test)]
 mod tests {
     use crate::{
-        game_engine::timer::Timer,
+        game_engine::{action::ActionOutput, timer::Timer},
         types::{SystemTimeTick, Tick},
         world::constants::*,
     };
     use std::{io::Write, thread, time::Duration};
 
+    #[test]
+    fn test_format_percentage() {
+        assert_eq!(super::format_percentage(0, 0), "    -");
+        assert_eq!(super::format_percentage(1, 2), "  50%");
+        assert_eq!(super::format_percentage(2, 3), "  67%");
+        assert_eq!(super::format_percentage(7, 7), " 100%");
+    }
+
+    #[test]
+    fn test_play_by_play_lines() {
+        let mut results = vec![ActionOutput::default()];
+        for (idx, description) in ["Jump ball", "Shot", "Rebound"].iter().enumerate() {
+            results.push(ActionOutput {
+                description: description.to_string(),
+                start_at: Timer::from(idx as u16 * 10),
+                ..Default::default()
+            });
+        }
+
+        let lines = super::play_by_play_lines(&results);
+        assert_eq!(lines.len(), 3);
+        let latest = lines[0]
+            .spans
+            .iter()
+            .map(|s| s.content.to_string())
+            .collect::<String>();
+        assert!(latest.contains("Rebound"));
+        assert!(latest.starts_with(&format!("[{}]", Timer::from(20).format())));
+    }
+
     #[ignore]
     #[test]
     fn test_timer_formatting() {
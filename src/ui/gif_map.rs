@@ -1,3 +1,4 @@
+use super::constants::is_accessible_mode;
 use crate::{
     image::{
         color_map::AsteroidColorMap,
@@ -18,6 +19,7 @@ use anyhow::anyhow;
 use image::{imageops::resize, GenericImageView, Rgba, RgbaImage};
 use imageproc::geometric_transformations::{rotate_about_center, Interpolation};
 use once_cell::sync::Lazy;
+use ratatui::text::Line;
 use std::collections::HashMap;
 
 const MAX_GIF_WIDTH: u32 = 160;
@@ -25,8 +27,7 @@ const MAX_GIF_HEIGHT: u32 = 140;
 pub const FRAMES_PER_REVOLUTION: usize = 360;
 
 pub static SPINNING_BALL_GIF: Lazy<GifLines> = Lazy::new(|| {
-    Gif::open("game/spinning_ball.gif".to_string())
-        .expect("Left shot gif should open")
+    Gif::open_or_placeholder("game/spinning_ball.gif".to_string())
         .iter()
         .map(|img: &RgbaImage| {
             let base = &mut UNIVERSE_BACKGROUND.clone();
@@ -51,37 +52,22 @@ pub static SPINNING_BALL_GIF: Lazy<GifLines> = Lazy::new(|| {
         .to_lines()
 });
 
-pub static LEFT_SHOT_GIF: Lazy<GifLines> = Lazy::new(|| {
-    Gif::open("game/left_shot.gif".to_string())
-        .expect("Left shot gif should open")
-        .to_lines()
-});
+pub static LEFT_SHOT_GIF: Lazy<GifLines> =
+    Lazy::new(|| Gif::open_or_placeholder("game/left_shot.gif".to_string()).to_lines());
 
-pub static RIGHT_SHOT_GIF: Lazy<GifLines> = Lazy::new(|| {
-    Gif::open("game/right_shot.gif".to_string())
-        .expect("Right shot gif should open")
-        .to_lines()
-});
+pub static RIGHT_SHOT_GIF: Lazy<GifLines> =
+    Lazy::new(|| Gif::open_or_placeholder("game/right_shot.gif".to_string()).to_lines());
 
 pub static PORTAL_GIFS: Lazy<Vec<GifLines>> = Lazy::new(|| {
     vec![
-        Gif::open("portal/portal_blue.gif".into())
-            .expect("Cannot open portal_blue.gif.")
-            .to_lines(),
-        Gif::open("portal/portal_pink.gif".into())
-            .expect("Cannot open portal_pink.gif.")
-            .to_lines(),
-        Gif::open("portal/portal_red.gif".into())
-            .expect("Cannot open portal_red.gif.")
-            .to_lines(),
+        Gif::open_or_placeholder("portal/portal_blue.gif".into()).to_lines(),
+        Gif::open_or_placeholder("portal/portal_pink.gif".into()).to_lines(),
+        Gif::open_or_placeholder("portal/portal_red.gif".into()).to_lines(),
     ]
 });
 
-pub static TREASURE_GIF: Lazy<GifLines> = Lazy::new(|| {
-    Gif::open("treasure/treasure.gif".into())
-        .expect("Cannot open treasure.gif.")
-        .to_lines()
-});
+pub static TREASURE_GIF: Lazy<GifLines> =
+    Lazy::new(|| Gif::open_or_placeholder("treasure/treasure.gif".into()).to_lines());
 
 pub enum ImageResizeInGalaxyGif {
     ZoomOutCentral { planet_type: PlanetType },
@@ -110,6 +96,18 @@ impl ImageResizeInGalaxyGif {
     }
 }
 
+// In accessible mode, images are replaced by a textual description.
+fn description_lines(description: String) -> ImageLines {
+    vec![Line::from(format!("[{}]", description))]
+}
+
+fn spaceship_description(spaceship: &Spaceship, doing: &str) -> ImageLines {
+    description_lines(format!(
+        "The {} {}: {} hull, {} engine, {} storage",
+        spaceship.name, doing, spaceship.hull, spaceship.engine, spaceship.storage
+    ))
+}
+
 #[derive(Debug, Default)]
 pub struct GifMap {
     players_lines: HashMap<PlayerId, (u64, GifLines)>,
@@ -131,6 +129,15 @@ impl GifMap {
     }
 
     pub fn player_frame_lines(&mut self, player: &Player, tick: usize) -> AppResult<ImageLines> {
+        if is_accessible_mode() {
+            return Ok(description_lines(format!(
+                "Portrait of {}, {} pirate, {} cm",
+                player.info.full_name(),
+                player.info.population,
+                player.info.height as u8
+            )));
+        }
+
         if let Some((version, lines)) = self.players_lines.get(&player.id) {
             if player.version == *version {
                 return Ok(lines[(tick / 8) % lines.len()].clone());
@@ -195,6 +202,13 @@ impl GifMap {
             .get_planet(planet_id)
             .ok_or(anyhow!("World: Planet not found."))?;
 
+        if is_accessible_mode() {
+            return Ok(description_lines(format!(
+                "{}, {} planet",
+                planet.name, planet.planet_type
+            )));
+        }
+
         let gif = Self::planet_zoom_in(planet)?;
         let lines = gif.to_lines();
         self.planets_zoom_in_lines.insert(planet.id, lines.clone());
@@ -377,6 +391,14 @@ impl GifMap {
         tick: usize,
         world: &World,
     ) -> AppResult<ImageLines> {
+        if is_accessible_mode() {
+            return Ok(description_lines(format!(
+                "{} system, {} satellites",
+                planet.name,
+                planet.satellites.len()
+            )));
+        }
+
         if let Some((version, lines)) = self.planets_zoom_out_lines.get(&planet.id) {
             if planet.version == *version {
                 return Ok(lines[tick % lines.len()].clone());
@@ -399,6 +421,10 @@ impl GifMap {
         spaceship: &Spaceship,
         tick: usize,
     ) -> AppResult<ImageLines> {
+        if is_accessible_mode() {
+            return Ok(spaceship_description(spaceship, "is docked"));
+        }
+
         let spacehip_image_id = spaceship.image_id();
 
         if let Some(lines) = self.on_planet_spaceship_lines.get(&spacehip_image_id) {
@@ -417,6 +443,10 @@ impl GifMap {
         spaceship: &Spaceship,
         tick: usize,
     ) -> AppResult<ImageLines> {
+        if is_accessible_mode() {
+            return Ok(spaceship_description(spaceship, "is in the shipyard"));
+        }
+
         let spacehip_image_id = spaceship.image_id();
 
         if let Some(lines) = self.in_shipyard_spaceship_lines.get(&spacehip_image_id) {
@@ -435,6 +465,10 @@ impl GifMap {
         spaceship: &Spaceship,
         tick: usize,
     ) -> AppResult<ImageLines> {
+        if is_accessible_mode() {
+            return Ok(spaceship_description(spaceship, "is travelling"));
+        }
+
         let spacehip_image_id = spaceship.image_id();
 
         if let Some(lines) = self.travelling_spaceship_lines.get(&spacehip_image_id) {
@@ -485,6 +519,10 @@ impl GifMap {
         spaceship: &Spaceship,
         tick: usize,
     ) -> AppResult<ImageLines> {
+        if is_accessible_mode() {
+            return Ok(spaceship_description(spaceship, "is exploring"));
+        }
+
         let spacehip_image_id = spaceship.image_id();
 
         if let Some(lines) = self.exploring_spaceship_lines.get(&spacehip_image_id) {
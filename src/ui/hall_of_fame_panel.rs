@@ -0,0 +1,219 @@
+use super::clickable_list::ClickableListState;
+use super::constants::*;
+use super::traits::{Screen, SplitPanel};
+use super::ui_callback::{CallbackRegistry, UiCallback};
+use super::utils::format_satoshi;
+use super::widgets::{default_block, selectable_list};
+use crate::types::{AppResult, SystemTimeTick};
+use crate::world::history::History;
+use crate::world::skill::Rated;
+use crate::world::world::World;
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Layout, Margin},
+    prelude::Rect,
+    text::{Line, Span},
+    widgets::{Paragraph, Wrap},
+    Frame,
+};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Default)]
+pub struct HallOfFamePanel {
+    index: usize,
+    history: History,
+    callback_registry: Arc<Mutex<CallbackRegistry>>,
+}
+
+impl HallOfFamePanel {
+    pub fn new(callback_registry: Arc<Mutex<CallbackRegistry>>) -> Self {
+        Self {
+            callback_registry,
+            ..Default::default()
+        }
+    }
+
+    fn render_legend_list(&self, frame: &mut Frame, area: Rect) {
+        frame.render_widget(default_block().title("Retired legends "), area);
+
+        let options = self
+            .history
+            .legends
+            .iter()
+            .enumerate()
+            .map(|(idx, legend)| {
+                (
+                    format!(
+                        " {:>2}. {:<24} {}",
+                        idx + 1,
+                        legend.name,
+                        legend.rating.stars()
+                    ),
+                    UiStyle::current().default,
+                )
+            })
+            .collect();
+
+        let list = selectable_list(options, &self.callback_registry);
+        frame.render_stateful_widget(
+            list,
+            area.inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+            &mut ClickableListState::default().with_selected(Some(self.index)),
+        );
+    }
+
+    fn render_records(&self, frame: &mut Frame, area: Rect) {
+        frame.render_widget(default_block().title("Records "), area);
+
+        let record_line = |title: &str, description: Option<String>| {
+            Line::from(vec![
+                Span::raw(format!("{:<20} ", title)),
+                match description {
+                    Some(description) => Span::styled(description, UiStyle::current().highlight),
+                    None => Span::styled("Not set yet", UiStyle::current().unselectable),
+                },
+            ])
+        };
+
+        let lines = vec![
+            record_line(
+                "Highest score",
+                self.history.highest_score.as_ref().map(|record| {
+                    format!(
+                        "{} {}-{} vs {} ({})",
+                        record.team_name,
+                        record.score,
+                        record.opponent_score,
+                        record.opponent_name,
+                        record.tick.formatted_as_date()
+                    )
+                }),
+            ),
+            record_line(
+                "Longest win streak",
+                self.history.longest_win_streak.as_ref().map(|record| {
+                    format!(
+                        "{} with {} wins ({})",
+                        record.team_name,
+                        record.value,
+                        record.tick.formatted_as_date()
+                    )
+                }),
+            ),
+            record_line(
+                "Richest team",
+                self.history.richest_team.as_ref().map(|record| {
+                    format!(
+                        "{} with {} ({})",
+                        record.team_name,
+                        format_satoshi(record.value),
+                        record.tick.formatted_as_date()
+                    )
+                }),
+            ),
+        ];
+
+        frame.render_widget(
+            Paragraph::new(lines).wrap(Wrap { trim: true }),
+            area.inner(Margin {
+                horizontal: 2,
+                vertical: 1,
+            }),
+        );
+    }
+
+    fn render_legend_details(&self, frame: &mut Frame, area: Rect) {
+        frame.render_widget(default_block().title("Legend "), area);
+        let legend = match self.history.legends.get(self.index) {
+            Some(legend) => legend,
+            None => return,
+        };
+
+        let lines = vec![
+            Line::from(Span::styled(legend.name.clone(), UiStyle::current().header)),
+            Line::from(""),
+            Line::from(format!("{:<12} {}", "Last team", legend.team_name)),
+            Line::from(format!("{:<12} {}", "Rating", legend.rating.stars())),
+            Line::from(format!("{:<12} {:.1}", "Reputation", legend.reputation)),
+            Line::from(format!(
+                "{:<12} {}",
+                "Retired",
+                legend.retired_at.formatted_as_date()
+            )),
+        ];
+
+        frame.render_widget(
+            Paragraph::new(lines).wrap(Wrap { trim: true }),
+            area.inner(Margin {
+                horizontal: 2,
+                vertical: 1,
+            }),
+        );
+    }
+}
+
+impl Screen for HallOfFamePanel {
+    fn update(&mut self, world: &World) -> AppResult<()> {
+        if world.dirty_ui || self.history != world.history {
+            self.history = world.history.clone();
+        }
+        Ok(())
+    }
+
+    fn render(
+        &mut self,
+        frame: &mut Frame,
+        _world: &World,
+        area: Rect,
+        _debug_view: bool,
+    ) -> AppResult<()> {
+        if self.history.is_empty() {
+            frame.render_widget(
+                Paragraph::new("History is still to be written.")
+                    .centered()
+                    .block(default_block().title("Hall of Fame ")),
+                area,
+            );
+            return Ok(());
+        }
+
+        let split = Layout::horizontal([Constraint::Length(40), Constraint::Min(48)]).split(area);
+        self.render_legend_list(frame, split[0]);
+
+        let right_split =
+            Layout::vertical([Constraint::Length(7), Constraint::Min(0)]).split(split[1]);
+        self.render_records(frame, right_split[0]);
+        self.render_legend_details(frame, right_split[1]);
+        Ok(())
+    }
+
+    fn handle_key_events(
+        &mut self,
+        key_event: crossterm::event::KeyEvent,
+        _world: &World,
+    ) -> Option<UiCallback> {
+        match key_event.code {
+            KeyCode::Up => self.next_index(),
+            KeyCode::Down => self.previous_index(),
+            _ => {}
+        }
+        None
+    }
+}
+
+impl SplitPanel for HallOfFamePanel {
+    fn index(&self) -> usize {
+        self.index
+    }
+
+    fn max_index(&self) -> usize {
+        self.history.legends.len()
+    }
+
+    fn set_index(&mut self, index: usize) {
+        self.index = index;
+    }
+}
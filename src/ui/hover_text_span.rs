@@ -52,6 +52,13 @@ impl Widget for HoverTextSpan<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         self.span.render(area, buf);
 
+        if self.hover_text.to_string().len() > 0 {
+            self.callback_registry
+                .lock()
+                .unwrap()
+                .register_focusable(area);
+        }
+
         // Render hover text if the mouse is hovering over the paragraph
         // and the hover text is not empty.
         if self.callback_registry.lock().unwrap().is_hovering(area)
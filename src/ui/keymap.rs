@@ -0,0 +1,399 @@
+use super::{constants::UiKey, my_team_panel::MyTeamView, ui::UiTab};
+use crate::world::position::MAX_POSITION;
+use crossterm::event::KeyCode;
+use std::fmt::Display;
+
+/// Scope in which a hotkey is active. Keyboard callbacks are resolved from the
+/// most specific context to the global one, so the same key can be bound in
+/// contexts which are never active at the same time.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyContext {
+    #[default]
+    Global,
+    Popup,
+    SpaceAdventure,
+    NewTeam,
+    Broadcast,
+    Panel(UiTab),
+    MyTeamView(MyTeamView),
+}
+
+impl Display for KeyContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Global => write!(f, "Global"),
+            Self::Popup => write!(f, "Popup"),
+            Self::SpaceAdventure => write!(f, "Space adventure"),
+            Self::NewTeam => write!(f, "New team"),
+            Self::Broadcast => write!(f, "Broadcast"),
+            Self::Panel(tab) => write!(f, "{} panel", tab),
+            Self::MyTeamView(view) => write!(f, "MyTeam {:?} view", view),
+        }
+    }
+}
+
+const NAVIGATION_KEYS: [(&str, KeyCode); 2] = [
+    ("Next tab", UiKey::NEXT_TAB),
+    ("Previous tab", UiKey::PREVIOUS_TAB),
+];
+
+impl KeyContext {
+    pub fn all() -> Vec<Self> {
+        let mut contexts = vec![
+            Self::Global,
+            Self::Popup,
+            Self::SpaceAdventure,
+            Self::NewTeam,
+            Self::Broadcast,
+        ];
+        for tab in [
+            UiTab::MyTeam,
+            UiTab::Teams,
+            UiTab::Players,
+            UiTab::Galaxy,
+            UiTab::Games,
+            UiTab::Calendar,
+            UiTab::Events,
+            UiTab::Notifications,
+            UiTab::Campaign,
+            UiTab::Tournaments,
+            UiTab::HallOfFame,
+            UiTab::Swarm,
+        ] {
+            contexts.push(Self::Panel(tab));
+        }
+        for view in [
+            MyTeamView::Info,
+            MyTeamView::Games,
+            MyTeamView::Market,
+            MyTeamView::Shipyard,
+            MyTeamView::Modules,
+            MyTeamView::Asteroids,
+            MyTeamView::Crew,
+            MyTeamView::Reputation,
+            MyTeamView::Finances,
+            MyTeamView::Orders,
+            MyTeamView::Contracts,
+            MyTeamView::Training,
+            MyTeamView::Academy,
+            MyTeamView::Staff,
+            MyTeamView::Appearance,
+            MyTeamView::Advisor,
+        ] {
+            contexts.push(Self::MyTeamView(view));
+        }
+        contexts
+    }
+
+    pub fn parent(&self) -> Option<Self> {
+        match self {
+            Self::Global => None,
+            Self::MyTeamView(_) => Some(Self::Panel(UiTab::MyTeam)),
+            _ => Some(Self::Global),
+        }
+    }
+
+    pub fn depth(&self) -> usize {
+        match self.parent() {
+            Some(parent) => parent.depth() + 1,
+            None => 0,
+        }
+    }
+
+    // Hotkeys bound in this context only, without the ones inherited from the parents.
+    pub fn keymap(&self) -> Vec<(&'static str, KeyCode)> {
+        let mut keymap = match self {
+            Self::Global => vec![
+                ("Quit", UiKey::ESC),
+                ("Debug mode", UiKey::UI_DEBUG_MODE),
+                ("Keyboard only mode", UiKey::KEYBOARD_ONLY_MODE),
+                ("Cycle UI theme", UiKey::CYCLE_UI_THEME),
+                ("Accessible mode", UiKey::ACCESSIBLE_MODE),
+                ("Page down / Focus next", UiKey::FOCUS_NEXT),
+                ("Page up / Focus previous", UiKey::FOCUS_PREVIOUS),
+                ("Toggle audio", UiKey::TOGGLE_AUDIO),
+                ("Previous radio", UiKey::PREVIOUS_RADIO),
+                ("Next radio", UiKey::NEXT_RADIO),
+                ("Pause world", UiKey::PAUSE_WORLD),
+                ("Fast-forward world", UiKey::FAST_FORWARD_WORLD),
+            ],
+            Self::Popup => vec![
+                ("Yes", UiKey::YES_TO_DIALOG),
+                ("No", UiKey::NO_TO_DIALOG),
+                ("Back to base", UiKey::SPACE_BACK_TO_BASE),
+            ],
+            Self::SpaceAdventure => vec![
+                ("Move left", UiKey::SPACE_MOVE_LEFT),
+                ("Move right", UiKey::SPACE_MOVE_RIGHT),
+                ("Move down", UiKey::SPACE_MOVE_DOWN),
+                ("Move up", UiKey::SPACE_MOVE_UP),
+                ("Main button", UiKey::SPACE_MAIN),
+                ("Second button", UiKey::SPACE_SECOND),
+                ("Pause", UiKey::SPACE_PAUSE),
+                ("Back to base", UiKey::SPACE_BACK_TO_BASE),
+            ],
+            Self::NewTeam => vec![("Campaign mode", UiKey::CAMPAIGN_MODE)],
+            Self::Broadcast => vec![
+                ("Exit broadcast mode", UiKey::BROADCAST_MODE),
+                ("Next selection", UiKey::NEXT_SELECTION),
+                ("Previous selection", UiKey::PREVIOUS_SELECTION),
+            ],
+            Self::Panel(tab) => {
+                let mut keymap = NAVIGATION_KEYS.to_vec();
+                keymap.append(&mut Self::panel_keymap(*tab));
+                keymap
+            }
+            Self::MyTeamView(view) => Self::my_team_view_keymap(*view),
+        };
+        keymap.sort_by_key(|(name, _)| *name);
+        keymap
+    }
+
+    fn panel_keymap(tab: UiTab) -> Vec<(&'static str, KeyCode)> {
+        match tab {
+            UiTab::MyTeam => {
+                let mut keymap = vec![
+                    ("Cycle view", UiKey::CYCLE_VIEW),
+                    ("Cycle sort", UiKey::CYCLE_SORT),
+                    ("Auto assign", UiKey::AUTO_ASSIGN),
+                    ("Space adventure", UiKey::SPACE_ADVENTURE),
+                    ("Explore", UiKey::EXPLORE),
+                    ("Set captain", UiKey::SET_CAPTAIN),
+                    ("Set pilot", UiKey::SET_PILOT),
+                    ("Set doctor", UiKey::SET_DOCTOR),
+                    ("Set engineer", UiKey::SET_ENGINEER),
+                    ("Set cook", UiKey::SET_COOK),
+                    ("Fire", UiKey::FIRE),
+                    ("Drink", UiKey::DRINK),
+                    ("Duel", UiKey::DUEL),
+                    ("Nickname", UiKey::NICKNAME_PLAYER),
+                ];
+                for position in 0..MAX_POSITION {
+                    keymap.push(("Set player position", UiKey::set_player_position(position)));
+                }
+                keymap
+            }
+            UiTab::Teams => vec![
+                ("Cycle view", UiKey::CYCLE_VIEW),
+                ("Search", UiKey::SEARCH),
+                ("Next selection", UiKey::NEXT_SELECTION),
+                ("Previous selection", UiKey::PREVIOUS_SELECTION),
+                ("Go to planet", UiKey::GO_TO_PLANET),
+                ("Challenge team", UiKey::CHALLENGE_TEAM),
+                ("Go to game", UiKey::GO_TO_GAME),
+                ("Build trade", UiKey::CREATE_TRADE),
+                ("Cycle trade resource", UiKey::CYCLE_TRADE_RESOURCE),
+                ("Offer resource sale", UiKey::OFFER_RESOURCE_SALE),
+                ("Offer resource purchase", UiKey::OFFER_RESOURCE_PURCHASE),
+                ("Cycle trade kartoffel", UiKey::CYCLE_TRADE_KARTOFFEL),
+                ("Offer kartoffel sale", UiKey::OFFER_KARTOFFEL_SALE),
+                ("Accept resource trade", UiKey::ACCEPT_TRADE),
+                ("Decline resource trade", UiKey::DECLINE_TRADE),
+            ],
+            UiTab::Players => vec![
+                ("Cycle view", UiKey::CYCLE_VIEW),
+                ("Cycle sort", UiKey::CYCLE_SORT),
+                ("Search", UiKey::SEARCH),
+                ("Go to team", UiKey::GO_TO_TEAM),
+                ("Go to team", UiKey::GO_TO_TEAM_ALTERNATIVE),
+                ("Go to planet", UiKey::GO_TO_PLANET),
+                ("Hire", UiKey::HIRE),
+                ("Scout planet", UiKey::SCOUT_PLANET),
+                ("Enroll in academy", UiKey::ENROLL_ACADEMY),
+                ("Lock player", UiKey::LOCK_PLAYER),
+                ("Unlock player", UiKey::UNLOCK_PLAYER),
+                ("Create trade", UiKey::CREATE_TRADE),
+                ("Accept trade", UiKey::ACCEPT_TRADE),
+                ("Decline trade", UiKey::DECLINE_TRADE),
+                ("Counter trade", UiKey::COUNTER_TRADE),
+                ("Feed kartoffel", UiKey::FEED_KARTOFFEL),
+                ("Assign kartoffel", UiKey::ASSIGN_KARTOFFEL),
+            ],
+            UiTab::Galaxy => vec![
+                ("Travel", UiKey::TRAVEL),
+                ("Travel route", UiKey::TRAVEL_ROUTE),
+                ("Queue travel", UiKey::QUEUE_TRAVEL),
+                ("Fuel grade", UiKey::FUEL_GRADE),
+                ("Engine tuning", UiKey::ENGINE_TUNING),
+                ("Space adventure", UiKey::SPACE_ADVENTURE),
+                ("Scan planets", UiKey::SCAN_PLANETS),
+                ("Build wormhole gate", UiKey::BUILD_WORMHOLE_GATE),
+            ],
+            UiTab::Games => vec![
+                ("Pitch view", UiKey::PITCH_VIEW),
+                ("Player status view", UiKey::PLAYER_STATUS_VIEW),
+                ("Box score view", UiKey::BOX_SCORE_VIEW),
+                ("Mute crowd chatter", UiKey::MUTE_CROWD_CHATTER),
+                ("Broadcast mode", UiKey::BROADCAST_MODE),
+                ("Set tactic", UiKey::SET_TACTIC),
+                ("Call timeout", UiKey::CALL_TIMEOUT),
+                ("Fiery speech", UiKey::SPEECH_FIERY),
+                ("Calm speech", UiKey::SPEECH_CALM),
+                ("Mocking speech", UiKey::SPEECH_MOCKING),
+                ("Substitute", UiKey::SUBSTITUTE),
+                ("Cycle sub out", UiKey::CYCLE_SUB_OUT),
+                ("Cycle sub in", UiKey::CYCLE_SUB_IN),
+                ("Next selection", UiKey::NEXT_SELECTION),
+                ("Previous selection", UiKey::PREVIOUS_SELECTION),
+            ],
+            UiTab::Swarm => vec![
+                ("Cycle view", UiKey::CYCLE_VIEW),
+                ("Accept", UiKey::YES_TO_DIALOG),
+                ("Decline", UiKey::NO_TO_DIALOG),
+            ],
+            UiTab::Tournaments => vec![("Register", UiKey::REGISTER_TOURNAMENT)],
+            UiTab::Notifications => vec![
+                ("Filter notifications", UiKey::FILTER_NOTIFICATIONS),
+                ("Clear notifications", UiKey::CLEAR_NOTIFICATIONS),
+            ],
+            UiTab::Calendar | UiTab::Events | UiTab::Campaign | UiTab::HallOfFame => vec![],
+        }
+    }
+
+    fn my_team_view_keymap(view: MyTeamView) -> Vec<(&'static str, KeyCode)> {
+        match view {
+            MyTeamView::Info => vec![
+                ("Set tactic", UiKey::SET_TACTIC),
+                ("Training focus", UiKey::TRAINING_FOCUS),
+                ("Go to planet", UiKey::GO_TO_PLANET),
+                ("Go to home planet", UiKey::GO_TO_HOME_PLANET),
+            ],
+            MyTeamView::Games => vec![
+                ("Go to game", UiKey::GO_TO_GAME),
+                ("Challenge team", UiKey::CHALLENGE_TEAM),
+                ("Play by play view", UiKey::PLAY_BY_PLAY_VIEW),
+                ("Scroll down", UiKey::SCROLL_PLAY_BY_PLAY_DOWN),
+                ("Scroll up", UiKey::SCROLL_PLAY_BY_PLAY_UP),
+                ("Export team data", UiKey::EXPORT_TEAM_DATA),
+                ("Share game summary", UiKey::SHARE_GAME_SUMMARY),
+            ],
+            MyTeamView::Market => vec![
+                ("Buy fuel", UiKey::BUY_FUEL),
+                ("Buy gold", UiKey::BUY_GOLD),
+                ("Buy scraps", UiKey::BUY_SCRAPS),
+                ("Buy rum", UiKey::BUY_RUM),
+                ("Sell fuel", UiKey::SELL_FUEL),
+                ("Sell gold", UiKey::SELL_GOLD),
+                ("Sell scraps", UiKey::SELL_SCRAPS),
+                ("Sell rum", UiKey::SELL_RUM),
+                ("Auto-refuel", UiKey::AUTO_REFUEL),
+                (
+                    "Lower auto-refuel target",
+                    UiKey::DECREASE_AUTO_REFUEL_TARGET,
+                ),
+                (
+                    "Raise auto-refuel target",
+                    UiKey::INCREASE_AUTO_REFUEL_TARGET,
+                ),
+            ],
+            MyTeamView::Shipyard => vec![
+                ("Upgrade spaceship", UiKey::UPGRADE_SPACESHIP),
+                ("Repair spaceship", UiKey::REPAIR_SPACESHIP),
+                ("Space adventure upgrades", UiKey::SPACE_UPGRADES_VIEW),
+                ("Switch spaceship", UiKey::SWITCH_SPACESHIP),
+                ("Buy spaceship", UiKey::BUY_SPACESHIP),
+                ("Cycle spaceship model", UiKey::CYCLE_SPACESHIP_PREFAB),
+            ],
+            MyTeamView::Modules => vec![
+                ("Install module", UiKey::INSTALL_MODULE),
+                ("Uninstall module", UiKey::UNINSTALL_MODULE),
+            ],
+            MyTeamView::Crew => vec![
+                ("Pay crew bonus", UiKey::PAY_CREW_BONUS),
+                ("Share rum", UiKey::SHARE_RUM),
+            ],
+            MyTeamView::Academy => vec![
+                ("Promote prospect", UiKey::PROMOTE_PROSPECT),
+                ("Release prospect", UiKey::RELEASE_PROSPECT),
+            ],
+            MyTeamView::Staff => vec![
+                ("Hire staff", UiKey::HIRE_STAFF),
+                ("Fire staff", UiKey::FIRE_STAFF),
+            ],
+            MyTeamView::Appearance => vec![
+                ("Cycle jersey style", UiKey::CYCLE_JERSEY_STYLE),
+                ("Apply appearance", UiKey::CHANGE_APPEARANCE),
+                ("Emblem editor", UiKey::EMBLEM_VIEW),
+                ("Cycle emblem symbol", UiKey::CYCLE_EMBLEM_SYMBOL),
+                ("Cycle emblem pattern", UiKey::CYCLE_EMBLEM_PATTERN),
+                ("Apply emblem", UiKey::CHANGE_EMBLEM),
+                ("Rename team", UiKey::RENAME_TEAM),
+                ("Rename spaceship", UiKey::RENAME_SPACESHIP),
+            ],
+            MyTeamView::Finances => vec![
+                ("Take loan", UiKey::TAKE_LOAN),
+                ("Repay loan", UiKey::REPAY_LOAN),
+                ("Insure hull", UiKey::INSURE_HULL),
+                ("Insure cargo", UiKey::INSURE_CARGO),
+            ],
+            MyTeamView::Asteroids
+            | MyTeamView::Reputation
+            | MyTeamView::Orders
+            | MyTeamView::Contracts
+            | MyTeamView::Training
+            | MyTeamView::Advisor => vec![],
+        }
+    }
+
+    // Hotkeys active in this context, including the inherited ones.
+    fn resolved_keymap(&self) -> Vec<(KeyContext, &'static str, KeyCode)> {
+        let mut keymap = vec![];
+        let mut context = Some(*self);
+        while let Some(current) = context {
+            for (name, key) in current.keymap() {
+                keymap.push((current, name, key));
+            }
+            context = current.parent();
+        }
+        keymap
+    }
+}
+
+/// Lists the keys bound to different actions in the same context,
+/// or shadowing a binding of a parent context.
+pub fn keymap_conflicts() -> Vec<String> {
+    let mut conflicts = vec![];
+    for context in KeyContext::all() {
+        let keymap = context.resolved_keymap();
+        for (idx, (owner, name, key)) in keymap.iter().enumerate() {
+            // Report each conflict only once, in the context that introduces it.
+            if *owner != context {
+                continue;
+            }
+            for (other_owner, other_name, other_key) in keymap.iter().skip(idx + 1) {
+                if key == other_key && name != other_name {
+                    conflicts.push(format!(
+                        "{}: {:?} bound to both {} and {} ({})",
+                        context, key, name, other_name, other_owner
+                    ));
+                }
+            }
+        }
+    }
+    conflicts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{keymap_conflicts, KeyContext};
+    use crate::ui::{constants::UiKey, my_team_panel::MyTeamView, ui::UiTab};
+
+    #[test]
+    fn test_no_keymap_conflicts() {
+        let conflicts = keymap_conflicts();
+        assert!(conflicts.is_empty(), "{:#?}", conflicts);
+    }
+
+    #[test]
+    fn test_market_keys_are_scoped() {
+        // Buying gold and going to a game share the same key in different views.
+        assert_eq!(UiKey::BUY_GOLD, UiKey::GO_TO_GAME);
+        let market = KeyContext::MyTeamView(MyTeamView::Market);
+        assert_eq!(market.depth(), 2);
+        assert_eq!(market.parent(), Some(KeyContext::Panel(UiTab::MyTeam)));
+        assert!(market
+            .keymap()
+            .iter()
+            .all(|(name, _)| !name.starts_with("Go to")));
+    }
+}
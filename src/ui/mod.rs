@@ -1,21 +1,34 @@
 mod big_numbers;
+mod broadcast_screen;
 mod button;
+mod calendar_panel;
+mod campaign_panel;
 mod clickable_list;
 mod clickable_table;
-mod constants;
+mod clipboard;
+pub(crate) mod constants;
+mod events_panel;
 mod galaxy_panel;
+mod game_over_screen;
 mod game_panel;
 mod gif_map;
+mod hall_of_fame_panel;
 mod hover_text_line;
 mod hover_text_span;
+mod keymap;
 mod my_team_panel;
 mod new_team_screen;
+mod notifications_panel;
 mod player_panel;
+pub(crate) mod player_sort;
 pub mod popup_message;
+mod scrollbar;
+mod search_box;
 mod space_screen;
 mod splash_screen;
 mod swarm_panel;
 mod team_panel;
+mod tournament_panel;
 pub(crate) mod traits;
 pub mod ui;
 pub mod ui_callback;
@@ -3,21 +3,57 @@ use super::{
     clickable_list::ClickableListState,
     clickable_table::{ClickableCell, ClickableRow, ClickableTable, ClickableTableState},
     constants::*,
+    game_panel::play_by_play_lines,
     gif_map::GifMap,
+    keymap::KeyContext,
+    player_sort::{PlayerSort, PlayerSortKey},
     traits::{PercentageRating, Screen, SplitPanel, UiStyled},
     ui_callback::{CallbackRegistry, UiCallback},
-    utils::{format_satoshi, hover_text_target},
+    utils::{format_satoshi, hover_text_target, img_to_lines, sparkline},
     widgets::*,
 };
 use crate::{
     game_engine::game::Game,
+    image::{
+        color_map::ColorPreset,
+        emblem::{flag_image, FLAG_WIDTH},
+        spaceship::SPACESHIP_IMAGE_WIDTH,
+    },
     store::load_game,
-    types::{AppResult, GameId, PlayerId, StorableResourceMap, SystemTimeTick, Tick},
+    types::{
+        AppResult, ContractId, GameId, PlayerId, ResourceMap, StorableResourceMap, SystemTimeTick,
+        Tick,
+    },
     world::{
+        academy::{self, ACADEMY_DAILY_FEE, MAX_ACADEMY_PROSPECTS},
+        action_queue::QueuedAction,
+        advisor::{team_advice, AdviceAction},
+        constants::{
+            APPEARANCE_CHANGE_COST, AUTO_REFUEL_TARGET_STEP, DAYS, EMBLEM_CHANGE_COST,
+            LONG_EXPLORATION_TIME, MAX_AUTO_REFUEL_TARGET, MAX_FLEET_SIZE, MAX_MORALE,
+            MAX_QUEUED_ACTIONS, MORALE_THRESHOLD_FOR_MUTINY, QUEUED_BUY_AMOUNT,
+            QUICK_EXPLORATION_TIME, TEAM_GRUDGE_STANDING,
+        },
+        contract::{ContractStatus, CONTRACT_FAILURE_REPUTATION_MALUS, MAX_ACCEPTED_CONTRACTS},
+        emblem::Emblem,
+        insurance::{InsuranceKind, INSURANCE_COVERAGE, INSURANCE_DEDUCTIBLE, INSURANCE_TERM},
+        jersey::Jersey,
+        loan::{max_loan_amount, LOAN_DAILY_INTEREST, LOAN_DEFAULT_REPUTATION_MALUS, LOAN_STEP},
+        market::MARKET_RESOURCES,
+        planet::{AsteroidFacility, Planet, PlanetType, MAX_PENDING_PRODUCTION_DAYS},
         position::{GamePosition, Position, MAX_POSITION},
+        scouting::SkillVisibility,
         skill::Rated,
-        spaceship::{SpaceshipComponent, SpaceshipUpgrade, SpaceshipUpgradeTarget},
-        types::{TeamBonus, TeamLocation},
+        space_upgrades::SpaceAdventureUpgrade,
+        spaceship::{
+            SpaceshipComponent, SpaceshipModule, SpaceshipPrefab, SpaceshipUpgrade,
+            SpaceshipUpgradeTarget,
+        },
+        staff::StaffRole,
+        team::Team,
+        training::{TrainingSchedule, TRAINING_DAYS, TRAINING_SESSION_TIREDNESS},
+        tutorial::{Tutorial, TutorialQuest},
+        types::{RenameTarget, TeamBonus, TeamLocation, TrainingFocus},
         world::World,
     },
 };
@@ -42,14 +78,28 @@ use std::{
 };
 use strum::IntoEnumIterator;
 
-#[derive(Debug, Default, PartialEq, Clone, Copy)]
+// Number of hourly prices shown in the market price trend.
+const MARKET_TREND_LENGTH: usize = 16;
+
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum MyTeamView {
     #[default]
     Info,
     Games,
     Market,
     Shipyard,
+    Modules,
     Asteroids,
+    Crew,
+    Reputation,
+    Finances,
+    Orders,
+    Contracts,
+    Training,
+    Academy,
+    Staff,
+    Appearance,
+    Advisor,
 }
 
 impl MyTeamView {
@@ -58,12 +108,30 @@ impl MyTeamView {
             MyTeamView::Info => MyTeamView::Games,
             MyTeamView::Games => MyTeamView::Market,
             MyTeamView::Market => MyTeamView::Shipyard,
-            MyTeamView::Shipyard => MyTeamView::Asteroids,
-            MyTeamView::Asteroids => MyTeamView::Info,
+            MyTeamView::Shipyard => MyTeamView::Modules,
+            MyTeamView::Modules => MyTeamView::Asteroids,
+            MyTeamView::Asteroids => MyTeamView::Crew,
+            MyTeamView::Crew => MyTeamView::Reputation,
+            MyTeamView::Reputation => MyTeamView::Finances,
+            MyTeamView::Finances => MyTeamView::Orders,
+            MyTeamView::Orders => MyTeamView::Contracts,
+            MyTeamView::Contracts => MyTeamView::Training,
+            MyTeamView::Training => MyTeamView::Academy,
+            MyTeamView::Academy => MyTeamView::Staff,
+            MyTeamView::Staff => MyTeamView::Appearance,
+            MyTeamView::Appearance => MyTeamView::Advisor,
+            MyTeamView::Advisor => MyTeamView::Info,
         }
     }
 }
 
+// First of the visible rows, keeping the selected row as centered as possible.
+fn first_visible_row(selected_row: usize, rows: usize, visible_rows: usize) -> usize {
+    selected_row
+        .saturating_sub(visible_rows / 2)
+        .min(rows.saturating_sub(visible_rows))
+}
+
 #[derive(Debug, Default, PartialEq)]
 enum PanelList {
     #[default]
@@ -77,10 +145,33 @@ pub struct MyTeamPanel {
     game_index: Option<usize>,
     planet_index: Option<usize>,
     spaceship_upgrade_index: usize,
+    space_upgrade_index: usize,
+    space_upgrades_view: bool,
+    spaceship_module_index: usize,
+    spaceship_prefab_index: usize,
     asteroid_index: Option<usize>,
+    finance_index: Option<usize>,
+    finance_entries: usize,
+    action_index: Option<usize>,
+    action_queue: Vec<QueuedAction>,
+    contract_index: Option<usize>,
+    contracts: Vec<ContractId>,
+    training_day_index: usize,
+    prospect_index: Option<usize>,
+    academy_prospects: Vec<PlayerId>,
+    staff_role_index: usize,
+    // Jersey being edited, None until the first change.
+    appearance_draft: Option<Jersey>,
+    emblem_draft: Option<Emblem>,
+    emblem_view: bool,
+    tutorial_checklist_expanded: bool,
+    play_by_play_view: bool,
+    play_by_play_offset: u16,
     view: MyTeamView,
     active_list: PanelList,
     players: Vec<PlayerId>,
+    players_sort: PlayerSort,
+    update_players_sort: bool,
     recent_games: Vec<GameId>,
     loaded_games: HashMap<GameId, Game>,
     planet_markets: Vec<PlanetId>,
@@ -105,6 +196,11 @@ impl MyTeamPanel {
         }
     }
 
+    pub fn set_players_sort(&mut self, players_sort: PlayerSort) {
+        self.players_sort = players_sort;
+        self.update_players_sort = true;
+    }
+
     fn render_view_buttons(&self, frame: &mut Frame, area: Rect) -> AppResult<()> {
         let hover_text_target = hover_text_target(frame);
         let mut view_info_button = Button::new(
@@ -153,6 +249,19 @@ impl MyTeamPanel {
             hover_text_target,
         );
 
+        let mut view_modules_button = Button::new(
+            "View: Modules".into(),
+            UiCallback::SetMyTeamPanelView {
+                view: MyTeamView::Modules,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hotkey(UiKey::CYCLE_VIEW)
+        .set_hover_text(
+            "View spaceship modules, install or remove them.".into(),
+            hover_text_target,
+        );
+
         let mut view_asteroids_button = Button::new(
             format!("View: Asteroids ({})", self.asteroid_ids.len()).into(),
             UiCallback::SetMyTeamPanelView {
@@ -166,29 +275,193 @@ impl MyTeamPanel {
             hover_text_target,
         );
 
+        let mut view_crew_button = Button::new(
+            "View: Crew".into(),
+            UiCallback::SetMyTeamPanelView {
+                view: MyTeamView::Crew,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hotkey(UiKey::CYCLE_VIEW)
+        .set_hover_text(
+            "View crew morale and the ship log.".into(),
+            hover_text_target,
+        );
+
+        let mut view_reputation_button = Button::new(
+            "View: Reputation".into(),
+            UiCallback::SetMyTeamPanelView {
+                view: MyTeamView::Reputation,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hotkey(UiKey::CYCLE_VIEW)
+        .set_hover_text(
+            "View the team reputation on each planet.".into(),
+            hover_text_target,
+        );
+
+        let mut view_finances_button = Button::new(
+            "View: Finances".into(),
+            UiCallback::SetMyTeamPanelView {
+                view: MyTeamView::Finances,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hotkey(UiKey::CYCLE_VIEW)
+        .set_hover_text(
+            "View the team income and expenses.".into(),
+            hover_text_target,
+        );
+
+        let mut view_orders_button = Button::new(
+            format!("View: Orders ({})", self.action_queue.len()).into(),
+            UiCallback::SetMyTeamPanelView {
+                view: MyTeamView::Orders,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hotkey(UiKey::CYCLE_VIEW)
+        .set_hover_text("View and edit the queued orders.".into(), hover_text_target);
+
+        let mut view_contracts_button = Button::new(
+            "View: Contracts".into(),
+            UiCallback::SetMyTeamPanelView {
+                view: MyTeamView::Contracts,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hotkey(UiKey::CYCLE_VIEW)
+        .set_hover_text(
+            "View delivery contracts offered by planets.".into(),
+            hover_text_target,
+        );
+
+        let mut view_training_button = Button::new(
+            "View: Training".into(),
+            UiCallback::SetMyTeamPanelView {
+                view: MyTeamView::Training,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hotkey(UiKey::CYCLE_VIEW)
+        .set_hover_text(
+            "View and edit the weekly training schedule.".into(),
+            hover_text_target,
+        );
+
+        let mut view_academy_button = Button::new(
+            format!("View: Academy ({})", self.academy_prospects.len()).into(),
+            UiCallback::SetMyTeamPanelView {
+                view: MyTeamView::Academy,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hotkey(UiKey::CYCLE_VIEW)
+        .set_hover_text(
+            "View the prospects developing in the academy.".into(),
+            hover_text_target,
+        );
+
+        let mut view_staff_button = Button::new(
+            "View: Staff".into(),
+            UiCallback::SetMyTeamPanelView {
+                view: MyTeamView::Staff,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hotkey(UiKey::CYCLE_VIEW)
+        .set_hover_text(
+            "View the coach, scout and mechanic working for the team.".into(),
+            hover_text_target,
+        );
+
+        let mut view_appearance_button = Button::new(
+            "View: Appearance".into(),
+            UiCallback::SetMyTeamPanelView {
+                view: MyTeamView::Appearance,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hotkey(UiKey::CYCLE_VIEW)
+        .set_hover_text(
+            "Recolor the jersey and the spaceship.".into(),
+            hover_text_target,
+        );
+
+        let mut view_advisor_button = Button::new(
+            "View: Advisor".into(),
+            UiCallback::SetMyTeamPanelView {
+                view: MyTeamView::Advisor,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hotkey(UiKey::CYCLE_VIEW)
+        .set_hover_text(
+            "View the suggestions of the captain's advisor.".into(),
+            hover_text_target,
+        );
+
         match self.view {
             MyTeamView::Info => view_info_button.disable(None),
             MyTeamView::Games => view_games_button.disable(None),
             MyTeamView::Market => view_market_button.disable(None),
             MyTeamView::Shipyard => view_shipyard_button.disable(None),
+            MyTeamView::Modules => view_modules_button.disable(None),
             MyTeamView::Asteroids => view_asteroids_button.disable(None),
+            MyTeamView::Crew => view_crew_button.disable(None),
+            MyTeamView::Reputation => view_reputation_button.disable(None),
+            MyTeamView::Finances => view_finances_button.disable(None),
+            MyTeamView::Orders => view_orders_button.disable(None),
+            MyTeamView::Contracts => view_contracts_button.disable(None),
+            MyTeamView::Training => view_training_button.disable(None),
+            MyTeamView::Academy => view_academy_button.disable(None),
+            MyTeamView::Staff => view_staff_button.disable(None),
+            MyTeamView::Appearance => view_appearance_button.disable(None),
+            MyTeamView::Advisor => view_advisor_button.disable(None),
         }
 
-        let split = Layout::vertical([
-            Constraint::Length(3),
-            Constraint::Length(3),
-            Constraint::Length(3),
-            Constraint::Length(3),
-            Constraint::Length(3),
-            Constraint::Min(0),
-        ])
-        .split(area);
+        let buttons = [
+            view_info_button,
+            view_games_button,
+            view_market_button,
+            view_shipyard_button,
+            view_modules_button,
+            view_asteroids_button,
+            view_crew_button,
+            view_reputation_button,
+            view_finances_button,
+            view_orders_button,
+            view_contracts_button,
+            view_training_button,
+            view_academy_button,
+            view_staff_button,
+            view_appearance_button,
+            view_advisor_button,
+        ];
 
-        frame.render_widget(view_info_button, split[0]);
-        frame.render_widget(view_games_button, split[1]);
-        frame.render_widget(view_market_button, split[2]);
-        frame.render_widget(view_shipyard_button, split[3]);
-        frame.render_widget(view_asteroids_button, split[4]);
+        // One row per view, in two columns when the panel is too short for all of them.
+        // If even the columns do not fit, the rows scroll to keep the current view visible.
+        let columns = if (area.height as usize) < buttons.len() {
+            2
+        } else {
+            1
+        };
+        let rows = (buttons.len() + columns - 1) / columns;
+        let visible_rows = rows.min(area.height as usize);
+        // Buttons are listed in the same order as the views are declared.
+        let first_row = first_visible_row(self.view as usize % rows, rows, visible_rows);
+        let column_split =
+            Layout::horizontal([Constraint::Ratio(1, columns as u32)].repeat(columns)).split(area);
+        for (idx, button) in buttons.into_iter().enumerate() {
+            let row = idx % rows;
+            if row < first_row || row >= first_row + visible_rows {
+                continue;
+            }
+            let row_split = Layout::vertical([Constraint::Length(1)].repeat(visible_rows))
+                .split(column_split[idx / rows]);
+            frame.render_widget(button, row_split[row - first_row]);
+        }
 
         Ok(())
     }
@@ -196,7 +469,107 @@ impl MyTeamPanel {
     fn render_market(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
         let split = Layout::horizontal([Constraint::Length(48), Constraint::Min(48)]).split(area);
         self.render_planet_markets(frame, world, split[0])?;
-        self.render_market_buttons(frame, world, split[1])?;
+        let market_split =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(5)]).split(split[1]);
+        self.render_market_buttons(frame, world, market_split[0])?;
+        self.render_auto_refuel(frame, world, market_split[1])?;
+
+        Ok(())
+    }
+
+    fn render_auto_refuel(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        let team = world.get_own_team()?;
+        let hover_text_target = hover_text_target(frame);
+        frame.render_widget(default_block().title("Auto-refuel "), area);
+
+        let split = Layout::horizontal([
+            Constraint::Length(22),
+            Constraint::Length(5),
+            Constraint::Length(14),
+            Constraint::Length(5),
+            Constraint::Min(0),
+        ])
+        .split(area.inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        }));
+
+        let toggle_button = Button::new(
+            format!(
+                "Auto-refuel: {}",
+                if team.auto_refuel { "On" } else { "Off" }
+            )
+            .into(),
+            UiCallback::SetAutoRefuel {
+                enabled: !team.auto_refuel,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            "Buy fuel up to the target automatically when landing on a planet with a market."
+                .into(),
+            hover_text_target,
+        )
+        .set_hotkey(UiKey::AUTO_REFUEL);
+        frame.render_widget(toggle_button, split[0]);
+
+        let target = if team.auto_refuel_target == 0 {
+            MAX_AUTO_REFUEL_TARGET
+        } else {
+            team.auto_refuel_target
+        };
+
+        let decrease = target.saturating_sub(AUTO_REFUEL_TARGET_STEP);
+        let mut decrease_button = Button::new(
+            "-".into(),
+            UiCallback::SetAutoRefuelTarget { target: decrease },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            format!("Lower the auto-refuel target to {}%.", decrease),
+            hover_text_target,
+        )
+        .set_hotkey(UiKey::DECREASE_AUTO_REFUEL_TARGET);
+        if let Err(err) = team.can_set_auto_refuel_target(decrease) {
+            decrease_button.disable(Some(err.to_string()));
+        }
+        frame.render_widget(decrease_button, split[1]);
+
+        frame.render_widget(
+            Paragraph::new(format!("Target {}%", target)).centered(),
+            split[2].inner(Margin {
+                horizontal: 0,
+                vertical: 1,
+            }),
+        );
+
+        let increase = target + AUTO_REFUEL_TARGET_STEP;
+        let mut increase_button = Button::new(
+            "+".into(),
+            UiCallback::SetAutoRefuelTarget { target: increase },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            format!("Raise the auto-refuel target to {}%.", increase),
+            hover_text_target,
+        )
+        .set_hotkey(UiKey::INCREASE_AUTO_REFUEL_TARGET);
+        if let Err(err) = team.can_set_auto_refuel_target(increase) {
+            increase_button.disable(Some(err.to_string()));
+        }
+        frame.render_widget(increase_button, split[3]);
+
+        frame.render_widget(
+            Paragraph::new(if team.auto_refuel {
+                format!("Next landing: {} fuel", team.auto_refuel_amount())
+            } else {
+                "Disabled".to_string()
+            }),
+            split[4].inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+        );
 
         Ok(())
     }
@@ -218,12 +591,12 @@ impl MyTeamPanel {
             let style = match team.current_location {
                 TeamLocation::OnPlanet { planet_id } => {
                     if planet_id == planet.id {
-                        UiStyle::OWN_TEAM
+                        UiStyle::current().own_team
                     } else {
-                        UiStyle::DEFAULT
+                        UiStyle::current().default
                     }
                 }
-                _ => UiStyle::DEFAULT,
+                _ => UiStyle::current().default,
             };
             options.push((text, style));
         }
@@ -241,28 +614,36 @@ impl MyTeamPanel {
 
         let planet_id = self.planet_markets[self.planet_index.unwrap_or_default()];
         let planet = world.get_planet_or_err(planet_id)?;
-        let merchant_bonus = TeamBonus::TradePrice.current_team_bonus(world, team.id)?;
+        let merchant_bonus = TeamBonus::TradePrice.current_team_bonus(world, team.id)?
+            * team.merchant_modifier(planet.id);
 
         frame.render_widget(
             Paragraph::new(vec![
                 Line::from(""),
-                Line::from(Span::styled(format!("Resource: Buy/Sell"), UiStyle::HEADER)),
+                Line::from(Span::styled(
+                    format!("Resource: Buy/Sell"),
+                    UiStyle::current().header,
+                )),
                 Line::from(vec![
                     Span::styled("Fuel      ", Resource::FUEL.style()),
                     Span::styled(
                         format!(
                             "{}",
-                            planet.resource_buy_price(Resource::FUEL, merchant_bonus)
+                            (planet.resource_buy_price(Resource::FUEL, merchant_bonus) as f32
+                                * team.spaceship.fuel_grade.price_modifier())
+                                as u32
                         ),
-                        UiStyle::OK,
+                        UiStyle::current().ok,
                     ),
                     Span::raw("/"),
                     Span::styled(
                         format!(
                             "{}",
-                            planet.resource_sell_price(Resource::FUEL, merchant_bonus)
+                            (planet.resource_sell_price(Resource::FUEL, merchant_bonus) as f32
+                                * team.spaceship.fuel_grade.price_modifier())
+                                as u32
                         ),
-                        UiStyle::ERROR,
+                        UiStyle::current().error,
                     ),
                 ]),
                 Line::from(vec![
@@ -272,7 +653,7 @@ impl MyTeamPanel {
                             "{}",
                             planet.resource_buy_price(Resource::GOLD, merchant_bonus)
                         ),
-                        UiStyle::OK,
+                        UiStyle::current().ok,
                     ),
                     Span::raw("/"),
                     Span::styled(
@@ -280,7 +661,7 @@ impl MyTeamPanel {
                             "{}",
                             planet.resource_sell_price(Resource::GOLD, merchant_bonus)
                         ),
-                        UiStyle::ERROR,
+                        UiStyle::current().error,
                     ),
                 ]),
                 Line::from(vec![
@@ -290,7 +671,7 @@ impl MyTeamPanel {
                             "{}",
                             planet.resource_buy_price(Resource::SCRAPS, merchant_bonus)
                         ),
-                        UiStyle::OK,
+                        UiStyle::current().ok,
                     ),
                     Span::raw("/"),
                     Span::styled(
@@ -298,7 +679,7 @@ impl MyTeamPanel {
                             "{}",
                             planet.resource_sell_price(Resource::SCRAPS, merchant_bonus)
                         ),
-                        UiStyle::ERROR,
+                        UiStyle::current().error,
                     ),
                 ]),
                 Line::from(vec![
@@ -308,7 +689,7 @@ impl MyTeamPanel {
                             "{}",
                             planet.resource_buy_price(Resource::RUM, merchant_bonus)
                         ),
-                        UiStyle::OK,
+                        UiStyle::current().ok,
                     ),
                     Span::raw("/"),
                     Span::styled(
@@ -316,13 +697,44 @@ impl MyTeamPanel {
                             "{}",
                             planet.resource_sell_price(Resource::RUM, merchant_bonus)
                         ),
-                        UiStyle::ERROR,
+                        UiStyle::current().error,
                     ),
                 ]),
             ]),
             split[1],
         );
 
+        let mut trend_lines = vec![Line::from(Span::styled(
+            "Price trend",
+            UiStyle::current().header,
+        ))];
+        for resource in MARKET_RESOURCES.iter() {
+            let history = planet.market.price_history(*resource);
+            let history = &history[history.len().saturating_sub(MARKET_TREND_LENGTH)..];
+            trend_lines.push(Line::from(vec![
+                Span::styled(format!("{:<10}", resource.to_string()), resource.style()),
+                Span::raw(sparkline(history)),
+            ]));
+        }
+        if let Some(event) = planet.market.event {
+            trend_lines.push(Line::from(""));
+            trend_lines.push(Line::from(Span::styled(
+                format!(
+                    "{} for {}",
+                    event,
+                    event
+                        .until
+                        .saturating_sub(world.last_tick_short_interval)
+                        .formatted()
+                ),
+                UiStyle::current().warning,
+            )));
+        }
+        // Leave room for the buy/sell prices above.
+        let trend_split =
+            Layout::vertical([Constraint::Length(7), Constraint::Min(0)]).split(split[1]);
+        frame.render_widget(Paragraph::new(trend_lines), trend_split[1]);
+
         Ok(())
     }
 
@@ -393,7 +805,7 @@ impl MyTeamPanel {
         frame.render_widget(
             Paragraph::new(Span::styled(
                 format!("       Key                                       Buy/Sell"),
-                UiStyle::HEADER,
+                UiStyle::current().header,
             )),
             button_split[0],
         );
@@ -432,20 +844,26 @@ impl MyTeamPanel {
             ])
             .split(button_split[button_split_idx + 1]);
 
-            let merchant_bonus = TeamBonus::TradePrice.current_team_bonus(world, team.id)?;
-            let buy_unit_cost = planet.resource_buy_price(*resource, merchant_bonus);
-            let sell_unit_cost = planet.resource_sell_price(*resource, merchant_bonus);
+            let merchant_bonus = TeamBonus::TradePrice.current_team_bonus(world, team.id)?
+                * team.merchant_modifier(planet.id);
+            let mut buy_unit_cost = planet.resource_buy_price(*resource, merchant_bonus);
+            let mut sell_unit_cost = planet.resource_sell_price(*resource, merchant_bonus);
+            if *resource == Resource::FUEL {
+                let modifier = team.spaceship.fuel_grade.price_modifier();
+                buy_unit_cost = ((buy_unit_cost as f32 * modifier) as u32).max(1);
+                sell_unit_cost = (sell_unit_cost as f32 * modifier) as u32;
+            }
             frame.render_widget(
                 Paragraph::new(Line::from(vec![
                     Span::styled(format!("{:<6} ", resource.to_string()), resource.style()),
                     Span::styled(
                         format!("{}", buy_ui_keys[button_split_idx].to_string()),
-                        UiStyle::OK,
+                        UiStyle::current().ok,
                     ),
                     Span::raw(format!("/")),
                     Span::styled(
                         format!("{}", sell_ui_keys[button_split_idx].to_string()),
-                        UiStyle::ERROR,
+                        UiStyle::current().error,
                     ),
                 ])),
                 resource_split[0].inner(Margin {
@@ -455,9 +873,9 @@ impl MyTeamPanel {
             );
             frame.render_widget(
                 Paragraph::new(Line::from(vec![
-                    Span::styled(format!("{}", buy_unit_cost), UiStyle::OK),
+                    Span::styled(format!("{}", buy_unit_cost), UiStyle::current().ok),
                     Span::raw(format!("/")),
-                    Span::styled(format!("{}", sell_unit_cost), UiStyle::ERROR),
+                    Span::styled(format!("{}", sell_unit_cost), UiStyle::current().error),
                 ])),
                 resource_split[7].inner(Margin {
                     horizontal: 1,
@@ -479,7 +897,7 @@ impl MyTeamPanel {
                     } else {
                         None
                     },
-                    UiStyle::OK,
+                    UiStyle::current().ok,
                 ) {
                     frame.render_widget(btn, resource_split[idx + 1]);
                 }
@@ -499,7 +917,7 @@ impl MyTeamPanel {
                     } else {
                         None
                     },
-                    UiStyle::ERROR,
+                    UiStyle::current().error,
                 ) {
                     frame.render_widget(btn, resource_split[idx + 4]);
                 }
@@ -554,12 +972,15 @@ impl MyTeamPanel {
                 )),
                 Span::styled(
                     format!(
-                        "Network: W{}/L{}/D{} ",
+                        "Network: W{}/L{}/D{} {}",
                         team.network_game_record[0],
                         team.network_game_record[1],
-                        team.network_game_record[2]
+                        team.network_game_record[2],
+                        team.elo
+                            .map(|elo| format!("({}) ", elo))
+                            .unwrap_or_default()
                     ),
-                    UiStyle::NETWORK,
+                    UiStyle::current().network,
                 ),
             ]),
             Line::from(format!("Treasury: {:<10}", format_satoshi(team.balance()),)),
@@ -585,7 +1006,10 @@ impl MyTeamPanel {
                     Resource::GOLD.style(),
                 ),
                 Span::raw(format!("{:>3} Kg  ", team.resources.value(&Resource::GOLD))),
-                Span::styled(format!("{:>10}:", "Kartoffeln"), UiStyle::STORAGE_KARTOFFEL),
+                Span::styled(
+                    format!("{:>10}:", "Kartoffeln"),
+                    UiStyle::current().storage_kartoffel,
+                ),
                 Span::raw(format!("{:>3}", team.kartoffel_ids.len())),
             ]),
             Line::from(vec![
@@ -688,12 +1112,28 @@ impl MyTeamPanel {
             frame.render_widget(home_planet_button, btm_button_split[1]);
         }
 
+        let spaceship_area = match world.tutorial.as_ref() {
+            Some(tutorial) if !tutorial.is_completed() => {
+                let height = if self.tutorial_checklist_expanded {
+                    TutorialQuest::iter().count() as u16 + 5
+                } else {
+                    3
+                };
+                let tutorial_split =
+                    Layout::vertical([Constraint::Length(height), Constraint::Min(0)])
+                        .split(split[1]);
+                self.render_tutorial_checklist(frame, tutorial, tutorial_split[0]);
+                tutorial_split[1]
+            }
+            _ => split[1],
+        };
+
         match team.current_location {
             TeamLocation::OnPlanet { planet_id } => {
                 if let Some(upgrade) = &team.spaceship.pending_upgrade {
-                    self.render_upgrading_spaceship(frame, world, split[1], upgrade)?
+                    self.render_upgrading_spaceship(frame, world, spaceship_area, upgrade)?
                 } else {
-                    self.render_on_planet_spaceship(frame, world, split[1], planet_id)?
+                    self.render_on_planet_spaceship(frame, world, spaceship_area, planet_id)?
                 }
             }
             TeamLocation::Travelling {
@@ -707,7 +1147,7 @@ impl MyTeamPanel {
                 } else {
                     (0 as Tick).formatted()
                 };
-                self.render_travelling_spaceship(frame, world, split[1], to, countdown)?
+                self.render_travelling_spaceship(frame, world, spaceship_area, to, countdown)?
             }
             TeamLocation::Exploring {
                 around,
@@ -720,7 +1160,7 @@ impl MyTeamPanel {
                 } else {
                     (0 as Tick).formatted()
                 };
-                self.render_exploring_spaceship(frame, world, split[1], around, countdown)?
+                self.render_exploring_spaceship(frame, world, spaceship_area, around, countdown)?
             }
             TeamLocation::OnSpaceAdventure { .. } => {
                 return Err(anyhow!("Team is on a space adventure"))
@@ -729,6 +1169,84 @@ impl MyTeamPanel {
         Ok(())
     }
 
+    fn render_tutorial_checklist(&self, frame: &mut Frame, tutorial: &Tutorial, area: Rect) {
+        let hover_text_target = hover_text_target(frame);
+        let completed = tutorial.completed_quests.len();
+        let total = TutorialQuest::iter().count();
+        let next_quest = TutorialQuest::iter().find(|quest| !tutorial.is_quest_completed(*quest));
+        let hint = next_quest
+            .map(|quest| quest.hint().to_string())
+            .unwrap_or_default();
+
+        if !self.tutorial_checklist_expanded {
+            let text = match next_quest {
+                Some(quest) => format!("Tutorial {}/{}: {} ▼", completed, total, quest),
+                None => format!("Tutorial {}/{} ▼", completed, total),
+            };
+            let button = Button::new(
+                text.into(),
+                UiCallback::ToggleTutorialChecklist,
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(hint, hover_text_target)
+            .set_box_style(UiStyle::current().network);
+            frame.render_widget(button, area);
+            return;
+        }
+
+        frame.render_widget(
+            default_block()
+                .border_style(UiStyle::current().network)
+                .title(format!("Tutorial {}/{} ", completed, total)),
+            area,
+        );
+        let split = Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(
+            area.inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+        );
+
+        let lines = TutorialQuest::iter()
+            .map(|quest| {
+                if tutorial.is_quest_completed(quest) {
+                    Line::from(Span::styled(
+                        format!("[x] {}", quest),
+                        UiStyle::current().ok,
+                    ))
+                } else if Some(quest) == next_quest {
+                    Line::from(vec![
+                        Span::raw(format!("[ ] {} ", quest)),
+                        Span::styled(
+                            format!("({})", quest.rewards_description()),
+                            UiStyle::current().highlight,
+                        ),
+                    ])
+                } else {
+                    Line::from(Span::styled(
+                        format!("[ ] {}", quest),
+                        UiStyle::current().unselectable,
+                    ))
+                }
+            })
+            .collect_vec();
+        frame.render_widget(
+            Paragraph::new(lines),
+            split[0].inner(Margin {
+                horizontal: 1,
+                vertical: 0,
+            }),
+        );
+
+        let button = Button::new(
+            "Hide checklist ▲".into(),
+            UiCallback::ToggleTutorialChecklist,
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(hint, hover_text_target);
+        frame.render_widget(button, split[1]);
+    }
+
     fn render_games(&mut self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
         let split = Layout::horizontal([Constraint::Length(48), Constraint::Min(48)]).split(area);
         self.render_challenge_teams(frame, world, split[0])?;
@@ -776,7 +1294,7 @@ impl MyTeamPanel {
                 )
                 .set_hover_text("Go to current game".into(), hover_text_target)
                 .set_hotkey(UiKey::GO_TO_GAME)
-                .set_box_style(UiStyle::OWN_TEAM),
+                .set_box_style(UiStyle::current().own_team),
                 split[0],
             );
             return Ok(());
@@ -858,7 +1376,7 @@ impl MyTeamPanel {
                         action.away_score,
                         game.away_team_in_game.name,
                     );
-                    let style = UiStyle::OWN_TEAM;
+                    let style = UiStyle::current().own_team;
                     options.push((text, style));
                 }
             }
@@ -866,15 +1384,13 @@ impl MyTeamPanel {
 
         for game_id in self.recent_games.iter() {
             if let Some(game) = world.past_games.get(game_id) {
+                let (home_score, away_score) = game.score();
                 let text = format!(
                     " {:>12} {:>3}-{:<3} {:<}",
-                    game.home_team_name,
-                    game.home_quarters_score.iter().sum::<u16>(),
-                    game.away_quarters_score.iter().sum::<u16>(),
-                    game.away_team_name,
+                    game.home_team_name, home_score, away_score, game.away_team_name,
                 );
 
-                let style = UiStyle::DEFAULT;
+                let style = UiStyle::current().default;
                 options.push((text, style));
             }
         }
@@ -907,6 +1423,59 @@ impl MyTeamPanel {
                 .get(&game_id)
                 .ok_or(anyhow!("Unable to get past game."))?;
 
+            if self.play_by_play_view {
+                let lines = play_by_play_lines(
+                    &self
+                        .loaded_games
+                        .get(&game_id)
+                        .expect("Failed to load game")
+                        .action_results,
+                );
+                self.play_by_play_offset = self
+                    .play_by_play_offset
+                    .min(lines.len().saturating_sub(1) as u16);
+
+                self.callback_registry
+                    .lock()
+                    .unwrap()
+                    .register_mouse_callback(
+                        crossterm::event::MouseEventKind::ScrollDown,
+                        Some(split[1]),
+                        UiCallback::ScrollPlayByPlay { lines: 1 },
+                    );
+                self.callback_registry
+                    .lock()
+                    .unwrap()
+                    .register_mouse_callback(
+                        crossterm::event::MouseEventKind::ScrollUp,
+                        Some(split[1]),
+                        UiCallback::ScrollPlayByPlay { lines: -1 },
+                    );
+
+                let play_by_play_split =
+                    Layout::vertical([Constraint::Length(2), Constraint::Min(0)]).split(
+                        split[1].inner(Margin {
+                            horizontal: 1,
+                            vertical: 1,
+                        }),
+                    );
+                frame.render_widget(
+                    Paragraph::new(Span::styled(
+                        format!(
+                            "Play-by-play {} - {} ",
+                            game.home_team_name, game.away_team_name
+                        ),
+                        UiStyle::current().header,
+                    )),
+                    play_by_play_split[0],
+                );
+                frame.render_widget(
+                    Paragraph::new(lines).scroll((self.play_by_play_offset, 0)),
+                    play_by_play_split[1],
+                );
+                return Ok(());
+            }
+
             let loaded_game = self
                 .loaded_games
                 .get(&game_id)
@@ -921,6 +1490,12 @@ impl MyTeamPanel {
                 .as_ref()
                 .expect("Loaded game should have set mvps.");
 
+            let (home_score, away_score) = game.score();
+            let (overtime_header, home_overtime, away_overtime) = match game.overtime_score {
+                Some((home, away)) => ("OT ", format!("{:02} ", home), format!("{:02} ", away)),
+                None => ("", String::new(), String::new()),
+            };
+
             let lines = vec![
                 Line::from(format!(
                     "Location {} - Attendance {}",
@@ -936,19 +1511,20 @@ impl MyTeamPanel {
                 Line::from(""),
                 Line::from(Span::styled(
                     format!(
-                        "{:12} {} {} {} {} {}",
-                        "Team", "Q1", "Q2", "Q3", "Q4", "Total"
+                        "{:12} {} {} {} {} {}{}",
+                        "Team", "Q1", "Q2", "Q3", "Q4", overtime_header, "Total"
                     ),
-                    UiStyle::HEADER,
+                    UiStyle::current().header,
                 )),
                 Line::from(format!(
-                    "{:12} {:02} {:02} {:02} {:02} {:<3} {}",
+                    "{:12} {:02} {:02} {:02} {:02} {}{:<3} {}",
                     game.home_team_name,
                     game.home_quarters_score[0],
                     game.home_quarters_score[1],
                     game.home_quarters_score[2],
                     game.home_quarters_score[3],
-                    game.home_quarters_score.iter().sum::<u16>(),
+                    home_overtime,
+                    home_score,
                     if game.home_team_knocked_out {
                         "knocked out"
                     } else {
@@ -956,13 +1532,14 @@ impl MyTeamPanel {
                     }
                 )),
                 Line::from(format!(
-                    "{:12} {:02} {:02} {:02} {:02} {:<3} {}",
+                    "{:12} {:02} {:02} {:02} {:02} {}{:<3} {}",
                     game.away_team_name,
                     game.away_quarters_score[0],
                     game.away_quarters_score[1],
                     game.away_quarters_score[2],
                     game.away_quarters_score[3],
-                    game.away_quarters_score.iter().sum::<u16>(),
+                    away_overtime,
+                    away_score,
                     if game.away_team_knocked_out {
                         "knocked out"
                     } else {
@@ -970,7 +1547,10 @@ impl MyTeamPanel {
                     }
                 )),
                 Line::from(format!("")),
-                Line::from(Span::styled(game.home_team_name.clone(), UiStyle::HEADER)),
+                Line::from(Span::styled(
+                    game.home_team_name.clone(),
+                    UiStyle::current().header,
+                )),
                 Line::from(format!(
                     "{:<18}{:<8}{:<8}{:<8}",
                     home_mvps[0].name,
@@ -1020,7 +1600,10 @@ impl MyTeamPanel {
                     )
                 )),
                 Line::from(""),
-                Line::from(Span::styled(game.away_team_name.clone(), UiStyle::HEADER)),
+                Line::from(Span::styled(
+                    game.away_team_name.clone(),
+                    UiStyle::current().header,
+                )),
                 Line::from(format!(
                     "{:<18}{:<8}{:<8}{:<8}",
                     away_mvps[0].name,
@@ -1087,16 +1670,23 @@ impl MyTeamPanel {
 
     fn render_shipyard(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
         let split = Layout::horizontal([Constraint::Length(48), Constraint::Min(48)]).split(area);
+        if self.space_upgrades_view {
+            self.render_space_upgrades(frame, world, split[0])?;
+            return self.render_space_upgrade_tree(frame, world, split[1]);
+        }
         self.render_shipyard_upgrades(frame, world, split[0])?;
 
         let team = world.get_own_team()?;
         match team.current_location {
             TeamLocation::OnPlanet { planet_id } => {
+                let ship_split =
+                    Layout::vertical([Constraint::Min(0), Constraint::Length(6)]).split(split[1]);
                 if let Some(upgrade) = &team.spaceship.pending_upgrade {
-                    self.render_upgrading_spaceship(frame, world, split[1], upgrade)?
+                    self.render_upgrading_spaceship(frame, world, ship_split[0], upgrade)?
                 } else {
-                    self.render_in_shipyard_spaceship(frame, world, split[1], planet_id)?
+                    self.render_in_shipyard_spaceship(frame, world, ship_split[0], planet_id)?
                 }
+                self.render_fleet(frame, team, ship_split[1], planet_id)?
             }
             TeamLocation::Travelling {
                 to,
@@ -1132,16 +1722,114 @@ impl MyTeamPanel {
         Ok(())
     }
 
-    fn render_shipyard_upgrades(
+    fn render_fleet(
         &self,
         frame: &mut Frame,
-        world: &World,
+        team: &Team,
         area: Rect,
+        planet_id: PlanetId,
     ) -> AppResult<()> {
-        let split = Layout::horizontal([Constraint::Length(12), Constraint::Length(30)]).split(
-            area.inner(Margin {
-                horizontal: 1,
-                vertical: 1,
+        let hover_text_target = hover_text_target(frame);
+        frame.render_widget(
+            default_block().title(format!("Fleet {}/{} ", team.fleet_size(), MAX_FLEET_SIZE)),
+            area,
+        );
+        let split = Layout::vertical([Constraint::Length(1), Constraint::Length(3)]).split(
+            area.inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+        );
+
+        let docked = team.docked_spaceships_at(planet_id);
+        let docked_text = if docked.is_empty() {
+            "none".to_string()
+        } else {
+            docked
+                .iter()
+                .map(|spaceship| spaceship.name.as_str())
+                .join(", ")
+        };
+        frame.render_widget(
+            Paragraph::new(format!(
+                " Flying {} - Docked here: {}",
+                team.spaceship.name, docked_text
+            )),
+            split[0],
+        );
+
+        let button_split = Layout::horizontal([
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+        ])
+        .split(split[1]);
+
+        let prefab_count = SpaceshipPrefab::iter().count();
+        let prefab = SpaceshipPrefab::iter()
+            .nth(self.spaceship_prefab_index % prefab_count)
+            .ok_or(anyhow!("Invalid spaceship prefab index"))?;
+        let prefab_button = Button::new(
+            format!("Model: {}", prefab).into(),
+            UiCallback::SetMyTeamPanelSpaceshipPrefab {
+                index: (self.spaceship_prefab_index + 1) % prefab_count,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            "Cycle the spaceship model to buy.".into(),
+            hover_text_target,
+        )
+        .set_hotkey(UiKey::CYCLE_SPACESHIP_PREFAB);
+        frame.render_widget(prefab_button, button_split[0]);
+
+        let mut buy_button = Button::new(
+            format!("Buy {} ({})", prefab, format_satoshi(prefab.cost())).into(),
+            UiCallback::BuySpaceship { prefab },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            "Buy a new spaceship. It will be docked at this asteroid.".into(),
+            hover_text_target,
+        )
+        .set_hotkey(UiKey::BUY_SPACESHIP);
+        if let Err(err) = team.can_buy_spaceship(prefab) {
+            buy_button.disable(Some(err.to_string()));
+        }
+        frame.render_widget(buy_button, button_split[1]);
+
+        let mut switch_button = Button::new(
+            match docked.first() {
+                Some(spaceship) => format!("Fly {}", spaceship.name),
+                None => "Switch spaceship".to_string(),
+            }
+            .into(),
+            UiCallback::SwitchSpaceship,
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            "Fly a docked spaceship, leaving the current one docked at this asteroid.".into(),
+            hover_text_target,
+        )
+        .set_hotkey(UiKey::SWITCH_SPACESHIP);
+        if let Err(err) = team.can_switch_spaceship() {
+            switch_button.disable(Some(err.to_string()));
+        }
+        frame.render_widget(switch_button, button_split[2]);
+
+        Ok(())
+    }
+
+    fn render_shipyard_upgrades(
+        &self,
+        frame: &mut Frame,
+        world: &World,
+        area: Rect,
+    ) -> AppResult<()> {
+        let split = Layout::horizontal([Constraint::Length(12), Constraint::Length(30)]).split(
+            area.inner(Margin {
+                horizontal: 1,
+                vertical: 1,
             }),
         );
         let team = world.get_own_team()?;
@@ -1157,7 +1845,13 @@ impl MyTeamPanel {
             2 => SpaceshipUpgradeTarget::Storage {
                 component: team.spaceship.storage.next(),
             },
-            3 => SpaceshipUpgradeTarget::Repairs {
+            3 => SpaceshipUpgradeTarget::LifeSupport {
+                component: team.spaceship.life_support.next(),
+            },
+            4 => SpaceshipUpgradeTarget::Scanner {
+                component: team.spaceship.scanner.next(),
+            },
+            5 => SpaceshipUpgradeTarget::Repairs {
                 amount: team.spaceship.durability() - team.spaceship.current_durability(),
             },
             _ => unreachable!(),
@@ -1167,7 +1861,9 @@ impl MyTeamPanel {
             0 => team.spaceship.hull.can_be_upgraded(),
             1 => team.spaceship.engine.can_be_upgraded(),
             2 => team.spaceship.storage.can_be_upgraded(),
-            3 => team.spaceship.can_be_repaired(),
+            3 => team.spaceship.life_support.can_be_upgraded(),
+            4 => team.spaceship.scanner.can_be_upgraded(),
+            5 => team.spaceship.can_be_repaired(),
             _ => unreachable!(),
         };
 
@@ -1176,9 +1872,9 @@ impl MyTeamPanel {
                 (
                     upgrade_target.to_string(),
                     if team.spaceship.can_be_upgraded(upgrade_target) {
-                        UiStyle::DEFAULT
+                        UiStyle::current().default
                     } else {
-                        UiStyle::UNSELECTABLE
+                        UiStyle::current().unselectable
                     },
                 )
             })
@@ -1199,7 +1895,9 @@ impl MyTeamPanel {
             0 => team.spaceship.hull.to_string(),
             1 => team.spaceship.engine.to_string(),
             2 => team.spaceship.storage.to_string(),
-            3 => {
+            3 => team.spaceship.life_support.to_string(),
+            4 => team.spaceship.scanner.to_string(),
+            5 => {
                 format!("Repairs {}", team.spaceship.current_durability())
             }
             _ => unreachable!(),
@@ -1209,7 +1907,9 @@ impl MyTeamPanel {
             0 => team.spaceship.hull.next().to_string(),
             1 => team.spaceship.engine.next().to_string(),
             2 => team.spaceship.storage.next().to_string(),
-            3 => team.spaceship.durability().to_string(),
+            3 => team.spaceship.life_support.next().to_string(),
+            4 => team.spaceship.scanner.next().to_string(),
+            5 => team.spaceship.durability().to_string(),
             _ => unreachable!(),
         };
 
@@ -1224,7 +1924,7 @@ impl MyTeamPanel {
                 if can_be_upgraded {
                     format!("{} -> {}", current, next)
                 } else {
-                    if self.spaceship_upgrade_index == 3 {
+                    if self.spaceship_upgrade_index == 5 {
                         "Fully repaired".to_string()
                     } else {
                         "Fully upgraded".to_string()
@@ -1237,25 +1937,40 @@ impl MyTeamPanel {
             0 => "Upgrade Hull",
             1 => "Upgrade Engine",
             2 => "Upgrade Storage",
-            3 => "Repair",
+            3 => "Upgrade Life Support",
+            4 => "Upgrade Scanner",
+            5 => "Repair",
             _ => unreachable!(),
         };
 
         let mut lines = vec![
             Line::from(""),
-            Line::from(Span::styled(header_text, UiStyle::HEADER)).centered(),
+            Line::from(Span::styled(header_text, UiStyle::current().header)).centered(),
             Line::from(Span::raw(upgrade_to_text)).centered(),
             Line::from(""),
         ];
 
+        let description = match self.spaceship_upgrade_index {
+            3 => Some(team.spaceship.life_support.next().description()),
+            4 => Some(team.spaceship.scanner.next().description()),
+            _ => None,
+        };
+        if let Some(description) = description.filter(|_| can_be_upgraded) {
+            lines.push(
+                Line::from(Span::styled(description, UiStyle::current().unselectable)).centered(),
+            );
+            lines.push(Line::from(""));
+        }
+
         if can_be_upgraded && !is_being_upgraded {
-            let upgrade = SpaceshipUpgrade::new(target);
+            let upgrade = SpaceshipUpgrade::new(target, world.now())
+                .with_repair_bonus(TeamBonus::Repairs.current_team_bonus(world, team.id)?);
             for (resource, amount) in upgrade.cost().iter() {
                 let have = team.resources.value(resource);
                 let style = if amount.clone() > have {
-                    UiStyle::ERROR
+                    UiStyle::current().error
                 } else {
-                    UiStyle::OK
+                    UiStyle::current().ok
                 };
 
                 lines.push(Line::from(vec![
@@ -1270,53 +1985,219 @@ impl MyTeamPanel {
         Ok(())
     }
 
-    fn render_asteroids(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
-        let split = Layout::horizontal([Constraint::Length(48), Constraint::Min(48)]).split(area);
-        self.render_asteroid_list(frame, world, split[0])?;
-        self.render_selected_asteroid(frame, world, split[1])?;
+    fn render_space_upgrades(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        let split = Layout::horizontal([Constraint::Length(16), Constraint::Length(30)]).split(
+            area.inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+        );
+        let team = world.get_own_team()?;
+        let progression = &team.space_adventure_progression;
+        frame.render_widget(default_block().title("Space adventure upgrades "), area);
+
+        let options = SpaceAdventureUpgrade::iter()
+            .map(|upgrade| {
+                (
+                    upgrade.to_string(),
+                    if progression.can_unlock(upgrade).is_ok() {
+                        UiStyle::current().default
+                    } else {
+                        UiStyle::current().unselectable
+                    },
+                )
+            })
+            .collect_vec();
+
+        let list = selectable_list(options, &self.callback_registry);
+
+        frame.render_stateful_widget(
+            list,
+            split[0].inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+            &mut ClickableListState::default().with_selected(Some(self.space_upgrade_index)),
+        );
+
+        let upgrade = SpaceAdventureUpgrade::iter()
+            .nth(self.space_upgrade_index)
+            .ok_or(anyhow!("Invalid space upgrade index"))?;
+        let level = progression.level(upgrade);
+
+        let upgrade_to_text = match progression.can_unlock(upgrade) {
+            Ok(_) => format!("Level {} -> {}", level, level + 1),
+            Err(e) => e.to_string(),
+        };
+
+        let mut lines = vec![
+            Line::from(""),
+            Line::from(Span::styled(upgrade.to_string(), UiStyle::current().header)).centered(),
+            Line::from(Span::raw(upgrade_to_text)).centered(),
+            Line::from(""),
+            Line::from(Span::styled(
+                upgrade.description(),
+                UiStyle::current().unselectable,
+            ))
+            .centered(),
+            Line::from(""),
+        ];
+
+        if !progression.is_maxed(upgrade) {
+            for (resource, amount) in progression.next_cost(upgrade).iter() {
+                let have = team.resources.value(resource);
+                let style = if amount.clone() > have {
+                    UiStyle::current().error
+                } else {
+                    UiStyle::current().ok
+                };
+
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {:<7} ", resource.to_string()), resource.style()),
+                    Span::styled(format!("{}/{}", have, amount), style),
+                ]));
+            }
+        }
+
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), split[1]);
+
         Ok(())
     }
 
-    fn render_asteroid_list(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
-        frame.render_widget(default_block().title("Asteroids "), area);
+    fn render_space_upgrade_tree(
+        &self,
+        frame: &mut Frame,
+        world: &World,
+        area: Rect,
+    ) -> AppResult<()> {
         let team = world.get_own_team()?;
-
-        if self.asteroid_ids.len() == 0 {
-            frame.render_widget(
-                Paragraph::new("No asteroid has been found yet, keep exploring!")
-                    .wrap(Wrap { trim: true }),
-                area.inner(Margin {
-                    horizontal: 2,
-                    vertical: 2,
+        let progression = &team.space_adventure_progression;
+        frame.render_widget(default_block().title("Upgrade tree "), area);
+
+        let selected = SpaceAdventureUpgrade::iter()
+            .nth(self.space_upgrade_index)
+            .ok_or(anyhow!("Invalid space upgrade index"))?;
+
+        // Root upgrades first, each followed by the branches depending on it.
+        let mut lines = vec![Line::from("")];
+        for root in SpaceAdventureUpgrade::iter().filter(|u| u.requires().is_none()) {
+            let branches = SpaceAdventureUpgrade::iter()
+                .filter(|u| u.requires() == Some(root))
+                .collect_vec();
+            let nodes = std::iter::once(("".to_string(), root)).chain(
+                branches.iter().enumerate().map(|(idx, &branch)| {
+                    let prefix = if idx == branches.len() - 1 {
+                        "└─ "
+                    } else {
+                        "├─ "
+                    };
+                    (prefix.to_string(), branch)
                 }),
             );
-            return Ok(());
+
+            for (prefix, upgrade) in nodes {
+                let level = progression.level(upgrade);
+                let style = if upgrade == selected {
+                    UiStyle::current().selected
+                } else if level > 0 {
+                    UiStyle::current().ok
+                } else if progression.can_unlock(upgrade).is_ok() {
+                    UiStyle::current().default
+                } else {
+                    UiStyle::current().unselectable
+                };
+                let bars = if is_accessible_mode() {
+                    format!("{}/{}", level, SpaceAdventureUpgrade::MAX_LEVEL)
+                } else {
+                    format!(
+                        "{}{}",
+                        "▰".repeat(level as usize),
+                        "▱".repeat((SpaceAdventureUpgrade::MAX_LEVEL - level) as usize)
+                    )
+                };
+                lines.push(Line::from(vec![
+                    Span::raw(format!("  {}", prefix)),
+                    Span::styled(format!("{:<14}", upgrade.to_string()), style),
+                    Span::raw(bars),
+                ]));
+            }
+            lines.push(Line::from(""));
         }
 
-        let split = Layout::horizontal([Constraint::Length(12), Constraint::Length(30)]).split(
+        lines.push(Line::from(format!(
+            "  Charge x{:.2}  Recoil x{:.2}  Thrust x{:.2}",
+            progression.charge_multiplier(),
+            progression.recoil_multiplier(),
+            progression.thrust_multiplier()
+        )));
+        lines.push(Line::from(format!(
+            "  Damage reduction {:.0}%  Tractor x{:.2}",
+            progression.damage_reduction() * 100.0,
+            progression.tractor_multiplier()
+        )));
+
+        let split = Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(
+            area.inner(Margin {
+                vertical: 1,
+                horizontal: 1,
+            }),
+        );
+        frame.render_widget(Paragraph::new(lines), split[0]);
+
+        let mut unlock_button = Button::new(
+            format!("Upgrade {}", selected).into(),
+            UiCallback::UnlockSpaceAdventureUpgrade { upgrade: selected },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            "Improve the spaceship for the next space adventures.".to_string(),
+            hover_text_target(&frame),
+        )
+        .set_hotkey(UiKey::UPGRADE_SPACESHIP);
+
+        if let Err(e) = team.can_unlock_space_adventure_upgrade(selected) {
+            unlock_button.disable(Some(e.to_string()));
+        }
+        frame.render_widget(unlock_button, split[1]);
+
+        Ok(())
+    }
+
+    fn render_modules(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        let split = Layout::horizontal([Constraint::Length(48), Constraint::Min(48)]).split(area);
+        self.render_module_list(frame, world, split[0])?;
+        self.render_selected_module(frame, world, split[1])?;
+        Ok(())
+    }
+
+    fn render_module_list(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        let team = world.get_own_team()?;
+        frame.render_widget(
+            default_block().title(format!(
+                "Modules {}/{} ",
+                team.spaceship.modules.len(),
+                team.spaceship.module_slots()
+            )),
+            area,
+        );
+
+        let split = Layout::horizontal([Constraint::Length(20), Constraint::Length(26)]).split(
             area.inner(Margin {
                 horizontal: 1,
                 vertical: 1,
             }),
         );
 
-        let options = self
-            .asteroid_ids
-            .iter()
-            .filter(|&&asteroid_id| world.get_planet_or_err(asteroid_id).is_ok())
-            .map(|&asteroid_id| {
-                let asteroid = world.get_planet_or_err(asteroid_id).unwrap();
-                let style = match team.current_location {
-                    TeamLocation::OnPlanet { planet_id } => {
-                        if planet_id == asteroid_id {
-                            UiStyle::OWN_TEAM
-                        } else {
-                            UiStyle::DEFAULT
-                        }
-                    }
-                    _ => UiStyle::DEFAULT,
-                };
-                (asteroid.name.clone(), style)
+        let options = SpaceshipModule::iter()
+            .map(|module| {
+                (
+                    module.to_string(),
+                    if team.spaceship.has_module(module) {
+                        UiStyle::current().own_team
+                    } else {
+                        UiStyle::current().default
+                    },
+                )
             })
             .collect_vec();
 
@@ -1328,70 +2209,2037 @@ impl MyTeamPanel {
                 horizontal: 1,
                 vertical: 1,
             }),
-            &mut ClickableListState::default().with_selected(self.asteroid_index),
+            &mut ClickableListState::default().with_selected(Some(self.spaceship_module_index)),
+        );
+
+        let module = SpaceshipModule::iter()
+            .nth(self.spaceship_module_index)
+            .ok_or(anyhow!("Invalid module index"))?;
+
+        let mut lines = vec![
+            Line::from(""),
+            Line::from(Span::styled(module.to_string(), UiStyle::current().header)).centered(),
+            Line::from(if team.spaceship.has_module(module) {
+                "Installed"
+            } else {
+                "Not installed"
+            })
+            .centered(),
+            Line::from(""),
+        ];
+
+        if !team.spaceship.has_module(module) {
+            for (resource, amount) in module.cost().iter() {
+                let have = team.resources.value(resource);
+                let style = if *amount > have {
+                    UiStyle::current().error
+                } else {
+                    UiStyle::current().ok
+                };
+
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {:<7} ", resource.to_string()), resource.style()),
+                    Span::styled(format!("{}/{}", have, amount), style),
+                ]));
+            }
+        }
+
+        frame.render_widget(Paragraph::new(lines), split[1]);
+
+        Ok(())
+    }
+
+    fn render_selected_module(
+        &self,
+        frame: &mut Frame,
+        world: &World,
+        area: Rect,
+    ) -> AppResult<()> {
+        let team = world.get_own_team()?;
+        let hover_text_target = hover_text_target(frame);
+        let module = SpaceshipModule::iter()
+            .nth(self.spaceship_module_index)
+            .ok_or(anyhow!("Invalid module index"))?;
+
+        frame.render_widget(default_block().title(format!("{} ", module)), area);
+
+        let split = Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(
+            area.inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+        );
+
+        let mut lines = vec![
+            Line::from(""),
+            Line::from(module.description()),
+            Line::from(""),
+        ];
+        if module.damage_reduction() > 0.0 {
+            lines.push(Line::from(format!(
+                "Damage reduction: {}%",
+                (100.0 * module.damage_reduction()).round()
+            )));
+        }
+        if module.mining_multiplier() != 1.0 {
+            lines.push(Line::from(format!(
+                "Mining power: x{:.1}",
+                module.mining_multiplier()
+            )));
+        }
+        if module.charge_multiplier() != 1.0 {
+            lines.push(Line::from(format!(
+                "Shooter charge: x{:.1}",
+                module.charge_multiplier()
+            )));
+        }
+        if module.tractor_multiplier() != 1.0 {
+            lines.push(Line::from(format!(
+                "Tractor strength: x{:.1}",
+                module.tractor_multiplier()
+            )));
+        }
+
+        frame.render_widget(
+            Paragraph::new(lines).wrap(Wrap { trim: true }),
+            split[0].inner(Margin {
+                horizontal: 1,
+                vertical: 0,
+            }),
         );
 
+        let button = if team.spaceship.has_module(module) {
+            let mut button = Button::new(
+                format!("Uninstall {}", module).into(),
+                UiCallback::UninstallSpaceshipModule { module },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(
+                "Remove the module from the spaceship. It will be scrapped.".into(),
+                hover_text_target,
+            )
+            .set_hotkey(UiKey::UNINSTALL_MODULE);
+            if let Err(err) = team.can_uninstall_spaceship_module(module) {
+                button.disable(Some(err.to_string()));
+            }
+            button
+        } else {
+            let mut button = Button::new(
+                format!("Install {}", module).into(),
+                UiCallback::InstallSpaceshipModule { module },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(
+                "Install the module on the spaceship.".into(),
+                hover_text_target,
+            )
+            .set_hotkey(UiKey::INSTALL_MODULE);
+            if let Err(err) = team.can_install_spaceship_module(module) {
+                button.disable(Some(err.to_string()));
+            }
+            button
+        };
+
+        frame.render_widget(button, split[1]);
+
+        Ok(())
+    }
+
+    fn render_orders(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        let split = Layout::horizontal([Constraint::Length(48), Constraint::Min(48)]).split(area);
+        self.render_action_queue(frame, world, split[0])?;
+        self.render_order_buttons(frame, world, split[1])?;
+        Ok(())
+    }
+
+    fn render_contracts(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        let split = Layout::horizontal([Constraint::Length(48), Constraint::Min(48)]).split(area);
+        self.render_contract_list(frame, world, split[0])?;
+        self.render_selected_contract(frame, world, split[1])?;
+        Ok(())
+    }
+
+    fn render_contract_list(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        frame.render_widget(
+            default_block().title(format!(
+                "Contracts {}/{} ",
+                world.accepted_contracts().len(),
+                MAX_ACCEPTED_CONTRACTS
+            )),
+            area,
+        );
+
+        if self.contracts.len() == 0 {
+            frame.render_widget(
+                Paragraph::new("No contract is offered at the moment, come back later.")
+                    .wrap(Wrap { trim: true }),
+                area.inner(Margin {
+                    horizontal: 2,
+                    vertical: 2,
+                }),
+            );
+            return Ok(());
+        }
+
+        let mut options = vec![];
+        for contract_id in self.contracts.iter() {
+            let contract = match world.contracts.iter().find(|c| c.id == *contract_id) {
+                Some(contract) => contract,
+                None => continue,
+            };
+            let destination = world.get_planet_or_err(contract.destination_id)?;
+            let style = if contract.status == ContractStatus::Accepted {
+                UiStyle::current().own_team
+            } else if Some(contract.origin_id) == self.current_planet_id {
+                UiStyle::current().default
+            } else {
+                UiStyle::current().unselectable
+            };
+            options.push((
+                format!(
+                    "{} {} to {}",
+                    contract.amount, contract.resource, destination.name
+                ),
+                style,
+            ));
+        }
+
+        let list = selectable_list(options, &self.callback_registry);
+        frame.render_stateful_widget(
+            list,
+            area.inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+            &mut ClickableListState::default().with_selected(self.contract_index),
+        );
+
+        Ok(())
+    }
+
+    fn render_selected_contract(
+        &self,
+        frame: &mut Frame,
+        world: &World,
+        area: Rect,
+    ) -> AppResult<()> {
+        frame.render_widget(default_block().title("Contract "), area);
+        let contract = match self
+            .contract_index
+            .and_then(|index| self.contracts.get(index))
+            .and_then(|id| world.contracts.iter().find(|c| c.id == *id))
+        {
+            Some(contract) => contract,
+            None => return Ok(()),
+        };
+
+        let team = world.get_own_team()?;
+        let hover_text_target = hover_text_target(frame);
+        let origin = world.get_planet_or_err(contract.origin_id)?;
+        let destination = world.get_planet_or_err(contract.destination_id)?;
+        let remaining_time = contract
+            .deadline
+            .saturating_sub(world.last_tick_short_interval)
+            .formatted();
+
+        let split = Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(
+            area.inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+        );
+
+        let mut lines = vec![
+            Line::from(""),
+            Line::from(vec![
+                Span::raw(format!("{:<12}", "Deliver")),
+                Span::styled(
+                    format!("{} {}", contract.amount, contract.resource),
+                    contract.resource.style(),
+                ),
+            ]),
+            Line::from(format!("{:<12}{}", "From", origin.name)),
+            Line::from(format!("{:<12}{}", "To", destination.name)),
+            Line::from(format!(
+                "{:<12}{}",
+                "Reward",
+                format_satoshi(contract.reward)
+            )),
+            Line::from(format!(
+                "{:<12}+{:.1}",
+                "Reputation", contract.reputation_bonus
+            )),
+            Line::from(""),
+        ];
+
+        let button = if contract.status == ContractStatus::Accepted {
+            lines.push(Line::from(Span::styled(
+                format!("Deliver within {}", remaining_time),
+                UiStyle::current().warning,
+            )));
+            lines.push(Line::from(format!(
+                "Failing the delivery costs {} reputation.",
+                CONTRACT_FAILURE_REPUTATION_MALUS
+            )));
+            let mut button = Button::new(
+                format!("Deliver to {}", destination.name).into(),
+                UiCallback::DeliverContract {
+                    contract_id: contract.id,
+                },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(
+                "Deliver the resources and collect the reward.".into(),
+                hover_text_target,
+            );
+            if let Err(err) = contract.can_be_delivered_by(team, world.last_tick_short_interval) {
+                button.disable(Some(err.to_string()));
+            }
+            button
+        } else {
+            lines.push(Line::from(format!("Offer expires in {}", remaining_time)));
+            lines.push(Line::from(format!(
+                "Once accepted, there are {} to deliver.",
+                contract.delivery_time.formatted()
+            )));
+            let mut button = Button::new(
+                "Accept contract".into(),
+                UiCallback::AcceptContract {
+                    contract_id: contract.id,
+                },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(
+                format!("Accept the contract on {}.", origin.name),
+                hover_text_target,
+            );
+            if let Err(err) = contract.can_be_accepted_by(
+                team,
+                world.accepted_contracts().len(),
+                world.last_tick_short_interval,
+            ) {
+                button.disable(Some(err.to_string()));
+            }
+            button
+        };
+
+        frame.render_widget(
+            Paragraph::new(lines).wrap(Wrap { trim: true }),
+            split[0].inner(Margin {
+                horizontal: 1,
+                vertical: 0,
+            }),
+        );
+        frame.render_widget(button, split[1]);
+
+        Ok(())
+    }
+
+    fn render_academy(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        let team = world.get_own_team()?;
+        let split = Layout::horizontal([Constraint::Length(48), Constraint::Min(48)]).split(area);
+
+        frame.render_widget(
+            default_block().title(format!(
+                "Academy {}/{} ",
+                self.academy_prospects.len(),
+                MAX_ACADEMY_PROSPECTS
+            )),
+            split[0],
+        );
+        if self.academy_prospects.len() == 0 {
+            let text = if team.asteroid_ids.is_empty() {
+                "The academy needs an asteroid to train prospects. Find one while exploring."
+            } else {
+                "No prospect is enrolled. Young free pirates can be enrolled from the Players tab."
+            };
+            frame.render_widget(
+                Paragraph::new(text).wrap(Wrap { trim: true }),
+                split[0].inner(Margin {
+                    horizontal: 2,
+                    vertical: 2,
+                }),
+            );
+            frame.render_widget(default_block().title("Prospect "), split[1]);
+            return Ok(());
+        }
+
+        let mut options = vec![];
+        for player_id in self.academy_prospects.iter() {
+            let player = world.get_player_or_err(*player_id)?;
+            options.push((
+                format!("{:<26} {}", player.info.shortened_name(), player.stars()),
+                UiStyle::current().default,
+            ));
+        }
+        let list = selectable_list(options, &self.callback_registry);
+        frame.render_stateful_widget(
+            list,
+            split[0].inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+            &mut ClickableListState::default().with_selected(self.prospect_index),
+        );
+
+        frame.render_widget(default_block().title("Prospect "), split[1]);
+        let player = match self
+            .prospect_index
+            .and_then(|index| self.academy_prospects.get(index))
+            .and_then(|&id| world.get_player(id))
+        {
+            Some(player) => player,
+            None => return Ok(()),
+        };
+        let (asteroid_id, enrolled) = match academy::academy_location(player) {
+            Some(location) => location,
+            None => return Ok(()),
+        };
+        let asteroid = world.get_planet_or_err(asteroid_id)?;
+
+        let right_split = Layout::vertical([
+            Constraint::Min(0),
+            Constraint::Length(3),
+            Constraint::Length(3),
+        ])
+        .split(split[1].inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        }));
+
+        let days_enrolled = world.last_tick_short_interval.saturating_sub(enrolled) / DAYS;
+        let lines = vec![
+            Line::from(""),
+            Line::from(format!("{:<16}{}", "Name", player.info.full_name())),
+            Line::from(format!("{:<16}{:.0}", "Age", player.info.age)),
+            Line::from(format!("{:<16}{}", "Overall", player.stars())),
+            Line::from(format!("{:<16}{}", "Potential", player.potential.stars())),
+            Line::from(format!("{:<16}{}", "Academy", asteroid.name)),
+            Line::from(format!("{:<16}{} days", "Enrolled for", days_enrolled)),
+            Line::from(format!(
+                "{:<16}{}/day",
+                "Fee",
+                format_satoshi(ACADEMY_DAILY_FEE)
+            )),
+            Line::from(""),
+            Line::from("Prospects train every day, faster when far from their potential."),
+        ];
+        frame.render_widget(
+            Paragraph::new(lines).wrap(Wrap { trim: true }),
+            right_split[0].inner(Margin {
+                horizontal: 1,
+                vertical: 0,
+            }),
+        );
+
+        let hover_text_target = hover_text_target(frame);
+        let mut promote_button = Button::new(
+            "Promote to the crew".into(),
+            UiCallback::PromoteProspect {
+                player_id: player.id,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            format!(
+                "Add {} to the roster. The team must be docked on {}.",
+                player.info.shortened_name(),
+                asteroid.name
+            ),
+            hover_text_target,
+        )
+        .set_hotkey(UiKey::PROMOTE_PROSPECT);
+        if let Err(err) = team.can_promote_prospect(player) {
+            promote_button.disable(Some(err.to_string()));
+        }
+        frame.render_widget(promote_button, right_split[1]);
+
+        let mut release_button = Button::new(
+            "Release".into(),
+            UiCallback::ReleaseProspect {
+                player_id: player.id,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            format!(
+                "Send {} away from the academy, back to the free pirates.",
+                player.info.shortened_name()
+            ),
+            hover_text_target,
+        )
+        .set_hotkey(UiKey::RELEASE_PROSPECT);
+        if let Err(err) = team.can_release_prospect(player) {
+            release_button.disable(Some(err.to_string()));
+        }
+        frame.render_widget(release_button, right_split[2]);
+
+        Ok(())
+    }
+
+    fn render_staff(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        let team = world.get_own_team()?;
+        let split = Layout::horizontal([Constraint::Length(48), Constraint::Min(48)]).split(area);
+
+        frame.render_widget(
+            default_block().title(format!(
+                "Staff - {}/day ",
+                format_satoshi(team.staff_salaries())
+            )),
+            split[0],
+        );
+        let mut options = vec![];
+        for role in StaffRole::iter() {
+            let text = match team.staff_member(role) {
+                Some(member) => {
+                    format!("{:<10} {:<22} {}", role, member.name, member.skill.stars())
+                }
+                None => format!("{:<10} {}", role, "Vacant"),
+            };
+            options.push((text, UiStyle::current().default));
+        }
+        let list = selectable_list(options, &self.callback_registry);
+        frame.render_stateful_widget(
+            list,
+            split[0].inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+            &mut ClickableListState::default().with_selected(Some(self.staff_role_index)),
+        );
+
+        let role = match StaffRole::iter().nth(self.staff_role_index) {
+            Some(role) => role,
+            None => return Ok(()),
+        };
+        frame.render_widget(default_block().title(format!("{} ", role)), split[1]);
+        let right_split = Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(
+            split[1].inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+        );
+
+        let hover_text_target = hover_text_target(frame);
+        let mut lines = vec![
+            Line::from(""),
+            Line::from(role.description()),
+            Line::from(""),
+        ];
+        let button = if let Some(member) = team.staff_member(role) {
+            lines.extend([
+                Line::from(format!("{:<16}{}", "Name", member.name)),
+                Line::from(format!("{:<16}{}", "Skill", member.skill.stars())),
+                Line::from(format!("{:<16}+{:.0}%", "Bonus", member.bonus() * 100.0)),
+                Line::from(format!(
+                    "{:<16}{}/day",
+                    "Salary",
+                    format_satoshi(member.salary())
+                )),
+                Line::from(format!(
+                    "{:<16}{} days",
+                    "In service",
+                    member.days_in_service(world.last_tick_short_interval)
+                )),
+            ]);
+
+            let mut fire_button = Button::new(
+                format!("Fire {}", role.to_string().to_lowercase()).into(),
+                UiCallback::FireStaff { role },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(
+                format!("Let {} go. No severance is paid.", member.name),
+                hover_text_target,
+            )
+            .set_hotkey(UiKey::FIRE_STAFF);
+            if let Err(err) = team.can_fire_staff(role) {
+                fire_button.disable(Some(err.to_string()));
+            }
+            fire_button
+        } else {
+            let candidate = if let Some(planet_id) = team.is_on_planet() {
+                world
+                    .staff_candidates(planet_id, world.last_tick_short_interval)?
+                    .into_iter()
+                    .find(|candidate| candidate.role == role)
+            } else {
+                None
+            };
+
+            let mut hire_button = Button::new(
+                format!("Hire {}", role.to_string().to_lowercase()).into(),
+                UiCallback::HireStaff { role },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hotkey(UiKey::HIRE_STAFF);
+            match candidate {
+                Some(candidate) => {
+                    lines.extend([
+                        Line::from(format!("{:<16}{}", "Candidate", candidate.name)),
+                        Line::from(format!("{:<16}{}", "Skill", candidate.skill.stars())),
+                        Line::from(format!("{:<16}+{:.0}%", "Bonus", candidate.bonus() * 100.0)),
+                        Line::from(format!(
+                            "{:<16}{}/day",
+                            "Salary",
+                            format_satoshi(candidate.salary())
+                        )),
+                        Line::from(""),
+                        Line::from("Candidates on each planet change every day."),
+                    ]);
+                    hire_button = hire_button.set_hover_text(
+                        format!(
+                            "Hire {} for {}.",
+                            candidate.name,
+                            format_satoshi(candidate.hiring_fee())
+                        ),
+                        hover_text_target,
+                    );
+                    if let Err(err) = team.can_hire_staff(&candidate) {
+                        hire_button.disable(Some(err.to_string()));
+                    }
+                }
+                None => {
+                    lines.push(Line::from(
+                        "Staff can be hired when docked on a populated planet.",
+                    ));
+                    hire_button.disable(Some("Nobody to hire here".to_string()));
+                }
+            }
+            hire_button
+        };
+
+        frame.render_widget(
+            Paragraph::new(lines).wrap(Wrap { trim: true }),
+            right_split[0].inner(Margin {
+                horizontal: 1,
+                vertical: 0,
+            }),
+        );
+        frame.render_widget(button, right_split[1]);
+
+        Ok(())
+    }
+
+    fn render_emblem(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        let team = world.get_own_team()?;
+        let emblem = self.emblem_draft.unwrap_or_else(|| team.emblem());
+        let split = Layout::horizontal([
+            Constraint::Length(32),
+            Constraint::Min(FLAG_WIDTH as u16 + 4),
+        ])
+        .split(area);
+
+        frame.render_widget(default_block().title("Emblem "), split[0]);
+        let button_split = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Min(0),
+        ])
+        .split(split[0].inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        }));
+
+        let hover_text_target = hover_text_target(frame);
+        frame.render_widget(
+            Button::new(
+                format!("Symbol: {}", emblem.symbol).into(),
+                UiCallback::SetEmblemDraft {
+                    emblem: Emblem {
+                        symbol: emblem.symbol.next(),
+                        ..emblem
+                    },
+                },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hotkey(UiKey::CYCLE_EMBLEM_SYMBOL)
+            .set_hover_text("Cycle the emblem symbol.".into(), hover_text_target),
+            button_split[0],
+        );
+        frame.render_widget(
+            Button::new(
+                format!("Pattern: {}", emblem.pattern).into(),
+                UiCallback::SetEmblemDraft {
+                    emblem: Emblem {
+                        pattern: emblem.pattern.next(),
+                        ..emblem
+                    },
+                },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hotkey(UiKey::CYCLE_EMBLEM_PATTERN)
+            .set_hover_text("Cycle the emblem pattern.".into(), hover_text_target),
+            button_split[1],
+        );
+
+        let channels = [
+            ("Field", emblem.color.red),
+            ("Pattern", emblem.color.green),
+            ("Symbol", emblem.color.blue),
+        ];
+        for (idx, (channel, rgb)) in channels.into_iter().enumerate() {
+            let next_rgb = ColorPreset::closest(rgb).next().to_rgb();
+            let mut color = emblem.color;
+            match idx {
+                0 => color.red = next_rgb,
+                1 => color.green = next_rgb,
+                _ => color.blue = next_rgb,
+            }
+            frame.render_widget(
+                Button::new(
+                    format!("{}: {:?}", channel, ColorPreset::closest(rgb)).into(),
+                    UiCallback::SetEmblemDraft {
+                        emblem: Emblem { color, ..emblem },
+                    },
+                    Arc::clone(&self.callback_registry),
+                )
+                .set_hover_text(
+                    format!("Cycle the {} color.", channel.to_lowercase()),
+                    hover_text_target,
+                ),
+                button_split[idx + 2],
+            );
+        }
+
+        let mut reset_button = Button::new(
+            "Reset".into(),
+            UiCallback::SetEmblemDraft {
+                emblem: team.emblem(),
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text("Discard the changes.".into(), hover_text_target);
+        if emblem == team.emblem() {
+            reset_button.disable(None);
+        }
+        frame.render_widget(reset_button, button_split[5]);
+
+        let mut apply_button = Button::new(
+            format!("Apply ({})", format_satoshi(EMBLEM_CHANGE_COST)).into(),
+            UiCallback::ChangeTeamEmblem { emblem },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hotkey(UiKey::CHANGE_EMBLEM)
+        .set_hover_text(
+            format!(
+                "Hoist the new flag for {}.",
+                format_satoshi(EMBLEM_CHANGE_COST)
+            ),
+            hover_text_target,
+        );
+        if let Err(err) = team.can_change_emblem(&emblem) {
+            apply_button.disable(Some(err.to_string()));
+        }
+        frame.render_widget(apply_button, button_split[6]);
+
+        frame.render_widget(default_block().title("Flag "), split[1]);
+        frame.render_widget(
+            Paragraph::new(img_to_lines(&flag_image(&emblem))).centered(),
+            split[1].inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+        );
+
+        Ok(())
+    }
+
+    fn rename_button<'a>(
+        &self,
+        team: &Team,
+        target: RenameTarget,
+        hotkey: KeyCode,
+        hover_text_target: Rect,
+    ) -> Button<'a> {
+        let mut button = Button::new(
+            target.to_string().into(),
+            UiCallback::PromptRename { target },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hotkey(hotkey)
+        .set_hover_text(
+            format!(
+                "Choose a new {} for {}.",
+                target.to_string().to_lowercase(),
+                format_satoshi(target.cost())
+            ),
+            hover_text_target,
+        );
+        if team.current_game.is_some() {
+            button.disable(Some("Team is playing".to_string()));
+        } else if team.balance() < target.cost() {
+            button.disable(Some("Insufficient satoshi".to_string()));
+        }
+        button
+    }
+
+    fn render_appearance(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        if self.emblem_view {
+            return self.render_emblem(frame, world, area);
+        }
+
+        let team = world.get_own_team()?;
+        let jersey = self
+            .appearance_draft
+            .clone()
+            .unwrap_or_else(|| team.jersey.clone());
+        let split = Layout::horizontal([
+            Constraint::Length(32),
+            Constraint::Length(24),
+            Constraint::Min(SPACESHIP_IMAGE_WIDTH as u16 + 4),
+        ])
+        .split(area);
+
+        frame.render_widget(default_block().title("Appearance "), split[0]);
+        let button_split = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Min(0),
+        ])
+        .split(split[0].inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        }));
+
+        let hover_text_target = hover_text_target(frame);
+        frame.render_widget(
+            Button::new(
+                format!("Style: {}", jersey.style).into(),
+                UiCallback::SetAppearanceDraft {
+                    jersey: Jersey {
+                        style: jersey.style.next(),
+                        ..jersey.clone()
+                    },
+                },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hotkey(UiKey::CYCLE_JERSEY_STYLE)
+            .set_hover_text("Cycle the jersey style.".into(), hover_text_target),
+            button_split[0],
+        );
+
+        let channels = [
+            ("Red", jersey.color.red),
+            ("Green", jersey.color.green),
+            ("Blue", jersey.color.blue),
+        ];
+        for (idx, (channel, rgb)) in channels.into_iter().enumerate() {
+            let next_rgb = ColorPreset::closest(rgb).next().to_rgb();
+            let mut color = jersey.color;
+            match idx {
+                0 => color.red = next_rgb,
+                1 => color.green = next_rgb,
+                _ => color.blue = next_rgb,
+            }
+            frame.render_widget(
+                Button::new(
+                    format!("{}: {:?}", channel, ColorPreset::closest(rgb)).into(),
+                    UiCallback::SetAppearanceDraft {
+                        jersey: Jersey {
+                            color,
+                            ..jersey.clone()
+                        },
+                    },
+                    Arc::clone(&self.callback_registry),
+                )
+                .set_hover_text(
+                    format!("Cycle the {} channel color.", channel.to_lowercase()),
+                    hover_text_target,
+                ),
+                button_split[idx + 1],
+            );
+        }
+
+        let mut reset_button = Button::new(
+            "Reset".into(),
+            UiCallback::SetAppearanceDraft {
+                jersey: team.jersey.clone(),
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text("Discard the changes.".into(), hover_text_target);
+        if jersey == team.jersey {
+            reset_button.disable(None);
+        }
+        frame.render_widget(reset_button, button_split[4]);
+
+        let mut apply_button = Button::new(
+            format!("Apply ({})", format_satoshi(APPEARANCE_CHANGE_COST)).into(),
+            UiCallback::ChangeTeamAppearance {
+                jersey: jersey.clone(),
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hotkey(UiKey::CHANGE_APPEARANCE)
+        .set_hover_text(
+            format!(
+                "Recolor jersey and spaceship for {}.",
+                format_satoshi(APPEARANCE_CHANGE_COST)
+            ),
+            hover_text_target,
+        );
+        if let Err(err) = team.can_change_appearance(&jersey) {
+            apply_button.disable(Some(err.to_string()));
+        }
+        frame.render_widget(apply_button, button_split[5]);
+
+        // The preview player gets a new version on every change, so it is composed
+        // directly rather than through the gif map.
+        frame.render_widget(default_block().title("Jersey "), split[1]);
+        let jersey_split = Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(
+            split[1].inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+        );
+        frame.render_widget(
+            self.rename_button(
+                team,
+                RenameTarget::Team,
+                UiKey::RENAME_TEAM,
+                hover_text_target,
+            ),
+            jersey_split[1],
+        );
+        if let Some(player_id) = team.player_ids.first() {
+            let mut player = world.get_player_or_err(*player_id)?.clone();
+            player.image.set_jersey(&jersey, &player.info);
+            if let Ok(gif) = player.compose_image() {
+                let img = gif[(self.tick / 8) % gif.len()].clone();
+                frame.render_widget(
+                    Paragraph::new(img_to_lines(&img)).centered(),
+                    jersey_split[0],
+                );
+            }
+        }
+
+        frame.render_widget(default_block().title("Spaceship "), split[2]);
+        let spaceship_split = Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(
+            split[2].inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+        );
+        frame.render_widget(
+            self.rename_button(
+                team,
+                RenameTarget::Spaceship,
+                UiKey::RENAME_SPACESHIP,
+                hover_text_target,
+            ),
+            spaceship_split[1],
+        );
+        let spaceship = team.spaceship.clone().with_color_map(jersey.color);
+        if let Ok(lines) = self
+            .gif_map
+            .lock()
+            .unwrap()
+            .on_planet_spaceship_lines(&spaceship, self.tick)
+        {
+            frame.render_widget(Paragraph::new(lines).centered(), spaceship_split[0]);
+        }
+
+        Ok(())
+    }
+
+    fn render_advisor(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        frame.render_widget(default_block().title("Advisor "), area);
+        let team = world.get_own_team()?;
+        let advice = team_advice(world, team)?;
+
+        let inner = area.inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        });
+        if advice.is_empty() {
+            frame.render_widget(
+                Paragraph::new("Everything is shipshape, captain. No suggestions for now.")
+                    .wrap(Wrap { trim: true }),
+                inner.inner(Margin {
+                    horizontal: 1,
+                    vertical: 1,
+                }),
+            );
+            return Ok(());
+        }
+
+        let hover_text_target = hover_text_target(frame);
+        let split = Layout::vertical(
+            advice
+                .iter()
+                .map(|_| Constraint::Length(3))
+                .chain([Constraint::Min(0)]),
+        )
+        .split(inner);
+
+        for (idx, advice) in advice.iter().enumerate() {
+            let row =
+                Layout::horizontal([Constraint::Min(0), Constraint::Length(28)]).split(split[idx]);
+            frame.render_widget(
+                Paragraph::new(advice.text.as_str()).wrap(Wrap { trim: true }),
+                row[0].inner(Margin {
+                    horizontal: 1,
+                    vertical: 0,
+                }),
+            );
+
+            let Some(action) = advice.action else {
+                continue;
+            };
+            let (callback, mut can_apply) = match action {
+                AdviceAction::AssignBestPositions => (UiCallback::AssignBestTeamPositions, Ok(())),
+                AdviceAction::BuyFuel { amount, unit_cost } => (
+                    UiCallback::TradeResource {
+                        resource: Resource::FUEL,
+                        amount: amount as i32,
+                        unit_cost,
+                    },
+                    team.can_trade_resource(Resource::FUEL, amount as i32, unit_cost),
+                ),
+                AdviceAction::SellResource {
+                    resource,
+                    amount,
+                    unit_cost,
+                } => (
+                    UiCallback::TradeResource {
+                        resource,
+                        amount: -(amount as i32),
+                        unit_cost,
+                    },
+                    team.can_trade_resource(resource, -(amount as i32), unit_cost),
+                ),
+                AdviceAction::SetTrainingFocus { focus } => (
+                    UiCallback::SetTrainingFocus { focus },
+                    team.can_change_training_focus(),
+                ),
+            };
+            if let (UiCallback::TradeResource { .. }, Some(planet_id)) =
+                (&callback, team.is_on_planet())
+            {
+                can_apply = can_apply.and_then(|_| {
+                    world.can_trade_on_planet(planet_id, world.last_tick_short_interval)
+                });
+            }
+
+            let mut button = Button::new(
+                action.label().into(),
+                callback,
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(advice.text.clone(), hover_text_target);
+            if let Err(e) = can_apply {
+                button.disable(Some(e.to_string()));
+            }
+            frame.render_widget(button, row[1]);
+        }
+
+        Ok(())
+    }
+
+    fn render_training(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        let team = world.get_own_team()?;
+        let split = Layout::horizontal([Constraint::Length(32), Constraint::Min(48)]).split(area);
+
+        let today = TrainingSchedule::day_index(world.last_tick_short_interval);
+        let options = team
+            .training_schedule
+            .sessions
+            .iter()
+            .enumerate()
+            .map(|(day, session)| {
+                let session = match session {
+                    Some(focus) => focus.to_string(),
+                    None => "Rest".to_string(),
+                };
+                let text = if day == today {
+                    format!("Day {} {:<10} today", day + 1, session)
+                } else {
+                    format!("Day {} {}", day + 1, session)
+                };
+                (text, UiStyle::current().default)
+            })
+            .collect_vec();
+
+        frame.render_widget(default_block().title("Training schedule "), split[0]);
+        let list = selectable_list(options, &self.callback_registry);
+        frame.render_stateful_widget(
+            list,
+            split[0].inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+            &mut ClickableListState::default().with_selected(Some(self.training_day_index)),
+        );
+
+        frame.render_widget(
+            default_block().title(format!("Day {} ", self.training_day_index + 1)),
+            split[1],
+        );
+        let right_split = Layout::vertical([
+            Constraint::Length(8),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Min(0),
+        ])
+        .split(split[1].inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        }));
+
+        let doctor_bonus = TeamBonus::Training.current_team_bonus(world, team.id)?;
+        let facility_modifier = TrainingSchedule::facility_modifier(&team.spaceship);
+        let mut energy = 0.0;
+        for player_id in team.player_ids.iter() {
+            let player = world.get_player_or_err(*player_id)?;
+            energy += TrainingSchedule::tiredness_modifier(player);
+        }
+        let energy = energy / team.player_ids.len().max(1) as f32;
+
+        let sessions = TrainingFocus::iter()
+            .map(|focus| format!("{} {}", focus, team.training_schedule.sessions_for(focus)))
+            .join("  ");
+        let lines = vec![
+            Line::from(format!("{:<16}x{:.2}", "Doctor bonus", doctor_bonus)),
+            Line::from(format!("{:<16}x{:.2}", "Facilities", facility_modifier)),
+            Line::from(format!("{:<16}x{:.2}", "Crew energy", energy)),
+            Line::from(""),
+            Line::from(format!("Sessions per week: {}", sessions)),
+            Line::from(format!(
+                "Each session costs {} energy.",
+                TRAINING_SESSION_TIREDNESS
+            )),
+        ];
+        frame.render_widget(
+            Paragraph::new(lines).wrap(Wrap { trim: true }),
+            right_split[0].inner(Margin {
+                horizontal: 1,
+                vertical: 0,
+            }),
+        );
+
+        let hover_text_target = hover_text_target(frame);
+        let can_change_training = team.can_change_training_focus();
+        let current_session = team.training_schedule.sessions[self.training_day_index];
+        let mut sessions = vec![None];
+        sessions.extend(TrainingFocus::iter().map(|focus| Some(focus)));
+        let mut button_split = vec![];
+        for row in [right_split[1], right_split[2]] {
+            button_split.extend_from_slice(
+                &Layout::horizontal([
+                    Constraint::Ratio(1, 3),
+                    Constraint::Ratio(1, 3),
+                    Constraint::Ratio(1, 3),
+                ])
+                .split(row),
+            );
+        }
+
+        for (idx, session) in sessions.into_iter().enumerate() {
+            let mut button = Button::new(
+                match session {
+                    Some(focus) => focus.to_string(),
+                    None => "Rest".to_string(),
+                }
+                .into(),
+                UiCallback::SetTrainingSession {
+                    day: self.training_day_index,
+                    focus: session,
+                },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(
+                match session {
+                    Some(focus) => format!(
+                        "Train {} on day {}.",
+                        focus.to_string().to_lowercase(),
+                        self.training_day_index + 1
+                    ),
+                    None => format!("Rest on day {}.", self.training_day_index + 1),
+                },
+                hover_text_target,
+            );
+            if session == current_session {
+                button.disable(None);
+            } else if let Err(err) = can_change_training.as_ref() {
+                button.disable(Some(err.to_string()));
+            }
+            frame.render_widget(button, button_split[idx]);
+        }
+
+        Ok(())
+    }
+
+    fn render_action_queue(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        frame.render_widget(
+            default_block().title(format!(
+                "Orders {}/{} ",
+                self.action_queue.len(),
+                MAX_QUEUED_ACTIONS
+            )),
+            area,
+        );
+
+        if self.action_queue.len() == 0 {
+            frame.render_widget(
+                Paragraph::new(
+                    "No orders queued. Queue travels from the galaxy panel, and buy or explore orders from here.",
+                )
+                .wrap(Wrap { trim: true }),
+                area.inner(Margin {
+                    horizontal: 2,
+                    vertical: 2,
+                }),
+            );
+            return Ok(());
+        }
+
+        let options = self
+            .action_queue
+            .iter()
+            .enumerate()
+            .map(|(idx, action)| {
+                (
+                    format!("{:>2}. {}", idx + 1, action.description(world)),
+                    UiStyle::current().default,
+                )
+            })
+            .collect_vec();
+
+        let list = selectable_list(options, &self.callback_registry);
+        frame.render_stateful_widget(
+            list,
+            area.inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+            &mut ClickableListState::default().with_selected(self.action_index),
+        );
+
+        Ok(())
+    }
+
+    fn render_order_buttons(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        let team = world.get_own_team()?;
+        let hover_text_target = hover_text_target(&frame);
+        frame.render_widget(default_block().title("Add orders "), area);
+
+        let split = Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)]).split(
+            area.inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+        );
+
+        let add_actions = [
+            QueuedAction::Buy {
+                resource: Resource::FUEL,
+                amount: QUEUED_BUY_AMOUNT,
+            },
+            QueuedAction::Explore {
+                duration: QUICK_EXPLORATION_TIME,
+            },
+            QueuedAction::Explore {
+                duration: LONG_EXPLORATION_TIME,
+            },
+            QueuedAction::ReturnHome,
+        ];
+        let add_split =
+            Layout::vertical([Constraint::Length(3)].repeat(add_actions.len())).split(split[0]);
+        for (idx, action) in add_actions.iter().enumerate() {
+            let mut button = Button::new(
+                action.description(world).into(),
+                UiCallback::QueueAction { action: *action },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(
+                "Add the order at the end of the queue.".into(),
+                hover_text_target,
+            );
+            if let Err(e) = team.can_queue_action(*action) {
+                button.disable(Some(e.to_string()));
+            }
+            frame.render_widget(button, add_split[idx]);
+        }
+
+        let edit_split = Layout::vertical([Constraint::Length(3)].repeat(3)).split(split[1]);
+        let index = self.action_index.unwrap_or_default();
+
+        let mut remove_button = Button::new(
+            "Remove order".into(),
+            UiCallback::RemoveQueuedAction { index },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text("Remove the selected order.".into(), hover_text_target);
+        let mut move_up_button = Button::new(
+            "Move up".into(),
+            UiCallback::MoveQueuedActionUp { index },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            "Execute the selected order earlier.".into(),
+            hover_text_target,
+        );
+        let mut clear_button = Button::new(
+            "Clear orders".into(),
+            UiCallback::ClearActionQueue,
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text("Remove all the orders.".into(), hover_text_target);
+
+        if self.action_index.is_none() {
+            remove_button.disable(None);
+            move_up_button.disable(None);
+            clear_button.disable(None);
+        } else if index == 0 {
+            move_up_button.disable(None);
+        }
+
+        frame.render_widget(remove_button, edit_split[0]);
+        frame.render_widget(move_up_button, edit_split[1]);
+        frame.render_widget(clear_button, edit_split[2]);
+
+        Ok(())
+    }
+
+    fn render_asteroids(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        let split = Layout::horizontal([Constraint::Length(48), Constraint::Min(48)]).split(area);
+        self.render_asteroid_list(frame, world, split[0])?;
+        self.render_selected_asteroid(frame, world, split[1])?;
+        Ok(())
+    }
+
+    fn render_asteroid_list(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        frame.render_widget(default_block().title("Asteroids "), area);
+        let team = world.get_own_team()?;
+
+        if self.asteroid_ids.len() == 0 {
+            frame.render_widget(
+                Paragraph::new("No asteroid has been found yet, keep exploring!")
+                    .wrap(Wrap { trim: true }),
+                area.inner(Margin {
+                    horizontal: 2,
+                    vertical: 2,
+                }),
+            );
+            return Ok(());
+        }
+
+        let split = Layout::horizontal([Constraint::Length(12), Constraint::Length(30)]).split(
+            area.inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+        );
+
+        let options = self
+            .asteroid_ids
+            .iter()
+            .filter(|&&asteroid_id| world.get_planet_or_err(asteroid_id).is_ok())
+            .map(|&asteroid_id| {
+                let asteroid = world.get_planet_or_err(asteroid_id).unwrap();
+                let style = match team.current_location {
+                    TeamLocation::OnPlanet { planet_id } => {
+                        if planet_id == asteroid_id {
+                            UiStyle::current().own_team
+                        } else {
+                            UiStyle::current().default
+                        }
+                    }
+                    _ => UiStyle::current().default,
+                };
+                (asteroid.name.clone(), style)
+            })
+            .collect_vec();
+
+        let list = selectable_list(options, &self.callback_registry);
+
+        frame.render_stateful_widget(
+            list,
+            split[0].inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+            &mut ClickableListState::default().with_selected(self.asteroid_index),
+        );
+
+        Ok(())
+    }
+
+    fn render_selected_asteroid(
+        &self,
+        frame: &mut Frame,
+        world: &World,
+        area: Rect,
+    ) -> AppResult<()> {
+        if self.asteroid_ids.len() == 0 {
+            frame.render_widget(default_block(), area);
+            return Ok(());
+        }
+
+        let asteroid =
+            world.get_planet_or_err(self.asteroid_ids[self.asteroid_index.unwrap_or_default()])?;
+
+        frame.render_widget(default_block().title(format!("{} ", asteroid.name)), area);
+
+        let split = Layout::vertical([
+            Constraint::Min(0),
+            Constraint::Length(4),
+            Constraint::Length(3),
+            Constraint::Length(3),
+        ])
+        .split(area.inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        }));
+
+        let img_lines = self
+            .gif_map
+            .lock()
+            .unwrap()
+            .planet_zoom_out_frame_lines(asteroid, 0, world)?;
+        frame.render_widget(
+            Paragraph::new(img_lines).centered(),
+            split[0].inner(Margin {
+                horizontal: 0,
+                vertical: 1,
+            }),
+        );
+
+        self.render_asteroid_production(frame, world, asteroid, split[1], split[2])?;
+
+        let b_split =
+            Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)]).split(split[3]);
+
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                "No kartoffeln to plant",
+                UiStyle::current().disconnected,
+            ))
+            .centered()
+            .block(default_block()),
+            b_split[0],
+        );
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                "No kartoffeln to harvest",
+                UiStyle::current().disconnected,
+            ))
+            .centered()
+            .block(default_block()),
+            b_split[1],
+        );
+
+        Ok(())
+    }
+
+    fn render_asteroid_production(
+        &self,
+        frame: &mut Frame,
+        world: &World,
+        asteroid: &Planet,
+        info_area: Rect,
+        buttons_area: Rect,
+    ) -> AppResult<()> {
+        let team = world.get_own_team()?;
+        let hover_text_target = hover_text_target(frame);
+
+        let parent_type = match asteroid.satellite_of {
+            Some(parent_id) => world.get_planet_or_err(parent_id)?.planet_type.clone(),
+            None => PlanetType::Asteroid,
+        };
+        let format_resources = |resources: &ResourceMap| {
+            let formatted = resources
+                .iter()
+                .filter(|(_, amount)| **amount > 0)
+                .sorted_by_key(|(resource, _)| resource.to_string())
+                .map(|(resource, amount)| format!("{} {}", amount, resource))
+                .join(", ");
+            if formatted.is_empty() {
+                "Nothing".to_string()
+            } else {
+                formatted
+            }
+        };
+
+        let facilities = if asteroid.facilities.is_empty() {
+            "None".to_string()
+        } else {
+            asteroid.facilities.iter().map(|f| f.to_string()).join(", ")
+        };
+
+        frame.render_widget(
+            Paragraph::new(vec![
+                Line::from(format!(
+                    "Daily production {}",
+                    format_resources(&asteroid.daily_production(&parent_type))
+                )),
+                Line::from(vec![
+                    Span::raw("Pending          "),
+                    Span::styled(
+                        format_resources(&asteroid.pending_production),
+                        UiStyle::current().highlight,
+                    ),
+                ]),
+                Line::from(format!("Facilities       {}", facilities)),
+                Line::from(Span::styled(
+                    format!(
+                        "Production is loaded when docking, up to {} days are stored.",
+                        MAX_PENDING_PRODUCTION_DAYS
+                    ),
+                    UiStyle::current().unselectable,
+                )),
+            ]),
+            info_area,
+        );
+
+        let constraints = [Constraint::Ratio(
+            1,
+            AsteroidFacility::iter().count() as u32,
+        )]
+        .repeat(AsteroidFacility::iter().count());
+        let b_split = Layout::horizontal(constraints).split(buttons_area);
+        for (idx, facility) in AsteroidFacility::iter().enumerate() {
+            let (resource, amount) = facility.production();
+            let cost = facility
+                .cost()
+                .iter()
+                .map(|(resource, amount)| format!("{} {}", amount, resource))
+                .join(", ");
+            let mut button = Button::new(
+                format!("Build {}", facility).into(),
+                UiCallback::BuildAsteroidFacility {
+                    asteroid_id: asteroid.id,
+                    facility,
+                },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(
+                format!("Produces {amount} {resource} per day. Cost: {cost}."),
+                hover_text_target,
+            );
+            if let Err(err) = team.can_build_asteroid_facility(asteroid, facility) {
+                button.disable(Some(err.to_string()));
+            }
+            frame.render_widget(button, b_split[idx]);
+        }
+
+        Ok(())
+    }
+
+    fn render_crew(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        let split = Layout::horizontal([Constraint::Length(48), Constraint::Min(48)]).split(area);
+        self.render_crew_morale(frame, world, split[0])?;
+        self.render_crew_log(frame, world, split[1])?;
+        Ok(())
+    }
+
+    fn render_crew_morale(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        let team = world.get_own_team()?;
+        let hover_text_target = hover_text_target(&frame);
+        frame.render_widget(default_block().title("Crew morale"), area);
+
+        let players = world.get_players_by_team(team)?;
+        let average_morale = if players.len() > 0 {
+            players.iter().map(|p| p.morale).sum::<f32>() / players.len() as f32
+        } else {
+            MAX_MORALE
+        };
+        let restless = players
+            .iter()
+            .filter(|p| {
+                p.morale < MORALE_THRESHOLD_FOR_MUTINY && Some(p.id) != team.crew_roles.captain
+            })
+            .count();
+
+        let status = if let Some(until) = team.on_strike_until {
+            Span::styled(
+                format!(
+                    "On strike for {}",
+                    until
+                        .saturating_sub(world.last_tick_short_interval)
+                        .formatted()
+                ),
+                UiStyle::current().error,
+            )
+        } else if average_morale < MORALE_THRESHOLD_FOR_MUTINY {
+            Span::styled("Mutinous", UiStyle::current().error)
+        } else if restless > 0 {
+            Span::styled("Restless", UiStyle::current().warning)
+        } else {
+            Span::styled("Loyal", UiStyle::current().ok)
+        };
+
+        let info = Paragraph::new(vec![
+            Line::from(""),
+            Line::from(vec![
+                Span::raw(format!("Average morale {:5}  ", average_morale.stars())),
+                status,
+            ]),
+            Line::from(format!("Pirates at risk of mutiny: {restless}")),
+            Line::from(""),
+            Line::from(format!(
+                "Bonus cost: {}",
+                format_satoshi(team.crew_bonus_cost())
+            )),
+            Line::from(format!("Rum needed: {} l", team.player_ids.len())),
+        ]);
+        frame.render_widget(
+            info,
+            area.inner(Margin {
+                horizontal: 2,
+                vertical: 1,
+            }),
+        );
+
+        let btm_split = Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(
+            area.inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+        );
+        let button_split = Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+            .split(btm_split[1]);
+
+        let mut bonus_button = Button::new(
+            "Pay bonus".into(),
+            UiCallback::PayCrewBonus,
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            format!(
+                "Pay {} to the crew to raise morale and end strikes.",
+                format_satoshi(team.crew_bonus_cost())
+            ),
+            hover_text_target,
+        )
+        .set_hotkey(UiKey::PAY_CREW_BONUS);
+        if let Err(err) = team.can_pay_crew_bonus() {
+            bonus_button.disable(Some(err.to_string()));
+        }
+        frame.render_widget(bonus_button, button_split[0]);
+
+        let mut rum_button = Button::new(
+            "Share rum".into(),
+            UiCallback::ShareRum,
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            "Give a round of rum to everybody to raise morale and end strikes. Pirates will be a bit tired afterwards.".into(),
+            hover_text_target,
+        )
+        .set_hotkey(UiKey::SHARE_RUM);
+        if let Err(err) = team.can_share_rum() {
+            rum_button.disable(Some(err.to_string()));
+        }
+        frame.render_widget(rum_button, button_split[1]);
+
+        Ok(())
+    }
+
+    fn render_crew_log(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        let lines = if world.crew_log.len() == 0 {
+            vec![Line::from("Nothing to report, captain.")]
+        } else {
+            world
+                .crew_log
+                .iter()
+                .rev()
+                .map(|entry| {
+                    Line::from(vec![
+                        Span::styled(
+                            format!("{} ", entry.tick.formatted_as_date()),
+                            UiStyle::current().highlight,
+                        ),
+                        Span::raw(entry.message.clone()),
+                    ])
+                })
+                .collect_vec()
+        };
+
+        frame.render_widget(default_block().title("Crew log"), area);
+        frame.render_widget(
+            Paragraph::new(lines).wrap(Wrap { trim: true }),
+            area.inner(Margin {
+                horizontal: 2,
+                vertical: 1,
+            }),
+        );
+        Ok(())
+    }
+
+    fn render_reputation(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        let team = world.get_own_team()?;
+        let split = Layout::horizontal([Constraint::Length(48), Constraint::Min(48)]).split(area);
+
+        let planets_with_gossip = team
+            .planet_reputation
+            .values()
+            .filter(|&&offset| offset < 0.0)
+            .count();
+        let mut summary_lines = vec![
+            Line::from(""),
+            Line::from(format!("Galaxy reputation {:5}", team.reputation.stars())),
+            Line::from(format!("Planets spreading gossip: {planets_with_gossip}")),
+            Line::from(""),
+            Line::from(
+                "Winning games, trading and delivering contracts on a planet raise the standing with its faction, earning better market prices, hiring costs and docking fees. Declining many challenges and trades, skipping docking fees or getting caught smuggling makes people talk: gossip lowers the standing on a planet and its neighbours, and with the crews docked there. Crews holding a grudge refuse to play or trade. Both are slowly forgotten.",
+            ),
+        ];
+        let crews_with_gossip = team
+            .team_reputation
+            .iter()
+            .filter_map(|(team_id, offset)| {
+                world
+                    .get_team(*team_id)
+                    .map(|other| (other.name.clone(), *offset))
+            })
+            .sorted_by(|(_, a), (_, b)| a.total_cmp(b))
+            .collect_vec();
+        if !crews_with_gossip.is_empty() {
+            summary_lines.push(Line::from(""));
+            summary_lines.push(Line::from(Span::styled(
+                format!("{:<24} {:>8}", "Crew", "Standing"),
+                UiStyle::current().header,
+            )));
+            for (name, offset) in crews_with_gossip {
+                let style = if offset <= TEAM_GRUDGE_STANDING {
+                    UiStyle::current().error
+                } else {
+                    UiStyle::current().warning
+                };
+                summary_lines.push(Line::from(Span::styled(
+                    format!("{:<24} {:>+8.1}", name, offset),
+                    style,
+                )));
+            }
+        }
+
+        frame.render_widget(default_block().title("Reputation"), split[0]);
+        frame.render_widget(
+            Paragraph::new(summary_lines).wrap(Wrap { trim: true }),
+            split[0].inner(Margin {
+                horizontal: 2,
+                vertical: 1,
+            }),
+        );
+
+        let mut lines = vec![Line::from(Span::styled(
+            format!(
+                "{:<16} {:<8} {:>8} {:>8} {:>6}",
+                "Planet", "Rep", "Standing", "Spread", "Dock"
+            ),
+            UiStyle::current().header,
+        ))];
+        for &planet_id in self.planet_markets.iter() {
+            let planet = world.get_planet_or_err(planet_id)?;
+            let offset = team
+                .planet_reputation
+                .get(&planet_id)
+                .copied()
+                .unwrap_or_default();
+            let style = if offset < 0.0 {
+                UiStyle::current().warning
+            } else if offset > 0.0 {
+                UiStyle::current().ok
+            } else if Some(planet_id) == self.current_planet_id {
+                UiStyle::current().own_team
+            } else {
+                UiStyle::current().default
+            };
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "{:<16} {:<8} {:>+8.1} {:>7}% {:>6}",
+                    planet.name,
+                    team.reputation_on_planet(planet_id).stars(),
+                    offset,
+                    (100.0 / team.merchant_modifier(planet_id)).round(),
+                    team.docking_fee(planet)
+                ),
+                style,
+            )));
+        }
+
+        frame.render_widget(default_block().title("Planet standing"), split[1]);
+        frame.render_widget(
+            Paragraph::new(lines),
+            split[1].inner(Margin {
+                horizontal: 2,
+                vertical: 1,
+            }),
+        );
+        Ok(())
+    }
+
+    fn render_finances(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        let team = world.get_own_team()?;
+        let split = Layout::horizontal([Constraint::Length(48), Constraint::Min(48)]).split(area);
+
+        let mut summary_lines = vec![
+            Line::from(format!("Balance: {}", format_satoshi(team.balance()))),
+            Line::from(""),
+        ];
+        summary_lines.append(&mut self.loan_lines(world, team)?);
+        summary_lines.push(Line::from(""));
+        summary_lines.append(&mut self.insurance_lines(world, team));
+        summary_lines.append(&mut vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                format!(
+                    "{:<10} {:>10} {:>10} {:>10}",
+                    "Week of", "Income", "Expenses", "Net"
+                ),
+                UiStyle::current().header,
+            )),
+        ]);
+        for summary in world.finances.weekly_summaries(world.now(), 4) {
+            let style = if summary.net() < 0 {
+                UiStyle::current().error
+            } else {
+                UiStyle::current().ok
+            };
+            summary_lines.push(Line::from(vec![
+                Span::raw(format!(
+                    "{:<10} {:>10} {:>10} ",
+                    summary.start.formatted_as_date(),
+                    summary.income,
+                    summary.expenses
+                )),
+                Span::styled(format!("{:>10}", summary.net()), style),
+            ]));
+        }
+
+        let summary_split = Layout::vertical([
+            Constraint::Min(0),
+            Constraint::Length(3),
+            Constraint::Length(3),
+        ])
+        .split(split[0].inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        }));
+
+        frame.render_widget(default_block().title("Weekly summary"), split[0]);
+        frame.render_widget(
+            Paragraph::new(summary_lines),
+            summary_split[0].inner(Margin {
+                horizontal: 1,
+                vertical: 0,
+            }),
+        );
+        self.render_loan_buttons(frame, world, team, summary_split[1])?;
+        self.render_insurance_buttons(frame, world, team, summary_split[2])?;
+
+        let mut lines = vec![Line::from(Span::styled(
+            format!(
+                "{:<10} {:<12} {:>10} {}",
+                "Date", "Category", "Amount", "Description"
+            ),
+            UiStyle::current().header,
+        ))];
+        if world.finances.entries.len() == 0 {
+            lines.push(Line::from("No transactions yet, captain."));
+        }
+        for entry in world
+            .finances
+            .entries
+            .iter()
+            .rev()
+            .skip(self.finance_index.unwrap_or_default())
+        {
+            let style = if entry.amount < 0 {
+                UiStyle::current().error
+            } else {
+                UiStyle::current().ok
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{:<10} ", entry.tick.formatted_as_date()),
+                    UiStyle::current().highlight,
+                ),
+                Span::raw(format!("{:<12} ", entry.category.to_string())),
+                Span::styled(format!("{:>+10} ", entry.amount), style),
+                Span::raw(entry.description.clone()),
+            ]));
+        }
+
+        frame.render_widget(
+            default_block().title(format!(
+                "Ledger ({} entries) ↓/↑",
+                world.finances.entries.len()
+            )),
+            split[1],
+        );
+        frame.render_widget(
+            Paragraph::new(lines),
+            split[1].inner(Margin {
+                horizontal: 2,
+                vertical: 1,
+            }),
+        );
+        Ok(())
+    }
+
+    fn loan_lines<'a>(&self, world: &World, team: &Team) -> AppResult<Vec<Line<'a>>> {
+        let lines = match team.loan.as_ref() {
+            Some(loan) => {
+                let planet = world.get_planet_or_err(loan.planet_id)?;
+                let due_style = if loan.due_at < world.now() + 2 * DAYS {
+                    UiStyle::current().error
+                } else {
+                    UiStyle::current().default
+                };
+                vec![
+                    Line::from(format!(
+                        "Debt: {} to {}",
+                        format_satoshi(loan.debt),
+                        planet.name
+                    )),
+                    Line::from(format!(
+                        "Interest: {} per day",
+                        format_satoshi(loan.daily_interest())
+                    )),
+                    Line::from(Span::styled(
+                        format!("Due by {}", loan.due_at.formatted_as_date()),
+                        due_style,
+                    )),
+                ]
+            }
+            None => vec![
+                Line::from("No debt."),
+                Line::from(format!(
+                    "Banks lend up to {} at {}% per day",
+                    format_satoshi(max_loan_amount(team)),
+                    LOAN_DAILY_INTEREST * 100.0
+                )),
+                Line::from(""),
+            ],
+        };
+        Ok(lines)
+    }
+
+    fn insurance_lines<'a>(&self, world: &World, team: &Team) -> Vec<Line<'a>> {
+        InsuranceKind::iter()
+            .map(|kind| match team.active_insurance(kind, world.now()) {
+                Some(policy) => Line::from(vec![
+                    Span::raw(format!("{} insurance: ", kind)),
+                    Span::styled(
+                        format!("until {}", policy.expires_at.formatted_as_date()),
+                        UiStyle::current().ok,
+                    ),
+                ]),
+                None => Line::from(format!(
+                    "{} insurance: {} per week",
+                    kind,
+                    format_satoshi(kind.premium(&team.spaceship))
+                )),
+            })
+            .collect()
+    }
+
+    fn render_insurance_buttons(
+        &self,
+        frame: &mut Frame,
+        world: &World,
+        team: &Team,
+        area: Rect,
+    ) -> AppResult<()> {
+        let hover_text_target = hover_text_target(frame);
+        let split =
+            Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)]).split(area);
+
+        for (idx, (kind, hotkey)) in [
+            (InsuranceKind::Hull, UiKey::INSURE_HULL),
+            (InsuranceKind::Cargo, UiKey::INSURE_CARGO),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let mut button = Button::new(
+                format!("Insure {}", kind.to_string().to_lowercase()).into(),
+                UiCallback::BuyInsurance { kind: *kind },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(
+                format!(
+                    "{} Pays {}% of the losses above {} satoshi for {} days.",
+                    kind.description(),
+                    (INSURANCE_COVERAGE * 100.0).round(),
+                    INSURANCE_DEDUCTIBLE,
+                    INSURANCE_TERM / DAYS
+                ),
+                hover_text_target,
+            )
+            .set_hotkey(*hotkey);
+            if let Err(err) = team.can_buy_insurance(*kind, world.now()) {
+                button.disable(Some(err.to_string()));
+            }
+            frame.render_widget(button, split[idx]);
+        }
         Ok(())
     }
 
-    fn render_selected_asteroid(
+    fn render_loan_buttons(
         &self,
         frame: &mut Frame,
         world: &World,
+        team: &Team,
         area: Rect,
     ) -> AppResult<()> {
-        if self.asteroid_ids.len() == 0 {
-            frame.render_widget(default_block(), area);
-            return Ok(());
-        }
-
-        let asteroid =
-            world.get_planet_or_err(self.asteroid_ids[self.asteroid_index.unwrap_or_default()])?;
-
-        frame.render_widget(default_block().title(format!("{} ", asteroid.name)), area);
-
-        let split = Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(
-            area.inner(Margin {
-                horizontal: 1,
-                vertical: 1,
-            }),
-        );
-
-        let img_lines = self
-            .gif_map
-            .lock()
-            .unwrap()
-            .planet_zoom_out_frame_lines(asteroid, 0, world)?;
-        frame.render_widget(
-            Paragraph::new(img_lines).centered(),
-            split[0].inner(Margin {
-                horizontal: 0,
-                vertical: 1,
-            }),
-        );
-
-        let b_split =
-            Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)]).split(split[1]);
-
-        frame.render_widget(
-            Paragraph::new(Span::styled(
-                "No kartoffeln to plant",
-                UiStyle::DISCONNECTED,
-            ))
-            .centered()
-            .block(default_block()),
-            b_split[0],
-        );
-        frame.render_widget(
-            Paragraph::new(Span::styled(
-                "No kartoffeln to harvest",
-                UiStyle::DISCONNECTED,
-            ))
-            .centered()
-            .block(default_block()),
-            b_split[1],
-        );
+        let hover_text_target = hover_text_target(frame);
+        let split =
+            Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)]).split(area);
 
+        let mut take_button = Button::new(
+            format!("Borrow {}", format_satoshi(LOAN_STEP)).into(),
+            UiCallback::TakeLoan { amount: LOAN_STEP },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            format!(
+                "Borrow from the planet bank. Unpaid debt at the due date costs {} reputation and the bank repossesses the team assets.",
+                LOAN_DEFAULT_REPUTATION_MALUS
+            ),
+            hover_text_target,
+        )
+        .set_hotkey(UiKey::TAKE_LOAN);
+        let can_take_loan = team
+            .is_on_planet()
+            .ok_or(anyhow!("Team is not on a planet"))
+            .and_then(|planet_id| world.get_planet_or_err(planet_id))
+            .and_then(|planet| team.can_take_loan(planet, LOAN_STEP));
+        if let Err(err) = can_take_loan {
+            take_button.disable(Some(err.to_string()));
+        }
+        frame.render_widget(take_button, split[0]);
+
+        let repay_amount = team
+            .loan
+            .as_ref()
+            .map(|loan| loan.debt.min(LOAN_STEP))
+            .unwrap_or(LOAN_STEP);
+        let mut repay_button = Button::new(
+            format!("Repay {}", format_satoshi(repay_amount)).into(),
+            UiCallback::RepayLoan {
+                amount: repay_amount,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text("Repay part of the debt.".into(), hover_text_target)
+        .set_hotkey(UiKey::REPAY_LOAN);
+        if let Err(err) = team.can_repay_loan(repay_amount) {
+            repay_button.disable(Some(err.to_string()));
+        }
+        frame.render_widget(repay_button, split[1]);
         Ok(())
     }
 
@@ -1407,90 +4255,62 @@ impl MyTeamPanel {
             Constraint::Length(11),
             Constraint::Length(11),
             Constraint::Length(11),
-            Constraint::Length(32),
-            Constraint::Length(32),
+            Constraint::Length(11),
+            Constraint::Length(11),
+            Constraint::Length(28),
+            Constraint::Length(28),
+            Constraint::Length(28),
+            Constraint::Length(16),
             Constraint::Min(0),
         ])
         .split(area.inner(Margin {
             vertical: 0,
             horizontal: 1,
         }));
-        let can_set_as_captain = team.can_set_crew_role(&player, CrewRole::Captain);
-        let mut captain_button = Button::new(
-            "captain".into(),
-            UiCallback::SetCrewRole {
-                player_id,
-                role: CrewRole::Captain,
-            },
-            Arc::clone(&self.callback_registry),
-        )
-        .set_hover_text(
-            format!(
-                "Set player to captain role: {} +{}%, {} {}%",
-                TeamBonus::Reputation,
-                TeamBonus::Reputation.as_skill(player)?.percentage(),
-                TeamBonus::TradePrice,
-                TeamBonus::TradePrice.as_skill(player)?.percentage()
-            ),
-            hover_text_target,
-        )
-        .set_hotkey(UiKey::SET_CAPTAIN);
-        if can_set_as_captain.is_err() {
-            captain_button.disable(None);
-        }
-        frame.render_widget(captain_button, button_splits[0]);
 
-        let can_set_as_pilot = team.can_set_crew_role(&player, CrewRole::Pilot);
-
-        let mut pilot_button = Button::new(
-            "pilot".into(),
-            UiCallback::SetCrewRole {
-                player_id,
-                role: CrewRole::Pilot,
-            },
-            Arc::clone(&self.callback_registry),
-        )
-        .set_hover_text(
-            format!(
-                "Set player to pilot role: {} +{}%, {} {}%",
-                TeamBonus::SpaceshipSpeed,
-                TeamBonus::SpaceshipSpeed.as_skill(player)?.percentage(),
-                TeamBonus::Exploration,
-                TeamBonus::Exploration.as_skill(player)?.percentage()
-            ),
-            hover_text_target,
-        )
-        .set_hotkey(UiKey::SET_PILOT);
-        if can_set_as_pilot.is_err() {
-            pilot_button.disable(None);
-        }
-        frame.render_widget(pilot_button, button_splits[1]);
-
-        let can_set_as_doctor = team.can_set_crew_role(&player, CrewRole::Doctor);
-
-        let mut doctor_button = Button::new(
-            "doctor".into(),
-            UiCallback::SetCrewRole {
-                player_id,
-                role: CrewRole::Doctor,
-            },
-            Arc::clone(&self.callback_registry),
-        )
-        .set_hover_text(
-            format!(
-                "Set player to doctor role: {} +{}%, {} {}%",
-                TeamBonus::TirednessRecovery,
-                TeamBonus::TirednessRecovery.as_skill(player)?.percentage(),
-                TeamBonus::Training,
-                TeamBonus::Training.as_skill(player)?.percentage()
-            ),
-            hover_text_target,
-        )
-        .set_hotkey(UiKey::SET_DOCTOR);
-        if can_set_as_doctor.is_err() {
-            doctor_button.disable(None);
+        for (idx, (role, hotkey)) in [
+            (CrewRole::Captain, UiKey::SET_CAPTAIN),
+            (CrewRole::Pilot, UiKey::SET_PILOT),
+            (CrewRole::Doctor, UiKey::SET_DOCTOR),
+            (CrewRole::Engineer, UiKey::SET_ENGINEER),
+            (CrewRole::Cook, UiKey::SET_COOK),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let bonuses = role
+                .bonuses()
+                .iter()
+                .map(|bonus| {
+                    Ok(format!(
+                        "{} +{}%",
+                        bonus,
+                        bonus.as_skill(player)?.percentage()
+                    ))
+                })
+                .collect::<AppResult<Vec<String>>>()?;
+            let mut role_button = Button::new(
+                role.to_string().to_lowercase().into(),
+                UiCallback::SetCrewRole {
+                    player_id,
+                    role: *role,
+                },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(
+                format!(
+                    "Set player to {} role: {}",
+                    role.to_string().to_lowercase(),
+                    bonuses.join(", ")
+                ),
+                hover_text_target,
+            )
+            .set_hotkey(*hotkey);
+            if team.can_set_crew_role(&player, *role).is_err() {
+                role_button.disable(None);
+            }
+            frame.render_widget(role_button, button_splits[idx]);
         }
-        frame.render_widget(doctor_button, button_splits[2]);
 
         let can_release = team.can_release_player(&player);
         let mut release_button = Button::new(
@@ -1504,31 +4324,62 @@ impl MyTeamPanel {
             release_button.disable(Some(format!("{}", can_release.unwrap_err().to_string())));
         }
 
-        frame.render_widget(release_button, button_splits[3]);
+        frame.render_widget(release_button, button_splits[5]);
 
         if let Ok(drink_button) =
             drink_button(world, player_id, &self.callback_registry, hover_text_target)
         {
-            frame.render_widget(drink_button, button_splits[4]);
+            frame.render_widget(drink_button, button_splits[6]);
+        }
+
+        if let Ok(duel_button) =
+            duel_button(world, player_id, &self.callback_registry, hover_text_target)
+        {
+            frame.render_widget(duel_button, button_splits[7]);
         }
 
+        let nickname_button = self.rename_button(
+            team,
+            RenameTarget::Player { player_id },
+            UiKey::NICKNAME_PLAYER,
+            hover_text_target,
+        );
+        frame.render_widget(nickname_button, button_splits[8]);
+
         Ok(())
     }
 
     fn build_players_table(&self, world: &World, table_width: u16) -> AppResult<ClickableTable> {
         let team = world.get_own_team().unwrap();
-        let header_cells = [
-            " Name",
-            "Overall",
-            "Potential",
-            "Current",
-            "Best",
-            "Role",
-            "Crew bonus",
-        ]
-        .iter()
-        .map(|h| ClickableCell::from(*h).style(UiStyle::HEADER));
+        let header_keys = [
+            PlayerSortKey::Name,
+            PlayerSortKey::Overall,
+            PlayerSortKey::Potential,
+            PlayerSortKey::Current,
+            PlayerSortKey::Best,
+            PlayerSortKey::Role,
+            PlayerSortKey::CrewBonus,
+        ];
+        let header_cells = header_keys.iter().map(|key| {
+            let text = if *key == self.players_sort.key {
+                format!("{}{}", self.players_sort.arrow(), key)
+            } else {
+                key.to_string()
+            };
+            let text = if *key == PlayerSortKey::Name {
+                format!(" {}", text)
+            } else {
+                text
+            };
+            ClickableCell::from(text).style(UiStyle::current().header)
+        });
         let header = ClickableRow::new(header_cells);
+        let header_callbacks = header_keys
+            .iter()
+            .map(|key| UiCallback::SortRoster {
+                players_sort: self.players_sort.by(*key),
+            })
+            .collect();
 
         // Calculate the available space for the players name in order to display the
         // full or shortened version.
@@ -1545,7 +4396,9 @@ impl MyTeamPanel {
                     Some(idx) => format!(
                         "{:<2} {:<5}",
                         (idx as Position).as_str(),
-                        if (idx as Position) < MAX_POSITION {
+                        if player.is_injured() {
+                            "Injured".to_string()
+                        } else if (idx as Position) < MAX_POSITION {
                             (idx as Position).player_rating(skills).stars()
                         } else {
                             "".to_string()
@@ -1557,54 +4410,24 @@ impl MyTeamPanel {
                 let overall = player.average_skill().stars();
                 let potential = player.potential.stars();
 
-                let bonus_string_1 = match player.info.crew_role {
-                    CrewRole::Pilot => {
-                        let skill = TeamBonus::SpaceshipSpeed.as_skill(player)?;
-                        Span::styled(
-                            format!("{} +{}%", TeamBonus::SpaceshipSpeed, skill.percentage()),
-                            skill.style(),
-                        )
-                    }
-                    CrewRole::Captain => {
-                        let skill = TeamBonus::Reputation.as_skill(player)?;
-                        Span::styled(
-                            format!("{} +{}%", TeamBonus::Reputation, skill.percentage()),
-                            skill.style(),
-                        )
-                    }
-                    CrewRole::Doctor => {
-                        let skill = TeamBonus::TirednessRecovery.as_skill(player)?;
-                        Span::styled(
-                            format!("{} +{}%", TeamBonus::TirednessRecovery, skill.percentage()),
-                            skill.style(),
-                        )
+                let bonuses = player.info.crew_role.bonuses();
+                let bonus_string_1 = match bonuses.first() {
+                    Some(bonus) => {
+                        let skill = bonus.as_skill(player)?;
+                        Span::styled(format!("{} +{}%", bonus, skill.percentage()), skill.style())
                     }
-                    _ => Span::raw(""),
+                    None => Span::raw(""),
                 };
 
-                let bonus_string_2 = match player.info.crew_role {
-                    CrewRole::Pilot => {
-                        let skill = TeamBonus::Exploration.as_skill(player)?;
-                        Span::styled(
-                            format!(" {} +{}%", TeamBonus::Exploration, skill.percentage()),
-                            skill.style(),
-                        )
-                    }
-                    CrewRole::Captain => {
-                        let skill = TeamBonus::TradePrice.as_skill(player)?;
-                        Span::styled(
-                            format!(" {} +{}%", TeamBonus::TradePrice, skill.percentage()),
-                            skill.style(),
-                        )
-                    }
-                    CrewRole::Doctor => {
-                        let skill = TeamBonus::Training.as_skill(player)?;
+                let bonus_string_2 = match bonuses.get(1) {
+                    Some(bonus) => {
+                        let skill = bonus.as_skill(player)?;
                         Span::styled(
-                            format!(" {} +{}%", TeamBonus::Training, skill.percentage()),
+                            format!(" {} +{}%", bonus, skill.percentage()),
                             skill.style(),
                         )
                     }
-                    _ => Span::raw(" "),
+                    None => Span::raw(" "),
                 };
 
                 let name = if name_header_width >= 2 * MAX_NAME_LENGTH as u16 + 2 {
@@ -1617,7 +4440,11 @@ impl MyTeamPanel {
                     ClickableCell::from(name),
                     ClickableCell::from(overall),
                     ClickableCell::from(potential),
-                    ClickableCell::from(current_role),
+                    if player.is_injured() {
+                        ClickableCell::from(Span::styled(current_role, UiStyle::current().error))
+                    } else {
+                        ClickableCell::from(current_role)
+                    },
                     ClickableCell::from(format!(
                         "{:<2} {:<5}",
                         best_role.as_str(),
@@ -1633,8 +4460,9 @@ impl MyTeamPanel {
 
         let table = ClickableTable::new(rows?, Arc::clone(&self.callback_registry))
             .header(header)
-            .hovering_style(UiStyle::HIGHLIGHT)
-            .highlight_style(UiStyle::SELECTED)
+            .header_callbacks(header_callbacks)
+            .hovering_style(UiStyle::current().highlight)
+            .highlight_style(UiStyle::current().selected)
             ._column_spacing(0)
             .widths(&[
                 Constraint::Min(MAX_NAME_LENGTH as u16 + 4),
@@ -1684,6 +4512,7 @@ impl MyTeamPanel {
             self.tick,
             frame,
             world,
+            SkillVisibility::Full,
             top_split[1],
         );
 
@@ -1731,6 +4560,8 @@ impl MyTeamPanel {
                 let position = team.player_ids.iter().position(|id| *id == player.id);
                 if position.is_some() && position.unwrap() == idx {
                     button.disable(None);
+                } else if let Err(e) = team.can_set_starting_position(player) {
+                    button.disable(Some(e.to_string()));
                 }
                 frame.render_widget(button, rect);
             }
@@ -1832,14 +4663,25 @@ impl MyTeamPanel {
                     component: team.spaceship.storage.next(),
                 })
             }
-            3 if team.spaceship.can_be_repaired() => Some(SpaceshipUpgradeTarget::Repairs {
+            3 if team.spaceship.life_support.can_be_upgraded() => {
+                Some(SpaceshipUpgradeTarget::LifeSupport {
+                    component: team.spaceship.life_support.next(),
+                })
+            }
+            4 if team.spaceship.scanner.can_be_upgraded() => {
+                Some(SpaceshipUpgradeTarget::Scanner {
+                    component: team.spaceship.scanner.next(),
+                })
+            }
+            5 if team.spaceship.can_be_repaired() => Some(SpaceshipUpgradeTarget::Repairs {
                 amount: team.spaceship.durability() - team.spaceship.current_durability(),
             }),
             _ => None,
         };
 
         if let Some(target) = target {
-            let upgrade = SpaceshipUpgrade::new(target);
+            let upgrade = SpaceshipUpgrade::new(target, world.now())
+                .with_repair_bonus(TeamBonus::Repairs.current_team_bonus(world, team.id)?);
             render_spaceship_upgrade(&team, &upgrade, &self.gif_map, self.tick, frame, area);
             frame.render_widget(default_block().title(upgrade.description()), area);
 
@@ -1894,8 +4736,19 @@ impl MyTeamPanel {
             frame.render_widget(paragraph.centered(), rect);
         }
         let planet = world.get_planet_or_err(planet_id)?;
+        let route_text = match team.travel_route.last() {
+            Some(destination_id) => format!(
+                " (route to {}, {} stops left)",
+                world.get_planet_or_err(*destination_id)?.name,
+                team.travel_route.len()
+            ),
+            None => "".to_string(),
+        };
         frame.render_widget(
-            default_block().title(format!("Travelling to {} - {}", planet.name, countdown)),
+            default_block().title(format!(
+                "Travelling to {}{} - {}",
+                planet.name, route_text, countdown
+            )),
             area,
         );
         Ok(())
@@ -1942,9 +4795,55 @@ impl MyTeamPanel {
     }
 
     pub fn set_view(&mut self, view: MyTeamView) {
+        if self.view != view {
+            self.play_by_play_view = false;
+            self.play_by_play_offset = 0;
+        }
         self.view = view;
     }
 
+    pub fn toggle_tutorial_checklist(&mut self) {
+        self.tutorial_checklist_expanded = !self.tutorial_checklist_expanded;
+    }
+
+    pub fn set_appearance_draft(&mut self, jersey: Jersey) {
+        self.appearance_draft = Some(jersey);
+    }
+
+    pub fn reset_appearance_draft(&mut self) {
+        self.appearance_draft = None;
+    }
+
+    pub fn set_emblem_draft(&mut self, emblem: Emblem) {
+        self.emblem_draft = Some(emblem);
+    }
+
+    pub fn reset_emblem_draft(&mut self) {
+        self.emblem_draft = None;
+    }
+
+    pub fn toggle_emblem_view(&mut self) {
+        self.emblem_view = !self.emblem_view;
+    }
+
+    pub fn set_spaceship_prefab(&mut self, index: usize) {
+        self.spaceship_prefab_index = index;
+    }
+
+    pub fn toggle_space_upgrades_view(&mut self) {
+        self.space_upgrades_view = !self.space_upgrades_view;
+    }
+
+    pub fn toggle_play_by_play_view(&mut self) {
+        self.play_by_play_view = !self.play_by_play_view;
+        self.play_by_play_offset = 0;
+    }
+
+    // Positive values scroll back towards the start of the game.
+    pub fn scroll_play_by_play(&mut self, lines: i16) {
+        self.play_by_play_offset = self.play_by_play_offset.saturating_add_signed(lines);
+    }
+
     pub fn reset_view(&mut self) {
         self.set_view(MyTeamView::Info);
     }
@@ -1961,19 +4860,43 @@ impl Screen for MyTeamPanel {
             _ => None,
         };
 
-        if self.players.len() != own_team.player_ids.len() || world.dirty_ui {
+        if self.action_queue != own_team.action_queue {
+            self.action_queue = own_team.action_queue.clone();
+        }
+        self.action_index = if self.action_queue.len() > 0 {
+            if let Some(index) = self.action_index {
+                Some(index % self.action_queue.len())
+            } else {
+                Some(0)
+            }
+        } else {
+            None
+        };
+
+        if self.players.len() != own_team.player_ids.len()
+            || world.dirty_ui
+            || self.update_players_sort
+        {
+            let selected_player_id = self
+                .player_index
+                .and_then(|index| self.players.get(index).copied());
+            let players_sort = self.players_sort;
             self.players = own_team.player_ids.clone();
             self.players.sort_by(|a, b| {
                 let a = world.get_player(*a).unwrap();
                 let b = world.get_player(*b).unwrap();
-                if a.rating() == b.rating() {
-                    b.average_skill()
-                        .partial_cmp(&a.average_skill())
-                        .expect("Skill value should exist")
-                } else {
-                    b.rating().cmp(&a.rating())
-                }
+                players_sort.compare(a, b, Some(own_team))
             });
+
+            // Keep the same player selected when the order changes.
+            if self.update_players_sort {
+                if let Some(index) = selected_player_id
+                    .and_then(|id| self.players.iter().position(|&player_id| player_id == id))
+                {
+                    self.player_index = Some(index);
+                }
+                self.update_players_sort = false;
+            }
         }
 
         if self.planet_markets.len() == 0 || world.dirty_ui {
@@ -2003,6 +4926,57 @@ impl Screen for MyTeamPanel {
             None
         };
 
+        // Accepted contracts first, then the offers sorted by planet.
+        self.contracts = world
+            .contracts
+            .iter()
+            .sorted_by_key(|contract| {
+                (
+                    contract.status != ContractStatus::Accepted,
+                    world
+                        .get_planet_or_err(contract.origin_id)
+                        .map(|planet| planet.name.clone())
+                        .unwrap_or_default(),
+                )
+            })
+            .map(|contract| contract.id)
+            .collect();
+        self.contract_index = if self.contracts.len() > 0 {
+            if let Some(index) = self.contract_index {
+                Some(index % self.contracts.len())
+            } else {
+                Some(0)
+            }
+        } else {
+            None
+        };
+
+        self.academy_prospects = world
+            .academy_prospects(own_team.id)
+            .iter()
+            .map(|player| player.id)
+            .collect();
+        self.prospect_index = if self.academy_prospects.len() > 0 {
+            if let Some(index) = self.prospect_index {
+                Some(index % self.academy_prospects.len())
+            } else {
+                Some(0)
+            }
+        } else {
+            None
+        };
+
+        self.finance_entries = world.finances.entries.len();
+        self.finance_index = if self.finance_entries > 0 {
+            if let Some(index) = self.finance_index {
+                Some(index % self.finance_entries)
+            } else {
+                Some(0)
+            }
+        } else {
+            None
+        };
+
         self.player_index = if self.players.len() > 0 {
             if let Some(index) = self.player_index {
                 Some(index % self.players.len())
@@ -2084,12 +5058,28 @@ impl Screen for MyTeamPanel {
 
         self.render_view_buttons(frame, bottom_split[0])?;
 
+        // Hotkeys of the selected view can reuse the keys of other views.
+        self.callback_registry
+            .lock()
+            .unwrap()
+            .set_key_context(KeyContext::MyTeamView(self.view));
         match self.view {
             MyTeamView::Info => self.render_info(frame, world, bottom_split[1])?,
             MyTeamView::Games => self.render_games(frame, world, bottom_split[1])?,
             MyTeamView::Market => self.render_market(frame, world, bottom_split[1])?,
             MyTeamView::Shipyard => self.render_shipyard(frame, world, bottom_split[1])?,
+            MyTeamView::Modules => self.render_modules(frame, world, bottom_split[1])?,
             MyTeamView::Asteroids => self.render_asteroids(frame, world, bottom_split[1])?,
+            MyTeamView::Crew => self.render_crew(frame, world, bottom_split[1])?,
+            MyTeamView::Reputation => self.render_reputation(frame, world, bottom_split[1])?,
+            MyTeamView::Finances => self.render_finances(frame, world, bottom_split[1])?,
+            MyTeamView::Orders => self.render_orders(frame, world, bottom_split[1])?,
+            MyTeamView::Contracts => self.render_contracts(frame, world, bottom_split[1])?,
+            MyTeamView::Training => self.render_training(frame, world, bottom_split[1])?,
+            MyTeamView::Academy => self.render_academy(frame, world, bottom_split[1])?,
+            MyTeamView::Staff => self.render_staff(frame, world, bottom_split[1])?,
+            MyTeamView::Appearance => self.render_appearance(frame, world, bottom_split[1])?,
+            MyTeamView::Advisor => self.render_advisor(frame, world, bottom_split[1])?,
         }
 
         Ok(())
@@ -2116,11 +5106,96 @@ impl Screen for MyTeamPanel {
                     view: self.view.next(),
                 });
             }
+            UiKey::CYCLE_SORT => {
+                return Some(UiCallback::SortRoster {
+                    players_sort: self.players_sort.next(),
+                });
+            }
+            UiKey::PLAY_BY_PLAY_VIEW if self.view == MyTeamView::Games => {
+                self.toggle_play_by_play_view();
+            }
+            UiKey::EXPORT_TEAM_DATA if self.view == MyTeamView::Games => {
+                return Some(UiCallback::ExportTeamData);
+            }
+            UiKey::SHARE_GAME_SUMMARY if self.view == MyTeamView::Games => {
+                if let Some(&game_id) = self
+                    .game_index
+                    .and_then(|index| self.recent_games.get(index))
+                {
+                    return Some(UiCallback::ShareGameSummary { game_id });
+                }
+            }
+            UiKey::SPACE_UPGRADES_VIEW if self.view == MyTeamView::Shipyard => {
+                self.toggle_space_upgrades_view();
+            }
+            UiKey::EMBLEM_VIEW if self.view == MyTeamView::Appearance => {
+                self.toggle_emblem_view();
+            }
+            UiKey::SCROLL_PLAY_BY_PLAY_DOWN
+                if self.view == MyTeamView::Games && self.play_by_play_view =>
+            {
+                self.scroll_play_by_play(1);
+            }
+            UiKey::SCROLL_PLAY_BY_PLAY_UP
+                if self.view == MyTeamView::Games && self.play_by_play_view =>
+            {
+                self.scroll_play_by_play(-1);
+            }
             _ => {}
         }
 
         None
     }
+
+    fn footer_spans(&self) -> Vec<String> {
+        if self.view == MyTeamView::Shipyard {
+            return vec![
+                format!(" {} ", UiKey::SPACE_UPGRADES_VIEW.to_string()),
+                if self.space_upgrades_view {
+                    " Spaceship upgrades ".to_string()
+                } else {
+                    " Space adventure upgrades ".to_string()
+                },
+            ];
+        }
+
+        if self.view == MyTeamView::Appearance {
+            return vec![
+                format!(" {} ", UiKey::EMBLEM_VIEW.to_string()),
+                if self.emblem_view {
+                    " Jersey ".to_string()
+                } else {
+                    " Emblem ".to_string()
+                },
+            ];
+        }
+
+        if self.view != MyTeamView::Games {
+            return vec![];
+        }
+
+        let mut v = vec![
+            format!(" {} ", UiKey::PLAY_BY_PLAY_VIEW.to_string()),
+            if self.play_by_play_view {
+                " Game summary ".to_string()
+            } else {
+                " Play-by-play ".to_string()
+            },
+        ];
+        if self.play_by_play_view {
+            v.push(format!(
+                " {}/{} ",
+                UiKey::SCROLL_PLAY_BY_PLAY_DOWN.to_string(),
+                UiKey::SCROLL_PLAY_BY_PLAY_UP.to_string()
+            ));
+            v.push(" Scroll ".to_string());
+        }
+        v.push(format!(" {} ", UiKey::EXPORT_TEAM_DATA.to_string()));
+        v.push(" Export ".to_string());
+        v.push(format!(" {} ", UiKey::SHARE_GAME_SUMMARY.to_string()));
+        v.push(" Share ".to_string());
+        v
+    }
 }
 
 impl SplitPanel for MyTeamPanel {
@@ -2129,10 +5204,29 @@ impl SplitPanel for MyTeamPanel {
             return self.game_index.unwrap_or_default();
         } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Market {
             return self.planet_index.unwrap_or_default();
+        } else if self.active_list == PanelList::Bottom
+            && self.view == MyTeamView::Shipyard
+            && self.space_upgrades_view
+        {
+            return self.space_upgrade_index;
         } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Shipyard {
             return self.spaceship_upgrade_index;
+        } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Modules {
+            return self.spaceship_module_index;
         } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Asteroids {
             return self.asteroid_index.unwrap_or_default();
+        } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Finances {
+            return self.finance_index.unwrap_or_default();
+        } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Orders {
+            return self.action_index.unwrap_or_default();
+        } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Contracts {
+            return self.contract_index.unwrap_or_default();
+        } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Training {
+            return self.training_day_index;
+        } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Academy {
+            return self.prospect_index.unwrap_or_default();
+        } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Staff {
+            return self.staff_role_index;
         }
 
         // we should always have at least 1 player
@@ -2144,10 +5238,29 @@ impl SplitPanel for MyTeamPanel {
             return self.recent_games.len();
         } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Market {
             return self.planet_markets.len();
+        } else if self.active_list == PanelList::Bottom
+            && self.view == MyTeamView::Shipyard
+            && self.space_upgrades_view
+        {
+            return SpaceAdventureUpgrade::MAX_INDEX;
         } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Shipyard {
             return SpaceshipUpgradeTarget::MAX_INDEX;
+        } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Modules {
+            return SpaceshipModule::MAX_INDEX;
         } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Asteroids {
             return self.asteroid_ids.len();
+        } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Finances {
+            return self.finance_entries;
+        } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Orders {
+            return self.action_queue.len();
+        } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Contracts {
+            return self.contracts.len();
+        } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Training {
+            return TRAINING_DAYS;
+        } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Academy {
+            return self.academy_prospects.len();
+        } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Staff {
+            return StaffRole::iter().count();
         }
         self.players.len()
     }
@@ -2160,23 +5273,75 @@ impl SplitPanel for MyTeamPanel {
                 self.planet_index = None;
             } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Shipyard {
                 panic!("Max upgrade_index should be 3");
+            } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Modules {
+                panic!("Max module_index should be 2");
             } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Asteroids {
                 self.asteroid_index = None;
+            } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Finances {
+                self.finance_index = None;
+            } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Orders {
+                self.action_index = None;
+            } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Contracts {
+                self.contract_index = None;
+            } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Training {
+                panic!("Max training_day_index should be {}", TRAINING_DAYS);
+            } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Academy {
+                self.prospect_index = None;
+            } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Staff {
+                panic!(
+                    "Max staff_role_index should be {}",
+                    StaffRole::iter().count()
+                );
             } else {
                 self.player_index = None;
             }
         } else {
             if self.active_list == PanelList::Bottom && self.view == MyTeamView::Games {
                 self.game_index = Some(index % self.max_index());
+                self.play_by_play_offset = 0;
             } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Market {
                 self.planet_index = Some(index % self.max_index());
+            } else if self.active_list == PanelList::Bottom
+                && self.view == MyTeamView::Shipyard
+                && self.space_upgrades_view
+            {
+                self.space_upgrade_index = index % self.max_index();
             } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Shipyard {
                 self.spaceship_upgrade_index = index % self.max_index();
+            } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Modules {
+                self.spaceship_module_index = index % self.max_index();
             } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Asteroids {
                 self.asteroid_index = Some(index % self.max_index());
+            } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Finances {
+                self.finance_index = Some(index % self.max_index());
+            } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Orders {
+                self.action_index = Some(index % self.max_index());
+            } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Contracts {
+                self.contract_index = Some(index % self.max_index());
+            } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Training {
+                self.training_day_index = index % self.max_index();
+            } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Academy {
+                self.prospect_index = Some(index % self.max_index());
+            } else if self.active_list == PanelList::Bottom && self.view == MyTeamView::Staff {
+                self.staff_role_index = index % self.max_index();
             } else {
                 self.player_index = Some(index % self.max_index());
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::first_visible_row;
+
+    #[test]
+    fn test_first_visible_row() {
+        assert_eq!(first_visible_row(0, 8, 8), 0);
+        assert_eq!(first_visible_row(7, 8, 8), 0);
+        assert_eq!(first_visible_row(0, 8, 3), 0);
+        assert_eq!(first_visible_row(4, 8, 3), 3);
+        assert_eq!(first_visible_row(7, 8, 3), 5);
+        assert_eq!(first_visible_row(3, 8, 0), 3);
+    }
+}
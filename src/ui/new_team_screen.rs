@@ -8,7 +8,7 @@ use super::utils::{format_satoshi, validate_textarea_input};
 use super::{
     constants::UiStyle,
     traits::Screen,
-    utils::{img_to_lines, input_from_key_event},
+    utils::{hover_text_target, img_to_lines, input_from_key_event},
     widgets::{default_block, render_player_description, selectable_list},
 };
 use crate::image::{color_map::ColorPreset, spaceship::SPACESHIP_IMAGE_WIDTH};
@@ -17,9 +17,12 @@ use crate::{
     types::{AppResult, PlanetId, PlayerId},
     world::{
         constants::*,
+        generation::GenerationOptions,
         jersey::{Jersey, JerseyStyle},
+        scouting::SkillVisibility,
         skill::Rated,
         spaceship::{Spaceship, SpaceshipPrefab},
+        utils::TEAM_DATA,
         world::World,
     },
 };
@@ -46,6 +49,17 @@ use strum::IntoEnumIterator;
 use tui_textarea::{CursorMove, TextArea};
 
 const INITIAL_TEAM_SIZE: usize = 5;
+const GALAXY_OPTIONS: [&'static str; 8] = [
+    "Galaxy size",
+    "AI teams",
+    "Resource richness",
+    "Difficulty",
+    "Quarter length",
+    "Overtime",
+    "Shot clock",
+    "Ironman",
+];
+const AI_TEAMS_STEP: usize = 3;
 const SPACESHIP_MODELS: [SpaceshipPrefab; 3] = [
     SpaceshipPrefab::Bresci,
     SpaceshipPrefab::Orwell,
@@ -55,6 +69,7 @@ const SPACESHIP_MODELS: [SpaceshipPrefab; 3] = [
 #[derive(Debug, Default, PartialOrd, PartialEq)]
 pub enum CreationState {
     #[default]
+    Galaxy,
     TeamName,
     ShipName,
     Planet,
@@ -67,6 +82,7 @@ pub enum CreationState {
 impl CreationState {
     pub fn next(&self) -> Self {
         match self {
+            CreationState::Galaxy => CreationState::TeamName,
             CreationState::TeamName => CreationState::ShipName,
             CreationState::ShipName => CreationState::Planet,
             CreationState::Planet => CreationState::Jersey,
@@ -79,6 +95,8 @@ impl CreationState {
 
     pub fn previous(&self) -> Self {
         match self {
+            // The galaxy cannot be generated again once the team creation has started.
+            CreationState::Galaxy => CreationState::Galaxy,
             CreationState::TeamName => CreationState::TeamName,
             CreationState::ShipName => CreationState::TeamName,
             CreationState::Planet => CreationState::ShipName,
@@ -101,6 +119,8 @@ enum ConfirmChoice {
 pub struct NewTeamScreen {
     state: CreationState,
     tick: usize,
+    generation_options: GenerationOptions,
+    galaxy_option_index: usize,
     team_name_textarea: TextArea<'static>,
     ship_name_textarea: TextArea<'static>,
     spaceship_model_index: usize,
@@ -116,6 +136,7 @@ pub struct NewTeamScreen {
     planet_players: HashMap<PlanetId, Vec<(PlayerId, u32)>>,
     selected_players: Vec<PlayerId>,
     confirm: ConfirmChoice,
+    campaign_mode: bool,
     callback_registry: Arc<Mutex<CallbackRegistry>>,
     gif_map: Arc<Mutex<GifMap>>,
 }
@@ -126,17 +147,17 @@ impl NewTeamScreen {
         gif_map: Arc<Mutex<GifMap>>,
     ) -> Self {
         let mut team_name_textarea = TextArea::default();
-        team_name_textarea.set_cursor_style(UiStyle::SELECTED);
+        team_name_textarea.set_cursor_style(UiStyle::current().selected);
         team_name_textarea.set_block(
             default_block()
-                .border_style(UiStyle::DEFAULT)
+                .border_style(UiStyle::current().default)
                 .title("Team name"),
         );
         let mut ship_name_textarea = TextArea::default();
-        ship_name_textarea.set_cursor_style(UiStyle::DEFAULT);
+        ship_name_textarea.set_cursor_style(UiStyle::current().default);
         ship_name_textarea.set_block(
             default_block()
-                .border_style(UiStyle::UNSELECTABLE)
+                .border_style(UiStyle::current().unselectable)
                 .title("Ship name"),
         );
         let rng = &mut ChaCha8Rng::from_entropy();
@@ -196,6 +217,95 @@ impl NewTeamScreen {
         self.set_index(0);
     }
 
+    fn ai_teams(&self) -> usize {
+        self.generation_options.ai_teams.min(TEAM_DATA.len())
+    }
+
+    fn change_galaxy_option(&mut self, forward: bool) {
+        let options = &mut self.generation_options;
+        match self.galaxy_option_index {
+            0 => options.galaxy_size = cycle(options.galaxy_size, forward),
+            1 => {
+                let ai_teams = options.ai_teams.min(TEAM_DATA.len());
+                options.ai_teams = if forward {
+                    (ai_teams + AI_TEAMS_STEP).min(TEAM_DATA.len())
+                } else {
+                    ai_teams.saturating_sub(AI_TEAMS_STEP)
+                };
+            }
+            2 => options.resource_richness = cycle(options.resource_richness, forward),
+            3 => options.difficulty = cycle(options.difficulty, forward),
+            4 => {
+                options.match_rules.quarter_length =
+                    cycle(options.match_rules.quarter_length, forward)
+            }
+            5 => options.match_rules.overtime = !options.match_rules.overtime,
+            6 => options.match_rules.shot_clock = cycle(options.match_rules.shot_clock, forward),
+            7 => options.ironman = !options.ironman,
+            _ => {}
+        }
+    }
+
+    fn render_galaxy_options(&mut self, frame: &mut Frame, area: Rect) {
+        let values = [
+            self.generation_options.galaxy_size.to_string(),
+            self.ai_teams().to_string(),
+            self.generation_options.resource_richness.to_string(),
+            self.generation_options.difficulty.to_string(),
+            self.generation_options
+                .match_rules
+                .quarter_length
+                .to_string(),
+            if self.generation_options.match_rules.overtime {
+                "On".to_string()
+            } else {
+                "Off".to_string()
+            },
+            self.generation_options.match_rules.shot_clock.to_string(),
+            if self.generation_options.ironman {
+                "On".to_string()
+            } else {
+                "Off".to_string()
+            },
+        ];
+        let description = match self.galaxy_option_index {
+            0 => "How many free pirates live on each planet.",
+            1 => "How many AI crews roam the galaxy.",
+            2 => "How rich the planets are in resources, affecting market prices.",
+            3 => "How strong the AI crews grow, the cost of pirates, market margins and event damage.",
+            4 => "How long each quarter of a game lasts.",
+            5 => "Whether tied games go to overtime.",
+            6 => "How long a team can keep the ball without shooting.",
+            _ => "Single save without backups, the world ends when your crew goes bankrupt or is left without a team.",
+        };
+
+        let mut lines = vec![
+            Line::from(""),
+            Line::from("Choose how the galaxy is generated."),
+            Line::from(""),
+        ];
+        for (idx, (name, value)) in GALAXY_OPTIONS.iter().zip(values.iter()).enumerate() {
+            let style = if idx == self.galaxy_option_index {
+                UiStyle::current().selected
+            } else {
+                UiStyle::current().default
+            };
+            lines.push(Line::from(Span::styled(
+                format!("{:<20} ← {:^10} →", name, value),
+                style,
+            )));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(description));
+        lines.push(Line::from(""));
+        lines.push(Line::from(
+            "[Press ↑/↓ to select an option, ←/→ to change it, enter to confirm.]",
+        ));
+
+        frame.render_widget(Paragraph::new(lines).centered(), area);
+        frame.render_widget(default_block().title("Galaxy"), area);
+    }
+
     fn render_intro(&mut self, frame: &mut Frame, area: Rect) {
         let text = format!(
             "
@@ -280,7 +390,7 @@ impl NewTeamScreen {
             frame.render_widget(
                 Paragraph::new(format!(" {}", selected_ship)).block(
                     default_block()
-                        .border_style(UiStyle::OK)
+                        .border_style(UiStyle::current().ok)
                         .title("Choose spaceship model ↓/↑"),
                 ),
                 area,
@@ -295,7 +405,7 @@ impl NewTeamScreen {
                             ship,
                             format_satoshi(ship.cost())
                         ),
-                        UiStyle::DEFAULT,
+                        UiStyle::current().default,
                     )
                 })
                 .collect_vec();
@@ -304,7 +414,7 @@ impl NewTeamScreen {
             frame.render_stateful_widget(
                 list.block(
                     default_block()
-                        .border_style(UiStyle::DEFAULT)
+                        .border_style(UiStyle::current().default)
                         .title("Choose spaceship model ↓/↑"),
                 ),
                 area,
@@ -313,7 +423,7 @@ impl NewTeamScreen {
         } else {
             frame.render_widget(
                 default_block()
-                    .border_style(UiStyle::UNSELECTABLE)
+                    .border_style(UiStyle::current().unselectable)
                     .title("Choose spaceship model ↓/↑"),
                 area,
             );
@@ -326,7 +436,7 @@ impl NewTeamScreen {
             frame.render_widget(
                 Paragraph::new(format!(" {}", selected_jersey_style)).block(
                     default_block()
-                        .border_style(UiStyle::OK)
+                        .border_style(UiStyle::current().ok)
                         .title("Choose jersey style ↓/↑"),
                 ),
                 area,
@@ -335,14 +445,14 @@ impl NewTeamScreen {
             let options = self
                 .jersey_styles
                 .iter()
-                .map(|jersey_style| (format!("{}", jersey_style), UiStyle::DEFAULT))
+                .map(|jersey_style| (format!("{}", jersey_style), UiStyle::current().default))
                 .collect_vec();
 
             let list = selectable_list(options, &self.callback_registry);
             frame.render_stateful_widget(
                 list.block(
                     default_block()
-                        .border_style(UiStyle::DEFAULT)
+                        .border_style(UiStyle::current().default)
                         .title("Choose jersey style ↓/↑"),
                 ),
                 area,
@@ -351,7 +461,7 @@ impl NewTeamScreen {
         } else {
             frame.render_widget(
                 default_block()
-                    .border_style(UiStyle::UNSELECTABLE)
+                    .border_style(UiStyle::current().unselectable)
                     .title("Choose jersey style ↓/↑"),
                 area,
             );
@@ -392,11 +502,11 @@ impl NewTeamScreen {
 
     fn render_colors_selection(&self, frame: &mut Frame, area: Rect) {
         let border_style = if self.state > CreationState::ShipModel {
-            UiStyle::OK
+            UiStyle::current().ok
         } else if self.state >= CreationState::Jersey {
-            UiStyle::DEFAULT
+            UiStyle::current().default
         } else {
-            UiStyle::UNSELECTABLE
+            UiStyle::current().unselectable
         };
 
         let color_split = Layout::horizontal([
@@ -526,7 +636,7 @@ impl NewTeamScreen {
             frame.render_widget(
                 Paragraph::new(format!(" {}", selected_planet.name.clone())).block(
                     default_block()
-                        .border_style(UiStyle::OK)
+                        .border_style(UiStyle::current().ok)
                         .title("Choose planet ↓/↑"),
                 ),
                 area,
@@ -537,7 +647,7 @@ impl NewTeamScreen {
                 .iter()
                 .map(|&planet_id| {
                     let planet = world.get_planet_or_err(planet_id).unwrap();
-                    (planet.name.clone(), UiStyle::DEFAULT)
+                    (planet.name.clone(), UiStyle::current().default)
                 })
                 .collect_vec();
 
@@ -545,7 +655,7 @@ impl NewTeamScreen {
             frame.render_stateful_widget(
                 list.block(
                     default_block()
-                        .border_style(UiStyle::DEFAULT)
+                        .border_style(UiStyle::current().default)
                         .title("Choose planet ↓/↑"),
                 ),
                 area,
@@ -554,7 +664,7 @@ impl NewTeamScreen {
         } else {
             frame.render_widget(
                 default_block()
-                    .border_style(UiStyle::UNSELECTABLE)
+                    .border_style(UiStyle::current().unselectable)
                     .title("Choose planet ↓/↑"),
                 area,
             );
@@ -635,9 +745,9 @@ impl NewTeamScreen {
         );
 
         let style = if remaining_balance >= 0 {
-            UiStyle::OK
+            UiStyle::current().ok
         } else {
-            UiStyle::ERROR
+            UiStyle::current().error
         };
         frame.render_widget(
             Paragraph::new(text).block(default_block().border_style(style)),
@@ -653,7 +763,7 @@ impl NewTeamScreen {
                         "Select {} players",
                         self.max_players_selected() - self.selected_players.len(),
                     ))
-                    .style(UiStyle::UNSELECTABLE),
+                    .style(UiStyle::current().unselectable),
                 area,
             );
             return;
@@ -665,11 +775,11 @@ impl NewTeamScreen {
             .iter()
             .map(|&player_data| {
                 let player_id = player_data.0;
-                let mut style = UiStyle::DEFAULT;
+                let mut style = UiStyle::current().default;
                 if self.selected_players.contains(&player_id)
                     && self.state <= CreationState::Players
                 {
-                    style = UiStyle::OK;
+                    style = UiStyle::current().ok;
                 }
 
                 if self.state > CreationState::Players
@@ -699,9 +809,9 @@ impl NewTeamScreen {
 
         let list = selectable_list(options, &self.callback_registry);
         let block_style = if self.state > CreationState::Players {
-            UiStyle::OK
+            UiStyle::current().ok
         } else {
-            UiStyle::DEFAULT
+            UiStyle::current().default
         };
 
         let mut state = if self.state > CreationState::Players {
@@ -737,6 +847,7 @@ impl NewTeamScreen {
             self.tick,
             frame,
             world,
+            SkillVisibility::Full,
             area,
         );
     }
@@ -746,6 +857,7 @@ impl NewTeamScreen {
             Constraint::Min(1),
             Constraint::Length(4),
             Constraint::Length(3),
+            Constraint::Length(3),
             Constraint::Min(1),
         ])
         .split(area);
@@ -788,10 +900,11 @@ impl NewTeamScreen {
                 jersey_colors: self.get_team_colors(),
                 players: self.selected_players.clone(),
                 spaceship: self.selected_ship(),
+                campaign: self.campaign_mode,
             },
             Arc::clone(&self.callback_registry),
         )
-        .set_style(UiStyle::OK);
+        .set_style(UiStyle::current().ok);
         frame.render_widget(yes_button, button_split[1]);
 
         let no_button = Button::new(
@@ -799,9 +912,40 @@ impl NewTeamScreen {
             UiCallback::CancelGeneratePlayerTeam,
             Arc::clone(&self.callback_registry),
         )
-        .set_style(UiStyle::ERROR);
+        .set_style(UiStyle::current().error);
 
         frame.render_widget(no_button, button_split[2]);
+
+        let campaign_split = Layout::horizontal([
+            Constraint::Length(side_width),
+            Constraint::Length(24),
+            Constraint::Length(side_width),
+        ])
+        .split(split[3]);
+        let campaign_button = Button::new(
+            format!(
+                "Campaign: {}",
+                if self.campaign_mode { "on" } else { "off" }
+            )
+            .into(),
+            UiCallback::ToggleCampaignMode,
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            "Follow a storyline from scrapyard team to galactic champions.".into(),
+            hover_text_target(frame),
+        )
+        .set_hotkey(UiKey::CAMPAIGN_MODE)
+        .set_box_style(if self.campaign_mode {
+            UiStyle::current().ok
+        } else {
+            UiStyle::current().default
+        });
+        frame.render_widget(campaign_button, campaign_split[1]);
+    }
+
+    pub fn toggle_campaign_mode(&mut self) {
+        self.campaign_mode = !self.campaign_mode;
     }
 
     fn max_players_selected(&self) -> usize {
@@ -815,10 +959,26 @@ impl NewTeamScreen {
     }
 }
 
+fn cycle<T: IntoEnumIterator + PartialEq + Copy>(value: T, forward: bool) -> T {
+    let values = T::iter().collect_vec();
+    let idx = values.iter().position(|v| *v == value).unwrap_or_default();
+    let len = values.len();
+    if forward {
+        values[(idx + 1) % len]
+    } else {
+        values[(idx + len - 1) % len]
+    }
+}
+
 impl Screen for NewTeamScreen {
     fn update(&mut self, world: &World) -> AppResult<()> {
         self.tick += 1;
 
+        // The world is generated only after choosing the galaxy options.
+        if self.state == CreationState::Galaxy {
+            return Ok(());
+        }
+
         // If planets is empty, we initialize the list of planets and planet_players
         if self.planet_ids.len() == 0 {
             self.planet_ids = world
@@ -857,6 +1017,11 @@ impl Screen for NewTeamScreen {
         area: Rect,
         _debug_view: bool,
     ) -> AppResult<()> {
+        if self.state == CreationState::Galaxy {
+            self.render_galaxy_options(frame, area);
+            return Ok(());
+        }
+
         if self.planet_ids.len() == 0 {
             return Ok(());
         }
@@ -965,6 +1130,16 @@ impl Screen for NewTeamScreen {
             KeyCode::Down => self.previous_index(),
             _ => {
                 match self.state {
+                    CreationState::Galaxy => match key_event.code {
+                        KeyCode::Enter => {
+                            return Some(UiCallback::GenerateGalaxy {
+                                options: self.generation_options,
+                            });
+                        }
+                        KeyCode::Left => self.change_galaxy_option(false),
+                        KeyCode::Right => self.change_galaxy_option(true),
+                        _ => {}
+                    },
                     CreationState::TeamName => match key_event.code {
                         KeyCode::Enter => {
                             if !validate_textarea_input(
@@ -985,18 +1160,22 @@ impl Screen for NewTeamScreen {
                             self.team_name_textarea.delete_line_by_head();
                             self.team_name_textarea.set_yank_text(name);
                             self.team_name_textarea.paste();
-                            self.team_name_textarea.set_cursor_style(UiStyle::DEFAULT);
+                            self.team_name_textarea
+                                .set_cursor_style(UiStyle::current().default);
 
                             self.team_name_textarea.set_block(
-                                default_block().border_style(UiStyle::OK).title("Team name"),
+                                default_block()
+                                    .border_style(UiStyle::current().ok)
+                                    .title("Team name"),
                             );
                             self.ship_name_textarea.set_block(
                                 default_block()
-                                    .border_style(UiStyle::DEFAULT)
+                                    .border_style(UiStyle::current().default)
                                     .title("Ship name"),
                             );
 
-                            self.ship_name_textarea.set_cursor_style(UiStyle::SELECTED);
+                            self.ship_name_textarea
+                                .set_cursor_style(UiStyle::current().selected);
 
                             self.set_state(self.state.next());
                         }
@@ -1029,10 +1208,13 @@ impl Screen for NewTeamScreen {
                             self.ship_name_textarea.delete_line_by_head();
                             self.ship_name_textarea.set_yank_text(name);
                             self.ship_name_textarea.paste();
-                            self.ship_name_textarea.set_cursor_style(UiStyle::DEFAULT);
+                            self.ship_name_textarea
+                                .set_cursor_style(UiStyle::current().default);
 
                             self.ship_name_textarea.set_block(
-                                default_block().border_style(UiStyle::OK).title("Ship name"),
+                                default_block()
+                                    .border_style(UiStyle::current().ok)
+                                    .title("Ship name"),
                             );
                             self.set_state(self.state.next())
                         }
@@ -1040,16 +1222,18 @@ impl Screen for NewTeamScreen {
                             if self.ship_name_textarea.lines()[0].is_empty() {
                                 self.team_name_textarea.set_block(
                                     default_block()
-                                        .border_style(UiStyle::DEFAULT)
+                                        .border_style(UiStyle::current().default)
                                         .title("Team name"),
                                 );
                                 self.ship_name_textarea.set_block(
                                     default_block()
-                                        .border_style(UiStyle::UNSELECTABLE)
+                                        .border_style(UiStyle::current().unselectable)
                                         .title("Ship name"),
                                 );
-                                self.team_name_textarea.set_cursor_style(UiStyle::SELECTED);
-                                self.ship_name_textarea.set_cursor_style(UiStyle::DEFAULT);
+                                self.team_name_textarea
+                                    .set_cursor_style(UiStyle::current().selected);
+                                self.ship_name_textarea
+                                    .set_cursor_style(UiStyle::current().default);
 
                                 self.set_state(self.state.previous());
                             } else {
@@ -1075,10 +1259,11 @@ impl Screen for NewTeamScreen {
                         KeyCode::Backspace => {
                             self.ship_name_textarea.set_block(
                                 default_block()
-                                    .border_style(UiStyle::DEFAULT)
+                                    .border_style(UiStyle::current().default)
                                     .title("Ship name"),
                             );
-                            self.ship_name_textarea.set_cursor_style(UiStyle::SELECTED);
+                            self.ship_name_textarea
+                                .set_cursor_style(UiStyle::current().selected);
                             self.set_state(self.state.previous());
                         }
 
@@ -1168,6 +1353,7 @@ impl Screen for NewTeamScreen {
                                 jersey_colors: self.get_team_colors(),
                                 players: self.selected_players.clone(),
                                 spaceship: self.selected_ship().clone(),
+                                campaign: self.campaign_mode,
                             });
                         }
                         KeyCode::Backspace => {
@@ -1193,6 +1379,7 @@ impl Screen for NewTeamScreen {
 impl SplitPanel for NewTeamScreen {
     fn index(&self) -> usize {
         match self.state {
+            CreationState::Galaxy => self.galaxy_option_index,
             CreationState::Planet => self.planet_index,
             CreationState::Jersey => self.jersey_style_index,
             CreationState::ShipModel => self.spaceship_model_index,
@@ -1203,6 +1390,7 @@ impl SplitPanel for NewTeamScreen {
 
     fn max_index(&self) -> usize {
         match self.state {
+            CreationState::Galaxy => GALAXY_OPTIONS.len(),
             CreationState::Planet => self.planet_ids.len(),
             CreationState::Jersey => self.jersey_styles.len(),
             CreationState::ShipModel => SPACESHIP_MODELS.len(),
@@ -1217,6 +1405,9 @@ impl SplitPanel for NewTeamScreen {
 
     fn set_index(&mut self, index: usize) {
         match self.state {
+            CreationState::Galaxy => {
+                self.galaxy_option_index = index;
+            }
             CreationState::Planet => {
                 self.planet_index = index;
             }
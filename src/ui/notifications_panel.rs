@@ -0,0 +1,208 @@
+use super::button::Button;
+use super::clickable_list::ClickableListState;
+use super::constants::*;
+use super::traits::{Screen, SplitPanel};
+use super::ui_callback::{CallbackRegistry, UiCallback};
+use super::utils::hover_text_target;
+use super::widgets::{default_block, selectable_list};
+use crate::types::{AppResult, SystemTimeTick};
+use crate::world::notifications::{Notification, NotificationCategory};
+use crate::world::world::World;
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Layout, Margin},
+    prelude::Rect,
+    text::{Line, Span},
+    widgets::{Paragraph, Wrap},
+    Frame,
+};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Default)]
+pub struct NotificationsPanel {
+    index: usize,
+    filter: Option<NotificationCategory>,
+    // Most recent notifications first, already filtered.
+    notifications: Vec<Notification>,
+    callback_registry: Arc<Mutex<CallbackRegistry>>,
+}
+
+impl NotificationsPanel {
+    pub fn new(callback_registry: Arc<Mutex<CallbackRegistry>>) -> Self {
+        Self {
+            callback_registry,
+            ..Default::default()
+        }
+    }
+
+    pub fn cycle_filter(&mut self) {
+        self.filter = NotificationCategory::next_filter(self.filter);
+        self.index = 0;
+    }
+
+    fn filter_label(&self) -> String {
+        self.filter
+            .map(|category| category.to_string())
+            .unwrap_or("All".to_string())
+    }
+
+    fn render_notification_list(&self, frame: &mut Frame, area: Rect) {
+        let split = Layout::vertical([Constraint::Min(3), Constraint::Length(3)]).split(area);
+        frame.render_widget(
+            default_block().title(format!("Notifications ({}) ", self.filter_label())),
+            split[0],
+        );
+
+        let options = self
+            .notifications
+            .iter()
+            .map(|notification| {
+                (
+                    format!(
+                        " {:<20} {:<12} {}",
+                        notification.tick.formatted_as_date(),
+                        notification.category.to_string(),
+                        notification.message
+                    ),
+                    UiStyle::current().default,
+                )
+            })
+            .collect();
+
+        let list = selectable_list(options, &self.callback_registry);
+        frame.render_stateful_widget(
+            list,
+            split[0].inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+            &mut ClickableListState::default().with_selected(Some(self.index)),
+        );
+
+        let button_split =
+            Layout::horizontal([Constraint::Length(24), Constraint::Length(24)]).split(split[1]);
+        frame.render_widget(
+            Button::new(
+                format!("Filter: {}", self.filter_label()).into(),
+                UiCallback::CycleNotificationFilter,
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(
+                "Show only one category of notifications.".into(),
+                hover_text_target(frame),
+            )
+            .set_hotkey(UiKey::FILTER_NOTIFICATIONS),
+            button_split[0],
+        );
+
+        let mut clear_button = Button::new(
+            "Clear".into(),
+            UiCallback::ClearNotifications {
+                category: self.filter,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            format!(
+                "Clear {} notifications.",
+                self.filter_label().to_lowercase()
+            ),
+            hover_text_target(frame),
+        )
+        .set_hotkey(UiKey::CLEAR_NOTIFICATIONS);
+        if self.notifications.is_empty() {
+            clear_button.disable(Some("No notifications to clear".to_string()));
+        }
+        frame.render_widget(clear_button, button_split[1]);
+    }
+
+    fn render_notification_details(&self, frame: &mut Frame, area: Rect) {
+        frame.render_widget(default_block().title("Details"), area);
+        let notification = match self.notifications.get(self.index) {
+            Some(notification) => notification,
+            None => return,
+        };
+
+        let lines = vec![
+            Line::from(Span::styled(
+                notification.category.to_string(),
+                UiStyle::current().header,
+            )),
+            Line::from(""),
+            Line::from(format!(
+                "{:<10} {}",
+                "When",
+                notification.tick.formatted_as_date()
+            )),
+            Line::from(""),
+            Line::from(notification.message.clone()),
+        ];
+
+        frame.render_widget(
+            Paragraph::new(lines).wrap(Wrap { trim: true }),
+            area.inner(Margin {
+                horizontal: 2,
+                vertical: 1,
+            }),
+        );
+    }
+}
+
+impl Screen for NotificationsPanel {
+    fn update(&mut self, world: &World) -> AppResult<()> {
+        self.notifications = world
+            .notifications
+            .iter()
+            .rev()
+            .filter(|notification| {
+                self.filter
+                    .map_or(true, |category| notification.category == category)
+            })
+            .cloned()
+            .collect();
+        if self.index >= self.notifications.len() {
+            self.index = self.notifications.len().saturating_sub(1);
+        }
+        Ok(())
+    }
+
+    fn render(
+        &mut self,
+        frame: &mut Frame,
+        _world: &World,
+        area: Rect,
+        _debug_view: bool,
+    ) -> AppResult<()> {
+        let split = Layout::horizontal([Constraint::Min(72), Constraint::Length(48)]).split(area);
+        self.render_notification_list(frame, split[0]);
+        self.render_notification_details(frame, split[1]);
+        Ok(())
+    }
+
+    fn handle_key_events(
+        &mut self,
+        key_event: crossterm::event::KeyEvent,
+        _world: &World,
+    ) -> Option<UiCallback> {
+        match key_event.code {
+            KeyCode::Up => self.next_index(),
+            KeyCode::Down => self.previous_index(),
+            _ => {}
+        }
+        None
+    }
+}
+
+impl SplitPanel for NotificationsPanel {
+    fn index(&self) -> usize {
+        self.index
+    }
+
+    fn max_index(&self) -> usize {
+        self.notifications.len()
+    }
+
+    fn set_index(&mut self, index: usize) {
+        self.index = index;
+    }
+}
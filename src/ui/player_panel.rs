@@ -2,19 +2,27 @@ use super::button::Button;
 use super::clickable_list::ClickableListState;
 use super::constants::*;
 use super::gif_map::GifMap;
+use super::player_sort::PlayerSort;
+use super::search_box::SearchBox;
 use super::ui_callback::{CallbackRegistry, UiCallback};
 use super::utils::{format_satoshi, hover_text_target};
 use super::{
     constants::{UiKey, IMG_FRAME_WIDTH, LEFT_PANEL_WIDTH},
     traits::{Screen, SplitPanel},
-    widgets::{default_block, render_player_description, selectable_list},
+    widgets::{
+        default_block, render_player_description, searchable_list, selectable_list,
+        trade_fairness_line,
+    },
 };
-use crate::network::trade::Trade;
+use crate::network::trade::{trade_fairness, Trade};
 use crate::types::AppResult;
 use crate::{
-    types::{PlayerId, TeamId},
+    types::{KartoffelId, PlayerId, TeamId},
     world::{
+        academy::{self, ACADEMY_DAILY_FEE},
+        kartoffel::{Kartoffel, KARTOFFEL_SATIETY_PER_RUM, MAX_KARTOFFEL_SATIETY},
         player::Player,
+        scouting::{SkillVisibility, SCOUTING_COST},
         skill::Rated,
         types::{PlayerLocation, TeamLocation},
         world::World,
@@ -26,6 +34,7 @@ use ratatui::layout::Margin;
 use ratatui::{
     layout::{Constraint, Layout},
     prelude::Rect,
+    text::{Line, Span},
     widgets::Paragraph,
     Frame,
 };
@@ -39,6 +48,7 @@ pub enum PlayerView {
     FreePirates,
     Tradable,
     OwnTeam,
+    Kartoffeln,
 }
 
 impl PlayerView {
@@ -47,7 +57,8 @@ impl PlayerView {
             PlayerView::All => PlayerView::FreePirates,
             PlayerView::FreePirates => PlayerView::Tradable,
             PlayerView::Tradable => PlayerView::OwnTeam,
-            PlayerView::OwnTeam => PlayerView::All,
+            PlayerView::OwnTeam => PlayerView::Kartoffeln,
+            PlayerView::Kartoffeln => PlayerView::All,
         }
     }
 
@@ -106,7 +117,13 @@ impl PlayerView {
 
                 player_team_planet_id == own_team_planet_id
             }
-            PlayerView::OwnTeam => player.team.is_some() && player.team.unwrap() == own_team.id,
+            PlayerView::OwnTeam => {
+                player.team.is_some()
+                    && player.team.unwrap() == own_team.id
+                    && matches!(player.current_location, PlayerLocation::WithTeam)
+            }
+            // Kartoffeln are listed instead of players.
+            PlayerView::Kartoffeln => false,
         }
     }
 
@@ -116,6 +133,7 @@ impl PlayerView {
             PlayerView::FreePirates => "Free pirates".to_string(),
             PlayerView::Tradable => "Open for trade".to_string(),
             PlayerView::OwnTeam => "Own team".to_string(),
+            PlayerView::Kartoffeln => "Kartoffeln".to_string(),
         }
     }
 }
@@ -124,13 +142,19 @@ impl PlayerView {
 pub struct PlayerListPanel {
     pub index: usize,
     pub locked_player_id: Option<PlayerId>,
+    // Satoshis asked by an AI team to accept trading the (proposer, target) players.
+    pub trade_sweetener: Option<(PlayerId, PlayerId, u32)>,
     pub selected_player_id: PlayerId,
     pub selected_team_id: Option<TeamId>,
     pub all_players: Vec<PlayerId>,
     pub players: Vec<PlayerId>,
+    kartoffeln: Vec<KartoffelId>,
     own_team_id: TeamId,
     view: PlayerView,
     update_view: bool,
+    search: SearchBox,
+    players_sort: PlayerSort,
+    update_players_sort: bool,
     tick: usize,
     callback_registry: Arc<Mutex<CallbackRegistry>>,
     gif_map: Arc<Mutex<GifMap>>,
@@ -154,6 +178,13 @@ impl PlayerListPanel {
             Constraint::Length(3),
             Constraint::Length(3),
             Constraint::Length(3),
+            Constraint::Length(3),
+            // The search box does not apply to kartoffeln.
+            Constraint::Length(if self.view == PlayerView::Kartoffeln {
+                0
+            } else {
+                3
+            }),
             Constraint::Min(1),
         ])
         .split(area);
@@ -199,17 +230,68 @@ impl PlayerListPanel {
         )
         .set_hotkey(UiKey::CYCLE_VIEW)
         .set_hover_text("View your own team players.".into(), hover_text_target);
+
+        let mut filter_kartoffeln_button = Button::new(
+            format!("View: {}", PlayerView::Kartoffeln.to_string()).into(),
+            UiCallback::SetPlayerPanelView {
+                view: PlayerView::Kartoffeln,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hotkey(UiKey::CYCLE_VIEW)
+        .set_hover_text("View your kartoffel companions.".into(), hover_text_target);
         match self.view {
             PlayerView::All => filter_all_button.disable(None),
             PlayerView::FreePirates => filter_free_pirates_button.disable(None),
             PlayerView::Tradable => filter_tradable_button.disable(None),
             PlayerView::OwnTeam => filter_own_team_button.disable(None),
+            PlayerView::Kartoffeln => filter_kartoffeln_button.disable(None),
         }
 
         frame.render_widget(filter_all_button, split[0]);
         frame.render_widget(filter_free_pirates_button, split[1]);
         frame.render_widget(filter_tradable_button, split[2]);
         frame.render_widget(filter_own_team_button, split[3]);
+        frame.render_widget(filter_kartoffeln_button, split[4]);
+
+        if self.view == PlayerView::Kartoffeln {
+            if self.kartoffeln.len() > 0 {
+                let options = self
+                    .kartoffeln
+                    .iter()
+                    .filter_map(|&kartoffel_id| world.get_kartoffel(kartoffel_id))
+                    .map(|kartoffel| {
+                        let style = if kartoffel.assigned_to.is_some() {
+                            UiStyle::current().ok
+                        } else {
+                            UiStyle::current().default
+                        };
+                        (
+                            format!("{:<22} {}", kartoffel.name, kartoffel.rarity),
+                            style,
+                        )
+                    })
+                    .collect();
+                let list = selectable_list(options, &self.callback_registry);
+                frame.render_stateful_widget(
+                    list.block(default_block().title("Kartoffeln ↓/↑")),
+                    split[6],
+                    &mut ClickableListState::default().with_selected(Some(self.index)),
+                );
+            } else {
+                frame.render_widget(default_block().title("Kartoffeln"), split[6]);
+            }
+            return;
+        }
+
+        self.search.render(
+            frame,
+            split[5],
+            &format!(
+                "Search name, planet, >stars ({})",
+                UiKey::SEARCH.to_string()
+            ),
+        );
 
         if self.players.len() > 0 {
             let mut options = vec![];
@@ -219,11 +301,11 @@ impl PlayerListPanel {
                     continue;
                 }
                 let player = player.unwrap();
-                let mut style = UiStyle::DEFAULT;
+                let mut style = UiStyle::current().default;
                 if player.team.is_some() && player.team.unwrap() == world.own_team_id {
-                    style = UiStyle::OK;
+                    style = UiStyle::current().ok;
                 } else if player.peer_id.is_some() {
-                    style = UiStyle::NETWORK;
+                    style = UiStyle::current().network;
                 }
                 let full_name = player.info.full_name();
                 let name = if full_name.len() <= 2 * MAX_NAME_LENGTH + 2 {
@@ -232,17 +314,27 @@ impl PlayerListPanel {
                     player.info.shortened_name()
                 };
 
-                let text = format!("{:<26} {}", name, player.stars());
+                let stars = if world.skill_visibility(player) == SkillVisibility::Full {
+                    player.stars()
+                } else {
+                    "?????".to_string()
+                };
+                let text = format!("{:<26} {}", name, stars);
                 options.push((text, style));
             }
-            let list = selectable_list(options, &self.callback_registry);
+            let list = searchable_list(options, self.search.query(), &self.callback_registry);
             frame.render_stateful_widget(
-                list.block(default_block().title("Players ↓/↑")),
-                split[4],
+                list.block(default_block().title(format!(
+                    "Players ↓/↑ - {}{} ({})",
+                    self.players_sort.arrow(),
+                    self.players_sort.key,
+                    UiKey::CYCLE_SORT.to_string()
+                ))),
+                split[6],
                 &mut ClickableListState::default().with_selected(Some(self.index)),
             );
         } else {
-            frame.render_widget(default_block().title("Players"), split[4]);
+            frame.render_widget(default_block().title("Players"), split[6]);
         }
     }
 
@@ -293,6 +385,7 @@ impl PlayerListPanel {
             self.tick,
             frame,
             world,
+            world.skill_visibility(player),
             h_split[0],
         );
         self.render_buttons(
@@ -318,6 +411,7 @@ impl PlayerListPanel {
                 self.tick,
                 frame,
                 world,
+                world.skill_visibility(locked_player),
                 h_split[1],
             );
             self.render_buttons(
@@ -335,6 +429,145 @@ impl PlayerListPanel {
         Ok(())
     }
 
+    fn build_kartoffel_panel(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        let kartoffel = match self
+            .kartoffeln
+            .get(self.index)
+            .and_then(|&id| world.get_kartoffel(id))
+        {
+            Some(kartoffel) => kartoffel,
+            None => {
+                frame.render_widget(
+                    Paragraph::new(
+                        " No kartoffel yet! Explore around planets to find some companions.",
+                    ),
+                    area.inner(Margin {
+                        vertical: 1,
+                        horizontal: 1,
+                    }),
+                );
+                return Ok(());
+            }
+        };
+        let own_team = world.get_own_team()?;
+
+        let split = Layout::vertical([
+            Constraint::Length(11),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Min(0),
+        ])
+        .split(area.inner(Margin {
+            horizontal: 1,
+            vertical: 0,
+        }));
+        let width = 60;
+        let split = split
+            .iter()
+            .map(|rect| Rect {
+                width: rect.width.min(width),
+                ..*rect
+            })
+            .collect::<Vec<Rect>>();
+
+        let companion_name = |player_id: Option<PlayerId>| {
+            player_id
+                .and_then(|id| world.get_player(id))
+                .map(|player| player.info.shortened_name())
+                .unwrap_or("None".to_string())
+        };
+
+        let bonus = kartoffel.companion_bonus();
+        let lines = vec![
+            Line::from(Span::styled(
+                kartoffel.name.clone(),
+                UiStyle::current().header,
+            )),
+            Line::from(""),
+            Line::from(format!("{:<12} {}", "Rarity", kartoffel.rarity)),
+            Line::from(format!("{:<12} {}", "Focus", kartoffel.focus)),
+            Line::from(format!(
+                "{:<12} {:.0}/{:.0}",
+                "Satiety", kartoffel.satiety, MAX_KARTOFFEL_SATIETY
+            )),
+            Line::from(format!(
+                "{:<12} +{:.1} {} skills",
+                "Bonus", bonus.amount, bonus.focus
+            )),
+            Line::from(format!(
+                "{:<12} {}",
+                "Companion",
+                companion_name(kartoffel.assigned_to)
+            )),
+            Line::from(""),
+            Line::from("Well fed kartoffeln give a bigger bonus to their companion in games."),
+        ];
+        frame.render_widget(
+            Paragraph::new(lines).block(default_block().title("Kartoffel")),
+            split[0],
+        );
+
+        let hover_text_target = hover_text_target(frame);
+        let mut feed_button = Button::new(
+            "Feed (1 rum)".into(),
+            UiCallback::FeedKartoffel {
+                kartoffel_id: kartoffel.id,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            format!(
+                "Feed {} some rum. Satiety +{:.0}",
+                kartoffel.name, KARTOFFEL_SATIETY_PER_RUM
+            ),
+            hover_text_target,
+        )
+        .set_hotkey(UiKey::FEED_KARTOFFEL);
+        if let Err(err) = kartoffel.can_be_fed_by(own_team, world.last_tick_short_interval) {
+            feed_button.disable(Some(err.to_string()));
+        }
+        frame.render_widget(feed_button, split[1]);
+
+        let next_companion = self.next_companion(kartoffel, world);
+        let assign_text = match next_companion {
+            Some(_) => format!("Assign to {}", companion_name(next_companion)),
+            None => "Send to the hold".to_string(),
+        };
+        let mut assign_button = Button::new(
+            assign_text.into(),
+            UiCallback::AssignKartoffel {
+                kartoffel_id: kartoffel.id,
+                player_id: next_companion,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            "Cycle the crew member accompanied by the kartoffel.".into(),
+            hover_text_target,
+        )
+        .set_hotkey(UiKey::ASSIGN_KARTOFFEL);
+        if own_team.player_ids.is_empty() {
+            assign_button.disable(Some("No crew member".into()));
+        }
+        frame.render_widget(assign_button, split[2]);
+
+        Ok(())
+    }
+
+    // Cycles through the crew: no companion, first pirate, ..., last pirate, no companion.
+    fn next_companion(&self, kartoffel: &Kartoffel, world: &World) -> Option<PlayerId> {
+        let own_team = world.get_own_team().ok()?;
+        match kartoffel.assigned_to {
+            None => own_team.player_ids.first().copied(),
+            Some(player_id) => own_team
+                .player_ids
+                .iter()
+                .skip_while(|&&id| id != player_id)
+                .nth(1)
+                .copied(),
+        }
+    }
+
     fn render_buttons(
         &self,
         player: &Player,
@@ -349,6 +582,7 @@ impl PlayerListPanel {
             Constraint::Length(3), //team
             Constraint::Length(3), //Lock/Unlock
             Constraint::Length(3), //hire info for FA or optionally trade
+            Constraint::Length(3), //scouting for FA
             Constraint::Min(1),
         ])
         .split(area);
@@ -383,6 +617,22 @@ impl PlayerListPanel {
                 .set_hotkey(UiKey::GO_TO_TEAM_ALTERNATIVE);
                 frame.render_widget(button, buttons_split[0]);
             }
+            PlayerLocation::InAcademy { asteroid_id, .. } => {
+                let asteroid = world.get_planet_or_err(asteroid_id)?;
+                let button = Button::new(
+                    format!("Prospect - Academy on {}", asteroid.name).into(),
+                    UiCallback::GoToPlanetZoomIn {
+                        planet_id: asteroid_id,
+                    },
+                    Arc::clone(&self.callback_registry),
+                )
+                .set_hover_text(
+                    format!("Go to {}, where the prospect trains", asteroid.name),
+                    hover_text_target,
+                )
+                .set_hotkey(UiKey::GO_TO_PLANET);
+                frame.render_widget(button, buttons_split[0]);
+            }
         }
         let lock_button =
             if self.locked_player_id.is_some() && self.locked_player_id.unwrap() == player.id {
@@ -416,8 +666,8 @@ impl PlayerListPanel {
 
         // Add hire button for free pirates
         if player.team.is_none() {
-            let can_hire = own_team.can_hire_player(&player);
-            let hire_cost = player.hire_cost(own_team.reputation);
+            let hire_cost = world.hire_cost(own_team, player);
+            let can_hire = own_team.can_hire_player(&player, hire_cost);
 
             let mut button = Button::new(
                 format!("Hire -{}", format_satoshi(hire_cost)).into(),
@@ -436,6 +686,50 @@ impl PlayerListPanel {
             }
 
             frame.render_widget(button, buttons_split[2]);
+
+            if let PlayerLocation::OnPlanet { planet_id } = player.current_location {
+                if let SkillVisibility::Hidden = world.skill_visibility(player) {
+                    let planet = world.get_planet_or_err(planet_id)?;
+                    let mut button = Button::new(
+                        format!("Scout planet -{}", format_satoshi(SCOUTING_COST)).into(),
+                        UiCallback::ScoutPlanet { planet_id },
+                        Arc::clone(&self.callback_registry),
+                    )
+                    .set_hover_text(
+                        format!(
+                            "Get a report on the free pirates of {}. Accuracy depends on your captain's reputation",
+                            planet.name
+                        ),
+                        hover_text_target,
+                    )
+                    .set_hotkey(UiKey::SCOUT_PLANET);
+                    if let Err(err) = own_team.can_scout_planet(planet) {
+                        button.disable(Some(err.to_string()));
+                    }
+
+                    frame.render_widget(button, buttons_split[3]);
+                } else if academy::can_enroll(player).is_ok() {
+                    let mut button = Button::new(
+                        format!("Enroll -{}/day", format_satoshi(ACADEMY_DAILY_FEE)).into(),
+                        UiCallback::EnrollInAcademy {
+                            player_id: player.id,
+                        },
+                        Arc::clone(&self.callback_registry),
+                    )
+                    .set_hover_text(
+                        "Enroll the prospect in the academy on your asteroid, to develop outside the roster.".into(),
+                        hover_text_target,
+                    )
+                    .set_hotkey(UiKey::ENROLL_ACADEMY);
+                    if let Err(err) = own_team
+                        .can_enroll_in_academy(player, world.academy_prospects(own_team.id).len())
+                    {
+                        button.disable(Some(err.to_string()));
+                    }
+
+                    frame.render_widget(button, buttons_split[3]);
+                }
+            }
         }
         // or if a trade exists and player is part of it, add trade buttons
         else if let Some(trade) = open_trade {
@@ -444,8 +738,17 @@ impl PlayerListPanel {
             if player.id == self.selected_player_id {
                 let proposer_team = world
                     .get_team_or_err(proposer_player.team.expect("Player should have a team"))?;
+                let satoshi_text = match trade.extra_satoshis {
+                    0 => "".to_string(),
+                    x if x > 0 => format!(", receiving {}", format_satoshi(x as u32)),
+                    x => format!(", paying {}", format_satoshi(x.unsigned_abs() as u32)),
+                };
                 let mut button = Button::new(
-                    "Accept trade".into(),
+                    if trade.is_counter_offer {
+                        "Accept counter-offer".into()
+                    } else {
+                        "Accept trade".into()
+                    },
                     UiCallback::AcceptTrade {
                         trade: trade.clone(),
                     },
@@ -453,21 +756,31 @@ impl PlayerListPanel {
                 )
                 .set_hover_text(
                     format!(
-                        "Accept to trade {} for {}",
-                        target_player.info.shortened_name(),
-                        proposer_player.info.shortened_name(),
+                        "Accept to trade {} for {}{}",
+                        trade.requested_assets(),
+                        trade.offered_assets(),
+                        satoshi_text
                     ),
                     hover_text_target,
                 )
                 .set_hotkey(UiKey::ACCEPT_TRADE);
 
-                let can_trade =
-                    proposer_team.can_trade_players(proposer_player, target_player, own_team);
+                let can_trade = proposer_team
+                    .can_trade_players(proposer_player, target_player, own_team)
+                    .and_then(|_| trade.can_be_settled_by(own_team, proposer_team))
+                    .and_then(|_| own_team.can_pay_trade_satoshis(-trade.extra_satoshis));
 
                 if can_trade.is_err() {
                     button.disable(Some(format!("{}", can_trade.unwrap_err().to_string())));
                 }
                 frame.render_widget(button, buttons_split[2]);
+                frame.render_widget(
+                    Paragraph::new(trade_fairness_line(trade.fairness())),
+                    buttons_split[3].inner(Margin {
+                        horizontal: 0,
+                        vertical: 1,
+                    }),
+                );
             } else if player.id == self.locked_player_id.expect("One player should be locked") {
                 let button = Button::new(
                     "Decline trade".into(),
@@ -479,14 +792,39 @@ impl PlayerListPanel {
                 .set_hover_text(
                     format!(
                         "Decline to trade {} for {}",
-                        target_player.info.shortened_name(),
-                        proposer_player.info.shortened_name(),
+                        trade.requested_assets(),
+                        trade.offered_assets(),
                     ),
                     hover_text_target,
                 )
                 .set_hotkey(UiKey::DECLINE_TRADE);
 
                 frame.render_widget(button, buttons_split[2]);
+
+                let sweetener = trade.fair_sweetener();
+                let mut counter_button = Button::new(
+                    format!("Counter-offer +{}", format_satoshi(sweetener)).into(),
+                    UiCallback::CounterTrade {
+                        trade: trade.clone(),
+                    },
+                    Arc::clone(&self.callback_registry),
+                )
+                .set_hover_text(
+                    format!(
+                        "Decline and ask for {} on top of {}",
+                        format_satoshi(sweetener),
+                        proposer_player.info.shortened_name(),
+                    ),
+                    hover_text_target,
+                )
+                .set_hotkey(UiKey::COUNTER_TRADE);
+                if trade.is_counter_offer {
+                    counter_button.disable(Some("Counter-offers cannot be countered".into()));
+                } else if sweetener == 0 {
+                    counter_button.disable(Some("The offer is already fair".into()));
+                }
+
+                frame.render_widget(counter_button, buttons_split[3]);
             };
         }
         // or finally if either the selected or locked player are part of own_team (but not both)
@@ -502,11 +840,26 @@ impl PlayerListPanel {
                         .can_trade_players(proposer_player, target_player, target_team)
                         .is_ok()
                     {
+                        // Include the sweetener asked in the last counter-offer for these players.
+                        let extra_satoshis = match self.trade_sweetener {
+                            Some((proposer_id, target_id, sweetener))
+                                if proposer_id == proposer_player.id
+                                    && target_id == target_player.id =>
+                            {
+                                sweetener
+                            }
+                            _ => 0,
+                        };
                         let mut trade_button = Button::new(
-                            "Propose trade".into(),
+                            if extra_satoshis > 0 {
+                                format!("Propose trade +{}", format_satoshi(extra_satoshis)).into()
+                            } else {
+                                "Propose trade".into()
+                            },
                             UiCallback::CreateTradeProposal {
                                 proposer_player_id: proposer_player.id,
                                 target_player_id: target_player.id,
+                                extra_satoshis: extra_satoshis as i64,
                             },
                             Arc::clone(&self.callback_registry),
                         )
@@ -520,6 +873,10 @@ impl PlayerListPanel {
                         )
                         .set_hotkey(UiKey::CREATE_TRADE);
 
+                        if let Err(err) = own_team.can_pay_trade_satoshis(extra_satoshis as i64) {
+                            trade_button.disable(Some(err.to_string()));
+                        }
+
                         if own_team
                             .sent_trades
                             .get(&(proposer_player.id, target_player.id))
@@ -529,6 +886,17 @@ impl PlayerListPanel {
                         }
 
                         frame.render_widget(trade_button, buttons_split[2]);
+                        frame.render_widget(
+                            Paragraph::new(trade_fairness_line(trade_fairness(
+                                proposer_player,
+                                target_player,
+                                extra_satoshis as i64,
+                            ))),
+                            buttons_split[3].inner(Margin {
+                                horizontal: 0,
+                                vertical: 1,
+                            }),
+                        );
                     }
                 }
             }
@@ -545,42 +913,65 @@ impl PlayerListPanel {
     pub fn reset_view(&mut self) {
         self.set_view(PlayerView::All);
     }
+
+    pub fn set_players_sort(&mut self, players_sort: PlayerSort) {
+        self.players_sort = players_sort;
+        self.update_players_sort = true;
+    }
+
+    fn search_rule(&self, player: &Player, world: &World) -> bool {
+        let planet = world
+            .get_planet(player.info.home_planet_id)
+            .map_or("", |planet| planet.name.as_str());
+        // Hidden skills must not be revealed by the rating threshold.
+        let rating = if world.skill_visibility(player) == SkillVisibility::Full {
+            Some(player.rating())
+        } else {
+            None
+        };
+        self.search
+            .query()
+            .matches(&player.info.full_name(), planet, rating)
+    }
 }
 
 impl Screen for PlayerListPanel {
     fn update(&mut self, world: &World) -> AppResult<()> {
         self.tick += 1;
         self.own_team_id = world.own_team_id;
-        if world.dirty_ui || self.all_players.len() != world.players.len() {
+        if world.dirty_ui
+            || self.all_players.len() != world.players.len()
+            || self.update_players_sort
+        {
+            let own_team = world.get_own_team().ok();
             self.all_players = world.players.keys().into_iter().cloned().collect();
             self.all_players.sort_by(|a, b| {
                 let a = world.get_player(*a).unwrap();
                 let b = world.get_player(*b).unwrap();
-                if a.rating() == b.rating() {
-                    b.average_skill()
-                        .partial_cmp(&a.average_skill())
-                        .expect("Skill value should exist")
-                } else {
-                    b.rating().cmp(&a.rating())
-                }
+                self.players_sort.compare(a, b, own_team)
             });
+            self.update_players_sort = false;
             self.update_view = true;
         }
+        if let Ok(own_team) = world.get_own_team() {
+            self.kartoffeln = own_team.kartoffel_ids.clone();
+        }
+
         if self.update_view {
             self.players = self
                 .all_players
                 .iter()
                 .filter(|&&player_id| {
                     let player = world.get_player(player_id).unwrap();
-                    self.view.rule(player, &world)
+                    self.view.rule(player, &world) && self.search_rule(player, world)
                 })
                 .map(|&player_id| player_id)
                 .collect();
             self.update_view = false;
         }
 
-        if self.index >= self.players.len() && self.players.len() > 0 {
-            self.set_index(self.players.len() - 1);
+        if self.index >= self.max_index() && self.max_index() > 0 {
+            self.set_index(self.max_index() - 1);
         }
 
         if self.index < self.players.len() && self.players.len() > 0 {
@@ -632,18 +1023,42 @@ impl Screen for PlayerListPanel {
         ])
         .split(area);
         self.build_left_panel(frame, world, left_right_split[0]);
-        self.build_right_panel(frame, world, left_right_split[1])?;
+        if self.view == PlayerView::Kartoffeln {
+            self.build_kartoffel_panel(frame, world, left_right_split[1])?;
+        } else {
+            self.build_right_panel(frame, world, left_right_split[1])?;
+        }
         Ok(())
     }
 
     fn handle_key_events(
         &mut self,
         key_event: crossterm::event::KeyEvent,
-        _world: &World,
+        world: &World,
     ) -> Option<UiCallback> {
+        if self.search.is_active() {
+            match key_event.code {
+                KeyCode::Up => self.next_index(),
+                KeyCode::Down => self.previous_index(),
+                // Keep the filter and jump to the top result.
+                KeyCode::Enter => {
+                    self.search.deactivate();
+                    self.set_index(0);
+                }
+                _ => {
+                    if self.search.handle_key_event(key_event) {
+                        self.update_view = true;
+                        self.set_index(0);
+                    }
+                }
+            }
+            return None;
+        }
+
         match key_event.code {
             KeyCode::Up => self.next_index(),
             KeyCode::Down => self.previous_index(),
+            UiKey::SEARCH if self.view != PlayerView::Kartoffeln => self.search.activate(),
             UiKey::GO_TO_TEAM => {
                 if let Some(_) = self.selected_team_id.clone() {
                     return Some(UiCallback::GoToPlayerTeam {
@@ -656,11 +1071,35 @@ impl Screen for PlayerListPanel {
                     view: self.view.next(),
                 });
             }
+            UiKey::CYCLE_SORT if self.view != PlayerView::Kartoffeln => {
+                return Some(UiCallback::SortPlayerList {
+                    players_sort: self.players_sort.next(),
+                });
+            }
+            UiKey::FEED_KARTOFFEL if self.view == PlayerView::Kartoffeln => {
+                if let Some(&kartoffel_id) = self.kartoffeln.get(self.index) {
+                    return Some(UiCallback::FeedKartoffel { kartoffel_id });
+                }
+            }
+            UiKey::ASSIGN_KARTOFFEL if self.view == PlayerView::Kartoffeln => {
+                let kartoffel = self
+                    .kartoffeln
+                    .get(self.index)
+                    .and_then(|&id| world.get_kartoffel(id))?;
+                return Some(UiCallback::AssignKartoffel {
+                    kartoffel_id: kartoffel.id,
+                    player_id: self.next_companion(kartoffel, world),
+                });
+            }
 
             _ => {}
         }
         None
     }
+
+    fn is_capturing_input(&self) -> bool {
+        self.search.is_active()
+    }
 }
 
 impl SplitPanel for PlayerListPanel {
@@ -669,7 +1108,11 @@ impl SplitPanel for PlayerListPanel {
     }
 
     fn max_index(&self) -> usize {
-        self.players.len()
+        if self.view == PlayerView::Kartoffeln {
+            self.kartoffeln.len()
+        } else {
+            self.players.len()
+        }
     }
 
     fn set_index(&mut self, index: usize) {
@@ -0,0 +1,166 @@
+use crate::world::{
+    player::Player,
+    position::{GamePosition, Position},
+    skill::Rated,
+    team::Team,
+};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use strum::Display;
+use strum_macros::EnumIter;
+
+#[derive(
+    Debug, Default, Clone, Copy, Display, Serialize, Deserialize, PartialEq, Eq, Hash, EnumIter,
+)]
+pub enum PlayerSortKey {
+    Name,
+    #[default]
+    Overall,
+    Potential,
+    // Position in the own team lineup.
+    Current,
+    // Rating in the best position.
+    Best,
+    Role,
+    #[strum(to_string = "Crew bonus")]
+    CrewBonus,
+    Age,
+    Value,
+}
+
+impl PlayerSortKey {
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Name => Self::Overall,
+            Self::Overall => Self::Potential,
+            Self::Potential => Self::Current,
+            Self::Current => Self::Best,
+            Self::Best => Self::Role,
+            Self::Role => Self::CrewBonus,
+            Self::CrewBonus => Self::Age,
+            Self::Age => Self::Value,
+            Self::Value => Self::Name,
+        }
+    }
+
+    // Names read better from A to Z, everything else from the highest value.
+    fn default_descending(&self) -> bool {
+        !matches!(self, Self::Name | Self::Current | Self::Role)
+    }
+}
+
+// The first crew bonus granted by the player's role, 0 for the mozzi.
+fn crew_bonus(player: &Player) -> f32 {
+    match player.info.crew_role.bonuses().first() {
+        Some(bonus) => bonus.as_skill(player).unwrap_or_default(),
+        None => 0.0,
+    }
+}
+
+fn best_role_rating(player: &Player) -> f32 {
+    let skills = player.current_skill_array();
+    Position::best(skills).player_rating(skills)
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct PlayerSort {
+    pub key: PlayerSortKey,
+    pub descending: bool,
+}
+
+impl Default for PlayerSort {
+    fn default() -> Self {
+        Self::new(PlayerSortKey::default())
+    }
+}
+
+impl PlayerSort {
+    pub fn new(key: PlayerSortKey) -> Self {
+        Self {
+            key,
+            descending: key.default_descending(),
+        }
+    }
+
+    // Sorting again by the same key reverses the order.
+    pub fn by(&self, key: PlayerSortKey) -> Self {
+        if key == self.key {
+            Self {
+                key,
+                descending: !self.descending,
+            }
+        } else {
+            Self::new(key)
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        Self::new(self.key.next())
+    }
+
+    pub fn arrow(&self) -> &'static str {
+        if self.descending {
+            "↓"
+        } else {
+            "↑"
+        }
+    }
+
+    // Players not in the team are listed after the ones in the lineup
+    // when sorting by current position.
+    pub fn compare(&self, a: &Player, b: &Player, team: Option<&Team>) -> Ordering {
+        let lineup_index = |player: &Player| {
+            team.and_then(|team| team.player_ids.iter().position(|id| *id == player.id))
+                .unwrap_or(usize::MAX)
+        };
+
+        let ordering = match self.key {
+            PlayerSortKey::Name => a.info.full_name().cmp(&b.info.full_name()),
+            PlayerSortKey::Overall => a
+                .rating()
+                .cmp(&b.rating())
+                .then(a.average_skill().total_cmp(&b.average_skill())),
+            PlayerSortKey::Potential => a.potential.total_cmp(&b.potential),
+            PlayerSortKey::Current => lineup_index(a).cmp(&lineup_index(b)),
+            PlayerSortKey::Best => best_role_rating(a).total_cmp(&best_role_rating(b)),
+            PlayerSortKey::Role => (a.info.crew_role as u8).cmp(&(b.info.crew_role as u8)),
+            PlayerSortKey::CrewBonus => crew_bonus(a).total_cmp(&crew_bonus(b)),
+            PlayerSortKey::Age => a.info.age.total_cmp(&b.info.age),
+            PlayerSortKey::Value => a.bare_value().total_cmp(&b.bare_value()),
+        };
+
+        if self.descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PlayerSort, PlayerSortKey};
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_player_sort_cycle() {
+        let default_sort = PlayerSort::default();
+        assert_eq!(default_sort.key, PlayerSortKey::Overall);
+        assert!(default_sort.descending);
+
+        let sort = PlayerSort::new(PlayerSortKey::Age);
+        assert!(sort.descending);
+        assert!(!sort.by(PlayerSortKey::Age).descending);
+        assert_eq!(
+            sort.by(PlayerSortKey::Name),
+            PlayerSort::new(PlayerSortKey::Name)
+        );
+        assert!(!PlayerSort::new(PlayerSortKey::Name).descending);
+
+        let mut key = PlayerSortKey::default();
+        for _ in PlayerSortKey::iter() {
+            key = key.next();
+        }
+        assert_eq!(key, PlayerSortKey::default());
+    }
+}
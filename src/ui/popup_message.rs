@@ -1,17 +1,27 @@
 use super::button::Button;
 use super::constants::{UiKey, UiStyle, UiText};
 use super::gif_map::{self, TREASURE_GIF};
+use super::team_panel::{trade_lot, TRADABLE_RESOURCES};
 use super::ui_callback::{CallbackRegistry, UiCallback};
 use super::utils::{
-    hover_text_target, img_to_lines, input_from_key_event, validate_textarea_input,
+    format_satoshi, hover_text_target, img_to_lines, input_from_key_event, validate_textarea_input,
 };
-use super::widgets::default_block;
+use super::widgets::{default_block, trade_fairness_line};
 use crate::image::types::{Gif, PrintableGif};
+use crate::network::trade::{resources_value, value_fairness, TradeDraft};
 use crate::types::*;
 use crate::ui::gif_map::PORTAL_GIFS;
-use crate::world::{player::Player, resources::Resource, skill::Rated};
+use crate::world::{
+    hazards::TravelHazardKind,
+    player::Player,
+    resources::Resource,
+    salvage::{Derelict, DerelictChoice},
+    skill::Rated,
+    types::RenameTarget,
+};
 use anyhow::anyhow;
 use core::fmt::Debug;
+use crossterm::event::KeyCode;
 use ratatui::layout::{Margin, Rect};
 use ratatui::widgets::{Clear, Paragraph, Wrap};
 use ratatui::{
@@ -19,12 +29,39 @@ use ratatui::{
     Frame,
 };
 use std::sync::{Arc, Mutex};
+use strum::IntoEnumIterator;
 use strum_macros::Display;
 use tui_textarea::TextArea;
 
 const FRAME_DURATION_MILLIS: Tick = 150;
 const TREASURE_GIF_ANIMATION_DELAY: Tick = 450;
 
+// Resources that can be added to a bundle trade.
+const BUNDLE_RESOURCES: [Resource; 5] = [
+    Resource::SATOSHI,
+    TRADABLE_RESOURCES[0],
+    TRADABLE_RESOURCES[1],
+    TRADABLE_RESOURCES[2],
+    TRADABLE_RESOURCES[3],
+];
+
+// Names are trimmed and capitalized like at the team creation.
+fn capitalized_input(popup_input: &TextArea<'static>) -> String {
+    popup_input.lines()[0]
+        .trim()
+        .chars()
+        .enumerate()
+        .map(|(i, c)| if i == 0 { c.to_ascii_uppercase() } else { c })
+        .collect()
+}
+
+fn bundle_resource_step(resource: Resource) -> u32 {
+    match resource {
+        Resource::SATOSHI => 10_000,
+        _ => trade_lot(resource),
+    }
+}
+
 #[derive(Debug, Display, Clone, PartialEq)]
 pub enum PopupMessage {
     Error {
@@ -45,9 +82,19 @@ pub enum PopupMessage {
         player_id: PlayerId,
         tick: Tick,
     },
+    LocalChallenge {
+        team_name: String,
+        team_id: TeamId,
+        tick: Tick,
+    },
     AsteroidNameDialog {
         tick: Tick,
     },
+    RenameDialog {
+        target: RenameTarget,
+        current_name: String,
+        tick: Tick,
+    },
     PortalFound {
         player_name: String,
         portal_target: String,
@@ -56,6 +103,7 @@ pub enum PopupMessage {
     ExplorationResult {
         resources: ResourceMap,
         players: Vec<Player>,
+        derelict: Option<Derelict>,
         tick: Tick,
     },
     TeamLanded {
@@ -64,26 +112,52 @@ pub enum PopupMessage {
         planet_filename: String,
         tick: Tick,
     },
-    Tutorial {
-        index: usize,
+    // Candidates as (id, name, athletics rating, technical rating).
+    SelectPilot {
+        pilots: Vec<(PlayerId, String, u8, u8)>,
+        tick: Tick,
+    },
+    TravelHazard {
+        kind: TravelHazardKind,
+        tick: Tick,
+    },
+    // Candidates as (id, name, trade value) for both sides of the trade.
+    TradeBuilder {
+        draft: TradeDraft,
+        target_team_name: String,
+        own_players: Vec<(PlayerId, String, f32)>,
+        target_players: Vec<(PlayerId, String, f32)>,
+        error: Option<String>,
         tick: Tick,
     },
 }
 
 impl PopupMessage {
-    const MAX_TUTORIAL_PAGE: usize = 2;
     fn rect(&self, area: Rect) -> Rect {
         let (width, height) = match self {
             PopupMessage::AsteroidNameDialog { .. } => (54, 28),
+            PopupMessage::RenameDialog { .. } => (54, 18),
             PopupMessage::PortalFound { .. } => (54, 44),
-            PopupMessage::ExplorationResult { resources, .. } => {
+            PopupMessage::ExplorationResult {
+                resources,
+                derelict,
+                ..
+            } => {
+                let derelict_height = if derelict.is_some() { 6 } else { 0 };
                 if resources.value(&&Resource::GOLD) > 0 {
-                    (54, 26)
+                    (54, 26 + derelict_height)
                 } else {
-                    (54, 16)
+                    (54, 16 + derelict_height)
                 }
             }
             PopupMessage::TeamLanded { .. } => (54, 26),
+            PopupMessage::SelectPilot { pilots, .. } => (54, 12 + pilots.len() as u16),
+            PopupMessage::TravelHazard { .. } => (60, 14),
+            PopupMessage::TradeBuilder {
+                own_players,
+                target_players,
+                ..
+            } => (80, 22 + own_players.len().max(target_players.len()) as u16),
             _ => (48, 16),
         };
 
@@ -152,6 +226,24 @@ impl PopupMessage {
                 }
             }
 
+            PopupMessage::RenameDialog { target, .. } => {
+                if key_event.code == UiKey::YES_TO_DIALOG {
+                    if validate_textarea_input(popup_input, target.to_string()) {
+                        return Some(UiCallback::Rename {
+                            target: *target,
+                            name: capitalized_input(popup_input),
+                        });
+                    }
+                } else if key_event.code == UiKey::NO_TO_DIALOG {
+                    if popup_input.lines()[0].len() == 0 {
+                        return Some(UiCallback::CloseUiPopup);
+                    }
+                    popup_input.input(input_from_key_event(key_event));
+                } else {
+                    popup_input.input(input_from_key_event(key_event));
+                }
+            }
+
             PopupMessage::ReleasePlayer { player_id, .. } => {
                 if key_event.code == UiKey::YES_TO_DIALOG {
                     return Some(UiCallback::ConfirmReleasePlayer {
@@ -162,6 +254,14 @@ impl PopupMessage {
                 }
             }
 
+            PopupMessage::LocalChallenge { team_id, .. } => {
+                if key_event.code == UiKey::YES_TO_DIALOG {
+                    return Some(UiCallback::AcceptLocalChallenge { team_id: *team_id });
+                } else if key_event.code == UiKey::NO_TO_DIALOG {
+                    return Some(UiCallback::DeclineLocalChallenge { team_id: *team_id });
+                }
+            }
+
             PopupMessage::PromptQuit { .. } => {
                 if key_event.code == UiKey::YES_TO_DIALOG {
                     return Some(UiCallback::QuitGame);
@@ -170,17 +270,38 @@ impl PopupMessage {
                 }
             }
 
-            PopupMessage::Tutorial { index, .. } => {
-                if key_event.code == UiKey::YES_TO_DIALOG
-                    && *index == PopupMessage::MAX_TUTORIAL_PAGE
-                {
+            PopupMessage::SelectPilot { pilots, .. } => {
+                if key_event.code == UiKey::YES_TO_DIALOG && pilots.len() > 0 {
+                    return Some(UiCallback::StartSpaceAdventure {
+                        pilot_id: pilots[0].0,
+                    });
+                } else if key_event.code == UiKey::NO_TO_DIALOG {
                     return Some(UiCallback::CloseUiPopup);
-                } else if key_event.code == UiKey::YES_TO_DIALOG {
-                    return Some(UiCallback::PushTutorialPage { index: index + 1 });
+                }
+            }
+
+            // The crew must decide what to do with the derelict before moving on.
+            PopupMessage::ExplorationResult {
+                derelict: Some(_), ..
+            } => {
+                if key_event.code == UiKey::NO_TO_DIALOG {
+                    return Some(UiCallback::ResolveDerelict {
+                        choice: DerelictChoice::Leave,
+                    });
+                }
+            }
+
+            // The travel is halted until one of the choices is picked.
+            PopupMessage::TravelHazard { .. } => {}
+
+            PopupMessage::TradeBuilder { .. } => {
+                if key_event.code == UiKey::YES_TO_DIALOG {
+                    return Some(UiCallback::ProposeTradeBundle);
                 } else if key_event.code == UiKey::NO_TO_DIALOG {
                     return Some(UiCallback::CloseUiPopup);
                 }
             }
+
             _ => {
                 if key_event.code == UiKey::YES_TO_DIALOG || key_event.code == UiKey::NO_TO_DIALOG {
                     return Some(UiCallback::CloseUiPopup);
@@ -216,7 +337,7 @@ impl PopupMessage {
             PopupMessage::Ok { message, tick, .. } => {
                 frame.render_widget(
                     Paragraph::new(format!("Message: {}", tick.formatted_as_date()))
-                        .block(default_block().border_style(UiStyle::OK))
+                        .block(default_block().border_style(UiStyle::current().ok))
                         .centered(),
                     split[0],
                 );
@@ -236,7 +357,7 @@ impl PopupMessage {
                 )
                 .set_hover_text("Close the popup".into(), hover_text_target)
                 .set_hotkey(UiKey::YES_TO_DIALOG)
-                .set_box_style(UiStyle::OK)
+                .set_box_style(UiStyle::current().ok)
                 .set_layer(1);
 
                 frame.render_widget(
@@ -251,7 +372,7 @@ impl PopupMessage {
             PopupMessage::Error { message, tick } => {
                 frame.render_widget(
                     Paragraph::new(format!("Error: {}", tick.formatted_as_date()))
-                        .block(default_block().border_style(UiStyle::ERROR))
+                        .block(default_block().border_style(UiStyle::current().error))
                         .centered(),
                     split[0],
                 );
@@ -271,7 +392,7 @@ impl PopupMessage {
                 )
                 .set_hover_text("Close the popup".into(), hover_text_target)
                 .set_hotkey(UiKey::YES_TO_DIALOG)
-                .set_box_style(UiStyle::OK)
+                .set_box_style(UiStyle::current().ok)
                 .set_layer(1);
 
                 frame.render_widget(
@@ -290,7 +411,7 @@ impl PopupMessage {
             } => {
                 frame.render_widget(
                     Paragraph::new(format!("Attention!"))
-                        .block(default_block().border_style(UiStyle::NETWORK))
+                        .block(default_block().border_style(UiStyle::current().network))
                         .centered(),
                     split[0],
                 );
@@ -323,7 +444,7 @@ impl PopupMessage {
                     hover_text_target,
                 )
                 .set_hotkey(UiKey::YES_TO_DIALOG)
-                .set_box_style(UiStyle::OK)
+                .set_box_style(UiStyle::current().ok)
                 .set_layer(1);
 
                 frame.render_widget(confirm_button, buttons_split[0]);
@@ -335,25 +456,84 @@ impl PopupMessage {
                 )
                 .set_hover_text(format!("Don't release {}", player_name), hover_text_target)
                 .set_hotkey(UiKey::NO_TO_DIALOG)
-                .set_box_style(UiStyle::ERROR)
+                .set_box_style(UiStyle::current().error)
                 .set_layer(1);
 
                 frame.render_widget(no_button, buttons_split[1]);
             }
 
+            PopupMessage::LocalChallenge {
+                team_name,
+                team_id,
+                tick,
+            } => {
+                frame.render_widget(
+                    Paragraph::new(format!("Challenge: {}", tick.formatted_as_date()))
+                        .block(default_block().border_style(UiStyle::current().network))
+                        .centered(),
+                    split[0],
+                );
+                frame.render_widget(
+                    Paragraph::new(format!(
+                        "{} challenged your crew to a game. Do you accept?",
+                        team_name
+                    ))
+                    .centered()
+                    .wrap(Wrap { trim: true }),
+                    split[1].inner(Margin {
+                        horizontal: 1,
+                        vertical: 1,
+                    }),
+                );
+
+                let buttons_split =
+                    Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+                        .split(split[2]);
+
+                let accept_button = Button::new(
+                    UiText::YES.into(),
+                    UiCallback::AcceptLocalChallenge { team_id: *team_id },
+                    Arc::clone(&callback_registry),
+                )
+                .set_hover_text(
+                    format!("Accept the challenge from {}.", team_name),
+                    hover_text_target,
+                )
+                .set_hotkey(UiKey::YES_TO_DIALOG)
+                .set_box_style(UiStyle::current().ok)
+                .set_layer(1);
+
+                frame.render_widget(accept_button, buttons_split[0]);
+
+                let decline_button = Button::new(
+                    UiText::NO.into(),
+                    UiCallback::DeclineLocalChallenge { team_id: *team_id },
+                    Arc::clone(&callback_registry),
+                )
+                .set_hover_text(
+                    format!("Decline the challenge from {}.", team_name),
+                    hover_text_target,
+                )
+                .set_hotkey(UiKey::NO_TO_DIALOG)
+                .set_box_style(UiStyle::current().error)
+                .set_layer(1);
+
+                frame.render_widget(decline_button, buttons_split[1]);
+            }
+
             PopupMessage::PromptQuit {
                 during_space_adventure,
                 ..
             } => {
                 frame.render_widget(
                     Paragraph::new(format!("Attention!"))
-                        .block(default_block().border_style(UiStyle::NETWORK))
+                        .block(default_block().border_style(UiStyle::current().network))
                         .centered(),
                     split[0],
                 );
 
                 let text = if *during_space_adventure {
-                    format!("Are you sure you want to quit?\nYou will lose the whole cargo! Go back to the base first\n(Press '{}')", UiKey::SPACE_BACK_TO_BASE)
+                    format!("Are you sure you want to quit?\nThe space adventure will be resumed paused next time.\n(Press '{}' to go back to the base)", UiKey::SPACE_BACK_TO_BASE)
                 } else {
                     "Are you sure you want to quit?".to_string()
                 };
@@ -376,7 +556,7 @@ impl PopupMessage {
                 )
                 .set_hover_text(format!("Confirm quitting."), hover_text_target)
                 .set_hotkey(UiKey::YES_TO_DIALOG)
-                .set_box_style(UiStyle::OK)
+                .set_box_style(UiStyle::current().ok)
                 .set_layer(1);
 
                 frame.render_widget(confirm_button, buttons_split[0]);
@@ -391,16 +571,86 @@ impl PopupMessage {
                     hover_text_target,
                 )
                 .set_hotkey(UiKey::NO_TO_DIALOG)
-                .set_box_style(UiStyle::ERROR)
+                .set_box_style(UiStyle::current().error)
                 .set_layer(1);
 
                 frame.render_widget(no_button, buttons_split[1]);
             }
 
+            PopupMessage::SelectPilot { pilots, tick } => {
+                frame.render_widget(
+                    Paragraph::new(format!("Choose a pilot: {}", tick.formatted_as_date()))
+                        .block(default_block().border_style(UiStyle::current().ok))
+                        .centered(),
+                    split[0],
+                );
+
+                let pilots_split = Layout::vertical(
+                    [Constraint::Length(1)]
+                        .repeat(pilots.len() + 1)
+                        .into_iter()
+                        .chain([Constraint::Min(0)]),
+                )
+                .split(split[1].inner(Margin {
+                    horizontal: 1,
+                    vertical: 1,
+                }));
+
+                frame.render_widget(
+                    Paragraph::new("Athletics improve the handling, technical the charge rate."),
+                    pilots_split[0],
+                );
+
+                for (idx, (player_id, name, athletics, technical)) in pilots.iter().enumerate() {
+                    let mut button = Button::text(
+                        format!(
+                            "{:>2} {:<24} Ath {:>2} Tec {:>2}",
+                            (idx + 1) % 10,
+                            name,
+                            athletics,
+                            technical
+                        )
+                        .into(),
+                        UiCallback::StartSpaceAdventure {
+                            pilot_id: *player_id,
+                        },
+                        Arc::clone(&callback_registry),
+                    )
+                    .set_hover_text(
+                        format!("Start the space adventure with {} as pilot.", name),
+                        hover_text_target,
+                    )
+                    .set_layer(1);
+                    if let Some(digit) = char::from_digit((idx as u32 + 1) % 10, 10) {
+                        button = button.set_hotkey(KeyCode::Char(digit));
+                    }
+
+                    frame.render_widget(button, pilots_split[idx + 1]);
+                }
+
+                let no_button = Button::new(
+                    UiText::NO.into(),
+                    UiCallback::CloseUiPopup,
+                    Arc::clone(&callback_registry),
+                )
+                .set_hover_text("Stay on the planet.".into(), hover_text_target)
+                .set_hotkey(UiKey::NO_TO_DIALOG)
+                .set_box_style(UiStyle::current().error)
+                .set_layer(1);
+
+                frame.render_widget(
+                    no_button,
+                    split[2].inner(Margin {
+                        vertical: 0,
+                        horizontal: 8,
+                    }),
+                );
+            }
+
             PopupMessage::AsteroidNameDialog { tick } => {
                 frame.render_widget(
                     Paragraph::new(format!("Asteroid discovered: {}", tick.formatted_as_date()))
-                        .block(default_block().border_style(UiStyle::NETWORK))
+                        .block(default_block().border_style(UiStyle::current().network))
                         .centered(),
                     split[0],
                 );
@@ -434,10 +684,10 @@ impl PopupMessage {
 
                 frame.render_widget(Paragraph::new(asteroid_img).centered(), m_split[1]);
 
-                popup_input.set_cursor_style(UiStyle::SELECTED);
+                popup_input.set_cursor_style(UiStyle::current().selected);
                 popup_input.set_block(
                     default_block()
-                        .border_style(UiStyle::DEFAULT)
+                        .border_style(UiStyle::current().default)
                         .title("Asteroid name"),
                 );
 
@@ -470,7 +720,7 @@ impl PopupMessage {
                     hover_text_target,
                 )
                 .set_hotkey(UiKey::YES_TO_DIALOG)
-                .set_box_style(UiStyle::OK)
+                .set_box_style(UiStyle::current().ok)
                 .set_layer(1);
 
                 if !validate_textarea_input(popup_input, "Asteroid name".into()) {
@@ -486,7 +736,93 @@ impl PopupMessage {
                 )
                 .set_hover_text("Leave the asteroid alone!".into(), hover_text_target)
                 .set_hotkey(UiKey::NO_TO_DIALOG)
-                .set_box_style(UiStyle::ERROR)
+                .set_box_style(UiStyle::current().error)
+                .set_layer(1);
+
+                frame.render_widget(no_button, buttons_split[1]);
+            }
+
+            PopupMessage::RenameDialog {
+                target,
+                current_name,
+                tick,
+            } => {
+                frame.render_widget(
+                    Paragraph::new(format!("Rename: {}", tick.formatted_as_date()))
+                        .block(default_block().border_style(UiStyle::current().highlight))
+                        .centered(),
+                    split[0],
+                );
+
+                let m_split = Layout::vertical([
+                    Constraint::Min(0),
+                    Constraint::Length(3), //input
+                ])
+                .split(split[1]);
+
+                frame.render_widget(
+                    Paragraph::new(format!(
+                        "Choose a new {} for {}.\nIt costs {}.",
+                        target.to_string().to_lowercase(),
+                        current_name,
+                        format_satoshi(target.cost())
+                    ))
+                    .centered()
+                    .wrap(Wrap { trim: true }),
+                    m_split[0].inner(Margin {
+                        horizontal: 1,
+                        vertical: 1,
+                    }),
+                );
+
+                popup_input.set_cursor_style(UiStyle::current().selected);
+                popup_input.set_block(
+                    default_block()
+                        .border_style(UiStyle::current().default)
+                        .title(target.to_string()),
+                );
+                frame.render_widget(
+                    &popup_input.clone(),
+                    m_split[1].inner(Margin {
+                        horizontal: 1,
+                        vertical: 0,
+                    }),
+                );
+
+                let buttons_split =
+                    Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+                        .split(split[2]);
+
+                let mut ok_button = Button::new(
+                    UiText::YES.into(),
+                    UiCallback::Rename {
+                        target: *target,
+                        name: capitalized_input(popup_input),
+                    },
+                    Arc::clone(&callback_registry),
+                )
+                .set_hover_text(
+                    format!("Rename for {}", format_satoshi(target.cost())),
+                    hover_text_target,
+                )
+                .set_hotkey(UiKey::YES_TO_DIALOG)
+                .set_box_style(UiStyle::current().ok)
+                .set_layer(1);
+
+                if !validate_textarea_input(popup_input, target.to_string()) {
+                    ok_button.disable(None);
+                }
+
+                frame.render_widget(ok_button, buttons_split[0]);
+
+                let no_button = Button::new(
+                    UiText::NO.into(),
+                    UiCallback::CloseUiPopup,
+                    Arc::clone(&callback_registry),
+                )
+                .set_hover_text("Keep the current name.".into(), hover_text_target)
+                .set_hotkey(UiKey::NO_TO_DIALOG)
+                .set_box_style(UiStyle::current().error)
                 .set_layer(1);
 
                 frame.render_widget(no_button, buttons_split[1]);
@@ -499,7 +835,7 @@ impl PopupMessage {
             } => {
                 frame.render_widget(
                     Paragraph::new(format!("Portal: {}", tick.formatted_as_date()))
-                        .block(default_block().border_style(UiStyle::HIGHLIGHT))
+                        .block(default_block().border_style(UiStyle::current().highlight))
                         .centered(),
                     split[0],
                 );
@@ -549,7 +885,7 @@ impl PopupMessage {
                 )
                 .set_hover_text("Close the popup".into(), hover_text_target)
                 .set_hotkey(UiKey::YES_TO_DIALOG)
-                .set_box_style(UiStyle::OK)
+                .set_box_style(UiStyle::current().ok)
                 .set_layer(1);
 
                 frame.render_widget(
@@ -564,11 +900,12 @@ impl PopupMessage {
             PopupMessage::ExplorationResult {
                 resources,
                 players,
+                derelict,
                 tick,
             } => {
                 frame.render_widget(
                     Paragraph::new(format!("Exploration result: {}", tick.formatted_as_date()))
-                        .block(default_block().border_style(UiStyle::HIGHLIGHT))
+                        .block(default_block().border_style(UiStyle::current().highlight))
                         .centered(),
                     split[0],
                 );
@@ -585,9 +922,12 @@ impl PopupMessage {
                     0
                 };
 
+                let derelict_text_height = if derelict.is_some() { 6 } else { 0 };
+
                 let m_split = Layout::vertical([
                     Constraint::Min(3),
                     Constraint::Length(treasure_image_height),
+                    Constraint::Length(derelict_text_height),
                 ])
                 .split(split[1]);
 
@@ -647,23 +987,95 @@ impl PopupMessage {
                     );
                 }
 
-                let button = Button::new(
-                    UiText::YES.into(),
-                    UiCallback::CloseUiPopup,
-                    Arc::clone(&callback_registry),
-                )
-                .set_hover_text("Close the popup".into(), hover_text_target)
-                .set_hotkey(UiKey::YES_TO_DIALOG)
-                .set_box_style(UiStyle::OK)
-                .set_layer(1);
+                if let Some(derelict) = derelict {
+                    frame.render_widget(
+                        Paragraph::new(format!(
+                            "The crew spotted the wreck of the {} drifting nearby. What should we do with it?",
+                            derelict.ship_name
+                        ))
+                        .centered()
+                        .wrap(Wrap { trim: true }),
+                        m_split[2].inner(Margin {
+                            horizontal: 1,
+                            vertical: 1,
+                        }),
+                    );
 
+                    let choices = DerelictChoice::iter().collect::<Vec<_>>();
+                    let buttons_split = Layout::horizontal(
+                        [Constraint::Ratio(1, choices.len() as u32)].repeat(choices.len()),
+                    )
+                    .split(split[2]);
+                    for (idx, choice) in choices.into_iter().enumerate() {
+                        let mut button = Button::new(
+                            format!("{}:{}", idx + 1, choice).into(),
+                            UiCallback::ResolveDerelict { choice },
+                            Arc::clone(&callback_registry),
+                        )
+                        .set_hover_text(choice.description(), hover_text_target)
+                        .set_layer(1);
+                        if let Some(digit) = char::from_digit(idx as u32 + 1, 10) {
+                            button = button.set_hotkey(KeyCode::Char(digit));
+                        }
+                        frame.render_widget(button, buttons_split[idx]);
+                    }
+                } else {
+                    let button = Button::new(
+                        UiText::YES.into(),
+                        UiCallback::CloseUiPopup,
+                        Arc::clone(&callback_registry),
+                    )
+                    .set_hover_text("Close the popup".into(), hover_text_target)
+                    .set_hotkey(UiKey::YES_TO_DIALOG)
+                    .set_box_style(UiStyle::current().ok)
+                    .set_layer(1);
+
+                    frame.render_widget(
+                        button,
+                        split[2].inner(Margin {
+                            vertical: 0,
+                            horizontal: 8,
+                        }),
+                    );
+                }
+            }
+
+            PopupMessage::TravelHazard { kind, tick } => {
                 frame.render_widget(
-                    button,
-                    split[2].inner(Margin {
-                        vertical: 0,
-                        horizontal: 8,
+                    Paragraph::new(format!("{}: {}", kind, tick.formatted_as_date()))
+                        .block(default_block().border_style(UiStyle::current().warning))
+                        .centered(),
+                    split[0],
+                );
+
+                frame.render_widget(
+                    Paragraph::new(kind.description())
+                        .centered()
+                        .wrap(Wrap { trim: true }),
+                    split[1].inner(Margin {
+                        horizontal: 1,
+                        vertical: 1,
                     }),
                 );
+
+                let choices = kind.choices();
+                let buttons_split = Layout::horizontal(
+                    [Constraint::Ratio(1, choices.len() as u32)].repeat(choices.len()),
+                )
+                .split(split[2]);
+                for (idx, choice) in choices.into_iter().enumerate() {
+                    let mut button = Button::new(
+                        format!("{}:{}", idx + 1, choice).into(),
+                        UiCallback::ResolveTravelHazard { choice },
+                        Arc::clone(&callback_registry),
+                    )
+                    .set_hover_text(choice.description(), hover_text_target)
+                    .set_layer(1);
+                    if let Some(digit) = char::from_digit(idx as u32 + 1, 10) {
+                        button = button.set_hotkey(KeyCode::Char(digit));
+                    }
+                    frame.render_widget(button, buttons_split[idx]);
+                }
             }
 
             PopupMessage::TeamLanded {
@@ -674,7 +1086,7 @@ impl PopupMessage {
             } => {
                 frame.render_widget(
                     Paragraph::new(format!("Team landed: {}", tick.formatted_as_date()))
-                        .block(default_block().border_style(UiStyle::HIGHLIGHT))
+                        .block(default_block().border_style(UiStyle::current().highlight))
                         .centered(),
                     split[0],
                 );
@@ -721,7 +1133,7 @@ impl PopupMessage {
                 )
                 .set_hover_text("Close the popup".into(), hover_text_target)
                 .set_hotkey(UiKey::YES_TO_DIALOG)
-                .set_box_style(UiStyle::OK)
+                .set_box_style(UiStyle::current().ok)
                 .set_layer(1);
 
                 frame.render_widget(
@@ -733,64 +1145,230 @@ impl PopupMessage {
                 );
             }
 
-            PopupMessage::Tutorial { index, .. } => {
+            PopupMessage::TradeBuilder {
+                draft,
+                target_team_name,
+                own_players,
+                target_players,
+                error,
+                tick,
+            } => {
                 frame.render_widget(
                     Paragraph::new(format!(
-                        "Tutorial {}/{}",
-                        index + 1,
-                        PopupMessage::MAX_TUTORIAL_PAGE + 1
+                        "Trade with {}: {}",
+                        target_team_name,
+                        tick.formatted_as_date()
                     ))
-                    .block(default_block().border_style(UiStyle::NETWORK))
+                    .block(default_block().border_style(UiStyle::current().network))
                     .centered(),
                     split[0],
                 );
 
-                let message = match index {
-                    0 => "Hello pirate! This is your team page.\nHere you can check your pirates and ship and interact with the market.",
-                    1 => "To start, you can try to challenge another team to a game,\nor maybe explore around your planet to gather resources.",
-                    _ => "Have fun!"
-                };
+                let message_split = Layout::vertical([
+                    Constraint::Min(0),    // sides
+                    Constraint::Length(1), // fairness
+                    Constraint::Length(2), // error
+                ])
+                .split(split[1].inner(Margin {
+                    horizontal: 1,
+                    vertical: 1,
+                }));
 
-                frame.render_widget(
-                    Paragraph::new(message).centered().wrap(Wrap { trim: true }),
-                    split[1].inner(Margin {
+                let sides_split =
+                    Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+                        .split(message_split[0]);
+
+                let mut offered_value = 0.0;
+                let mut requested_value = 0.0;
+                for (is_proposer_side, players, selected_ids, resources, area) in [
+                    (
+                        true,
+                        own_players,
+                        &draft.proposer_player_ids,
+                        &draft.proposer_resources,
+                        sides_split[0],
+                    ),
+                    (
+                        false,
+                        target_players,
+                        &draft.target_player_ids,
+                        &draft.target_resources,
+                        sides_split[1],
+                    ),
+                ] {
+                    let side_split = Layout::vertical([
+                        Constraint::Length(1),                    // title
+                        Constraint::Length(players.len() as u16), // players
+                        Constraint::Length(1),
+                        Constraint::Length(BUNDLE_RESOURCES.len() as u16), // resources
+                        Constraint::Min(0),
+                    ])
+                    .split(area.inner(Margin {
                         horizontal: 1,
-                        vertical: 1,
-                    }),
+                        vertical: 0,
+                    }));
+
+                    let title = if is_proposer_side {
+                        "You give".to_string()
+                    } else {
+                        format!("{} gives", target_team_name)
+                    };
+                    frame.render_widget(
+                        Paragraph::new(title).style(UiStyle::current().highlight),
+                        side_split[0],
+                    );
+
+                    let players_split =
+                        Layout::vertical([Constraint::Length(1)].repeat(players.len()))
+                            .split(side_split[1]);
+                    for (idx, (player_id, name, value)) in players.iter().enumerate() {
+                        let is_selected = selected_ids.contains(player_id);
+                        if is_selected {
+                            if is_proposer_side {
+                                offered_value += value;
+                            } else {
+                                requested_value += value;
+                            }
+                        }
+                        let button = Button::text(
+                            format!(
+                                "{} {:<24} {:>5.1}",
+                                if is_selected { "●" } else { "○" },
+                                name,
+                                value
+                            )
+                            .into(),
+                            UiCallback::ToggleTradeBuilderPlayer {
+                                player_id: *player_id,
+                                is_proposer_side,
+                            },
+                            Arc::clone(&callback_registry),
+                        )
+                        .set_hover_text(
+                            format!(
+                                "{} {} to the trade. The first player picked leads the trade.",
+                                if is_selected { "Remove" } else { "Add" },
+                                name
+                            ),
+                            hover_text_target,
+                        )
+                        .set_layer(1);
+                        frame.render_widget(button, players_split[idx]);
+                    }
+
+                    if is_proposer_side {
+                        offered_value += resources_value(resources);
+                    } else {
+                        requested_value += resources_value(resources);
+                    }
+
+                    let resources_split =
+                        Layout::vertical([Constraint::Length(1)].repeat(BUNDLE_RESOURCES.len()))
+                            .split(side_split[3]);
+                    for (idx, resource) in BUNDLE_RESOURCES.iter().enumerate() {
+                        let row_split = Layout::horizontal([
+                            Constraint::Length(3),
+                            Constraint::Min(0),
+                            Constraint::Length(3),
+                        ])
+                        .split(resources_split[idx]);
+                        let step = bundle_resource_step(*resource);
+                        let amount = resources.value(resource);
+
+                        let mut decrease_button = Button::text(
+                            " - ".into(),
+                            UiCallback::UpdateTradeBuilderResource {
+                                resource: *resource,
+                                amount: -(step as i32),
+                                is_proposer_side,
+                            },
+                            Arc::clone(&callback_registry),
+                        )
+                        .set_hover_text(
+                            format!("Remove {} {} from the trade.", step, resource),
+                            hover_text_target,
+                        )
+                        .set_layer(1);
+                        if amount == 0 {
+                            decrease_button.disable(None);
+                        }
+                        frame.render_widget(decrease_button, row_split[0]);
+
+                        let text = if *resource == Resource::SATOSHI {
+                            format!("{:<8} {:>12}", resource, format_satoshi(amount))
+                        } else {
+                            format!("{:<8} {:>12}", resource, amount)
+                        };
+                        frame.render_widget(Paragraph::new(text).centered(), row_split[1]);
+
+                        let increase_button = Button::text(
+                            " + ".into(),
+                            UiCallback::UpdateTradeBuilderResource {
+                                resource: *resource,
+                                amount: step as i32,
+                                is_proposer_side,
+                            },
+                            Arc::clone(&callback_registry),
+                        )
+                        .set_hover_text(
+                            format!("Add {} {} to the trade.", step, resource),
+                            hover_text_target,
+                        )
+                        .set_layer(1);
+                        frame.render_widget(increase_button, row_split[2]);
+                    }
+                }
+
+                frame.render_widget(
+                    Paragraph::new(trade_fairness_line(value_fairness(
+                        offered_value,
+                        requested_value,
+                    )))
+                    .centered(),
+                    message_split[1],
                 );
 
-                let next_button = Button::new(
-                    "Next >>".into(),
-                    UiCallback::PushTutorialPage { index: index + 1 },
+                if let Some(error) = error {
+                    frame.render_widget(
+                        Paragraph::new(error.clone())
+                            .style(UiStyle::current().error)
+                            .centered()
+                            .wrap(Wrap { trim: true }),
+                        message_split[2],
+                    );
+                }
+
+                let buttons_split =
+                    Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+                        .split(split[2]);
+
+                let mut propose_button = Button::new(
+                    "Propose trade".into(),
+                    UiCallback::ProposeTradeBundle,
                     Arc::clone(&callback_registry),
                 )
-                .set_hover_text("Next tutorial".into(), hover_text_target)
+                .set_hover_text(
+                    format!("Send the trade offer to {}.", target_team_name),
+                    hover_text_target,
+                )
                 .set_hotkey(UiKey::YES_TO_DIALOG)
-                .set_box_style(UiStyle::OK)
+                .set_box_style(UiStyle::current().ok)
                 .set_layer(1);
+                if let Err(err) = draft.is_valid() {
+                    propose_button.disable(Some(err.to_string()));
+                }
+                frame.render_widget(propose_button, buttons_split[0]);
 
-                let close_button = Button::new(
-                    "Close".into(),
+                let cancel_button = Button::new(
+                    UiText::NO.into(),
                     UiCallback::CloseUiPopup,
                     Arc::clone(&callback_registry),
                 )
-                .set_hover_text("Skip the tutorial".into(), hover_text_target)
+                .set_hover_text("Discard the trade.".into(), hover_text_target)
                 .set_hotkey(UiKey::NO_TO_DIALOG)
-                .set_box_style(UiStyle::ERROR)
+                .set_box_style(UiStyle::current().error)
                 .set_layer(1);
-
-                match index {
-                    x if *x < PopupMessage::MAX_TUTORIAL_PAGE => {
-                        let buttons_split =
-                            Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
-                                .split(split[2]);
-                        frame.render_widget(next_button, buttons_split[0]);
-                        frame.render_widget(close_button, buttons_split[1]);
-                    }
-                    _ => {
-                        frame.render_widget(close_button, split[2]);
-                    }
-                }
+                frame.render_widget(cancel_button, buttons_split[1]);
             }
         }
         Ok(())
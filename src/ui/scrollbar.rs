@@ -0,0 +1,78 @@
+use super::{
+    constants::UiStyle,
+    ui_callback::{CallbackRegistry, UiCallback},
+};
+use crossterm::event::{MouseButton, MouseEventKind};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState, StatefulWidget},
+};
+use std::sync::{Arc, Mutex};
+
+// Maps a row of the scrollbar track to the item at the same relative position.
+fn track_index(row: u16, track_height: u16, items_length: usize) -> usize {
+    if track_height <= 1 || items_length == 0 {
+        return 0;
+    }
+    (row as usize * (items_length - 1)) / (track_height as usize - 1)
+}
+
+/// Renders a vertical scrollbar in a one column wide area.
+/// Clicking on the track or dragging along it selects the item
+/// at the corresponding position in the list.
+pub fn render_scrollbar(
+    area: Rect,
+    buf: &mut Buffer,
+    items_length: usize,
+    position: usize,
+    callback_registry: &Arc<Mutex<CallbackRegistry>>,
+) {
+    if area.is_empty() || items_length == 0 {
+        return;
+    }
+
+    let mut scrollbar_state = ScrollbarState::new(items_length).position(position);
+    Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .begin_symbol(None)
+        .end_symbol(None)
+        .track_style(UiStyle::current().unselectable)
+        .thumb_style(UiStyle::current().default)
+        .render(area, buf, &mut scrollbar_state);
+
+    // Mouse up is used rather than mouse down so that the track rows
+    // are not registered as focusable elements in keyboard-only mode.
+    let mut registry = callback_registry.lock().unwrap();
+    for row in 0..area.height {
+        let index = track_index(row, area.height, items_length);
+        let row_area = Rect {
+            y: area.y + row,
+            height: 1,
+            ..area
+        };
+        for event_kind in [
+            MouseEventKind::Drag(MouseButton::Left),
+            MouseEventKind::Up(MouseButton::Left),
+        ] {
+            registry.register_mouse_callback(
+                event_kind,
+                Some(row_area),
+                UiCallback::SetPanelIndex { index },
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::track_index;
+
+    #[test]
+    fn test_track_index() {
+        assert_eq!(track_index(0, 10, 100), 0);
+        assert_eq!(track_index(9, 10, 100), 99);
+        assert_eq!(track_index(3, 10, 4), 1);
+        assert_eq!(track_index(0, 1, 100), 0);
+        assert_eq!(track_index(5, 10, 0), 0);
+    }
+}
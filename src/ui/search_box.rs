@@ -0,0 +1,175 @@
+use super::{constants::UiStyle, utils::input_from_key_event, widgets::default_block};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{layout::Rect, Frame};
+use tui_textarea::{CursorMove, TextArea};
+
+// Lowercases a single char so that byte offsets can be mapped back to the original text.
+fn fold(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+// Byte range of the first case-insensitive occurrence of word in text.
+fn find_case_insensitive(text: &str, word: &str) -> Option<(usize, usize)> {
+    if word.is_empty() {
+        return None;
+    }
+
+    for (start, _) in text.char_indices() {
+        let mut text_chars = text[start..].char_indices();
+        let mut end = start;
+        let found = word.chars().all(|w| match text_chars.next() {
+            Some((offset, c)) if fold(c) == fold(w) => {
+                end = start + offset + c.len_utf8();
+                true
+            }
+            _ => false,
+        });
+        if found {
+            return Some((start, end));
+        }
+    }
+    None
+}
+
+/// Filter typed in a search box.
+/// Every word must appear in the entry name or planet, case insensitive,
+/// while a `>N` word keeps only entries rated at least N stars.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SearchQuery {
+    words: Vec<String>,
+    min_rating: Option<u8>,
+}
+
+impl SearchQuery {
+    pub fn parse(text: &str) -> Self {
+        let mut query = Self::default();
+        for word in text.split_whitespace() {
+            if let Some(stars) = word.strip_prefix('>') {
+                if let Ok(stars) = stars.parse::<f32>() {
+                    // Ratings go from 0 to 20, a full star spanning 4 points.
+                    query.min_rating = Some((stars * 4.0 - 1.0).clamp(0.0, 20.0).round() as u8);
+                    continue;
+                }
+            }
+            query.words.push(word.to_string());
+        }
+        query
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty() && self.min_rating.is_none()
+    }
+
+    // Entries with an unknown rating never pass a rating threshold.
+    pub fn matches(&self, name: &str, planet: &str, rating: Option<u8>) -> bool {
+        if let Some(min_rating) = self.min_rating {
+            if rating.map_or(true, |rating| rating < min_rating) {
+                return false;
+            }
+        }
+
+        self.words.iter().all(|word| {
+            find_case_insensitive(name, word).is_some()
+                || find_case_insensitive(planet, word).is_some()
+        })
+    }
+
+    // Byte range of the first word found in the text, used to highlight matches.
+    pub fn match_range(&self, text: &str) -> Option<(usize, usize)> {
+        self.words
+            .iter()
+            .find_map(|word| find_case_insensitive(text, word))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SearchBox {
+    textarea: TextArea<'static>,
+    query: SearchQuery,
+    active: bool,
+}
+
+impl SearchBox {
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn query(&self) -> &SearchQuery {
+        &self.query
+    }
+
+    pub fn activate(&mut self) {
+        self.active = true;
+    }
+
+    pub fn deactivate(&mut self) {
+        self.active = false;
+    }
+
+    // Returns true if the key changed the query.
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) -> bool {
+        match key_event.code {
+            KeyCode::Esc => {
+                self.textarea.move_cursor(CursorMove::End);
+                self.textarea.delete_line_by_head();
+                self.active = false;
+            }
+            // The search box is a single line.
+            KeyCode::Enter => return false,
+            _ => {
+                self.textarea.input(input_from_key_event(key_event));
+            }
+        }
+
+        let query = SearchQuery::parse(&self.textarea.lines()[0]);
+        if query == self.query {
+            return false;
+        }
+        self.query = query;
+        true
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, title: &str) {
+        let (border_style, cursor_style) = if self.active {
+            (UiStyle::current().selected, UiStyle::current().selected)
+        } else {
+            (UiStyle::current().default, UiStyle::current().default)
+        };
+        self.textarea.set_cursor_style(cursor_style);
+        self.textarea.set_block(
+            default_block()
+                .border_style(border_style)
+                .title(title.to_string()),
+        );
+        frame.render_widget(&self.textarea, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_case_insensitive, SearchQuery};
+
+    #[test]
+    fn test_search_query() {
+        let query = SearchQuery::parse("  rEbe >3 ");
+        assert_eq!(query.min_rating, Some(11));
+        assert!(query.matches("Rebels", "Earth", Some(12)));
+        assert!(!query.matches("Rebels", "Earth", Some(10)));
+        assert!(!query.matches("Rebels", "Earth", None));
+        assert!(!query.matches("Pirates", "Earth", Some(20)));
+        assert!(query.matches("Pirates", "Rebellion", Some(20)));
+        assert_eq!(query.match_range("The Rebels"), Some((4, 8)));
+
+        assert!(SearchQuery::parse("").is_empty());
+        assert!(SearchQuery::parse(">x").matches(">X-wing", "", None));
+        assert_eq!(SearchQuery::parse(">0").min_rating, Some(0));
+        assert_eq!(SearchQuery::parse(">5").min_rating, Some(19));
+    }
+
+    #[test]
+    fn test_find_case_insensitive() {
+        assert_eq!(find_case_insensitive("Ärger", "äR"), Some((0, 3)));
+        assert_eq!(find_case_insensitive("abc", "abcd"), None);
+        assert_eq!(find_case_insensitive("abc", ""), None);
+    }
+}
@@ -1,7 +1,9 @@
 use super::constants::BARS_LENGTH;
 use super::ui_callback::UiCallback;
 use super::utils::{big_text, img_to_lines};
-use super::widgets::{get_charge_spans, get_durability_spans, get_fuel_spans, get_storage_spans};
+use super::widgets::{
+    get_charge_spans, get_durability_spans, get_fuel_spans, get_oxygen_spans, get_storage_spans,
+};
 use super::{traits::Screen, ui_callback::CallbackRegistry};
 use crate::space_adventure::{PlayerControlled, ShooterState};
 use crate::types::AppResult;
@@ -22,11 +24,22 @@ const CONTROLS: [&'static str; 5] = [
     "╚═════╩═════╩═════╝   ╚═════╝            ╚═════╝            ╚═════╝             ",
 ];
 
+const PAUSED: [&'static str; 5] = [
+    "██████╗  █████╗ ██╗   ██╗███████╗███████╗██████╗ ",
+    "██╔══██╗██╔══██╗██║   ██║██╔════╝██╔════╝██╔══██╗",
+    "██████╔╝███████║██║   ██║███████╗█████╗  ██║  ██║",
+    "██╔═══╝ ██╔══██║██║   ██║╚════██║██╔══╝  ██║  ██║",
+    "██║     ██║  ██║╚██████╔╝███████║███████╗██████╔╝",
+];
+
 #[derive(Debug, Default)]
 pub struct SpaceScreen {
     tick: usize,
     entity_count: usize,
+    difficulty_level: u32,
+    is_paused: bool,
     controls: Paragraph<'static>,
+    paused: Paragraph<'static>,
     _callback_registry: Arc<Mutex<CallbackRegistry>>,
 }
 
@@ -34,6 +47,7 @@ impl SpaceScreen {
     pub fn new(_callback_registry: Arc<Mutex<CallbackRegistry>>) -> Self {
         Self {
             controls: big_text(&CONTROLS).left_aligned(),
+            paused: big_text(&PAUSED).centered(),
             _callback_registry,
             ..Default::default()
         }
@@ -46,6 +60,8 @@ impl Screen for SpaceScreen {
 
         if let Some(space_adventure) = &world.space_adventure {
             self.entity_count = space_adventure.entity_count();
+            self.difficulty_level = space_adventure.difficulty_level();
+            self.is_paused = space_adventure.is_paused();
         }
 
         Ok(())
@@ -71,15 +87,18 @@ impl Screen for SpaceScreen {
         frame.render_widget(Paragraph::new(space_img_lines).centered(), split[0]);
 
         let info_split = Layout::horizontal([
-            Constraint::Ratio(1, 4),
-            Constraint::Ratio(1, 4),
-            Constraint::Ratio(1, 4),
-            Constraint::Ratio(1, 4),
+            Constraint::Ratio(1, 5),
+            Constraint::Ratio(1, 5),
+            Constraint::Ratio(1, 5),
+            Constraint::Ratio(1, 5),
+            Constraint::Ratio(1, 5),
         ])
         .split(split[1]);
 
         if let Some(player) = space_adventure.get_player() {
-            let bars_length = (area.width as usize / 4 - 20).min(BARS_LENGTH);
+            let bars_length = (area.width as usize / 5)
+                .saturating_sub(20)
+                .min(BARS_LENGTH);
 
             let description: &dyn PlayerControlled = player
                 .as_trait_ref()
@@ -126,6 +145,15 @@ impl Screen for SpaceScreen {
                 )),
                 info_split[3],
             );
+
+            frame.render_widget(
+                Line::from(get_oxygen_spans(
+                    space_adventure.oxygen(),
+                    space_adventure.max_oxygen(),
+                    bars_length,
+                )),
+                info_split[4],
+            );
         }
         if space_adventure.is_starting() {
             let v_split =
@@ -134,6 +162,17 @@ impl Screen for SpaceScreen {
             frame.render_widget(&self.controls, v_split[1]);
         }
 
+        if space_adventure.is_paused() {
+            let v_split = Layout::vertical([
+                Constraint::Min(0),
+                Constraint::Length(5),
+                Constraint::Min(0),
+            ])
+            .split(split[0]);
+            frame.render_widget(Clear, v_split[1]);
+            frame.render_widget(&self.paused, v_split[1]);
+        }
+
         Ok(())
     }
 
@@ -150,11 +189,21 @@ impl Screen for SpaceScreen {
             UiKey::SPACE_MAIN => Some(UiCallback::SpaceMainButton),
             UiKey::SPACE_SECOND => Some(UiCallback::SpaceSecondButton),
             UiKey::SPACE_BACK_TO_BASE => Some(UiCallback::StopSpaceAdventure),
+            UiKey::SPACE_PAUSE => Some(UiCallback::ToggleSpaceAdventurePause),
             _ => None,
         };
     }
 
     fn footer_spans(&self) -> Vec<String> {
-        vec![format!(" Entity count {:<4} ", self.entity_count)]
+        vec![
+            format!(" Danger level {:<2} ", self.difficulty_level),
+            format!(" Entity count {:<4} ", self.entity_count),
+            format!(" {} ", UiKey::SPACE_PAUSE.to_string()),
+            if self.is_paused {
+                " Resume ".to_string()
+            } else {
+                " Pause ".to_string()
+            },
+        ]
     }
 }
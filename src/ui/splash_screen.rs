@@ -1,8 +1,9 @@
 use super::button::RadioButton;
 use super::gif_map::*;
 use super::ui_callback::{CallbackRegistry, UiCallback};
-use super::utils::big_text;
+use super::utils::{bar_string, big_text};
 use super::{
+    constants::{is_accessible_mode, UiStyle},
     traits::{Screen, SplitPanel},
     widgets::default_block,
 };
@@ -83,6 +84,14 @@ const TITLE: [&'static str; 13] = [
 ];
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+fn accessible_mode_text() -> String {
+    if is_accessible_mode() {
+        "Accessible: On ".to_string()
+    } else {
+        "Accessible: Off".to_string()
+    }
+}
+
 impl SplashScreen {
     pub fn new(
         store_prefix: &str,
@@ -107,6 +116,8 @@ impl SplashScreen {
         selection_text.push(continue_text);
         selection_text.push("New Game".to_string());
         selection_text.push("Music: On ".to_string());
+        selection_text.push(format!("Theme: {}", UiStyle::theme()));
+        selection_text.push(accessible_mode_text());
         selection_text.push("Quit".to_string());
 
         let quote = QUOTES
@@ -133,6 +144,8 @@ impl SplashScreen {
             0 => UiCallback::ContinueGame,
             1 => UiCallback::NewGame,
             2 => UiCallback::ToggleAudio,
+            3 => UiCallback::CycleUiTheme,
+            4 => UiCallback::ToggleAccessibleMode,
             _ => UiCallback::QuitGame,
         }
     }
@@ -150,6 +163,8 @@ impl Screen for SplashScreen {
         } else {
             "Music: Off".to_string()
         };
+        self.selection_text[3] = format!("Theme: {}", UiStyle::theme());
+        self.selection_text[4] = accessible_mode_text();
         Ok(())
     }
     fn render(
@@ -282,12 +297,29 @@ impl Screen for SplashScreen {
             frame.render_widget(button, selection_split[i]);
         }
 
-        frame.render_widget(
-            Paragraph::new(self.quote)
-                .wrap(Wrap { trim: true })
-                .block(default_block()),
-            split[4],
-        );
+        // While catching up, the quote gives way to the simulation progress.
+        if let Some(catch_up) = world.catch_up.as_ref() {
+            let progress = catch_up.progress(world.last_tick_short_interval);
+            let bars_length = (split[4].width as usize).saturating_sub(16).min(50);
+            let mut lines = vec![format!(
+                "Catching up {}",
+                bar_string((progress * bars_length as f32) as usize, bars_length)
+            )];
+            lines.push(catch_up.notable_events(world)?.join(" · "));
+            frame.render_widget(
+                Paragraph::new(lines.join("\n"))
+                    .centered()
+                    .block(default_block()),
+                split[4],
+            );
+        } else {
+            frame.render_widget(
+                Paragraph::new(self.quote)
+                    .wrap(Wrap { trim: true })
+                    .block(default_block()),
+                split[4],
+            );
+        }
         Ok(())
     }
 
@@ -316,8 +348,14 @@ impl Screen for SplashScreen {
                 2 => {
                     return Some(UiCallback::ToggleAudio);
                 }
-                //quit
                 3 => {
+                    return Some(UiCallback::CycleUiTheme);
+                }
+                4 => {
+                    return Some(UiCallback::ToggleAccessibleMode);
+                }
+                //quit
+                5 => {
                     return Some(UiCallback::QuitGame);
                 }
                 _ => {}
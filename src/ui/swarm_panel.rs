@@ -1,4 +1,5 @@
 use super::button::Button;
+use super::clipboard::ClipboardTarget;
 use super::constants::*;
 use super::ui_callback::{CallbackRegistry, UiCallback};
 use super::utils::{hover_text_target, SwarmPanelEvent};
@@ -7,13 +8,15 @@ use super::{
     utils::input_from_key_event,
     widgets::default_block,
 };
-use crate::network::types::TeamRanking;
+use crate::network::address_book::AddressBook;
+use crate::network::types::{LobbyEntry, TeamRanking};
 use crate::types::{AppResult, SystemTimeTick, TeamId, Tick};
 use crate::ui::constants::UiKey;
 use crate::world::constants::{MIN_PLAYERS_PER_GAME, SECONDS};
+use crate::world::elo::INITIAL_ELO_RATING;
 use crate::world::{skill::Rated, world::World};
 use core::fmt::Debug;
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use itertools::Itertools;
 use libp2p::PeerId;
 use ratatui::layout::Margin;
@@ -24,7 +27,7 @@ use ratatui::{
     widgets::{List, ListItem, Paragraph, Wrap},
     Frame,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
 use strum_macros::Display;
 use tui_textarea::{CursorMove, TextArea};
@@ -38,6 +41,9 @@ pub enum SwarmView {
     Requests,
     Log,
     TeamRanking,
+    Lobby,
+    Ladder,
+    DirectMessages,
 }
 
 impl SwarmView {
@@ -46,7 +52,10 @@ impl SwarmView {
             SwarmView::Chat => SwarmView::Requests,
             SwarmView::Requests => SwarmView::Log,
             SwarmView::Log => SwarmView::TeamRanking,
-            SwarmView::TeamRanking => SwarmView::Chat,
+            SwarmView::TeamRanking => SwarmView::Lobby,
+            SwarmView::Lobby => SwarmView::Ladder,
+            SwarmView::Ladder => SwarmView::DirectMessages,
+            SwarmView::DirectMessages => SwarmView::Chat,
         }
     }
 }
@@ -61,6 +70,11 @@ pub struct SwarmPanel {
     team_id_to_peer_id: HashMap<TeamId, PeerId>,
     peer_id_to_team_id: HashMap<PeerId, TeamId>,
     team_ranking: HashMap<TeamId, TeamRanking>,
+    lobby: HashMap<PeerId, (Tick, LobbyEntry)>,
+    direct_messages: HashMap<PeerId, Vec<SwarmPanelEvent>>,
+    unread_direct_messages: HashSet<PeerId>,
+    favorite_peers: HashSet<PeerId>,
+    blocked_peers: HashSet<PeerId>,
     callback_registry: Arc<Mutex<CallbackRegistry>>,
 }
 
@@ -92,6 +106,49 @@ impl SwarmPanel {
         }
     }
 
+    pub fn update_lobby_entry(&mut self, peer_id: PeerId, timestamp: Tick, entry: LobbyEntry) {
+        if let Some((current_timestamp, _)) = self.lobby.get(&peer_id) {
+            if *current_timestamp > timestamp {
+                return;
+            }
+        }
+        self.lobby.insert(peer_id, (timestamp, entry));
+    }
+
+    // Incoming messages have the sender peer_id set, outgoing ones have none.
+    pub fn push_direct_message_event(&mut self, peer_id: PeerId, event: SwarmPanelEvent) {
+        if event.peer_id.is_some() {
+            self.unread_direct_messages.insert(peer_id);
+        }
+        self.direct_messages.entry(peer_id).or_default().push(event);
+    }
+
+    pub fn update_address_book(&mut self, address_book: &AddressBook) {
+        self.favorite_peers = address_book
+            .entries
+            .iter()
+            .filter(|entry| entry.favorite)
+            .filter_map(|entry| entry.peer_id)
+            .collect();
+        self.blocked_peers = address_book.blocked.iter().copied().collect();
+    }
+
+    // Known peers sorted so that the selection index is stable across renders,
+    // with favorites on top.
+    fn peers(&self) -> Vec<(TeamId, PeerId)> {
+        self.team_id_to_peer_id
+            .iter()
+            .map(|(&team_id, &peer_id)| (team_id, peer_id))
+            .sorted_by_key(|(_, peer_id)| {
+                (!self.favorite_peers.contains(peer_id), peer_id.to_base58())
+            })
+            .collect_vec()
+    }
+
+    fn selected_peer(&self) -> Option<(TeamId, PeerId)> {
+        self.peers().get(self.index).copied()
+    }
+
     pub fn push_log_event(&mut self, event: SwarmPanelEvent) {
         if let Some(last_event) = self
             .events
@@ -134,10 +191,14 @@ impl SwarmPanel {
 
     pub fn remove_peer_id(&mut self, peer_id: &PeerId) {
         self.connected_peers.retain(|id| id != peer_id);
+        self.lobby.remove(peer_id);
     }
 
     fn build_left_panel(&mut self, frame: &mut Frame, world: &World, area: Rect) {
         let split = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
             Constraint::Length(3),
             Constraint::Length(3),
             Constraint::Length(3),
@@ -201,6 +262,45 @@ impl SwarmPanel {
             hover_text_target,
         );
 
+        let mut lobby_button = Button::new(
+            "View:Lobby".into(),
+            UiCallback::SetSwarmPanelView {
+                topic: SwarmView::Lobby,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hotkey(UiKey::CYCLE_VIEW)
+        .set_hover_text(
+            "View teams currently online and challenge them.".into(),
+            hover_text_target,
+        );
+
+        let mut ladder_button = Button::new(
+            "View:Ladder".into(),
+            UiCallback::SetSwarmPanelView {
+                topic: SwarmView::Ladder,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hotkey(UiKey::CYCLE_VIEW)
+        .set_hover_text(
+            "View the ladder of known peers, ranked by the rating earned in network games.".into(),
+            hover_text_target,
+        );
+
+        let mut direct_messages_button = Button::new(
+            "View:Direct messages".into(),
+            UiCallback::SetSwarmPanelView {
+                topic: SwarmView::DirectMessages,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hotkey(UiKey::CYCLE_VIEW)
+        .set_hover_text(
+            "View private messages with the peer selected in the list below.".into(),
+            hover_text_target,
+        );
+
         match self.view {
             SwarmView::Chat => {
                 chat_button.disable(None);
@@ -214,25 +314,52 @@ impl SwarmPanel {
             SwarmView::TeamRanking => {
                 team_ranking_button.disable(None);
             }
+            SwarmView::Lobby => {
+                lobby_button.disable(None);
+            }
+            SwarmView::Ladder => {
+                ladder_button.disable(None);
+            }
+            SwarmView::DirectMessages => {
+                direct_messages_button.disable(None);
+            }
         }
 
         frame.render_widget(chat_button, split[0]);
         frame.render_widget(requests_button, split[1]);
         frame.render_widget(log_button, split[2]);
         frame.render_widget(team_ranking_button, split[3]);
+        frame.render_widget(lobby_button, split[4]);
+        frame.render_widget(ladder_button, split[5]);
+        frame.render_widget(direct_messages_button, split[6]);
 
         let mut items: Vec<ListItem> = vec![];
 
-        for (&team_id, peer_id) in self.team_id_to_peer_id.iter() {
-            if let Ok(team) = world.get_team_or_err(team_id) {
-                let style = if self.connected_peers.contains(peer_id) {
-                    UiStyle::NETWORK
+        for (idx, (team_id, peer_id)) in self.peers().iter().enumerate() {
+            if let Ok(team) = world.get_team_or_err(*team_id) {
+                let mut style = if self.blocked_peers.contains(peer_id) {
+                    UiStyle::current().disconnected
+                } else if self.unread_direct_messages.contains(peer_id) {
+                    UiStyle::current().direct_message
+                } else if self.connected_peers.contains(peer_id) {
+                    UiStyle::current().network
                 } else {
-                    UiStyle::DISCONNECTED
+                    UiStyle::current().disconnected
+                };
+                if idx == self.index {
+                    style = style.patch(UiStyle::current().selected);
+                }
+                let mark = if self.favorite_peers.contains(peer_id) {
+                    "* "
+                } else if self.blocked_peers.contains(peer_id) {
+                    "x "
+                } else {
+                    ""
                 };
                 items.push(ListItem::new(Span::styled(
                     format!(
-                        "{} ({})",
+                        "{}{} ({})",
+                        mark,
                         team.name.clone(),
                         peer_id
                             .to_base58()
@@ -246,7 +373,7 @@ impl SwarmPanel {
             }
         }
         let list = List::new(items);
-        frame.render_widget(list.block(default_block().title("Peers")), split[4]);
+        frame.render_widget(list.block(default_block().title("Peers")), split[7]);
 
         let dial_button = Button::new(
             "Ping".into(),
@@ -254,7 +381,7 @@ impl SwarmPanel {
             Arc::clone(&self.callback_registry),
         );
 
-        frame.render_widget(dial_button, split[5]);
+        frame.render_widget(dial_button, split[8]);
     }
 
     fn build_challenge_list(
@@ -338,7 +465,7 @@ impl SwarmPanel {
                     },
                     Arc::clone(&self.callback_registry),
                 )
-                .set_box_style(UiStyle::OK)
+                .set_box_style(UiStyle::current().ok)
                 .set_hover_text(
                     format!("Accept the challenge from {} and start a game.", team.name),
                     hover_text_target,
@@ -354,7 +481,7 @@ impl SwarmPanel {
                     },
                     Arc::clone(&self.callback_registry),
                 )
-                .set_box_style(UiStyle::ERROR)
+                .set_box_style(UiStyle::current().error)
                 .set_hover_text(
                     format!("Decline the challenge from {}.", team.name),
                     hover_text_target,
@@ -433,7 +560,7 @@ impl SwarmPanel {
                     },
                     Arc::clone(&self.callback_registry),
                 )
-                .set_box_style(UiStyle::OK)
+                .set_box_style(UiStyle::current().ok)
                 .set_hover_text(
                     format!(
                         "Accept to trade {} for {}.",
@@ -453,7 +580,7 @@ impl SwarmPanel {
                     },
                     Arc::clone(&self.callback_registry),
                 )
-                .set_box_style(UiStyle::ERROR)
+                .set_box_style(UiStyle::current().error)
                 .set_hover_text(
                     format!(
                         "Decline to trade {} for {}.",
@@ -515,7 +642,7 @@ impl SwarmPanel {
             if world.get_team(team_id).is_some() {
                 frame.render_widget(
                     Button::no_box(
-                        Span::styled(text, UiStyle::NETWORK)
+                        Span::styled(text, UiStyle::current().network)
                             .into_left_aligned_line()
                             .into(),
                         UiCallback::GoToTeam { team_id },
@@ -528,15 +655,234 @@ impl SwarmPanel {
                         ),
                         hover_text_target,
                     ),
-                    // .set_hover_style(UiStyle::HIGHLIGHT),
+                    // .set_hover_style(UiStyle::current().highlight),
                     split[idx],
                 );
             } else {
-                frame.render_widget(Span::styled(text, UiStyle::DISCONNECTED), split[idx]);
+                frame.render_widget(
+                    Span::styled(text, UiStyle::current().disconnected),
+                    split[idx],
+                );
             };
         }
     }
 
+    fn render_ladder(&self, frame: &mut Frame, world: &World, area: Rect) {
+        frame.render_widget(default_block().title("Ladder"), area);
+        let hover_text_target = hover_text_target(frame);
+
+        // Known peers and the own team, ranked by ladder rating.
+        let teams = self
+            .team_id_to_peer_id
+            .keys()
+            .chain(std::iter::once(&world.own_team_id))
+            .unique()
+            .filter_map(|&team_id| world.get_team(team_id))
+            .sorted_by(|a, b| {
+                b.elo_rating()
+                    .cmp(&a.elo_rating())
+                    .then_with(|| a.name.cmp(&b.name))
+            })
+            .collect_vec();
+
+        let mut constraints = [Constraint::Length(1)].repeat(teams.len());
+        constraints.push(Constraint::Min(0));
+        let split = Layout::vertical(constraints).split(area.inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        }));
+
+        for (idx, team) in teams.iter().enumerate() {
+            let record = team.network_game_record;
+            let text = format!(
+                " {:>3}. {:<MAX_NAME_LENGTH$}  Rating {:>5}  {:12}",
+                idx + 1,
+                team.name,
+                team.elo
+                    .map(|elo| elo.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+                format!("W{}/L{}/D{}", record[0], record[1], record[2]),
+            );
+            let style = if team.id == world.own_team_id {
+                UiStyle::current().own_team
+            } else if self
+                .team_id_to_peer_id
+                .get(&team.id)
+                .is_some_and(|peer_id| self.connected_peers.contains(peer_id))
+            {
+                UiStyle::current().network
+            } else {
+                UiStyle::current().disconnected
+            };
+
+            frame.render_widget(
+                Button::no_box(
+                    Span::styled(text, style).into_left_aligned_line().into(),
+                    UiCallback::GoToTeam { team_id: team.id },
+                    Arc::clone(&self.callback_registry),
+                )
+                .set_hover_text(
+                    format!("Go to team {} (Rating {})", team.name, team.elo_rating()),
+                    hover_text_target,
+                ),
+                split[idx],
+            );
+        }
+    }
+
+    fn render_lobby(&self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
+        frame.render_widget(default_block().title("Lobby"), area);
+        let hover_text_target = hover_text_target(frame);
+        let own_team = world.get_own_team()?;
+
+        let entries = self
+            .lobby
+            .values()
+            .filter(|(_, entry)| entry.team_id != own_team.id)
+            .sorted_by(|(_, a), (_, b)| b.rating.total_cmp(&a.rating))
+            .collect_vec();
+
+        let mut constraints = [Constraint::Length(3)].repeat(entries.len());
+        constraints.push(Constraint::Min(0));
+        let split = Layout::vertical(constraints).split(area.inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        }));
+
+        for (idx, (timestamp, entry)) in entries.iter().enumerate() {
+            let line_split = Layout::horizontal([
+                Constraint::Length(MAX_NAME_LENGTH as u16 + 16),
+                Constraint::Length(32),
+                Constraint::Length(14),
+                Constraint::Length(14),
+                Constraint::Length(10),
+                Constraint::Min(0),
+            ])
+            .split(split[idx]);
+
+            let team = world.get_team(entry.team_id);
+            frame.render_widget(
+                Paragraph::new(vec![
+                    Line::from(Span::styled(
+                        entry.team_name.clone(),
+                        UiStyle::current().network,
+                    )),
+                    Line::from(format!(
+                        "{} {}",
+                        entry.rating.stars(),
+                        timestamp.formatted_as_time()
+                    )),
+                ]),
+                line_split[0],
+            );
+            frame.render_widget(
+                Paragraph::new(vec![
+                    Line::from(entry.location.clone()),
+                    Line::from(format!(
+                        "Ladder rating {}",
+                        entry.elo.unwrap_or(INITIAL_ELO_RATING)
+                    )),
+                ]),
+                line_split[1],
+            );
+            let (status, style) = if entry.accepts_challenges {
+                ("Available", UiStyle::current().ok)
+            } else {
+                ("Busy", UiStyle::current().warning)
+            };
+            frame.render_widget(Span::styled(status, style), line_split[2]);
+
+            let mut challenge_button = Button::new(
+                "Challenge".into(),
+                UiCallback::ChallengeTeam {
+                    team_id: entry.team_id,
+                },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(
+                format!("Challenge {} to a game.", entry.team_name),
+                hover_text_target,
+            );
+            match team {
+                Some(team) => {
+                    if !entry.accepts_challenges {
+                        challenge_button.disable(Some(format!("{} is busy", entry.team_name)));
+                    } else if let Err(err) = own_team.can_challenge_team(team) {
+                        challenge_button.disable(Some(err.to_string()));
+                    }
+                }
+                None => challenge_button.disable(Some("Team data not received yet".into())),
+            }
+            frame.render_widget(challenge_button, line_split[3]);
+
+            let mut trade_button = Button::new(
+                "Trade".into(),
+                UiCallback::GoToTeam {
+                    team_id: entry.team_id,
+                },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(
+                format!("Browse the crew of {} to propose a trade.", entry.team_name),
+                hover_text_target,
+            );
+            if team.is_none() {
+                trade_button.disable(Some("Team data not received yet".into()));
+            }
+            frame.render_widget(trade_button, line_split[4]);
+        }
+
+        Ok(())
+    }
+
+    fn render_direct_messages(&mut self, frame: &mut Frame, world: &World, area: Rect) {
+        let (team_id, peer_id) = match self.selected_peer() {
+            Some(peer) => peer,
+            None => {
+                frame.render_widget(
+                    Paragraph::new("Select a peer to start a conversation.")
+                        .block(default_block().title("Direct messages")),
+                    area,
+                );
+                return;
+            }
+        };
+        self.unread_direct_messages.remove(&peer_id);
+
+        let team_name = world
+            .get_team(team_id)
+            .map(|team| team.name.clone())
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        let mut items = vec![];
+        for event in self
+            .direct_messages
+            .get(&peer_id)
+            .map(|events| events.iter().rev().collect_vec())
+            .unwrap_or_default()
+        {
+            let (from, style) = match event.peer_id {
+                Some(_) => (team_name.as_str(), UiStyle::current().direct_message),
+                None => ("You", UiStyle::current().own_team),
+            };
+            items.push(Line::from(vec![
+                Span::styled(
+                    format!("[{}] ", event.timestamp.formatted_as_time()),
+                    UiStyle::current().highlight,
+                ),
+                Span::styled(format!("{}: ", from), style),
+                Span::raw(event.text.clone()),
+            ]));
+        }
+
+        frame.render_widget(
+            Paragraph::new(items)
+                .wrap(Wrap { trim: true })
+                .block(default_block().title(format!("Direct messages with {}", team_name))),
+            area,
+        );
+    }
+
     fn build_right_panel(&mut self, frame: &mut Frame, world: &World, area: Rect) -> AppResult<()> {
         let split = Layout::vertical([Constraint::Min(1), Constraint::Length(3)]).split(area);
 
@@ -563,6 +909,21 @@ impl SwarmPanel {
             return Ok(());
         }
 
+        if self.view == SwarmView::Lobby {
+            self.render_lobby(frame, world, split[0])?;
+            return Ok(());
+        }
+
+        if self.view == SwarmView::Ladder {
+            self.render_ladder(frame, world, split[0]);
+            return Ok(());
+        }
+
+        if self.view == SwarmView::DirectMessages {
+            self.render_direct_messages(frame, world, split[0]);
+            return Ok(());
+        }
+
         let mut items = vec![];
         for event in self
             .events
@@ -586,9 +947,9 @@ impl SwarmPanel {
                     items.push(Line::from(vec![
                         Span::styled(
                             format!("[{}] ", event.timestamp.formatted_as_time()),
-                            UiStyle::HIGHLIGHT,
+                            UiStyle::current().highlight,
                         ),
-                        Span::styled(format!("{}: ", from), UiStyle::NETWORK),
+                        Span::styled(format!("{}: ", from), UiStyle::current().network),
                         Span::raw(event.text.clone()),
                     ]));
                 }
@@ -601,9 +962,9 @@ impl SwarmPanel {
                     items.push(Line::from(vec![
                         Span::styled(
                             format!("[{}] ", event.timestamp.formatted_as_time()),
-                            UiStyle::HIGHLIGHT,
+                            UiStyle::current().highlight,
                         ),
-                        Span::styled(format!("{own_message}: "), UiStyle::OWN_TEAM),
+                        Span::styled(format!("{own_message}: "), UiStyle::current().own_team),
                         Span::raw(event.text.clone()),
                     ]));
                 }
@@ -619,6 +980,10 @@ impl SwarmPanel {
         Ok(())
     }
 
+    pub fn insert_text(&mut self, text: &str) {
+        self.textarea.insert_str(text);
+    }
+
     pub fn set_view(&mut self, topic: SwarmView) {
         log::info!("Current view {}", self.view);
         self.view = topic;
@@ -656,6 +1021,9 @@ impl Screen for SwarmPanel {
                     topic: self.view.next(),
                 });
             }
+            KeyCode::Char('v') if key_event.modifiers == KeyModifiers::CONTROL => {
+                return Some(UiCallback::PasteFromClipboard);
+            }
             KeyCode::Enter => {
                 let lines: Vec<String> = self
                     .textarea
@@ -671,7 +1039,50 @@ impl Screen for SwarmPanel {
 
                 match command {
                     "/dial" => {
-                        return Some(UiCallback::DialSeed);
+                        // Without a target, the default seed node is dialed.
+                        let target = split_input.clone().skip(1).collect::<Vec<_>>().join("");
+                        if target.is_empty() {
+                            return Some(UiCallback::DialSeed);
+                        }
+                        return Some(UiCallback::DialAddress { target });
+                    }
+                    "/book" => {
+                        return Some(UiCallback::ShowAddressBook);
+                    }
+                    "/fav" | "/unfav" | "/block" | "/unblock" => {
+                        // Acts on the given peer ID, or on the selected peer.
+                        let peer_id = match split_input.clone().nth(1) {
+                            Some(arg) => match arg.parse::<PeerId>() {
+                                Ok(peer_id) => peer_id,
+                                Err(_) => {
+                                    self.push_log_event(SwarmPanelEvent {
+                                        timestamp: Tick::now(),
+                                        peer_id: None,
+                                        text: format!("Invalid peer ID: {}", arg),
+                                    });
+                                    return None;
+                                }
+                            },
+                            None => self.selected_peer()?.1,
+                        };
+                        return Some(match command {
+                            "/fav" => UiCallback::SetPeerFavorite {
+                                peer_id,
+                                favorite: true,
+                            },
+                            "/unfav" => UiCallback::SetPeerFavorite {
+                                peer_id,
+                                favorite: false,
+                            },
+                            "/block" => UiCallback::SetPeerBlocked {
+                                peer_id,
+                                blocked: true,
+                            },
+                            _ => UiCallback::SetPeerBlocked {
+                                peer_id,
+                                blocked: false,
+                            },
+                        });
                     }
                     "/sync" => {
                         return Some(UiCallback::Sync);
@@ -679,21 +1090,47 @@ impl Screen for SwarmPanel {
                     "/clear" => {
                         self.events.clear();
                     }
+                    "/copy" => {
+                        let arg = split_input.clone().nth(1).unwrap_or("peer");
+                        match ClipboardTarget::from_command_arg(arg) {
+                            Some(target) => {
+                                return Some(UiCallback::CopyToClipboard { target });
+                            }
+                            None => self.push_log_event(SwarmPanelEvent {
+                                timestamp: Tick::now(),
+                                peer_id: None,
+                                text: format!("Cannot copy {}: use peer, seed or team", arg),
+                            }),
+                        }
+                    }
+                    "/cheer" => {
+                        let text = split_input.skip(1).collect::<Vec<&str>>().join(" ");
+                        return Some(UiCallback::SendCrowdChatter { text });
+                    }
 
                     "/help" => {
                         self.push_log_event(SwarmPanelEvent {
                             timestamp: Tick::now(),
                             peer_id: None,
-                            text: "/Commands:\n/dial <Option<ip_address>>\n/sync\n/clear"
+                            text: "/Commands:\n/dial <Option<address, ip[:port] or peer ID>>\n/book (address book)\n/fav, /unfav, /block, /unblock <Option<peer ID>> (selected peer by default)\n/sync\n/clear\n/copy <peer|seed|team>\nCtrl+V to paste\n/cheer <text> (crowd chatter for the game selected in the games panel)"
                                 .to_string(),
                         });
                     }
                     _ => {
-                        self.push_chat_event(SwarmPanelEvent {
+                        let event = SwarmPanelEvent {
                             timestamp: Tick::now(),
                             peer_id: None,
                             text: lines[0].clone(),
-                        });
+                        };
+                        if self.view == SwarmView::DirectMessages {
+                            let (_, peer_id) = self.selected_peer()?;
+                            self.push_direct_message_event(peer_id, event);
+                            return Some(UiCallback::SendDirectMessage {
+                                peer_id,
+                                message: lines[0].clone(),
+                            });
+                        }
+                        self.push_chat_event(event);
                         return Some(UiCallback::SendMessage {
                             message: lines[0].clone(),
                         });
@@ -714,7 +1151,7 @@ impl SplitPanel for SwarmPanel {
     }
 
     fn max_index(&self) -> usize {
-        self.peer_id_to_team_id.len()
+        self.team_id_to_peer_id.len()
     }
 
     fn set_index(&mut self, index: usize) {
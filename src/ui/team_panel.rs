@@ -1,22 +1,27 @@
 use super::button::{Button, RadioButton};
 use super::clickable_list::ClickableListState;
 use super::gif_map::GifMap;
+use super::search_box::SearchBox;
 use super::ui_callback::{CallbackRegistry, UiCallback};
-use super::utils::hover_text_target;
+use super::utils::{format_satoshi, hover_text_target};
 use super::widgets::{
-    go_to_team_current_planet_button, render_challenge_button, render_spaceship_description,
+    co_captain_button, go_to_team_current_planet_button, render_challenge_button,
+    render_spaceship_description,
 };
 use super::{
     constants::*,
     traits::{Screen, SplitPanel},
     utils::img_to_lines,
-    widgets::{default_block, selectable_list},
+    widgets::{default_block, searchable_list},
 };
 use crate::image::spaceship::{SPACESHIP_IMAGE_HEIGHT, SPACESHIP_IMAGE_WIDTH};
+use crate::network::trade::MAX_TRADE_BUNDLE_PLAYERS;
 use crate::types::AppResult;
 use crate::world::position::MAX_POSITION;
+use crate::world::resources::Resource;
 use crate::world::team::Team;
 use crate::{
+    image::emblem::{flag_image, FLAG_HEIGHT, FLAG_WIDTH},
     image::game::floor_from_size,
     image::player::{PLAYER_IMAGE_HEIGHT, PLAYER_IMAGE_WIDTH},
     types::{PlayerId, TeamId},
@@ -40,6 +45,21 @@ use std::{sync::Arc, sync::Mutex};
 use strum_macros::Display;
 
 const IMG_FRAME_WIDTH: u16 = 80;
+pub const TRADABLE_RESOURCES: [Resource; 4] = [
+    Resource::SCRAPS,
+    Resource::FUEL,
+    Resource::RUM,
+    Resource::GOLD,
+];
+
+// Amount of resource exchanged in a single trade offer to another team.
+pub fn trade_lot(resource: Resource) -> u32 {
+    match resource {
+        Resource::GOLD => 5,
+        Resource::RUM => 20,
+        _ => 100,
+    }
+}
 
 #[derive(Debug, Clone, Copy, Display, Default, PartialEq, Hash)]
 pub enum TeamView {
@@ -84,6 +104,9 @@ pub struct TeamListPanel {
     pub teams: Vec<TeamId>,
     pub all_teams: Vec<TeamId>,
     view: TeamView,
+    search: SearchBox,
+    trade_resource_index: usize,
+    trade_kartoffel_index: usize,
     update_view: bool,
     current_team_players_length: usize,
     tick: usize,
@@ -103,6 +126,16 @@ impl TeamListPanel {
         }
     }
 
+    fn search_rule(&self, team: &Team, world: &World) -> bool {
+        let planet = world
+            .get_planet(team.home_planet_id)
+            .map_or("", |planet| planet.name.as_str());
+        let rating = world.team_rating(team.id).unwrap_or_default().rating();
+        self.search
+            .query()
+            .matches(&team.name, planet, Some(rating))
+    }
+
     fn next_player_index(&mut self) {
         if self.current_team_players_length == 0 {
             return;
@@ -125,6 +158,7 @@ impl TeamListPanel {
             Constraint::Length(3),
             Constraint::Length(3),
             Constraint::Length(3),
+            Constraint::Length(3),
             Constraint::Min(1),
         ])
         .split(area);
@@ -177,6 +211,15 @@ impl TeamListPanel {
         frame.render_widget(filter_challenge_button, split[1]);
         frame.render_widget(filter_peers_button, split[2]);
 
+        self.search.render(
+            frame,
+            split[3],
+            &format!(
+                "Search name, planet, >stars ({})",
+                UiKey::SEARCH.to_string()
+            ),
+        );
+
         if self.teams.len() > 0 {
             let mut options = vec![];
             for &team_id in self.teams.iter() {
@@ -185,11 +228,11 @@ impl TeamListPanel {
                     continue;
                 }
                 let team = team.unwrap();
-                let mut style = UiStyle::DEFAULT;
+                let mut style = UiStyle::current().default;
                 if team.id == world.own_team_id {
-                    style = UiStyle::OWN_TEAM;
+                    style = UiStyle::current().own_team;
                 } else if team.peer_id.is_some() {
-                    style = UiStyle::NETWORK;
+                    style = UiStyle::current().network;
                 }
                 let text = format!(
                     "{:<MAX_NAME_LENGTH$} {}",
@@ -198,15 +241,15 @@ impl TeamListPanel {
                 );
                 options.push((text, style));
             }
-            let list = selectable_list(options, &self.callback_registry);
+            let list = searchable_list(options, self.search.query(), &self.callback_registry);
 
             frame.render_stateful_widget(
                 list.block(default_block().title("Teams ↓/↑")),
-                split[3],
+                split[4],
                 &mut ClickableListState::default().with_selected(Some(self.index)),
             );
         } else {
-            frame.render_widget(default_block().title("Teams"), split[3]);
+            frame.render_widget(default_block().title("Teams"), split[4]);
         }
     }
 
@@ -257,6 +300,19 @@ impl TeamListPanel {
 
         let player_rating_split = Layout::horizontal(constraints).split(vertical_split[3]);
 
+        // The team flag stands on the floor, left of the crew.
+        if player_img_split[0].width >= FLAG_WIDTH as u16 + 2 {
+            let flag_split = Layout::vertical([
+                Constraint::Min(0),
+                Constraint::Length(FLAG_HEIGHT as u16 / 2),
+            ])
+            .split(player_img_split[0]);
+            frame.render_widget(
+                Paragraph::new(img_to_lines(&flag_image(&team.emblem()))).centered(),
+                flag_split[1],
+            );
+        }
+
         for i in 0..MAX_POSITION as usize {
             if i >= team.player_ids.len() {
                 break;
@@ -362,17 +418,30 @@ impl TeamListPanel {
             }
         }
 
+        // Teams from the network get an extra row of buttons to trade resources,
+        // and another one to sell kartoffeln if there are any on board.
+        let resource_trade_rows = match team.peer_id {
+            Some(_) if !world.get_own_team()?.kartoffel_ids.is_empty() => 6,
+            Some(_) => 3,
+            None => 0,
+        };
         let ship_buttons_split = Layout::vertical([
             Constraint::Min(SPACESHIP_IMAGE_HEIGHT as u16 / 2 + 1), // ship
             Constraint::Length(3),                                  //button
+            Constraint::Length(resource_trade_rows),                //resource trade
         ])
         .split(bottom_split[1].inner(Margin {
             horizontal: 1,
             vertical: 1,
         }));
 
-        let button_split = Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
-            .split(ship_buttons_split[1]);
+        let button_split = Layout::horizontal([
+            Constraint::Ratio(1, 4),
+            Constraint::Ratio(1, 4),
+            Constraint::Ratio(1, 4),
+            Constraint::Ratio(1, 4),
+        ])
+        .split(ship_buttons_split[1]);
 
         let hover_text_target = hover_text_target(frame);
         if team.id != world.own_team_id {
@@ -394,6 +463,39 @@ impl TeamListPanel {
                 frame,
                 button_split[1],
             )?;
+
+            if let Some(button) =
+                co_captain_button(world, team, &self.callback_registry, hover_text_target)
+            {
+                frame.render_widget(button, button_split[2]);
+            }
+
+            let own_team = world.get_own_team()?;
+            let mut trade_builder_button = Button::new(
+                "Build trade".into(),
+                UiCallback::OpenTradeBuilder { team_id: team.id },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(
+                format!(
+                    "Trade up to {} players and resources with {}.",
+                    MAX_TRADE_BUNDLE_PLAYERS, team.name
+                ),
+                hover_text_target,
+            )
+            .set_hotkey(UiKey::CREATE_TRADE);
+            if let Err(err) = own_team.can_exchange_resources(
+                team,
+                (Resource::SATOSHI, 0),
+                (Resource::SATOSHI, 0),
+            ) {
+                trade_builder_button.disable(Some(err.to_string()));
+            }
+            frame.render_widget(trade_builder_button, button_split[3]);
+
+            if team.peer_id.is_some() {
+                self.render_resource_trade_buttons(frame, world, team, ship_buttons_split[2])?;
+            }
         }
 
         render_spaceship_description(
@@ -422,6 +524,226 @@ impl TeamListPanel {
         Ok(())
     }
 
+    fn render_resource_trade_buttons(
+        &self,
+        frame: &mut Frame,
+        world: &World,
+        team: &Team,
+        area: Rect,
+    ) -> AppResult<()> {
+        let own_team = world.get_own_team()?;
+        let rows = Layout::vertical([Constraint::Length(3), Constraint::Length(3)]).split(area);
+        let split = Layout::horizontal([
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+        ])
+        .split(rows[0]);
+        let hover_text_target = hover_text_target(frame);
+
+        if let Some(trade) = own_team.received_resource_trades.get(&team.id) {
+            let description = format!(
+                "{} {} for {}",
+                if trade.proposer_sells { "Buy" } else { "Sell" },
+                trade.goods(),
+                format_satoshi(trade.satoshi)
+            );
+            frame.render_widget(
+                Paragraph::new(format!("Offer: {}", description)).centered(),
+                split[0].inner(Margin {
+                    horizontal: 0,
+                    vertical: 1,
+                }),
+            );
+
+            let mut accept_button = Button::new(
+                "Accept offer".into(),
+                UiCallback::AcceptResourceTrade {
+                    trade: trade.clone(),
+                },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(
+                format!("Accept the offer of {}: {}.", team.name, description),
+                hover_text_target,
+            )
+            .set_hotkey(UiKey::ACCEPT_TRADE);
+            if let Err(err) = trade.can_be_settled_by(own_team, team) {
+                accept_button.disable(Some(err.to_string()));
+            }
+            frame.render_widget(accept_button, split[1]);
+
+            let decline_button = Button::new(
+                "Decline offer".into(),
+                UiCallback::DeclineResourceTrade {
+                    trade: trade.clone(),
+                },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(
+                format!("Decline the offer of {}.", team.name),
+                hover_text_target,
+            )
+            .set_hotkey(UiKey::DECLINE_TRADE);
+            frame.render_widget(decline_button, split[2]);
+            return Ok(());
+        }
+
+        let resource = TRADABLE_RESOURCES[self.trade_resource_index];
+        let next_resource =
+            TRADABLE_RESOURCES[(self.trade_resource_index + 1) % TRADABLE_RESOURCES.len()];
+        let resource_button = Button::new(
+            format!("Resource: {}", resource).into(),
+            UiCallback::SetTeamPanelTradeResource {
+                resource: next_resource,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            "Cycle the resource to trade with this team.".into(),
+            hover_text_target,
+        )
+        .set_hotkey(UiKey::CYCLE_TRADE_RESOURCE);
+        frame.render_widget(resource_button, split[0]);
+
+        // Offers are priced at the middle of the local market buy and sell prices.
+        let amount = trade_lot(resource);
+        let unit_price = match own_team
+            .is_on_planet()
+            .and_then(|planet_id| world.get_planet(planet_id))
+        {
+            Some(planet) => {
+                (planet.resource_buy_price(resource, 1.0)
+                    + planet.resource_sell_price(resource, 1.0))
+                    / 2
+            }
+            None => resource.base_price() as u32,
+        };
+        let satoshi = amount * unit_price;
+        let pending_offer = own_team.sent_resource_trades.contains_key(&team.id);
+
+        for (idx, proposer_sells) in [true, false].into_iter().enumerate() {
+            let (text, hover_text, key, given, received) = if proposer_sells {
+                (
+                    format!("Sell {} {}", amount, resource),
+                    format!(
+                        "Offer {} {} {} for {}.",
+                        team.name,
+                        amount,
+                        resource,
+                        format_satoshi(satoshi)
+                    ),
+                    UiKey::OFFER_RESOURCE_SALE,
+                    (resource, amount),
+                    (Resource::SATOSHI, satoshi),
+                )
+            } else {
+                (
+                    format!("Buy {} {}", amount, resource),
+                    format!(
+                        "Ask {} for {} {} in exchange of {}.",
+                        team.name,
+                        amount,
+                        resource,
+                        format_satoshi(satoshi)
+                    ),
+                    UiKey::OFFER_RESOURCE_PURCHASE,
+                    (Resource::SATOSHI, satoshi),
+                    (resource, amount),
+                )
+            };
+            let mut button = Button::new(
+                text.into(),
+                UiCallback::ProposeResourceTrade {
+                    team_id: team.id,
+                    resource,
+                    amount,
+                    satoshi,
+                    proposer_sells,
+                },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(hover_text, hover_text_target)
+            .set_hotkey(key);
+            if pending_offer {
+                button.disable(Some("Offer pending".to_string()));
+            } else if let Err(err) = own_team.can_exchange_resources(team, given, received) {
+                button.disable(Some(err.to_string()));
+            }
+            frame.render_widget(button, split[idx + 1]);
+        }
+
+        if own_team.kartoffel_ids.is_empty() {
+            return Ok(());
+        }
+
+        let kartoffel_split = Layout::horizontal([
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+        ])
+        .split(rows[1]);
+        let kartoffel_index = self.trade_kartoffel_index % own_team.kartoffel_ids.len();
+        let kartoffel = world.get_kartoffel_or_err(own_team.kartoffel_ids[kartoffel_index])?;
+        let kartoffel_button = Button::new(
+            format!("Kartoffel: {}", kartoffel.name).into(),
+            UiCallback::SetTeamPanelTradeKartoffel {
+                index: (kartoffel_index + 1) % own_team.kartoffel_ids.len(),
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            "Cycle the kartoffel to sell to this team.".into(),
+            hover_text_target,
+        )
+        .set_hotkey(UiKey::CYCLE_TRADE_KARTOFFEL);
+        frame.render_widget(kartoffel_button, kartoffel_split[0]);
+
+        let satoshi = kartoffel.rarity.value();
+        let mut sell_button = Button::new(
+            format!("Sell {}", kartoffel.rarity).into(),
+            UiCallback::ProposeKartoffelTrade {
+                team_id: team.id,
+                kartoffel_id: kartoffel.id,
+                satoshi,
+            },
+            Arc::clone(&self.callback_registry),
+        )
+        .set_hover_text(
+            format!(
+                "Offer {} {} for {}.",
+                team.name,
+                kartoffel.name,
+                format_satoshi(satoshi)
+            ),
+            hover_text_target,
+        )
+        .set_hotkey(UiKey::OFFER_KARTOFFEL_SALE);
+        if pending_offer {
+            sell_button.disable(Some("Offer pending".to_string()));
+        } else if let Err(err) = own_team.can_exchange_resources(
+            team,
+            (Resource::SCRAPS, 0),
+            (Resource::SATOSHI, satoshi),
+        ) {
+            sell_button.disable(Some(err.to_string()));
+        }
+        frame.render_widget(sell_button, kartoffel_split[1]);
+
+        Ok(())
+    }
+
+    pub fn set_trade_kartoffel(&mut self, index: usize) {
+        self.trade_kartoffel_index = index;
+    }
+
+    pub fn set_trade_resource(&mut self, resource: Resource) {
+        self.trade_resource_index = TRADABLE_RESOURCES
+            .iter()
+            .position(|&r| r == resource)
+            .unwrap_or_default();
+    }
+
     pub fn set_view(&mut self, filter: TeamView) {
         self.view = filter;
         self.update_view = true;
@@ -456,6 +778,7 @@ impl Screen for TeamListPanel {
                 .filter(|&&team_id| {
                     let team = world.get_team_or_err(team_id).unwrap();
                     self.view.rule(team, world.get_own_team().unwrap())
+                        && self.search_rule(team, world)
                 })
                 .map(|&player_id| player_id)
                 .collect();
@@ -511,9 +834,29 @@ impl Screen for TeamListPanel {
         key_event: crossterm::event::KeyEvent,
         _world: &World,
     ) -> Option<UiCallback> {
+        if self.search.is_active() {
+            match key_event.code {
+                KeyCode::Up => self.next_index(),
+                KeyCode::Down => self.previous_index(),
+                // Keep the filter and jump to the top result.
+                KeyCode::Enter => {
+                    self.search.deactivate();
+                    self.set_index(0);
+                }
+                _ => {
+                    if self.search.handle_key_event(key_event) {
+                        self.update_view = true;
+                        self.set_index(0);
+                    }
+                }
+            }
+            return None;
+        }
+
         match key_event.code {
             KeyCode::Up => self.next_index(),
             KeyCode::Down => self.previous_index(),
+            UiKey::SEARCH => self.search.activate(),
             UiKey::NEXT_SELECTION => self.next_player_index(),
             UiKey::PREVIOUS_SELECTION => self.previous_player_index(),
             UiKey::CYCLE_VIEW => {
@@ -540,6 +883,10 @@ impl Screen for TeamListPanel {
             " Select player ".to_string(),
         ]
     }
+
+    fn is_capturing_input(&self) -> bool {
+        self.search.is_active()
+    }
 }
 
 impl SplitPanel for TeamListPanel {
@@ -0,0 +1,335 @@
+use super::button::Button;
+use super::clickable_list::ClickableListState;
+use super::constants::*;
+use super::traits::{Screen, SplitPanel};
+use super::ui_callback::{CallbackRegistry, UiCallback};
+use super::utils::{format_satoshi, hover_text_target};
+use super::widgets::{default_block, selectable_list};
+use crate::types::{AppResult, SystemTimeTick, TeamId};
+use crate::world::tournament::{Tournament, TournamentMatch, TournamentStatus};
+use crate::world::world::World;
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Layout, Margin},
+    prelude::Rect,
+    text::{Line, Span},
+    widgets::{Paragraph, Wrap},
+    Frame,
+};
+use std::sync::{Arc, Mutex};
+
+// Lines taken by a first round match: home team, away team and a separator.
+const MATCH_HEIGHT: usize = 3;
+
+#[derive(Debug, Default)]
+pub struct TournamentPanel {
+    index: usize,
+    tournaments: Vec<Tournament>,
+    callback_registry: Arc<Mutex<CallbackRegistry>>,
+}
+
+impl TournamentPanel {
+    pub fn new(callback_registry: Arc<Mutex<CallbackRegistry>>) -> Self {
+        Self {
+            callback_registry,
+            ..Default::default()
+        }
+    }
+
+    fn team_span(
+        &self,
+        world: &World,
+        team_id: Option<TeamId>,
+        tournament_match: &TournamentMatch,
+    ) -> Span<'static> {
+        let team_id = match team_id {
+            Some(team_id) => team_id,
+            None => return Span::styled("bye", UiStyle::current().unselectable),
+        };
+        let name = world
+            .get_team(team_id)
+            .map(|team| team.name.clone())
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        let style = match tournament_match.winner {
+            Some(winner) if winner != team_id => UiStyle::current().unselectable,
+            _ if team_id == world.own_team_id => UiStyle::current().own_team,
+            Some(_) => UiStyle::current().ok,
+            None => UiStyle::current().default,
+        };
+        let symbol = if tournament_match.winner == Some(team_id) {
+            "▶"
+        } else if tournament_match.game_id.is_some() && tournament_match.winner.is_none() {
+            "●"
+        } else {
+            " "
+        };
+        Span::styled(format!("{} {}", symbol, name), style)
+    }
+
+    fn render_tournament_list(&self, frame: &mut Frame, area: Rect) {
+        frame.render_widget(default_block().title("Tournaments "), area);
+
+        let options = self
+            .tournaments
+            .iter()
+            .map(|tournament| {
+                let style = match tournament.status {
+                    TournamentStatus::Registration => UiStyle::current().highlight,
+                    TournamentStatus::Ongoing => UiStyle::current().warning,
+                    TournamentStatus::Ended => UiStyle::current().unselectable,
+                };
+                (format!(" {}", tournament.name), style)
+            })
+            .collect();
+
+        let list = selectable_list(options, &self.callback_registry);
+        frame.render_stateful_widget(
+            list,
+            area.inner(Margin {
+                horizontal: 1,
+                vertical: 1,
+            }),
+            &mut ClickableListState::default().with_selected(Some(self.index)),
+        );
+    }
+
+    fn render_tournament_details(
+        &self,
+        frame: &mut Frame,
+        world: &World,
+        tournament: &Tournament,
+        area: Rect,
+    ) -> AppResult<()> {
+        frame.render_widget(default_block().title(format!("{} ", tournament.name)), area);
+        let split = Layout::vertical([
+            Constraint::Length(6),
+            Constraint::Length(3),
+            Constraint::Min(0),
+        ])
+        .split(area.inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        }));
+
+        let planet_name = world
+            .get_planet_or_err(tournament.planet_id)
+            .map(|planet| planet.name.clone())
+            .unwrap_or_default();
+        let status = match tournament.status {
+            TournamentStatus::Registration => format!(
+                "Registrations open until {}",
+                tournament.registration_deadline.formatted_as_date()
+            ),
+            TournamentStatus::Ongoing => format!("Round {} ongoing", tournament.rounds.len()),
+            TournamentStatus::Ended => match tournament.winner.and_then(|id| world.get_team(id)) {
+                Some(winner) => format!("Won by {}", winner.name),
+                None => "Cancelled".to_string(),
+            },
+        };
+        let (winner_prize, runner_up_prize) = tournament.prizes();
+        let lines = vec![
+            Line::from(format!("{:<12} {}", "Host", planet_name)),
+            Line::from(vec![
+                Span::raw(format!("{:<12} ", "Status")),
+                Span::styled(status, UiStyle::current().highlight),
+            ]),
+            Line::from(format!(
+                "{:<12} {}",
+                "Entry fee",
+                format_satoshi(tournament.entry_fee)
+            )),
+            Line::from(format!(
+                "{:<12} {} (winner {}, runner-up {})",
+                "Prize pool",
+                format_satoshi(tournament.prize_pool),
+                format_satoshi(winner_prize),
+                format_satoshi(runner_up_prize)
+            )),
+            Line::from(format!("{:<12} {}", "Teams", tournament.participants.len())),
+        ];
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), split[0]);
+
+        if tournament.status == TournamentStatus::Registration {
+            let own_team = world.get_own_team()?;
+            let mut button = Button::new(
+                format!("Register -{}", format_satoshi(tournament.entry_fee)).into(),
+                UiCallback::RegisterForTournament {
+                    tournament_id: tournament.id,
+                },
+                Arc::clone(&self.callback_registry),
+            )
+            .set_hover_text(
+                format!(
+                    "Register to the {}. The team must be on {} when its games are scheduled or it will forfeit",
+                    tournament.name, planet_name
+                ),
+                hover_text_target(frame),
+            )
+            .set_hotkey(UiKey::REGISTER_TOURNAMENT);
+            if let Err(err) = tournament.can_register(own_team, world.now()) {
+                button.disable(Some(err.to_string()));
+            }
+            frame.render_widget(
+                button,
+                Layout::horizontal([Constraint::Length(32), Constraint::Min(0)]).split(split[1])[0],
+            );
+        }
+
+        self.render_bracket(frame, world, tournament, split[2]);
+        Ok(())
+    }
+
+    fn render_bracket(
+        &self,
+        frame: &mut Frame,
+        world: &World,
+        tournament: &Tournament,
+        area: Rect,
+    ) {
+        let first_round = match tournament.rounds.first() {
+            Some(round) => round,
+            None => {
+                let lines = tournament
+                    .participants
+                    .iter()
+                    .filter_map(|&team_id| world.get_team(team_id))
+                    .map(|team| {
+                        let style = if team.id == world.own_team_id {
+                            UiStyle::current().own_team
+                        } else {
+                            UiStyle::current().default
+                        };
+                        Line::from(Span::styled(format!("  {}", team.name), style))
+                    })
+                    .collect::<Vec<Line>>();
+                frame.render_widget(Paragraph::new(lines), area);
+                return;
+            }
+        };
+
+        // The bracket has a column per round, each match is centered
+        // on the two matches of the previous round it comes from.
+        let number_of_rounds = (first_round.len() * 2).trailing_zeros() as usize;
+        let columns = Layout::horizontal(
+            (0..number_of_rounds)
+                .map(|_| Constraint::Ratio(1, number_of_rounds as u32))
+                .collect::<Vec<Constraint>>(),
+        )
+        .split(area);
+
+        for round_index in 0..number_of_rounds {
+            let number_of_matches = first_round.len() >> round_index;
+            let match_height = MATCH_HEIGHT << round_index;
+            let padding = (match_height - MATCH_HEIGHT) / 2;
+
+            let title = if round_index + 1 == number_of_rounds {
+                "Final".to_string()
+            } else {
+                format!("Round {}", round_index + 1)
+            };
+            let mut lines = vec![Line::from(Span::styled(title, UiStyle::current().header))];
+            for match_index in 0..number_of_matches {
+                for _ in 0..padding {
+                    lines.push(Line::from(""));
+                }
+                match tournament
+                    .rounds
+                    .get(round_index)
+                    .and_then(|round| round.get(match_index))
+                {
+                    Some(tournament_match) => {
+                        lines.push(Line::from(self.team_span(
+                            world,
+                            tournament_match.home,
+                            tournament_match,
+                        )));
+                        lines.push(Line::from(self.team_span(
+                            world,
+                            tournament_match.away,
+                            tournament_match,
+                        )));
+                    }
+                    None => {
+                        lines.push(Line::from(Span::styled(
+                            "  TBD",
+                            UiStyle::current().unselectable,
+                        )));
+                        lines.push(Line::from(Span::styled(
+                            "  TBD",
+                            UiStyle::current().unselectable,
+                        )));
+                    }
+                }
+                for _ in 0..(match_height - 2 - padding) {
+                    lines.push(Line::from(""));
+                }
+            }
+            frame.render_widget(Paragraph::new(lines), columns[round_index]);
+        }
+    }
+}
+
+impl Screen for TournamentPanel {
+    fn update(&mut self, world: &World) -> AppResult<()> {
+        if world.dirty_ui || self.tournaments.len() != world.tournaments.len() {
+            // Active tournaments are listed first, then the most recent ones.
+            self.tournaments = world.tournaments.iter().rev().cloned().collect();
+            self.tournaments
+                .sort_by_key(|tournament| !tournament.is_active());
+        }
+        Ok(())
+    }
+
+    fn render(
+        &mut self,
+        frame: &mut Frame,
+        world: &World,
+        area: Rect,
+        _debug_view: bool,
+    ) -> AppResult<()> {
+        if self.tournaments.is_empty() {
+            frame.render_widget(
+                Paragraph::new("No tournament has been announced yet.")
+                    .centered()
+                    .block(default_block().title("Tournaments ")),
+                area,
+            );
+            return Ok(());
+        }
+
+        let split = Layout::horizontal([Constraint::Length(36), Constraint::Min(48)]).split(area);
+        self.render_tournament_list(frame, split[0]);
+        if let Some(tournament) = self.tournaments.get(self.index) {
+            self.render_tournament_details(frame, world, tournament, split[1])?;
+        }
+        Ok(())
+    }
+
+    fn handle_key_events(
+        &mut self,
+        key_event: crossterm::event::KeyEvent,
+        _world: &World,
+    ) -> Option<UiCallback> {
+        match key_event.code {
+            KeyCode::Up => self.next_index(),
+            KeyCode::Down => self.previous_index(),
+            _ => {}
+        }
+        None
+    }
+}
+
+impl SplitPanel for TournamentPanel {
+    fn index(&self) -> usize {
+        self.index
+    }
+
+    fn max_index(&self) -> usize {
+        self.tournaments.len()
+    }
+
+    fn set_index(&mut self, index: usize) {
+        self.index = index;
+    }
+}
@@ -1,4 +1,4 @@
-use super::constants::UiStyle;
+use super::constants::{UiStyle, LIST_PAGE_SIZE};
 use super::ui_callback::UiCallback;
 use crate::world::resources::Resource;
 use crate::world::world::World;
@@ -31,6 +31,11 @@ pub trait Screen {
     fn footer_spans(&self) -> Vec<String> {
         vec![]
     }
+
+    // Screens with a focused text input receive every key, global hotkeys included.
+    fn is_capturing_input(&self) -> bool {
+        false
+    }
 }
 
 impl Debug for dyn Screen {
@@ -55,6 +60,17 @@ pub trait SplitPanel {
             self.set_index((current_index + self.max_index() - 1) % self.max_index());
         }
     }
+    // Page movements stop at the list ends rather than wrapping around.
+    fn page_down(&mut self) {
+        if self.max_index() > 0 {
+            self.set_index((self.index() + LIST_PAGE_SIZE).min(self.max_index() - 1));
+        }
+    }
+    fn page_up(&mut self) {
+        if self.max_index() > 0 {
+            self.set_index(self.index().saturating_sub(LIST_PAGE_SIZE));
+        }
+    }
 }
 
 impl Debug for dyn SplitPanel {
@@ -69,45 +85,20 @@ pub trait UiStyled {
 
 impl UiStyled for f32 {
     fn style(&self) -> Style {
-        match self.rating() {
-            0 => Style::default().fg(Color::DarkGray),
-            1..=2 => Style::default().fg(Color::Red),
-            3..=4 => Style::default().fg(Color::LightRed),
-            5..=6 => Style::default().fg(Color::Yellow),
-            7..=8 => Style::default().fg(Color::LightYellow),
-            9..=10 => Style::default().fg(Color::White),
-            11..=12 => Style::default().fg(Color::White),
-            13..=14 => Style::default().fg(Color::LightGreen),
-            15..=16 => Style::default().fg(Color::Green),
-            17..=18 => Style::default().fg(Color::Cyan),
-            19..=20 => Style::default().fg(Color::LightBlue),
-            _ => panic!("Invalid rating"),
-        }
+        UiStyle::current().rating(self.rating())
     }
 }
+
 impl UiStyled for u8 {
     fn style(&self) -> Style {
-        match self.rating() {
-            0 => Style::default().fg(Color::DarkGray),
-            1..=2 => Style::default().fg(Color::Red),
-            3..=4 => Style::default().fg(Color::LightRed),
-            5..=6 => Style::default().fg(Color::Yellow),
-            7..=8 => Style::default().fg(Color::LightYellow),
-            9..=10 => Style::default().fg(Color::White),
-            11..=12 => Style::default().fg(Color::White),
-            13..=14 => Style::default().fg(Color::LightGreen),
-            15..=16 => Style::default().fg(Color::Green),
-            17..=18 => Style::default().fg(Color::Cyan),
-            19..=20 => Style::default().fg(Color::LightBlue),
-            _ => panic!("Invalid rating"),
-        }
+        UiStyle::current().rating(self.rating())
     }
 }
 
 impl UiStyled for Resource {
     fn style(&self) -> Style {
         let [r, g, b, _] = self.color().0;
-        UiStyle::DEFAULT.fg(Color::Rgb(r, g, b))
+        UiStyle::current().default.fg(Color::Rgb(r, g, b))
     }
 }
 
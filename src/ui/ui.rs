@@ -1,7 +1,11 @@
+use super::broadcast_screen::BroadcastScreen;
 use super::button::Button;
-use super::constants::{UiKey, UiStyle};
+use super::clipboard::SystemClipboard;
+use super::constants::{with_accessible_mode, UiKey, UiStyle, UiTheme};
 use super::galaxy_panel::GalaxyPanel;
+use super::game_over_screen::GameOverScreen;
 use super::gif_map::GifMap;
+use super::keymap::{keymap_conflicts, KeyContext};
 use super::popup_message::PopupMessage;
 use super::space_screen::SpaceScreen;
 use super::splash_screen::{AudioPlayerState, SplashScreen};
@@ -10,12 +14,16 @@ use super::ui_callback::{CallbackRegistry, UiCallback};
 use super::utils::SwarmPanelEvent;
 use super::widgets::default_block;
 use super::{
-    game_panel::GamePanel, my_team_panel::MyTeamPanel, new_team_screen::NewTeamScreen,
+    calendar_panel::CalendarPanel, campaign_panel::CampaignPanel, events_panel::EventsPanel,
+    game_panel::GamePanel, hall_of_fame_panel::HallOfFamePanel, my_team_panel::MyTeamPanel,
+    new_team_screen::NewTeamScreen, notifications_panel::NotificationsPanel,
     player_panel::PlayerListPanel, swarm_panel::SwarmPanel, team_panel::TeamListPanel,
-    traits::Screen,
+    tournament_panel::TournamentPanel, traits::Screen,
 };
+use crate::assets::asset_health;
 use crate::audio::music_player::MusicPlayer;
 use crate::types::{AppResult, SystemTimeTick, Tick};
+use crate::world::types::TimeSpeed;
 use crate::world::world::World;
 use core::fmt::Debug;
 use itertools::Itertools;
@@ -42,15 +50,24 @@ pub enum UiState {
     NewTeam,
     Main,
     SpaceAdventure,
+    Broadcast,
+    GameOver,
 }
 
-#[derive(Debug, Clone, Copy, Hash, Display, PartialEq)]
+#[derive(Debug, Clone, Copy, Hash, Display, PartialEq, Eq)]
 pub enum UiTab {
     MyTeam,
     Teams,
     Players,
     Galaxy,
     Games,
+    Calendar,
+    Events,
+    Notifications,
+    Campaign,
+    Tournaments,
+    #[strum(to_string = "Hall of Fame")]
+    HallOfFame,
     Swarm,
 }
 
@@ -64,14 +81,25 @@ pub struct Ui {
     pub splash_screen: SplashScreen,
     pub new_team_screen: NewTeamScreen,
     pub space_screen: SpaceScreen,
+    pub broadcast_screen: BroadcastScreen,
+    pub game_over_screen: GameOverScreen,
     pub player_panel: PlayerListPanel,
     pub team_panel: TeamListPanel,
     pub game_panel: GamePanel,
     pub swarm_panel: SwarmPanel,
+    pub calendar_panel: CalendarPanel,
+    pub events_panel: EventsPanel,
+    pub notifications_panel: NotificationsPanel,
+    pub campaign_panel: CampaignPanel,
+    pub tournament_panel: TournamentPanel,
+    pub hall_of_fame_panel: HallOfFamePanel,
     pub my_team_panel: MyTeamPanel,
     pub galaxy_panel: GalaxyPanel,
     popup_messages: Vec<PopupMessage>,
     popup_input: TextArea<'static>,
+    theme: UiTheme,
+    accessible_mode: bool,
+    pub clipboard: SystemClipboard,
     callback_registry: Arc<Mutex<CallbackRegistry>>,
 }
 
@@ -89,7 +117,13 @@ impl Ui {
             PlayerListPanel::new(Arc::clone(&callback_registry), Arc::clone(&gif_map));
         let team_panel = TeamListPanel::new(Arc::clone(&callback_registry), Arc::clone(&gif_map));
         let game_panel = GamePanel::new(Arc::clone(&callback_registry), Arc::clone(&gif_map));
-        let swarm_panel = SwarmPanel::new(Arc::clone(&callback_registry));
+        let mut swarm_panel = SwarmPanel::new(Arc::clone(&callback_registry));
+        let calendar_panel = CalendarPanel::new(Arc::clone(&callback_registry));
+        let events_panel = EventsPanel::new(Arc::clone(&callback_registry));
+        let notifications_panel = NotificationsPanel::new(Arc::clone(&callback_registry));
+        let campaign_panel = CampaignPanel::new(Arc::clone(&callback_registry));
+        let tournament_panel = TournamentPanel::new(Arc::clone(&callback_registry));
+        let hall_of_fame_panel = HallOfFamePanel::new(Arc::clone(&callback_registry));
         let my_team_panel = MyTeamPanel::new(Arc::clone(&callback_registry), Arc::clone(&gif_map));
         let new_team_screen =
             NewTeamScreen::new(Arc::clone(&callback_registry), Arc::clone(&gif_map));
@@ -102,12 +136,29 @@ impl Ui {
         ui_tabs.push(UiTab::Players);
         ui_tabs.push(UiTab::Galaxy);
         ui_tabs.push(UiTab::Games);
+        ui_tabs.push(UiTab::Calendar);
+        ui_tabs.push(UiTab::Events);
+        ui_tabs.push(UiTab::Notifications);
+        ui_tabs.push(UiTab::Campaign);
+        ui_tabs.push(UiTab::Tournaments);
+        ui_tabs.push(UiTab::HallOfFame);
 
         if !disable_network {
             ui_tabs.push(UiTab::Swarm);
         }
 
         let space_screen = SpaceScreen::new(Arc::clone(&callback_registry));
+        let broadcast_screen = BroadcastScreen::new(Arc::clone(&callback_registry));
+        let game_over_screen = GameOverScreen::new(Arc::clone(&callback_registry));
+
+        for conflict in keymap_conflicts() {
+            log::warn!("Hotkey conflict: {}", conflict);
+            swarm_panel.push_log_event(SwarmPanelEvent {
+                timestamp: Tick::now(),
+                peer_id: None,
+                text: format!("Hotkey conflict\n{}", conflict),
+            });
+        }
 
         Self {
             state: UiState::default(),
@@ -118,13 +169,24 @@ impl Ui {
             splash_screen,
             new_team_screen,
             space_screen,
+            broadcast_screen,
+            game_over_screen,
             player_panel,
             team_panel,
             game_panel,
             swarm_panel,
+            calendar_panel,
+            events_panel,
+            notifications_panel,
+            campaign_panel,
+            tournament_panel,
+            hall_of_fame_panel,
             my_team_panel,
             galaxy_panel,
             popup_input: TextArea::default(),
+            theme: UiTheme::default(),
+            accessible_mode: false,
+            clipboard: SystemClipboard::default(),
             popup_messages: vec![],
             callback_registry,
         }
@@ -146,12 +208,61 @@ impl Ui {
         self.popup_messages.remove(0);
     }
 
+    // The popup currently displayed, if any.
+    pub fn popup_mut(&mut self) -> Option<&mut PopupMessage> {
+        self.popup_messages.first_mut()
+    }
+
+    pub fn popup_count(&self) -> usize {
+        self.popup_messages.len()
+    }
+
+    pub fn theme(&self) -> UiTheme {
+        self.theme
+    }
+
+    pub fn set_theme(&mut self, theme: UiTheme) {
+        self.theme = theme;
+    }
+
+    pub fn set_accessible_mode(&mut self, enabled: bool) {
+        self.accessible_mode = enabled;
+    }
+
+    pub fn state(&self) -> &UiState {
+        &self.state
+    }
+
     pub fn set_state(&mut self, state: UiState) {
         self.state = state;
     }
 
+    // Keyboard-only mode gives a linear focus order over all buttons.
+    pub fn set_keyboard_only(&mut self, keyboard_only: bool) {
+        self.callback_registry
+            .lock()
+            .unwrap()
+            .set_keyboard_only(keyboard_only);
+    }
+
     pub fn toggle_data_view(&mut self) {
         self.debug_view = !self.debug_view;
+        if self.debug_view {
+            self.push_asset_health_report();
+        }
+    }
+
+    fn push_asset_health_report(&mut self) {
+        let health = asset_health();
+        let mut text = health.summary();
+        for failure in health.failures.iter() {
+            text.push_str(format!("\n{}: {}", failure.path, failure.error).as_str());
+        }
+        self.swarm_panel.push_log_event(SwarmPanelEvent {
+            timestamp: Tick::now(),
+            peer_id: None,
+            text,
+        });
     }
 
     fn get_active_screen(&self) -> &dyn Screen {
@@ -164,15 +275,23 @@ impl Ui {
                 UiTab::Players => &self.player_panel,
                 UiTab::Galaxy => &self.galaxy_panel,
                 UiTab::Games => &self.game_panel,
+                UiTab::Calendar => &self.calendar_panel,
+                UiTab::Events => &self.events_panel,
+                UiTab::Notifications => &self.notifications_panel,
+                UiTab::Campaign => &self.campaign_panel,
+                UiTab::Tournaments => &self.tournament_panel,
+                UiTab::HallOfFame => &self.hall_of_fame_panel,
                 UiTab::Swarm => &self.swarm_panel,
             },
             UiState::SpaceAdventure => &self.space_screen,
+            UiState::Broadcast => &self.broadcast_screen,
+            UiState::GameOver => &self.game_over_screen,
         }
     }
 
     pub fn get_active_panel(&mut self) -> Option<&mut dyn SplitPanel> {
         match self.state {
-            UiState::Splash => None,
+            UiState::Splash | UiState::Broadcast | UiState::GameOver => None,
             UiState::NewTeam => Some(&mut self.new_team_screen),
             _ => match self.ui_tabs[self.tab_index] {
                 UiTab::MyTeam => Some(&mut self.my_team_panel),
@@ -180,6 +299,12 @@ impl Ui {
                 UiTab::Players => Some(&mut self.player_panel),
                 UiTab::Galaxy => Some(&mut self.galaxy_panel),
                 UiTab::Games => Some(&mut self.game_panel),
+                UiTab::Calendar => Some(&mut self.calendar_panel),
+                UiTab::Events => Some(&mut self.events_panel),
+                UiTab::Notifications => Some(&mut self.notifications_panel),
+                UiTab::Campaign => Some(&mut self.campaign_panel),
+                UiTab::Tournaments => Some(&mut self.tournament_panel),
+                UiTab::HallOfFame => Some(&mut self.hall_of_fame_panel),
                 UiTab::Swarm => Some(&mut self.swarm_panel),
             },
         }
@@ -195,9 +320,17 @@ impl Ui {
                 UiTab::Players => &mut self.player_panel,
                 UiTab::Galaxy => &mut self.galaxy_panel,
                 UiTab::Games => &mut self.game_panel,
+                UiTab::Calendar => &mut self.calendar_panel,
+                UiTab::Events => &mut self.events_panel,
+                UiTab::Notifications => &mut self.notifications_panel,
+                UiTab::Campaign => &mut self.campaign_panel,
+                UiTab::Tournaments => &mut self.tournament_panel,
+                UiTab::HallOfFame => &mut self.hall_of_fame_panel,
                 UiTab::Swarm => &mut self.swarm_panel,
             },
             UiState::SpaceAdventure => &mut self.space_screen,
+            UiState::Broadcast => &mut self.broadcast_screen,
+            UiState::GameOver => &mut self.game_over_screen,
         }
     }
 
@@ -206,6 +339,12 @@ impl Ui {
         key_event: crossterm::event::KeyEvent,
         world: &World,
     ) -> Option<UiCallback> {
+        if self.popup_messages.len() == 0 && self.get_active_screen_mut().is_capturing_input() {
+            return self
+                .get_active_screen_mut()
+                .handle_key_events(key_event, world);
+        }
+
         match key_event.code {
             UiKey::ESC => {
                 return Some(UiCallback::PromptQuit);
@@ -215,6 +354,47 @@ impl Ui {
                 return Some(UiCallback::ToggleUiDebugMode);
             }
 
+            UiKey::CYCLE_UI_THEME => {
+                return Some(UiCallback::CycleUiTheme);
+            }
+
+            UiKey::ACCESSIBLE_MODE => {
+                return Some(UiCallback::ToggleAccessibleMode);
+            }
+
+            UiKey::KEYBOARD_ONLY_MODE => {
+                self.callback_registry
+                    .lock()
+                    .unwrap()
+                    .toggle_keyboard_only();
+                None
+            }
+
+            UiKey::FOCUS_NEXT if self.callback_registry.lock().unwrap().is_keyboard_only() => {
+                self.callback_registry.lock().unwrap().focus_next();
+                None
+            }
+
+            UiKey::FOCUS_PREVIOUS if self.callback_registry.lock().unwrap().is_keyboard_only() => {
+                self.callback_registry.lock().unwrap().focus_previous();
+                None
+            }
+
+            UiKey::PAGE_DOWN => Some(UiCallback::PanelPageDown),
+
+            UiKey::PAGE_UP => Some(UiCallback::PanelPageUp),
+
+            UiKey::ACTIVATE_FOCUS
+                if self
+                    .callback_registry
+                    .lock()
+                    .unwrap()
+                    .focused_rect()
+                    .is_some() =>
+            {
+                self.callback_registry.lock().unwrap().activate_focused()
+            }
+
             UiKey::NEXT_TAB if self.state == UiState::Main => {
                 self.next_tab();
                 None
@@ -269,7 +449,26 @@ impl Ui {
     }
 
     pub fn update(&mut self, world: &World, audio_player: Option<&MusicPlayer>) -> AppResult<()> {
+        let (theme, accessible_mode) = (self.theme, self.accessible_mode);
+        UiStyle::with_theme(theme, || {
+            with_accessible_mode(accessible_mode, || self.update_screens(world, audio_player))
+        })
+    }
+
+    fn update_screens(
+        &mut self,
+        world: &World,
+        audio_player: Option<&MusicPlayer>,
+    ) -> AppResult<()> {
         self.callback_registry.lock().unwrap().clear();
+        // Once the game is over, there is nothing left to do but to look at the summary.
+        if world.game_over.is_some()
+            && (self.state == UiState::Main || self.state == UiState::SpaceAdventure)
+        {
+            self.state = UiState::GameOver;
+        } else if world.game_over.is_none() && self.state == UiState::GameOver {
+            self.state = UiState::Main;
+        }
         match self.state {
             UiState::Splash => {
                 // This is only to get a nice view in the splash screen
@@ -293,8 +492,16 @@ impl Ui {
                 self.player_panel.update(world)?;
                 self.game_panel.update(world)?;
                 self.galaxy_panel.update(world)?;
+                self.calendar_panel.update(world)?;
+                self.events_panel.update(world)?;
+                self.notifications_panel.update(world)?;
+                self.campaign_panel.update(world)?;
+                self.tournament_panel.update(world)?;
+                self.hall_of_fame_panel.update(world)?;
             }
             UiState::SpaceAdventure => self.space_screen.update(world)?,
+            UiState::Broadcast => self.broadcast_screen.update(world)?,
+            UiState::GameOver => self.game_over_screen.update(world)?,
         }
 
         Ok(())
@@ -302,6 +509,20 @@ impl Ui {
 
     /// Renders the user interface widgets.
     pub fn render(&mut self, frame: &mut Frame, world: &World, audio_player: Option<&MusicPlayer>) {
+        let (theme, accessible_mode) = (self.theme, self.accessible_mode);
+        UiStyle::with_theme(theme, || {
+            with_accessible_mode(accessible_mode, || {
+                self.render_screens(frame, world, audio_player)
+            })
+        })
+    }
+
+    fn render_screens(
+        &mut self,
+        frame: &mut Frame,
+        world: &World,
+        audio_player: Option<&MusicPlayer>,
+    ) {
         self.callback_registry.lock().unwrap().clear();
         if self.popup_messages.len() > 0 {
             self.callback_registry.lock().unwrap().set_max_layer(1);
@@ -316,9 +537,22 @@ impl Ui {
 
         // Render footer
         // We render the footer first because hover text is displayed in the footer (and thus must overwrite it)
-        self.render_footer(frame, world, audio_player, split[1]);
+        if self.state != UiState::Broadcast {
+            self.render_footer(frame, world, audio_player, split[1]);
+        }
 
         // render selected tab
+        let key_context = match self.state {
+            UiState::Splash | UiState::GameOver => KeyContext::Global,
+            UiState::NewTeam => KeyContext::NewTeam,
+            UiState::Main => KeyContext::Panel(self.ui_tabs[self.tab_index]),
+            UiState::SpaceAdventure => KeyContext::SpaceAdventure,
+            UiState::Broadcast => KeyContext::Broadcast,
+        };
+        self.callback_registry
+            .lock()
+            .unwrap()
+            .set_key_context(key_context);
         let render_result = match self.state {
             UiState::Splash => self
                 .splash_screen
@@ -364,12 +598,12 @@ impl Ui {
                         },
                         Arc::clone(&self.callback_registry),
                     )
-                    .set_hover_style(UiStyle::HIGHLIGHT);
+                    .set_hover_style(UiStyle::current().highlight);
 
                     if idx == self.tab_index {
                         button = button
-                            .set_style(UiStyle::SELECTED)
-                            .set_hover_style(UiStyle::SELECTED);
+                            .set_style(UiStyle::current().selected)
+                            .set_hover_style(UiStyle::current().selected);
                     }
 
                     frame.render_widget(button, tab_split[idx]);
@@ -383,6 +617,14 @@ impl Ui {
                 self.space_screen
                     .render(frame, world, split[0], self.debug_view)
             }
+            // The broadcast screen hides footer and tabs and takes the whole terminal.
+            UiState::Broadcast => self
+                .broadcast_screen
+                .render(frame, world, area, self.debug_view),
+            UiState::GameOver => {
+                self.game_over_screen
+                    .render(frame, world, split[0], self.debug_view)
+            }
         };
 
         if let Err(err) = render_result {
@@ -394,6 +636,10 @@ impl Ui {
             self.swarm_panel.push_log_event(event);
         }
 
+        self.callback_registry
+            .lock()
+            .unwrap()
+            .set_key_context(KeyContext::Popup);
         if let Err(err) = self.render_popup_messages(frame, area) {
             let event = SwarmPanelEvent {
                 timestamp: Tick::now(),
@@ -418,6 +664,10 @@ impl Ui {
         Ok(())
     }
 
+    pub fn active_tab(&self) -> UiTab {
+        self.ui_tabs[self.tab_index]
+    }
+
     pub fn switch_to(&mut self, tab: UiTab) {
         for i in 0..self.ui_tabs.len() {
             if self.ui_tabs[i] == tab {
@@ -436,6 +686,8 @@ impl Ui {
     ) {
         let split = Layout::horizontal([
             Constraint::Min(50),
+            Constraint::Length(12),
+            Constraint::Length(10),
             Constraint::Length(20),
             Constraint::Length(3),
             Constraint::Length(3),
@@ -445,7 +697,20 @@ impl Ui {
 
         let mut spans = vec![" Esc ".to_string(), " Quit ".to_string()];
 
-        if !self.debug_view && self.state == UiState::Main {
+        if self.callback_registry.lock().unwrap().is_keyboard_only() {
+            spans.extend(vec![
+                format!(
+                    " {}/{} ",
+                    UiKey::FOCUS_PREVIOUS.to_string(),
+                    UiKey::FOCUS_NEXT.to_string()
+                ),
+                " Focus ".to_string(),
+                format!(" {} ", UiKey::ACTIVATE_FOCUS.to_string()),
+                " Select ".to_string(),
+                format!(" {} ", UiKey::KEYBOARD_ONLY_MODE.to_string()),
+                " Exit keyboard-only ".to_string(),
+            ]);
+        } else if !self.debug_view && self.state == UiState::Main {
             spans.extend(vec![
                 format!(" {} ", UiKey::PREVIOUS_TAB.to_string()),
                 " Previous tab ".to_string(),
@@ -474,6 +739,7 @@ impl Ui {
                     world.next_free_pirates_refresh().formatted()
                 ));
             }
+            spans.push(format!(" {} ", asset_health().summary()));
 
             spans
         } else {
@@ -498,6 +764,47 @@ impl Ui {
             split[0],
         );
 
+        if world.has_own_team() {
+            let is_paused = world.time_speed == TimeSpeed::Paused;
+            frame.render_widget(
+                Button::no_box(
+                    format!(
+                        " {}: {} ",
+                        UiKey::PAUSE_WORLD.to_string(),
+                        if is_paused { "Resume" } else { "Pause " }
+                    )
+                    .into(),
+                    UiCallback::SetTimeSpeed {
+                        speed: if is_paused {
+                            TimeSpeed::Normal
+                        } else {
+                            TimeSpeed::Paused
+                        },
+                    },
+                    Arc::clone(&self.callback_registry),
+                )
+                .set_hotkey(UiKey::PAUSE_WORLD),
+                split[1],
+            );
+
+            frame.render_widget(
+                Button::no_box(
+                    format!(
+                        " {}: {} ",
+                        UiKey::FAST_FORWARD_WORLD.to_string(),
+                        world.time_speed
+                    )
+                    .into(),
+                    UiCallback::SetTimeSpeed {
+                        speed: world.time_speed.next(),
+                    },
+                    Arc::clone(&self.callback_registry),
+                )
+                .set_hotkey(UiKey::FAST_FORWARD_WORLD),
+                split[2],
+            );
+        }
+
         if let Some(audio_player) = &audio_player {
             frame.render_widget(
                 Button::no_box(
@@ -515,7 +822,7 @@ impl Ui {
                     Arc::clone(&self.callback_registry),
                 )
                 .set_hotkey(UiKey::TOGGLE_AUDIO),
-                split[1],
+                split[3],
             );
 
             frame.render_widget(
@@ -525,7 +832,7 @@ impl Ui {
                     Arc::clone(&self.callback_registry),
                 )
                 .set_hotkey(UiKey::PREVIOUS_RADIO),
-                split[2],
+                split[4],
             );
 
             frame.render_widget(
@@ -535,11 +842,11 @@ impl Ui {
                     Arc::clone(&self.callback_registry),
                 )
                 .set_hotkey(UiKey::NEXT_RADIO),
-                split[3],
+                split[5],
             );
             if audio_player.is_playing() {
                 if let Some(currently_playing) = audio_player.currently_playing() {
-                    frame.render_widget(Paragraph::new(format!(" {currently_playing} ")), split[4]);
+                    frame.render_widget(Paragraph::new(format!(" {currently_playing} ")), split[6]);
                 }
             }
         }
@@ -1,38 +1,77 @@
 use super::{
+    clipboard::ClipboardTarget,
     galaxy_panel::ZoomLevel,
+    keymap::KeyContext,
     my_team_panel::MyTeamView,
     new_team_screen::CreationState,
     player_panel::PlayerView,
+    player_sort::PlayerSort,
     popup_message::PopupMessage,
     swarm_panel::SwarmView,
     team_panel::TeamView,
     traits::{Screen, SplitPanel},
     ui::{UiState, UiTab},
+    utils::{format_satoshi, SwarmPanelEvent},
 };
 use crate::{
     app::App,
-    game_engine::{tactic::Tactic, types::TeamInGame},
+    export::{game_summary_markdown, TeamExport},
+    game_engine::{
+        tactic::Tactic,
+        types::{CrowdChatter, GameCommand, SpeechTone, TeamInGame},
+    },
     image::color_map::{ColorMap, ColorPreset},
-    network::{challenge::Challenge, trade::Trade},
+    network::{
+        address_book::parse_dial_target,
+        challenge::Challenge,
+        resource_trade::ResourceTrade,
+        trade::{
+            fair_sweetener, resources_value, trade_fairness, trade_value, value_fairness,
+            value_sweetener, Trade, TradeDraft, MAX_TRADE_SWEETENER,
+        },
+        types::NetworkRequestState,
+    },
     space_adventure::{PlayerControlled, PlayerInput, SpaceAdventure},
+    store::{
+        load_game, save_address_book, save_game_summary_export, save_settings, save_team_export,
+    },
     types::{
-        AppCallback, AppResult, GameId, PlanetId, PlayerId, ResourceMap, StorableResourceMap,
-        SystemTimeTick, TeamId, Tick,
+        AppCallback, AppResult, ContractId, GameId, KartoffelId, PlanetId, PlayerId, ResourceMap,
+        StorableResourceMap, SystemTimeTick, TeamId, Tick, TournamentId,
     },
     world::{
+        action_queue::{move_action_up, QueuedAction},
         constants::*,
+        duel::DUEL_RECAP_PLAYS,
+        emblem::Emblem,
+        finances::FinanceCategory,
+        generation::GenerationOptions,
+        gossip::Gossip,
+        hazards::HazardChoice,
+        hooks::HookEvent,
+        insurance::{cargo_loss_value, hull_loss_value, InsuranceKind},
         jersey::{Jersey, JerseyStyle},
-        player::Trait,
+        notifications::NotificationCategory,
+        planet::AsteroidFacility,
         resources::Resource,
         role::CrewRole,
-        skill::MAX_SKILL,
-        spaceship::{Spaceship, SpaceshipUpgrade, SpaceshipUpgradeTarget},
+        salvage::DerelictChoice,
+        skill::Rated,
+        space_upgrades::SpaceAdventureUpgrade,
+        spaceship::{
+            FuelGrade, Spaceship, SpaceshipModule, SpaceshipPrefab, SpaceshipUpgrade,
+            SpaceshipUpgradeTarget,
+        },
+        staff::StaffRole,
         team::Team,
-        types::{PlayerLocation, TeamBonus, TeamLocation, TrainingFocus},
+        traits::Trait,
+        types::{PlayerLocation, RenameTarget, TeamBonus, TeamLocation, TimeSpeed, TrainingFocus},
     },
 };
 use anyhow::anyhow;
-use crossterm::event::{KeyCode, MouseEvent, MouseEventKind};
+use crossterm::event::{KeyCode, MouseButton, MouseEvent, MouseEventKind};
+use itertools::Itertools;
+use libp2p::PeerId;
 use log::info;
 use rand::{seq::IteratorRandom, Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng;
@@ -43,9 +82,6 @@ use std::collections::HashMap;
 pub enum UiCallback {
     None,
     PromptQuit,
-    PushTutorialPage {
-        index: usize,
-    },
     ToggleUiDebugMode,
     SetPanelIndex {
         index: usize,
@@ -91,27 +127,109 @@ pub enum UiCallback {
     DeclineChallenge {
         challenge: Challenge,
     },
+    AcceptLocalChallenge {
+        team_id: TeamId,
+    },
+    DeclineLocalChallenge {
+        team_id: TeamId,
+    },
     CreateTradeProposal {
         proposer_player_id: PlayerId,
         target_player_id: PlayerId,
+        extra_satoshis: i64,
     },
     AcceptTrade {
         trade: Trade,
     },
+    CounterTrade {
+        trade: Trade,
+    },
     DeclineTrade {
         trade: Trade,
     },
+    OpenTradeBuilder {
+        team_id: TeamId,
+    },
+    ToggleTradeBuilderPlayer {
+        player_id: PlayerId,
+        is_proposer_side: bool,
+    },
+    UpdateTradeBuilderResource {
+        resource: Resource,
+        amount: i32,
+        is_proposer_side: bool,
+    },
+    ProposeTradeBundle,
     GoToTrade {
         trade: Trade,
     },
+    SetTeamPanelTradeResource {
+        resource: Resource,
+    },
+    SetTeamPanelTradeKartoffel {
+        index: usize,
+    },
+    ProposeKartoffelTrade {
+        team_id: TeamId,
+        kartoffel_id: KartoffelId,
+        satoshi: u32,
+    },
+    ProposeResourceTrade {
+        team_id: TeamId,
+        resource: Resource,
+        amount: u32,
+        satoshi: u32,
+        proposer_sells: bool,
+    },
+    AcceptResourceTrade {
+        trade: ResourceTrade,
+    },
+    DeclineResourceTrade {
+        trade: ResourceTrade,
+    },
     SetTeamColors {
         color: ColorPreset,
         channel: usize,
     },
+    SetAppearanceDraft {
+        jersey: Jersey,
+    },
+    ChangeTeamAppearance {
+        jersey: Jersey,
+    },
+    SetEmblemDraft {
+        emblem: Emblem,
+    },
+    ChangeTeamEmblem {
+        emblem: Emblem,
+    },
+    PromptRename {
+        target: RenameTarget,
+    },
+    Rename {
+        target: RenameTarget,
+        name: String,
+    },
     SetTeamTactic {
         tactic: Tactic,
     },
     SetNextTeamTactic,
+    CallGameTimeout {
+        game_id: GameId,
+    },
+    SubstituteGamePlayer {
+        game_id: GameId,
+        player_out: PlayerId,
+        player_in: PlayerId,
+    },
+    GiveCaptainSpeech {
+        game_id: GameId,
+        tone: SpeechTone,
+    },
+    SetGameTactic {
+        game_id: GameId,
+        tactic: Tactic,
+    },
     NextUiTab,
     PreviousUiTab,
     SetUiTab {
@@ -119,11 +237,34 @@ pub enum UiCallback {
     },
     NextPanelIndex,
     PreviousPanelIndex,
+    PanelPageUp,
+    PanelPageDown,
     CloseUiPopup,
     NewGame,
+    GenerateGalaxy {
+        options: GenerationOptions,
+    },
     ContinueGame,
     QuitGame,
+    ContinueInSandbox,
     ToggleAudio,
+    CycleUiTheme,
+    ToggleAccessibleMode,
+    ExportTeamData,
+    CopyToClipboard {
+        target: ClipboardTarget,
+    },
+    PasteFromClipboard,
+    ShareGameSummary {
+        game_id: GameId,
+    },
+    CycleNotificationFilter,
+    ClearNotifications {
+        category: Option<NotificationCategory>,
+    },
+    SetTimeSpeed {
+        speed: TimeSpeed,
+    },
     PreviousRadio,
     NextRadio,
     SetSwarmPanelView {
@@ -132,12 +273,25 @@ pub enum UiCallback {
     SetMyTeamPanelView {
         view: MyTeamView,
     },
+    ToggleTutorialChecklist,
+    SetMyTeamPanelSpaceshipPrefab {
+        index: usize,
+    },
+    ScrollPlayByPlay {
+        lines: i16,
+    },
     SetPlayerPanelView {
         view: PlayerView,
     },
     SetTeamPanelView {
         view: TeamView,
     },
+    SortRoster {
+        players_sort: PlayerSort,
+    },
+    SortPlayerList {
+        players_sort: PlayerSort,
+    },
     HirePlayer {
         player_id: PlayerId,
     },
@@ -157,6 +311,17 @@ pub enum UiCallback {
     Drink {
         player_id: PlayerId,
     },
+    Duel {
+        challenger_id: PlayerId,
+        opponent_id: PlayerId,
+    },
+    AssignKartoffel {
+        kartoffel_id: KartoffelId,
+        player_id: Option<PlayerId>,
+    },
+    FeedKartoffel {
+        kartoffel_id: KartoffelId,
+    },
     GeneratePlayerTeam {
         name: String,
         home_planet: PlanetId,
@@ -164,21 +329,51 @@ pub enum UiCallback {
         jersey_colors: ColorMap,
         players: Vec<PlayerId>,
         spaceship: Spaceship,
+        campaign: bool,
     },
     CancelGeneratePlayerTeam,
+    ToggleCampaignMode,
     AssignBestTeamPositions,
     SwapPlayerPositions {
         player_id: PlayerId,
         position: usize,
     },
     TogglePitchView,
+    ToggleBroadcastMode,
     TogglePlayerStatusView,
     NextTrainingFocus {
         team_id: TeamId,
     },
+    SetTrainingFocus {
+        focus: TrainingFocus,
+    },
+    SetTrainingSession {
+        day: usize,
+        focus: Option<TrainingFocus>,
+    },
     TravelToPlanet {
         planet_id: PlanetId,
     },
+    TravelRouteToPlanet {
+        planet_id: PlanetId,
+    },
+    QueueAction {
+        action: QueuedAction,
+    },
+    RemoveQueuedAction {
+        index: usize,
+    },
+    MoveQueuedActionUp {
+        index: usize,
+    },
+    ClearActionQueue,
+    SetCoCaptain {
+        team_id: Option<TeamId>,
+    },
+    SendCoCaptainOrder {
+        team_id: TeamId,
+        action: QueuedAction,
+    },
     ExploreAroundPlanet {
         duration: Tick,
     },
@@ -186,10 +381,29 @@ pub enum UiCallback {
         planet_id: PlanetId,
     },
     DialSeed,
+    DialAddress {
+        target: String,
+    },
+    ShowAddressBook,
+    SetPeerFavorite {
+        peer_id: PeerId,
+        favorite: bool,
+    },
+    SetPeerBlocked {
+        peer_id: PeerId,
+        blocked: bool,
+    },
     Sync,
     SendMessage {
         message: String,
     },
+    SendDirectMessage {
+        peer_id: PeerId,
+        message: String,
+    },
+    SendCrowdChatter {
+        text: String,
+    },
     PushUiPopup {
         popup_message: PopupMessage,
     },
@@ -203,7 +417,87 @@ pub enum UiCallback {
     UpgradeSpaceship {
         upgrade: SpaceshipUpgrade,
     },
-    StartSpaceAdventure,
+    InstallSpaceshipModule {
+        module: SpaceshipModule,
+    },
+    UninstallSpaceshipModule {
+        module: SpaceshipModule,
+    },
+    BuySpaceship {
+        prefab: SpaceshipPrefab,
+    },
+    ResolveDerelict {
+        choice: DerelictChoice,
+    },
+    ResolveTravelHazard {
+        choice: HazardChoice,
+    },
+    SwitchSpaceship,
+    UnlockSpaceAdventureUpgrade {
+        upgrade: SpaceAdventureUpgrade,
+    },
+    BuildAsteroidFacility {
+        asteroid_id: PlanetId,
+        facility: AsteroidFacility,
+    },
+    AcceptContract {
+        contract_id: ContractId,
+    },
+    DeliverContract {
+        contract_id: ContractId,
+    },
+    ScoutPlanet {
+        planet_id: PlanetId,
+    },
+    RegisterForTournament {
+        tournament_id: TournamentId,
+    },
+    PayCrewBonus,
+    ShareRum,
+    TakeLoan {
+        amount: u32,
+    },
+    RepayLoan {
+        amount: u32,
+    },
+    BuyInsurance {
+        kind: InsuranceKind,
+    },
+    SetFuelGrade {
+        grade: FuelGrade,
+    },
+    SetEngineTuning {
+        tuning: i8,
+    },
+    SetAutoRefuel {
+        enabled: bool,
+    },
+    SetAutoRefuelTarget {
+        target: u8,
+    },
+    ScanNearbyPlanets,
+    EnrollInAcademy {
+        player_id: PlayerId,
+    },
+    PromoteProspect {
+        player_id: PlayerId,
+    },
+    ReleaseProspect {
+        player_id: PlayerId,
+    },
+    HireStaff {
+        role: StaffRole,
+    },
+    FireStaff {
+        role: StaffRole,
+    },
+    BuildWormholeGate {
+        planet_id: PlanetId,
+    },
+    PromptSelectPilot,
+    StartSpaceAdventure {
+        pilot_id: PlayerId,
+    },
     StopSpaceAdventure,
     ReturnFromSpaceAdventure,
     SpaceMovePlayerLeft,
@@ -212,6 +506,7 @@ pub enum UiCallback {
     SpaceMovePlayerUp,
     SpaceMainButton,
     SpaceSecondButton,
+    ToggleSpaceAdventurePause,
 }
 
 impl UiCallback {
@@ -383,6 +678,12 @@ impl UiCallback {
                 PlayerLocation::WithTeam => {
                     return Self::go_to_current_team_planet(player.team.unwrap())(app);
                 }
+                PlayerLocation::InAcademy { asteroid_id, .. } => {
+                    app.ui
+                        .galaxy_panel
+                        .go_to_planet(asteroid_id, None, ZoomLevel::In);
+                    app.ui.switch_to(super::ui::UiTab::Galaxy);
+                }
             };
 
             Ok(None)
@@ -412,6 +713,9 @@ impl UiCallback {
     fn trade_resource(resource: Resource, amount: i32, unit_cost: u32) -> AppCallback {
         Box::new(move |app: &mut App| {
             let mut own_team = app.world.get_own_team()?.clone();
+            if let Some(planet_id) = own_team.is_on_planet() {
+                app.world.can_trade_on_planet(planet_id, app.world.now())?;
+            }
             if amount > 0 {
                 let max_capacity = if resource == Resource::FUEL {
                     own_team.fuel_capacity()
@@ -424,6 +728,13 @@ impl UiCallback {
                 own_team
                     .resources
                     .sub(Resource::SATOSHI, unit_cost * amount as u32)?;
+                app.world.record_transaction(
+                    own_team.id,
+                    app.world.now(),
+                    FinanceCategory::Trading,
+                    -((unit_cost * amount as u32) as i64),
+                    format!("Bought {} {}", amount, resource),
+                );
             } else if amount < 0 {
                 own_team.resources.sub(resource, (-amount) as u32)?;
                 own_team.resources.add(
@@ -431,6 +742,18 @@ impl UiCallback {
                     unit_cost * (-amount) as u32,
                     own_team.storage_capacity(),
                 )?;
+                app.world.record_transaction(
+                    own_team.id,
+                    app.world.now(),
+                    FinanceCategory::Trading,
+                    (unit_cost * (-amount) as u32) as i64,
+                    format!("Sold {} {}", -amount, resource),
+                );
+            }
+            if let Some(planet_id) = own_team.is_on_planet() {
+                app.world
+                    .register_market_trade(own_team.id, planet_id, resource, amount)?;
+                own_team.add_planet_standing(planet_id, PLANET_STANDING_TRADE_BONUS);
             }
             app.world.teams.insert(own_team.id, own_team);
             app.world.dirty = true;
@@ -534,7 +857,11 @@ impl UiCallback {
         })
     }
 
-    fn trade_players(proposer_player_id: PlayerId, target_player_id: PlayerId) -> AppCallback {
+    fn trade_players(
+        proposer_player_id: PlayerId,
+        target_player_id: PlayerId,
+        extra_satoshis: i64,
+    ) -> AppCallback {
         Box::new(move |app: &mut App| {
             let own_team = app.world.get_own_team()?;
 
@@ -547,21 +874,55 @@ impl UiCallback {
 
             let proposer_player = app.world.get_player_or_err(proposer_player_id)?;
             own_team.can_trade_players(proposer_player, target_player, target_team)?;
+            own_team.can_pay_trade_satoshis(extra_satoshis)?;
 
             if let Some(peer_id) = target_team.peer_id {
                 let trade = app
                     .network_handler
                     .as_mut()
                     .ok_or(anyhow!("Network handler is not initialized"))?
-                    .send_new_trade(&app.world, peer_id, proposer_player_id, target_player_id)?;
+                    .send_new_trade(
+                        &app.world,
+                        peer_id,
+                        proposer_player_id,
+                        target_player_id,
+                        extra_satoshis,
+                    )?;
                 let own_team = app.world.get_own_team_mut()?;
                 own_team.add_sent_trade(trade);
+                app.ui.player_panel.trade_sweetener = None;
                 return Ok(Some("Trade offer sent".to_string()));
             }
 
-            if proposer_player.bare_value() >= target_player.bare_value() {
+            if trade_fairness(proposer_player, target_player, extra_satoshis) >= 0.0 {
+                let own_team_id = own_team.id;
+                let target_team_id = target_team.id;
+                let proposer_name = proposer_player.info.shortened_name();
+                let target_name = target_player.info.shortened_name();
                 app.world
                     .swap_players_team(proposer_player_id, target_player_id)?;
+                app.world.settle_trade_satoshis(
+                    own_team_id,
+                    -extra_satoshis,
+                    app.world.now(),
+                    format!("Trade sweetener for {}", target_name),
+                )?;
+                app.world.settle_trade_satoshis(
+                    target_team_id,
+                    extra_satoshis,
+                    app.world.now(),
+                    format!("Trade sweetener for {}", proposer_name),
+                )?;
+                app.world.emit_hook_event(HookEvent::team_trade(
+                    own_team_id,
+                    target_team_id,
+                    &[proposer_player_id],
+                    &[target_player_id],
+                    &ResourceMap::new(),
+                    &ResourceMap::new(),
+                    extra_satoshis,
+                ));
+                app.ui.player_panel.trade_sweetener = None;
 
                 let locked_id = app.ui.player_panel.locked_player_id;
                 let selected_id = app.ui.player_panel.selected_player_id;
@@ -572,9 +933,192 @@ impl UiCallback {
 
                 return Ok(Some("Trade accepted".to_string()));
             }
+
+            // The AI answers with the sweetener that would make the trade fair.
+            let sweetener = fair_sweetener(proposer_player, target_player);
+            if sweetener <= MAX_TRADE_SWEETENER
+                && own_team.can_pay_trade_satoshis(sweetener as i64).is_ok()
+            {
+                let message = format!(
+                    "Trade rejected.\nCounter-offer: {} would accept for a sweetener of {}.",
+                    target_team.name,
+                    format_satoshi(sweetener)
+                );
+                app.ui.player_panel.trade_sweetener =
+                    Some((proposer_player_id, target_player_id, sweetener));
+                return Ok(Some(message));
+            }
             return Ok(Some("Trade Rejected".to_string()));
         })
     }
+
+    fn open_trade_builder(team_id: TeamId) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let own_team = app.world.get_own_team()?;
+            let target_team = app.world.get_team_or_err(team_id)?;
+            own_team.can_exchange_resources(
+                target_team,
+                (Resource::SATOSHI, 0),
+                (Resource::SATOSHI, 0),
+            )?;
+
+            let candidates = |team: &Team| -> AppResult<Vec<(PlayerId, String, f32)>> {
+                team.player_ids
+                    .iter()
+                    .map(|id| {
+                        let player = app.world.get_player_or_err(*id)?;
+                        Ok((player.id, player.info.shortened_name(), trade_value(player)))
+                    })
+                    .collect()
+            };
+            let own_players = candidates(own_team)?;
+            let target_players = candidates(target_team)?;
+            let target_team_name = target_team.name.clone();
+
+            app.ui.push_popup(PopupMessage::TradeBuilder {
+                draft: TradeDraft::new(team_id),
+                target_team_name,
+                own_players,
+                target_players,
+                error: None,
+                tick: Tick::now(),
+            });
+            Ok(None)
+        })
+    }
+
+    fn trade_builder_draft(app: &mut App) -> AppResult<TradeDraft> {
+        match app.ui.popup_mut() {
+            Some(PopupMessage::TradeBuilder { draft, .. }) => Ok(draft.clone()),
+            _ => Err(anyhow!("Trade builder is not open")),
+        }
+    }
+
+    fn set_trade_builder(app: &mut App, updated_draft: TradeDraft, message: Option<String>) {
+        if let Some(PopupMessage::TradeBuilder { draft, error, .. }) = app.ui.popup_mut() {
+            *draft = updated_draft;
+            *error = message;
+        }
+    }
+
+    // Checks the draft against the current state of both teams.
+    fn check_trade_draft(app: &App, draft: &TradeDraft) -> AppResult<()> {
+        draft.is_valid()?;
+        let own_team = app.world.get_own_team()?;
+        let target_team = app.world.get_team_or_err(draft.target_team_id)?;
+        let players = |player_ids: &[PlayerId]| {
+            player_ids
+                .iter()
+                .map(|id| app.world.get_player_or_err(*id))
+                .collect::<AppResult<Vec<_>>>()
+        };
+        let proposer_players = players(&draft.proposer_player_ids)?;
+        let target_players = players(&draft.target_player_ids)?;
+
+        own_team.can_trade_players(proposer_players[0], target_players[0], target_team)?;
+        own_team.can_trade_bundle(
+            &proposer_players,
+            &target_players,
+            &draft.proposer_resources,
+            &draft.target_resources,
+            target_team,
+        )?;
+        target_team.can_trade_bundle(
+            &target_players,
+            &proposer_players,
+            &draft.target_resources,
+            &draft.proposer_resources,
+            own_team,
+        )?;
+        Ok(())
+    }
+
+    fn update_trade_builder(
+        app: &mut App,
+        update: impl FnOnce(&mut TradeDraft) -> AppResult<()>,
+    ) -> AppResult<Option<String>> {
+        let mut draft = Self::trade_builder_draft(app)?;
+        let result = update(&mut draft).and_then(|_| Self::check_trade_draft(app, &draft));
+        Self::set_trade_builder(app, draft, result.err().map(|err| err.to_string()));
+        Ok(None)
+    }
+
+    fn propose_trade_bundle() -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let draft = Self::trade_builder_draft(app)?;
+            if let Err(err) = Self::check_trade_draft(app, &draft) {
+                Self::set_trade_builder(app, draft, Some(err.to_string()));
+                return Ok(None);
+            }
+
+            let own_team = app.world.get_own_team()?;
+            let target_team = app.world.get_team_or_err(draft.target_team_id)?;
+            if let Some(peer_id) = target_team.peer_id {
+                let trade = app
+                    .network_handler
+                    .as_mut()
+                    .ok_or(anyhow!("Network handler is not initialized"))?
+                    .send_new_trade_bundle(&app.world, peer_id, &draft)?;
+                let own_team = app.world.get_own_team_mut()?;
+                own_team.add_sent_trade(trade);
+                app.ui.close_popup();
+                return Ok(Some("Trade offer sent".to_string()));
+            }
+
+            // AI teams accept any trade that is fair for them.
+            let value = |player_ids: &[PlayerId], resources: &ResourceMap| -> AppResult<f32> {
+                let mut value = resources_value(resources);
+                for player_id in player_ids.iter() {
+                    value += trade_value(app.world.get_player_or_err(*player_id)?);
+                }
+                Ok(value)
+            };
+            let offered = value(&draft.proposer_player_ids, &draft.proposer_resources)?;
+            let requested = value(&draft.target_player_ids, &draft.target_resources)?;
+            let own_team_id = own_team.id;
+            let own_team_name = own_team.name.clone();
+            let target_team_name = target_team.name.clone();
+
+            if value_fairness(offered, requested) < 0.0 {
+                let message = format!(
+                    "Trade rejected: {} would accept for {} more.",
+                    target_team_name,
+                    format_satoshi(value_sweetener(offered, requested))
+                );
+                Self::set_trade_builder(app, draft, Some(message));
+                return Ok(None);
+            }
+
+            app.world
+                .swap_player_groups(&draft.proposer_player_ids, &draft.target_player_ids)?;
+            app.world.settle_trade_resources(
+                own_team_id,
+                &draft.proposer_resources,
+                &draft.target_resources,
+                app.world.now(),
+                format!("Trade with {}", target_team_name),
+            )?;
+            app.world.settle_trade_resources(
+                draft.target_team_id,
+                &draft.target_resources,
+                &draft.proposer_resources,
+                app.world.now(),
+                format!("Trade with {}", own_team_name),
+            )?;
+            app.world.emit_hook_event(HookEvent::team_trade(
+                own_team_id,
+                draft.target_team_id,
+                &draft.proposer_player_ids,
+                &draft.target_player_ids,
+                &draft.proposer_resources,
+                &draft.target_resources,
+                0,
+            ));
+            app.ui.close_popup();
+            Ok(Some("Trade accepted".to_string()))
+        })
+    }
+
     fn next_ui_tab() -> AppCallback {
         Box::new(move |app: &mut App| {
             app.ui.next_tab();
@@ -614,6 +1158,24 @@ impl UiCallback {
         })
     }
 
+    fn panel_page_up() -> AppCallback {
+        Box::new(move |app: &mut App| {
+            if let Some(panel) = app.ui.get_active_panel() {
+                panel.page_up();
+            }
+            Ok(None)
+        })
+    }
+
+    fn panel_page_down() -> AppCallback {
+        Box::new(move |app: &mut App| {
+            if let Some(panel) = app.ui.get_active_panel() {
+                panel.page_down();
+            }
+            Ok(None)
+        })
+    }
+
     fn generate_own_team(
         name: String,
         home_planet: PlanetId,
@@ -621,6 +1183,7 @@ impl UiCallback {
         jersey_colors: ColorMap,
         players: Vec<PlayerId>,
         spaceship: Spaceship,
+        campaign: bool,
     ) -> AppCallback {
         Box::new(move |app: &mut App| {
             app.world.generate_own_team(
@@ -632,10 +1195,20 @@ impl UiCallback {
                 spaceship.clone(),
             )?;
             app.ui.set_state(UiState::Main);
-            app.ui.push_popup(PopupMessage::Tutorial {
-                index: 0,
+            let message = app.world.start_tutorial()?;
+            app.ui.push_popup(PopupMessage::Ok {
+                message,
+                is_skippable: false,
                 tick: Tick::now(),
             });
+            if campaign {
+                let message = app.world.start_campaign(app.world.now())?;
+                app.ui.push_popup(PopupMessage::Ok {
+                    message,
+                    is_skippable: false,
+                    tick: Tick::now(),
+                });
+            }
             Ok(None)
         })
     }
@@ -670,6 +1243,7 @@ impl UiCallback {
     fn swap_player_positions(player_id: PlayerId, position: usize) -> AppCallback {
         Box::new(move |app: &mut App| {
             let mut team = app.world.get_own_team()?.clone();
+            team.can_set_starting_position(app.world.get_player_or_err(player_id)?)?;
             let current_player_position = team
                 .player_ids
                 .iter()
@@ -702,6 +1276,32 @@ impl UiCallback {
         })
     }
 
+    fn set_training_focus(focus: TrainingFocus) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let mut team = app.world.get_own_team()?.clone();
+            team.can_change_training_focus()?;
+            team.training_focus = Some(focus);
+            app.world.teams.insert(team.id, team);
+            app.world.dirty = true;
+            app.world.dirty_network = true;
+            app.world.dirty_ui = true;
+            Ok(None)
+        })
+    }
+
+    fn set_training_session(day: usize, focus: Option<TrainingFocus>) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let mut team = app.world.get_own_team()?.clone();
+            team.can_change_training_focus()?;
+            team.training_schedule.set_session(day, focus);
+            app.world.teams.insert(team.id, team);
+            app.world.dirty = true;
+            app.world.dirty_network = true;
+            app.world.dirty_ui = true;
+            Ok(None)
+        })
+    }
+
     fn travel_to_planet(planet_id: PlanetId) -> AppCallback {
         Box::new(move |app: &mut App| {
             let mut own_team = app.world.get_own_team()?.clone();
@@ -730,10 +1330,11 @@ impl UiCallback {
             let distance = app
                 .world
                 .distance_between_planets(current_planet.id, target_planet.id)?;
+            own_team.travel_route.clear();
             own_team.current_location = TeamLocation::Travelling {
                 from: current_planet.id,
                 to: planet_id,
-                started: Tick::now(),
+                started: app.world.now(),
                 duration,
                 distance,
             };
@@ -776,62 +1377,111 @@ impl UiCallback {
         })
     }
 
-    fn explore_around_planet(duration: Tick) -> AppCallback {
+    fn travel_route_to_planet(planet_id: PlanetId) -> AppCallback {
         Box::new(move |app: &mut App| {
-            let mut own_team = app.world.get_own_team()?.clone();
-
-            let planet_id = match own_team.current_location {
-                TeamLocation::OnPlanet { planet_id } => planet_id,
-                TeamLocation::Travelling { .. } => return Err(anyhow!("Team is travelling")),
-                TeamLocation::Exploring { .. } => return Err(anyhow!("Team is already exploring")),
-                TeamLocation::OnSpaceAdventure { .. } => {
-                    return Err(anyhow!("Team is on a space adventure"))
-                }
-            };
-
-            let mut around_planet = app.world.get_planet_or_err(planet_id)?.clone();
-            own_team.can_explore_around_planet(&around_planet, duration)?;
-
-            own_team.current_location = TeamLocation::Exploring {
-                around: planet_id,
-                started: Tick::now(),
-                duration,
-            };
-
-            // For simplicity we just subtract the fuel upfront, maybe would be nicer on UI to
-            // show the fuel consumption as the team travels in world.tick_travel,
-            // but this would require more operations and checks in the tick function.
-            own_team.resources.sub(
-                Resource::FUEL,
-                (duration as f32 * own_team.spaceship_fuel_consumption()).max(1.0) as u32,
-            )?;
-
-            around_planet.team_ids.retain(|&x| x != own_team.id);
-            app.world.planets.insert(around_planet.id, around_planet);
+            let own_team = app.world.get_own_team()?.clone();
+            app.world
+                .travel_along_route(&own_team, planet_id, app.world.now())?;
+            info!(
+                "Team {:?} is travelling to {:?} with {} stops",
+                own_team.id,
+                planet_id,
+                app.world.get_own_team()?.travel_route.len()
+            );
+            Ok(None)
+        })
+    }
 
-            let pirate_jersey = Jersey {
-                style: JerseyStyle::Pirate,
-                color: own_team.jersey.color.clone(),
-            };
+    fn queue_action(action: QueuedAction) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let mut own_team = app.world.get_own_team()?.clone();
+            own_team.can_queue_action(action)?;
+            own_team.action_queue.push(action);
+            own_team.action_queue_version += 1;
+            let is_co_captained = own_team.co_captain_id.is_some();
+            app.world.teams.insert(own_team.id, own_team);
 
-            for player in own_team.player_ids.iter() {
-                let mut player = app.world.get_player_or_err(*player)?.clone();
-                player.set_jersey(&pirate_jersey);
-                app.world.players.insert(player.id, player);
+            // Orders are attributed to each captain when the team is co-captained.
+            if is_co_captained {
+                let description = action.description(&app.world);
+                app.world.log_crew_event(
+                    app.world.now(),
+                    format!("Captain ordered: {}", description.to_lowercase()),
+                );
+                app.world.dirty_network = true;
             }
-
-            app.world.teams.insert(own_team.id, own_team);
             app.world.dirty = true;
-            app.world.dirty_network = true;
             app.world.dirty_ui = true;
-
             Ok(None)
         })
     }
 
-    fn dial_seed() -> AppCallback {
+    fn edit_action_queue<F>(edit: F) -> AppCallback
+    where
+        F: Fn(&mut Vec<QueuedAction>) + 'static,
+    {
         Box::new(move |app: &mut App| {
-            app.network_handler
+            let mut own_team = app.world.get_own_team()?.clone();
+            edit(&mut own_team.action_queue);
+            own_team.action_queue_version += 1;
+            if own_team.co_captain_id.is_some() {
+                app.world.dirty_network = true;
+            }
+            app.world.teams.insert(own_team.id, own_team);
+            app.world.dirty = true;
+            app.world.dirty_ui = true;
+            Ok(None)
+        })
+    }
+
+    fn set_co_captain(team_id: Option<TeamId>) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let mut own_team = app.world.get_own_team()?.clone();
+            let message = match team_id {
+                Some(id) => {
+                    let team = app.world.get_team_or_err(id)?;
+                    if team.peer_id.is_none() {
+                        return Err(anyhow!("Only network teams can be co-captains"));
+                    }
+                    format!("{} is now co-captain", team.name)
+                }
+                None => "Co-captain dismissed".to_string(),
+            };
+            own_team.co_captain_id = team_id;
+            app.world.teams.insert(own_team.id, own_team);
+            app.world.log_crew_event(app.world.now(), message.clone());
+            app.world.dirty = true;
+            app.world.dirty_network = true;
+            app.world.dirty_ui = true;
+            Ok(Some(message))
+        })
+    }
+
+    fn send_co_captain_order(team_id: TeamId, action: QueuedAction) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let team = app.world.get_team_or_err(team_id)?;
+            team.can_queue_action(action)?;
+            app.network_handler
+                .as_mut()
+                .ok_or(anyhow!("Network handler is not initialized"))?
+                .send_new_co_captain_order(&app.world, team_id, action)?;
+
+            Ok(Some("Order sent".to_string()))
+        })
+    }
+
+    fn explore_around_planet(duration: Tick) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let own_team = app.world.get_own_team()?.clone();
+            app.world
+                .start_exploration(&own_team, duration, app.world.now())?;
+            Ok(None)
+        })
+    }
+
+    fn dial_seed() -> AppCallback {
+        Box::new(move |app: &mut App| {
+            app.network_handler
                 .as_mut()
                 .ok_or(anyhow!("Network handler is not initialized"))?
                 .dial_seed()
@@ -841,6 +1491,75 @@ impl UiCallback {
         })
     }
 
+    fn dial_address(target: String) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let network_handler = app
+                .network_handler
+                .as_mut()
+                .ok_or(anyhow!("Network handler is not initialized"))?;
+            let address = parse_dial_target(&target, &network_handler.address_book)?;
+            network_handler.dial_address(address.clone())?;
+            app.ui.swarm_panel.push_log_event(SwarmPanelEvent {
+                timestamp: Tick::now(),
+                peer_id: None,
+                text: format!("Dialing {}...", address),
+            });
+            Ok(None)
+        })
+    }
+
+    fn show_address_book() -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let network_handler = app
+                .network_handler
+                .as_ref()
+                .ok_or(anyhow!("Network handler is not initialized"))?;
+            let address_book = &network_handler.address_book;
+            let text = if address_book.entries.is_empty() {
+                "Address book is empty: /dial <address> to connect to a friend.".to_string()
+            } else {
+                let mut lines = address_book
+                    .sorted_entries()
+                    .iter()
+                    .map(|entry| {
+                        format!(
+                            "{}{} {} {} {} (last seen {})",
+                            if entry.favorite { "* " } else { "" },
+                            entry.name.clone().unwrap_or("Unknown".to_string()),
+                            entry
+                                .rating
+                                .map(|rating| rating.stars())
+                                .unwrap_or_default(),
+                            entry
+                                .peer_id
+                                .map(|peer_id| peer_id.to_base58())
+                                .unwrap_or_default(),
+                            entry
+                                .address
+                                .as_ref()
+                                .map(|address| address.to_string())
+                                .unwrap_or_default(),
+                            entry
+                                .last_seen
+                                .map(|tick| tick.formatted_as_date())
+                                .unwrap_or("never".to_string())
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                if !address_book.blocked.is_empty() {
+                    lines.push(format!("Blocked peers: {}", address_book.blocked.len()));
+                }
+                lines.join("\n")
+            };
+            app.ui.swarm_panel.push_log_event(SwarmPanelEvent {
+                timestamp: Tick::now(),
+                peer_id: None,
+                text,
+            });
+            Ok(None)
+        })
+    }
+
     fn sync() -> AppCallback {
         Box::new(move |app: &mut App| {
             app.world.dirty_network = true;
@@ -859,6 +1578,44 @@ impl UiCallback {
         })
     }
 
+    fn send_crowd_chatter(text: String) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let game_id = app
+                .ui
+                .game_panel
+                .selected_game_id()
+                .ok_or(anyhow!("Select a game in the games panel first"))?;
+            let game = app.world.get_game_or_err(game_id)?;
+
+            if game.home_team_in_game.peer_id.is_none() && game.away_team_in_game.peer_id.is_none()
+            {
+                return Err(anyhow!("Crowd chatter is only available for network games"));
+            }
+            if game.home_team_in_game.team_id == app.world.own_team_id
+                || game.away_team_in_game.team_id == app.world.own_team_id
+            {
+                return Err(anyhow!("Players cannot join the crowd chatter"));
+            }
+
+            let author = app.world.get_own_team()?.name.clone();
+            let chatter = CrowdChatter::new(game_id, author, game.timer, &text)?;
+            app.world.add_crowd_chatter(chatter.clone())?;
+            app.network_handler
+                .as_mut()
+                .ok_or(anyhow!("Network handler is not initialized"))?
+                .send_crowd_chatter(chatter)?;
+
+            Ok(None)
+        })
+    }
+
+    fn queue_game_command(game_id: GameId, command: GameCommand) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            app.world.queue_game_command(game_id, command)?;
+            Ok(None)
+        })
+    }
+
     fn name_and_accept_asteroid(name: String, filename: String) -> AppCallback {
         Box::new(move |app: &mut App| {
             let mut team = app.world.get_own_team()?.clone();
@@ -885,7 +1642,9 @@ impl UiCallback {
                     team.version += 1;
 
                     app.world.planets.insert(asteroid.id, asteroid);
+                    let team_id = team.id;
                     app.world.teams.insert(team.id, team);
+                    app.world.discover_planets(team_id, vec![asteroid_id]);
                 }
                 _ => return Err(anyhow!("Invalid team location when accepting asteroid.")),
             }
@@ -906,6 +1665,23 @@ impl UiCallback {
 
             for (resource, amount) in &upgrade.cost() {
                 team.resources.sub(*resource, *amount)?;
+                if *resource == Resource::SATOSHI {
+                    let description = match upgrade.target {
+                        SpaceshipUpgradeTarget::Hull { .. } => "Hull upgrade",
+                        SpaceshipUpgradeTarget::Engine { .. } => "Engine upgrade",
+                        SpaceshipUpgradeTarget::Storage { .. } => "Storage upgrade",
+                        SpaceshipUpgradeTarget::LifeSupport { .. } => "Life support upgrade",
+                        SpaceshipUpgradeTarget::Scanner { .. } => "Scanner upgrade",
+                        SpaceshipUpgradeTarget::Repairs { .. } => "Spaceship repairs",
+                    };
+                    app.world.record_transaction(
+                        team.id,
+                        app.world.now(),
+                        FinanceCategory::Spaceship,
+                        -(*amount as i64),
+                        description.to_string(),
+                    );
+                }
             }
 
             team.spaceship.pending_upgrade = Some(upgrade.clone());
@@ -933,6 +1709,12 @@ impl UiCallback {
                 SpaceshipUpgradeTarget::Storage { component } => {
                     team.spaceship.storage = component.clone()
                 }
+                SpaceshipUpgradeTarget::LifeSupport { component } => {
+                    team.spaceship.life_support = component.clone()
+                }
+                SpaceshipUpgradeTarget::Scanner { component } => {
+                    team.spaceship.scanner = component.clone()
+                }
                 SpaceshipUpgradeTarget::Repairs { .. } => {}
             };
 
@@ -957,6 +1739,270 @@ impl UiCallback {
         })
     }
 
+    fn unlock_space_adventure_upgrade(upgrade: SpaceAdventureUpgrade) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let mut team = app.world.get_own_team()?.clone();
+            team.can_unlock_space_adventure_upgrade(upgrade)?;
+
+            for (resource, amount) in &team.space_adventure_progression.next_cost(upgrade) {
+                team.resources.sub(*resource, *amount)?;
+            }
+            team.space_adventure_progression.unlock(upgrade)?;
+            let level = team.space_adventure_progression.level(upgrade);
+            app.world.teams.insert(team.id, team);
+
+            app.world.dirty = true;
+            app.world.dirty_network = true;
+            app.world.dirty_ui = true;
+
+            Ok(Some(format!("{} upgraded to level {}", upgrade, level)))
+        })
+    }
+
+    fn install_spaceship_module(module: SpaceshipModule) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let mut team = app.world.get_own_team()?.clone();
+            team.can_install_spaceship_module(module)?;
+
+            for (resource, amount) in &module.cost() {
+                team.resources.sub(*resource, *amount)?;
+                if *resource == Resource::SATOSHI {
+                    app.world.record_transaction(
+                        team.id,
+                        app.world.now(),
+                        FinanceCategory::Spaceship,
+                        -(*amount as i64),
+                        format!("Installed {}", module),
+                    );
+                }
+            }
+
+            team.spaceship.modules.push(module);
+            app.world.teams.insert(team.id, team);
+
+            app.world.dirty = true;
+            app.world.dirty_network = true;
+            app.world.dirty_ui = true;
+
+            Ok(Some(format!("{} installed", module)))
+        })
+    }
+
+    fn buy_spaceship(prefab: SpaceshipPrefab) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let mut team = app.world.get_own_team()?.clone();
+            team.can_buy_spaceship(prefab)?;
+            let asteroid_id = team
+                .is_on_planet()
+                .ok_or(anyhow!("Team is not on a planet"))?;
+
+            let cost = prefab.cost();
+            team.resources.sub(Resource::SATOSHI, cost)?;
+            app.world.record_transaction(
+                team.id,
+                app.world.now(),
+                FinanceCategory::Spaceship,
+                -(cost as i64),
+                format!("Bought {}", prefab),
+            );
+
+            let spaceship = prefab
+                .spaceship(prefab.to_string())
+                .with_color_map(team.spaceship.image.color_map());
+            team.docked_spaceships.push((asteroid_id, spaceship));
+            app.world.teams.insert(team.id, team);
+
+            app.world.dirty = true;
+            app.world.dirty_network = true;
+            app.world.dirty_ui = true;
+
+            Ok(Some(format!("{} docked at the asteroid", prefab)))
+        })
+    }
+
+    fn build_asteroid_facility(asteroid_id: PlanetId, facility: AsteroidFacility) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let mut team = app.world.get_own_team()?.clone();
+            let mut asteroid = app.world.get_planet_or_err(asteroid_id)?.clone();
+            team.can_build_asteroid_facility(&asteroid, facility)?;
+
+            for (resource, amount) in &facility.cost() {
+                team.resources.sub(*resource, *amount)?;
+                if *resource == Resource::SATOSHI {
+                    app.world.record_transaction(
+                        team.id,
+                        app.world.now(),
+                        FinanceCategory::Asteroids,
+                        -(*amount as i64),
+                        format!("Built {} on {}", facility, asteroid.name),
+                    );
+                }
+            }
+
+            asteroid.facilities.push(facility);
+            let message = format!("{} built on {}", facility, asteroid.name);
+            app.world.teams.insert(team.id, team);
+            app.world.planets.insert(asteroid.id, asteroid);
+
+            app.world.dirty = true;
+            app.world.dirty_network = true;
+            app.world.dirty_ui = true;
+
+            Ok(Some(message))
+        })
+    }
+
+    fn uninstall_spaceship_module(module: SpaceshipModule) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let mut team = app.world.get_own_team()?.clone();
+            team.can_uninstall_spaceship_module(module)?;
+
+            // Modules are scrapped when uninstalled, there is no refund.
+            team.spaceship.modules.retain(|&m| m != module);
+            app.world.teams.insert(team.id, team);
+
+            app.world.dirty = true;
+            app.world.dirty_network = true;
+            app.world.dirty_ui = true;
+
+            Ok(Some(format!("{} uninstalled", module)))
+        })
+    }
+
+    fn set_fuel_grade(grade: FuelGrade) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let mut team = app.world.get_own_team()?.clone();
+            team.can_set_fuel_grade(grade)?;
+            team.spaceship.fuel_grade = grade;
+            app.world.teams.insert(team.id, team);
+
+            app.world.dirty = true;
+            app.world.dirty_network = true;
+            app.world.dirty_ui = true;
+            Ok(None)
+        })
+    }
+
+    fn set_engine_tuning(tuning: i8) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let mut team = app.world.get_own_team()?.clone();
+            team.can_set_engine_tuning(tuning)?;
+            team.spaceship.engine_tuning = tuning;
+            app.world.teams.insert(team.id, team);
+
+            app.world.dirty = true;
+            app.world.dirty_network = true;
+            app.world.dirty_ui = true;
+            Ok(None)
+        })
+    }
+
+    fn set_auto_refuel(enabled: bool) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let mut team = app.world.get_own_team()?.clone();
+            team.auto_refuel = enabled;
+            if team.auto_refuel_target == 0 {
+                team.auto_refuel_target = MAX_AUTO_REFUEL_TARGET;
+            }
+            app.world.teams.insert(team.id, team);
+
+            app.world.dirty = true;
+            app.world.dirty_network = true;
+            app.world.dirty_ui = true;
+            Ok(None)
+        })
+    }
+
+    fn set_auto_refuel_target(target: u8) -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let mut team = app.world.get_own_team()?.clone();
+            team.can_set_auto_refuel_target(target)?;
+            team.auto_refuel_target = target;
+            app.world.teams.insert(team.id, team);
+
+            app.world.dirty = true;
+            app.world.dirty_network = true;
+            app.world.dirty_ui = true;
+            Ok(None)
+        })
+    }
+
+    fn pay_crew_bonus() -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let mut team = app.world.get_own_team()?.clone();
+            team.can_pay_crew_bonus()?;
+
+            let cost = team.crew_bonus_cost();
+            team.resources.sub(Resource::SATOSHI, cost)?;
+            app.world.record_transaction(
+                team.id,
+                app.world.now(),
+                FinanceCategory::CrewBonus,
+                -(cost as i64),
+                "Bonus paid to the crew".to_string(),
+            );
+
+            for player_id in team.player_ids.iter() {
+                let mut player = app.world.get_player_or_err(*player_id)?.clone();
+                player.add_morale(MORALE_CREW_BONUS);
+                player.version += 1;
+                app.world.players.insert(player.id, player);
+            }
+
+            let was_on_strike = team.is_on_strike();
+            team.on_strike_until = None;
+            app.world.teams.insert(team.id, team);
+
+            let message = if was_on_strike {
+                format!("The captain paid a bonus of {cost} satoshi and ended the strike.")
+            } else {
+                format!("The captain paid a bonus of {cost} satoshi to the crew.")
+            };
+            app.world.log_crew_event(app.world.now(), message.clone());
+
+            app.world.dirty = true;
+            app.world.dirty_network = true;
+            app.world.dirty_ui = true;
+
+            Ok(Some(message))
+        })
+    }
+
+    fn share_rum() -> AppCallback {
+        Box::new(move |app: &mut App| {
+            let mut team = app.world.get_own_team()?.clone();
+            team.can_share_rum()?;
+
+            let amount = team.player_ids.len() as u32;
+            team.resources.sub(Resource::RUM, amount)?;
+
+            for player_id in team.player_ids.iter() {
+                let mut player = app.world.get_player_or_err(*player_id)?.clone();
+                player.add_morale(MORALE_SHARE_RUM_BONUS);
+                player.add_tiredness(TIREDNESS_SHARE_RUM_MALUS);
+                player.version += 1;
+                app.world.players.insert(player.id, player);
+            }
+
+            let was_on_strike = team.is_on_strike();
+            team.on_strike_until = None;
+            app.world.teams.insert(team.id, team);
+
+            let message = if was_on_strike {
+                format!("The captain shared {amount} l of rum and ended the strike.")
+            } else {
+                format!("The captain shared {amount} l of rum with the crew.")
+            };
+            app.world.log_crew_event(app.world.now(), message.clone());
+
+            app.world.dirty = true;
+            app.world.dirty_network = true;
+            app.world.dirty_ui = true;
+
+            Ok(Some(message))
+        })
+    }
+
     pub fn call(&self, app: &mut App) -> AppResult<Option<String>> {
         match self {
             UiCallback::None => Ok(None),
@@ -969,14 +2015,6 @@ impl UiCallback {
 
                 Ok(None)
             }
-            UiCallback::PushTutorialPage { index } => {
-                app.ui.close_popup();
-                app.ui.push_popup(PopupMessage::Tutorial {
-                    index: *index,
-                    tick: Tick::now(),
-                });
-                Ok(None)
-            }
             UiCallback::ToggleUiDebugMode => {
                 app.ui.toggle_data_view();
                 Ok(None)
@@ -1040,72 +2078,243 @@ impl UiCallback {
                 app.ui.game_panel.toggle_pitch_view();
                 Ok(None)
             }
-            UiCallback::TogglePlayerStatusView => {
-                app.ui.game_panel.toggle_player_status_view();
+            UiCallback::ToggleBroadcastMode => {
+                match app.ui.state() {
+                    UiState::Broadcast => app.ui.set_state(UiState::Main),
+                    UiState::Main => {
+                        let game_id = app.ui.game_panel.selected_game_id();
+                        app.ui.broadcast_screen.set_game_id(game_id);
+                        app.ui.set_state(UiState::Broadcast);
+                    }
+                    _ => {}
+                }
+                Ok(None)
+            }
+            UiCallback::TogglePlayerStatusView => {
+                app.ui.game_panel.toggle_player_status_view();
+                Ok(None)
+            }
+            UiCallback::ChallengeTeam { team_id } => Self::challenge_team(*team_id)(app),
+            UiCallback::AcceptChallenge { challenge } => {
+                app.network_handler
+                    .as_mut()
+                    .ok_or(anyhow!("Network handler is not initialized"))?
+                    .accept_challenge(&&app.world, challenge.clone())?;
+
+                let own_team = app.world.get_own_team_mut()?;
+                own_team.remove_challenge(
+                    challenge.home_team_in_game.team_id,
+                    challenge.away_team_in_game.team_id,
+                );
+                Ok(None)
+            }
+            UiCallback::AcceptLocalChallenge { team_id } => {
+                app.ui.close_popup();
+                Self::challenge_team(*team_id)(app)
+            }
+            UiCallback::DeclineLocalChallenge { team_id } => {
+                app.ui.close_popup();
+                let team_name = app.world.get_team_or_err(*team_id)?.name.clone();
+                let own_team = app.world.get_own_team()?;
+                if let Some(planet_id) = own_team.is_on_planet() {
+                    let own_team_id = own_team.id;
+                    app.world.spread_gossip(
+                        own_team_id,
+                        planet_id,
+                        Gossip::DeclinedChallenge,
+                        Some(*team_id),
+                        app.world.now(),
+                    )?;
+                }
+                Ok(Some(format!("Declined challenge from {}", team_name)))
+            }
+            UiCallback::DeclineChallenge { challenge } => {
+                app.network_handler
+                    .as_mut()
+                    .ok_or(anyhow!("Network handler is not initialized"))?
+                    .decline_challenge(challenge.clone())?;
+                let own_team = app.world.get_own_team_mut()?;
+                own_team.remove_challenge(
+                    challenge.home_team_in_game.team_id,
+                    challenge.away_team_in_game.team_id,
+                );
+                if let Some(planet_id) = own_team.is_on_planet() {
+                    let own_team_id = own_team.id;
+                    let challenger_id = if challenge.home_team_in_game.team_id == own_team_id {
+                        challenge.away_team_in_game.team_id
+                    } else {
+                        challenge.home_team_in_game.team_id
+                    };
+                    app.world.spread_gossip(
+                        own_team_id,
+                        planet_id,
+                        Gossip::DeclinedChallenge,
+                        Some(challenger_id),
+                        app.world.now(),
+                    )?;
+                }
+                Ok(None)
+            }
+            UiCallback::CreateTradeProposal {
+                proposer_player_id,
+                target_player_id,
+                extra_satoshis,
+            } => Self::trade_players(*proposer_player_id, *target_player_id, *extra_satoshis)(app),
+            UiCallback::AcceptTrade { trade } => {
+                app.network_handler
+                    .as_mut()
+                    .ok_or(anyhow!("Network handler is not initialized"))?
+                    .accept_trade(&&app.world, trade.clone())?;
+
+                let own_team = app.world.get_own_team_mut()?;
+                own_team.remove_trade(trade.proposer_player.id, trade.target_player.id);
+                Ok(None)
+            }
+            UiCallback::CounterTrade { trade } => {
+                let sweetener = trade.fair_sweetener();
+                if sweetener == 0 {
+                    return Err(anyhow!("The offer is already fair"));
+                }
+                let counter_offer = trade.counter_offer(sweetener);
+
+                let network_handler = app
+                    .network_handler
+                    .as_mut()
+                    .ok_or(anyhow!("Network handler is not initialized"))?;
+                let mut declined_trade = trade.clone();
+                declined_trade.state = NetworkRequestState::Failed {
+                    error_message: "Answered with a counter-offer".to_string(),
+                };
+                network_handler.send_trade(declined_trade)?;
+                network_handler.send_trade(counter_offer.clone())?;
+
+                let own_team = app.world.get_own_team_mut()?;
+                own_team.remove_trade(trade.proposer_player.id, trade.target_player.id);
+                own_team.add_sent_trade(counter_offer);
+                Ok(Some(format!(
+                    "Counter-offer sent, asking for {}",
+                    format_satoshi(sweetener)
+                )))
+            }
+            UiCallback::DeclineTrade { trade } => {
+                app.network_handler
+                    .as_mut()
+                    .ok_or(anyhow!("Network handler is not initialized"))?
+                    .decline_trade(trade.clone())?;
+                let own_team = app.world.get_own_team_mut()?;
+                own_team.remove_trade(trade.proposer_player.id, trade.target_player.id);
+                if let Some(planet_id) = own_team.is_on_planet() {
+                    let own_team_id = own_team.id;
+                    app.world.spread_gossip(
+                        own_team_id,
+                        planet_id,
+                        Gossip::DeclinedTrade,
+                        trade.proposer_player.team,
+                        app.world.now(),
+                    )?;
+                }
+                Ok(None)
+            }
+            UiCallback::OpenTradeBuilder { team_id } => Self::open_trade_builder(*team_id)(app),
+            UiCallback::ToggleTradeBuilderPlayer {
+                player_id,
+                is_proposer_side,
+            } => Self::update_trade_builder(app, |draft| {
+                draft.toggle_player(*player_id, *is_proposer_side)
+            }),
+            UiCallback::UpdateTradeBuilderResource {
+                resource,
+                amount,
+                is_proposer_side,
+            } => Self::update_trade_builder(app, |draft| {
+                draft.update_resource(*resource, *amount, *is_proposer_side);
+                Ok(())
+            }),
+            UiCallback::ProposeTradeBundle => Self::propose_trade_bundle()(app),
+            UiCallback::GoToTrade { trade } => Self::go_to_trade(trade.clone())(app),
+            UiCallback::SetTeamPanelTradeResource { resource } => {
+                app.ui.team_panel.set_trade_resource(*resource);
+                Ok(None)
+            }
+            UiCallback::SetTeamPanelTradeKartoffel { index } => {
+                app.ui.team_panel.set_trade_kartoffel(*index);
                 Ok(None)
             }
-            UiCallback::ChallengeTeam { team_id } => Self::challenge_team(*team_id)(app),
-            UiCallback::AcceptChallenge { challenge } => {
-                app.network_handler
+            UiCallback::ProposeKartoffelTrade {
+                team_id,
+                kartoffel_id,
+                satoshi,
+            } => {
+                let trade = app
+                    .network_handler
                     .as_mut()
                     .ok_or(anyhow!("Network handler is not initialized"))?
-                    .accept_challenge(&&app.world, challenge.clone())?;
-
+                    .send_new_kartoffel_trade(&app.world, *team_id, *kartoffel_id, *satoshi)?;
                 let own_team = app.world.get_own_team_mut()?;
-                own_team.remove_challenge(
-                    challenge.home_team_in_game.team_id,
-                    challenge.away_team_in_game.team_id,
-                );
-                Ok(None)
+                own_team.add_sent_resource_trade(trade);
+                Ok(Some("Kartoffel trade offer sent".to_string()))
             }
-            UiCallback::DeclineChallenge { challenge } => {
-                app.network_handler
+            UiCallback::ProposeResourceTrade {
+                team_id,
+                resource,
+                amount,
+                satoshi,
+                proposer_sells,
+            } => {
+                let trade = app
+                    .network_handler
                     .as_mut()
                     .ok_or(anyhow!("Network handler is not initialized"))?
-                    .decline_challenge(challenge.clone())?;
+                    .send_new_resource_trade(
+                        &app.world,
+                        *team_id,
+                        *resource,
+                        *amount,
+                        *satoshi,
+                        *proposer_sells,
+                    )?;
                 let own_team = app.world.get_own_team_mut()?;
-                own_team.remove_challenge(
-                    challenge.home_team_in_game.team_id,
-                    challenge.away_team_in_game.team_id,
-                );
-                Ok(None)
+                own_team.add_sent_resource_trade(trade);
+                Ok(Some("Resource trade offer sent".to_string()))
             }
-            UiCallback::CreateTradeProposal {
-                proposer_player_id,
-                target_player_id,
-            } => Self::trade_players(*proposer_player_id, *target_player_id)(app),
-            UiCallback::AcceptTrade { trade } => {
+            UiCallback::AcceptResourceTrade { trade } => {
                 app.network_handler
                     .as_mut()
                     .ok_or(anyhow!("Network handler is not initialized"))?
-                    .accept_trade(&&app.world, trade.clone())?;
-
+                    .accept_resource_trade(&app.world, trade.clone())?;
                 let own_team = app.world.get_own_team_mut()?;
-                own_team.remove_trade(trade.proposer_player.id, trade.target_player.id);
+                own_team.remove_resource_trade(trade.proposer_team_id, trade.target_team_id);
+                own_team.add_accepted_resource_trade(trade.clone());
                 Ok(None)
             }
-            UiCallback::DeclineTrade { trade } => {
+            UiCallback::DeclineResourceTrade { trade } => {
                 app.network_handler
                     .as_mut()
                     .ok_or(anyhow!("Network handler is not initialized"))?
-                    .decline_trade(trade.clone())?;
+                    .decline_resource_trade(trade.clone())?;
                 let own_team = app.world.get_own_team_mut()?;
-                own_team.remove_trade(trade.proposer_player.id, trade.target_player.id);
+                own_team.remove_resource_trade(trade.proposer_team_id, trade.target_team_id);
                 Ok(None)
             }
-            UiCallback::GoToTrade { trade } => Self::go_to_trade(trade.clone())(app),
             UiCallback::NextUiTab => Self::next_ui_tab()(app),
             UiCallback::PreviousUiTab => Self::previous_ui_tab()(app),
             UiCallback::SetUiTab { ui_tab } => Self::set_ui_tab(*ui_tab)(app),
             UiCallback::NextPanelIndex => Self::next_panel_index()(app),
             UiCallback::PreviousPanelIndex => Self::previous_panel_index()(app),
+            UiCallback::PanelPageUp => Self::panel_page_up()(app),
+            UiCallback::PanelPageDown => Self::panel_page_down()(app),
             UiCallback::CloseUiPopup => {
                 app.ui.close_popup();
                 Ok(None)
             }
             UiCallback::NewGame => {
                 app.ui.set_state(UiState::NewTeam);
+                Ok(None)
+            }
+            UiCallback::GenerateGalaxy { options } => {
+                app.world.generation_options = *options;
                 app.new_world();
+                app.ui.new_team_screen.set_state(CreationState::TeamName);
                 Ok(None)
             }
             UiCallback::ContinueGame => {
@@ -1116,6 +2325,14 @@ impl UiCallback {
                 app.quit()?;
                 Ok(None)
             }
+            UiCallback::ContinueInSandbox => {
+                app.world.continue_in_sandbox()?;
+                app.ui.set_state(UiState::Main);
+                Ok(Some(
+                    "Sandbox mode: the galaxy keeps going, but there is no more game over."
+                        .to_string(),
+                ))
+            }
             UiCallback::ToggleAudio => {
                 if let Some(player) = app.audio_player.as_mut() {
                     player.toggle()?;
@@ -1125,6 +2342,83 @@ impl UiCallback {
 
                 Ok(None)
             }
+            UiCallback::CycleUiTheme => {
+                app.settings.ui_theme = app.settings.ui_theme.next();
+                app.ui.set_theme(app.settings.ui_theme);
+                save_settings(&app.settings)?;
+                Ok(None)
+            }
+            UiCallback::ToggleAccessibleMode => {
+                app.settings.accessible_mode = !app.settings.accessible_mode;
+                app.ui.set_accessible_mode(app.settings.accessible_mode);
+                app.ui.set_keyboard_only(app.settings.accessible_mode);
+                save_settings(&app.settings)?;
+                Ok(None)
+            }
+            UiCallback::ExportTeamData => {
+                // Past games are not kept in the world, so they are loaded from the store.
+                let past_games = app
+                    .world
+                    .past_games
+                    .keys()
+                    .filter_map(|game_id| load_game(*game_id).ok())
+                    .collect::<Vec<_>>();
+                let export = TeamExport::from_world(&app.world, &past_games, Tick::now())?;
+                let dir = save_team_export(&export, app.settings.export_dir())?;
+                Ok(Some(format!("Team data exported to {}", dir.display())))
+            }
+            UiCallback::CopyToClipboard { target } => {
+                let text = match target {
+                    ClipboardTarget::PeerId => app
+                        .network_handler
+                        .as_ref()
+                        .ok_or(anyhow!("Network handler is not initialized"))?
+                        .swarm
+                        .local_peer_id()
+                        .to_base58(),
+                    ClipboardTarget::Seed => app.world.seed.to_string(),
+                    ClipboardTarget::TeamId => app.world.own_team_id.to_string(),
+                };
+                app.ui.clipboard.copy(&text)?;
+                Ok(Some(format!(
+                    "Copied {} to the clipboard:\n{}",
+                    target, text
+                )))
+            }
+            UiCallback::PasteFromClipboard => {
+                let text = app.ui.clipboard.paste()?;
+                app.ui.swarm_panel.insert_text(&text);
+                Ok(None)
+            }
+            UiCallback::ShareGameSummary { game_id } => {
+                let summary = app
+                    .world
+                    .past_games
+                    .get(game_id)
+                    .ok_or(anyhow!("The game has not ended yet"))?;
+                let game = load_game(*game_id)?;
+                let location = &app.world.get_planet_or_err(summary.location)?.name;
+                let text = game_summary_markdown(summary, &game, location);
+                let path = save_game_summary_export(&text, app.settings.export_dir())?;
+                Ok(Some(format!("Game summary written to {}", path.display())))
+            }
+            UiCallback::CycleNotificationFilter => {
+                app.ui.notifications_panel.cycle_filter();
+                Ok(None)
+            }
+            UiCallback::ClearNotifications { category } => {
+                app.world.clear_notifications(*category);
+                Ok(None)
+            }
+            UiCallback::SetTimeSpeed { speed } => {
+                app.world.can_set_time_speed(
+                    *speed,
+                    app.is_network_active(),
+                    app.network_handler.is_some(),
+                )?;
+                app.world.time_speed = *speed;
+                Ok(None)
+            }
             UiCallback::PreviousRadio => {
                 if let Some(player) = app.audio_player.as_mut() {
                     player.previous_audio_sample()?;
@@ -1145,10 +2439,66 @@ impl UiCallback {
                 app.ui.swarm_panel.set_view(*topic);
                 Ok(None)
             }
+            UiCallback::SetAppearanceDraft { jersey } => {
+                app.ui.my_team_panel.set_appearance_draft(jersey.clone());
+                Ok(None)
+            }
+            UiCallback::ChangeTeamAppearance { jersey } => {
+                app.world
+                    .change_own_team_appearance(jersey.clone(), app.world.now())?;
+                app.ui.my_team_panel.reset_appearance_draft();
+                Ok(Some("Team appearance changed".to_string()))
+            }
+            UiCallback::SetEmblemDraft { emblem } => {
+                app.ui.my_team_panel.set_emblem_draft(*emblem);
+                Ok(None)
+            }
+            UiCallback::ChangeTeamEmblem { emblem } => {
+                app.world.change_own_team_emblem(*emblem, app.world.now())?;
+                app.ui.my_team_panel.reset_emblem_draft();
+                Ok(Some("Team emblem changed".to_string()))
+            }
+            UiCallback::PromptRename { target } => {
+                let team = app.world.get_own_team()?;
+                let current_name = match target {
+                    RenameTarget::Team => team.name.clone(),
+                    RenameTarget::Spaceship => team.spaceship.name.clone(),
+                    RenameTarget::Player { player_id } => {
+                        app.world.get_player_or_err(*player_id)?.info.full_name()
+                    }
+                };
+                app.ui.push_popup(PopupMessage::RenameDialog {
+                    target: *target,
+                    current_name,
+                    tick: Tick::now(),
+                });
+                Ok(None)
+            }
+            UiCallback::Rename { target, name } => {
+                let message = app.world.rename(*target, name.clone(), app.world.now())?;
+                app.ui.close_popup();
+                Ok(Some(message))
+            }
             UiCallback::SetMyTeamPanelView { view } => {
                 app.ui.my_team_panel.set_view(*view);
                 Ok(None)
             }
+            UiCallback::ToggleTutorialChecklist => {
+                app.ui.my_team_panel.toggle_tutorial_checklist();
+                Ok(None)
+            }
+            UiCallback::SetMyTeamPanelSpaceshipPrefab { index } => {
+                app.ui.my_team_panel.set_spaceship_prefab(*index);
+                Ok(None)
+            }
+            UiCallback::ScrollPlayByPlay { lines } => {
+                if app.ui.active_tab() == UiTab::Games {
+                    app.ui.game_panel.scroll_play_by_play(*lines);
+                } else {
+                    app.ui.my_team_panel.scroll_play_by_play(*lines);
+                }
+                Ok(None)
+            }
             UiCallback::SetPlayerPanelView { view } => {
                 app.ui.player_panel.set_view(*view);
                 Ok(None)
@@ -1157,6 +2507,18 @@ impl UiCallback {
                 app.ui.team_panel.set_view(*view);
                 Ok(None)
             }
+            UiCallback::SortRoster { players_sort } => {
+                app.ui.my_team_panel.set_players_sort(*players_sort);
+                app.settings.roster_sort = *players_sort;
+                save_settings(&app.settings)?;
+                Ok(None)
+            }
+            UiCallback::SortPlayerList { players_sort } => {
+                app.ui.player_panel.set_players_sort(*players_sort);
+                app.settings.player_list_sort = *players_sort;
+                save_settings(&app.settings)?;
+                Ok(None)
+            }
             UiCallback::HirePlayer { player_id } => {
                 app.world
                     .hire_player_for_team(*player_id, app.world.own_team_id)?;
@@ -1192,24 +2554,59 @@ impl UiCallback {
                 Ok(None)
             }
 
+            UiCallback::Duel {
+                challenger_id,
+                opponent_id,
+            } => {
+                let result = app
+                    .world
+                    .play_duel(*challenger_id, *opponent_id, app.world.now())?;
+                let winner = app.world.get_player_or_err(result.winner_id)?;
+                let loser = app.world.get_player_or_err(result.loser_id)?;
+
+                let mut message = format!(
+                    "{} beat {} {}-{}.\n",
+                    winner.info.shortened_name(),
+                    loser.info.shortened_name(),
+                    result.winner_score,
+                    result.loser_score
+                );
+                for play in result
+                    .plays
+                    .iter()
+                    .skip(result.plays.len().saturating_sub(DUEL_RECAP_PLAYS))
+                {
+                    message.push_str(&format!("\n{play}"));
+                }
+                message.push_str(&format!(
+                    "\n\n{} is in high spirits, {} needs some rest.",
+                    winner.info.shortened_name(),
+                    loser.info.shortened_name()
+                ));
+
+                app.ui.push_popup(PopupMessage::Ok {
+                    message,
+                    is_skippable: true,
+                    tick: Tick::now(),
+                });
+                Ok(None)
+            }
+            UiCallback::AssignKartoffel {
+                kartoffel_id,
+                player_id,
+            } => {
+                app.world.assign_kartoffel(*kartoffel_id, *player_id)?;
+                Ok(None)
+            }
+            UiCallback::FeedKartoffel { kartoffel_id } => {
+                app.world.feed_kartoffel(*kartoffel_id, app.world.now())?;
+                Ok(None)
+            }
+
             UiCallback::Drink { player_id } => {
                 let mut player = app.world.get_player_or_err(*player_id)?.clone();
                 player.can_drink(&app.world)?;
-
-                let morale_bonus = if matches!(player.special_trait, Some(Trait::Spugna)) {
-                    MAX_SKILL
-                } else {
-                    MORALE_DRINK_BONUS
-                };
-
-                let tiredness_malus = if matches!(player.special_trait, Some(Trait::Spugna)) {
-                    TIREDNESS_DRINK_MALUS_SPUGNA
-                } else {
-                    TIREDNESS_DRINK_MALUS
-                };
-
-                player.add_morale(morale_bonus);
-                player.add_tiredness(tiredness_malus);
+                player.drink();
 
                 let mut team = app
                     .world
@@ -1273,7 +2670,7 @@ impl UiCallback {
                         team.current_location = TeamLocation::Travelling {
                             from,
                             to,
-                            started: Tick::now(),
+                            started: app.world.now(),
                             duration: 10 * SECONDS,
                             distance,
                         };
@@ -1301,6 +2698,7 @@ impl UiCallback {
                 jersey_colors,
                 players,
                 spaceship,
+                campaign,
             } => Self::generate_own_team(
                 name.clone(),
                 *home_planet,
@@ -1308,22 +2706,115 @@ impl UiCallback {
                 *jersey_colors,
                 players.clone(),
                 spaceship.clone(),
+                *campaign,
             )(app),
             UiCallback::CancelGeneratePlayerTeam => Self::cancel_generate_own_team()(app),
+            UiCallback::ToggleCampaignMode => {
+                app.ui.new_team_screen.toggle_campaign_mode();
+                Ok(None)
+            }
             UiCallback::AssignBestTeamPositions => Self::assign_best_team_positions()(app),
             UiCallback::SwapPlayerPositions {
                 player_id,
                 position,
             } => Self::swap_player_positions(*player_id, *position)(app),
             UiCallback::NextTrainingFocus { team_id } => Self::next_training_focus(*team_id)(app),
+            UiCallback::SetTrainingFocus { focus } => Self::set_training_focus(*focus)(app),
+            UiCallback::SetTrainingSession { day, focus } => {
+                Self::set_training_session(*day, *focus)(app)
+            }
             UiCallback::TravelToPlanet { planet_id } => Self::travel_to_planet(*planet_id)(app),
+            UiCallback::TravelRouteToPlanet { planet_id } => {
+                Self::travel_route_to_planet(*planet_id)(app)
+            }
+            UiCallback::QueueAction { action } => Self::queue_action(*action)(app),
+            UiCallback::RemoveQueuedAction { index } => {
+                let index = *index;
+                Self::edit_action_queue(move |queue| {
+                    if index < queue.len() {
+                        queue.remove(index);
+                    }
+                })(app)
+            }
+            UiCallback::MoveQueuedActionUp { index } => {
+                let index = *index;
+                Self::edit_action_queue(move |queue| move_action_up(queue, index))(app)
+            }
+            UiCallback::ClearActionQueue => Self::edit_action_queue(|queue| queue.clear())(app),
+            UiCallback::SetCoCaptain { team_id } => Self::set_co_captain(*team_id)(app),
+            UiCallback::SendCoCaptainOrder { team_id, action } => {
+                Self::send_co_captain_order(*team_id, *action)(app)
+            }
             UiCallback::ExploreAroundPlanet { duration } => {
                 Self::explore_around_planet(duration.clone())(app)
             }
             UiCallback::ZoomInToPlanet { planet_id } => Self::zoom_in_to_planet(*planet_id)(app),
             UiCallback::DialSeed => Self::dial_seed()(app),
+            UiCallback::DialAddress { target } => Self::dial_address(target.clone())(app),
+            UiCallback::ShowAddressBook => Self::show_address_book()(app),
+            UiCallback::SetPeerFavorite { peer_id, favorite } => {
+                let network_handler = app
+                    .network_handler
+                    .as_mut()
+                    .ok_or(anyhow!("Network handler is not initialized"))?;
+                network_handler
+                    .address_book
+                    .set_favorite(*peer_id, *favorite);
+                save_address_book(&network_handler.address_book)?;
+                app.ui
+                    .swarm_panel
+                    .update_address_book(&network_handler.address_book);
+                Ok(None)
+            }
+            UiCallback::SetPeerBlocked { peer_id, blocked } => {
+                let network_handler = app
+                    .network_handler
+                    .as_mut()
+                    .ok_or(anyhow!("Network handler is not initialized"))?;
+                network_handler.address_book.set_blocked(*peer_id, *blocked);
+                save_address_book(&network_handler.address_book)?;
+                app.ui
+                    .swarm_panel
+                    .update_address_book(&network_handler.address_book);
+                Ok(Some(if *blocked {
+                    format!(
+                        "Peer {} is blocked: their challenges, trades and messages will be ignored.",
+                        peer_id
+                    )
+                } else {
+                    format!("Peer {} is not blocked anymore.", peer_id)
+                }))
+            }
             UiCallback::Sync => Self::sync()(app),
             UiCallback::SendMessage { message } => Self::send(message.clone())(app),
+            UiCallback::SendDirectMessage { peer_id, message } => {
+                app.network_handler
+                    .as_mut()
+                    .ok_or(anyhow!("Network handler is not initialized"))?
+                    .send_direct_msg(*peer_id, message.clone())?;
+                Ok(None)
+            }
+            UiCallback::SendCrowdChatter { text } => Self::send_crowd_chatter(text.clone())(app),
+            UiCallback::CallGameTimeout { game_id } => {
+                Self::queue_game_command(*game_id, GameCommand::Timeout)(app)
+            }
+            UiCallback::SubstituteGamePlayer {
+                game_id,
+                player_out,
+                player_in,
+            } => Self::queue_game_command(
+                *game_id,
+                GameCommand::Substitution {
+                    player_out: *player_out,
+                    player_in: *player_in,
+                },
+            )(app),
+            UiCallback::GiveCaptainSpeech { game_id, tone } => {
+                Self::queue_game_command(*game_id, GameCommand::CaptainSpeech { tone: *tone })(app)
+            }
+            UiCallback::SetGameTactic { game_id, tactic } => {
+                Self::queue_game_command(*game_id, GameCommand::SetTactic { tactic: *tactic })(app)
+            }
             UiCallback::PushUiPopup { popup_message } => {
                 app.ui.push_popup(popup_message.clone());
                 Ok(None)
@@ -1337,15 +2828,188 @@ impl UiCallback {
             UiCallback::UpgradeSpaceship { upgrade } => {
                 Self::upgrade_spaceship(upgrade.clone())(app)
             }
-            UiCallback::StartSpaceAdventure => {
-                app.ui.set_state(UiState::SpaceAdventure);
+            UiCallback::InstallSpaceshipModule { module } => {
+                Self::install_spaceship_module(*module)(app)
+            }
+            UiCallback::UninstallSpaceshipModule { module } => {
+                Self::uninstall_spaceship_module(*module)(app)
+            }
+            UiCallback::BuySpaceship { prefab } => Self::buy_spaceship(*prefab)(app),
+            UiCallback::ResolveDerelict { choice } => {
+                let message = app.world.resolve_derelict(*choice, app.world.now())?;
+                app.ui.close_popup();
+                if *choice != DerelictChoice::Leave {
+                    app.ui.push_popup(PopupMessage::Ok {
+                        message,
+                        is_skippable: true,
+                        tick: Tick::now(),
+                    });
+                }
+                Ok(None)
+            }
+            UiCallback::ResolveTravelHazard { choice } => {
+                let message = app.world.resolve_travel_hazard(*choice, app.world.now())?;
+                app.ui.close_popup();
+                app.ui.push_popup(PopupMessage::Ok {
+                    message,
+                    is_skippable: true,
+                    tick: Tick::now(),
+                });
+                Ok(None)
+            }
+            UiCallback::SwitchSpaceship => {
+                let mut team = app.world.get_own_team()?.clone();
+                team.switch_spaceship()?;
+                let name = team.spaceship.name.clone();
+                app.world.teams.insert(team.id, team);
+                app.world.dirty = true;
+                app.world.dirty_network = true;
+                app.world.dirty_ui = true;
+                Ok(Some(format!("Now flying {}", name)))
+            }
+            UiCallback::UnlockSpaceAdventureUpgrade { upgrade } => {
+                Self::unlock_space_adventure_upgrade(*upgrade)(app)
+            }
+            UiCallback::BuildAsteroidFacility {
+                asteroid_id,
+                facility,
+            } => Self::build_asteroid_facility(*asteroid_id, *facility)(app),
+            UiCallback::AcceptContract { contract_id } => {
+                app.world.accept_contract(*contract_id, app.world.now())?;
+                Ok(Some("Contract accepted".to_string()))
+            }
+            UiCallback::DeliverContract { contract_id } => {
+                app.world.deliver_contract(*contract_id, app.world.now())?;
+                Ok(Some("Contract delivered".to_string()))
+            }
+            UiCallback::RegisterForTournament { tournament_id } => {
+                app.world
+                    .register_for_tournament(*tournament_id, app.world.now())?;
+                Ok(Some("Registered to the tournament".to_string()))
+            }
+            UiCallback::ScoutPlanet { planet_id } => {
+                app.world.scout_planet(*planet_id, app.world.now())?;
+                Ok(Some("Scouting report received".to_string()))
+            }
+            UiCallback::PayCrewBonus => Self::pay_crew_bonus()(app),
+            UiCallback::ShareRum => Self::share_rum()(app),
+            UiCallback::TakeLoan { amount } => {
+                app.world.take_loan(*amount, app.world.now())?;
+                Ok(Some(format!("Borrowed {}", format_satoshi(*amount))))
+            }
+            UiCallback::BuyInsurance { kind } => {
+                app.world.buy_insurance(*kind, app.world.now())?;
+                Ok(Some(format!("{} insured for the next week", kind)))
+            }
+            UiCallback::RepayLoan { amount } => {
+                app.world.repay_loan(*amount, app.world.now())?;
+                if app.world.get_own_team()?.loan.is_none() {
+                    return Ok(Some("Loan repaid in full".to_string()));
+                }
+                Ok(None)
+            }
+            UiCallback::SetFuelGrade { grade } => Self::set_fuel_grade(*grade)(app),
+            UiCallback::SetEngineTuning { tuning } => Self::set_engine_tuning(*tuning)(app),
+            UiCallback::SetAutoRefuel { enabled } => Self::set_auto_refuel(*enabled)(app),
+            UiCallback::SetAutoRefuelTarget { target } => {
+                Self::set_auto_refuel_target(*target)(app)
+            }
+            UiCallback::EnrollInAcademy { player_id } => {
+                let message = app.world.enroll_in_academy(*player_id, app.world.now())?;
+                Ok(Some(message))
+            }
+            UiCallback::PromoteProspect { player_id } => {
+                let message = app.world.promote_academy_prospect(*player_id)?;
+                Ok(Some(message))
+            }
+            UiCallback::ReleaseProspect { player_id } => {
+                let message = app.world.release_academy_prospect(*player_id)?;
+                Ok(Some(message))
+            }
+            UiCallback::HireStaff { role } => {
+                let message = app
+                    .world
+                    .hire_staff(*role, app.world.last_tick_short_interval)?;
+                Ok(Some(message))
+            }
+            UiCallback::FireStaff { role } => {
+                let message = app.world.fire_staff(*role)?;
+                Ok(Some(message))
+            }
+            UiCallback::ScanNearbyPlanets => {
+                let message = app.world.scan_nearby_planets(app.world.now())?;
+                Ok(Some(message))
+            }
+            UiCallback::BuildWormholeGate { planet_id } => {
+                let message = app.world.build_wormhole_gate(
+                    app.world.own_team_id,
+                    *planet_id,
+                    app.world.now(),
+                )?;
+                Ok(Some(message))
+            }
+            UiCallback::PromptSelectPilot => {
+                let own_team = app.world.get_own_team()?;
+                own_team.can_start_space_adventure()?;
+
+                // The current crew pilot is proposed first.
+                let pilots = app
+                    .world
+                    .get_players_by_team(own_team)?
+                    .iter()
+                    .filter(|player| !player.is_knocked_out())
+                    .sorted_by_key(|player| {
+                        (
+                            own_team.crew_roles.pilot != Some(player.id),
+                            -((player.athletics.rating() + player.technical.rating()) as i16),
+                        )
+                    })
+                    .map(|player| {
+                        (
+                            player.id,
+                            player.info.full_name(),
+                            player.athletics.rating(),
+                            player.technical.rating(),
+                        )
+                    })
+                    .collect_vec();
+
+                if pilots.is_empty() {
+                    return Err(anyhow!("No pirate can pilot the spaceship"));
+                }
+
+                app.ui.push_popup(PopupMessage::SelectPilot {
+                    pilots,
+                    tick: Tick::now(),
+                });
+                Ok(None)
+            }
+            UiCallback::StartSpaceAdventure { pilot_id } => {
+                let pilot = app.world.get_player_or_err(*pilot_id)?.clone();
                 let mut own_team = app.world.get_own_team()?.clone();
                 own_team.can_start_space_adventure()?;
-                let space = SpaceAdventure::new()?.with_spaceship(
-                    &own_team.spaceship,
-                    own_team.resources.clone(),
-                    own_team.fuel(),
-                )?;
+                if !own_team.player_ids.contains(pilot_id) {
+                    return Err(anyhow!("Pilot is not part of the crew"));
+                }
+                app.ui.close_popup();
+                app.ui.set_state(UiState::SpaceAdventure);
+                let space = SpaceAdventure::new()?
+                    .with_spaceship(
+                        &own_team.spaceship,
+                        own_team.resources.clone(),
+                        own_team.fuel(),
+                        &own_team.space_adventure_progression,
+                    )?
+                    .with_oxygen(
+                        own_team
+                            .spaceship
+                            .oxygen_autonomy(own_team.player_ids.len()),
+                    )
+                    .with_pilot(&pilot)?
+                    .with_hull_care(
+                        TeamBonus::HullCare.current_team_bonus(&app.world, own_team.id)?,
+                    )?
+                    .with_boss_encounter(own_team.has_boss_encounter());
 
                 match own_team.current_location {
                     TeamLocation::OnPlanet { planet_id } => {
@@ -1375,8 +3039,19 @@ impl UiCallback {
             UiCallback::ReturnFromSpaceAdventure => {
                 app.ui.set_state(UiState::Main);
                 let mut own_team = app.world.get_own_team()?.clone();
+                let mut should_reward_boss = false;
+                let mut cargo_report = String::new();
+                let mut insured_losses = vec![];
 
                 if let Some(space) = app.world.space_adventure.as_ref() {
+                    if let Some(pilot_id) = space.pilot_id() {
+                        if let Ok(pilot) = app.world.get_player_or_err(pilot_id) {
+                            let mut pilot = pilot.clone();
+                            pilot.add_piloting_experience(space.distance());
+                            app.world.players.insert(pilot.id, pilot);
+                        }
+                    }
+
                     if let Some(player) = space.get_player() {
                         let player_control: &dyn PlayerControlled = player
                             .as_trait_ref()
@@ -1394,27 +3069,95 @@ impl UiCallback {
                             new_resources.insert(Resource::FUEL, player_control.fuel());
                         }
 
+                        // Report what the cargo gained from the space adventure.
+                        let gains = [
+                            Resource::GOLD,
+                            Resource::SCRAPS,
+                            Resource::RUM,
+                            Resource::FUEL,
+                        ]
+                        .iter()
+                        .filter_map(|resource| {
+                            let gain = new_resources
+                                .value(resource)
+                                .saturating_sub(own_team.resources.value(resource));
+                            (gain > 0).then(|| format!("+{} {}", gain, resource))
+                        })
+                        .collect_vec();
+                        if gains.len() > 0 {
+                            cargo_report = format!(" Cargo: {}.", gains.join(", "));
+                        }
+
+                        let durability_lost = own_team
+                            .spaceship
+                            .current_durability()
+                            .saturating_sub(player_control.current_durability());
+                        insured_losses
+                            .push((InsuranceKind::Hull, hull_loss_value(durability_lost)));
+                        if player_control.durability() == 0 {
+                            insured_losses.push((
+                                InsuranceKind::Cargo,
+                                cargo_loss_value(player_control.resources()),
+                            ));
+                        }
+
                         own_team.resources = new_resources;
                         own_team
                             .spaceship
                             .set_current_durability(player_control.current_durability());
 
-                        match own_team.current_location {
+                        // The boss spoils are claimed only if the crew made it back safely.
+                        should_reward_boss =
+                            space.is_boss_defeated() && player_control.current_durability() > 0;
+
+                        let around = match own_team.current_location {
                             TeamLocation::OnSpaceAdventure { around } => {
                                 own_team.current_location =
-                                    TeamLocation::OnPlanet { planet_id: around }
+                                    TeamLocation::OnPlanet { planet_id: around };
+                                around
                             }
                             _ => {
                                 return Err(anyhow!("Team should be on a space adventure."));
                             }
-                        }
+                        };
+                        let own_team_id = own_team.id;
                         app.world.teams.insert(own_team.id, own_team);
+
+                        // Flying around the system charts it.
+                        let charted = app.world.discover_system(own_team_id, around)?;
+                        if charted > 0 {
+                            cargo_report =
+                                format!("{} Charted {} new world(s).", cargo_report, charted);
+                        }
                     }
                     app.world.space_adventure = None;
-                    return Ok(Some("Team returned from space adventure.".to_string()));
+                    app.world.space_adventure_snapshot = None;
+
+                    for (kind, loss_value) in insured_losses {
+                        if let Some(claim) =
+                            app.world
+                                .claim_insurance(kind, loss_value, app.world.now())?
+                        {
+                            cargo_report = format!("{} {}", cargo_report, claim);
+                        }
+                    }
+
+                    if should_reward_boss {
+                        let message = app.world.reward_boss_defeat(app.world.now())?;
+                        app.ui.push_popup(PopupMessage::Ok {
+                            message,
+                            is_skippable: false,
+                            tick: Tick::now(),
+                        });
+                    }
+                    return Ok(Some(format!(
+                        "Team returned from space adventure.{}",
+                        cargo_report
+                    )));
                 }
 
                 app.world.space_adventure = None;
+                app.world.space_adventure_snapshot = None;
                 Ok(None)
             }
             UiCallback::SpaceMovePlayerLeft => {
@@ -1457,6 +3200,17 @@ impl UiCallback {
                     space.handle_player_input(PlayerInput::SecondButton)?;
                 }
 
+                Ok(None)
+            }
+            UiCallback::ToggleSpaceAdventurePause => {
+                if let Some(space) = app.world.space_adventure.as_mut() {
+                    space.toggle_pause();
+                    // Store the adventure when pausing, so that it can be resumed after a restart.
+                    if space.is_paused() {
+                        app.world.store_space_adventure()?;
+                    }
+                }
+
                 Ok(None)
             }
         }
@@ -1466,9 +3220,19 @@ impl UiCallback {
 #[derive(Default, Debug, PartialEq)]
 pub struct CallbackRegistry {
     mouse_callbacks: HashMap<MouseEventKind, HashMap<Option<Rect>, UiCallback>>,
-    keyboard_callbacks: HashMap<KeyCode, UiCallback>,
+    // Hotkeys are registered in the context of the screen being rendered,
+    // so that the same key can trigger different actions in different screens.
+    keyboard_callbacks: HashMap<KeyContext, HashMap<KeyCode, UiCallback>>,
+    key_context: KeyContext,
     hovering: (u16, u16),
     max_layer: u8,
+    // In keyboard-only mode the hovering position follows the focused element
+    // rather than the mouse, so that hover styles and texts show the focus.
+    keyboard_only: bool,
+    focus_index: Option<usize>,
+    // Interactive areas registered during the current and the previous render.
+    focusables: Vec<Rect>,
+    last_focusables: Vec<Rect>,
 }
 
 impl CallbackRegistry {
@@ -1494,20 +3258,130 @@ impl CallbackRegistry {
         rect: Option<Rect>,
         callback: UiCallback,
     ) {
+        if event_kind == MouseEventKind::Down(MouseButton::Left) {
+            if let Some(r) = rect {
+                self.register_focusable(r);
+            }
+        }
         self.mouse_callbacks
             .entry(event_kind)
             .or_insert_with(HashMap::new)
             .insert(rect, callback);
     }
 
+    pub fn set_key_context(&mut self, key_context: KeyContext) {
+        self.key_context = key_context;
+    }
+
+    pub fn get_key_context(&self) -> KeyContext {
+        self.key_context
+    }
+
     pub fn register_keyboard_callback(&mut self, key_code: KeyCode, callback: UiCallback) {
-        self.keyboard_callbacks.insert(key_code, callback);
+        self.keyboard_callbacks
+            .entry(self.key_context)
+            .or_insert_with(HashMap::new)
+            .insert(key_code, callback);
+    }
+
+    pub fn register_focusable(&mut self, rect: Rect) {
+        if rect.width > 0 && rect.height > 0 && !self.focusables.contains(&rect) {
+            // Keep reading order to have a predictable focus cycle.
+            let index = self
+                .focusables
+                .partition_point(|r| (r.y, r.x) <= (rect.y, rect.x));
+            self.focusables.insert(index, rect);
+        }
     }
 
     pub fn clear(&mut self) {
         self.mouse_callbacks.clear();
         self.keyboard_callbacks.clear();
+        self.key_context = KeyContext::Global;
         self.max_layer = 0;
+
+        // The registry is cleared both on update and on render,
+        // so we keep the last non-empty list of focusables.
+        if self.focusables.len() > 0 {
+            self.last_focusables = std::mem::take(&mut self.focusables);
+            self.update_focus_hovering();
+        }
+    }
+
+    pub fn is_keyboard_only(&self) -> bool {
+        self.keyboard_only
+    }
+
+    pub fn toggle_keyboard_only(&mut self) {
+        self.set_keyboard_only(!self.keyboard_only);
+    }
+
+    pub fn set_keyboard_only(&mut self, keyboard_only: bool) {
+        self.keyboard_only = keyboard_only;
+        self.focus_index = None;
+    }
+
+    fn current_focusables(&self) -> &Vec<Rect> {
+        if self.focusables.len() > 0 {
+            &self.focusables
+        } else {
+            &self.last_focusables
+        }
+    }
+
+    fn update_focus_hovering(&mut self) {
+        if !self.keyboard_only {
+            return;
+        }
+        let focusables = self.current_focusables();
+        if let Some(index) = self.focus_index {
+            if focusables.len() > 0 {
+                let rect = focusables[index % focusables.len()];
+                self.hovering = (rect.x, rect.y);
+            }
+        }
+    }
+
+    pub fn focused_rect(&self) -> Option<Rect> {
+        let focusables = self.current_focusables();
+        if !self.keyboard_only || focusables.len() == 0 {
+            return None;
+        }
+        self.focus_index
+            .map(|index| focusables[index % focusables.len()])
+    }
+
+    pub fn focus_next(&mut self) {
+        let len = self.current_focusables().len();
+        if len == 0 {
+            return;
+        }
+        self.focus_index = match self.focus_index {
+            Some(index) => Some((index + 1) % len),
+            None => Some(0),
+        };
+        self.update_focus_hovering();
+    }
+
+    pub fn focus_previous(&mut self) {
+        let len = self.current_focusables().len();
+        if len == 0 {
+            return;
+        }
+        self.focus_index = match self.focus_index {
+            Some(index) => Some((index % len + len - 1) % len),
+            None => Some(len - 1),
+        };
+        self.update_focus_hovering();
+    }
+
+    // Returns the callback of the focused element, as if it was clicked.
+    pub fn activate_focused(&self) -> Option<UiCallback> {
+        let rect = self.focused_rect()?;
+        self.mouse_callbacks
+            .get(&MouseEventKind::Down(MouseButton::Left))?
+            .get(&Some(rect))
+            .cloned()
     }
 
     pub fn is_hovering(&self, rect: Rect) -> bool {
@@ -1515,6 +3389,10 @@ impl CallbackRegistry {
     }
 
     pub fn set_hovering(&mut self, event: MouseEvent) {
+        // The mouse should not steal the focus in keyboard-only mode.
+        if self.keyboard_only {
+            return;
+        }
         self.hovering = (event.column, event.row);
     }
 
@@ -1535,6 +3413,101 @@ impl CallbackRegistry {
     }
 
     pub fn handle_keyboard_event(&self, key_code: &KeyCode) -> Option<UiCallback> {
-        self.keyboard_callbacks.get(key_code).cloned()
+        // Resolve from the most specific context to the global one.
+        // When a popup is open, only popup and global hotkeys are active.
+        let mut contexts = self
+            .keyboard_callbacks
+            .keys()
+            .filter(|context| {
+                self.max_layer == 0 || matches!(context, KeyContext::Popup | KeyContext::Global)
+            })
+            .collect::<Vec<&KeyContext>>();
+        contexts.sort_by_key(|context| std::cmp::Reverse(context.depth()));
+
+        for context in contexts {
+            if let Some(callback) = self.keyboard_callbacks[context].get(key_code) {
+                return Some(callback.clone());
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CallbackRegistry, UiCallback};
+    use crate::ui::{constants::UiKey, keymap::KeyContext, my_team_panel::MyTeamView, ui::UiTab};
+    use crossterm::event::{MouseButton, MouseEventKind};
+    use ratatui::layout::Rect;
+
+    #[test]
+    fn test_keyboard_only_focus_cycle() {
+        let mut registry = CallbackRegistry::new();
+        let first = Rect::new(0, 0, 10, 1);
+        let second = Rect::new(0, 2, 10, 1);
+        // Registered out of reading order on purpose.
+        registry.register_mouse_callback(
+            MouseEventKind::Down(MouseButton::Left),
+            Some(second),
+            UiCallback::PayCrewBonus,
+        );
+        registry.register_mouse_callback(
+            MouseEventKind::Down(MouseButton::Left),
+            Some(first),
+            UiCallback::ShareRum,
+        );
+
+        // Without keyboard-only mode nothing is focused.
+        registry.focus_next();
+        assert!(registry.focused_rect().is_none());
+
+        registry.toggle_keyboard_only();
+        registry.focus_next();
+        assert_eq!(registry.focused_rect(), Some(first));
+        assert!(registry.is_hovering(first));
+        assert_eq!(registry.activate_focused(), Some(UiCallback::ShareRum));
+
+        registry.focus_next();
+        assert_eq!(registry.focused_rect(), Some(second));
+        registry.focus_next();
+        assert_eq!(registry.focused_rect(), Some(first));
+        registry.focus_previous();
+        assert_eq!(registry.focused_rect(), Some(second));
+
+        // Focus survives a new render with the same layout.
+        registry.clear();
+        assert_eq!(registry.focused_rect(), Some(second));
+        assert!(registry.is_hovering(second));
+    }
+
+    #[test]
+    fn test_keyboard_callbacks_resolve_by_context() {
+        let mut registry = CallbackRegistry::new();
+        registry.register_keyboard_callback(UiKey::TOGGLE_AUDIO, UiCallback::ShareRum);
+        registry.set_key_context(KeyContext::Panel(UiTab::MyTeam));
+        registry.register_keyboard_callback(UiKey::GO_TO_GAME, UiCallback::PayCrewBonus);
+        registry.set_key_context(KeyContext::MyTeamView(MyTeamView::Market));
+        registry.register_keyboard_callback(UiKey::BUY_GOLD, UiCallback::ShareRum);
+
+        // The most specific context wins.
+        assert_eq!(
+            registry.handle_keyboard_event(&UiKey::BUY_GOLD),
+            Some(UiCallback::ShareRum)
+        );
+        assert_eq!(
+            registry.handle_keyboard_event(&UiKey::TOGGLE_AUDIO),
+            Some(UiCallback::ShareRum)
+        );
+
+        // Panel hotkeys are disabled while a popup is open.
+        registry.set_max_layer(1);
+        assert!(registry.handle_keyboard_event(&UiKey::BUY_GOLD).is_none());
+        assert!(registry
+            .handle_keyboard_event(&UiKey::TOGGLE_AUDIO)
+            .is_some());
+
+        registry.clear();
+        assert_eq!(registry.get_key_context(), KeyContext::Global);
+        assert!(registry.handle_keyboard_event(&UiKey::BUY_GOLD).is_none());
     }
 }
@@ -1,8 +1,8 @@
 use super::{
-    constants::{UiStyle, MAX_NAME_LENGTH, MIN_NAME_LENGTH},
+    constants::{is_accessible_mode, UiStyle, MAX_NAME_LENGTH, MIN_NAME_LENGTH},
     widgets::default_block,
 };
-use crate::types::Tick;
+use crate::{types::Tick, world::constants::HOURS};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use image::{Pixel, RgbaImage};
 use libp2p::PeerId;
@@ -115,9 +115,9 @@ pub fn big_text<'a>(text: &'a [&str]) -> Paragraph<'a> {
             let mut spans = vec![];
             for c in line.chars() {
                 if c == '█' {
-                    spans.push(Span::styled("█", UiStyle::SHADOW));
+                    spans.push(Span::styled("█", UiStyle::current().shadow));
                 } else {
-                    spans.push(Span::styled(c.to_string(), UiStyle::HIGHLIGHT));
+                    spans.push(Span::styled(c.to_string(), UiStyle::current().highlight));
                 }
             }
             Line::from(spans)
@@ -138,20 +138,32 @@ pub fn hover_text_target(frame: &Frame) -> Rect {
 pub fn validate_textarea_input(textarea: &mut TextArea<'_>, title: String) -> bool {
     let text = textarea.lines()[0].trim();
     if text.len() < MIN_NAME_LENGTH {
-        textarea.set_style(UiStyle::ERROR);
+        textarea.set_style(UiStyle::current().error);
         textarea.set_block(default_block().title(title).title("(too short)"));
         false
     } else if text.len() > MAX_NAME_LENGTH {
-        textarea.set_style(UiStyle::ERROR);
+        textarea.set_style(UiStyle::current().error);
         textarea.set_block(default_block().title(title).title("(too long)"));
         false
     } else {
-        textarea.set_style(UiStyle::DEFAULT);
+        textarea.set_style(UiStyle::current().default);
         textarea.set_block(default_block().title(title));
         true
     }
 }
 
+// Filled and empty bar glyphs, or a percentage in accessible mode.
+pub fn bar_string(filled: usize, bars_length: usize) -> String {
+    if is_accessible_mode() {
+        return format!("{}%", 100 * filled / bars_length.max(1));
+    }
+    format!(
+        "{}{}",
+        "▰".repeat(filled),
+        "▱".repeat(bars_length.saturating_sub(filled))
+    )
+}
+
 pub fn format_satoshi(amount: u32) -> String {
     const SATOSHI_PER_BITCOIN: u32 = 100_000_000;
     if amount >= 100_000 {
@@ -162,9 +174,60 @@ pub fn format_satoshi(amount: u32) -> String {
     format!("{amount} sat")
 }
 
+/// Compact price chart drawn with block characters, one per value.
+pub fn sparkline(values: &[u32]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let min = values.iter().min().copied().unwrap_or_default();
+    let max = values.iter().max().copied().unwrap_or_default();
+    values
+        .iter()
+        .map(|value| {
+            if max == min {
+                BARS[BARS.len() / 2]
+            } else {
+                BARS[((value - min) as usize * (BARS.len() - 1)) / (max - min) as usize]
+            }
+        })
+        .collect()
+}
+
+/// Returns the cells on the straight line between two points on the map,
+/// flagging those where the spaceship would be after each full hour of travel.
+pub fn dead_reckoning_path(
+    from: (u16, u16),
+    to: (u16, u16),
+    travel_time: Tick,
+) -> Vec<((u16, u16), bool)> {
+    let dx = to.0 as f32 - from.0 as f32;
+    let dy = to.1 as f32 - from.1 as f32;
+    let steps = dx.abs().max(dy.abs()).round() as usize;
+    if steps == 0 || travel_time == 0 {
+        return vec![];
+    }
+
+    let hours = (travel_time / HOURS) as usize;
+    let mut hourly_steps = (1..=hours)
+        .map(|hour| {
+            ((hour as Tick * HOURS) as f32 / travel_time as f32 * steps as f32).round() as usize
+        })
+        .collect::<Vec<usize>>();
+    hourly_steps.dedup();
+
+    // Skip the first and last cells, where the planets are drawn.
+    (1..steps)
+        .map(|step| {
+            let fraction = step as f32 / steps as f32;
+            let x = (from.0 as f32 + dx * fraction).round() as u16;
+            let y = (from.1 as f32 + dy * fraction).round() as u16;
+            ((x, y), hourly_steps.contains(&step))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
-    use super::format_satoshi;
+    use super::{dead_reckoning_path, format_satoshi, sparkline};
+    use crate::world::constants::HOURS;
 
     #[test]
     fn test_format_satoshi() {
@@ -178,4 +241,30 @@ mod test {
         assert_eq!(format_satoshi(100_000_000), "1 BTC");
         assert_eq!(format_satoshi(1_234_567_890), "12.34568 BTC");
     }
+
+    #[test]
+    fn test_sparkline() {
+        assert_eq!(sparkline(&[]), "");
+        assert_eq!(sparkline(&[5, 5]), "▅▅");
+        assert_eq!(sparkline(&[10, 20, 30, 80]), "▁▂▃█");
+    }
+
+    #[test]
+    fn test_dead_reckoning_path() {
+        assert!(dead_reckoning_path((4, 4), (4, 4), 3 * HOURS).is_empty());
+
+        let path = dead_reckoning_path((0, 0), (10, 0), 5 * HOURS);
+        assert_eq!(path.len(), 9);
+        assert_eq!(path[0].0, (1, 0));
+        assert_eq!(path[8].0, (9, 0));
+        let markers = path
+            .iter()
+            .filter(|(_, is_marker)| *is_marker)
+            .map(|(cell, _)| *cell)
+            .collect::<Vec<(u16, u16)>>();
+        assert_eq!(markers, vec![(2, 0), (4, 0), (6, 0), (8, 0)]);
+
+        let path = dead_reckoning_path((10, 6), (0, 0), HOURS / 2);
+        assert!(path.iter().all(|(_, is_marker)| !is_marker));
+    }
 }
@@ -5,22 +5,26 @@ use super::{
     gif_map::GifMap,
     hover_text_line::HoverTextLine,
     hover_text_span::HoverTextSpan,
+    search_box::SearchQuery,
     traits::UiStyled,
     ui_callback::{CallbackRegistry, UiCallback},
-    utils::{format_satoshi, hover_text_target},
+    utils::{bar_string, format_satoshi, hover_text_target},
 };
 use crate::{
     game_engine::constants::MIN_TIREDNESS_FOR_ROLL_DECLINE,
     image::{player::PLAYER_IMAGE_WIDTH, spaceship::SPACESHIP_IMAGE_WIDTH},
     types::*,
     world::{
+        action_queue::QueuedAction,
         constants::*,
-        player::{Player, Trait},
+        player::Player,
         position::{GamePosition, Position, MAX_POSITION},
         resources::Resource,
+        scouting::SkillVisibility,
         skill::{GameSkill, Rated, SKILL_NAMES},
         spaceship::{SpaceshipUpgrade, SpaceshipUpgradeTarget},
         team::Team,
+        traits::{Trait, TRAIT_ACQUISITION_THRESHOLD},
         types::TeamLocation,
         world::World,
     },
@@ -35,12 +39,16 @@ use ratatui::{
     Frame,
 };
 use std::sync::{Arc, Mutex};
-
-pub const UP_ARROW_SPAN: Lazy<Span<'static>> = Lazy::new(|| Span::styled("↑", UiStyle::HEADER));
-pub const UP_RIGHT_ARROW_SPAN: Lazy<Span<'static>> = Lazy::new(|| Span::styled("↗", UiStyle::OK));
-pub const DOWN_ARROW_SPAN: Lazy<Span<'static>> = Lazy::new(|| Span::styled("↓", UiStyle::ERROR));
+use strum::IntoEnumIterator;
+
+pub const UP_ARROW_SPAN: Lazy<Span<'static>> =
+    Lazy::new(|| Span::styled("↑", UiStyle::current().header));
+pub const UP_RIGHT_ARROW_SPAN: Lazy<Span<'static>> =
+    Lazy::new(|| Span::styled("↗", UiStyle::current().ok));
+pub const DOWN_ARROW_SPAN: Lazy<Span<'static>> =
+    Lazy::new(|| Span::styled("↓", UiStyle::current().error));
 pub const DOWN_RIGHT_ARROW_SPAN: Lazy<Span<'static>> =
-    Lazy::new(|| Span::styled("↘", UiStyle::WARNING));
+    Lazy::new(|| Span::styled("↘", UiStyle::current().warning));
 
 pub const SWITCH_ARROW_SPAN: Lazy<Span<'static>> =
     Lazy::new(|| Span::styled("⇆", Style::default().fg(Color::Yellow)));
@@ -68,8 +76,61 @@ pub fn selectable_list<'a>(
         .collect();
 
     ClickableList::new(items, Arc::clone(&callback_registry))
-        .highlight_style(UiStyle::SELECTED)
-        .hovering_style(UiStyle::HIGHLIGHT)
+        .highlight_style(UiStyle::current().selected)
+        .hovering_style(UiStyle::current().highlight)
+}
+
+// Like selectable_list, with the parts matching the search query highlighted.
+pub fn searchable_list<'a>(
+    options: Vec<(String, Style)>,
+    query: &SearchQuery,
+    callback_registry: &Arc<Mutex<CallbackRegistry>>,
+) -> ClickableList<'a> {
+    let items: Vec<ClickableListItem> = options
+        .into_iter()
+        .map(|(text, style)| {
+            let line = if let Some((start, end)) = query.match_range(&text) {
+                Line::from(vec![
+                    Span::styled(format!(" {}", &text[..start]), style),
+                    Span::styled(
+                        text[start..end].to_string(),
+                        style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+                    ),
+                    Span::styled(text[end..].to_string(), style),
+                ])
+            } else {
+                Line::from(Span::styled(format!(" {}", text), style))
+            };
+            ClickableListItem::new(line)
+        })
+        .collect();
+
+    ClickableList::new(items, Arc::clone(&callback_registry))
+        .highlight_style(UiStyle::current().selected)
+        .hovering_style(UiStyle::current().highlight)
+}
+
+// Meter going from unfavorable (left) to favorable (right) for the team receiving the offer.
+pub fn trade_fairness_line<'a>(fairness: f32) -> Line<'a> {
+    const METER_LENGTH: usize = 21;
+    let marker =
+        (((fairness.clamp(-1.0, 1.0) + 1.0) / 2.0) * (METER_LENGTH - 1) as f32).round() as usize;
+    let meter = (0..METER_LENGTH)
+        .map(|i| if i == marker { '●' } else { '━' })
+        .collect::<String>();
+    let style = if fairness >= 0.0 {
+        UiStyle::current().ok
+    } else if fairness >= -0.2 {
+        UiStyle::current().warning
+    } else {
+        UiStyle::current().error
+    };
+
+    Line::from(vec![
+        Span::raw(" Fairness "),
+        Span::styled(meter, style),
+        Span::styled(format!(" {:+.0}%", fairness * 100.0), style),
+    ])
 }
 
 pub fn go_to_team_current_planet_button<'a>(
@@ -171,6 +232,43 @@ pub fn drink_button<'a>(
     Ok(button)
 }
 
+pub fn duel_button<'a>(
+    world: &World,
+    player_id: PlayerId,
+    callback_registry: &Arc<Mutex<CallbackRegistry>>,
+    hover_text_target: Rect,
+) -> AppResult<Button<'a>> {
+    let player = world.get_player_or_err(player_id)?;
+    let opponent_id = world.duel_opponent(player_id)?;
+    let opponent = world.get_player_or_err(opponent_id)?;
+    let can_duel = world
+        .get_own_team()?
+        .can_duel(player, opponent, world.now());
+
+    let mut button = Button::new(
+        format!("Duel {}", opponent.info.shortened_name()).into(),
+        UiCallback::Duel {
+            challenger_id: player_id,
+            opponent_id,
+        },
+        Arc::clone(&callback_registry),
+    )
+    .set_hotkey(UiKey::DUEL)
+    .set_hover_text(
+        format!(
+            "Play a 1v1 against {}: the winner gains morale and reputation, the loser gets tired.",
+            opponent.info.full_name()
+        ),
+        hover_text_target,
+    );
+
+    if can_duel.is_err() {
+        button.disable(Some(format!("{}", can_duel.unwrap_err().to_string())));
+    }
+
+    Ok(button)
+}
+
 pub fn go_to_team_home_planet_button<'a>(
     world: &World,
     team: &Team,
@@ -217,7 +315,7 @@ pub fn render_challenge_button<'a>(
             },
             Arc::clone(&callback_registry),
         )
-        .set_box_style(UiStyle::OK)
+        .set_box_style(UiStyle::current().ok)
         .set_hover_text(
             format!("Accept the challenge from {} and start a game.", team.name),
             hover_text_target,
@@ -230,7 +328,7 @@ pub fn render_challenge_button<'a>(
             },
             Arc::clone(&callback_registry),
         )
-        .set_box_style(UiStyle::ERROR)
+        .set_box_style(UiStyle::current().error)
         .set_hover_text(
             format!("Decline the challenge from {}.", team.name),
             hover_text_target,
@@ -294,9 +392,9 @@ pub fn render_challenge_button<'a>(
                 button.disable(Some(format!("{}", can_challenge.unwrap_err().to_string())));
             } else {
                 button = if team.peer_id.is_some() {
-                    button.set_box_style(UiStyle::NETWORK)
+                    button.set_box_style(UiStyle::current().network)
                 } else {
-                    button.set_box_style(UiStyle::OK)
+                    button.set_box_style(UiStyle::current().ok)
                 };
             }
             button
@@ -307,6 +405,74 @@ pub fn render_challenge_button<'a>(
     Ok(())
 }
 
+pub fn co_captain_button<'a>(
+    world: &World,
+    team: &Team,
+    callback_registry: &Arc<Mutex<CallbackRegistry>>,
+    hover_text_target: Rect,
+) -> Option<Button<'a>> {
+    let own_team = world.get_own_team().ok()?;
+
+    // We are co-captain of this team: we can give orders to it.
+    if team.co_captain_id == Some(own_team.id) {
+        let action = QueuedAction::ReturnHome;
+        let mut button = Button::new(
+            "Order: return home".into(),
+            UiCallback::SendCoCaptainOrder {
+                team_id: team.id,
+                action,
+            },
+            Arc::clone(&callback_registry),
+        )
+        .set_hover_text(
+            format!(
+                "Ask {} to add a return home order to their queue.",
+                team.name
+            ),
+            hover_text_target,
+        )
+        .set_box_style(UiStyle::current().network);
+        if let Err(e) = team.can_queue_action(action) {
+            button.disable(Some(e.to_string()));
+        }
+        return Some(button);
+    }
+
+    if team.peer_id.is_none() {
+        return None;
+    }
+
+    let button = if own_team.co_captain_id == Some(team.id) {
+        Button::new(
+            "Dismiss co-captain".into(),
+            UiCallback::SetCoCaptain { team_id: None },
+            Arc::clone(&callback_registry),
+        )
+        .set_hover_text(
+            format!("{} will not be able to give orders anymore.", team.name),
+            hover_text_target,
+        )
+    } else {
+        Button::new(
+            "Make co-captain".into(),
+            UiCallback::SetCoCaptain {
+                team_id: Some(team.id),
+            },
+            Arc::clone(&callback_registry),
+        )
+        .set_hover_text(
+            format!(
+                "Let the manager of {} queue orders for your team over the network.",
+                team.name
+            ),
+            hover_text_target,
+        )
+        .set_box_style(UiStyle::current().network)
+    };
+
+    Some(button)
+}
+
 pub fn trade_resource_button<'a>(
     world: &World,
     resource: Resource,
@@ -328,14 +494,20 @@ pub fn trade_resource_button<'a>(
     )
     .set_box_style(box_style);
 
-    if world
-        .get_own_team()?
+    let own_team = world.get_own_team()?;
+    if own_team
         .can_trade_resource(resource, amount, unit_cost)
         .is_err()
     {
         button.disable(None);
     }
 
+    if let Some(planet_id) = own_team.is_on_planet() {
+        if let Err(e) = world.can_trade_on_planet(planet_id, world.last_tick_short_interval) {
+            button.disable(Some(e.to_string()));
+        }
+    }
+
     if amount == 0 {
         button.set_text("".into());
         button.disable(None);
@@ -417,6 +589,94 @@ pub fn explore_button<'a>(
     Ok(button)
 }
 
+pub fn fuel_grade_button<'a>(
+    team: &Team,
+    callback_registry: &Arc<Mutex<CallbackRegistry>>,
+    hover_text_target: Rect,
+) -> Button<'a> {
+    let grade = team.spaceship.fuel_grade.next();
+    let mut button = Button::new(
+        format!("Fuel: {}", team.spaceship.fuel_grade).into(),
+        UiCallback::SetFuelGrade { grade },
+        Arc::clone(&callback_registry),
+    )
+    .set_hover_text(
+        format!(
+            "Switch to {} fuel (price x{:.1}): {}",
+            grade,
+            grade.price_modifier(),
+            grade.description()
+        ),
+        hover_text_target,
+    )
+    .set_hotkey(UiKey::FUEL_GRADE);
+
+    if let Err(err) = team.can_set_fuel_grade(grade) {
+        button.disable(Some(err.to_string()));
+    }
+
+    button
+}
+
+pub fn engine_tuning_button<'a>(
+    team: &Team,
+    callback_registry: &Arc<Mutex<CallbackRegistry>>,
+    hover_text_target: Rect,
+) -> Button<'a> {
+    let tuning = if team.spaceship.engine_tuning >= MAX_ENGINE_TUNING {
+        MIN_ENGINE_TUNING
+    } else {
+        team.spaceship.engine_tuning + 1
+    };
+    let mut button = Button::new(
+        format!("Engine tuning: {:+}", team.spaceship.engine_tuning).into(),
+        UiCallback::SetEngineTuning { tuning },
+        Arc::clone(&callback_registry),
+    )
+    .set_hover_text(
+        format!(
+            "Set engine tuning to {:+}. Higher tuning travels faster but burns more fuel per distance.",
+            tuning
+        ),
+        hover_text_target,
+    )
+    .set_hotkey(UiKey::ENGINE_TUNING);
+
+    if let Err(err) = team.can_set_engine_tuning(tuning) {
+        button.disable(Some(err.to_string()));
+    }
+
+    button
+}
+
+pub fn scan_button<'a>(
+    team: &Team,
+    callback_registry: &Arc<Mutex<CallbackRegistry>>,
+    hover_text_target: Rect,
+) -> Button<'a> {
+    let scanner = team.spaceship.scanner;
+    let mut button = Button::new(
+        "Scan".into(),
+        UiCallback::ScanNearbyPlanets,
+        Arc::clone(&callback_registry),
+    )
+    .set_hover_text(
+        format!(
+            "Scan the planets within {:.1} AU for free pirates and resources (Fuel {})",
+            scanner.range() as f32 / AU as f32,
+            scanner.fuel_cost()
+        ),
+        hover_text_target,
+    )
+    .set_hotkey(UiKey::SCAN_PLANETS);
+
+    if let Err(err) = team.can_scan_planets() {
+        button.disable(Some(err.to_string()));
+    }
+
+    button
+}
+
 pub fn space_adventure_button<'a>(
     world: &World,
     team: &Team,
@@ -425,7 +685,7 @@ pub fn space_adventure_button<'a>(
 ) -> AppResult<Button<'a>> {
     let mut button = Button::new(
         "Space Adventure".into(),
-        UiCallback::StartSpaceAdventure,
+        UiCallback::PromptSelectPilot,
         Arc::clone(&callback_registry),
     )
     .set_hotkey(UiKey::SPACE_ADVENTURE);
@@ -587,6 +847,17 @@ pub fn get_storage_spans(
     storage_capacity: u32,
     bars_length: usize,
 ) -> Vec<Span> {
+    if is_accessible_mode() {
+        return vec![Span::raw(format!(
+            "Stiva: gold {}, scraps {}, rum {}, {}/{} ",
+            resources.value(&Resource::GOLD),
+            resources.value(&Resource::SCRAPS),
+            resources.value(&Resource::RUM),
+            resources.used_storage_capacity(),
+            storage_capacity
+        ))];
+    }
+
     if let [gold_length, scraps_length, rum_length, free_bars] =
         get_storage_lengths(resources, storage_capacity, bars_length)[..4]
     {
@@ -611,16 +882,13 @@ pub fn get_crew_spans(team: &Team) -> Vec<Span> {
     let bars_length = team.spaceship.crew_capacity() as usize;
     let crew_length = team.player_ids.len();
 
-    let crew_bars = format!(
-        "{}{}",
-        "▰".repeat(crew_length),
-        "▱".repeat(bars_length - crew_length),
-    );
+    let crew_bars = bar_string(crew_length, bars_length);
 
     let crew_style = match crew_length {
-        x if x < MIN_PLAYERS_PER_GAME => UiStyle::ERROR,
-        x if x < team.spaceship.crew_capacity() as usize => UiStyle::WARNING,
-        _ => UiStyle::OK,
+        x if x < MIN_PLAYERS_PER_GAME => UiStyle::current().error,
+        _ if team.is_overcrowded() => UiStyle::current().error,
+        x if x < team.spaceship.crew_capacity() as usize => UiStyle::current().warning,
+        _ => UiStyle::current().ok,
     };
 
     vec![
@@ -636,7 +904,7 @@ pub fn get_crew_spans(team: &Team) -> Vec<Span> {
 
 pub fn get_durability_spans<'a>(value: u32, max_value: u32, bars_length: usize) -> Vec<Span<'a>> {
     let length = (value as f32 / max_value as f32 * bars_length as f32).round() as usize;
-    let bars = format!("{}{}", "▰".repeat(length), "▱".repeat(bars_length - length),);
+    let bars = bar_string(length, bars_length);
 
     let style = (20.0 * (value as f32 / max_value as f32)).bound().style();
 
@@ -654,7 +922,7 @@ pub fn get_charge_spans<'a>(
     bars_length: usize,
 ) -> Vec<Span<'a>> {
     let length = (value as f32 / max_value as f32 * bars_length as f32).round() as usize;
-    let bars = format!("{}{}", "▰".repeat(length), "▱".repeat(bars_length - length),);
+    let bars = bar_string(length, bars_length);
 
     let style = if is_recharging {
         0.0.style()
@@ -675,11 +943,7 @@ pub fn get_charge_spans<'a>(
 
 pub fn get_fuel_spans<'a>(fuel: u32, fuel_capacity: u32, bars_length: usize) -> Vec<Span<'a>> {
     let fuel_length = (fuel as f32 / fuel_capacity as f32 * bars_length as f32).round() as usize;
-    let fuel_bars = format!(
-        "{}{}",
-        "▰".repeat(fuel_length),
-        "▱".repeat(bars_length - fuel_length),
-    );
+    let fuel_bars = bar_string(fuel_length, bars_length);
 
     let fuel_style = (20.0 * (fuel as f32 / fuel_capacity as f32))
         .bound()
@@ -692,6 +956,21 @@ pub fn get_fuel_spans<'a>(fuel: u32, fuel_capacity: u32, bars_length: usize) ->
     ]
 }
 
+pub fn get_oxygen_spans<'a>(oxygen: u32, max_oxygen: u32, bars_length: usize) -> Vec<Span<'a>> {
+    let length = (oxygen as f32 / max_oxygen.max(1) as f32 * bars_length as f32).round() as usize;
+    let bars = bar_string(length, bars_length);
+
+    let style = (20.0 * (oxygen as f32 / max_oxygen.max(1) as f32))
+        .bound()
+        .style();
+
+    vec![
+        Span::raw("O2:    "),
+        Span::styled(bars, style),
+        Span::raw(format!(" {}s", oxygen)),
+    ]
+}
+
 pub fn render_spaceship_description(
     team: &Team,
     gif_map: &Arc<Mutex<GifMap>>,
@@ -750,6 +1029,10 @@ pub fn render_spaceship_description(
                 "Consumption: {:.2} t/h",
                 team.spaceship_fuel_consumption() * HOURS as f32
             )),
+            Line::from(format!(
+                "Fuel: {}  Tuning: {:+}",
+                team.spaceship.fuel_grade, team.spaceship.engine_tuning
+            )),
             Line::from(format!(
                 "Max distance: {:.3} AU",
                 team.spaceship.max_distance(team.fuel()) / AU as f32
@@ -812,6 +1095,10 @@ pub fn render_spaceship_upgrade(
         SpaceshipUpgradeTarget::Hull { component } => upgraded_ship.hull = component.clone(),
         SpaceshipUpgradeTarget::Engine { component } => upgraded_ship.engine = component.clone(),
         SpaceshipUpgradeTarget::Storage { component } => upgraded_ship.storage = component.clone(),
+        SpaceshipUpgradeTarget::LifeSupport { component } => {
+            upgraded_ship.life_support = component.clone()
+        }
+        SpaceshipUpgradeTarget::Scanner { component } => upgraded_ship.scanner = component.clone(),
         SpaceshipUpgradeTarget::Repairs { .. } => upgraded_ship.reset_durability(),
     }
 
@@ -845,11 +1132,11 @@ pub fn render_spaceship_upgrade(
                     upgraded_ship.speed(storage_units) * HOURS as f32 / AU as f32
                 ),
                 if upgraded_ship.speed(storage_units) > team.spaceship.speed(storage_units) {
-                    UiStyle::OK
+                    UiStyle::current().ok
                 } else if upgraded_ship.speed(storage_units) < team.spaceship.speed(storage_units) {
-                    UiStyle::ERROR
+                    UiStyle::current().error
                 } else {
-                    UiStyle::DEFAULT
+                    UiStyle::current().default
                 },
             ),
             Span::raw(" AU/h"),
@@ -864,11 +1151,47 @@ pub fn render_spaceship_upgrade(
             Span::styled(
                 format!("{}", upgraded_ship.crew_capacity()),
                 if upgraded_ship.crew_capacity() > team.spaceship.crew_capacity() {
-                    UiStyle::OK
+                    UiStyle::current().ok
                 } else if upgraded_ship.crew_capacity() < team.spaceship.crew_capacity() {
-                    UiStyle::ERROR
+                    UiStyle::current().error
+                } else {
+                    UiStyle::current().default
+                },
+            ),
+        ]),
+        Line::from(vec![
+            Span::raw(format!(
+                "{:<13} {:<5}",
+                "Life support:",
+                team.spaceship.life_support.to_string()
+            )),
+            Span::raw(" --> "),
+            Span::styled(
+                upgraded_ship.life_support.to_string(),
+                if upgraded_ship.life_support.crew_capacity()
+                    > team.spaceship.life_support.crew_capacity()
+                {
+                    UiStyle::current().ok
+                } else if team.spaceship.is_life_support_failing() {
+                    UiStyle::current().error
                 } else {
-                    UiStyle::DEFAULT
+                    UiStyle::current().default
+                },
+            ),
+        ]),
+        Line::from(vec![
+            Span::raw(format!(
+                "{:<13} {:<5}",
+                "Scanner:",
+                team.spaceship.scanner.to_string()
+            )),
+            Span::raw(" --> "),
+            Span::styled(
+                upgraded_ship.scanner.to_string(),
+                if upgraded_ship.scanner.range() > team.spaceship.scanner.range() {
+                    UiStyle::current().ok
+                } else {
+                    UiStyle::current().default
                 },
             ),
         ]),
@@ -882,11 +1205,11 @@ pub fn render_spaceship_upgrade(
             Span::styled(
                 format!("{}", upgraded_ship.fuel_capacity()),
                 if upgraded_ship.fuel_capacity() > team.spaceship.fuel_capacity() {
-                    UiStyle::OK
+                    UiStyle::current().ok
                 } else if upgraded_ship.fuel_capacity() < team.spaceship.fuel_capacity() {
-                    UiStyle::ERROR
+                    UiStyle::current().error
                 } else {
-                    UiStyle::DEFAULT
+                    UiStyle::current().default
                 },
             ),
             Span::raw(" t"),
@@ -901,11 +1224,11 @@ pub fn render_spaceship_upgrade(
             Span::styled(
                 format!("{}", upgraded_ship.storage_capacity()),
                 if upgraded_ship.storage_capacity() > team.spaceship.storage_capacity() {
-                    UiStyle::OK
+                    UiStyle::current().ok
                 } else if upgraded_ship.storage_capacity() < team.spaceship.storage_capacity() {
-                    UiStyle::ERROR
+                    UiStyle::current().error
                 } else {
-                    UiStyle::DEFAULT
+                    UiStyle::current().default
                 },
             ),
         ]),
@@ -924,11 +1247,11 @@ pub fn render_spaceship_upgrade(
                     upgraded_ship.durability()
                 ),
                 if upgraded_ship.durability() > team.spaceship.durability() {
-                    UiStyle::OK
+                    UiStyle::current().ok
                 } else if upgraded_ship.durability() < team.spaceship.durability() {
-                    UiStyle::ERROR
+                    UiStyle::current().error
                 } else {
-                    UiStyle::DEFAULT
+                    UiStyle::current().default
                 },
             ),
         ]),
@@ -947,13 +1270,13 @@ pub fn render_spaceship_upgrade(
                 if upgraded_ship.fuel_consumption(storage_units)
                     > team.spaceship.fuel_consumption(storage_units)
                 {
-                    UiStyle::OK
+                    UiStyle::current().ok
                 } else if upgraded_ship.fuel_consumption(storage_units)
                     < team.spaceship.fuel_consumption(storage_units)
                 {
-                    UiStyle::ERROR
+                    UiStyle::current().error
                 } else {
-                    UiStyle::DEFAULT
+                    UiStyle::current().default
                 },
             ),
             Span::raw(" t/h"),
@@ -973,13 +1296,13 @@ pub fn render_spaceship_upgrade(
                 if upgraded_ship.max_distance(upgraded_ship.fuel_capacity())
                     > team.spaceship.max_distance(team.spaceship.fuel_capacity())
                 {
-                    UiStyle::OK
+                    UiStyle::current().ok
                 } else if upgraded_ship.max_distance(upgraded_ship.fuel_capacity())
                     < team.spaceship.max_distance(team.spaceship.fuel_capacity())
                 {
-                    UiStyle::ERROR
+                    UiStyle::current().error
                 } else {
-                    UiStyle::DEFAULT
+                    UiStyle::current().default
                 },
             ),
             Span::raw(" AU"),
@@ -1002,6 +1325,7 @@ pub fn render_player_description(
     tick: usize,
     frame: &mut Frame,
     world: &World,
+    visibility: SkillVisibility,
     area: Rect,
 ) {
     let h_split = Layout::horizontal([
@@ -1035,14 +1359,31 @@ pub fn render_player_description(
 
     let hover_text_target = hover_text_target(frame);
 
+    // Pirates without a trait show the one they are closest to acquire.
+    let progressing_trait = Trait::iter()
+        .filter(|t| player.trait_progress.value(*t) > 0.0)
+        .max_by(|a, b| {
+            player
+                .trait_progress
+                .value(*a)
+                .total_cmp(&player.trait_progress.value(*b))
+        });
     let trait_span = if let Some(t) = player.special_trait {
         let trait_style = match t {
-            Trait::Killer => UiStyle::TRAIT_KILLER,
-            Trait::Relentless => UiStyle::TRAIT_RELENTLESS,
-            Trait::Showpirate => UiStyle::TRAIT_SHOWPIRATE,
-            Trait::Spugna => UiStyle::TRAIT_SPUGNA,
+            Trait::Killer => UiStyle::current().trait_killer,
+            Trait::Relentless => UiStyle::current().trait_relentless,
+            Trait::Showpirate => UiStyle::current().trait_showpirate,
+            Trait::Spugna => UiStyle::current().trait_spugna,
         };
         Span::styled(format!("{t}"), trait_style)
+    } else if let Some(t) = progressing_trait {
+        Span::styled(
+            format!(
+                "{t} {:.0}%",
+                100.0 * player.trait_progress.value(t) / TRAIT_ACQUISITION_THRESHOLD
+            ),
+            UiStyle::current().disconnected,
+        )
     } else {
         Span::raw("")
     };
@@ -1061,6 +1402,8 @@ pub fn render_player_description(
             trait_span,
             if let Some(t) = player.special_trait {
                 t.description(&player)
+            } else if let Some(t) = progressing_trait {
+                format!("Becoming {t} by {}.", t.acquisition_hint())
             } else {
                     "".to_string()
             },
@@ -1090,16 +1433,12 @@ pub fn render_player_description(
     }
 
     let morale_length = (morale / MAX_MORALE * BARS_LENGTH as f32).round() as usize;
-    let morale_string = format!(
-        "{}{}",
-        "▰".repeat(morale_length),
-        "▱".repeat(BARS_LENGTH - morale_length),
-    );
+    let morale_string = bar_string(morale_length, BARS_LENGTH);
     let morale_style = match morale {
-        x if x > 1.75 * MORALE_THRESHOLD_FOR_LEAVING => UiStyle::OK,
-        x if x > MORALE_THRESHOLD_FOR_LEAVING => UiStyle::WARNING,
-        x if x > 0.0 => UiStyle::ERROR,
-        _ => UiStyle::UNSELECTABLE,
+        x if x > 1.75 * MORALE_THRESHOLD_FOR_LEAVING => UiStyle::current().ok,
+        x if x > MORALE_THRESHOLD_FOR_LEAVING => UiStyle::current().warning,
+        x if x > 0.0 => UiStyle::current().error,
+        _ => UiStyle::current().unselectable,
     };
 
     frame.render_widget(
@@ -1143,16 +1482,12 @@ pub fn render_player_description(
     }
 
     let tiredness_length = (tiredness / MAX_TIREDNESS * BARS_LENGTH as f32).round() as usize;
-    let energy_string = format!(
-        "{}{}",
-        "▰".repeat(BARS_LENGTH - tiredness_length),
-        "▱".repeat(tiredness_length),
-    );
+    let energy_string = bar_string(BARS_LENGTH - tiredness_length, BARS_LENGTH);
     let energy_style = match tiredness {
-        x if x < MIN_TIREDNESS_FOR_ROLL_DECLINE * 0.75 => UiStyle::OK,
-        x if x < MIN_TIREDNESS_FOR_ROLL_DECLINE * 1.5 => UiStyle::WARNING,
-        x if x < MAX_TIREDNESS => UiStyle::ERROR,
-        _ => UiStyle::UNSELECTABLE,
+        x if x < MIN_TIREDNESS_FOR_ROLL_DECLINE * 0.75 => UiStyle::current().ok,
+        x if x < MIN_TIREDNESS_FOR_ROLL_DECLINE * 1.5 => UiStyle::current().warning,
+        x if x < MAX_TIREDNESS => UiStyle::current().error,
+        _ => UiStyle::current().unselectable,
     };
 
     frame.render_widget(
@@ -1169,29 +1504,33 @@ pub fn render_player_description(
         header_body_stats[3],
     );
 
-    frame.render_widget(
-        Paragraph::new(format!(
-            "{} yo, {} cm, {} kg, {}",
-            player.info.age as u8,
-            player.info.height as u8,
-            player.info.weight as u8,
-            player.info.population,
-        )),
-        header_body_stats[4],
-    );
+    let mut info_spans = vec![Span::raw(format!(
+        "{} yo, {} cm, {} kg, {}",
+        player.info.age as u8,
+        player.info.height as u8,
+        player.info.weight as u8,
+        player.info.population,
+    ))];
+    if let Some(injury) = player.injury {
+        info_spans.push(Span::styled(
+            format!(
+                " - {} ({})",
+                injury.kind,
+                injury.recovery_time_left.formatted()
+            ),
+            UiStyle::current().error,
+        ));
+    }
+    frame.render_widget(Paragraph::new(Line::from(info_spans)), header_body_stats[4]);
 
     frame.render_widget(
-        Paragraph::new(format_player_data(player)),
+        Paragraph::new(format_player_data(player, visibility)),
         header_body_stats[6],
     );
 
     // Render main block
-    let block = default_block().title(format!(
-        "{} {} {}",
-        player.info.first_name,
-        player.info.last_name,
-        player.stars()
-    ));
+    let (stars, _) = rating_stars(player, visibility);
+    let block = default_block().title(format!("{} {}", player.info.titled_name(), stars));
     frame.render_widget(block, area);
 }
 
@@ -1206,12 +1545,45 @@ fn improvement_indicator<'a>(skill: f32, previous: f32) -> Span<'a> {
     } else if skill < previous - 0.33 {
         DOWN_RIGHT_ARROW_SPAN.clone()
     } else {
-        Span::styled(" ", UiStyle::DEFAULT)
+        Span::styled(" ", UiStyle::current().default)
+    }
+}
+
+// Star ratings are only shown when all the skills are known.
+fn rating_stars(rated: &impl Rated, visibility: SkillVisibility) -> (String, Style) {
+    if visibility == SkillVisibility::Full {
+        (rated.stars(), rated.rating().style())
+    } else {
+        ("?????".to_string(), UiStyle::current().unselectable)
     }
 }
 
-fn format_player_data(player: &Player) -> Vec<Line> {
-    let skills = player.current_skill_array();
+fn skill_value<'a>(
+    player: &Player,
+    visibility: SkillVisibility,
+    index: usize,
+) -> (String, Style, Span<'a>) {
+    let skill = player.current_skill_array()[index];
+    match visibility.estimated_skill(player.id, index, skill) {
+        Some(estimate) if visibility == SkillVisibility::Full => (
+            format!("{:02}", estimate.value()),
+            estimate.style(),
+            improvement_indicator(skill, player.previous_skills[index]),
+        ),
+        Some(estimate) => (
+            format!("{:02}", estimate.value()),
+            estimate.style(),
+            Span::styled("?", UiStyle::current().unselectable),
+        ),
+        None => (
+            "??".to_string(),
+            UiStyle::current().unselectable,
+            Span::styled(" ", UiStyle::current().default),
+        ),
+    }
+}
+
+fn format_player_data(player: &Player, visibility: SkillVisibility) -> Vec<Line> {
     let mut text = vec![];
     let mut roles = (0..MAX_POSITION)
         .map(|i: Position| {
@@ -1224,108 +1596,88 @@ fn format_player_data(player: &Player) -> Vec<Line> {
     roles.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
 
     let mut spans = vec![];
+    let (stars, style) = rating_stars(&roles[0].1, visibility);
     spans.push(Span::styled(
-        format!("{:<2} {:<5}          ", roles[0].0, roles[0].1.stars()),
-        roles[0].1.style(),
-    ));
-    spans.push(Span::styled(
-        format!("Athletics {:<5}", player.athletics.stars()),
-        player.athletics.rating().style(),
+        format!("{:<2} {:<5}          ", roles[0].0, stars),
+        style,
     ));
+    let (stars, style) = rating_stars(&player.athletics, visibility);
+    spans.push(Span::styled(format!("Athletics {:<5}", stars), style));
     text.push(Line::from(spans));
 
     for i in 0..4 {
         let mut spans = vec![];
+        let (stars, style) = rating_stars(&roles[i + 1].1, visibility);
         spans.push(Span::styled(
-            format!("{:<2} {:<5}       ", roles[i + 1].0, roles[i + 1].1.stars()),
-            roles[i + 1].1.style(),
+            format!("{:<2} {:<5}       ", roles[i + 1].0, stars),
+            style,
         ));
 
+        let (value, style, indicator) = skill_value(player, visibility, i);
         spans.push(Span::styled(
-            format!(
-                "   {:<MAX_NAME_LENGTH$}{:02} ",
-                SKILL_NAMES[i],
-                skills[i].value(),
-            ),
-            skills[i].style(),
+            format!("   {:<MAX_NAME_LENGTH$}{} ", SKILL_NAMES[i], value),
+            style,
         ));
-        spans.push(improvement_indicator(skills[i], player.previous_skills[i]));
+        spans.push(indicator);
 
         text.push(Line::from(spans));
     }
     text.push(Line::from(""));
 
+    let (offense_stars, offense_style) = rating_stars(&player.offense, visibility);
+    let (defense_stars, defense_style) = rating_stars(&player.defense, visibility);
     text.push(Line::from(vec![
         Span::styled(
-            format!("{} {:<5}     ", "Offense", player.offense.stars()),
-            player.offense.rating().style(),
-        ),
-        Span::styled(
-            format!("{} {}", "Defense", player.defense.stars()),
-            player.defense.rating().style(),
+            format!("{} {:<5}     ", "Offense", offense_stars),
+            offense_style,
         ),
+        Span::styled(format!("{} {}", "Defense", defense_stars), defense_style),
     ]));
     for i in 0..4 {
         let mut spans = vec![];
+        let (value, style, indicator) = skill_value(player, visibility, i + 4);
         spans.push(Span::styled(
-            format!("{:<10}{:02} ", SKILL_NAMES[i + 4], skills[i + 4].value(),),
-            skills[i + 4].style(),
-        ));
-        spans.push(improvement_indicator(
-            skills[i + 4],
-            player.previous_skills[i + 4],
+            format!("{:<10}{} ", SKILL_NAMES[i + 4], value),
+            style,
         ));
+        spans.push(indicator);
 
+        let (value, style, indicator) = skill_value(player, visibility, i + 8);
         spans.push(Span::styled(
-            format!(
-                "    {:<MAX_NAME_LENGTH$}{:02} ",
-                SKILL_NAMES[i + 8],
-                skills[i + 8].value(),
-            ),
-            skills[i + 8].style(),
-        ));
-        spans.push(improvement_indicator(
-            skills[i + 8],
-            player.previous_skills[i + 8],
+            format!("    {:<MAX_NAME_LENGTH$}{} ", SKILL_NAMES[i + 8], value),
+            style,
         ));
+        spans.push(indicator);
 
         text.push(Line::from(spans));
     }
     text.push(Line::from(""));
+
+    let (technical_stars, technical_style) = rating_stars(&player.technical, visibility);
+    let (mental_stars, mental_style) = rating_stars(&player.mental, visibility);
     text.push(Line::from(vec![
         Span::styled(
-            format!("{} {:<5}   ", "Technical", player.technical.stars()),
-            player.technical.rating().style(),
-        ),
-        Span::styled(
-            format!("{} {}", "Mental", player.mental.stars()),
-            player.mental.rating().style(),
+            format!("{} {:<5}   ", "Technical", technical_stars),
+            technical_style,
         ),
+        Span::styled(format!("{} {}", "Mental", mental_stars), mental_style),
     ]));
 
     for i in 0..4 {
         let mut spans = vec![];
+        let (value, style, indicator) = skill_value(player, visibility, i + 12);
         spans.push(Span::styled(
-            format!("{:<10}{:02} ", SKILL_NAMES[i + 12], skills[i + 12].value(),),
-            skills[i + 12].style(),
-        ));
-        spans.push(improvement_indicator(
-            skills[i + 12],
-            player.previous_skills[i + 12],
+            format!("{:<10}{} ", SKILL_NAMES[i + 12], value),
+            style,
         ));
+        spans.push(indicator);
 
+        let (value, style, indicator) = skill_value(player, visibility, i + 16);
         spans.push(Span::styled(
-            format!(
-                "    {:<MAX_NAME_LENGTH$}{:02} ",
-                SKILL_NAMES[i + 16],
-                skills[i + 16].value(),
-            ),
-            skills[i + 16].style(),
-        ));
-        spans.push(improvement_indicator(
-            skills[i + 16],
-            player.previous_skills[i + 16],
+            format!("    {:<MAX_NAME_LENGTH$}{} ", SKILL_NAMES[i + 16], value),
+            style,
         ));
+        spans.push(indicator);
 
         text.push(Line::from(spans));
     }
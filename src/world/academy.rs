@@ -0,0 +1,84 @@
+use super::{
+    constants::MAX_SKILL_INCREASE_PER_LONG_TICK, player::Player, skill::Rated,
+    types::PlayerLocation,
+};
+use crate::types::{AppResult, PlanetId, Tick};
+use anyhow::anyhow;
+
+// Daily fee paid for each prospect enrolled in the academy.
+pub const ACADEMY_DAILY_FEE: u32 = 200;
+pub const MAX_ACADEMY_PROSPECTS: usize = 4;
+// Only young and unproven pirates can be enrolled.
+pub const ACADEMY_MAX_RELATIVE_AGE: f32 = 0.3;
+pub const ACADEMY_MAX_RATING: u8 = 8;
+// Training of every skill per day, before the potential modifier.
+pub const ACADEMY_DAILY_TRAINING: f32 = 0.12;
+
+pub fn can_enroll(player: &Player) -> AppResult<()> {
+    if player.team.is_some() {
+        return Err(anyhow!("Already in a team"));
+    }
+
+    if player.info.relative_age() > ACADEMY_MAX_RELATIVE_AGE {
+        return Err(anyhow!("Too old for the academy"));
+    }
+
+    if player.rating() > ACADEMY_MAX_RATING {
+        return Err(anyhow!("Too skilled for the academy"));
+    }
+
+    Ok(())
+}
+
+pub fn academy_location(player: &Player) -> Option<(PlanetId, Tick)> {
+    match player.current_location {
+        PlayerLocation::InAcademy {
+            asteroid_id,
+            enrolled,
+        } => Some((asteroid_id, enrolled)),
+        _ => None,
+    }
+}
+
+// A day of drills: prospects below their potential develop faster.
+pub fn train_prospect(player: &mut Player) {
+    let potential_modifier = (1.0 + (player.potential - player.average_skill()) / 20.0).max(0.0);
+    for training in player.skills_training.iter_mut() {
+        *training = (*training + ACADEMY_DAILY_TRAINING * potential_modifier)
+            .min(MAX_SKILL_INCREASE_PER_LONG_TICK);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{can_enroll, train_prospect, ACADEMY_MAX_RATING};
+    use crate::types::{PlayerId, TeamId};
+    use crate::world::{player::Player, skill::Rated, utils::PLANET_DATA};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_academy_prospect() {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let mut player = Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 0.0);
+        player.info.age = player.info.population.min_age();
+        for idx in 0..20 {
+            player.modify_skill(idx, -20.0);
+        }
+        assert!(player.rating() <= ACADEMY_MAX_RATING);
+        assert!(can_enroll(&player).is_ok());
+
+        player.info.age = player.info.population.max_age();
+        assert!(can_enroll(&player).is_err());
+        player.info.age = player.info.population.min_age();
+        player.team = Some(TeamId::new_v4());
+        assert!(can_enroll(&player).is_err());
+
+        player.skills_training = [0.0; 20];
+        train_prospect(&mut player);
+        assert!(player
+            .skills_training
+            .iter()
+            .all(|&training| training > 0.0));
+    }
+}
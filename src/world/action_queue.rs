@@ -0,0 +1,71 @@
+use super::{resources::Resource, world::World};
+use crate::types::{PlanetId, SystemTimeTick, Tick};
+use serde::{Deserialize, Serialize};
+
+/// Orders that the own team executes one after the other, as soon as it is idle on a planet.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum QueuedAction {
+    TravelTo { planet_id: PlanetId },
+    Buy { resource: Resource, amount: u32 },
+    Explore { duration: Tick },
+    ReturnHome,
+}
+
+impl QueuedAction {
+    pub fn description(&self, world: &World) -> String {
+        match self {
+            Self::TravelTo { planet_id } => {
+                let planet_name = match world.get_planet_or_err(*planet_id) {
+                    Ok(planet) => planet.name.clone(),
+                    Err(_) => "unknown planet".to_string(),
+                };
+                format!("Travel to {}", planet_name)
+            }
+            Self::Buy { resource, amount } => format!("Buy {} {}", amount, resource),
+            Self::Explore { duration } => format!("Explore for {}", duration.formatted()),
+            Self::ReturnHome => "Return home".to_string(),
+        }
+    }
+}
+
+pub fn move_action_up(queue: &mut Vec<QueuedAction>, index: usize) {
+    if index > 0 && index < queue.len() {
+        queue.swap(index - 1, index);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{move_action_up, QueuedAction};
+    use crate::world::{constants::HOURS, resources::Resource};
+
+    #[test]
+    fn test_move_action_up() {
+        let mut queue = vec![
+            QueuedAction::ReturnHome,
+            QueuedAction::Buy {
+                resource: Resource::FUEL,
+                amount: 50,
+            },
+            QueuedAction::Explore {
+                duration: 4 * HOURS,
+            },
+        ];
+
+        move_action_up(&mut queue, 2);
+        assert_eq!(
+            queue[1],
+            QueuedAction::Explore {
+                duration: 4 * HOURS
+            }
+        );
+
+        // Moving the first action up does nothing.
+        move_action_up(&mut queue, 0);
+        assert_eq!(queue[0], QueuedAction::ReturnHome);
+
+        // Out of bounds indexes are ignored.
+        move_action_up(&mut queue, 5);
+        assert_eq!(queue.len(), 3);
+    }
+}
@@ -0,0 +1,313 @@
+use super::{
+    planet::Planet,
+    player::Player,
+    position::{GamePosition, Position, MAX_POSITION},
+    resources::Resource,
+    skill::SKILL_NAMES,
+    team::Team,
+    types::{TeamBonus, TrainingFocus},
+    world::World,
+};
+use crate::types::{AppResult, StorableResourceMap};
+use itertools::Itertools;
+use strum::IntoEnumIterator;
+
+// Fuel below this fraction of the tank capacity is worth a warning.
+const LOW_FUEL_RATIO: f32 = 0.25;
+// Cargo hold above this fraction of the storage capacity is worth a warning.
+const FULL_STORAGE_RATIO: f32 = 0.9;
+// A starter this much below the average starter rating is a weak spot.
+const WEAK_POSITION_GAP: f32 = 3.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AdviceAction {
+    AssignBestPositions,
+    BuyFuel {
+        amount: u32,
+        unit_cost: u32,
+    },
+    SellResource {
+        resource: Resource,
+        amount: u32,
+        unit_cost: u32,
+    },
+    SetTrainingFocus {
+        focus: TrainingFocus,
+    },
+}
+
+impl AdviceAction {
+    pub fn label(&self) -> String {
+        match self {
+            Self::AssignBestPositions => "Auto-assign positions".to_string(),
+            Self::BuyFuel { amount, .. } => format!("Buy {} fuel", amount),
+            Self::SellResource {
+                resource, amount, ..
+            } => format!("Sell {} {}", amount, resource.to_string().to_lowercase()),
+            Self::SetTrainingFocus { focus } => format!("Focus {}", focus),
+        }
+    }
+}
+
+/// A suggestion of the advisor, optionally applied with a single click.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Advice {
+    pub text: String,
+    pub action: Option<AdviceAction>,
+}
+
+impl Advice {
+    fn new(text: String, action: Option<AdviceAction>) -> Self {
+        Self { text, action }
+    }
+}
+
+pub fn team_advice(world: &World, team: &Team) -> AppResult<Vec<Advice>> {
+    let players = team
+        .player_ids
+        .iter()
+        .filter_map(|&id| world.get_player(id))
+        .collect_vec();
+
+    let market = match team.is_on_planet() {
+        Some(planet_id) => {
+            let planet = world.get_planet_or_err(planet_id)?;
+            let merchant_bonus = TeamBonus::TradePrice.current_team_bonus(world, team.id)?
+                * team.merchant_modifier(planet_id);
+            Some((planet, merchant_bonus))
+        }
+        None => None,
+    };
+
+    let mut advice = lineup_advice(team, &players);
+    advice.extend(fuel_advice(team, market));
+    advice.extend(cargo_advice(team, market));
+    advice.extend(training_advice(team, &players));
+    Ok(advice)
+}
+
+pub fn lineup_advice(team: &Team, players: &[&Player]) -> Vec<Advice> {
+    let mut advice = vec![];
+    if players.len() < MAX_POSITION as usize {
+        advice.push(Advice::new(
+            format!(
+                "The crew has only {} pirates, hire more to field a full team.",
+                players.len()
+            ),
+            None,
+        ));
+        return advice;
+    }
+
+    let best = Team::best_position_assignment(players.to_vec());
+    if best[..MAX_POSITION as usize] != team.player_ids[..MAX_POSITION as usize] {
+        advice.push(Advice::new(
+            "The starting lineup could be stronger with a different assignment.".to_string(),
+            Some(AdviceAction::AssignBestPositions),
+        ));
+    }
+
+    let ratings = players
+        .iter()
+        .take(MAX_POSITION as usize)
+        .enumerate()
+        .map(|(position, player)| {
+            (position as Position).player_rating(player.current_skill_array())
+        })
+        .collect_vec();
+    let average = ratings.iter().sum::<f32>() / ratings.len() as f32;
+    for (position, rating) in ratings.iter().enumerate() {
+        if *rating < average - WEAK_POSITION_GAP {
+            advice.push(Advice::new(
+                format!(
+                    "{} is a weak spot, look for a better pirate to play there.",
+                    (position as Position).as_str()
+                ),
+                None,
+            ));
+        }
+    }
+    advice
+}
+
+pub fn fuel_advice(team: &Team, market: Option<(&Planet, f32)>) -> Option<Advice> {
+    let capacity = team.fuel_capacity();
+    if team.fuel() as f32 >= capacity as f32 * LOW_FUEL_RATIO {
+        return None;
+    }
+
+    let text = format!("Fuel is running low ({}/{}).", team.fuel(), capacity);
+    let Some((planet, merchant_bonus)) = market.filter(|(p, _)| p.total_population() > 0) else {
+        return Some(Advice::new(
+            format!("{} Refuel at the next inhabited planet.", text),
+            None,
+        ));
+    };
+
+    let unit_cost = planet.resource_buy_price(Resource::FUEL, merchant_bonus);
+    let amount = (capacity - team.fuel()).min(team.balance() / unit_cost);
+    if amount == 0 {
+        return Some(Advice::new(
+            format!("{} There are not enough satoshi to refuel.", text),
+            None,
+        ));
+    }
+    Some(Advice::new(
+        text,
+        Some(AdviceAction::BuyFuel { amount, unit_cost }),
+    ))
+}
+
+pub fn cargo_advice(team: &Team, market: Option<(&Planet, f32)>) -> Option<Advice> {
+    let cargo = [Resource::GOLD, Resource::SCRAPS, Resource::RUM]
+        .into_iter()
+        .filter(|resource| team.resources.value(resource) > 0)
+        .collect_vec();
+    if cargo.is_empty() {
+        return None;
+    }
+
+    let Some((planet, merchant_bonus)) = market.filter(|(p, _)| p.total_population() > 0) else {
+        // Only worth mentioning when the hold is getting full.
+        if (team.used_storage_capacity() as f32)
+            < team.storage_capacity() as f32 * FULL_STORAGE_RATIO
+        {
+            return None;
+        }
+        return Some(Advice::new(
+            "The cargo hold is almost full and cannot be sold here, travel to an inhabited planet."
+                .to_string(),
+            None,
+        ));
+    };
+
+    if (team.used_storage_capacity() as f32) < team.storage_capacity() as f32 * FULL_STORAGE_RATIO {
+        return None;
+    }
+
+    // Sell the cargo taking the most space, keeping the rum for the crew.
+    let resource = cargo
+        .into_iter()
+        .filter(|&resource| resource != Resource::RUM)
+        .max_by_key(|resource| team.resources.value(resource) * resource.to_storing_space())?;
+    let unit_cost = planet.resource_sell_price(resource, merchant_bonus);
+    if unit_cost == 0 {
+        return Some(Advice::new(
+            format!(
+                "Nobody buys {} on {}, try another market.",
+                resource.to_string().to_lowercase(),
+                planet.name
+            ),
+            None,
+        ));
+    }
+    Some(Advice::new(
+        "The cargo hold is almost full.".to_string(),
+        Some(AdviceAction::SellResource {
+            resource,
+            amount: team.resources.value(&resource),
+            unit_cost,
+        }),
+    ))
+}
+
+// The game training should focus on the skills in which the crew is weakest.
+pub fn training_advice(team: &Team, players: &[&Player]) -> Option<Advice> {
+    if players.is_empty() {
+        return None;
+    }
+
+    let weakest = TrainingFocus::iter()
+        .min_by(|a, b| focus_average(players, *a).total_cmp(&focus_average(players, *b)))?;
+    if team.training_focus == Some(weakest) {
+        return None;
+    }
+
+    let skills = (0..SKILL_NAMES.len())
+        .filter(|&idx| weakest.is_focus(idx))
+        .map(|idx| SKILL_NAMES[idx].to_lowercase())
+        .join(", ");
+    Some(Advice::new(
+        format!(
+            "The crew is weakest in {} ({}), focus the training on it.",
+            weakest.to_string().to_lowercase(),
+            skills
+        ),
+        Some(AdviceAction::SetTrainingFocus { focus: weakest }),
+    ))
+}
+
+fn focus_average(players: &[&Player], focus: TrainingFocus) -> f32 {
+    let values = players
+        .iter()
+        .flat_map(|player| {
+            player
+                .current_skill_array()
+                .into_iter()
+                .enumerate()
+                .filter(|(idx, _)| focus.is_focus(*idx))
+                .map(|(_, value)| value)
+        })
+        .collect_vec();
+    values.iter().sum::<f32>() / values.len().max(1) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fuel_advice, lineup_advice, training_advice, AdviceAction};
+    use crate::types::{PlanetId, PlayerId, StorableResourceMap, TeamId};
+    use crate::world::{player::Player, resources::Resource, team::Team, utils::PLANET_DATA};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_team_advice() {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let mut team = Team::random(
+            TeamId::new_v4(),
+            PlanetId::new_v4(),
+            "Team".into(),
+            "Ship".into(),
+        );
+        let players = (0..6)
+            .map(|_| Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 10.0))
+            .collect::<Vec<Player>>();
+        let player_refs = players.iter().collect::<Vec<&Player>>();
+        team.player_ids = Team::best_position_assignment(player_refs.clone());
+        let player_refs = team
+            .player_ids
+            .iter()
+            .filter_map(|id| players.iter().find(|p| p.id == *id))
+            .collect::<Vec<&Player>>();
+        assert!(lineup_advice(&team, &player_refs)
+            .iter()
+            .all(|advice| advice.action.is_none()));
+        assert!(!lineup_advice(&team, &player_refs[..3]).is_empty());
+
+        // Empty tank, refuel with the available satoshi.
+        team.resources = Default::default();
+        let planet = PLANET_DATA
+            .iter()
+            .find(|planet| planet.total_population() > 0)
+            .unwrap();
+        assert!(fuel_advice(&team, Some((planet, 1.0)))
+            .unwrap()
+            .action
+            .is_none());
+        team.resources
+            .saturating_add(Resource::SATOSHI, 1_000_000, u32::MAX);
+        let advice = fuel_advice(&team, Some((planet, 1.0))).unwrap();
+        assert!(matches!(
+            advice.action,
+            Some(AdviceAction::BuyFuel { amount, .. }) if amount == team.fuel_capacity()
+        ));
+        assert!(fuel_advice(&team, None).unwrap().action.is_none());
+
+        let advice = training_advice(&team, &player_refs).unwrap();
+        let Some(AdviceAction::SetTrainingFocus { focus }) = advice.action else {
+            panic!("Training advice should set the focus");
+        };
+        team.training_focus = Some(focus);
+        assert!(training_advice(&team, &player_refs).is_none());
+    }
+}
@@ -0,0 +1,236 @@
+use super::{
+    constants::{MAX_MORALE, MIN_PLAYERS_PER_GAME},
+    planet::Planet,
+    player::Player,
+    resources::Resource,
+    spaceship::{SpaceshipComponent, SpaceshipUpgrade, SpaceshipUpgradeTarget},
+    team::Team,
+    traits,
+};
+use crate::{
+    game_engine::constants::MIN_TIREDNESS_FOR_ROLL_DECLINE,
+    types::{PlanetId, PlayerId, StorableResourceMap, Tick},
+};
+use rand::{seq::IteratorRandom, Rng};
+use rand_chacha::ChaCha8Rng;
+
+// A pirate this much below the crew average is replaced when possible.
+const AI_RELEASE_SKILL_GAP: f32 = 4.0;
+// Satoshi kept aside for hiring before spending on the spaceship.
+const AI_UPGRADE_BALANCE_RESERVE: u32 = 15000;
+pub const AI_UPGRADE_PROBABILITY: f64 = 0.2;
+pub const AI_TRAVEL_PROBABILITY: f64 = 0.15;
+// AI pirates have a rum when their morale drops below this value,
+// and the captain pays a bonus when the crew average does.
+const AI_MORALE_THRESHOLD: f32 = MAX_MORALE / 2.0;
+
+// Returns the weakest pirate of an AI crew if the team can afford to let them go.
+pub fn player_to_release(team: &Team, players: &[&Player]) -> Option<PlayerId> {
+    if players.len() <= MIN_PLAYERS_PER_GAME + 1 {
+        return None;
+    }
+
+    let weakest = players.iter().min_by(|a, b| {
+        a.average_skill()
+            .partial_cmp(&b.average_skill())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    })?;
+
+    if team.crew_roles.captain == Some(weakest.id)
+        || team.crew_roles.doctor == Some(weakest.id)
+        || team.crew_roles.pilot == Some(weakest.id)
+    {
+        return None;
+    }
+
+    let average = players.iter().map(|p| p.average_skill()).sum::<f32>() / players.len() as f32;
+    if team.is_overcrowded() || weakest.average_skill() < average - AI_RELEASE_SKILL_GAP {
+        return Some(weakest.id);
+    }
+
+    None
+}
+
+// Returns the cheapest spaceship upgrade the team can afford while keeping a reserve.
+pub fn spaceship_upgrade(team: &Team, current_tick: Tick) -> Option<SpaceshipUpgrade> {
+    let spaceship = &team.spaceship;
+    let mut targets = vec![];
+    if spaceship.hull.can_be_upgraded() {
+        targets.push(SpaceshipUpgradeTarget::Hull {
+            component: spaceship.hull.next(),
+        });
+    }
+    if spaceship.engine.can_be_upgraded() {
+        targets.push(SpaceshipUpgradeTarget::Engine {
+            component: spaceship.engine.next(),
+        });
+    }
+    if spaceship.storage.can_be_upgraded() {
+        targets.push(SpaceshipUpgradeTarget::Storage {
+            component: spaceship.storage.next(),
+        });
+    }
+    if spaceship.life_support.can_be_upgraded() {
+        targets.push(SpaceshipUpgradeTarget::LifeSupport {
+            component: spaceship.life_support.next(),
+        });
+    }
+
+    let satoshi_cost = |upgrade: &SpaceshipUpgrade| {
+        upgrade
+            .cost()
+            .iter()
+            .filter(|(resource, _)| *resource == Resource::SATOSHI)
+            .map(|(_, amount)| *amount)
+            .sum::<u32>()
+    };
+
+    targets
+        .into_iter()
+        .map(|target| SpaceshipUpgrade::new(target, current_tick))
+        .filter(|upgrade| {
+            team.can_set_upgrade_spaceship(upgrade.clone()).is_ok()
+                && team.balance() >= satoshi_cost(upgrade) + AI_UPGRADE_BALANCE_RESERVE
+        })
+        .min_by_key(|upgrade| satoshi_cost(upgrade))
+}
+
+// Returns the pirates of an AI crew having a rum, the most demoralized first.
+// Nobody drinks if the hangover would weaken them in the next game.
+pub fn players_to_drink(team: &Team, players: &[&Player]) -> Vec<PlayerId> {
+    let mut drinkers = players
+        .iter()
+        .filter(|player| {
+            player.morale < AI_MORALE_THRESHOLD
+                && player.tiredness + traits::drink_tiredness_malus(player)
+                    < MIN_TIREDNESS_FOR_ROLL_DECLINE
+        })
+        .collect::<Vec<_>>();
+    drinkers.sort_by(|a, b| a.morale.total_cmp(&b.morale));
+    drinkers
+        .iter()
+        .take(team.resources.value(&Resource::RUM) as usize)
+        .map(|player| player.id)
+        .collect()
+}
+
+// Returns true if the captain of an AI crew should pay a bonus to lift the morale,
+// like the own captain would. The hiring reserve is never spent on it.
+pub fn should_pay_crew_bonus(team: &Team, players: &[&Player]) -> bool {
+    if players.is_empty() || team.can_pay_crew_bonus().is_err() {
+        return false;
+    }
+
+    if team.balance() < team.crew_bonus_cost() + AI_UPGRADE_BALANCE_RESERVE {
+        return false;
+    }
+
+    let average = players.iter().map(|p| p.morale).sum::<f32>() / players.len() as f32;
+    average < AI_MORALE_THRESHOLD
+}
+
+// Picks another inhabited planet for the team to move to.
+pub fn travel_destination(
+    rng: &mut ChaCha8Rng,
+    team: &Team,
+    planets: &[&Planet],
+) -> Option<PlanetId> {
+    if !rng.gen_bool(AI_TRAVEL_PROBABILITY) {
+        return None;
+    }
+
+    let current_planet_id = team.is_on_planet()?;
+    planets
+        .iter()
+        .filter(|planet| {
+            planet.id != current_planet_id
+                && planet.peer_id.is_none()
+                && planet.total_population() > 0
+        })
+        .choose(rng)
+        .map(|planet| planet.id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{player_to_release, players_to_drink, should_pay_crew_bonus, spaceship_upgrade};
+    use crate::types::{PlanetId, PlayerId, StorableResourceMap, TeamId};
+    use crate::world::{
+        constants::{MAX_MORALE, MAX_TIREDNESS},
+        player::Player,
+        resources::Resource,
+        team::Team,
+        utils::PLANET_DATA,
+    };
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_ai_management_decisions() {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let planet_id = PlanetId::new_v4();
+        let mut team = Team::random(TeamId::new_v4(), planet_id, "Team".into(), "Ship".into());
+
+        let mut players = (0..8)
+            .map(|_| {
+                let mut player =
+                    Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 10.0);
+                player.team = Some(team.id);
+                player
+            })
+            .collect::<Vec<Player>>();
+        team.player_ids = players.iter().map(|p| p.id).collect();
+
+        let weakest_id = players[0].id;
+        for idx in 0..20 {
+            players[0].modify_skill(idx, -20.0);
+        }
+        let player_refs = players.iter().collect::<Vec<&Player>>();
+        assert_eq!(player_to_release(&team, &player_refs), Some(weakest_id));
+
+        // Small crews are kept.
+        assert_eq!(player_to_release(&team, &player_refs[..6]), None);
+
+        // No upgrade without money.
+        team.resources = Default::default();
+        assert!(spaceship_upgrade(&team, 0).is_none());
+
+        team.resources
+            .saturating_add(Resource::SATOSHI, 10_000_000, u32::MAX);
+        team.resources
+            .saturating_add(Resource::SCRAPS, 10_000, u32::MAX);
+        team.resources
+            .saturating_add(Resource::GOLD, 1000, u32::MAX);
+        assert!(spaceship_upgrade(&team, 0).is_some());
+
+        // Only demoralized and rested pirates drink, as long as there is rum.
+        for player in players.iter_mut() {
+            player.morale = MAX_MORALE;
+            player.tiredness = 0.0;
+        }
+        players[1].morale = 1.0;
+        players[2].morale = 2.0;
+        players[3].morale = 0.0;
+        players[3].tiredness = MAX_TIREDNESS;
+        let player_refs = players.iter().collect::<Vec<&Player>>();
+        assert!(players_to_drink(&team, &player_refs).is_empty());
+        team.resources.saturating_add(Resource::RUM, 1, u32::MAX);
+        assert_eq!(players_to_drink(&team, &player_refs), vec![players[1].id]);
+        team.resources.saturating_add(Resource::RUM, 5, u32::MAX);
+        assert_eq!(
+            players_to_drink(&team, &player_refs),
+            vec![players[1].id, players[2].id]
+        );
+
+        // The captain pays a bonus once the whole crew is down.
+        team.crew_roles.captain = Some(players[4].id);
+        assert!(!should_pay_crew_bonus(&team, &player_refs));
+        for player in players.iter_mut() {
+            player.morale = 0.0;
+        }
+        let player_refs = players.iter().collect::<Vec<&Player>>();
+        assert!(should_pay_crew_bonus(&team, &player_refs));
+        team.crew_roles.captain = None;
+        assert!(!should_pay_crew_bonus(&team, &player_refs));
+    }
+}
@@ -0,0 +1,96 @@
+use crate::types::{SystemTimeTick, Tick};
+use chrono::{DateTime, Local};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CalendarEventKind {
+    Game,
+    Challenge,
+    Arrival,
+    Exploration,
+    SpaceshipUpgrade,
+    StrikeEnd,
+    FreePiratesRefresh,
+}
+
+impl Display for CalendarEventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Game => write!(f, "Game"),
+            Self::Challenge => write!(f, "Challenge"),
+            Self::Arrival => write!(f, "Arrival"),
+            Self::Exploration => write!(f, "Exploration"),
+            Self::SpaceshipUpgrade => write!(f, "Shipyard"),
+            Self::StrikeEnd => write!(f, "Crew"),
+            Self::FreePiratesRefresh => write!(f, "Market"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarEvent {
+    pub tick: Tick,
+    pub kind: CalendarEventKind,
+    pub description: String,
+}
+
+impl CalendarEvent {
+    pub fn new(tick: Tick, kind: CalendarEventKind, description: String) -> Self {
+        Self {
+            tick,
+            kind,
+            description,
+        }
+    }
+
+    pub fn countdown(&self, now: Tick) -> String {
+        if self.tick > now {
+            format!("in {}", (self.tick - now).formatted())
+        } else {
+            "now".to_string()
+        }
+    }
+}
+
+/// Keeps only the events that have not happened yet, soonest first.
+pub fn upcoming(mut events: Vec<CalendarEvent>, now: Tick) -> Vec<CalendarEvent> {
+    events.retain(|event| event.tick >= now);
+    events.sort_by_key(|event| event.tick);
+    events
+}
+
+// Ticks are formatted in the local time zone, so we show which one it is.
+pub fn local_timezone() -> String {
+    let now: DateTime<Local> = Local::now();
+    format!("UTC{}", now.format("%:z"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{upcoming, CalendarEvent, CalendarEventKind};
+    use crate::world::constants::{HOURS, MINUTES};
+
+    #[test]
+    fn test_upcoming_events() {
+        let now = 10 * HOURS;
+        let events = vec![
+            CalendarEvent::new(
+                now + 2 * HOURS,
+                CalendarEventKind::Arrival,
+                "Arrival".to_string(),
+            ),
+            CalendarEvent::new(now - MINUTES, CalendarEventKind::Game, "Past".to_string()),
+            CalendarEvent::new(
+                now + 5 * MINUTES,
+                CalendarEventKind::Game,
+                "Game".to_string(),
+            ),
+        ];
+
+        let events = upcoming(events, now);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].kind, CalendarEventKind::Game);
+        assert_eq!(events[1].kind, CalendarEventKind::Arrival);
+        assert_eq!(events[0].countdown(now), "in 00:05:00");
+    }
+}
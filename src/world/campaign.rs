@@ -0,0 +1,190 @@
+use super::{resources::Resource, team::Team};
+use crate::types::{TeamId, Tick};
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::fmt::Display;
+use strum_macros::EnumIter;
+
+pub const CONTENDERS_WINS: u32 = 5;
+pub const RISING_STARS_REPUTATION: f32 = 10.0;
+
+#[derive(
+    Debug, Clone, Copy, Default, Serialize_repr, Deserialize_repr, PartialEq, Eq, Hash, EnumIter,
+)]
+#[repr(u8)]
+pub enum CampaignChapter {
+    #[default]
+    Scrapyard,
+    FirstFlight,
+    Contenders,
+    RisingStars,
+    BeltRivals,
+    GalacticFinals,
+    Champions,
+}
+
+impl Display for CampaignChapter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Scrapyard => write!(f, "Scrapyard dreams"),
+            Self::FirstFlight => write!(f, "First flight"),
+            Self::Contenders => write!(f, "Contenders"),
+            Self::RisingStars => write!(f, "Rising stars"),
+            Self::BeltRivals => write!(f, "Rivals of the belt"),
+            Self::GalacticFinals => write!(f, "Galactic finals"),
+            Self::Champions => write!(f, "Galactic champions"),
+        }
+    }
+}
+
+impl CampaignChapter {
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Scrapyard => Self::FirstFlight,
+            Self::FirstFlight => Self::Contenders,
+            Self::Contenders => Self::RisingStars,
+            Self::RisingStars => Self::BeltRivals,
+            Self::BeltRivals => Self::GalacticFinals,
+            Self::GalacticFinals => Self::Champions,
+            Self::Champions => Self::Champions,
+        }
+    }
+
+    pub fn is_final(&self) -> bool {
+        *self == Self::Champions
+    }
+
+    pub fn story(&self) -> &'static str {
+        match self {
+            Self::Scrapyard => "Your crew is little more than a bunch of misfits patching up a wreck in a scrapyard. Nobody in the galaxy knows your name. Time to change that.",
+            Self::FirstFlight => "A first victory! The old engine coughs, but it holds. The scrapyard has become too small for your ambitions: take the ship out among the stars.",
+            Self::Contenders => "Word travels fast between the planets. Crews start whispering about the upstarts from the scrapyard. Prove it was not just luck.",
+            Self::RisingStars => "The wins are piling up and the crowds are growing. Reputation opens doors that satoshis alone cannot.",
+            Self::BeltRivals => "The Belt Marauders have heard of you, and they do not like sharing the spotlight. They are waiting on their planet: go and teach them a lesson.",
+            Self::GalacticFinals => "Only one crew stands between you and glory: the Crimson Armada, undefeated champions of the galaxy. Face them and claim the title.",
+            Self::Champions => "From a rusty scrapyard to the top of the galaxy. Songs about your crew will be sung in every spaceport for generations.",
+        }
+    }
+
+    pub fn objective(&self) -> String {
+        match self {
+            Self::Scrapyard => "Win your first game".to_string(),
+            Self::FirstFlight => "Land on a planet other than your home".to_string(),
+            Self::Contenders => format!("Win {} games", CONTENDERS_WINS),
+            Self::RisingStars => format!("Reach reputation {}", RISING_STARS_REPUTATION),
+            Self::BeltRivals => "Defeat the Belt Marauders".to_string(),
+            Self::GalacticFinals => "Defeat the Crimson Armada".to_string(),
+            Self::Champions => "Campaign completed".to_string(),
+        }
+    }
+
+    // Unique opponent generated when the chapter starts: team name, ship name and base level.
+    pub fn rival(&self) -> Option<(&'static str, &'static str, f32)> {
+        match self {
+            Self::BeltRivals => Some(("Belt Marauders", "Rusty Fang", 10.0)),
+            Self::GalacticFinals => Some(("Crimson Armada", "Red Sovereign", 14.0)),
+            _ => None,
+        }
+    }
+
+    pub fn rewards(&self) -> Vec<(Resource, u32)> {
+        match self {
+            Self::Scrapyard => vec![(Resource::SATOSHI, 10_000), (Resource::RUM, 10)],
+            Self::FirstFlight => vec![(Resource::FUEL, 100)],
+            Self::Contenders => vec![(Resource::SATOSHI, 30_000)],
+            Self::RisingStars => vec![(Resource::GOLD, 5), (Resource::SCRAPS, 50)],
+            Self::BeltRivals => vec![(Resource::SATOSHI, 60_000), (Resource::GOLD, 10)],
+            Self::GalacticFinals => vec![(Resource::SATOSHI, 150_000), (Resource::GOLD, 25)],
+            Self::Champions => vec![],
+        }
+    }
+
+    pub fn rewards_description(&self) -> String {
+        self.rewards()
+            .iter()
+            .map(|(resource, amount)| format!("{} {}", amount, resource))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Campaign {
+    pub chapter: CampaignChapter,
+    pub rival_id: Option<TeamId>,
+    // Teams defeated by the own team since the campaign started.
+    pub defeated_team_ids: Vec<TeamId>,
+    pub completed_chapters: Vec<(CampaignChapter, Tick)>,
+}
+
+impl Campaign {
+    pub fn is_completed(&self) -> bool {
+        self.chapter.is_final()
+    }
+
+    pub fn is_chapter_completed(&self, team: &Team) -> bool {
+        let wins = team.game_record[0] + team.network_game_record[0];
+        match self.chapter {
+            CampaignChapter::Scrapyard => wins >= 1,
+            CampaignChapter::FirstFlight => match team.is_on_planet() {
+                Some(planet_id) => planet_id != team.home_planet_id,
+                None => false,
+            },
+            CampaignChapter::Contenders => wins >= CONTENDERS_WINS,
+            CampaignChapter::RisingStars => team.reputation >= RISING_STARS_REPUTATION,
+            CampaignChapter::BeltRivals | CampaignChapter::GalacticFinals => match self.rival_id {
+                Some(rival_id) => self.defeated_team_ids.contains(&rival_id),
+                None => false,
+            },
+            CampaignChapter::Champions => false,
+        }
+    }
+
+    pub fn complete_chapter(&mut self, tick: Tick) {
+        self.completed_chapters.push((self.chapter, tick));
+        self.chapter = self.chapter.next();
+        self.rival_id = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Campaign, CampaignChapter};
+    use crate::types::{PlanetId, TeamId};
+    use crate::world::{team::Team, types::TeamLocation};
+
+    #[test]
+    fn test_campaign_milestones() {
+        let mut team = Team::default();
+        team.home_planet_id = PlanetId::new_v4();
+        team.current_location = TeamLocation::OnPlanet {
+            planet_id: team.home_planet_id,
+        };
+        let mut campaign = Campaign::default();
+        assert!(!campaign.is_chapter_completed(&team));
+
+        team.game_record = [1, 0, 0];
+        assert!(campaign.is_chapter_completed(&team));
+        campaign.complete_chapter(0);
+        assert_eq!(campaign.chapter, CampaignChapter::FirstFlight);
+        assert!(!campaign.is_chapter_completed(&team));
+
+        team.current_location = TeamLocation::OnPlanet {
+            planet_id: PlanetId::new_v4(),
+        };
+        assert!(campaign.is_chapter_completed(&team));
+
+        // Rival chapters are completed only by beating the rival team.
+        campaign.chapter = CampaignChapter::BeltRivals;
+        let rival_id = TeamId::new_v4();
+        campaign.rival_id = Some(rival_id);
+        campaign.defeated_team_ids.push(TeamId::new_v4());
+        assert!(!campaign.is_chapter_completed(&team));
+        campaign.defeated_team_ids.push(rival_id);
+        assert!(campaign.is_chapter_completed(&team));
+
+        campaign.chapter = CampaignChapter::Champions;
+        assert!(campaign.is_completed());
+        assert!(!campaign.is_chapter_completed(&team));
+    }
+}
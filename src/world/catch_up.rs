@@ -0,0 +1,154 @@
+use super::{
+    constants::{DAYS, HOURS},
+    world::World,
+};
+use crate::types::{AppResult, PlayerId, SystemTimeTick, Tick};
+use crate::ui::utils::format_satoshi;
+
+// Time away is only simulated up to this point, the rest is skipped.
+pub const MAX_CATCH_UP_TIME: Tick = 7 * DAYS;
+// World time simulated between two progress updates.
+pub const CATCH_UP_CHUNK: Tick = 1 * HOURS;
+
+/// Progress of the simulation of the time passed since the world was saved,
+/// together with the state of the own team before it started.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CatchUp {
+    pub from: Tick,
+    pub to: Tick,
+    pub skipped: Tick,
+    game_record: [u32; 3],
+    balance: u32,
+    reputation: f32,
+    player_ids: Vec<PlayerId>,
+}
+
+impl CatchUp {
+    pub fn new(world: &World, from: Tick, to: Tick, skipped: Tick) -> AppResult<Self> {
+        let own_team = world.get_own_team()?;
+        Ok(Self {
+            from,
+            to,
+            skipped,
+            game_record: own_team.game_record,
+            balance: own_team.balance(),
+            reputation: own_team.reputation,
+            player_ids: own_team.player_ids.clone(),
+        })
+    }
+
+    pub fn progress(&self, current: Tick) -> f32 {
+        if self.to <= self.from {
+            return 1.0;
+        }
+        (current.saturating_sub(self.from) as f32 / (self.to - self.from) as f32).min(1.0)
+    }
+
+    // What happened to the own team so far during the simulation.
+    pub fn notable_events(&self, world: &World) -> AppResult<Vec<String>> {
+        let own_team = world.get_own_team()?;
+        let mut events = vec![];
+
+        let [wins, losses, draws] =
+            [0, 1, 2].map(|idx| own_team.game_record[idx].saturating_sub(self.game_record[idx]));
+        let games = wins + losses + draws;
+        if games > 0 {
+            events.push(format!(
+                "You missed {} game{} ({}W {}L {}D)",
+                games,
+                if games > 1 { "s" } else { "" },
+                wins,
+                losses,
+                draws
+            ));
+        }
+
+        let left = self
+            .player_ids
+            .iter()
+            .filter(|id| !own_team.player_ids.contains(id))
+            .count();
+        if left > 0 {
+            events.push(format!(
+                "{} pirate{} left the crew",
+                left,
+                if left > 1 { "s" } else { "" }
+            ));
+        }
+
+        let balance = own_team.balance() as i64 - self.balance as i64;
+        if balance != 0 {
+            events.push(format!(
+                "Treasury {}{}",
+                if balance > 0 { "+" } else { "-" },
+                format_satoshi(balance.unsigned_abs() as u32)
+            ));
+        }
+
+        let reputation = own_team.reputation - self.reputation;
+        if reputation.abs() >= 0.1 {
+            events.push(format!("Reputation {:+.1}", reputation));
+        }
+
+        Ok(events)
+    }
+
+    pub fn summary(&self, world: &World) -> AppResult<String> {
+        let mut summary = format!(
+            "While you were away ({})",
+            (self.to - self.from + self.skipped).formatted()
+        );
+        let events = self.notable_events(world)?;
+        if events.is_empty() {
+            summary.push_str("\nnothing much happened.");
+        } else {
+            summary.push(':');
+            for event in events {
+                summary.push_str(&format!("\n{}", event));
+            }
+        }
+        if self.skipped > 0 {
+            summary.push_str(&format!(
+                "\n\nOnly the last {} days were simulated.",
+                MAX_CATCH_UP_TIME.as_days()
+            ));
+        }
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CatchUp, MAX_CATCH_UP_TIME};
+    use crate::types::AppResult;
+    use crate::world::{constants::DAYS, world::World};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_catch_up_events() -> AppResult<()> {
+        let mut world = World::new(None);
+        world.initialize(false)?;
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let home_planet_id = *world.planets.keys().next().unwrap();
+        world.own_team_id =
+            world.generate_random_team(rng, home_planet_id, "own".into(), "ship".into())?;
+
+        let catch_up = CatchUp::new(&world, 0, 2 * DAYS, MAX_CATCH_UP_TIME)?;
+        assert_eq!(catch_up.progress(DAYS), 0.5);
+        assert_eq!(catch_up.progress(3 * DAYS), 1.0);
+        assert!(catch_up.notable_events(&world)?.is_empty());
+
+        let mut own_team = world.get_own_team()?.clone();
+        own_team.game_record[0] += 2;
+        own_team.game_record[1] += 1;
+        world.teams.insert(own_team.id, own_team);
+        assert_eq!(
+            catch_up.notable_events(&world)?,
+            vec!["You missed 3 games (2W 1L 0D)".to_string()]
+        );
+        assert!(catch_up.summary(&world)?.contains("Only the last"));
+
+        Ok(())
+    }
+}
@@ -32,6 +32,11 @@ pub const MAX_PLAYERS_PER_TEAM: usize = MIN_PLAYERS_PER_GAME + 5;
 // More realistically, one should take a factor 10 less.
 pub const EXPERIENCE_PER_SKILL_MULTIPLIER: f32 = 0.0000035;
 pub const MAX_SKILL_INCREASE_PER_LONG_TICK: f32 = 0.75;
+// Experience gained by the skills involved in each game action (shots, rebounds, steals...),
+// in the same units as the experience at position.
+pub const EXPERIENCE_PER_GAME_ACTION: f32 = 500.0;
+// Skill experience gained by the pilot per unit of distance travelled in a space adventure.
+pub const SPACE_ADVENTURE_EXPERIENCE_PER_DISTANCE: f32 = 0.0002;
 pub const SKILL_DECREMENT_PER_LONG_TICK: f32 = -0.04;
 
 pub const REPUTATION_PER_EXPERIENCE: f32 = 0.0001;
@@ -62,6 +67,17 @@ pub const BASE_SPEED: f32 = 0.55 * LIGHT_SPEED; // Very fast ;)
 pub const BASE_FUEL_CONSUMPTION: f32 = 2.5 / HOURS as f32; // TONNES per HOURS
 pub const FUEL_CONSUMPTION_PER_UNIT_STORAGE: f32 = 1.0 / 3_000.0; // 3_000 storage units double the fuel consumption
 pub const SPEED_PENALTY_PER_UNIT_STORAGE: f32 = 1.0 / 5_000.0; // 5_000 storage units halves the speed
+pub const MIN_ENGINE_TUNING: i8 = -2;
+pub const MAX_ENGINE_TUNING: i8 = 2;
+// Each tuning step changes the speed by 10% and the consumption by 25%,
+// so that economy mode reaches further while overdrive arrives sooner.
+pub const ENGINE_TUNING_SPEED_STEP: f32 = 0.1;
+pub const ENGINE_TUNING_CONSUMPTION_STEP: f32 = 0.25;
+pub const ENGINE_BREAKDOWN_DURABILITY_MALUS: u32 = 5;
+// Life support fails when the spaceship durability drops below this fraction,
+// losing its crew capacity bonus until the spaceship is repaired.
+pub const LIFE_SUPPORT_FAILURE_DURABILITY_FRACTION: f32 = 0.25;
+pub const BASE_OXYGEN_AUTONOMY: Tick = 90 * SECONDS;
 
 pub const LANDING_TIME_OVERHEAD: Tick = 10 * MINUTES;
 
@@ -69,11 +85,27 @@ pub const REPUTATION_BONUS_WINNER: f32 = 0.5;
 pub const REPUTATION_BONUS_LOSER: f32 = -0.2;
 pub const REPUTATION_BONUS_DRAW: f32 = 0.25;
 pub const TEAM_REPUTATION_BONUS_MODIFIER: f32 = 0.000002;
+pub const GOSSIP_NEIGHBOUR_MODIFIER: f32 = 0.5;
+pub const GOSSIP_GLOBAL_MODIFIER: f32 = 0.1;
+pub const GOSSIP_RECOVERY_PER_LONG_TICK: f32 = 0.25;
+pub const GOSSIP_STRIKES_FOR_DECLINES: u32 = 3;
+// Crews refuse to deal with a team once their standing drops this low.
+pub const TEAM_GRUDGE_STANDING: f32 = -2.0;
+// Standing with a planet faction, earned through games, trades and contracts.
+pub const MAX_PLANET_STANDING: f32 = MAX_SKILL / 4.0;
+pub const PLANET_STANDING_WIN_BONUS: f32 = 0.5;
+pub const PLANET_STANDING_TRADE_BONUS: f32 = 0.05;
+pub const PLANET_STANDING_CONTRACT_BONUS: f32 = 0.75;
+pub const BASE_DOCKING_FEE: u32 = 250;
 
 pub const QUICK_EXPLORATION_TIME: Tick = 1 * HOURS;
 pub const LONG_EXPLORATION_TIME: Tick = 8 * HOURS;
 pub const ASTEROID_DISCOVERY_PROBABILITY: f64 = 0.15;
 pub const PORTAL_DISCOVERY_PROBABILITY: f64 = 0.05;
+// Probability per hour of exploration to hear a rumor about the Kartoffel mothership.
+pub const BOSS_RUMOR_DISCOVERY_PROBABILITY: f64 = 0.2;
+pub const BOSS_RUMORS_FOR_ENCOUNTER: u32 = 3;
+pub const LEGENDARY_PIRATE_BASE_LEVEL: f32 = 12.0;
 
 pub const MAX_NUM_ASTEROID_PER_TEAM: usize = 5;
 
@@ -124,6 +156,7 @@ impl MoraleModifier {
 pub const MAX_TIREDNESS: f32 = MAX_SKILL;
 pub const MAX_MORALE: f32 = MAX_SKILL;
 pub const MORALE_DECREASE_PER_LONG_TICK: f32 = MoraleModifier::MEDIUM_MALUS;
+pub const MORALE_OVERCROWDING_MALUS: f32 = MoraleModifier::HIGH_MALUS;
 pub const MORALE_INCREASE_PER_GAME: f32 = MoraleModifier::SEVERE_BONUS;
 pub const MORALE_RELEASE_MALUS: f32 = MoraleModifier::MEDIUM_MALUS;
 pub const MORALE_THRESHOLD_FOR_LEAVING: f32 = 2.0;
@@ -136,5 +169,44 @@ pub const TIREDNESS_DRINK_MALUS: f32 = TirednessCost::SEVERE;
 pub const TIREDNESS_DRINK_MALUS_SPUGNA: f32 = TirednessCost::HIGH;
 pub const TRAIT_PROBABILITY: f64 = 0.25;
 
+// A crew whose average morale drops below MORALE_THRESHOLD_FOR_MUTINY may mutiny.
+// The probability is checked once per MEDIUM tick, so at zero morale a mutiny
+// breaks out on average every 100 minutes.
+pub const MORALE_THRESHOLD_FOR_MUTINY: f32 = 5.0;
+pub const MUTINY_PROBABILITY_MORALE_MODIFIER: f64 = 0.01;
+pub const MUTINY_STRIKE_DURATION: Tick = 6 * HOURS;
+pub const MUTINY_THEFT_FRACTION: f32 = 0.2;
+pub const CREW_BONUS_COST_PER_PLAYER: u32 = 2_000;
+// Fee to recolor the jersey and spaceship after team creation.
+pub const APPEARANCE_CHANGE_COST: u32 = 1_000;
+pub const EMBLEM_CHANGE_COST: u32 = 500;
+pub const RENAME_TEAM_COST: u32 = 5_000;
+pub const RENAME_SPACESHIP_COST: u32 = 2_000;
+pub const NICKNAME_COST: u32 = 500;
+pub const MORALE_CREW_BONUS: f32 = MoraleModifier::HIGH_BONUS;
+pub const MORALE_SHARE_RUM_BONUS: f32 = MoraleModifier::MEDIUM_BONUS;
+pub const TIREDNESS_SHARE_RUM_MALUS: f32 = TirednessCost::HIGH;
+pub const MAX_CREW_LOG_ENTRIES: usize = 100;
+pub const MAX_QUEUED_ACTIONS: usize = 10;
+// Spaceships owned by a team, including the one it is flying.
+pub const MAX_FLEET_SIZE: usize = 3;
+pub const QUEUED_BUY_AMOUNT: u32 = 50;
+// Auto-refuel target, as a percentage of the fuel tank.
+pub const AUTO_REFUEL_TARGET_STEP: u8 = 10;
+pub const MAX_AUTO_REFUEL_TARGET: u8 = 100;
+pub const MAX_FINANCE_ENTRIES: usize = 1_000;
+
+// AI teams on the same planet may challenge the own team, at most once per cooldown.
+// The probability is checked once per MEDIUM tick. Teams with a much higher
+// reputation do not bother challenging the own team.
+pub const NPC_CHALLENGE_PROBABILITY: f64 = 0.02;
+pub const NPC_CHALLENGE_COOLDOWN: Tick = 2 * HOURS;
+pub const NPC_CHALLENGE_REPUTATION_RANGE: f32 = 10.0;
+
+// Probability for a player involved in an action to get injured.
+// It grows with tiredness and shrinks with strength and stamina.
+pub const INJURY_BASE_PROBABILITY: f64 = 0.0001;
+pub const INJURY_TIREDNESS_MODIFIER: f64 = 4.0;
+
 pub const MIN_RELATIVE_RETIREMENT_AGE: f32 = 0.96;
 pub const PEAK_PERFORMANCE_RELATIVE_AGE: f32 = 0.65;
@@ -0,0 +1,167 @@
+use super::{constants::DAYS, resources::Resource, team::Team};
+use crate::types::{AppResult, ContractId, PlanetId, StorableResourceMap, Tick};
+use anyhow::anyhow;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+pub const MAX_CONTRACT_OFFERS_PER_PLANET: usize = 2;
+pub const MAX_ACCEPTED_CONTRACTS: usize = 3;
+pub const CONTRACT_OFFER_DURATION: Tick = 1 * DAYS;
+pub const CONTRACT_FAILURE_REPUTATION_MALUS: f32 = 1.0;
+const CONTRACT_RESOURCES: [Resource; 3] = [Resource::SCRAPS, Resource::RUM, Resource::GOLD];
+
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq)]
+#[repr(u8)]
+pub enum ContractStatus {
+    Offered,
+    Accepted,
+}
+
+/// Delivery mission offered by a planet: bring some resources to another planet
+/// before the deadline to get a reward in satoshi and reputation.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Contract {
+    pub id: ContractId,
+    pub origin_id: PlanetId,
+    pub destination_id: PlanetId,
+    pub resource: Resource,
+    pub amount: u32,
+    pub reward: u32,
+    pub reputation_bonus: f32,
+    pub status: ContractStatus,
+    // Offers are withdrawn at the deadline, accepted contracts fail after it.
+    pub deadline: Tick,
+    pub delivery_time: Tick,
+}
+
+impl Contract {
+    pub fn random(
+        rng: &mut ChaCha8Rng,
+        origin_id: PlanetId,
+        destination_id: PlanetId,
+        current_tick: Tick,
+    ) -> Self {
+        let resource = CONTRACT_RESOURCES[rng.gen_range(0..CONTRACT_RESOURCES.len())];
+        let amount = match resource {
+            Resource::SCRAPS => rng.gen_range(4..=12) * 10,
+            Resource::RUM => rng.gen_range(2..=8) * 5,
+            _ => rng.gen_range(2..=8),
+        };
+        let reward =
+            (resource.base_price() * amount as f32 * rng.gen_range(1.3..1.8)).round() as u32;
+
+        Self {
+            id: ContractId::new_v4(),
+            origin_id,
+            destination_id,
+            resource,
+            amount,
+            reward,
+            reputation_bonus: rng.gen_range(0.5..2.0),
+            status: ContractStatus::Offered,
+            deadline: current_tick + CONTRACT_OFFER_DURATION,
+            delivery_time: rng.gen_range(1..=3) * DAYS,
+        }
+    }
+
+    pub fn is_expired(&self, current_tick: Tick) -> bool {
+        current_tick > self.deadline
+    }
+
+    pub fn accept(&mut self, current_tick: Tick) {
+        self.status = ContractStatus::Accepted;
+        self.deadline = current_tick + self.delivery_time;
+    }
+
+    pub fn can_be_accepted_by(
+        &self,
+        team: &Team,
+        accepted_contracts: usize,
+        current_tick: Tick,
+    ) -> AppResult<()> {
+        if self.status != ContractStatus::Offered || self.is_expired(current_tick) {
+            return Err(anyhow!("Contract is not available"));
+        }
+
+        if team.is_on_planet() != Some(self.origin_id) {
+            return Err(anyhow!("Can only accept contracts on the offering planet"));
+        }
+
+        if accepted_contracts >= MAX_ACCEPTED_CONTRACTS {
+            return Err(anyhow!("Too many contracts accepted"));
+        }
+
+        Ok(())
+    }
+
+    pub fn can_be_delivered_by(&self, team: &Team, current_tick: Tick) -> AppResult<()> {
+        if self.status != ContractStatus::Accepted {
+            return Err(anyhow!("Contract has not been accepted"));
+        }
+
+        if self.is_expired(current_tick) {
+            return Err(anyhow!("Contract deadline has passed"));
+        }
+
+        if team.is_on_planet() != Some(self.destination_id) {
+            return Err(anyhow!("Can only deliver on the destination planet"));
+        }
+
+        if team.resources.value(&self.resource) < self.amount {
+            return Err(anyhow!("Insufficient {}", self.resource));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Contract, ContractStatus};
+    use crate::types::{PlanetId, ResourceMap, StorableResourceMap};
+    use crate::world::{team::Team, types::TeamLocation};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_contract_lifecycle() {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let origin_id = PlanetId::new_v4();
+        let destination_id = PlanetId::new_v4();
+        let mut contract = Contract::random(rng, origin_id, destination_id, 0);
+        assert_eq!(contract.status, ContractStatus::Offered);
+
+        let mut team = Team::default();
+        team.current_location = TeamLocation::OnPlanet {
+            planet_id: destination_id,
+        };
+        assert!(contract.can_be_accepted_by(&team, 0, 0).is_err());
+
+        team.current_location = TeamLocation::OnPlanet {
+            planet_id: origin_id,
+        };
+        assert!(contract.can_be_accepted_by(&team, 0, 0).is_ok());
+        assert!(contract
+            .can_be_accepted_by(&team, 0, contract.deadline + 1)
+            .is_err());
+
+        contract.accept(10);
+        assert_eq!(contract.deadline, 10 + contract.delivery_time);
+        assert!(contract.can_be_accepted_by(&team, 0, 10).is_err());
+
+        team.current_location = TeamLocation::OnPlanet {
+            planet_id: destination_id,
+        };
+        assert!(contract.can_be_delivered_by(&team, 10).is_err());
+
+        let mut resources = ResourceMap::new();
+        resources.saturating_add(contract.resource, contract.amount, u32::MAX);
+        team.resources = resources;
+        assert!(contract.can_be_delivered_by(&team, 10).is_ok());
+        assert!(contract
+            .can_be_delivered_by(&team, contract.deadline + 1)
+            .is_err());
+    }
+}
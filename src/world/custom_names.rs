@@ -0,0 +1,111 @@
+use itertools::Itertools;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+pub const MIN_CUSTOM_NAME_LENGTH: usize = 2;
+pub const MAX_CUSTOM_NAME_LENGTH: usize = 16;
+
+/// Name pools dropped by the user in the store directory.
+/// Every non-empty pool overrides the corresponding embedded asset data,
+/// empty pools fall back to the built-in names.
+#[derive(Debug, Default, Clone, Deserialize, PartialEq)]
+pub struct CustomNames {
+    #[serde(default)]
+    pub first_names_he: Vec<String>,
+    #[serde(default)]
+    pub first_names_she: Vec<String>,
+    #[serde(default)]
+    pub last_names: Vec<String>,
+    // Pairs of (team name, spaceship name), as in the teams data asset.
+    #[serde(default)]
+    pub team_names: Vec<(String, String)>,
+    // Built-in planet name to custom planet name.
+    #[serde(default)]
+    pub planet_names: HashMap<String, String>,
+}
+
+impl CustomNames {
+    pub fn is_empty(&self) -> bool {
+        self.first_names_he.is_empty()
+            && self.first_names_she.is_empty()
+            && self.last_names.is_empty()
+            && self.team_names.is_empty()
+            && self.planet_names.is_empty()
+    }
+
+    // Drops invalid and duplicated entries, logging what was discarded.
+    pub fn validated(self) -> Self {
+        let team_names = self
+            .team_names
+            .into_iter()
+            .filter_map(|(team_name, ship_name)| {
+                Some((validate_name(&team_name)?, validate_name(&ship_name)?))
+            })
+            .unique_by(|(team_name, _)| team_name.clone())
+            .collect();
+
+        let planet_names = self
+            .planet_names
+            .into_iter()
+            .filter_map(|(planet, name)| Some((planet, validate_name(&name)?)))
+            .collect();
+
+        Self {
+            first_names_he: validate_names(self.first_names_he),
+            first_names_she: validate_names(self.first_names_she),
+            last_names: validate_names(self.last_names),
+            team_names,
+            planet_names,
+        }
+    }
+}
+
+fn validate_name(name: &str) -> Option<String> {
+    let name = name.trim();
+    let length = name.chars().count();
+    if length < MIN_CUSTOM_NAME_LENGTH
+        || length > MAX_CUSTOM_NAME_LENGTH
+        || name.chars().any(|c| c.is_control())
+    {
+        log::warn!("Discarding invalid custom name {:?}", name);
+        return None;
+    }
+    Some(name.to_string())
+}
+
+fn validate_names(names: Vec<String>) -> Vec<String> {
+    names
+        .iter()
+        .filter_map(|name| validate_name(name))
+        .unique()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CustomNames;
+    use crate::types::AppResult;
+
+    #[test]
+    fn test_custom_names_validation() -> AppResult<()> {
+        let data = r#"{
+            "first_names_he": ["Luca", " Luca ", "", "X", "Bartholomew Maximilian"],
+            "team_names": [["Rebels", "Falcon"], ["Rebels", "Eagle"], ["Ok", "\n"]],
+            "planet_names": {"Sagittarius": "Arrow", "Earth": "A name way too long to fit"}
+        }"#;
+        let names = serde_json::from_str::<CustomNames>(data)?.validated();
+
+        assert_eq!(names.first_names_he, vec!["Luca".to_string()]);
+        assert!(names.first_names_she.is_empty());
+        assert_eq!(
+            names.team_names,
+            vec![("Rebels".to_string(), "Falcon".to_string())]
+        );
+        assert_eq!(names.planet_names.len(), 1);
+        assert_eq!(names.planet_names["Sagittarius"], "Arrow");
+        assert!(!names.is_empty());
+
+        assert!(CustomNames::default().validated().is_empty());
+        Ok(())
+    }
+}
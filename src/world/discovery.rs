@@ -0,0 +1,89 @@
+use super::planet::Planet;
+use crate::types::{PlanetId, PlanetMap};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Planets charted by a team. The others are shown as unknown in the galaxy.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Discovery {
+    pub planet_ids: HashSet<PlanetId>,
+}
+
+impl Discovery {
+    pub fn is_discovered(&self, planet_id: PlanetId) -> bool {
+        self.planet_ids.contains(&planet_id)
+    }
+
+    // Charts the planets, returning the ones which were not known yet.
+    pub fn discover(&mut self, planet_ids: Vec<PlanetId>) -> Vec<PlanetId> {
+        planet_ids
+            .into_iter()
+            .filter(|&planet_id| self.planet_ids.insert(planet_id))
+            .collect()
+    }
+}
+
+// Landing on a planet charts it, together with the planets it orbits and its satellites.
+pub fn surroundings(planets: &PlanetMap, planet: &Planet) -> Vec<PlanetId> {
+    let mut planet_ids = vec![planet.id];
+    planet_ids.extend(planet.satellites.iter());
+    let mut parent_id = planet.satellite_of;
+    while let Some(id) = parent_id {
+        planet_ids.push(id);
+        parent_id = planets.get(&id).and_then(|parent| parent.satellite_of);
+    }
+    planet_ids
+}
+
+// Exploring around a planet charts the whole system it belongs to,
+// that is the satellites of all the planets it orbits, except for the galaxy itself.
+pub fn system(planets: &PlanetMap, planet: &Planet) -> Vec<PlanetId> {
+    let mut planet_ids = surroundings(planets, planet);
+    let mut parent_id = planet.satellite_of;
+    while let Some(parent) = parent_id.and_then(|id| planets.get(&id)) {
+        if parent.satellite_of.is_none() {
+            break;
+        }
+        planet_ids.extend(parent.satellites.iter());
+        parent_id = parent.satellite_of;
+    }
+    planet_ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{surroundings, system, Discovery};
+    use crate::types::PlanetMap;
+    use crate::world::planet::Planet;
+
+    #[test]
+    fn test_discovery() {
+        let mut galaxy = Planet::default();
+        let mut star = Planet::asteroid("Star".into(), "star".into(), galaxy.id);
+        let other_star = Planet::asteroid("Other star".into(), "other".into(), galaxy.id);
+        let mut planet = Planet::asteroid("Planet".into(), "planet".into(), star.id);
+        let sibling = Planet::asteroid("Sibling".into(), "sibling".into(), star.id);
+        let moon = Planet::asteroid("Moon".into(), "moon".into(), planet.id);
+        galaxy.satellites = vec![star.id, other_star.id];
+        star.satellites = vec![planet.id, sibling.id];
+        planet.satellites = vec![moon.id];
+        let mut planets = PlanetMap::new();
+        for p in [&galaxy, &star, &other_star, &planet, &sibling, &moon] {
+            planets.insert(p.id, p.clone());
+        }
+
+        let mut discovery = Discovery::default();
+        let discovered = discovery.discover(surroundings(&planets, &planet));
+        assert_eq!(discovered.len(), 4);
+        assert!(discovery.is_discovered(moon.id));
+        assert!(discovery.is_discovered(star.id));
+        assert!(!discovery.is_discovered(sibling.id));
+
+        // Only the sibling is new when charting the whole system, other systems stay unknown.
+        assert_eq!(
+            discovery.discover(system(&planets, &moon)),
+            vec![sibling.id]
+        );
+        assert!(!discovery.is_discovered(other_star.id));
+    }
+}
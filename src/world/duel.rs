@@ -0,0 +1,133 @@
+use super::{
+    constants::{MoraleModifier, TirednessCost, HOURS},
+    player::Player,
+    skill::GameSkill,
+};
+use crate::types::{PlayerId, Tick};
+use rand_chacha::ChaCha8Rng;
+
+pub const DUEL_WINNING_SCORE: u8 = 7;
+// The duel is stopped after this many possessions, the player ahead winning it.
+const MAX_DUEL_POSSESSIONS: usize = 30;
+// Drive advantage needed to get all the way to the basket.
+const DUEL_DRIVE_ADVANTAGE: i16 = 10;
+pub const DUEL_COOLDOWN: Tick = 4 * HOURS;
+pub const DUEL_WINNER_MORALE_BONUS: f32 = MoraleModifier::HIGH_BONUS;
+pub const DUEL_WINNER_REPUTATION_BONUS: f32 = 0.1;
+pub const DUEL_LOSER_TIREDNESS: f32 = TirednessCost::CRITICAL;
+// Number of plays shown in the recap popup.
+pub const DUEL_RECAP_PLAYS: usize = 3;
+
+/// Outcome of a one on one game between two crew members, played onboard.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuelResult {
+    pub winner_id: PlayerId,
+    pub loser_id: PlayerId,
+    pub winner_score: u8,
+    pub loser_score: u8,
+    // Scoring plays, in order.
+    pub plays: Vec<String>,
+}
+
+// Plays a streetball game to DUEL_WINNING_SCORE: makes inside the arc are worth 1, deep shots 2.
+pub fn simulate_duel(challenger: &Player, opponent: &Player, rng: &mut ChaCha8Rng) -> DuelResult {
+    let players = [challenger, opponent];
+    let mut scores = [0_u8; 2];
+    let mut plays = vec![];
+
+    for possession in 0..MAX_DUEL_POSSESSIONS {
+        let attacker_idx = possession % 2;
+        let attacker = players[attacker_idx];
+        let defender = players[1 - attacker_idx];
+
+        let drive = (attacker.roll(rng)
+            + attacker.technical.ball_handling.value()
+            + attacker.athletics.quickness.value()) as i16
+            - (defender.roll(rng)
+                + defender.defense.perimeter_defense.value()
+                + defender.athletics.quickness.value()) as i16;
+
+        let (shot, points, shot_skill, contest_skill, difficulty) = match drive {
+            x if x > DUEL_DRIVE_ADVANTAGE => (
+                "a layup",
+                1,
+                attacker.offense.close_range,
+                defender.defense.block,
+                -5,
+            ),
+            x if x >= 0 => (
+                "a jumper",
+                1,
+                attacker.offense.medium_range,
+                defender.defense.perimeter_defense,
+                0,
+            ),
+            _ => (
+                "a deep three",
+                2,
+                attacker.offense.long_range,
+                defender.defense.perimeter_defense,
+                5,
+            ),
+        };
+
+        let shot_result = (attacker.roll(rng) + shot_skill.value()) as i16
+            - (defender.roll(rng) + contest_skill.value()) as i16;
+        if shot_result <= difficulty {
+            continue;
+        }
+
+        scores[attacker_idx] += points;
+        plays.push(format!(
+            "{} scores {} over {} ({}-{}).",
+            attacker.info.shortened_name(),
+            shot,
+            defender.info.shortened_name(),
+            scores[0],
+            scores[1]
+        ));
+
+        if scores[attacker_idx] >= DUEL_WINNING_SCORE {
+            break;
+        }
+    }
+
+    // On a tie the challenger, who had the first possession, loses.
+    let winner_idx = if scores[0] > scores[1] { 0 } else { 1 };
+    DuelResult {
+        winner_id: players[winner_idx].id,
+        loser_id: players[1 - winner_idx].id,
+        winner_score: scores[winner_idx],
+        loser_score: scores[1 - winner_idx],
+        plays,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{simulate_duel, DUEL_WINNING_SCORE};
+    use crate::types::PlayerId;
+    use crate::world::{player::Player, utils::PLANET_DATA};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_simulate_duel() {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let rookie = Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 0.0);
+        let veteran = Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 15.0);
+
+        let mut veteran_wins = 0;
+        for _ in 0..20 {
+            let result = simulate_duel(&rookie, &veteran, rng);
+            assert_ne!(result.winner_id, result.loser_id);
+            assert!(result.winner_score >= result.loser_score);
+            assert!(result.winner_score <= DUEL_WINNING_SCORE + 1);
+            if result.winner_id == veteran.id {
+                veteran_wins += 1;
+            }
+        }
+
+        assert!(veteran_wins > 10);
+    }
+}
@@ -0,0 +1,62 @@
+pub const INITIAL_ELO_RATING: u32 = 1200;
+// Maximum rating change after a single game.
+pub const ELO_K_FACTOR: f32 = 32.0;
+const ELO_MIN_RATING: u32 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameOutcome {
+    Win,
+    Loss,
+    Draw,
+}
+
+impl GameOutcome {
+    fn score(&self) -> f32 {
+        match self {
+            Self::Win => 1.0,
+            Self::Loss => 0.0,
+            Self::Draw => 0.5,
+        }
+    }
+}
+
+// Probability of winning against the opponent, as expected from the ratings.
+pub fn expected_score(rating: u32, opponent_rating: u32) -> f32 {
+    1.0 / (1.0 + 10.0_f32.powf((opponent_rating as f32 - rating as f32) / 400.0))
+}
+
+// Returns the new ratings of the two teams, given the outcome for the first one.
+pub fn updated_ratings(rating: u32, opponent_rating: u32, outcome: GameOutcome) -> (u32, u32) {
+    let delta = ELO_K_FACTOR * (outcome.score() - expected_score(rating, opponent_rating));
+    let apply =
+        |rating: u32, delta: f32| ((rating as f32 + delta).round() as u32).max(ELO_MIN_RATING);
+    (apply(rating, delta), apply(opponent_rating, -delta))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expected_score, updated_ratings, GameOutcome, ELO_K_FACTOR, INITIAL_ELO_RATING};
+
+    #[test]
+    fn test_elo_update() {
+        assert_eq!(expected_score(INITIAL_ELO_RATING, INITIAL_ELO_RATING), 0.5);
+        assert!(expected_score(1600, 1200) > 0.9);
+
+        let half_k = (ELO_K_FACTOR / 2.0) as u32;
+        assert_eq!(
+            updated_ratings(1200, 1200, GameOutcome::Win),
+            (1200 + half_k, 1200 - half_k)
+        );
+        assert_eq!(updated_ratings(1200, 1200, GameOutcome::Draw), (1200, 1200));
+
+        // Beating a stronger team is worth more than beating a weaker one.
+        let (underdog, _) = updated_ratings(1000, 1400, GameOutcome::Win);
+        let (favourite, _) = updated_ratings(1400, 1000, GameOutcome::Win);
+        assert!(underdog - 1000 > favourite - 1400);
+
+        // A draw against a stronger team is a gain.
+        let (rating, opponent_rating) = updated_ratings(1000, 1400, GameOutcome::Draw);
+        assert!(rating > 1000);
+        assert!(opponent_rating < 1400);
+    }
+}
@@ -0,0 +1,73 @@
+use super::jersey::Jersey;
+use crate::image::color_map::ColorMap;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use strum_macros::EnumIter;
+
+#[derive(
+    Debug, Default, Clone, Copy, Display, Serialize, Deserialize, PartialEq, Hash, EnumIter,
+)]
+pub enum EmblemSymbol {
+    #[default]
+    Skull,
+    Crossbones,
+    Star,
+    Anchor,
+    Moon,
+}
+
+impl EmblemSymbol {
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Skull => Self::Crossbones,
+            Self::Crossbones => Self::Star,
+            Self::Star => Self::Anchor,
+            Self::Anchor => Self::Moon,
+            Self::Moon => Self::Skull,
+        }
+    }
+}
+
+#[derive(
+    Debug, Default, Clone, Copy, Display, Serialize, Deserialize, PartialEq, Hash, EnumIter,
+)]
+pub enum EmblemPattern {
+    #[default]
+    Plain,
+    Stripes,
+    Checkered,
+    Halved,
+    Border,
+}
+
+impl EmblemPattern {
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Plain => Self::Stripes,
+            Self::Stripes => Self::Checkered,
+            Self::Checkered => Self::Halved,
+            Self::Halved => Self::Border,
+            Self::Border => Self::Plain,
+        }
+    }
+}
+
+/// Team flag, flown next to the crew. The red channel colors the field,
+/// the green one the pattern and the blue one the symbol.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Emblem {
+    pub symbol: EmblemSymbol,
+    pub pattern: EmblemPattern,
+    pub color: ColorMap,
+}
+
+impl Emblem {
+    // Teams without a custom emblem fly a plain skull in the jersey colors.
+    pub fn from_jersey(jersey: &Jersey) -> Self {
+        Self {
+            symbol: EmblemSymbol::Skull,
+            pattern: EmblemPattern::Plain,
+            color: jersey.color,
+        }
+    }
+}
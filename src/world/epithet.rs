@@ -0,0 +1,105 @@
+use super::player::Player;
+use crate::game_engine::types::GameStats;
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+// Single game performances earning a pirate an epithet.
+pub const EPITHET_POINTS: u8 = 30;
+pub const EPITHET_REBOUNDS: u8 = 15;
+pub const EPITHET_ASSISTS: u8 = 12;
+pub const EPITHET_STEALS: u8 = 6;
+pub const EPITHET_BLOCKS: u8 = 5;
+pub const EPITHET_CLUTCH_SHOTS: u8 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter)]
+pub enum Feat {
+    Clutch,
+    Scoring,
+    Rebounding,
+    Playmaking,
+    Stealing,
+    Blocking,
+}
+
+impl Feat {
+    pub fn achieved(&self, stats: &GameStats) -> bool {
+        match self {
+            Self::Clutch => stats.clutch_shots >= EPITHET_CLUTCH_SHOTS,
+            Self::Scoring => stats.points >= EPITHET_POINTS,
+            Self::Rebounding => {
+                stats.offensive_rebounds + stats.defensive_rebounds >= EPITHET_REBOUNDS
+            }
+            Self::Playmaking => stats.assists >= EPITHET_ASSISTS,
+            Self::Stealing => stats.steals >= EPITHET_STEALS,
+            Self::Blocking => stats.blocks >= EPITHET_BLOCKS,
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Clutch => "a string of clutch shots",
+            Self::Scoring => "a huge scoring game",
+            Self::Rebounding => "owning the boards",
+            Self::Playmaking => "a night of assists",
+            Self::Stealing => "picking every pocket",
+            Self::Blocking => "a wall of blocks",
+        }
+    }
+
+    fn titles(&self) -> [&'static str; 3] {
+        match self {
+            Self::Clutch => ["Ice", "Last Breath", "Silent Blade"],
+            Self::Scoring => ["Hammer", "Cannon", "Storm"],
+            Self::Rebounding => ["Claw", "Kraken", "Anchor"],
+            Self::Playmaking => ["Compass", "Navigator", "Lighthouse"],
+            Self::Stealing => ["Ghost", "Pickpocket", "Shadow"],
+            Self::Blocking => ["Wall", "Shield", "Reef"],
+        }
+    }
+}
+
+// The most notable feat of the game, clutch shots first.
+pub fn notable_feat(stats: &GameStats) -> Option<Feat> {
+    Feat::iter().find(|feat| feat.achieved(stats))
+}
+
+pub fn generate_epithet(rng: &mut ChaCha8Rng, feat: Feat, home_planet_name: &str) -> String {
+    let title = feat.titles().choose(rng).copied().unwrap_or("Legend");
+    format!("The {} of {}", title, home_planet_name)
+}
+
+// Commentary line for a made shot by a pirate with an epithet.
+pub fn epithet_commentary(player: &Player) -> Option<String> {
+    let epithet = player.info.epithet.as_ref()?;
+    Some(format!(
+        "{}, {}, strikes again!",
+        player.info.shortened_name(),
+        epithet.replacen("The ", "the ", 1)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate_epithet, notable_feat, Feat, EPITHET_CLUTCH_SHOTS, EPITHET_POINTS};
+    use crate::game_engine::types::GameStats;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_epithet_generation() {
+        let mut stats = GameStats::default();
+        assert_eq!(notable_feat(&stats), None);
+
+        stats.points = EPITHET_POINTS;
+        assert_eq!(notable_feat(&stats), Some(Feat::Scoring));
+        stats.clutch_shots = EPITHET_CLUTCH_SHOTS;
+        assert_eq!(notable_feat(&stats), Some(Feat::Clutch));
+
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let epithet = generate_epithet(rng, Feat::Scoring, "Ganymede");
+        assert!(epithet.starts_with("The "));
+        assert!(epithet.ends_with(" of Ganymede"));
+    }
+}
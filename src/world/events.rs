@@ -0,0 +1,103 @@
+use super::constants::{MoraleModifier, HOURS};
+use crate::types::{PlanetId, ResourceMap, Tick};
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use strum_macros::Display;
+
+// Checked once every TickInterval::MEDIUM.
+pub const WORLD_EVENT_PROBABILITY: f64 = 0.004;
+pub const MAX_WORLD_EVENTS: usize = 50;
+pub const METEOR_SHOWER_DAMAGE: u32 = 15;
+pub const RUM_FESTIVAL_MORALE_BONUS: f32 = MoraleModifier::HIGH_BONUS;
+pub const PLAGUE_DURATION: Tick = 24 * HOURS;
+
+#[derive(Debug, Clone, Copy, Display, Serialize_repr, Deserialize_repr, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum WorldEventKind {
+    #[strum(to_string = "Meteor shower")]
+    MeteorShower,
+    #[strum(to_string = "Rum festival")]
+    RumFestival,
+    Plague,
+}
+
+impl WorldEventKind {
+    pub fn random(rng: &mut ChaCha8Rng) -> Self {
+        *[Self::MeteorShower, Self::RumFestival, Self::Plague]
+            .choose(rng)
+            .expect("There should be a world event kind")
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::MeteorShower => "Meteors rained on the spaceships docked at the planet.",
+            Self::RumFestival => "Rum flowed freely, cheering up all the crews at the planet.",
+            Self::Plague => "The planet is quarantined and its market is closed.",
+        }
+    }
+}
+
+/// Something that happened on a planet, affecting the teams that were there.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WorldEvent {
+    pub kind: WorldEventKind,
+    pub planet_id: PlanetId,
+    pub tick: Tick,
+    // Ticks at which the effects of the event end, for events with durable effects.
+    pub until: Tick,
+    pub message: String,
+    // Planet resources taken off the market, restored when the event ends.
+    #[serde(skip_serializing_if = "ResourceMap::is_empty")]
+    #[serde(default)]
+    pub drained_resources: ResourceMap,
+}
+
+impl WorldEvent {
+    pub fn new(kind: WorldEventKind, planet_id: PlanetId, tick: Tick, message: String) -> Self {
+        let until = match kind {
+            WorldEventKind::Plague => tick + PLAGUE_DURATION,
+            _ => tick,
+        };
+
+        Self {
+            kind,
+            planet_id,
+            tick,
+            until,
+            message,
+            drained_resources: ResourceMap::default(),
+        }
+    }
+
+    pub fn is_active(&self, tick: Tick) -> bool {
+        tick < self.until
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WorldEvent, WorldEventKind, PLAGUE_DURATION};
+    use crate::types::PlanetId;
+
+    #[test]
+    fn test_world_event_duration() {
+        let meteors = WorldEvent::new(
+            WorldEventKind::MeteorShower,
+            PlanetId::default(),
+            100,
+            String::new(),
+        );
+        assert!(!meteors.is_active(100));
+
+        let plague = WorldEvent::new(
+            WorldEventKind::Plague,
+            PlanetId::default(),
+            100,
+            String::new(),
+        );
+        assert!(plague.is_active(100 + PLAGUE_DURATION - 1));
+        assert!(!plague.is_active(100 + PLAGUE_DURATION));
+    }
+}
@@ -0,0 +1,165 @@
+use super::constants::*;
+use crate::types::Tick;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::fmt::Display;
+
+pub const WEEK: Tick = 7 * DAYS;
+
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Hash)]
+#[repr(u8)]
+pub enum FinanceCategory {
+    GameIncome,
+    Trading,
+    Hiring,
+    Spaceship,
+    CrewBonus,
+    Theft,
+    Campaign,
+    Asteroids,
+    Contracts,
+    Tournaments,
+    DockingFees,
+    Fines,
+    Appearance,
+    Loans,
+    Insurance,
+    Tutorial,
+    Academy,
+    Staff,
+}
+
+impl Display for FinanceCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::GameIncome => write!(f, "Game income"),
+            Self::Trading => write!(f, "Trading"),
+            Self::Hiring => write!(f, "Hiring"),
+            Self::Spaceship => write!(f, "Spaceship"),
+            Self::CrewBonus => write!(f, "Crew bonus"),
+            Self::Theft => write!(f, "Theft"),
+            Self::Campaign => write!(f, "Campaign"),
+            Self::Asteroids => write!(f, "Asteroids"),
+            Self::Contracts => write!(f, "Contracts"),
+            Self::Tournaments => write!(f, "Tournaments"),
+            Self::DockingFees => write!(f, "Docking fees"),
+            Self::Fines => write!(f, "Fines"),
+            Self::Appearance => write!(f, "Appearance"),
+            Self::Loans => write!(f, "Loans"),
+            Self::Insurance => write!(f, "Insurance"),
+            Self::Tutorial => write!(f, "Tutorial"),
+            Self::Academy => write!(f, "Academy"),
+            Self::Staff => write!(f, "Staff"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FinanceEntry {
+    pub tick: Tick,
+    pub category: FinanceCategory,
+    // Positive for income, negative for expenses.
+    pub amount: i64,
+    pub description: String,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct WeeklySummary {
+    pub start: Tick,
+    pub income: u64,
+    pub expenses: u64,
+}
+
+impl WeeklySummary {
+    pub fn net(&self) -> i64 {
+        self.income as i64 - self.expenses as i64
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Finances {
+    pub entries: Vec<FinanceEntry>,
+}
+
+impl Finances {
+    pub fn record(
+        &mut self,
+        tick: Tick,
+        category: FinanceCategory,
+        amount: i64,
+        description: String,
+    ) {
+        if amount == 0 {
+            return;
+        }
+
+        self.entries.push(FinanceEntry {
+            tick,
+            category,
+            amount,
+            description,
+        });
+        if self.entries.len() > MAX_FINANCE_ENTRIES {
+            self.entries.remove(0);
+        }
+    }
+
+    // Returns the summaries of the last weeks, starting from the current one.
+    pub fn weekly_summaries(&self, now: Tick, weeks: usize) -> Vec<WeeklySummary> {
+        let mut summaries = (0..weeks as Tick)
+            .map(|week| WeeklySummary {
+                start: now.saturating_sub((week + 1) * WEEK),
+                ..Default::default()
+            })
+            .collect::<Vec<WeeklySummary>>();
+
+        for entry in self.entries.iter().filter(|e| e.tick <= now) {
+            let week = ((now - entry.tick) / WEEK) as usize;
+            if week >= weeks {
+                continue;
+            }
+            if entry.amount > 0 {
+                summaries[week].income += entry.amount as u64;
+            } else {
+                summaries[week].expenses += (-entry.amount) as u64;
+            }
+        }
+
+        summaries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FinanceCategory, Finances, WEEK};
+    use crate::world::constants::{DAYS, MAX_FINANCE_ENTRIES};
+
+    #[test]
+    fn test_weekly_summaries() {
+        let mut finances = Finances::default();
+        let now = 10 * WEEK;
+
+        finances.record(now - DAYS, FinanceCategory::GameIncome, 500, "".into());
+        finances.record(now - 2 * DAYS, FinanceCategory::Hiring, -200, "".into());
+        finances.record(now - WEEK - DAYS, FinanceCategory::Trading, 300, "".into());
+        finances.record(now - 5 * WEEK, FinanceCategory::Theft, -1000, "".into());
+        // Zero amounts are not recorded.
+        finances.record(now, FinanceCategory::Spaceship, 0, "".into());
+        assert_eq!(finances.entries.len(), 4);
+
+        let summaries = finances.weekly_summaries(now, 2);
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].income, 500);
+        assert_eq!(summaries[0].expenses, 200);
+        assert_eq!(summaries[0].net(), 300);
+        assert_eq!(summaries[1].income, 300);
+        assert_eq!(summaries[1].expenses, 0);
+        assert_eq!(summaries[1].start, now - 2 * WEEK);
+
+        for idx in 0..MAX_FINANCE_ENTRIES {
+            finances.record(now, FinanceCategory::Trading, idx as i64 + 1, "".into());
+        }
+        assert_eq!(finances.entries.len(), MAX_FINANCE_ENTRIES);
+        assert_eq!(finances.entries[0].amount, 1);
+    }
+}
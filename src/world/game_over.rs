@@ -0,0 +1,190 @@
+use super::{constants::*, resources::Resource, team::Team};
+use crate::types::{ResourceMap, StorableResourceMap, Tick};
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::fmt::Display;
+
+// How long the own team can stay insolvent or understaffed before the game is over.
+pub const GAME_OVER_GRACE_PERIOD: Tick = 3 * DAYS;
+// Auctioned resources only fetch a fraction of their base price.
+const AUCTION_PRICE_RATIO: f32 = 0.5;
+const AUCTIONED_RESOURCES: [Resource; 4] = [
+    Resource::GOLD,
+    Resource::RUM,
+    Resource::SCRAPS,
+    Resource::FUEL,
+];
+
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum GameOverReason {
+    Bankruptcy,
+    Understaffed,
+}
+
+impl Display for GameOverReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bankruptcy => write!(f, "Bankruptcy"),
+            Self::Understaffed => write!(f, "Abandoned ship"),
+        }
+    }
+}
+
+impl GameOverReason {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Bankruptcy => "The treasury could not pay the debts. The cargo was auctioned to the creditors and most pirates left the crew, only a handful of loyal ones stayed aboard.",
+            Self::Understaffed => "Without enough pirates to field a team, the crew could not play anymore. One by one, the last pirates left the ship.",
+        }
+    }
+}
+
+/// Tracks since when the own team is in trouble, ending the game after the grace period.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GameOverWatch {
+    pub insolvent_since: Option<Tick>,
+    pub understaffed_since: Option<Tick>,
+}
+
+impl GameOverWatch {
+    // Bankruptcy is always checked, an understaffed crew only ends ironman worlds.
+    pub fn check(
+        &mut self,
+        team: &Team,
+        tick: Tick,
+        check_understaffed: bool,
+    ) -> Option<GameOverReason> {
+        let is_insolvent = team.is_insolvent(tick);
+        let is_understaffed = check_understaffed && team.player_ids.len() < MIN_PLAYERS_PER_GAME;
+        update_since(&mut self.insolvent_since, is_insolvent, tick);
+        update_since(&mut self.understaffed_since, is_understaffed, tick);
+
+        if is_expired(self.insolvent_since, tick) {
+            Some(GameOverReason::Bankruptcy)
+        } else if is_expired(self.understaffed_since, tick) {
+            Some(GameOverReason::Understaffed)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_warning(&self) -> bool {
+        self.insolvent_since.is_some() || self.understaffed_since.is_some()
+    }
+}
+
+fn update_since(since: &mut Option<Tick>, condition: bool, tick: Tick) {
+    if !condition {
+        *since = None;
+    } else if since.is_none() {
+        *since = Some(tick);
+    }
+}
+
+fn is_expired(since: Option<Tick>, tick: Tick) -> bool {
+    since.is_some_and(|since| tick.saturating_sub(since) > GAME_OVER_GRACE_PERIOD)
+}
+
+/// Final summary of the own team career, shown when the world ends.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameOver {
+    pub reason: GameOverReason,
+    pub tick: Tick,
+    pub team_name: String,
+    pub game_record: [u32; 3],
+    pub network_game_record: [u32; 3],
+    pub reputation: f32,
+    pub crew_size: usize,
+    pub total_travelled: u128,
+    #[serde(default)]
+    pub auction_proceeds: u32,
+}
+
+impl GameOver {
+    pub fn new(reason: GameOverReason, team: &Team, tick: Tick) -> Self {
+        Self {
+            reason,
+            tick,
+            team_name: team.name.clone(),
+            game_record: team.game_record,
+            network_game_record: team.network_game_record,
+            reputation: team.reputation,
+            crew_size: team.player_ids.len(),
+            total_travelled: team.spaceship.total_travelled,
+            auction_proceeds: 0,
+        }
+    }
+}
+
+// Sells all the cargo at auction, returning the satoshi earned.
+pub fn auction_assets(resources: &mut ResourceMap) -> u32 {
+    let mut proceeds = 0;
+    for resource in AUCTIONED_RESOURCES {
+        let amount = resources.value(&resource);
+        resources.saturating_sub(resource, amount);
+        proceeds += (amount as f32 * resource.base_price() * AUCTION_PRICE_RATIO) as u32;
+    }
+    resources.saturating_add(Resource::SATOSHI, proceeds, u32::MAX);
+    proceeds
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{auction_assets, GameOverReason, GameOverWatch, GAME_OVER_GRACE_PERIOD};
+    use crate::types::{PlanetId, PlayerId, ResourceMap, StorableResourceMap};
+    use crate::world::{
+        constants::MIN_PLAYERS_PER_GAME, loan::Loan, resources::Resource, team::Team,
+    };
+
+    #[test]
+    fn test_game_over_watch() {
+        let mut team = Team::default();
+        team.player_ids = (0..MIN_PLAYERS_PER_GAME)
+            .map(|_| PlayerId::new_v4())
+            .collect();
+        let mut watch = GameOverWatch::default();
+        assert!(watch.check(&team, 0, true).is_none());
+        assert!(!watch.is_warning());
+
+        // An understaffed crew has until the end of the grace period to hire.
+        team.player_ids.pop();
+        assert!(watch.check(&team, 0, false).is_none());
+        assert!(!watch.is_warning());
+        assert!(watch.check(&team, 0, true).is_none());
+        assert!(watch.is_warning());
+        assert!(watch.check(&team, GAME_OVER_GRACE_PERIOD, true).is_none());
+        team.player_ids.push(PlayerId::new_v4());
+        assert!(watch
+            .check(&team, GAME_OVER_GRACE_PERIOD + 1, true)
+            .is_none());
+        assert!(!watch.is_warning());
+
+        // Spending borrowed satoshi does not make the team insolvent.
+        team.resources
+            .saturating_add(Resource::SATOSHI, 500, u32::MAX);
+        team.loan = Some(Loan::new(PlanetId::new_v4(), 1_000, 0));
+        assert!(watch.check(&team, 10, false).is_none());
+        assert!(!watch.is_warning());
+
+        team.resources.saturating_sub(Resource::SATOSHI, 500);
+        assert!(watch.check(&team, 10, false).is_none());
+        assert_eq!(
+            watch.check(&team, 11 + GAME_OVER_GRACE_PERIOD, false),
+            Some(GameOverReason::Bankruptcy)
+        );
+    }
+
+    #[test]
+    fn test_auction_assets() {
+        let mut resources = ResourceMap::new();
+        resources.saturating_add(Resource::SATOSHI, 100, u32::MAX);
+        resources.saturating_add(Resource::GOLD, 2, u32::MAX);
+        resources.saturating_add(Resource::FUEL, 10, u32::MAX);
+
+        assert_eq!(auction_assets(&mut resources), 1_750 + 150);
+        assert_eq!(resources.value(&Resource::SATOSHI), 2_000);
+        assert_eq!(resources.value(&Resource::GOLD), 0);
+        assert_eq!(resources.value(&Resource::FUEL), 0);
+    }
+}
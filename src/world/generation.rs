@@ -0,0 +1,202 @@
+use crate::game_engine::rules::MatchRules;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::ops::RangeInclusive;
+use strum_macros::{Display, EnumIter};
+
+// Upper bound to the number of AI teams, the actual number is capped by the available team data.
+pub const MAX_AI_TEAMS: usize = 32;
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    Serialize_repr,
+    Deserialize_repr,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumIter,
+    Display,
+)]
+#[repr(u8)]
+pub enum GalaxySize {
+    Small,
+    #[default]
+    Standard,
+    Large,
+}
+
+impl GalaxySize {
+    // Multiplier to the number of free pirates living on each planet.
+    pub fn population_multiplier(&self) -> f32 {
+        match self {
+            Self::Small => 0.5,
+            Self::Standard => 1.0,
+            Self::Large => 1.5,
+        }
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    Serialize_repr,
+    Deserialize_repr,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumIter,
+    Display,
+)]
+#[repr(u8)]
+pub enum ResourceRichness {
+    Scarce,
+    #[default]
+    Standard,
+    Abundant,
+}
+
+impl ResourceRichness {
+    // Multiplier to the resources available on planets, which determine market prices.
+    pub fn resource_multiplier(&self) -> f32 {
+        match self {
+            Self::Scarce => 0.5,
+            Self::Standard => 1.0,
+            Self::Abundant => 1.5,
+        }
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    Serialize_repr,
+    Deserialize_repr,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumIter,
+    Display,
+)]
+#[repr(u8)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Brutal,
+}
+
+impl Difficulty {
+    pub fn ai_team_level_range(&self) -> RangeInclusive<i32> {
+        match self {
+            Self::Easy => 0..=5,
+            Self::Normal => 0..=8,
+            Self::Brutal => 3..=10,
+        }
+    }
+
+    // Added to the base level of the free pirates available for hire.
+    pub fn free_pirates_level_bonus(&self) -> f32 {
+        match self {
+            Self::Easy => 1.0,
+            Self::Normal => 0.0,
+            Self::Brutal => -1.0,
+        }
+    }
+
+    pub fn modifier(&self, kind: DifficultyModifier) -> f32 {
+        match (kind, self) {
+            (DifficultyModifier::AiGrowth, Self::Easy) => 0.75,
+            (DifficultyModifier::AiGrowth, Self::Brutal) => 1.5,
+            (DifficultyModifier::MarketMargin, Self::Easy) => 0.75,
+            (DifficultyModifier::MarketMargin, Self::Brutal) => 1.5,
+            (DifficultyModifier::HireCost, Self::Easy) => 0.8,
+            (DifficultyModifier::HireCost, Self::Brutal) => 1.3,
+            (DifficultyModifier::EventHarshness, Self::Easy) => 0.5,
+            (DifficultyModifier::EventHarshness, Self::Brutal) => 1.5,
+            (_, Self::Normal) => 1.0,
+        }
+    }
+}
+
+/// Aspects of the world scaled by the difficulty, as multipliers to the normal values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+pub enum DifficultyModifier {
+    // Skills gained in training by the players of AI crews.
+    AiGrowth,
+    // Spread between the buy and sell prices on the markets for the own team.
+    MarketMargin,
+    // Cost of hiring free pirates for the own team.
+    HireCost,
+    // Damage dealt by world events and travel hazards.
+    EventHarshness,
+}
+
+/// Options chosen when starting a new game, used to generate the local galaxy.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct GenerationOptions {
+    pub galaxy_size: GalaxySize,
+    pub ai_teams: usize,
+    pub resource_richness: ResourceRichness,
+    pub difficulty: Difficulty,
+    #[serde(default)]
+    pub match_rules: MatchRules,
+    // Single save without backups, and the world ends when the own team is game over.
+    #[serde(default)]
+    pub ironman: bool,
+}
+
+impl Default for GenerationOptions {
+    fn default() -> Self {
+        Self {
+            galaxy_size: GalaxySize::default(),
+            ai_teams: MAX_AI_TEAMS,
+            resource_richness: ResourceRichness::default(),
+            difficulty: Difficulty::default(),
+            match_rules: MatchRules::default(),
+            ironman: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Difficulty, DifficultyModifier, GalaxySize, GenerationOptions};
+    use crate::{types::AppResult, world::world::World};
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_generation_options() -> AppResult<()> {
+        let mut default_world = World::new(Some(0));
+        default_world.initialize(true)?;
+
+        let mut small_world = World::new(Some(0));
+        small_world.generation_options = GenerationOptions {
+            galaxy_size: GalaxySize::Small,
+            ai_teams: 3,
+            ..Default::default()
+        };
+        small_world.initialize(true)?;
+
+        assert_eq!(small_world.teams.len(), 3);
+        assert!(small_world.teams.len() < default_world.teams.len());
+        assert!(small_world.players.len() < default_world.players.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_difficulty_modifiers() {
+        for kind in DifficultyModifier::iter() {
+            assert_eq!(Difficulty::Normal.modifier(kind), 1.0);
+            assert!(Difficulty::Easy.modifier(kind) < 1.0);
+            assert!(Difficulty::Brutal.modifier(kind) > 1.0);
+        }
+    }
+}
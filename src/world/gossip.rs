@@ -0,0 +1,50 @@
+use super::constants::GOSSIP_STRIKES_FOR_DECLINES;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+#[derive(Debug, Clone, Copy, Display, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum Gossip {
+    DeclinedChallenge,
+    DeclinedTrade,
+    UnpaidDockingFee,
+    Smuggling,
+}
+
+impl Gossip {
+    // Reputation lost on the planet where the gossip started.
+    // Neighbouring planets lose half of it, the global reputation a fraction.
+    pub fn reputation_malus(&self) -> f32 {
+        match self {
+            Self::DeclinedChallenge => 0.5,
+            Self::DeclinedTrade => 0.75,
+            Self::UnpaidDockingFee => 1.5,
+            Self::Smuggling => 2.0,
+        }
+    }
+
+    // Offences needed within a long tick before people start talking.
+    // Turning down a single challenge or trade is nobody's business.
+    pub fn strikes_to_spread(&self) -> u32 {
+        match self {
+            Self::DeclinedChallenge | Self::DeclinedTrade => GOSSIP_STRIKES_FOR_DECLINES,
+            Self::UnpaidDockingFee | Self::Smuggling => 1,
+        }
+    }
+
+    pub fn rumor(&self, team_name: &str) -> String {
+        match self {
+            Self::DeclinedChallenge => {
+                format!("The {team_name} are said to run away from a fair game.")
+            }
+            Self::DeclinedTrade => {
+                format!("The {team_name} are said to go back on their word when trading.")
+            }
+            Self::UnpaidDockingFee => {
+                format!("The {team_name} are said to sneak off without paying the docking fees.")
+            }
+            Self::Smuggling => {
+                format!("The {team_name} were caught smuggling rum past the customs.")
+            }
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use super::{
+    constants::{MoraleModifier, MINUTES},
+    player::Player,
+    skill::GameSkill,
+};
+use crate::types::Tick;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use strum_macros::{Display, EnumIter};
+
+pub const TRAVEL_HAZARD_PROBABILITY_PER_HOUR: f64 = 0.25;
+// Short hops are safe.
+pub const TRAVEL_HAZARD_MIN_TRAVEL_TIME: Tick = 30 * MINUTES;
+pub const STORM_MAX_DAMAGE: u32 = 8;
+pub const DETOUR_FUEL_COST: u32 = 15;
+pub const DETOUR_DELAY: Tick = 20 * MINUTES;
+pub const CUSTOMS_FINE: u32 = 1_500;
+pub const OUTRUN_FAILURE_DAMAGE: u32 = 4;
+pub const INSPECTION_DELAY: Tick = 30 * MINUTES;
+// Rum beyond this ration per crew member is seized as contraband during an inspection.
+pub const CUSTOMS_RUM_ALLOWANCE_PER_PLAYER: u32 = 2;
+pub const TRADERS_FUEL_GIFT: u32 = 10;
+pub const TRADERS_RUM_AMOUNT: u32 = 5;
+pub const TRADERS_RUM_PRICE: u32 = 400;
+
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Display, EnumIter)]
+#[repr(u8)]
+pub enum TravelHazardKind {
+    #[strum(to_string = "Asteroid storm")]
+    AsteroidStorm,
+    #[strum(to_string = "Customs patrol")]
+    CustomsPatrol,
+    #[strum(to_string = "Stranded traders")]
+    StrandedTraders,
+}
+
+impl TravelHazardKind {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::AsteroidStorm => {
+                "A swarm of rocks is blocking the way. The pilot wants to know what to do."
+            }
+            Self::CustomsPatrol => {
+                "A customs patrol is signalling the ship to stop for an inspection."
+            }
+            Self::StrandedTraders => "A merchant shuttle ran out of fuel and is calling for help.",
+        }
+    }
+
+    pub fn choices(&self) -> Vec<HazardChoice> {
+        match self {
+            Self::AsteroidStorm => vec![HazardChoice::PushThrough, HazardChoice::Detour],
+            Self::CustomsPatrol => vec![
+                HazardChoice::PayFine,
+                HazardChoice::Outrun,
+                HazardChoice::Submit,
+            ],
+            Self::StrandedTraders => vec![
+                HazardChoice::Help,
+                HazardChoice::Trade,
+                HazardChoice::Ignore,
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Display, EnumIter)]
+#[repr(u8)]
+pub enum HazardChoice {
+    #[strum(to_string = "Push through")]
+    PushThrough,
+    Detour,
+    #[strum(to_string = "Pay fine")]
+    PayFine,
+    Outrun,
+    Submit,
+    Help,
+    Trade,
+    Ignore,
+}
+
+impl HazardChoice {
+    pub fn description(&self) -> String {
+        match self {
+            Self::PushThrough => format!(
+                "Fly through the storm. The hull takes up to {} damage, less with an agile crew.",
+                STORM_MAX_DAMAGE
+            ),
+            Self::Detour => format!(
+                "Go around the storm, burning {} fuel and losing some time.",
+                DETOUR_FUEL_COST
+            ),
+            Self::PayFine => format!("Pay a {} satoshi 'fine' and move on.", CUSTOMS_FINE),
+            Self::Outrun => {
+                "Try to outrun the patrol. Failing means a damaged hull and a demoralized crew."
+                    .into()
+            }
+            Self::Submit => {
+                "Let them search the ship. It takes a while, the crew hates it and any rum beyond the crew ration is seized.".into()
+            }
+            Self::Help => format!(
+                "Give them {} fuel. The crew will feel good about it.",
+                TRADERS_FUEL_GIFT
+            ),
+            Self::Trade => format!(
+                "Buy {} rum for {} satoshi from their cargo.",
+                TRADERS_RUM_AMOUNT, TRADERS_RUM_PRICE
+            ),
+            Self::Ignore => "Leave them to their fate. The crew won't like it.".into(),
+        }
+    }
+}
+
+/// A hazard halting the travel until the crew picks how to deal with it.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct TravelHazard {
+    pub kind: TravelHazardKind,
+    pub started: Tick,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HazardOutcome {
+    pub fuel: i32,
+    pub durability: i32,
+    // Morale added to every crew member.
+    pub morale: f32,
+    pub satoshi: i32,
+    pub rum: u32,
+    // Extra travel time.
+    pub delay: Tick,
+    pub message: String,
+}
+
+// The most agile crew member takes the helm.
+fn best_agility_roll(crew: &[&Player], rng: &mut ChaCha8Rng) -> f32 {
+    crew.iter()
+        .map(|player| {
+            player.roll(rng) as f32
+                + player.athletics.quickness.value() as f32
+                + player.technical.ball_handling.value() as f32
+        })
+        .fold(0.0, f32::max)
+}
+
+pub fn roll_hazard_outcome(
+    choice: HazardChoice,
+    crew: &[&Player],
+    rng: &mut ChaCha8Rng,
+) -> HazardOutcome {
+    match choice {
+        HazardChoice::PushThrough => {
+            // Rolls range roughly from 0 to 80.
+            let skill = (best_agility_roll(crew, rng) / 80.0).min(1.0);
+            let damage = ((STORM_MAX_DAMAGE as f32 * (1.0 - skill)).round() as u32).max(1);
+            HazardOutcome {
+                durability: -(damage as i32),
+                morale: MoraleModifier::SMALL_MALUS,
+                message: format!(
+                    "The ship made it through the storm, taking {} damage.",
+                    damage
+                ),
+                ..Default::default()
+            }
+        }
+        HazardChoice::Detour => HazardOutcome {
+            fuel: -(DETOUR_FUEL_COST as i32),
+            delay: DETOUR_DELAY,
+            message: "The ship went around the storm.".into(),
+            ..Default::default()
+        },
+        HazardChoice::PayFine => HazardOutcome {
+            satoshi: -(CUSTOMS_FINE as i32),
+            message: "The patrol took the money and let the ship go.".into(),
+            ..Default::default()
+        },
+        HazardChoice::Outrun => {
+            let skill = best_agility_roll(crew, rng) / 80.0;
+            if rng.gen_bool((skill as f64).clamp(0.2, 0.8)) {
+                HazardOutcome {
+                    morale: MoraleModifier::MEDIUM_BONUS,
+                    message: "The patrol was left behind in the dust!".into(),
+                    ..Default::default()
+                }
+            } else {
+                HazardOutcome {
+                    durability: -(OUTRUN_FAILURE_DAMAGE as i32),
+                    morale: MoraleModifier::MEDIUM_MALUS,
+                    message: format!(
+                        "The patrol opened fire before giving up the chase: {} damage.",
+                        OUTRUN_FAILURE_DAMAGE
+                    ),
+                    ..Default::default()
+                }
+            }
+        }
+        HazardChoice::Submit => HazardOutcome {
+            morale: MoraleModifier::SMALL_MALUS,
+            delay: INSPECTION_DELAY,
+            message: "The inspection found nothing, after turning the ship upside down.".into(),
+            ..Default::default()
+        },
+        HazardChoice::Help => HazardOutcome {
+            fuel: -(TRADERS_FUEL_GIFT as i32),
+            morale: MoraleModifier::MEDIUM_BONUS,
+            message: "The traders thanked the crew and went on their way.".into(),
+            ..Default::default()
+        },
+        HazardChoice::Trade => HazardOutcome {
+            satoshi: -(TRADERS_RUM_PRICE as i32),
+            rum: TRADERS_RUM_AMOUNT,
+            message: format!("Bought {} rum from the traders.", TRADERS_RUM_AMOUNT),
+            ..Default::default()
+        },
+        HazardChoice::Ignore => HazardOutcome {
+            morale: MoraleModifier::SMALL_MALUS,
+            message: "The ship flew past the stranded traders.".into(),
+            ..Default::default()
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{roll_hazard_outcome, TravelHazardKind, STORM_MAX_DAMAGE};
+    use crate::types::PlayerId;
+    use crate::world::{player::Player, utils::PLANET_DATA};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_hazard_outcomes() {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let players = (0..5)
+            .map(|_| Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 0.0))
+            .collect::<Vec<Player>>();
+        let crew = players.iter().collect::<Vec<&Player>>();
+
+        for kind in TravelHazardKind::iter() {
+            let choices = kind.choices();
+            assert!(choices.len() >= 2 && choices.len() <= 3);
+            for choice in choices {
+                let outcome = roll_hazard_outcome(choice, &crew, rng);
+                assert!(!outcome.message.is_empty());
+                assert!(outcome.durability >= -(STORM_MAX_DAMAGE as i32));
+            }
+        }
+    }
+}
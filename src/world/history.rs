@@ -0,0 +1,206 @@
+use super::{player::Player, skill::Rated, team::Team};
+use crate::game_engine::game::Game;
+use crate::types::{GameId, PlayerId, TeamId, Tick};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub const MAX_LEGENDS: usize = 20;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct TeamRecord {
+    pub team_id: TeamId,
+    pub team_name: String,
+    pub value: u32,
+    pub tick: Tick,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ScoreRecord {
+    pub game_id: GameId,
+    pub team_name: String,
+    pub opponent_name: String,
+    pub score: u16,
+    pub opponent_score: u16,
+    pub tick: Tick,
+}
+
+/// A retired pirate remembered in the Hall of Fame.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Legend {
+    pub player_id: PlayerId,
+    pub name: String,
+    pub team_name: String,
+    pub reputation: f32,
+    pub rating: u8,
+    pub retired_at: Tick,
+}
+
+/// Records of the world history, kept across the whole save.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct History {
+    pub highest_score: Option<ScoreRecord>,
+    pub longest_win_streak: Option<TeamRecord>,
+    pub richest_team: Option<TeamRecord>,
+    pub legends: Vec<Legend>,
+    // Current win streak of each team, used to update the longest streak record.
+    pub win_streaks: HashMap<TeamId, u32>,
+}
+
+impl History {
+    pub fn is_empty(&self) -> bool {
+        self.highest_score.is_none()
+            && self.longest_win_streak.is_none()
+            && self.richest_team.is_none()
+            && self.legends.is_empty()
+    }
+
+    pub fn record_game(&mut self, game: &Game) {
+        let tick = game.ended_at.unwrap_or(game.starting_at);
+        let (home_score, away_score) = game.get_score();
+        let home = &game.home_team_in_game;
+        let away = &game.away_team_in_game;
+
+        self.record_score(
+            game.id, &home.name, &away.name, home_score, away_score, tick,
+        );
+        self.record_score(
+            game.id, &away.name, &home.name, away_score, home_score, tick,
+        );
+
+        match game.winner {
+            Some(winner_id) if winner_id == home.team_id => {
+                self.record_win(home.team_id, &home.name, away.team_id, tick)
+            }
+            Some(_) => self.record_win(away.team_id, &away.name, home.team_id, tick),
+            None => {
+                self.win_streaks.remove(&home.team_id);
+                self.win_streaks.remove(&away.team_id);
+            }
+        }
+    }
+
+    pub fn record_score(
+        &mut self,
+        game_id: GameId,
+        team_name: &str,
+        opponent_name: &str,
+        score: u16,
+        opponent_score: u16,
+        tick: Tick,
+    ) {
+        if self
+            .highest_score
+            .as_ref()
+            .is_some_and(|record| record.score >= score)
+        {
+            return;
+        }
+
+        self.highest_score = Some(ScoreRecord {
+            game_id,
+            team_name: team_name.to_string(),
+            opponent_name: opponent_name.to_string(),
+            score,
+            opponent_score,
+            tick,
+        });
+    }
+
+    pub fn record_win(&mut self, team_id: TeamId, team_name: &str, loser_id: TeamId, tick: Tick) {
+        self.win_streaks.remove(&loser_id);
+        let streak = self.win_streaks.entry(team_id).or_insert(0);
+        *streak += 1;
+        let streak = *streak;
+
+        if self
+            .longest_win_streak
+            .as_ref()
+            .is_some_and(|record| record.value >= streak)
+        {
+            return;
+        }
+
+        self.longest_win_streak = Some(TeamRecord {
+            team_id,
+            team_name: team_name.to_string(),
+            value: streak,
+            tick,
+        });
+    }
+
+    pub fn record_balance(&mut self, team: &Team, tick: Tick) {
+        let balance = team.balance();
+        if self
+            .richest_team
+            .as_ref()
+            .is_some_and(|record| record.value >= balance)
+        {
+            return;
+        }
+
+        self.richest_team = Some(TeamRecord {
+            team_id: team.id,
+            team_name: team.name.clone(),
+            value: balance,
+            tick,
+        });
+    }
+
+    // Only the most renowned retired pirates make it into the Hall of Fame.
+    pub fn record_retirement(&mut self, player: &Player, team_name: &str, tick: Tick) {
+        self.legends.push(Legend {
+            player_id: player.id,
+            name: format!("{} {}", player.info.first_name, player.info.last_name),
+            team_name: team_name.to_string(),
+            reputation: player.reputation,
+            rating: player.average_skill().rating(),
+            retired_at: tick,
+        });
+        self.legends
+            .sort_by(|a, b| b.reputation.total_cmp(&a.reputation));
+        self.legends.truncate(MAX_LEGENDS);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{History, MAX_LEGENDS};
+    use crate::types::{GameId, PlanetId, PlayerId, TeamId};
+    use crate::world::{player::Player, team::Team, utils::PLANET_DATA};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_history_records() {
+        let mut history = History::default();
+        assert!(history.is_empty());
+
+        let team_a = TeamId::new_v4();
+        let team_b = TeamId::new_v4();
+        history.record_win(team_a, "A", team_b, 0);
+        history.record_win(team_a, "A", team_b, 1);
+        history.record_win(team_b, "B", team_a, 2);
+        let record = history.longest_win_streak.as_ref().unwrap();
+        assert_eq!(record.team_id, team_a);
+        assert_eq!(record.value, 2);
+        assert_eq!(history.win_streaks.get(&team_a), None);
+        assert_eq!(history.win_streaks.get(&team_b), Some(&1));
+
+        history.record_score(GameId::new_v4(), "A", "B", 80, 70, 0);
+        history.record_score(GameId::new_v4(), "B", "A", 60, 50, 1);
+        assert_eq!(history.highest_score.as_ref().unwrap().score, 80);
+
+        let team = Team::random(team_a, PlanetId::new_v4(), "A".into(), "Ship".into());
+        history.record_balance(&team, 0);
+        assert_eq!(history.richest_team.as_ref().unwrap().value, team.balance());
+
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        for idx in 0..MAX_LEGENDS + 5 {
+            let mut player = Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 0.0);
+            player.reputation = idx as f32;
+            history.record_retirement(&player, "A", 0);
+        }
+        assert_eq!(history.legends.len(), MAX_LEGENDS);
+        assert_eq!(history.legends[0].reputation, (MAX_LEGENDS + 4) as f32);
+    }
+}
@@ -0,0 +1,245 @@
+use super::{resources::Resource, world::World};
+use crate::types::{AppResult, GameId, PlanetId, PlayerId, ResourceMap, TeamId};
+use crate::ui::ui_callback::UiCallback;
+use anyhow::anyhow;
+use once_cell::sync::Lazy;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::sync::Mutex;
+
+// Events are dropped, oldest first, if nobody drains the queue.
+pub const MAX_QUEUED_HOOK_EVENTS: usize = 256;
+
+pub type HookFactory = fn() -> Box<dyn WorldHook>;
+
+static HOOK_FACTORIES: Lazy<Mutex<Vec<HookFactory>>> = Lazy::new(|| Mutex::new(vec![]));
+
+/// Entry point for mods: crates embedding the game register their hooks
+/// before starting it, and every new app gets its own instance of them.
+pub fn register_hook_factory(factory: HookFactory) {
+    HOOK_FACTORIES.lock().unwrap().push(factory);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HookEventKind {
+    GameEnded,
+    PlanetVisited,
+    TradeCompleted,
+}
+
+/// World events that mods can subscribe to. They are queued by the world
+/// while ticking and dispatched by the app to the registered hooks.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HookEvent {
+    GameEnded {
+        game_id: GameId,
+        home_team_id: TeamId,
+        away_team_id: TeamId,
+        winner: Option<TeamId>,
+    },
+    PlanetVisited {
+        team_id: TeamId,
+        planet_id: PlanetId,
+    },
+    // Positive amounts are bought by the team, negative ones sold.
+    MarketTradeCompleted {
+        team_id: TeamId,
+        planet_id: PlanetId,
+        resource: Resource,
+        amount: i32,
+    },
+    // Players and resources given by each team, satoshi sweetener included.
+    TeamTradeCompleted {
+        proposer_team_id: TeamId,
+        target_team_id: TeamId,
+        proposer_player_ids: Vec<PlayerId>,
+        target_player_ids: Vec<PlayerId>,
+        proposer_resources: ResourceMap,
+        target_resources: ResourceMap,
+    },
+}
+
+impl HookEvent {
+    pub fn kind(&self) -> HookEventKind {
+        match self {
+            Self::GameEnded { .. } => HookEventKind::GameEnded,
+            Self::PlanetVisited { .. } => HookEventKind::PlanetVisited,
+            Self::MarketTradeCompleted { .. } | Self::TeamTradeCompleted { .. } => {
+                HookEventKind::TradeCompleted
+            }
+        }
+    }
+
+    // The sweetener is paid by the proposer when positive, by the target when negative.
+    pub fn team_trade(
+        proposer_team_id: TeamId,
+        target_team_id: TeamId,
+        proposer_player_ids: &[PlayerId],
+        target_player_ids: &[PlayerId],
+        proposer_resources: &ResourceMap,
+        target_resources: &ResourceMap,
+        extra_satoshis: i64,
+    ) -> Self {
+        let mut proposer_resources = proposer_resources.clone();
+        let mut target_resources = target_resources.clone();
+        let sweetener_payer = if extra_satoshis > 0 {
+            &mut proposer_resources
+        } else {
+            &mut target_resources
+        };
+        if extra_satoshis != 0 {
+            *sweetener_payer.entry(Resource::SATOSHI).or_default() +=
+                extra_satoshis.unsigned_abs() as u32;
+        }
+
+        Self::TeamTradeCompleted {
+            proposer_team_id,
+            target_team_id,
+            proposer_player_ids: proposer_player_ids.to_vec(),
+            target_player_ids: target_player_ids.to_vec(),
+            proposer_resources,
+            target_resources,
+        }
+    }
+}
+
+/// A mod reacting to world events. Hooks only get read access to the world
+/// and act on it by returning callbacks, which are run like the UI ones.
+pub trait WorldHook: Debug + Send {
+    fn name(&self) -> &str;
+    fn subscriptions(&self) -> Vec<HookEventKind>;
+    fn on_event(&mut self, event: &HookEvent, world: &World) -> Vec<UiCallback>;
+}
+
+#[derive(Debug, Default)]
+pub struct HookRegistry {
+    hooks: Vec<Box<dyn WorldHook>>,
+}
+
+impl HookRegistry {
+    // Instantiates the hooks registered by mods.
+    pub fn from_factories() -> AppResult<Self> {
+        let mut registry = Self::default();
+        for factory in HOOK_FACTORIES.lock().unwrap().iter() {
+            registry.register(factory())?;
+        }
+        Ok(registry)
+    }
+
+    pub fn register(&mut self, hook: Box<dyn WorldHook>) -> AppResult<()> {
+        if self.hooks.iter().any(|h| h.name() == hook.name()) {
+            return Err(anyhow!("Hook {} is already registered", hook.name()));
+        }
+        self.hooks.push(hook);
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    pub fn dispatch(&mut self, events: &VecDeque<HookEvent>, world: &World) -> Vec<UiCallback> {
+        let mut callbacks = vec![];
+        for event in events {
+            for hook in self.hooks.iter_mut() {
+                if hook.subscriptions().contains(&event.kind()) {
+                    callbacks.extend(hook.on_event(event, world));
+                }
+            }
+        }
+        callbacks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{register_hook_factory, HookEvent, HookEventKind, HookRegistry, WorldHook};
+    use crate::types::{AppResult, GameId, PlanetId, PlayerId, ResourceMap, TeamId};
+    use crate::ui::ui_callback::UiCallback;
+    use crate::world::resources::Resource;
+    use crate::world::world::World;
+    use std::collections::VecDeque;
+
+    #[derive(Debug)]
+    struct VisitCounter {
+        visits: usize,
+    }
+
+    impl WorldHook for VisitCounter {
+        fn name(&self) -> &str {
+            "visit counter"
+        }
+
+        fn subscriptions(&self) -> Vec<HookEventKind> {
+            vec![HookEventKind::PlanetVisited]
+        }
+
+        fn on_event(&mut self, _event: &HookEvent, _world: &World) -> Vec<UiCallback> {
+            self.visits += 1;
+            vec![UiCallback::None]
+        }
+    }
+
+    #[test]
+    fn test_hook_dispatch() -> AppResult<()> {
+        let mut registry = HookRegistry::default();
+        registry.register(Box::new(VisitCounter { visits: 0 }))?;
+        assert!(registry
+            .register(Box::new(VisitCounter { visits: 0 }))
+            .is_err());
+
+        let world = World::new(None);
+        let events = VecDeque::from([
+            HookEvent::PlanetVisited {
+                team_id: TeamId::new_v4(),
+                planet_id: PlanetId::new_v4(),
+            },
+            HookEvent::GameEnded {
+                game_id: GameId::new_v4(),
+                home_team_id: TeamId::new_v4(),
+                away_team_id: TeamId::new_v4(),
+                winner: None,
+            },
+        ]);
+        let callbacks = registry.dispatch(&events, &world);
+        assert_eq!(callbacks.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hook_factories() -> AppResult<()> {
+        register_hook_factory(|| Box::new(VisitCounter { visits: 0 }));
+        let registry = HookRegistry::from_factories()?;
+        assert!(!registry.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_team_trade_event() {
+        let proposer_player_ids = [PlayerId::new_v4()];
+        let proposer_resources = ResourceMap::from([(Resource::SCRAPS, 10)]);
+        let event = HookEvent::team_trade(
+            TeamId::new_v4(),
+            TeamId::new_v4(),
+            &proposer_player_ids,
+            &[],
+            &proposer_resources,
+            &ResourceMap::new(),
+            -500,
+        );
+        assert_eq!(event.kind(), HookEventKind::TradeCompleted);
+
+        let HookEvent::TeamTradeCompleted {
+            proposer_resources,
+            target_resources,
+            ..
+        } = event
+        else {
+            panic!("Should be a team trade");
+        };
+        assert_eq!(proposer_resources.get(&Resource::SATOSHI), None);
+        assert_eq!(target_resources.get(&Resource::SATOSHI), Some(&500));
+    }
+}
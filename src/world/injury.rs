@@ -0,0 +1,78 @@
+use super::constants::*;
+use crate::types::Tick;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use strum::Display;
+
+#[derive(Debug, Clone, Copy, Display, Serialize_repr, Deserialize_repr, PartialEq, Hash)]
+#[repr(u8)]
+pub enum InjuryKind {
+    Bruise,
+    Sprain,
+    Fracture,
+}
+
+impl InjuryKind {
+    pub fn random(rng: &mut ChaCha8Rng) -> Self {
+        match rng.gen_range(0..10) {
+            0 => Self::Fracture,
+            1..=3 => Self::Sprain,
+            _ => Self::Bruise,
+        }
+    }
+
+    pub fn recovery_time(&self) -> Tick {
+        match self {
+            Self::Bruise => 12 * HOURS,
+            Self::Sprain => 2 * DAYS,
+            Self::Fracture => 5 * DAYS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Injury {
+    pub kind: InjuryKind,
+    pub recovery_time_left: Tick,
+}
+
+impl Injury {
+    pub fn new(kind: InjuryKind) -> Self {
+        Self {
+            kind,
+            recovery_time_left: kind.recovery_time(),
+        }
+    }
+
+    // The doctor bonus speeds up the recovery.
+    // Returns true if the injury is healed.
+    pub fn recover(&mut self, elapsed: Tick, bonus: f32) -> bool {
+        let recovery = (elapsed as f32 * bonus) as Tick;
+        self.recovery_time_left = self.recovery_time_left.saturating_sub(recovery);
+        self.recovery_time_left == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Injury, InjuryKind};
+    use crate::world::constants::HOURS;
+
+    #[test]
+    fn test_injury_recovery() {
+        let mut injury = Injury::new(InjuryKind::Bruise);
+        assert_eq!(injury.recovery_time_left, 12 * HOURS);
+
+        assert!(!injury.recover(4 * HOURS, 1.0));
+        assert_eq!(injury.recovery_time_left, 8 * HOURS);
+
+        // A good doctor makes the recovery faster.
+        assert!(!injury.recover(2 * HOURS, 2.0));
+        assert_eq!(injury.recovery_time_left, 4 * HOURS);
+
+        assert!(injury.recover(5 * HOURS, 1.0));
+        assert_eq!(injury.recovery_time_left, 0);
+    }
+}
@@ -0,0 +1,116 @@
+use super::{
+    constants::DAYS,
+    resources::Resource,
+    spaceship::{Spaceship, SpaceshipUpgrade},
+};
+use crate::types::{ResourceMap, StorableResourceMap, Tick};
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use strum_macros::{Display, EnumIter};
+
+pub const INSURANCE_TERM: Tick = 7 * DAYS;
+// Share of the loss refunded by the insurer, after the deductible.
+pub const INSURANCE_COVERAGE: f32 = 0.75;
+pub const INSURANCE_DEDUCTIBLE: u32 = 250;
+
+#[derive(
+    Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Eq, Hash, Display, EnumIter,
+)]
+#[repr(u8)]
+pub enum InsuranceKind {
+    Hull,
+    Cargo,
+}
+
+impl InsuranceKind {
+    // Weekly premium as a share of the spaceship value.
+    fn premium_rate(&self) -> f32 {
+        match self {
+            Self::Hull => 0.02,
+            Self::Cargo => 0.01,
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Hull => "Covers the hull damage taken in space adventures and travel hazards.",
+            Self::Cargo => "Covers the cargo lost when the spaceship is wrecked in space.",
+        }
+    }
+
+    pub fn premium(&self, spaceship: &Spaceship) -> u32 {
+        (spaceship.cost() as f32 * self.premium_rate()).round() as u32
+    }
+}
+
+/// A policy paid upfront, covering the losses of its kind until it expires.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct InsurancePolicy {
+    pub kind: InsuranceKind,
+    pub expires_at: Tick,
+}
+
+impl InsurancePolicy {
+    pub fn new(kind: InsuranceKind, current_tick: Tick) -> Self {
+        Self {
+            kind,
+            expires_at: current_tick + INSURANCE_TERM,
+        }
+    }
+
+    pub fn is_active(&self, current_tick: Tick) -> bool {
+        current_tick <= self.expires_at
+    }
+}
+
+// Damage is valued at the shipyard repair price.
+pub fn hull_loss_value(durability_lost: u32) -> u32 {
+    durability_lost * SpaceshipUpgrade::REPAIR_BASE_COST
+}
+
+// Cargo is valued at the resources base price, satoshi excluded.
+pub fn cargo_loss_value(cargo: &ResourceMap) -> u32 {
+    [
+        Resource::GOLD,
+        Resource::SCRAPS,
+        Resource::RUM,
+        Resource::FUEL,
+    ]
+    .iter()
+    .map(|resource| (cargo.value(resource) as f32 * resource.base_price()) as u32)
+    .sum()
+}
+
+pub fn insurance_payout(loss_value: u32) -> u32 {
+    (loss_value.saturating_sub(INSURANCE_DEDUCTIBLE) as f32 * INSURANCE_COVERAGE) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        cargo_loss_value, hull_loss_value, insurance_payout, InsuranceKind, InsurancePolicy,
+        INSURANCE_DEDUCTIBLE, INSURANCE_TERM,
+    };
+    use crate::types::{ResourceMap, StorableResourceMap};
+    use crate::world::{resources::Resource, spaceship::SpaceshipUpgrade};
+
+    #[test]
+    fn test_insurance_claims() {
+        let policy = InsurancePolicy::new(InsuranceKind::Hull, 0);
+        assert!(policy.is_active(INSURANCE_TERM));
+        assert!(!policy.is_active(INSURANCE_TERM + 1));
+
+        assert_eq!(hull_loss_value(10), 10 * SpaceshipUpgrade::REPAIR_BASE_COST);
+        assert_eq!(insurance_payout(INSURANCE_DEDUCTIBLE), 0);
+        assert_eq!(insurance_payout(INSURANCE_DEDUCTIBLE + 1_000), 750);
+
+        let mut cargo = ResourceMap::new();
+        cargo.saturating_add(Resource::SATOSHI, 10_000, u32::MAX);
+        assert_eq!(cargo_loss_value(&cargo), 0);
+        cargo.saturating_add(Resource::GOLD, 2, u32::MAX);
+        assert_eq!(
+            cargo_loss_value(&cargo),
+            2 * Resource::GOLD.base_price() as u32
+        );
+    }
+}
@@ -27,6 +27,16 @@ impl JerseyStyle {
         }
     }
 
+    // Cycles through the styles available at creation.
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Classic => Self::Stripe,
+            Self::Stripe => Self::Fancy,
+            Self::Fancy => Self::Gilet,
+            _ => Self::Classic,
+        }
+    }
+
     pub fn is_available_at_creation(&self) -> bool {
         match self {
             Self::Pirate => false,
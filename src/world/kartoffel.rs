@@ -1,20 +1,122 @@
-use super::{planet::Planet, types::KartoffelLocation};
-use crate::types::{KartoffelId, TeamId};
+use super::{
+    constants::HOURS,
+    planet::Planet,
+    player::Player,
+    resources::Resource,
+    team::Team,
+    types::{KartoffelLocation, TrainingFocus},
+};
+use crate::types::{AppResult, KartoffelId, PlayerId, StorableResourceMap, TeamId, Tick};
+use anyhow::anyhow;
 use libp2p::PeerId;
+use rand::{distributions::WeightedIndex, prelude::Distribution, seq::SliceRandom};
 use rand_chacha::ChaCha8Rng;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
+use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumIter};
 
-#[derive(Debug, Default, Serialize_repr, Deserialize_repr, Clone, PartialEq)]
+// Probability per hour of exploration to find a kartoffel.
+pub const KARTOFFEL_DISCOVERY_PROBABILITY: f64 = 0.05;
+pub const MAX_KARTOFFELN_PER_TEAM: usize = 8;
+pub const MAX_KARTOFFEL_SATIETY: f32 = 10.0;
+pub const KARTOFFEL_SATIETY_PER_RUM: f32 = 4.0;
+pub const KARTOFFEL_HUNGER_PER_LONG_TICK: f32 = 1.0;
+// Kartoffeln cannot be fed again until they digest.
+pub const KARTOFFEL_FEEDING_COOLDOWN: Tick = 6 * HOURS;
+
+const KARTOFFEL_NAMES: [&str; 12] = [
+    "Knolle",
+    "Spud",
+    "Tater",
+    "Erdapfel",
+    "Patata",
+    "Bintje",
+    "Russet",
+    "Yukon",
+    "Nicola",
+    "Linda",
+    "Annabelle",
+    "Gnocco",
+];
+
+#[derive(
+    Debug, Default, Serialize_repr, Deserialize_repr, Clone, Copy, PartialEq, Display, EnumIter,
+)]
 #[repr(u8)]
 pub enum KartoffelRarity {
     #[default]
+    #[strum(to_string = "Common")]
     COMMON,
+    #[strum(to_string = "Uncommon")]
     UNCOMMON,
+    #[strum(to_string = "Rare")]
     RARE,
+    #[strum(to_string = "Legendary")]
     LEGENDARY,
 }
 
+impl KartoffelRarity {
+    fn discovery_weight(&self) -> u32 {
+        match self {
+            Self::COMMON => 60,
+            Self::UNCOMMON => 25,
+            Self::RARE => 12,
+            Self::LEGENDARY => 3,
+        }
+    }
+
+    pub fn random(rng: &mut ChaCha8Rng) -> Self {
+        let rarities = Self::iter().collect::<Vec<Self>>();
+        let weights = rarities
+            .iter()
+            .map(|rarity| rarity.discovery_weight())
+            .collect::<Vec<u32>>();
+        match WeightedIndex::new(&weights) {
+            Ok(distribution) => rarities[distribution.sample(rng)],
+            Err(_) => Self::default(),
+        }
+    }
+
+    // Skill bonus given by a well fed kartoffel to the skills in its focus.
+    pub fn skill_bonus(&self) -> f32 {
+        match self {
+            Self::COMMON => 0.5,
+            Self::UNCOMMON => 1.0,
+            Self::RARE => 1.5,
+            Self::LEGENDARY => 2.5,
+        }
+    }
+
+    // Reference price when offering the kartoffel to another team.
+    pub fn value(&self) -> u32 {
+        match self {
+            Self::COMMON => 2_000,
+            Self::UNCOMMON => 6_000,
+            Self::RARE => 20_000,
+            Self::LEGENDARY => 75_000,
+        }
+    }
+}
+
+/// Passive bonus to a skill group, given by a kartoffel companion to the player it is assigned to.
+/// It is stored on the player so that it is shared over the network with the rest of the player data.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct CompanionBonus {
+    pub focus: TrainingFocus,
+    pub amount: f32,
+}
+
+impl CompanionBonus {
+    pub fn apply(&self, player: &mut Player) {
+        for idx in 0..20 {
+            if self.focus.is_focus(idx) {
+                player.modify_skill(idx, self.amount);
+            }
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Kartoffel {
     pub id: KartoffelId,
@@ -25,16 +127,34 @@ pub struct Kartoffel {
     pub team: Option<TeamId>,
     pub filename: String,
     pub current_location: KartoffelLocation,
+    #[serde(default)]
+    pub focus: TrainingFocus,
+    #[serde(default)]
+    pub satiety: f32,
+    #[serde(default)]
+    pub last_fed: Tick,
+    #[serde(default)]
+    pub assigned_to: Option<PlayerId>,
 }
 
 impl Kartoffel {
-    pub fn random(_rng: &mut ChaCha8Rng, id: KartoffelId, home_planet: &Planet) -> Self {
-        let mut name = id.to_string();
-        name.truncate(6);
+    pub fn random(rng: &mut ChaCha8Rng, id: KartoffelId, home_planet: &Planet) -> Self {
+        let name = format!(
+            "{} {}",
+            KARTOFFEL_NAMES
+                .choose(rng)
+                .expect("There should be a kartoffel name"),
+            home_planet.name
+        );
+        let focus = *TrainingFocus::iter()
+            .collect::<Vec<TrainingFocus>>()
+            .choose(rng)
+            .expect("There should be a training focus");
+
         Self {
             id,
             peer_id: None,
-            rarity: KartoffelRarity::default(),
+            rarity: KartoffelRarity::random(rng),
             version: 0,
             name,
             team: None,
@@ -42,6 +162,98 @@ impl Kartoffel {
             current_location: KartoffelLocation::OnPlanet {
                 planet_id: home_planet.id,
             },
+            focus,
+            satiety: MAX_KARTOFFEL_SATIETY / 2.0,
+            last_fed: 0,
+            assigned_to: None,
+        }
+    }
+
+    // Hungry kartoffeln give only half of their bonus.
+    pub fn companion_bonus(&self) -> CompanionBonus {
+        CompanionBonus {
+            focus: self.focus,
+            amount: self.rarity.skill_bonus() * (0.5 + 0.5 * self.satiety / MAX_KARTOFFEL_SATIETY),
+        }
+    }
+
+    pub fn can_be_fed_by(&self, team: &Team, current_tick: Tick) -> AppResult<()> {
+        if self.team != Some(team.id) {
+            return Err(anyhow!("Kartoffel is not in the team"));
+        }
+
+        if self.satiety >= MAX_KARTOFFEL_SATIETY {
+            return Err(anyhow!("{} is not hungry", self.name));
+        }
+
+        if self.last_fed > 0 && current_tick < self.last_fed + KARTOFFEL_FEEDING_COOLDOWN {
+            return Err(anyhow!("{} is digesting", self.name));
+        }
+
+        if team.resources.value(&Resource::RUM) == 0 {
+            return Err(anyhow!("No rum to feed"));
+        }
+
+        Ok(())
+    }
+
+    pub fn feed(&mut self, current_tick: Tick) {
+        self.satiety = (self.satiety + KARTOFFEL_SATIETY_PER_RUM).min(MAX_KARTOFFEL_SATIETY);
+        self.last_fed = current_tick;
+    }
+
+    pub fn starve(&mut self) {
+        self.satiety = (self.satiety - KARTOFFEL_HUNGER_PER_LONG_TICK).max(0.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Kartoffel, MAX_KARTOFFEL_SATIETY};
+    use crate::types::{KartoffelId, PlayerId, StorableResourceMap, TeamId};
+    use crate::world::{
+        planet::Planet, player::Player, resources::Resource, team::Team, utils::PLANET_DATA,
+    };
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_kartoffel_companion() {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let planet = Planet::default();
+        let mut team = Team::random(TeamId::new_v4(), planet.id, "A".into(), "A".into());
+        let mut kartoffel = Kartoffel::random(rng, KartoffelId::new_v4(), &planet);
+        kartoffel.team = Some(team.id);
+
+        // Fed kartoffeln give a higher bonus.
+        let hungry_bonus = kartoffel.companion_bonus();
+        assert!(kartoffel.can_be_fed_by(&team, 100).is_err());
+        team.resources
+            .add(Resource::RUM, 1, team.storage_capacity())
+            .unwrap();
+        assert!(kartoffel.can_be_fed_by(&team, 100).is_ok());
+        kartoffel.feed(100);
+        assert!(kartoffel.companion_bonus().amount > hungry_bonus.amount);
+        assert!(kartoffel.can_be_fed_by(&team, 101).is_err());
+
+        kartoffel.satiety = MAX_KARTOFFEL_SATIETY;
+        for _ in 0..20 {
+            kartoffel.starve();
+        }
+        assert_eq!(kartoffel.satiety, 0.0);
+
+        let mut player = Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 0.0);
+        let skills = player.current_skill_array();
+        kartoffel.companion_bonus().apply(&mut player);
+        let boosted_skills = player.current_skill_array();
+        let mut focus_increase = 0.0;
+        for idx in 0..20 {
+            if kartoffel.focus.is_focus(idx) {
+                focus_increase += boosted_skills[idx] - skills[idx];
+            } else {
+                assert_eq!(boosted_skills[idx], skills[idx]);
+            }
         }
+        assert!(focus_increase > 0.0);
     }
 }
@@ -0,0 +1,131 @@
+use super::{constants::DAYS, resources::Resource, team::Team};
+use crate::types::{PlanetId, ResourceMap, StorableResourceMap, Tick};
+use serde::{Deserialize, Serialize};
+
+// Amount borrowed or repaid at once from the finances view.
+pub const LOAN_STEP: u32 = 10_000;
+pub const LOAN_TERM: Tick = 14 * DAYS;
+// Share of the debt charged as interest every day.
+pub const LOAN_DAILY_INTEREST: f32 = 0.01;
+pub const LOAN_DEFAULT_REPUTATION_MALUS: f32 = 4.0;
+const BASE_LOAN_LIMIT: u32 = 50_000;
+const LOAN_LIMIT_PER_REPUTATION: u32 = 10_000;
+// Resources seized by the bank on default, after the satoshi.
+const REPOSSESSED_RESOURCES: [Resource; 4] = [
+    Resource::GOLD,
+    Resource::RUM,
+    Resource::SCRAPS,
+    Resource::FUEL,
+];
+
+/// Satoshi borrowed from the bank of a planet. Interest is charged daily on the debt,
+/// which must be repaid before the due date or the bank repossesses the team assets.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Loan {
+    pub planet_id: PlanetId,
+    pub principal: u32,
+    pub debt: u32,
+    pub due_at: Tick,
+    pub last_interest_at: Tick,
+}
+
+impl Loan {
+    pub fn new(planet_id: PlanetId, amount: u32, current_tick: Tick) -> Self {
+        Self {
+            planet_id,
+            principal: amount,
+            debt: amount,
+            due_at: current_tick + LOAN_TERM,
+            last_interest_at: current_tick,
+        }
+    }
+
+    // Borrowing more from the same bank keeps the original due date.
+    pub fn increase(&mut self, amount: u32) {
+        self.principal += amount;
+        self.debt += amount;
+    }
+
+    pub fn daily_interest(&self) -> u32 {
+        (self.debt as f32 * LOAN_DAILY_INTEREST).ceil() as u32
+    }
+
+    // Days of interest to charge since the last time.
+    pub fn interest_days(&self, current_tick: Tick) -> u32 {
+        (current_tick.saturating_sub(self.last_interest_at) / DAYS) as u32
+    }
+
+    pub fn is_overdue(&self, current_tick: Tick) -> bool {
+        current_tick > self.due_at
+    }
+}
+
+// Banks lend more to teams with a good reputation.
+pub fn max_loan_amount(team: &Team) -> u32 {
+    BASE_LOAN_LIMIT + LOAN_LIMIT_PER_REPUTATION * team.reputation.max(0.0) as u32
+}
+
+// Takes satoshi first, then resources at their base price, until the debt is covered.
+// Returns the seized resources and the part of the debt left uncovered.
+pub fn repossess(resources: &mut ResourceMap, debt: u32) -> (ResourceMap, u32) {
+    let mut seized = ResourceMap::new();
+    let mut debt = debt;
+
+    let satoshi = resources.value(&Resource::SATOSHI).min(debt);
+    if satoshi > 0 {
+        resources.saturating_sub(Resource::SATOSHI, satoshi);
+        seized.insert(Resource::SATOSHI, satoshi);
+        debt -= satoshi;
+    }
+
+    for resource in REPOSSESSED_RESOURCES {
+        if debt == 0 {
+            break;
+        }
+        let unit_price = resource.base_price() as u32;
+        let amount = resources.value(&resource).min(debt.div_ceil(unit_price));
+        if amount == 0 {
+            continue;
+        }
+        resources.saturating_sub(resource, amount);
+        seized.insert(resource, amount);
+        debt = debt.saturating_sub(amount * unit_price);
+    }
+
+    (seized, debt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{repossess, Loan, LOAN_TERM};
+    use crate::types::{PlanetId, ResourceMap, StorableResourceMap};
+    use crate::world::{constants::DAYS, resources::Resource};
+
+    #[test]
+    fn test_loan_interest_and_repossession() {
+        let mut loan = Loan::new(PlanetId::new_v4(), 10_000, 0);
+        assert_eq!(loan.daily_interest(), 100);
+        assert_eq!(loan.interest_days(DAYS - 1), 0);
+        assert_eq!(loan.interest_days(3 * DAYS), 3);
+        loan.increase(5_000);
+        assert_eq!(loan.debt, 15_000);
+        assert_eq!(loan.due_at, LOAN_TERM);
+        assert!(!loan.is_overdue(LOAN_TERM));
+        assert!(loan.is_overdue(LOAN_TERM + 1));
+
+        let mut resources = ResourceMap::new();
+        resources.saturating_add(Resource::SATOSHI, 1_000, u32::MAX);
+        resources.saturating_add(Resource::GOLD, 2, u32::MAX);
+        resources.saturating_add(Resource::RUM, 10, u32::MAX);
+
+        let (seized, unpaid) = repossess(&mut resources, 4_000);
+        assert_eq!(unpaid, 0);
+        assert_eq!(seized.value(&Resource::SATOSHI), 1_000);
+        assert_eq!(seized.value(&Resource::GOLD), 2);
+        assert_eq!(resources.value(&Resource::RUM), 10);
+
+        let (seized, unpaid) = repossess(&mut resources, 2_000);
+        assert_eq!(seized.value(&Resource::RUM), 10);
+        assert_eq!(unpaid, 750);
+    }
+}
@@ -0,0 +1,215 @@
+use super::{constants::HOURS, resources::Resource};
+use crate::types::Tick;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::{collections::HashMap, fmt::Display};
+
+pub const MARKET_UPDATE_INTERVAL: Tick = 1 * HOURS;
+pub const MAX_PRICE_HISTORY: usize = 24;
+pub const MARKET_RESOURCES: [Resource; 4] = [
+    Resource::FUEL,
+    Resource::GOLD,
+    Resource::SCRAPS,
+    Resource::RUM,
+];
+const MIN_PRICE_MODIFIER: f32 = 0.5;
+const MAX_PRICE_MODIFIER: f32 = 2.0;
+// Fraction of the distance to the base price recovered at each update.
+const MEAN_REVERSION: f32 = 0.1;
+const RANDOM_DRIFT: f32 = 0.03;
+// Price change for each unit traded, relative to the storing space of the resource.
+const TRADE_IMPACT: f32 = 0.002;
+const MARKET_EVENT_PROBABILITY: f64 = 0.002;
+const MARKET_EVENT_DURATION: Tick = 12 * HOURS;
+
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq)]
+#[repr(u8)]
+pub enum MarketEventKind {
+    Shortage,
+    Surplus,
+}
+
+impl MarketEventKind {
+    pub fn price_multiplier(&self) -> f32 {
+        match self {
+            Self::Shortage => 1.8,
+            Self::Surplus => 0.6,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct MarketEvent {
+    pub resource: Resource,
+    pub kind: MarketEventKind,
+    pub until: Tick,
+}
+
+impl Display for MarketEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let resource = self.resource.to_string().to_lowercase();
+        match self.kind {
+            MarketEventKind::Shortage => write!(f, "{} shortage", resource),
+            MarketEventKind::Surplus => write!(f, "{} surplus", resource),
+        }
+    }
+}
+
+/// Supply and demand state of a planet market.
+/// Prices drift randomly, react to trades and slowly revert to the base price.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PlanetMarket {
+    pub price_modifiers: HashMap<Resource, f32>,
+    pub event: Option<MarketEvent>,
+    // Buy prices sampled at every update, oldest first.
+    pub price_history: HashMap<Resource, Vec<u32>>,
+    pub last_update: Tick,
+}
+
+impl PlanetMarket {
+    pub fn price_modifier(&self, resource: Resource) -> f32 {
+        let modifier = self.price_modifiers.get(&resource).copied().unwrap_or(1.0);
+        match self.event {
+            Some(event) if event.resource == resource => modifier * event.kind.price_multiplier(),
+            _ => modifier,
+        }
+    }
+
+    fn set_price_modifier(&mut self, resource: Resource, modifier: f32) {
+        self.price_modifiers.insert(
+            resource,
+            modifier.clamp(MIN_PRICE_MODIFIER, MAX_PRICE_MODIFIER),
+        );
+    }
+
+    // Buying (positive amount) raises the price, selling lowers it.
+    pub fn register_trade(&mut self, resource: Resource, amount: i32) {
+        let impact = TRADE_IMPACT * amount as f32 * resource.to_storing_space().max(1) as f32;
+        let modifier = self.price_modifiers.get(&resource).copied().unwrap_or(1.0);
+        self.set_price_modifier(resource, modifier * (1.0 + impact));
+    }
+
+    pub fn drift(&mut self, rng: &mut ChaCha8Rng) {
+        for resource in MARKET_RESOURCES {
+            let modifier = self.price_modifiers.get(&resource).copied().unwrap_or(1.0);
+            let reverted = modifier + (1.0 - modifier) * MEAN_REVERSION;
+            let noise = rng.gen_range(-RANDOM_DRIFT..RANDOM_DRIFT);
+            self.set_price_modifier(resource, reverted + noise);
+        }
+    }
+
+    // Expires the current event and possibly starts a new one, which is returned.
+    pub fn tick_event(&mut self, current_tick: Tick, rng: &mut ChaCha8Rng) -> Option<MarketEvent> {
+        if let Some(event) = self.event {
+            if current_tick < event.until {
+                return None;
+            }
+            self.event = None;
+        }
+
+        if !rng.gen_bool(MARKET_EVENT_PROBABILITY) {
+            return None;
+        }
+
+        let resource = MARKET_RESOURCES[rng.gen_range(0..MARKET_RESOURCES.len())];
+        let kind = if rng.gen_bool(0.5) {
+            MarketEventKind::Shortage
+        } else {
+            MarketEventKind::Surplus
+        };
+        let event = MarketEvent {
+            resource,
+            kind,
+            until: current_tick + MARKET_EVENT_DURATION,
+        };
+        self.event = Some(event);
+        Some(event)
+    }
+
+    pub fn record_price(&mut self, resource: Resource, price: u32) {
+        let history = self.price_history.entry(resource).or_insert(vec![]);
+        history.push(price);
+        if history.len() > MAX_PRICE_HISTORY {
+            history.remove(0);
+        }
+    }
+
+    pub fn price_history(&self, resource: Resource) -> &[u32] {
+        self.price_history
+            .get(&resource)
+            .map(|history| history.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        MarketEventKind, PlanetMarket, MARKET_EVENT_DURATION, MAX_PRICE_HISTORY, MAX_PRICE_MODIFIER,
+    };
+    use crate::world::resources::Resource;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_market_reacts_to_trades() {
+        let mut market = PlanetMarket::default();
+        assert_eq!(market.price_modifier(Resource::RUM), 1.0);
+
+        market.register_trade(Resource::RUM, 50);
+        let after_buy = market.price_modifier(Resource::RUM);
+        assert!(after_buy > 1.0);
+
+        market.register_trade(Resource::RUM, -100);
+        assert!(market.price_modifier(Resource::RUM) < after_buy);
+
+        // Prices are bounded.
+        market.register_trade(Resource::GOLD, 100_000);
+        assert_eq!(market.price_modifier(Resource::GOLD), MAX_PRICE_MODIFIER);
+
+        // And revert towards the base price over time.
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        for _ in 0..100 {
+            market.drift(rng);
+        }
+        assert!((market.price_modifier(Resource::GOLD) - 1.0).abs() < 0.2);
+    }
+
+    #[test]
+    fn test_market_events() {
+        let mut market = PlanetMarket::default();
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let mut tick = 0;
+        let event = loop {
+            if let Some(event) = market.tick_event(tick, rng) {
+                break event;
+            }
+            tick += 1;
+        };
+
+        let expected = match event.kind {
+            MarketEventKind::Shortage => 1.8,
+            MarketEventKind::Surplus => 0.6,
+        };
+        assert_eq!(market.price_modifier(event.resource), expected);
+        assert!(market.tick_event(tick + 1, rng).is_none());
+
+        // Events expire.
+        market.tick_event(tick + MARKET_EVENT_DURATION, rng);
+        assert_ne!(market.event, Some(event));
+
+        for price in 0..2 * MAX_PRICE_HISTORY as u32 {
+            market.record_price(Resource::FUEL, price);
+        }
+        assert_eq!(
+            market.price_history(Resource::FUEL).len(),
+            MAX_PRICE_HISTORY
+        );
+        assert_eq!(
+            market.price_history(Resource::FUEL)[0],
+            MAX_PRICE_HISTORY as u32
+        );
+    }
+}
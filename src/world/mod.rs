@@ -1,14 +1,53 @@
+pub mod academy;
+pub mod action_queue;
+pub mod advisor;
+pub mod ai_management;
+pub mod calendar;
+pub mod campaign;
+pub mod catch_up;
 pub mod constants;
+pub mod contract;
+pub mod custom_names;
+pub mod discovery;
+pub mod duel;
+pub mod elo;
+pub mod emblem;
+pub mod epithet;
+pub mod events;
+pub mod finances;
+pub mod game_over;
+pub mod generation;
+pub mod gossip;
+pub mod hazards;
+pub mod history;
+pub mod hooks;
+pub mod injury;
+pub mod insurance;
 pub mod jersey;
 pub mod kartoffel;
+pub mod loan;
+pub mod market;
+pub mod mutiny;
+pub mod notifications;
 pub mod planet;
 pub mod player;
 pub mod position;
 pub mod resources;
 pub mod role;
+pub mod route;
+pub mod salvage;
+pub mod scanner;
+pub mod scouting;
 pub mod skill;
+pub mod space_upgrades;
 pub mod spaceship;
+pub mod staff;
 pub mod team;
+pub mod tournament;
+pub mod training;
+pub mod traits;
+pub mod tutorial;
 pub mod types;
 pub mod utils;
 pub mod world;
+pub mod wormhole;
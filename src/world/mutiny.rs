@@ -0,0 +1,34 @@
+use crate::types::Tick;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use strum::Display;
+
+#[derive(Debug, Clone, Copy, Display, Serialize_repr, Deserialize_repr, PartialEq, Hash)]
+#[repr(u8)]
+pub enum MutinyKind {
+    Strike,
+    Theft,
+    Desertion,
+}
+
+impl MutinyKind {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Strike => "The crew refuses to play until the captain makes amends.",
+            Self::Theft => "Some pirates broke into the hold and stole part of the treasury.",
+            Self::Desertion => "The most demoralized pirates left the crew together.",
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CrewLogEntry {
+    pub tick: Tick,
+    pub message: String,
+}
+
+impl CrewLogEntry {
+    pub fn new(tick: Tick, message: String) -> Self {
+        Self { tick, message }
+    }
+}
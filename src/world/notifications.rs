@@ -0,0 +1,68 @@
+use crate::types::Tick;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use strum_macros::{Display, EnumIter};
+
+pub const MAX_NOTIFICATIONS: usize = 200;
+
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Display, EnumIter)]
+#[repr(u8)]
+pub enum NotificationCategory {
+    Games,
+    Trades,
+    Challenges,
+    Exploration,
+    Market,
+}
+
+impl NotificationCategory {
+    // Cycles through the categories, None meaning no filter.
+    pub fn next_filter(filter: Option<Self>) -> Option<Self> {
+        match filter {
+            None => Some(Self::Games),
+            Some(Self::Games) => Some(Self::Trades),
+            Some(Self::Trades) => Some(Self::Challenges),
+            Some(Self::Challenges) => Some(Self::Exploration),
+            Some(Self::Exploration) => Some(Self::Market),
+            Some(Self::Market) => None,
+        }
+    }
+}
+
+/// Persistent record of something the player may have missed in a popup.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Notification {
+    pub tick: Tick,
+    pub category: NotificationCategory,
+    pub message: String,
+}
+
+impl Notification {
+    pub fn new(tick: Tick, category: NotificationCategory, message: String) -> Self {
+        Self {
+            tick,
+            category,
+            message,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NotificationCategory;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_next_filter_cycles_all_categories() {
+        let mut filter = None;
+        let mut seen = vec![];
+        loop {
+            filter = NotificationCategory::next_filter(filter);
+            match filter {
+                Some(category) => seen.push(category),
+                None => break,
+            }
+        }
+        assert_eq!(seen, NotificationCategory::iter().collect::<Vec<_>>());
+    }
+}
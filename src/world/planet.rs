@@ -1,6 +1,4 @@
-use super::constants::HOURS;
-use super::{resources::Resource, skill::MAX_SKILL, types::Population};
-use crate::types::{SystemTimeTick, Tick};
+use super::{market::PlanetMarket, resources::Resource, skill::MAX_SKILL, types::Population};
 use crate::world::skill::GameSkill;
 use crate::world::utils::is_default;
 use crate::{
@@ -21,7 +19,8 @@ use strum_macros::{Display, EnumIter};
 
 const TRADE_DELTA_SCARCITY: f32 = 3.0;
 const TRADE_DELTA_BUY_SELL: f32 = 0.07;
-const RESOURCE_PRICE_REFRESH_RATE_MILLIS: Tick = 2 * HOURS;
+// Production is not collected forever: storage on the asteroid is limited.
+pub const MAX_PENDING_PRODUCTION_DAYS: u32 = 7;
 
 #[derive(Debug, Display, Clone, Serialize_repr, Deserialize_repr, PartialEq, Default, EnumIter)]
 #[repr(u8)]
@@ -63,6 +62,16 @@ pub struct Planet {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub custom_radio_stream: Option<String>,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub facilities: Vec<AsteroidFacility>,
+    // Resources produced on an owned asteroid, waiting for the team to dock there.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub pending_production: ResourceMap,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub market: PlanetMarket,
 }
 
 impl Planet {
@@ -75,16 +84,14 @@ impl Planet {
         let amount_modifier =
             relative_amount / TRADE_DELTA_SCARCITY + (1.0 - relative_amount) * TRADE_DELTA_SCARCITY;
 
-        let random_fluctuation =
-            0.2 * ((Tick::now() / RESOURCE_PRICE_REFRESH_RATE_MILLIS) as f32).sin();
-
         let mut s = DefaultHasher::new();
         self.name.hash(&mut s);
         let planet_fluctation = 0.05 * (s.finish() as f32).sin();
 
         let price = resource.base_price()
             * amount_modifier
-            * (1.0 + random_fluctuation + planet_fluctation);
+            * self.market.price_modifier(resource)
+            * (1.0 + planet_fluctation);
         log::debug!(
             "Calculated price for {} (amount={}): {} * {} = {}",
             resource,
@@ -166,6 +173,130 @@ impl Planet {
             team_ids: vec![],
             //TODO: add option to customize asteroid radio stream
             custom_radio_stream: None,
+            facilities: vec![],
+            pending_production: HashMap::new(),
+            market: PlanetMarket::default(),
+        }
+    }
+
+    pub fn has_facility(&self, facility: AsteroidFacility) -> bool {
+        self.facilities.contains(&facility)
+    }
+
+    // Daily production of an asteroid orbiting a planet of the given type.
+    pub fn daily_production(&self, parent_type: &PlanetType) -> ResourceMap {
+        let mut production = ResourceMap::new();
+        if self.planet_type != PlanetType::Asteroid {
+            return production;
+        }
+
+        let base = parent_type.asteroid_base_production();
+        if let Some((resource, amount)) = base {
+            production.insert(resource, amount);
+        }
+
+        for facility in self.facilities.iter() {
+            let (resource, mut amount) = facility.production();
+            // Facilities exploiting the natural resource of the asteroid are more efficient.
+            if base.map(|(r, _)| r) == Some(resource) {
+                amount *= 2;
+            }
+            *production.entry(resource).or_insert(0) += amount;
+        }
+        production
+    }
+
+    pub fn accumulate_production(&mut self, production: &ResourceMap) {
+        for (resource, amount) in production.iter() {
+            let max_amount = amount * MAX_PENDING_PRODUCTION_DAYS;
+            let pending = self.pending_production.entry(*resource).or_insert(0);
+            *pending = (*pending + amount).min(max_amount);
+        }
+    }
+}
+
+impl PlanetType {
+    fn asteroid_base_production(&self) -> Option<(Resource, u32)> {
+        match self {
+            Self::Lava | Self::Rocky | Self::Ring => Some((Resource::SCRAPS, 3)),
+            Self::Earth | Self::Islands | Self::Wet => Some((Resource::RUM, 1)),
+            Self::Ice | Self::Gas => Some((Resource::SCRAPS, 1)),
+            Self::Sol | Self::BlackHole => Some((Resource::GOLD, 1)),
+            Self::Asteroid => None,
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Hash, EnumIter)]
+#[repr(u8)]
+pub enum AsteroidFacility {
+    ScrapsMine,
+    GoldMine,
+    RumDistillery,
+}
+
+impl std::fmt::Display for AsteroidFacility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ScrapsMine => write!(f, "Scraps mine"),
+            Self::GoldMine => write!(f, "Gold mine"),
+            Self::RumDistillery => write!(f, "Rum distillery"),
+        }
+    }
+}
+
+impl AsteroidFacility {
+    pub fn production(&self) -> (Resource, u32) {
+        match self {
+            Self::ScrapsMine => (Resource::SCRAPS, 5),
+            Self::GoldMine => (Resource::GOLD, 1),
+            Self::RumDistillery => (Resource::RUM, 2),
+        }
+    }
+
+    pub fn cost(&self) -> Vec<(Resource, u32)> {
+        match self {
+            Self::ScrapsMine => vec![(Resource::SATOSHI, 8000), (Resource::SCRAPS, 60)],
+            Self::GoldMine => vec![
+                (Resource::SATOSHI, 20000),
+                (Resource::SCRAPS, 120),
+                (Resource::GOLD, 2),
+            ],
+            Self::RumDistillery => vec![(Resource::SATOSHI, 10000), (Resource::SCRAPS, 40)],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AsteroidFacility, Planet, PlanetType, MAX_PENDING_PRODUCTION_DAYS};
+    use crate::types::{PlanetId, StorableResourceMap};
+    use crate::world::resources::Resource;
+
+    #[test]
+    fn test_asteroid_production() {
+        let mut asteroid = Planet::asteroid(
+            "Rock".to_string(),
+            "asteroid".to_string(),
+            PlanetId::new_v4(),
+        );
+        let production = asteroid.daily_production(&PlanetType::Lava);
+        assert_eq!(production.value(&Resource::SCRAPS), 3);
+        assert_eq!(production.value(&Resource::GOLD), 0);
+
+        // Facilities matching the natural resource produce twice as much.
+        asteroid.facilities.push(AsteroidFacility::ScrapsMine);
+        asteroid.facilities.push(AsteroidFacility::GoldMine);
+        let production = asteroid.daily_production(&PlanetType::Lava);
+        assert_eq!(production.value(&Resource::SCRAPS), 13);
+        assert_eq!(production.value(&Resource::GOLD), 1);
+
+        for _ in 0..2 * MAX_PENDING_PRODUCTION_DAYS {
+            asteroid.accumulate_production(&production);
+        }
+        assert_eq!(
+            asteroid.pending_production.value(&Resource::SCRAPS),
+            13 * MAX_PENDING_PRODUCTION_DAYS
+        );
+    }
+}
@@ -1,13 +1,16 @@
 use super::{
     constants::{COST_PER_VALUE, EXPERIENCE_PER_SKILL_MULTIPLIER, SPECIAL_TRAIT_VALUE_BONUS},
+    injury::Injury,
     jersey::Jersey,
+    kartoffel::CompanionBonus,
     planet::Planet,
     position::{GamePosition, MAX_POSITION},
     resources::Resource,
     role::CrewRole,
     skill::{GameSkill, Skill, MAX_SKILL, MIN_SKILL},
+    traits::{self, Trait, TraitProgress},
     types::{PlayerLocation, Pronoun, Region, TrainingFocus},
-    utils::PLAYER_DATA,
+    utils::{FALLBACK_PLAYER_DATA, PLAYER_DATA},
     world::World,
 };
 use crate::{
@@ -28,8 +31,6 @@ use rand::{seq::SliceRandom, Rng};
 use rand_chacha::ChaCha8Rng;
 use rand_distr::{Distribution, Normal};
 use serde::{de::Visitor, ser::SerializeStruct, Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
-use strum_macros::Display;
 
 const HOOK_MAX_BALL_HANDLING: f32 = 4.0;
 const EYE_PATCH_MAX_VISION: f32 = 4.0;
@@ -56,6 +57,10 @@ pub struct Player {
     pub previous_skills: [Skill; 20], // This is for displaying purposes to show the skills that were recently modified
     pub tiredness: f32,
     pub morale: f32,
+    pub injury: Option<Injury>,
+    // Skill bonus from the kartoffel companion assigned to the player.
+    pub companion_bonus: Option<CompanionBonus>,
+    pub trait_progress: TraitProgress,
 }
 
 impl Serialize for Player {
@@ -64,7 +69,7 @@ impl Serialize for Player {
         // and serialize them in a vector which is then deserialized
         // into the corresponding fields
         let compact_skills = self.current_skill_array().to_vec();
-        let mut state = serializer.serialize_struct("Player", 14)?;
+        let mut state = serializer.serialize_struct("Player", 18)?;
         state.serialize_field("id", &self.id)?;
         state.serialize_field("peer_id", &self.peer_id)?;
         state.serialize_field("version", &self.version)?;
@@ -80,6 +85,9 @@ impl Serialize for Player {
         state.serialize_field("tiredness", &self.tiredness)?;
         state.serialize_field("morale", &self.morale)?;
         state.serialize_field("compact_skills", &compact_skills)?;
+        state.serialize_field("injury", &self.injury)?;
+        state.serialize_field("companion_bonus", &self.companion_bonus)?;
+        state.serialize_field("trait_progress", &self.trait_progress)?;
         state.end()
     }
 }
@@ -105,6 +113,9 @@ impl<'de> Deserialize<'de> for Player {
             Tiredness,
             Morale,
             CompactSkills,
+            Injury,
+            CompanionBonus,
+            TraitProgress,
         }
 
         impl<'de> Deserialize<'de> for Field {
@@ -138,6 +149,9 @@ impl<'de> Deserialize<'de> for Player {
                             "tiredness" => Ok(Field::Tiredness),
                             "morale" => Ok(Field::Morale),
                             "compact_skills" => Ok(Field::CompactSkills),
+                            "injury" => Ok(Field::Injury),
+                            "companion_bonus" => Ok(Field::CompanionBonus),
+                            "trait_progress" => Ok(Field::TraitProgress),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -205,6 +219,10 @@ impl<'de> Deserialize<'de> for Player {
                 let compact_skills: Vec<Skill> = seq
                     .next_element()?
                     .ok_or_else(|| serde::de::Error::invalid_length(14, &self))?;
+                // Injury was added later, so it may be missing from older saves.
+                let injury = seq.next_element()?.unwrap_or_default();
+                let companion_bonus = seq.next_element()?.unwrap_or_default();
+                let trait_progress = seq.next_element()?.unwrap_or_default();
 
                 let mut player = Player {
                     id,
@@ -226,6 +244,9 @@ impl<'de> Deserialize<'de> for Player {
                     previous_skills,
                     tiredness,
                     morale,
+                    injury,
+                    companion_bonus,
+                    trait_progress,
                 };
 
                 player.athletics = Athletics {
@@ -281,6 +302,9 @@ impl<'de> Deserialize<'de> for Player {
                 let mut tiredness = None;
                 let mut morale = None;
                 let mut compact_skills: Option<Vec<Skill>> = None;
+                let mut injury = None;
+                let mut companion_bonus = None;
+                let mut trait_progress = None;
 
                 while let Some(key) = map.next_key()? {
                     match key {
@@ -374,6 +398,24 @@ impl<'de> Deserialize<'de> for Player {
                             }
                             compact_skills = Some(map.next_value()?);
                         }
+                        Field::Injury => {
+                            if injury.is_some() {
+                                return Err(serde::de::Error::duplicate_field("injury"));
+                            }
+                            injury = Some(map.next_value()?);
+                        }
+                        Field::CompanionBonus => {
+                            if companion_bonus.is_some() {
+                                return Err(serde::de::Error::duplicate_field("companion_bonus"));
+                            }
+                            companion_bonus = Some(map.next_value()?);
+                        }
+                        Field::TraitProgress => {
+                            if trait_progress.is_some() {
+                                return Err(serde::de::Error::duplicate_field("trait_progress"));
+                            }
+                            trait_progress = Some(map.next_value()?);
+                        }
                     }
                 }
 
@@ -400,6 +442,9 @@ impl<'de> Deserialize<'de> for Player {
                 let morale = morale.ok_or_else(|| serde::de::Error::missing_field("morale"))?;
                 let compact_skills = compact_skills
                     .ok_or_else(|| serde::de::Error::missing_field("compact_skills"))?;
+                let injury = injury.unwrap_or_default();
+                let companion_bonus = companion_bonus.unwrap_or_default();
+                let trait_progress = trait_progress.unwrap_or_default();
 
                 let mut player = Player {
                     id,
@@ -421,6 +466,9 @@ impl<'de> Deserialize<'de> for Player {
                     previous_skills,
                     tiredness,
                     morale,
+                    injury,
+                    companion_bonus,
+                    trait_progress,
                 };
 
                 player.athletics = Athletics {
@@ -474,6 +522,9 @@ impl<'de> Deserialize<'de> for Player {
             "tiredness",
             "morale",
             "compact_skills",
+            "injury",
+            "companion_bonus",
+            "trait_progress",
         ];
         deserializer.deserialize_struct("Player", FIELDS, PlayerVisitor)
     }
@@ -500,7 +551,7 @@ impl Player {
         }
 
         // Spugna can drink ad libitum
-        if self.morale == MAX_SKILL && !matches!(self.special_trait, Some(Trait::Spugna)) {
+        if self.morale == MAX_SKILL && !traits::can_drink_at_max_morale(self) {
             return Err(anyhow!("No need to drink"));
         }
 
@@ -515,6 +566,14 @@ impl Player {
         Ok(())
     }
 
+    // Applies the effects of a rum, which must be taken from the team resources by the caller.
+    pub fn drink(&mut self) {
+        self.add_morale(traits::drink_morale_bonus(self));
+        self.add_tiredness(traits::drink_tiredness_malus(self));
+        self.trait_progress
+            .add(Trait::Spugna, traits::TRAIT_PROGRESS_PER_DRINK);
+    }
+
     pub fn bare_value(&self) -> f32 {
         // Age modifier decrease linearly from (0,1.5) to (PEAK_PERFORMANCE_RELATIVE_AGE, 1.0),
         // then decreases linearly from (PEAK_PERFORMANCE_RELATIVE_AGE, 1.0) to (1.0, 0.5).
@@ -597,6 +656,9 @@ impl Player {
             previous_skills: [Skill::default(); 20],
             tiredness: 0.0,
             morale: MAX_MORALE,
+            injury: None,
+            companion_bonus: None,
+            trait_progress: TraitProgress::default(),
         };
 
         player.apply_info_modifiers();
@@ -625,6 +687,9 @@ impl Player {
         } else if athletics.stamina > 15.0 && rng.gen_bool(TRAIT_PROBABILITY) {
             player.special_trait = Some(Trait::Relentless);
         }
+        if let Some(special_trait) = player.special_trait {
+            player.trait_progress = TraitProgress::with_trait(special_trait);
+        }
 
         player.previous_skills = player.current_skill_array();
 
@@ -752,7 +817,7 @@ impl Player {
         self.image.compose(&self.info)
     }
 
-    fn skill_at_index(&self, idx: usize) -> Skill {
+    pub(crate) fn skill_at_index(&self, idx: usize) -> Skill {
         match idx {
             0 => self.athletics.quickness,
             1 => self.athletics.vertical,
@@ -802,12 +867,20 @@ impl Player {
         self.tiredness == MAX_TIREDNESS
     }
 
+    pub fn is_injured(&self) -> bool {
+        self.injury.is_some()
+    }
+
+    pub fn injury_probability(&self) -> f64 {
+        let tiredness_modifier =
+            1.0 + INJURY_TIREDNESS_MODIFIER * (self.tiredness / MAX_TIREDNESS) as f64;
+        let athletics_modifier =
+            1.5 - ((self.athletics.strength + self.athletics.stamina) / (2.0 * MAX_SKILL)) as f64;
+        INJURY_BASE_PROBABILITY * tiredness_modifier * athletics_modifier
+    }
+
     pub fn add_tiredness(&mut self, tiredness: f32) {
-        let max_tiredness = if self.special_trait == Some(Trait::Relentless) {
-            MAX_TIREDNESS - 1.0
-        } else {
-            MAX_TIREDNESS
-        };
+        let max_tiredness = traits::max_tiredness(self);
         self.tiredness = (self.tiredness
             + tiredness / (1.0 + self.athletics.stamina / MAX_TIREDNESS))
             .min(max_tiredness)
@@ -867,40 +940,58 @@ impl Player {
         }
 
         let new_value = (self.skill_at_index(idx) + value).bound();
+        self.set_skill_at_index(idx, new_value);
+    }
+
+    // Sets the skill as is, without any of the modifiers applied by modify_skill.
+    pub(crate) fn set_skill_at_index(&mut self, idx: usize, value: Skill) {
         match idx {
-            0 => self.athletics.quickness = new_value,
-            1 => self.athletics.vertical = new_value,
-            2 => self.athletics.strength = new_value,
-            3 => self.athletics.stamina = new_value,
-            4 => self.offense.brawl = new_value,
-            5 => self.offense.close_range = new_value,
-            6 => self.offense.medium_range = new_value,
-            7 => self.offense.long_range = new_value,
-            8 => self.defense.steal = new_value,
-            9 => self.defense.block = new_value,
-            10 => self.defense.perimeter_defense = new_value,
-            11 => self.defense.interior_defense = new_value,
-            12 => self.technical.passing = new_value,
-            13 => self.technical.ball_handling = new_value,
-            14 => self.technical.post_moves = new_value,
-            15 => self.technical.rebounds = new_value,
-            16 => self.mental.vision = new_value,
-            17 => self.mental.aggression = new_value,
-            18 => self.mental.intuition = new_value,
-            19 => self.mental.charisma = new_value,
+            0 => self.athletics.quickness = value,
+            1 => self.athletics.vertical = value,
+            2 => self.athletics.strength = value,
+            3 => self.athletics.stamina = value,
+            4 => self.offense.brawl = value,
+            5 => self.offense.close_range = value,
+            6 => self.offense.medium_range = value,
+            7 => self.offense.long_range = value,
+            8 => self.defense.steal = value,
+            9 => self.defense.block = value,
+            10 => self.defense.perimeter_defense = value,
+            11 => self.defense.interior_defense = value,
+            12 => self.technical.passing = value,
+            13 => self.technical.ball_handling = value,
+            14 => self.technical.post_moves = value,
+            15 => self.technical.rebounds = value,
+            16 => self.mental.vision = value,
+            17 => self.mental.aggression = value,
+            18 => self.mental.intuition = value,
+            19 => self.mental.charisma = value,
             _ => panic!("Invalid skill index {}", idx),
         }
     }
 
+    // Returns the training gained by each skill.
     pub fn update_skills_training(
         &mut self,
         experience_at_position: [u16; MAX_POSITION as usize],
+        action_experience: [f32; 20],
         training_bonus: f32,
         training_focus: Option<TrainingFocus>,
-    ) {
+    ) -> [f32; 20] {
         // potential_modifier has a value ranging from 0.0 to 2.0.
         // Players with skills below their potential improve faster, above their potential improve slower.
         let potential_modifier = 1.0 + (self.potential - self.average_skill()) / 20.0;
+        let focus_bonus = |idx: usize| match training_focus {
+            Some(focus) => {
+                if focus.is_focus(idx) {
+                    2.0
+                } else {
+                    0.5
+                }
+            }
+            None => 1.0,
+        };
+        let previous_training = self.skills_training;
         log::info!("Previous Experience increase: {:#?}", self.skills_training);
         for p in 0..MAX_POSITION {
             if experience_at_position[p as usize] == 0 {
@@ -908,16 +999,7 @@ impl Player {
             }
 
             for (idx, &w) in p.weights().iter().enumerate() {
-                let training_focus_bonus = match training_focus {
-                    Some(focus) => {
-                        if focus.is_focus(idx) {
-                            2.0
-                        } else {
-                            0.5
-                        }
-                    }
-                    None => 1.0,
-                };
+                let training_focus_bonus = focus_bonus(idx);
                 self.skills_training[idx] += experience_at_position[p as usize] as f32
                     * w
                     * EXPERIENCE_PER_SKILL_MULTIPLIER
@@ -947,11 +1029,42 @@ impl Player {
             }
         }
 
+        // Skills used in the game actions improve on top of the ones of the position.
+        for (idx, &experience) in action_experience.iter().enumerate() {
+            if experience == 0.0 {
+                continue;
+            }
+            self.skills_training[idx] += experience
+                * EXPERIENCE_PER_SKILL_MULTIPLIER
+                * training_bonus
+                * focus_bonus(idx)
+                * potential_modifier;
+            self.skills_training[idx] =
+                self.skills_training[idx].min(MAX_SKILL_INCREASE_PER_LONG_TICK);
+        }
+
         log::info!("Total Experience increase: {:#?}", self.skills_training);
+
+        let mut gains = [0.0; 20];
+        for idx in 0..gains.len() {
+            gains[idx] = (self.skills_training[idx] - previous_training[idx]).max(0.0);
+        }
+        gains
+    }
+
+    // Piloting during a space adventure trains athletics and technical skills.
+    pub fn add_piloting_experience(&mut self, distance: f32) {
+        let potential_modifier = 1.0 + (self.potential - self.average_skill()) / 20.0;
+        let increase =
+            distance * SPACE_ADVENTURE_EXPERIENCE_PER_DISTANCE * potential_modifier.max(0.0);
+        for idx in (0..4).chain(12..16) {
+            self.skills_training[idx] =
+                (self.skills_training[idx] + increase).min(MAX_SKILL_INCREASE_PER_LONG_TICK);
+        }
     }
 
     pub fn tiredness_weighted_rating_at_position(&self, position: Position) -> f32 {
-        if self.is_knocked_out() {
+        if self.is_knocked_out() || self.is_injured() {
             return 0.0;
         }
         position.player_rating(self.current_skill_array()) * (MAX_TIREDNESS - self.tiredness / 2.0)
@@ -975,10 +1088,19 @@ pub struct InfoStats {
     pub pronouns: Pronoun,
     pub height: f32,
     pub weight: f32,
+    // Given by the crew, replaces the shortened name.
+    #[serde(default)]
+    pub nickname: Option<String>,
+    // Earned after a notable game, shown next to the name.
+    #[serde(default)]
+    pub epithet: Option<String>,
 }
 
 impl InfoStats {
     pub fn shortened_name(&self) -> String {
+        if let Some(nickname) = self.nickname.as_ref() {
+            return nickname.clone();
+        }
         format!(
             "{}.{}",
             self.first_name.chars().next().unwrap_or_default(),
@@ -987,7 +1109,17 @@ impl InfoStats {
     }
 
     pub fn full_name(&self) -> String {
-        format!("{} {}", self.first_name, self.last_name)
+        match self.nickname.as_ref() {
+            Some(nickname) => format!("{} \"{}\" {}", self.first_name, nickname, self.last_name),
+            None => format!("{} {}", self.first_name, self.last_name),
+        }
+    }
+
+    pub fn titled_name(&self) -> String {
+        match self.epithet.as_ref() {
+            Some(epithet) => format!("{}, {}", self.full_name(), epithet),
+            None => self.full_name(),
+        }
     }
 
     pub fn relative_age(&self) -> f32 {
@@ -1003,7 +1135,9 @@ impl InfoStats {
             Some(p) => p,
             None => home_planet.random_population(rng).unwrap_or_default(),
         };
-        let p_data = PLAYER_DATA.get(&population).unwrap();
+        let p_data = PLAYER_DATA
+            .get(&population)
+            .unwrap_or(&FALLBACK_PLAYER_DATA);
         let pronouns = if population == Population::Polpett || population == Population::Octopulp {
             Pronoun::They
         } else {
@@ -1059,34 +1193,8 @@ impl InfoStats {
             pronouns,
             height,
             weight,
-        }
-    }
-}
-
-#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Display)]
-#[repr(u8)]
-pub enum Trait {
-    Killer,
-    Relentless,
-    Showpirate,
-    Spugna,
-}
-
-impl Trait {
-    pub fn description(&self, player: &Player) -> String {
-        match self {
-            Trait::Killer => format!(
-                "Better at brawling during games. Bonus is based on reputation (+{}).",
-                player.reputation.value()
-            ),
-            Trait::Relentless => format!("Cannot get exhausted"),
-            Trait::Showpirate => {
-                format!(
-                    "Increase games attendance based on reputation (+{}%)",
-                    player.reputation.value()
-                )
-            }
-            Trait::Spugna => format!("Immediately maximizes morale when drinking. It is said that a drunk pilot could bring you somewhere unexpected...",),
+            nickname: None,
+            epithet: None,
         }
     }
 }
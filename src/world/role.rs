@@ -1,3 +1,4 @@
+use super::types::TeamBonus;
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use strum_macros::{Display, EnumIter};
 
@@ -11,6 +12,20 @@ pub enum CrewRole {
     Pilot,
     #[default]
     Mozzo,
-    // Chef,
-    // Engineer,
+    Engineer,
+    Cook,
+}
+
+impl CrewRole {
+    // The team bonuses granted by the role, the main one first.
+    pub fn bonuses(&self) -> &'static [TeamBonus] {
+        match self {
+            Self::Captain => &[TeamBonus::Reputation, TeamBonus::TradePrice],
+            Self::Doctor => &[TeamBonus::TirednessRecovery, TeamBonus::Training],
+            Self::Pilot => &[TeamBonus::SpaceshipSpeed, TeamBonus::Exploration],
+            Self::Engineer => &[TeamBonus::HullCare, TeamBonus::Repairs],
+            Self::Cook => &[TeamBonus::GameStamina, TeamBonus::Provisions],
+            Self::Mozzo => &[],
+        }
+    }
 }
@@ -0,0 +1,87 @@
+use crate::types::{PlanetId, Tick};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RouteLeg {
+    pub from: PlanetId,
+    pub to: PlanetId,
+    pub distance: u128,
+    pub duration: Tick,
+    pub fuel: u32,
+}
+
+/// Multi-leg travel plan. Every intermediate stop is a populated planet,
+/// where the team can refuel before the next leg.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TravelRoute {
+    pub legs: Vec<RouteLeg>,
+}
+
+impl TravelRoute {
+    pub fn new(legs: Vec<RouteLeg>) -> Self {
+        Self { legs }
+    }
+
+    pub fn destination(&self) -> Option<PlanetId> {
+        self.legs.last().map(|leg| leg.to)
+    }
+
+    pub fn stops(&self) -> Vec<PlanetId> {
+        self.legs.iter().map(|leg| leg.to).collect()
+    }
+
+    pub fn total_distance(&self) -> u128 {
+        self.legs.iter().map(|leg| leg.distance).sum()
+    }
+
+    pub fn total_duration(&self) -> Tick {
+        self.legs.iter().map(|leg| leg.duration).sum()
+    }
+
+    pub fn total_fuel(&self) -> u32 {
+        self.legs.iter().map(|leg| leg.fuel).sum()
+    }
+
+    // Fuel that has to be bought along the way, assuming the team
+    // buys only what is needed to complete the next leg.
+    pub fn fuel_to_buy(&self, current_fuel: u32) -> u32 {
+        self.total_fuel().saturating_sub(current_fuel)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RouteLeg, TravelRoute};
+    use crate::types::PlanetId;
+    use crate::world::constants::HOURS;
+
+    #[test]
+    fn test_travel_route_totals() {
+        let a = PlanetId::new_v4();
+        let b = PlanetId::new_v4();
+        let c = PlanetId::new_v4();
+        let route = TravelRoute::new(vec![
+            RouteLeg {
+                from: a,
+                to: b,
+                distance: 100,
+                duration: HOURS,
+                fuel: 10,
+            },
+            RouteLeg {
+                from: b,
+                to: c,
+                distance: 250,
+                duration: 2 * HOURS,
+                fuel: 25,
+            },
+        ]);
+
+        assert_eq!(route.destination(), Some(c));
+        assert_eq!(route.stops(), vec![b, c]);
+        assert_eq!(route.total_distance(), 350);
+        assert_eq!(route.total_duration(), 3 * HOURS);
+        assert_eq!(route.total_fuel(), 35);
+        assert_eq!(route.fuel_to_buy(20), 15);
+        assert_eq!(route.fuel_to_buy(50), 0);
+    }
+}
@@ -0,0 +1,168 @@
+use super::{constants::TirednessCost, player::Player, resources::Resource, skill::GameSkill};
+use crate::types::{PlanetId, ResourceMap};
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::collections::HashMap;
+use strum_macros::{Display, EnumIter};
+
+// Probability per hour of exploration.
+pub const DERELICT_DISCOVERY_PROBABILITY: f64 = 0.06;
+pub const SALVAGE_BASE_SCRAPS: u32 = 8;
+pub const BOARDING_MAX_GOLD: u32 = 3;
+pub const BOARDING_FAILURE_TIREDNESS: f32 = TirednessCost::CRITICAL;
+pub const TOW_FUEL_COST: u32 = 10;
+pub const TOW_MAX_REPUTATION: f32 = 1.5;
+
+const DERELICT_NAMES: [&'static str; 8] = [
+    "Rusty Comet",
+    "Lost Sparrow",
+    "Ninth Orbit",
+    "Silent Marauder",
+    "Broken Halo",
+    "Cold Ember",
+    "Drifting Tuna",
+    "Last Rebel",
+];
+
+/// A shipwreck found while exploring, waiting for the crew to decide what to do with it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Derelict {
+    pub ship_name: String,
+    pub found_around: PlanetId,
+}
+
+impl Derelict {
+    pub fn random(rng: &mut ChaCha8Rng, found_around: PlanetId) -> Self {
+        Self {
+            ship_name: DERELICT_NAMES[rng.gen_range(0..DERELICT_NAMES.len())].to_string(),
+            found_around,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Display, EnumIter)]
+#[repr(u8)]
+pub enum DerelictChoice {
+    Salvage,
+    Board,
+    Tow,
+    Leave,
+}
+
+impl DerelictChoice {
+    pub fn description(&self) -> String {
+        match self {
+            Self::Salvage => "Strip the hull for scraps. Safe, better with a technical crew.".into(),
+            Self::Board => "Search the wreck for gold. Risky, the crew may come back exhausted. Better with an athletic crew.".into(),
+            Self::Tow => format!(
+                "Tow the wreck to port for reputation, using {} fuel. Better with a cool-headed crew.",
+                TOW_FUEL_COST
+            ),
+            Self::Leave => "Leave the wreck to drift.".into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DerelictOutcome {
+    pub success: bool,
+    pub resources: ResourceMap,
+    pub reputation: f32,
+    // Tiredness added to every crew member.
+    pub tiredness: f32,
+}
+
+// The best crew member for the job leads the operation.
+fn best_roll(crew: &[&Player], rng: &mut ChaCha8Rng, skill: impl Fn(&Player) -> f32) -> f32 {
+    crew.iter()
+        .map(|player| player.roll(rng) as f32 + skill(player))
+        .fold(0.0, f32::max)
+}
+
+pub fn roll_derelict_outcome(
+    choice: DerelictChoice,
+    crew: &[&Player],
+    rng: &mut ChaCha8Rng,
+) -> DerelictOutcome {
+    let mut resources = HashMap::new();
+    match choice {
+        DerelictChoice::Salvage => {
+            let roll = best_roll(crew, rng, |p| {
+                p.technical.passing.value() as f32 + p.technical.ball_handling.value() as f32
+            });
+            resources.insert(Resource::SCRAPS, SALVAGE_BASE_SCRAPS + roll as u32 / 2);
+            DerelictOutcome {
+                success: true,
+                resources,
+                ..Default::default()
+            }
+        }
+        DerelictChoice::Board => {
+            let roll = best_roll(crew, rng, |p| {
+                p.athletics.quickness.value() as f32 + p.athletics.strength.value() as f32
+            });
+            // Rolls range roughly from 0 to 80.
+            if rng.gen_bool((roll as f64 / 80.0).clamp(0.1, 0.9)) {
+                resources.insert(Resource::GOLD, rng.gen_range(1..=BOARDING_MAX_GOLD));
+                DerelictOutcome {
+                    success: true,
+                    resources,
+                    ..Default::default()
+                }
+            } else {
+                DerelictOutcome {
+                    success: false,
+                    tiredness: BOARDING_FAILURE_TIREDNESS,
+                    ..Default::default()
+                }
+            }
+        }
+        DerelictChoice::Tow => {
+            let roll = best_roll(crew, rng, |p| {
+                p.mental.vision.value() as f32 + p.mental.charisma.value() as f32
+            });
+            DerelictOutcome {
+                success: true,
+                reputation: TOW_MAX_REPUTATION * (roll / 80.0).min(1.0),
+                ..Default::default()
+            }
+        }
+        DerelictChoice::Leave => DerelictOutcome::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{roll_derelict_outcome, DerelictChoice, SALVAGE_BASE_SCRAPS};
+    use crate::types::{PlayerId, StorableResourceMap};
+    use crate::world::{player::Player, resources::Resource, utils::PLANET_DATA};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_derelict_outcomes() {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let players = (0..5)
+            .map(|_| Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 0.0))
+            .collect::<Vec<Player>>();
+        let crew = players.iter().collect::<Vec<&Player>>();
+
+        for choice in DerelictChoice::iter() {
+            let outcome = roll_derelict_outcome(choice, &crew, rng);
+            match choice {
+                DerelictChoice::Salvage => {
+                    assert!(outcome.resources.value(&Resource::SCRAPS) >= SALVAGE_BASE_SCRAPS)
+                }
+                DerelictChoice::Board => assert!(
+                    outcome.success == (outcome.resources.value(&Resource::GOLD) > 0)
+                        && outcome.success == (outcome.tiredness == 0.0)
+                ),
+                DerelictChoice::Tow => assert!(outcome.reputation > 0.0),
+                DerelictChoice::Leave => assert_eq!(outcome, Default::default()),
+            }
+        }
+    }
+}
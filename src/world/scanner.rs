@@ -0,0 +1,85 @@
+use super::{planet::Planet, player::Player, resources::Resource, skill::Rated};
+use crate::types::{PlanetId, Tick};
+use serde::{Deserialize, Serialize};
+
+// Only the closest planets are listed in the scan results popup.
+pub const MAX_SCAN_REPORT_LINES: usize = 8;
+
+/// What the spaceship scanner picked up on a planet without landing there.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScanResult {
+    pub planet_id: PlanetId,
+    pub tick: Tick,
+    pub free_pirates: usize,
+    pub best_pirate_rating: u8,
+    pub richest_resource: Option<Resource>,
+}
+
+impl ScanResult {
+    pub fn new(planet: &Planet, free_pirates: &[&Player], tick: Tick) -> Self {
+        let richest_resource = planet
+            .resources
+            .iter()
+            .filter(|(_, &amount)| amount > 0)
+            .max_by_key(|(_, &amount)| amount)
+            .map(|(resource, _)| *resource);
+        Self {
+            planet_id: planet.id,
+            tick,
+            free_pirates: free_pirates.len(),
+            best_pirate_rating: free_pirates
+                .iter()
+                .map(|player| player.rating())
+                .max()
+                .unwrap_or_default(),
+            richest_resource,
+        }
+    }
+
+    pub fn description(&self) -> String {
+        let pirates = if self.free_pirates == 0 {
+            "no free pirates".to_string()
+        } else {
+            format!(
+                "{} free pirate(s), best {}",
+                self.free_pirates,
+                self.best_pirate_rating.stars()
+            )
+        };
+        match self.richest_resource {
+            Some(resource) => format!(
+                "{}, rich in {}",
+                pirates,
+                resource.to_string().to_lowercase()
+            ),
+            None => pirates,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScanResult;
+    use crate::types::{PlanetId, PlayerId};
+    use crate::world::{planet::Planet, player::Player, resources::Resource, utils::PLANET_DATA};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_scan_result() {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let mut planet = Planet::asteroid("Rock".into(), "rock".into(), PlanetId::new_v4());
+        let result = ScanResult::new(&planet, &[], 0);
+        assert_eq!(result.description(), "no free pirates");
+
+        planet.resources.insert(Resource::GOLD, 3);
+        planet.resources.insert(Resource::SCRAPS, 8);
+        let players = (0..3)
+            .map(|_| Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 10.0))
+            .collect::<Vec<Player>>();
+        let result = ScanResult::new(&planet, &players.iter().collect::<Vec<&Player>>(), 0);
+        assert_eq!(result.free_pirates, 3);
+        assert_eq!(result.richest_resource, Some(Resource::SCRAPS));
+        assert!(result.description().ends_with("rich in scraps"));
+    }
+}
@@ -0,0 +1,104 @@
+use super::skill::{MAX_SKILL, MIN_SKILL};
+use crate::types::{PlanetId, PlayerId, Tick};
+use serde::{Deserialize, Serialize};
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+pub const SCOUTING_COST: u32 = 2500;
+const MIN_SCOUTING_ACCURACY: f32 = 0.35;
+// Maximum error on a revealed skill when the accuracy is minimal.
+const MAX_SKILL_ERROR: f32 = 5.0;
+
+/// How much of the skills of a player can be seen by the own team.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SkillVisibility {
+    Full,
+    Scouted { accuracy: f32 },
+    Hidden,
+}
+
+impl SkillVisibility {
+    // Returns the skill as seen by the own team, None if it is not revealed.
+    pub fn estimated_skill(&self, player_id: PlayerId, index: usize, skill: f32) -> Option<f32> {
+        let accuracy = match self {
+            Self::Full => return Some(skill),
+            Self::Scouted { accuracy } => *accuracy,
+            Self::Hidden => return None,
+        };
+
+        // Hash player and skill so that the estimate does not change between renders.
+        let mut s = DefaultHasher::new();
+        (player_id, index).hash(&mut s);
+        let hash = s.finish();
+        let reveal_roll = (hash % 1000) as f32 / 1000.0;
+        if reveal_roll >= accuracy {
+            return None;
+        }
+
+        let error_roll = ((hash / 1000) % 2001) as f32 / 1000.0 - 1.0;
+        let error = (error_roll * (1.0 - accuracy) * MAX_SKILL_ERROR).round();
+        Some((skill + error).round().clamp(MIN_SKILL, MAX_SKILL))
+    }
+}
+
+/// Report on the free pirates of a planet, valid until the free pirates are refreshed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScoutingReport {
+    pub planet_id: PlanetId,
+    pub tick: Tick,
+    pub accuracy: f32,
+    pub player_ids: Vec<PlayerId>,
+}
+
+impl ScoutingReport {
+    // A well-known captain gets better information from the locals.
+    pub fn accuracy_for_reputation(captain_reputation: Option<f32>) -> f32 {
+        let reputation = captain_reputation.unwrap_or_default().clamp(0.0, MAX_SKILL);
+        MIN_SCOUTING_ACCURACY + (1.0 - MIN_SCOUTING_ACCURACY) * reputation / MAX_SKILL
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ScoutingReport, SkillVisibility, MIN_SCOUTING_ACCURACY};
+    use crate::types::PlayerId;
+    use crate::world::skill::MAX_SKILL;
+
+    #[test]
+    fn test_scouting_accuracy() {
+        assert_eq!(
+            ScoutingReport::accuracy_for_reputation(None),
+            MIN_SCOUTING_ACCURACY
+        );
+        assert_eq!(
+            ScoutingReport::accuracy_for_reputation(Some(MAX_SKILL)),
+            1.0
+        );
+
+        let player_id = PlayerId::new_v4();
+        assert_eq!(
+            SkillVisibility::Full.estimated_skill(player_id, 0, 12.3),
+            Some(12.3)
+        );
+        assert!(SkillVisibility::Hidden
+            .estimated_skill(player_id, 0, 12.3)
+            .is_none());
+
+        // A perfect report reveals every skill exactly.
+        let perfect = SkillVisibility::Scouted { accuracy: 1.0 };
+        for index in 0..20 {
+            assert_eq!(perfect.estimated_skill(player_id, index, 12.0), Some(12.0));
+        }
+
+        // A poor report hides some skills, and estimates are stable.
+        let poor = SkillVisibility::Scouted {
+            accuracy: MIN_SCOUTING_ACCURACY,
+        };
+        let estimates = (0..20)
+            .map(|index| poor.estimated_skill(player_id, index, 12.0))
+            .collect::<Vec<Option<f32>>>();
+        assert!(estimates.iter().any(|estimate| estimate.is_none()));
+        for (index, estimate) in estimates.iter().enumerate() {
+            assert_eq!(poor.estimated_skill(player_id, index, 12.0), *estimate);
+        }
+    }
+}
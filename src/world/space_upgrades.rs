@@ -0,0 +1,167 @@
+use super::resources::Resource;
+use crate::types::AppResult;
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::fmt::Display;
+use strum_macros::EnumIter;
+
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Eq, Hash, EnumIter)]
+#[repr(u8)]
+pub enum SpaceAdventureUpgrade {
+    Capacitors,
+    RapidFire,
+    Plating,
+    Thrusters,
+    Magnets,
+}
+
+impl Display for SpaceAdventureUpgrade {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Capacitors => write!(f, "Capacitors"),
+            Self::RapidFire => write!(f, "Rapid fire"),
+            Self::Plating => write!(f, "Plating"),
+            Self::Thrusters => write!(f, "Thrusters"),
+            Self::Magnets => write!(f, "Magnets"),
+        }
+    }
+}
+
+impl SpaceAdventureUpgrade {
+    pub const MAX_INDEX: usize = 5; // = SpaceAdventureUpgrade::iter().count();
+    pub const MAX_LEVEL: u8 = 3;
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Capacitors => "Start space adventures with a bigger shooter charge.",
+            Self::RapidFire => "Shorter recoil between shots.",
+            Self::Plating => "Absorbs part of the damage taken during space adventures.",
+            Self::Thrusters => "Stronger thrust, to dodge asteroids and pirates.",
+            Self::Magnets => "Pulls resource fragments towards the spaceship.",
+        }
+    }
+
+    // Each branch of the tree starts from a root upgrade.
+    pub fn requires(&self) -> Option<Self> {
+        match self {
+            Self::Capacitors | Self::Plating => None,
+            Self::RapidFire => Some(Self::Capacitors),
+            Self::Thrusters | Self::Magnets => Some(Self::Plating),
+        }
+    }
+
+    // Upgrades are paid with what is collected in space.
+    pub fn cost(&self, level: u8) -> Vec<(Resource, u32)> {
+        let level = level as u32;
+        let scraps = match self {
+            Self::Capacitors | Self::Plating => 60,
+            Self::RapidFire | Self::Thrusters | Self::Magnets => 90,
+        } * (level + 1);
+
+        if level == 0 {
+            vec![(Resource::SCRAPS, scraps)]
+        } else {
+            vec![(Resource::SCRAPS, scraps), (Resource::GOLD, level)]
+        }
+    }
+}
+
+/// Permanent upgrades to the spaceship performance during space adventures,
+/// unlocked in the shipyard with the resources collected in space.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub struct SpaceAdventureProgression {
+    levels: [u8; SpaceAdventureUpgrade::MAX_INDEX],
+}
+
+impl SpaceAdventureProgression {
+    pub fn level(&self, upgrade: SpaceAdventureUpgrade) -> u8 {
+        self.levels[upgrade as usize]
+    }
+
+    pub fn is_maxed(&self, upgrade: SpaceAdventureUpgrade) -> bool {
+        self.level(upgrade) >= SpaceAdventureUpgrade::MAX_LEVEL
+    }
+
+    pub fn next_cost(&self, upgrade: SpaceAdventureUpgrade) -> Vec<(Resource, u32)> {
+        upgrade.cost(self.level(upgrade))
+    }
+
+    pub fn can_unlock(&self, upgrade: SpaceAdventureUpgrade) -> AppResult<()> {
+        if self.is_maxed(upgrade) {
+            return Err(anyhow!("Fully upgraded"));
+        }
+
+        if let Some(required) = upgrade.requires() {
+            if self.level(required) == 0 {
+                return Err(anyhow!("Requires {}", required));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn unlock(&mut self, upgrade: SpaceAdventureUpgrade) -> AppResult<()> {
+        self.can_unlock(upgrade)?;
+        self.levels[upgrade as usize] += 1;
+        Ok(())
+    }
+
+    pub fn charge_multiplier(&self) -> f32 {
+        1.0 + 0.2 * self.level(SpaceAdventureUpgrade::Capacitors) as f32
+    }
+
+    pub fn recoil_multiplier(&self) -> f32 {
+        1.0 - 0.2 * self.level(SpaceAdventureUpgrade::RapidFire) as f32
+    }
+
+    pub fn damage_reduction(&self) -> f32 {
+        0.1 * self.level(SpaceAdventureUpgrade::Plating) as f32
+    }
+
+    pub fn thrust_multiplier(&self) -> f32 {
+        1.0 + 0.15 * self.level(SpaceAdventureUpgrade::Thrusters) as f32
+    }
+
+    pub fn tractor_multiplier(&self) -> f32 {
+        1.0 + 0.5 * self.level(SpaceAdventureUpgrade::Magnets) as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SpaceAdventureProgression, SpaceAdventureUpgrade};
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_space_adventure_upgrade_tree() {
+        assert_eq!(
+            SpaceAdventureUpgrade::iter().count(),
+            SpaceAdventureUpgrade::MAX_INDEX
+        );
+
+        let mut progression = SpaceAdventureProgression::default();
+        assert!(progression
+            .can_unlock(SpaceAdventureUpgrade::RapidFire)
+            .is_err());
+        assert_eq!(progression.charge_multiplier(), 1.0);
+
+        progression
+            .unlock(SpaceAdventureUpgrade::Capacitors)
+            .unwrap();
+        assert!(progression
+            .can_unlock(SpaceAdventureUpgrade::RapidFire)
+            .is_ok());
+        assert!(progression.charge_multiplier() > 1.0);
+
+        for _ in 1..SpaceAdventureUpgrade::MAX_LEVEL {
+            progression
+                .unlock(SpaceAdventureUpgrade::Capacitors)
+                .unwrap();
+        }
+        assert!(progression.is_maxed(SpaceAdventureUpgrade::Capacitors));
+        assert!(progression
+            .unlock(SpaceAdventureUpgrade::Capacitors)
+            .is_err());
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
         spaceship::{SpaceshipImage, SpaceshipImageId},
         types::Gif,
     },
-    types::{AppResult, SystemTimeTick, Tick},
+    types::{AppResult, Tick},
     world::utils::is_default,
 };
 use rand::{seq::IteratorRandom, SeedableRng};
@@ -502,16 +502,214 @@ impl SpaceshipComponent for Storage {
     }
 }
 
+#[derive(
+    Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Hash, Default, EnumIter,
+)]
+#[repr(u8)]
+pub enum LifeSupport {
+    #[default]
+    Basic,
+    Recycler,
+    Hydroponics,
+    Biosphere,
+}
+
+impl Display for LifeSupport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Basic => write!(f, "Basic"),
+            Self::Recycler => write!(f, "Recycler"),
+            Self::Hydroponics => write!(f, "Hydroponics"),
+            Self::Biosphere => write!(f, "Biosphere"),
+        }
+    }
+}
+
+// Life support is not drawn, so it is not a SpaceshipComponent.
+impl LifeSupport {
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Basic => Self::Recycler,
+            Self::Recycler => Self::Hydroponics,
+            Self::Hydroponics => Self::Biosphere,
+            Self::Biosphere => Self::Biosphere,
+        }
+    }
+
+    pub fn previous(&self) -> Self {
+        match self {
+            Self::Basic => Self::Basic,
+            Self::Recycler => Self::Basic,
+            Self::Hydroponics => Self::Recycler,
+            Self::Biosphere => Self::Hydroponics,
+        }
+    }
+
+    pub fn can_be_upgraded(&self) -> bool {
+        self.next() != *self
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Basic => "Oxygen tanks and a leaky scrubber. Keeps the crew alive, barely.",
+            Self::Recycler => "Recycles air and water, leaving room for one more pirate.",
+            Self::Hydroponics => "Grows algae and rum-grade sugarcane. Two more pirates can fit.",
+            Self::Biosphere => "A small jungle in the cargo bay. Three more pirates can fit.",
+        }
+    }
+
+    pub fn crew_capacity(&self) -> u8 {
+        match self {
+            Self::Basic => 0,
+            Self::Recycler => 1,
+            Self::Hydroponics => 2,
+            Self::Biosphere => 3,
+        }
+    }
+
+    // Multiplier to the oxygen autonomy during space adventures.
+    pub fn oxygen_multiplier(&self) -> f32 {
+        match self {
+            Self::Basic => 1.0,
+            Self::Recycler => 1.5,
+            Self::Hydroponics => 2.0,
+            Self::Biosphere => 3.0,
+        }
+    }
+
+    pub fn cost(&self) -> u32 {
+        match self {
+            Self::Basic => 0,
+            Self::Recycler => 8000,
+            Self::Hydroponics => 18000,
+            Self::Biosphere => 32000,
+        }
+    }
+
+    pub fn upgrade_cost(&self) -> Vec<(Resource, u32)> {
+        if !self.can_be_upgraded() {
+            return vec![];
+        }
+
+        let scraps_cost = (self.next().cost() - self.cost()) / 30;
+
+        vec![
+            (Resource::SATOSHI, self.next().cost() - self.cost()),
+            (Resource::SCRAPS, scraps_cost),
+        ]
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Hash, Default, EnumIter,
+)]
+#[repr(u8)]
+pub enum Scanner {
+    #[default]
+    NoScanner,
+    ShortRange,
+    LongRange,
+    DeepSpace,
+}
+
+impl Display for Scanner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoScanner => write!(f, "None"),
+            Self::ShortRange => write!(f, "Short range"),
+            Self::LongRange => write!(f, "Long range"),
+            Self::DeepSpace => write!(f, "Deep space"),
+        }
+    }
+}
+
+impl Scanner {
+    pub fn next(&self) -> Self {
+        match self {
+            Self::NoScanner => Self::ShortRange,
+            Self::ShortRange => Self::LongRange,
+            Self::LongRange => Self::DeepSpace,
+            Self::DeepSpace => Self::DeepSpace,
+        }
+    }
+
+    pub fn previous(&self) -> Self {
+        match self {
+            Self::NoScanner => Self::NoScanner,
+            Self::ShortRange => Self::NoScanner,
+            Self::LongRange => Self::ShortRange,
+            Self::DeepSpace => Self::LongRange,
+        }
+    }
+
+    pub fn can_be_upgraded(&self) -> bool {
+        self.next() != *self
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::NoScanner => "No scanner installed, the crew has to go and see.",
+            Self::ShortRange => "Scans the planets orbiting close by.",
+            Self::LongRange => "Scans the whole neighbourhood of the current planet.",
+            Self::DeepSpace => "Reaches out to the closest star systems.",
+        }
+    }
+
+    // Maximum distance of the scanned planets, in Km.
+    pub fn range(&self) -> u128 {
+        match self {
+            Self::NoScanner => 0,
+            Self::ShortRange => AU,
+            Self::LongRange => 3 * AU,
+            Self::DeepSpace => LIGHT_YEAR,
+        }
+    }
+
+    // Fuel burnt to power a scan.
+    pub fn fuel_cost(&self) -> u32 {
+        match self {
+            Self::NoScanner => 0,
+            Self::ShortRange => 5,
+            Self::LongRange => 10,
+            Self::DeepSpace => 25,
+        }
+    }
+
+    pub fn cost(&self) -> u32 {
+        match self {
+            Self::NoScanner => 0,
+            Self::ShortRange => 6000,
+            Self::LongRange => 15000,
+            Self::DeepSpace => 30000,
+        }
+    }
+
+    pub fn upgrade_cost(&self) -> Vec<(Resource, u32)> {
+        if !self.can_be_upgraded() {
+            return vec![];
+        }
+
+        let scraps_cost = (self.next().cost() - self.cost()) / 40;
+
+        vec![
+            (Resource::SATOSHI, self.next().cost() - self.cost()),
+            (Resource::SCRAPS, scraps_cost),
+        ]
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Hash, EnumIter)]
 pub enum SpaceshipUpgradeTarget {
     Hull { component: Hull },
     Engine { component: Engine },
     Storage { component: Storage },
+    LifeSupport { component: LifeSupport },
+    Scanner { component: Scanner },
     Repairs { amount: u32 },
 }
 
 impl SpaceshipUpgradeTarget {
-    pub const MAX_INDEX: usize = 4; // = SpaceshipUpgradeTarget::iter().count();
+    pub const MAX_INDEX: usize = 6; // = SpaceshipUpgradeTarget::iter().count();
 }
 
 impl Default for SpaceshipUpgradeTarget {
@@ -526,6 +724,8 @@ impl Display for SpaceshipUpgradeTarget {
             Self::Hull { .. } => write!(f, "Hull"),
             Self::Engine { .. } => write!(f, "Engine"),
             Self::Storage { .. } => write!(f, "Storage"),
+            Self::LifeSupport { .. } => write!(f, "Life support"),
+            Self::Scanner { .. } => write!(f, "Scanner"),
             Self::Repairs { .. } => write!(f, "Repairs"),
         }
     }
@@ -536,6 +736,10 @@ pub struct SpaceshipUpgrade {
     pub target: SpaceshipUpgradeTarget,
     pub started: Tick,
     pub duration: Tick,
+    // Percentage saved on the repairs cost thanks to the engineer.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub repair_discount: u8,
 }
 
 impl SpaceshipUpgrade {
@@ -543,7 +747,7 @@ impl SpaceshipUpgrade {
     pub const REPAIR_BASE_DURATION: Tick = 2 * MINUTES;
     pub const SPACESHIP_UPGRADE_BASE_DURATION: Tick = 8 * HOURS;
 
-    pub fn new(target: SpaceshipUpgradeTarget) -> Self {
+    pub fn new(target: SpaceshipUpgradeTarget, started: Tick) -> Self {
         let duration = match target {
             SpaceshipUpgradeTarget::Repairs { amount } => {
                 amount as Tick * SpaceshipUpgrade::REPAIR_BASE_DURATION
@@ -551,10 +755,21 @@ impl SpaceshipUpgrade {
             _ => SpaceshipUpgrade::SPACESHIP_UPGRADE_BASE_DURATION,
         };
         SpaceshipUpgrade {
-            started: Tick::now(),
+            started,
             duration,
             target,
+            repair_discount: 0,
+        }
+    }
+
+    // The engineer speeds up the repairs and saves on their cost.
+    pub fn with_repair_bonus(mut self, bonus: f32) -> Self {
+        if let SpaceshipUpgradeTarget::Repairs { .. } = self.target {
+            let bonus = bonus.max(1.0);
+            self.duration = (self.duration as f32 / bonus) as Tick;
+            self.repair_discount = ((1.0 - 1.0 / bonus) * 100.0).round() as u8;
         }
+        self
     }
 
     pub fn description(&self) -> String {
@@ -569,11 +784,18 @@ impl SpaceshipUpgrade {
             SpaceshipUpgradeTarget::Hull { component } => component.previous().upgrade_cost(),
             SpaceshipUpgradeTarget::Engine { component } => component.previous().upgrade_cost(),
             SpaceshipUpgradeTarget::Storage { component } => component.previous().upgrade_cost(),
+            SpaceshipUpgradeTarget::LifeSupport { component } => {
+                component.previous().upgrade_cost()
+            }
+            SpaceshipUpgradeTarget::Scanner { component } => component.previous().upgrade_cost(),
             SpaceshipUpgradeTarget::Repairs { amount } => {
                 vec![
                     (
                         Resource::SATOSHI,
-                        amount * SpaceshipUpgrade::REPAIR_BASE_COST,
+                        amount
+                            * SpaceshipUpgrade::REPAIR_BASE_COST
+                            * (100 - self.repair_discount as u32)
+                            / 100,
                     ),
                     (Resource::SCRAPS, amount),
                 ]
@@ -582,6 +804,140 @@ impl SpaceshipUpgrade {
     }
 }
 
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Hash, EnumIter)]
+#[repr(u8)]
+pub enum SpaceshipModule {
+    ShieldGenerator,
+    MiningLaser,
+    TractorBeam,
+    TrainingRoom,
+}
+
+impl Display for SpaceshipModule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ShieldGenerator => write!(f, "Shield generator"),
+            Self::MiningLaser => write!(f, "Mining laser"),
+            Self::TractorBeam => write!(f, "Tractor beam"),
+            Self::TrainingRoom => write!(f, "Training room"),
+        }
+    }
+}
+
+impl SpaceshipModule {
+    pub const MAX_INDEX: usize = 4; // = SpaceshipModule::iter().count();
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::ShieldGenerator => "Absorbs part of the damage taken during space adventures.",
+            Self::MiningLaser => "Increases the shooter charge and breaks asteroids apart faster.",
+            Self::TractorBeam => "Pulls resource fragments towards the spaceship more strongly.",
+            Self::TrainingRoom => "Makes the crew training sessions more effective.",
+        }
+    }
+
+    pub fn cost(&self) -> Vec<(Resource, u32)> {
+        match self {
+            Self::ShieldGenerator => vec![(Resource::SATOSHI, 12000), (Resource::SCRAPS, 150)],
+            Self::MiningLaser => vec![
+                (Resource::SATOSHI, 9000),
+                (Resource::SCRAPS, 80),
+                (Resource::GOLD, 1),
+            ],
+            Self::TractorBeam => vec![(Resource::SATOSHI, 7000), (Resource::SCRAPS, 100)],
+            Self::TrainingRoom => vec![(Resource::SATOSHI, 10000), (Resource::SCRAPS, 120)],
+        }
+    }
+
+    pub fn damage_reduction(&self) -> f32 {
+        match self {
+            Self::ShieldGenerator => 0.35,
+            _ => 0.0,
+        }
+    }
+
+    pub fn mining_multiplier(&self) -> f32 {
+        match self {
+            Self::MiningLaser => 1.6,
+            _ => 1.0,
+        }
+    }
+
+    pub fn charge_multiplier(&self) -> f32 {
+        match self {
+            Self::MiningLaser => 1.5,
+            _ => 1.0,
+        }
+    }
+
+    pub fn tractor_multiplier(&self) -> f32 {
+        match self {
+            Self::TractorBeam => 2.5,
+            _ => 1.0,
+        }
+    }
+}
+
+#[derive(
+    Debug, Default, Display, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Hash,
+)]
+#[repr(u8)]
+pub enum FuelGrade {
+    Dirty,
+    #[default]
+    Standard,
+    Premium,
+}
+
+impl FuelGrade {
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Dirty => Self::Standard,
+            Self::Standard => Self::Premium,
+            Self::Premium => Self::Dirty,
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Dirty => "Cheap fuel full of impurities. Burns fast and can damage the engine.",
+            Self::Standard => "Regular fuel, what every merchant sells.",
+            Self::Premium => "Refined fuel. Expensive, but the engine runs cleaner and faster.",
+        }
+    }
+
+    pub fn price_modifier(&self) -> f32 {
+        match self {
+            Self::Dirty => 0.6,
+            Self::Standard => 1.0,
+            Self::Premium => 1.6,
+        }
+    }
+
+    pub fn consumption_modifier(&self) -> f32 {
+        match self {
+            Self::Dirty => 1.3,
+            Self::Standard => 1.0,
+            Self::Premium => 0.85,
+        }
+    }
+
+    pub fn speed_modifier(&self) -> f32 {
+        match self {
+            Self::Premium => 1.05,
+            _ => 1.0,
+        }
+    }
+
+    // Probability per hour of travel that the engine breaks down.
+    pub fn breakdown_probability(&self) -> f64 {
+        match self {
+            Self::Dirty => 0.04,
+            _ => 0.0,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default, Hash)]
 pub struct Spaceship {
     pub name: String,
@@ -590,6 +946,23 @@ pub struct Spaceship {
     pub storage: Storage,
     #[serde(skip_serializing_if = "is_default")]
     #[serde(default)]
+    pub modules: Vec<SpaceshipModule>,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub fuel_grade: FuelGrade,
+    // Engine tuning trades speed for fuel consumption,
+    // from MIN_ENGINE_TUNING (economy) to MAX_ENGINE_TUNING (overdrive).
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub engine_tuning: i8,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub life_support: LifeSupport,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub scanner: Scanner,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
     current_durability: u32,
     pub image: SpaceshipImage,
     #[serde(skip_serializing_if = "is_default")]
@@ -613,6 +986,11 @@ impl Spaceship {
             hull,
             engine,
             storage,
+            modules: vec![],
+            fuel_grade: FuelGrade::default(),
+            engine_tuning: 0,
+            life_support: LifeSupport::default(),
+            scanner: Scanner::default(),
             current_durability: 0,
             image: SpaceshipImage::new(color_map),
             total_travelled: 0,
@@ -647,6 +1025,8 @@ impl Spaceship {
             SpaceshipUpgradeTarget::Hull { .. } => self.hull.can_be_upgraded(),
             SpaceshipUpgradeTarget::Engine { .. } => self.engine.can_be_upgraded(),
             SpaceshipUpgradeTarget::Storage { .. } => self.storage.can_be_upgraded(),
+            SpaceshipUpgradeTarget::LifeSupport { .. } => self.life_support.can_be_upgraded(),
+            SpaceshipUpgradeTarget::Scanner { .. } => self.scanner.can_be_upgraded(),
             SpaceshipUpgradeTarget::Repairs { .. } => self.can_be_repaired(),
         }
     }
@@ -695,7 +1075,12 @@ impl Spaceship {
 
     pub fn speed(&self, storage_units: u32) -> f32 {
         // Returns the speed in Km/ms (Kilometers per Tick)
-        BASE_SPEED * self.hull.speed() * self.engine.speed() * self.storage.speed()
+        BASE_SPEED
+            * self.hull.speed()
+            * self.engine.speed()
+            * self.storage.speed()
+            * self.fuel_grade.speed_modifier()
+            * (1.0 + ENGINE_TUNING_SPEED_STEP * self.engine_tuning as f32)
             / (1.0 + SPEED_PENALTY_PER_UNIT_STORAGE * storage_units as f32)
     }
 
@@ -711,8 +1096,35 @@ impl Spaceship {
         self.hull.durability() + self.engine.durability() + self.storage.durability()
     }
 
+    pub fn is_life_support_failing(&self) -> bool {
+        (self.current_durability as f32)
+            < self.durability() as f32 * LIFE_SUPPORT_FAILURE_DURABILITY_FRACTION
+    }
+
     pub fn crew_capacity(&self) -> u8 {
-        self.hull.crew_capacity() + self.engine.crew_capacity() + self.storage.crew_capacity()
+        let structural_capacity =
+            self.hull.crew_capacity() + self.engine.crew_capacity() + self.storage.crew_capacity();
+        let life_support_capacity = if self.is_life_support_failing() {
+            0
+        } else {
+            self.life_support.crew_capacity()
+        };
+        (structural_capacity + life_support_capacity).min(MAX_PLAYERS_PER_TEAM as u8)
+    }
+
+    // Returns how long the crew can breathe during a space adventure.
+    // Every pirate above the base crew consumes extra oxygen.
+    pub fn oxygen_autonomy(&self, crew_size: usize) -> Tick {
+        let crew_modifier = MIN_PLAYERS_PER_GAME as f32 / crew_size.max(1) as f32;
+        let failure_modifier = if self.is_life_support_failing() {
+            0.5
+        } else {
+            1.0
+        };
+        (BASE_OXYGEN_AUTONOMY as f32
+            * self.life_support.oxygen_multiplier()
+            * crew_modifier.min(1.0)
+            * failure_modifier) as Tick
     }
 
     pub fn storage_capacity(&self) -> u32 {
@@ -731,14 +1143,52 @@ impl Spaceship {
             * self.hull.fuel_consumption()
             * self.engine.fuel_consumption()
             * self.storage.fuel_consumption()
+            * self.fuel_grade.consumption_modifier()
+            * (1.0 + ENGINE_TUNING_CONSUMPTION_STEP * self.engine_tuning as f32)
             * (1.0 + FUEL_CONSUMPTION_PER_UNIT_STORAGE * storage_units as f32)
     }
 
     pub fn cost(&self) -> u32 {
-        let base_cost = self.hull.cost() + self.engine.cost() + self.storage.cost();
+        let base_cost = self.hull.cost()
+            + self.engine.cost()
+            + self.storage.cost()
+            + self.life_support.cost()
+            + self.scanner.cost();
         (base_cost as f32 * SPACESHIP_BASE_COST_MULTIPLIER) as u32
     }
 
+    pub fn module_slots(&self) -> usize {
+        self.size() as usize + 1
+    }
+
+    pub fn has_module(&self, module: SpaceshipModule) -> bool {
+        self.modules.contains(&module)
+    }
+
+    pub fn damage_reduction(&self) -> f32 {
+        // Reductions stack multiplicatively so that they can never reach 100%.
+        1.0 - self
+            .modules
+            .iter()
+            .map(|m| 1.0 - m.damage_reduction())
+            .product::<f32>()
+    }
+
+    pub fn mining_multiplier(&self) -> f32 {
+        self.modules.iter().map(|m| m.mining_multiplier()).product()
+    }
+
+    pub fn charge_multiplier(&self) -> f32 {
+        self.modules.iter().map(|m| m.charge_multiplier()).product()
+    }
+
+    pub fn tractor_multiplier(&self) -> f32 {
+        self.modules
+            .iter()
+            .map(|m| m.tractor_multiplier())
+            .product()
+    }
+
     pub fn max_distance(&self, current_fuel: u32) -> f32 {
         // Return the max distance in kilometers.
         let storage_units = 0;
@@ -910,6 +1360,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_spaceship_modules() {
+        let mut spaceship = SpaceshipPrefab::Ragnarok.spaceship("test".to_string());
+        assert_eq!(spaceship.damage_reduction(), 0.0);
+        assert_eq!(spaceship.mining_multiplier(), 1.0);
+        assert_eq!(spaceship.module_slots(), 3);
+
+        spaceship.modules.push(SpaceshipModule::ShieldGenerator);
+        spaceship.modules.push(SpaceshipModule::MiningLaser);
+        assert!(spaceship.has_module(SpaceshipModule::ShieldGenerator));
+        assert!(!spaceship.has_module(SpaceshipModule::TractorBeam));
+        assert!(spaceship.damage_reduction() > 0.0 && spaceship.damage_reduction() < 1.0);
+        assert!(spaceship.mining_multiplier() > 1.0);
+        assert!(spaceship.charge_multiplier() > 1.0);
+        assert_eq!(spaceship.tractor_multiplier(), 1.0);
+    }
+
+    #[test]
+    fn test_fuel_grade_and_engine_tuning() {
+        let mut spaceship = SpaceshipPrefab::Yukawa.spaceship("test".to_string());
+        let speed = spaceship.speed(0);
+        let consumption = spaceship.fuel_consumption(0);
+
+        spaceship.engine_tuning = MAX_ENGINE_TUNING;
+        assert!(spaceship.speed(0) > speed);
+        assert!(spaceship.fuel_consumption(0) > consumption);
+        // Overdrive gets there faster, but burns more fuel per distance.
+        assert!(
+            spaceship.max_distance(100)
+                < SpaceshipPrefab::Yukawa
+                    .spaceship("test".to_string())
+                    .max_distance(100)
+        );
+
+        spaceship.engine_tuning = MIN_ENGINE_TUNING;
+        assert!(spaceship.speed(0) < speed);
+        assert!(spaceship.fuel_consumption(0) < consumption);
+
+        spaceship.engine_tuning = 0;
+        spaceship.fuel_grade = FuelGrade::Dirty;
+        assert!(spaceship.fuel_consumption(0) > consumption);
+        spaceship.fuel_grade = FuelGrade::Premium;
+        assert!(spaceship.fuel_consumption(0) < consumption);
+        assert!(spaceship.speed(0) > speed);
+    }
+
     #[test]
     fn test_total_travelled_au() -> AppResult<()> {
         let name = "test".to_string();
@@ -973,4 +1469,27 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_life_support_crew_capacity() {
+        let mut spaceship = SpaceshipPrefab::Yukawa.spaceship("test".to_string());
+        let base_capacity = spaceship.crew_capacity();
+
+        spaceship.life_support = LifeSupport::Hydroponics;
+        assert_eq!(spaceship.crew_capacity(), base_capacity + 2);
+        assert!(spaceship.crew_capacity() as usize <= MAX_PLAYERS_PER_TEAM);
+
+        // A badly damaged spaceship loses the life support bonus.
+        spaceship.set_current_durability(0);
+        assert!(spaceship.is_life_support_failing());
+        assert_eq!(spaceship.crew_capacity(), base_capacity);
+
+        spaceship.reset_durability();
+        assert!(
+            spaceship.oxygen_autonomy(MIN_PLAYERS_PER_GAME)
+                > spaceship.oxygen_autonomy(MAX_PLAYERS_PER_TEAM)
+        );
+        assert_eq!(LifeSupport::Biosphere.next(), LifeSupport::Biosphere);
+        assert!(LifeSupport::Biosphere.upgrade_cost().is_empty());
+    }
 }
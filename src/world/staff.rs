@@ -0,0 +1,126 @@
+use super::{
+    constants::DAYS,
+    planet::Planet,
+    player::InfoStats,
+    skill::{Skill, MAX_SKILL},
+    types::TeamBonus,
+};
+use crate::types::{PlanetId, Tick};
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::fmt::Display;
+use strum::EnumIter;
+
+// Daily salary of a staff member, growing with their skill.
+pub const STAFF_BASE_SALARY: u32 = 120;
+pub const STAFF_SALARY_PER_SKILL: u32 = 20;
+// Hiring a staff member costs a week of salary upfront.
+pub const STAFF_HIRING_FEE_DAYS: u32 = 7;
+// A top staff member adds half of the base crew bonus.
+const STAFF_BONUS_PER_SKILL: f32 = 0.5 / MAX_SKILL;
+const MIN_CANDIDATE_SKILL: Skill = 4.0;
+
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Eq, Hash, EnumIter)]
+#[repr(u8)]
+pub enum StaffRole {
+    Coach,
+    Scout,
+    Mechanic,
+}
+
+impl Display for StaffRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Coach => write!(f, "Coach"),
+            Self::Scout => write!(f, "Scout"),
+            Self::Mechanic => write!(f, "Mechanic"),
+        }
+    }
+}
+
+impl StaffRole {
+    pub fn improves(&self, bonus: TeamBonus) -> bool {
+        match self {
+            Self::Coach => matches!(bonus, TeamBonus::Training),
+            Self::Scout => matches!(bonus, TeamBonus::Exploration),
+            Self::Mechanic => matches!(bonus, TeamBonus::Repairs),
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Coach => "Runs the drills, improving the training rate of the crew.",
+            Self::Scout => {
+                "Knows where to look, improving exploration and the chance of finding free pirates."
+            }
+            Self::Mechanic => {
+                "Keeps the spaceship in shape, lowering the duration and cost of repairs."
+            }
+        }
+    }
+}
+
+/// Non-playing crew member, paid a daily salary and improving one of the team bonuses.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StaffMember {
+    pub name: String,
+    pub role: StaffRole,
+    pub skill: Skill,
+    pub home_planet_id: PlanetId,
+    pub hired: Tick,
+}
+
+impl StaffMember {
+    pub fn random(rng: &mut ChaCha8Rng, role: StaffRole, planet: &Planet) -> Self {
+        let info = InfoStats::for_position(None, None, rng, planet);
+        Self {
+            name: info.full_name(),
+            role,
+            skill: rng.gen_range(MIN_CANDIDATE_SKILL..MAX_SKILL),
+            home_planet_id: planet.id,
+            hired: 0,
+        }
+    }
+
+    pub fn salary(&self) -> u32 {
+        STAFF_BASE_SALARY + STAFF_SALARY_PER_SKILL * self.skill as u32
+    }
+
+    pub fn hiring_fee(&self) -> u32 {
+        STAFF_HIRING_FEE_DAYS * self.salary()
+    }
+
+    pub fn bonus(&self) -> f32 {
+        STAFF_BONUS_PER_SKILL * self.skill
+    }
+
+    pub fn days_in_service(&self, tick: Tick) -> u128 {
+        tick.saturating_sub(self.hired) / DAYS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StaffMember, StaffRole, STAFF_BASE_SALARY, STAFF_HIRING_FEE_DAYS};
+    use crate::world::{skill::MAX_SKILL, types::TeamBonus, utils::PLANET_DATA};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_staff_member() {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let mut member = StaffMember::random(rng, StaffRole::Mechanic, &PLANET_DATA[0]);
+        assert!(!member.name.is_empty());
+        assert!(StaffRole::Mechanic.improves(TeamBonus::Repairs));
+        assert!(!StaffRole::Coach.improves(TeamBonus::Repairs));
+
+        member.skill = 0.0;
+        assert_eq!(member.salary(), STAFF_BASE_SALARY);
+        assert_eq!(member.bonus(), 0.0);
+        member.skill = MAX_SKILL;
+        assert_eq!(member.hiring_fee(), STAFF_HIRING_FEE_DAYS * member.salary());
+        assert_eq!(member.bonus(), 0.5);
+    }
+}
@@ -1,17 +1,41 @@
 use super::{
-    constants::{INITIAL_TEAM_BALANCE, MIN_PLAYERS_PER_GAME},
+    academy::{self, ACADEMY_DAILY_FEE, MAX_ACADEMY_PROSPECTS},
+    action_queue::QueuedAction,
+    constants::{
+        APPEARANCE_CHANGE_COST, AUTO_REFUEL_TARGET_STEP, BASE_DOCKING_FEE,
+        BOSS_RUMORS_FOR_ENCOUNTER, CREW_BONUS_COST_PER_PLAYER, EMBLEM_CHANGE_COST,
+        INITIAL_TEAM_BALANCE, MAX_AUTO_REFUEL_TARGET, MAX_ENGINE_TUNING, MAX_FLEET_SIZE,
+        MAX_PLANET_STANDING, MAX_QUEUED_ACTIONS, MIN_ENGINE_TUNING, MIN_PLAYERS_PER_GAME,
+        TEAM_GRUDGE_STANDING,
+    },
+    duel::DUEL_COOLDOWN,
+    elo::INITIAL_ELO_RATING,
+    emblem::Emblem,
+    gossip::Gossip,
+    hazards::{
+        HazardChoice, TravelHazard, CUSTOMS_FINE, DETOUR_FUEL_COST, TRADERS_FUEL_GIFT,
+        TRADERS_RUM_AMOUNT, TRADERS_RUM_PRICE,
+    },
+    insurance::{InsuranceKind, InsurancePolicy},
     jersey::Jersey,
-    planet::Planet,
+    loan::{max_loan_amount, Loan},
+    planet::{AsteroidFacility, Planet},
     player::Player,
     position::MAX_POSITION,
     resources::Resource,
     role::CrewRole,
-    spaceship::{Spaceship, SpaceshipUpgrade},
-    types::{TeamLocation, TrainingFocus},
+    salvage::{Derelict, DerelictChoice, TOW_FUEL_COST},
+    scouting::SCOUTING_COST,
+    skill::{GameSkill, MAX_SKILL},
+    space_upgrades::{SpaceAdventureProgression, SpaceAdventureUpgrade},
+    spaceship::{FuelGrade, Spaceship, SpaceshipModule, SpaceshipPrefab, SpaceshipUpgrade},
+    staff::{StaffMember, StaffRole},
+    training::TrainingSchedule,
+    types::{RenameTarget, TeamBonus, TeamLocation, TrainingFocus},
 };
 use crate::{
     game_engine::tactic::Tactic,
-    network::{challenge::Challenge, trade::Trade},
+    network::{challenge::Challenge, resource_trade::ResourceTrade, trade::Trade},
     types::*,
     world::{constants::MAX_PLAYERS_PER_TEAM, utils::is_default},
 };
@@ -27,6 +51,12 @@ pub struct CrewRoles {
     pub doctor: Option<PlayerId>,
     pub pilot: Option<PlayerId>,
     pub mozzo: Vec<PlayerId>,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub engineer: Option<PlayerId>,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub cook: Option<PlayerId>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
@@ -35,6 +65,19 @@ pub struct Team {
     pub version: u64,
     pub name: String,
     pub reputation: f32,
+    // Standing with each planet faction, as an offset to the galaxy reputation.
+    // Raised by games, trades and contracts, lowered by gossip and crimes.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub planet_reputation: HashMap<PlanetId, f32>,
+    // Standing with other crews, lowered by the gossip they heard about the team.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub team_reputation: HashMap<TeamId, f32>,
+    // Offences committed since the last long tick, see Gossip::strikes_to_spread.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub gossip_strikes: HashMap<Gossip, u32>,
     #[serde(skip_serializing_if = "is_default")]
     #[serde(default)]
     pub player_ids: Vec<PlayerId>,
@@ -43,8 +86,17 @@ pub struct Team {
     pub kartoffel_ids: Vec<KartoffelId>,
     pub crew_roles: CrewRoles,
     pub jersey: Jersey,
+    // None until the crew designs its own flag.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub emblem: Option<Emblem>,
     pub resources: ResourceMap,
     pub spaceship: Spaceship,
+    // Spaceships left docked at the team asteroids, with the asteroid they are docked at.
+    // Travel and exploration always use the active spaceship.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub docked_spaceships: Vec<(PlanetId, Spaceship)>,
     pub home_planet_id: PlanetId,
     #[serde(skip_serializing_if = "is_default")]
     #[serde(default)]
@@ -58,13 +110,81 @@ pub struct Team {
     #[serde(skip_serializing_if = "is_default")]
     #[serde(default)]
     pub network_game_record: [u32; 3], // Stores game record as wins/losses/draws
+    // Ladder rating from network games, unset until the first one is played.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub elo: Option<u32>,
     pub game_tactic: Tactic,
     pub training_focus: Option<TrainingFocus>,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub training_schedule: TrainingSchedule,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub on_strike_until: Option<Tick>,
+    // Remaining stops of a multi-leg travel, after the current leg.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub travel_route: Vec<PlanetId>,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub action_queue: Vec<QueuedAction>,
+    // Increased at every change to the action queue, to detect simultaneous orders.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub action_queue_version: u64,
+    // Team whose manager can give orders to this team over the network.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub co_captain_id: Option<TeamId>,
+    // Rumors about the Kartoffel mothership, heard while exploring.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub boss_rumors: u32,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub space_adventure_progression: SpaceAdventureProgression,
+    // Buy fuel up to the target percentage of the tank when landing on a planet with a market.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub auto_refuel: bool,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub auto_refuel_target: u8,
+    // Shipwreck found while exploring, until the crew decides what to do with it.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub derelict: Option<Derelict>,
+    // Hazard halting the current travel. Not persisted, so reloading simply resumes the travel.
+    #[serde(skip)]
+    pub travel_hazard: Option<TravelHazard>,
+    // Tick of the last onboard duel between crew members.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub last_duel: Tick,
+    // Satoshi owed to a planet bank.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub loan: Option<Loan>,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub insurance: Vec<InsurancePolicy>,
+    // Non-playing crew, at most one per role.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub staff: Vec<StaffMember>,
     #[serde(skip)]
     pub sent_trades: HashMap<(PlayerId, PlayerId), Trade>,
     #[serde(skip)]
     pub received_trades: HashMap<(PlayerId, PlayerId), Trade>,
     #[serde(skip)]
+    pub sent_resource_trades: HashMap<TeamId, ResourceTrade>,
+    #[serde(skip)]
+    pub received_resource_trades: HashMap<TeamId, ResourceTrade>,
+    // Trades accepted by the team, waiting for the proposer to settle first.
+    #[serde(skip)]
+    pub accepted_resource_trades: HashMap<TeamId, ResourceTrade>,
+    #[serde(skip)]
     pub sent_challenges: HashMap<TeamId, Challenge>,
     #[serde(skip)]
     pub received_challenges: HashMap<TeamId, Challenge>,
@@ -138,10 +258,54 @@ impl Team {
         self.received_trades.clear();
     }
 
+    pub fn add_sent_resource_trade(&mut self, trade: ResourceTrade) {
+        self.sent_resource_trades
+            .insert(trade.target_team_id, trade);
+    }
+
+    pub fn add_received_resource_trade(&mut self, trade: ResourceTrade) {
+        self.received_resource_trades
+            .insert(trade.proposer_team_id, trade);
+    }
+
+    pub fn remove_resource_trade(&mut self, proposer_team_id: TeamId, target_team_id: TeamId) {
+        let team_id = if proposer_team_id == self.id {
+            target_team_id
+        } else {
+            proposer_team_id
+        };
+        self.sent_resource_trades.remove(&team_id);
+        self.received_resource_trades.remove(&team_id);
+        self.accepted_resource_trades.remove(&team_id);
+    }
+
+    pub fn add_accepted_resource_trade(&mut self, trade: ResourceTrade) {
+        self.accepted_resource_trades
+            .insert(trade.proposer_team_id, trade);
+    }
+
+    pub fn clear_resource_trades(&mut self) {
+        self.sent_resource_trades.clear();
+        self.received_resource_trades.clear();
+        self.accepted_resource_trades.clear();
+    }
+
+    pub fn elo_rating(&self) -> u32 {
+        self.elo.unwrap_or(INITIAL_ELO_RATING)
+    }
+
     pub fn balance(&self) -> u32 {
         self.resources.value(&Resource::SATOSHI)
     }
 
+    // A team is insolvent when the treasury cannot cover the interest on its loan,
+    // or the loan is past its due date. Spending borrowed satoshi is fine.
+    pub fn is_insolvent(&self, current_tick: Tick) -> bool {
+        self.loan.as_ref().is_some_and(|loan| {
+            loan.is_overdue(current_tick) || self.balance() < loan.daily_interest()
+        })
+    }
+
     pub fn fuel(&self) -> u32 {
         self.resources.value(&Resource::FUEL)
     }
@@ -150,6 +314,26 @@ impl Team {
         self.spaceship.fuel_capacity()
     }
 
+    // Fuel missing to reach the auto-refuel target.
+    pub fn auto_refuel_amount(&self) -> u32 {
+        if !self.auto_refuel {
+            return 0;
+        }
+        let target = self.fuel_capacity() * self.auto_refuel_target as u32 / 100;
+        target.saturating_sub(self.fuel())
+    }
+
+    pub fn can_set_auto_refuel_target(&self, target: u8) -> AppResult<()> {
+        if target < AUTO_REFUEL_TARGET_STEP || target > MAX_AUTO_REFUEL_TARGET {
+            return Err(anyhow!(
+                "Target must be between {}% and {}%",
+                AUTO_REFUEL_TARGET_STEP,
+                MAX_AUTO_REFUEL_TARGET
+            ));
+        }
+        Ok(())
+    }
+
     pub fn used_storage_capacity(&self) -> u32 {
         self.resources.used_storage_capacity()
     }
@@ -167,6 +351,73 @@ impl Team {
             .fuel_consumption(self.used_storage_capacity())
     }
 
+    pub fn reputation_on_planet(&self, planet_id: PlanetId) -> f32 {
+        (self.reputation
+            + self
+                .planet_reputation
+                .get(&planet_id)
+                .copied()
+                .unwrap_or_default())
+        .bound()
+    }
+
+    pub fn hiring_reputation(&self, player: &Player) -> f32 {
+        match player.is_on_planet() {
+            Some(planet_id) => self.reputation_on_planet(planet_id),
+            None => self.reputation,
+        }
+    }
+
+    pub fn add_planet_standing(&mut self, planet_id: PlanetId, amount: f32) {
+        let offset = self.planet_reputation.entry(planet_id).or_default();
+        *offset = (*offset + amount).clamp(-MAX_SKILL, MAX_PLANET_STANDING);
+    }
+
+    pub fn add_team_standing(&mut self, team_id: TeamId, amount: f32) {
+        let offset = self.team_reputation.entry(team_id).or_default();
+        *offset = (*offset + amount).clamp(-MAX_SKILL, 0.0);
+    }
+
+    pub fn standing_with_team(&self, team_id: TeamId) -> f32 {
+        self.team_reputation
+            .get(&team_id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    // Crews refuse to deal with a team they heard too much bad gossip about, either way.
+    fn check_grudge(&self, team: &Team) -> AppResult<()> {
+        if self.standing_with_team(team.id) <= TEAM_GRUDGE_STANDING {
+            return Err(anyhow!("The {} hold a grudge against the crew", team.name));
+        }
+        if team.standing_with_team(self.id) <= TEAM_GRUDGE_STANDING {
+            return Err(anyhow!("Holding a grudge against the {}", team.name));
+        }
+        Ok(())
+    }
+
+    // Bad gossip makes merchants on a planet less willing to give a fair price,
+    // while a good standing earns a discount.
+    pub fn merchant_modifier(&self, planet_id: PlanetId) -> f32 {
+        let offset = self
+            .planet_reputation
+            .get(&planet_id)
+            .copied()
+            .unwrap_or_default();
+        (1.0 + offset / (2.0 * MAX_SKILL)).clamp(0.5, 1.0 + MAX_PLANET_STANDING / (2.0 * MAX_SKILL))
+    }
+
+    // Fee charged when landing on an inhabited planet. Teams land for free at home and on their asteroids.
+    pub fn docking_fee(&self, planet: &Planet) -> u32 {
+        if planet.total_population() == 0
+            || planet.id == self.home_planet_id
+            || self.asteroid_ids.contains(&planet.id)
+        {
+            return 0;
+        }
+        (BASE_DOCKING_FEE as f32 / self.merchant_modifier(planet.id)).round() as u32
+    }
+
     pub fn is_on_planet(&self) -> Option<PlanetId> {
         match self.current_location {
             TeamLocation::OnPlanet { planet_id } => Some(planet_id),
@@ -174,6 +425,11 @@ impl Team {
         }
     }
 
+    // The crew can outgrow the spaceship when life support fails.
+    pub fn is_overcrowded(&self) -> bool {
+        self.player_ids.len() > self.spaceship.crew_capacity() as usize
+    }
+
     pub fn can_add_player(&self, player: &Player) -> AppResult<()> {
         if player.team.is_some() {
             return Err(anyhow!("Already in a team"));
@@ -207,11 +463,22 @@ impl Team {
         Ok(())
     }
 
-    pub fn can_hire_player(&self, player: &Player) -> AppResult<()> {
+    pub fn can_set_starting_position(&self, player: &Player) -> AppResult<()> {
+        if let Some(injury) = player.injury {
+            return Err(anyhow!(
+                "{} is recovering from a {}",
+                player.info.shortened_name(),
+                injury.kind.to_string().to_lowercase()
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn can_hire_player(&self, player: &Player, hire_cost: u32) -> AppResult<()> {
         self.can_add_player(player)?;
-        let hiring_cost = player.hire_cost(self.reputation);
-        if self.balance() < hiring_cost {
-            return Err(anyhow!("Not enough money {}", hiring_cost));
+        if self.balance() < hire_cost {
+            return Err(anyhow!("Not enough money {}", hire_cost));
         }
 
         Ok(())
@@ -237,6 +504,45 @@ impl Team {
         Ok(())
     }
 
+    pub fn can_duel(
+        &self,
+        challenger: &Player,
+        opponent: &Player,
+        current_tick: Tick,
+    ) -> AppResult<()> {
+        if challenger.id == opponent.id {
+            return Err(anyhow!("A pirate cannot duel themselves"));
+        }
+
+        for player in [challenger, opponent] {
+            if !self.player_ids.contains(&player.id) {
+                return Err(anyhow!(
+                    "{} is not in the crew",
+                    player.info.shortened_name()
+                ));
+            }
+            if player.is_knocked_out() {
+                return Err(anyhow!("{} is exhausted", player.info.shortened_name()));
+            }
+            if player.is_injured() {
+                return Err(anyhow!("{} is injured", player.info.shortened_name()));
+            }
+        }
+
+        if self.current_game.is_some() {
+            return Err(anyhow!("Team is playing"));
+        }
+
+        if self.last_duel > 0 && current_tick < self.last_duel + DUEL_COOLDOWN {
+            return Err(anyhow!(
+                "Next duel in {}",
+                (self.last_duel + DUEL_COOLDOWN - current_tick).formatted()
+            ));
+        }
+
+        Ok(())
+    }
+
     pub fn can_set_crew_role(&self, player: &Player, role: CrewRole) -> AppResult<()> {
         if player.team.is_none() {
             return Err(anyhow!("Player is not in a team"));
@@ -267,6 +573,16 @@ impl Team {
                     return Err(anyhow!("Player is already mozzo"));
                 }
             }
+            CrewRole::Engineer => {
+                if self.crew_roles.engineer == Some(player.id) {
+                    return Err(anyhow!("Player is already engineer"));
+                }
+            }
+            CrewRole::Cook => {
+                if self.crew_roles.cook == Some(player.id) {
+                    return Err(anyhow!("Player is already cook"));
+                }
+            }
         }
         Ok(())
     }
@@ -280,6 +596,10 @@ impl Team {
             return Err(anyhow!("Cannot challenge self"));
         }
 
+        if self.is_on_strike() {
+            return Err(anyhow!("The crew refuses to play"));
+        }
+
         if self.is_on_planet().is_none() {
             return Err(anyhow!("Team is in space"));
         }
@@ -308,9 +628,19 @@ impl Team {
             return Err(anyhow!("Opponent is already playing"));
         }
 
+        self.check_grudge(team)?;
+
         self.can_challenge_team_over_network(team)
     }
 
+    // Positive amounts are paid by the team, negative ones are received.
+    pub fn can_pay_trade_satoshis(&self, amount: i64) -> AppResult<()> {
+        if amount > 0 && (self.balance() as i64) < amount {
+            return Err(anyhow!("Insufficient satoshi"));
+        }
+        Ok(())
+    }
+
     pub fn can_trade_players(
         &self,
         proposer_player: &Player,
@@ -346,6 +676,130 @@ impl Team {
             return Err(anyhow!("Opponent is playing"));
         }
 
+        self.check_grudge(target_team)?;
+
+        Ok(())
+    }
+
+    // Checks the roster and storage of the team once the players and
+    // resources of a bundle trade have been exchanged.
+    pub fn can_trade_bundle(
+        &self,
+        given_players: &[&Player],
+        received_players: &[&Player],
+        given_resources: &ResourceMap,
+        received_resources: &ResourceMap,
+        counterpart_team: &Team,
+    ) -> AppResult<()> {
+        if self.id == counterpart_team.id {
+            return Err(anyhow!("Cannot trade with oneself"));
+        }
+
+        for player in given_players.iter() {
+            if player.team != Some(self.id) || !self.player_ids.contains(&player.id) {
+                return Err(anyhow!(
+                    "{} is not part of the team",
+                    player.info.shortened_name()
+                ));
+            }
+        }
+
+        for player in received_players.iter() {
+            if player.team != Some(counterpart_team.id) {
+                return Err(anyhow!(
+                    "{} is not part of {}",
+                    player.info.shortened_name(),
+                    counterpart_team.name
+                ));
+            }
+        }
+
+        let roster_size = self.player_ids.len() + received_players.len() - given_players.len();
+        if roster_size > self.spaceship.crew_capacity() as usize {
+            return Err(anyhow!("Not enough room on the spaceship"));
+        }
+
+        self.check_grudge(counterpart_team)?;
+
+        self.exchanged_resource_bundle(given_resources, received_resources)?;
+        Ok(())
+    }
+
+    pub fn can_exchange_resources(
+        &self,
+        counterpart_team: &Team,
+        given: (Resource, u32),
+        received: (Resource, u32),
+    ) -> AppResult<()> {
+        if self.id == counterpart_team.id {
+            return Err(anyhow!("Cannot trade with oneself"));
+        }
+
+        if self.is_on_planet().is_none() || self.is_on_planet() != counterpart_team.is_on_planet() {
+            return Err(anyhow!("Not on the same planet"));
+        }
+
+        if self.current_game.is_some() {
+            return Err(anyhow!("Team is playing"));
+        }
+
+        self.exchanged_resources(given, received)?;
+        Ok(())
+    }
+
+    fn exchanged_resources(
+        &self,
+        (given, given_amount): (Resource, u32),
+        (received, received_amount): (Resource, u32),
+    ) -> AppResult<ResourceMap> {
+        let max_capacity = if received == Resource::FUEL {
+            self.fuel_capacity()
+        } else {
+            self.storage_capacity()
+        };
+
+        let mut resources = self.resources.clone();
+        resources.sub(given, given_amount)?;
+        resources.add(received, received_amount, max_capacity)?;
+        Ok(resources)
+    }
+
+    fn exchanged_resource_bundle(
+        &self,
+        given: &ResourceMap,
+        received: &ResourceMap,
+    ) -> AppResult<ResourceMap> {
+        let mut resources = self.resources.clone();
+        for (resource, amount) in given.iter() {
+            resources.sub(*resource, *amount)?;
+        }
+        for (resource, amount) in received.iter() {
+            let max_capacity = if *resource == Resource::FUEL {
+                self.fuel_capacity()
+            } else {
+                self.storage_capacity()
+            };
+            resources.add(*resource, *amount, max_capacity)?;
+        }
+        Ok(resources)
+    }
+
+    pub fn exchange_resource_bundle(
+        &mut self,
+        given: &ResourceMap,
+        received: &ResourceMap,
+    ) -> AppResult<()> {
+        self.resources = self.exchanged_resource_bundle(given, received)?;
+        Ok(())
+    }
+
+    // Gives and receives resources at once, leaving the team untouched on failure.
+    pub fn exchange_resources(
+        &mut self,
+        given: (Resource, u32),
+        received: (Resource, u32),
+    ) -> AppResult<()> {
+        self.resources = self.exchanged_resources(given, received)?;
         Ok(())
     }
 
@@ -396,6 +850,11 @@ impl Team {
         Ok(())
     }
 
+    // Enough rumors have been heard to track down the Kartoffel mothership.
+    pub fn has_boss_encounter(&self) -> bool {
+        self.boss_rumors >= BOSS_RUMORS_FOR_ENCOUNTER
+    }
+
     pub fn can_start_space_adventure(&self) -> AppResult<()> {
         if self.player_ids.len() < 1 {
             return Err(anyhow!("No pirate to explore"));
@@ -424,6 +883,26 @@ impl Team {
         Ok(())
     }
 
+    pub fn can_scan_planets(&self) -> AppResult<()> {
+        if self.spaceship.scanner.range() == 0 {
+            return Err(anyhow!("No scanner installed"));
+        }
+
+        if self.is_on_planet().is_none() {
+            return Err(anyhow!("Scanning is only possible on a planet"));
+        }
+
+        if self.spaceship.pending_upgrade.is_some() {
+            return Err(anyhow!("Upgrading spaceship"));
+        }
+
+        if self.fuel() < self.spaceship.scanner.fuel_cost() {
+            return Err(anyhow!("Not enough fuel"));
+        }
+
+        Ok(())
+    }
+
     pub fn can_explore_around_planet(
         &self,
         planet: &Planet,
@@ -455,6 +934,106 @@ impl Team {
         Ok(())
     }
 
+    pub fn can_queue_action(&self, action: QueuedAction) -> AppResult<()> {
+        if self.action_queue.len() >= MAX_QUEUED_ACTIONS {
+            return Err(anyhow!("Too many orders queued"));
+        }
+
+        if let QueuedAction::TravelTo { planet_id } = action {
+            // The last queued travel, or the current position, is where the team will be.
+            let last_destination = self
+                .action_queue
+                .iter()
+                .rev()
+                .find_map(|queued| match queued {
+                    QueuedAction::TravelTo { planet_id } => Some(*planet_id),
+                    QueuedAction::ReturnHome => Some(self.home_planet_id),
+                    _ => None,
+                })
+                .or(self.is_on_planet());
+            if last_destination == Some(planet_id) {
+                return Err(anyhow!("Already going to this planet"));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn can_change_appearance(&self, jersey: &Jersey) -> AppResult<()> {
+        if self.current_game.is_some() {
+            return Err(anyhow!("Team is playing"));
+        }
+
+        // While travelling the crew wears the pirate jersey.
+        if self.is_on_planet().is_none() {
+            return Err(anyhow!("Team is not on a planet"));
+        }
+
+        if !jersey.style.is_available_at_creation() {
+            return Err(anyhow!("Jersey style {} is not available", jersey.style));
+        }
+
+        if *jersey == self.jersey && jersey.color == self.spaceship.image.color_map() {
+            return Err(anyhow!("Appearance is unchanged"));
+        }
+
+        if self.balance() < APPEARANCE_CHANGE_COST {
+            return Err(anyhow!("Insufficient satoshi"));
+        }
+
+        Ok(())
+    }
+
+    pub fn emblem(&self) -> Emblem {
+        self.emblem
+            .unwrap_or_else(|| Emblem::from_jersey(&self.jersey))
+    }
+
+    pub fn can_change_emblem(&self, emblem: &Emblem) -> AppResult<()> {
+        if self.current_game.is_some() {
+            return Err(anyhow!("Team is playing"));
+        }
+
+        if *emblem == self.emblem() {
+            return Err(anyhow!("Emblem is unchanged"));
+        }
+
+        if self.balance() < EMBLEM_CHANGE_COST {
+            return Err(anyhow!("Insufficient satoshi"));
+        }
+
+        Ok(())
+    }
+
+    pub fn can_rename(&self, target: RenameTarget, name: &str) -> AppResult<()> {
+        if name.trim().is_empty() {
+            return Err(anyhow!("Name is empty"));
+        }
+
+        if self.current_game.is_some() {
+            return Err(anyhow!("Team is playing"));
+        }
+
+        match target {
+            RenameTarget::Team if name == self.name => {
+                return Err(anyhow!("Name is unchanged"));
+            }
+            RenameTarget::Spaceship if name == self.spaceship.name => {
+                return Err(anyhow!("Name is unchanged"));
+            }
+            RenameTarget::Player { player_id } if !self.player_ids.contains(&player_id) => {
+                return Err(anyhow!("Player is not in the crew"));
+            }
+            _ => {}
+        }
+
+        if self.balance() < target.cost() {
+            return Err(anyhow!("Insufficient satoshi"));
+        }
+
+        Ok(())
+    }
+
     pub fn can_change_training_focus(&self) -> AppResult<()> {
         if self.current_game.is_some() {
             return Err(anyhow!("Team is playing"));
@@ -512,7 +1091,492 @@ impl Team {
         Ok(())
     }
 
-    pub fn max_resource_storage_capacity(&self, resource: Resource) -> u32 {
+    pub fn can_unlock_space_adventure_upgrade(
+        &self,
+        upgrade: SpaceAdventureUpgrade,
+    ) -> AppResult<()> {
+        if self.is_on_planet().is_none() {
+            return Err(anyhow!("Can only upgrade on a planet"));
+        }
+
+        self.space_adventure_progression.can_unlock(upgrade)?;
+
+        for (resource, amount) in self.space_adventure_progression.next_cost(upgrade).iter() {
+            if self.resources.value(resource) < *amount {
+                return Err(anyhow!("Insufficient resources"));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn can_install_spaceship_module(&self, module: SpaceshipModule) -> AppResult<()> {
+        if self.is_on_planet().is_none() {
+            return Err(anyhow!("Can only install modules on a planet"));
+        }
+
+        if self.spaceship.pending_upgrade.is_some() {
+            return Err(anyhow!("Upgrading spaceship"));
+        }
+
+        if self.spaceship.has_module(module) {
+            return Err(anyhow!("Module already installed"));
+        }
+
+        if self.spaceship.modules.len() >= self.spaceship.module_slots() {
+            return Err(anyhow!("No free module slot"));
+        }
+
+        for (resource, amount) in module.cost().iter() {
+            if self.resources.value(resource) < *amount {
+                return Err(anyhow!("Insufficient resources"));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn can_uninstall_spaceship_module(&self, module: SpaceshipModule) -> AppResult<()> {
+        if self.is_on_planet().is_none() {
+            return Err(anyhow!("Can only uninstall modules on a planet"));
+        }
+
+        if self.spaceship.pending_upgrade.is_some() {
+            return Err(anyhow!("Upgrading spaceship"));
+        }
+
+        if !self.spaceship.has_module(module) {
+            return Err(anyhow!("Module is not installed"));
+        }
+
+        Ok(())
+    }
+
+    pub fn fleet_size(&self) -> usize {
+        1 + self.docked_spaceships.len()
+    }
+
+    pub fn docked_spaceships_at(&self, planet_id: PlanetId) -> Vec<&Spaceship> {
+        self.docked_spaceships
+            .iter()
+            .filter(|(id, _)| *id == planet_id)
+            .map(|(_, spaceship)| spaceship)
+            .collect()
+    }
+
+    fn is_on_own_asteroid(&self) -> AppResult<PlanetId> {
+        match self.is_on_planet() {
+            Some(planet_id) if self.asteroid_ids.contains(&planet_id) => Ok(planet_id),
+            _ => Err(anyhow!("Must be on an own asteroid")),
+        }
+    }
+
+    pub fn can_buy_spaceship(&self, prefab: SpaceshipPrefab) -> AppResult<()> {
+        self.is_on_own_asteroid()?;
+
+        if self.current_game.is_some() {
+            return Err(anyhow!("Team is currently playing"));
+        }
+
+        if self.fleet_size() >= MAX_FLEET_SIZE {
+            return Err(anyhow!("Fleet is full"));
+        }
+
+        if self.balance() < prefab.cost() {
+            return Err(anyhow!("Insufficient satoshi"));
+        }
+
+        Ok(())
+    }
+
+    pub fn can_switch_spaceship(&self) -> AppResult<()> {
+        let planet_id = self.is_on_own_asteroid()?;
+
+        if self.current_game.is_some() {
+            return Err(anyhow!("Team is currently playing"));
+        }
+
+        if self.spaceship.pending_upgrade.is_some() {
+            return Err(anyhow!("Upgrading spaceship"));
+        }
+
+        let spaceship = self
+            .docked_spaceships_at(planet_id)
+            .first()
+            .copied()
+            .ok_or(anyhow!("No spaceship docked here"))?;
+
+        if self.player_ids.len() > spaceship.crew_capacity() as usize {
+            return Err(anyhow!("Crew does not fit in {}", spaceship.name));
+        }
+
+        if self.used_storage_capacity() > spaceship.storage_capacity() {
+            return Err(anyhow!("Cargo does not fit in {}", spaceship.name));
+        }
+
+        if self.fuel() > spaceship.fuel_capacity() {
+            return Err(anyhow!("Fuel does not fit in {}", spaceship.name));
+        }
+
+        Ok(())
+    }
+
+    // Fly the first spaceship docked at the current asteroid, leaving the active one
+    // docked in its place. Repeated switches cycle through the ships docked there.
+    pub fn switch_spaceship(&mut self) -> AppResult<()> {
+        self.can_switch_spaceship()?;
+        let planet_id = self.is_on_own_asteroid()?;
+        let index = self
+            .docked_spaceships
+            .iter()
+            .position(|(id, _)| *id == planet_id)
+            .ok_or(anyhow!("No spaceship docked here"))?;
+        let (_, spaceship) = self.docked_spaceships.remove(index);
+        let previous = std::mem::replace(&mut self.spaceship, spaceship);
+        self.docked_spaceships.push((planet_id, previous));
+        Ok(())
+    }
+
+    pub fn can_resolve_derelict(&self, choice: DerelictChoice) -> AppResult<()> {
+        let derelict = self
+            .derelict
+            .as_ref()
+            .ok_or(anyhow!("No derelict to board"))?;
+
+        if choice == DerelictChoice::Leave {
+            return Ok(());
+        }
+
+        if self.is_on_planet() != Some(derelict.found_around) {
+            return Err(anyhow!("The derelict has drifted away"));
+        }
+
+        if self.current_game.is_some() {
+            return Err(anyhow!("Team is currently playing"));
+        }
+
+        if choice == DerelictChoice::Tow && self.fuel() < TOW_FUEL_COST {
+            return Err(anyhow!("Not enough fuel to tow"));
+        }
+
+        Ok(())
+    }
+
+    pub fn can_resolve_travel_hazard(&self, choice: HazardChoice) -> AppResult<()> {
+        let hazard = self
+            .travel_hazard
+            .as_ref()
+            .ok_or(anyhow!("No hazard to deal with"))?;
+
+        if !hazard.kind.choices().contains(&choice) {
+            return Err(anyhow!("Invalid choice for {}", hazard.kind));
+        }
+
+        match choice {
+            HazardChoice::Detour if self.fuel() < DETOUR_FUEL_COST => {
+                Err(anyhow!("Not enough fuel for a detour"))
+            }
+            HazardChoice::Help if self.fuel() < TRADERS_FUEL_GIFT => {
+                Err(anyhow!("Not enough fuel to spare"))
+            }
+            HazardChoice::PayFine if self.balance() < CUSTOMS_FINE => {
+                Err(anyhow!("Not enough satoshi to pay the fine"))
+            }
+            HazardChoice::Trade if self.balance() < TRADERS_RUM_PRICE => {
+                Err(anyhow!("Not enough satoshi to buy rum"))
+            }
+            HazardChoice::Trade
+                if self.used_storage_capacity()
+                    + TRADERS_RUM_AMOUNT * Resource::RUM.to_storing_space()
+                    > self.storage_capacity() =>
+            {
+                Err(anyhow!("Not enough storage for the rum"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn can_take_loan(&self, planet: &Planet, amount: u32) -> AppResult<()> {
+        if amount == 0 {
+            return Err(anyhow!("Invalid amount"));
+        }
+
+        if self.is_on_planet() != Some(planet.id) {
+            return Err(anyhow!("Can only borrow from the current planet bank"));
+        }
+
+        if planet.total_population() == 0 {
+            return Err(anyhow!("There is no bank on {}", planet.name));
+        }
+
+        let debt = match self.loan.as_ref() {
+            Some(loan) if loan.planet_id != planet.id => {
+                return Err(anyhow!("Repay the current loan first"));
+            }
+            Some(loan) => loan.debt,
+            None => 0,
+        };
+
+        let max_amount = max_loan_amount(self);
+        if debt + amount > max_amount {
+            return Err(anyhow!("The bank lends at most {} satoshi", max_amount));
+        }
+
+        Ok(())
+    }
+
+    pub fn can_repay_loan(&self, amount: u32) -> AppResult<()> {
+        let loan = self.loan.as_ref().ok_or(anyhow!("No loan to repay"))?;
+        if amount == 0 {
+            return Err(anyhow!("Invalid amount"));
+        }
+
+        if self.balance() < amount.min(loan.debt) {
+            return Err(anyhow!("Insufficient satoshi"));
+        }
+
+        Ok(())
+    }
+
+    pub fn active_insurance(
+        &self,
+        kind: InsuranceKind,
+        current_tick: Tick,
+    ) -> Option<&InsurancePolicy> {
+        self.insurance
+            .iter()
+            .find(|policy| policy.kind == kind && policy.is_active(current_tick))
+    }
+
+    pub fn can_buy_insurance(&self, kind: InsuranceKind, current_tick: Tick) -> AppResult<()> {
+        if self.active_insurance(kind, current_tick).is_some() {
+            return Err(anyhow!("{} is already insured", kind));
+        }
+
+        if self.balance() < kind.premium(&self.spaceship) {
+            return Err(anyhow!("Insufficient satoshi"));
+        }
+
+        Ok(())
+    }
+
+    pub fn can_scout_planet(&self, planet: &Planet) -> AppResult<()> {
+        if planet.total_population() == 0 {
+            return Err(anyhow!("Nobody to scout on {}", planet.name));
+        }
+
+        if self.is_on_planet() != Some(planet.id) {
+            return Err(anyhow!("Can only scout the current planet"));
+        }
+
+        if self.balance() < SCOUTING_COST {
+            return Err(anyhow!("Insufficient satoshi"));
+        }
+
+        Ok(())
+    }
+
+    pub fn can_enroll_in_academy(&self, player: &Player, prospects: usize) -> AppResult<()> {
+        academy::can_enroll(player)?;
+
+        if self.asteroid_ids.is_empty() {
+            return Err(anyhow!("An asteroid is needed to host the academy"));
+        }
+
+        if self.is_on_planet().is_none() || self.is_on_planet() != player.is_on_planet() {
+            return Err(anyhow!("Not on the same planet"));
+        }
+
+        if prospects >= MAX_ACADEMY_PROSPECTS {
+            return Err(anyhow!("Academy is full"));
+        }
+
+        if self.balance() < ACADEMY_DAILY_FEE {
+            return Err(anyhow!("Insufficient satoshi"));
+        }
+
+        Ok(())
+    }
+
+    pub fn can_promote_prospect(&self, player: &Player) -> AppResult<()> {
+        let (asteroid_id, _) =
+            academy::academy_location(player).ok_or(anyhow!("Not in the academy"))?;
+
+        if player.team != Some(self.id) {
+            return Err(anyhow!("Not in the team academy"));
+        }
+
+        if self.player_ids.len() >= self.spaceship.crew_capacity() as usize {
+            return Err(anyhow!("Team is full"));
+        }
+
+        if self.is_on_planet() != Some(asteroid_id) {
+            return Err(anyhow!("Can only promote on the academy asteroid"));
+        }
+
+        if self.current_game.is_some() {
+            return Err(anyhow!("Team is playing"));
+        }
+
+        Ok(())
+    }
+
+    pub fn staff_member(&self, role: StaffRole) -> Option<&StaffMember> {
+        self.staff.iter().find(|member| member.role == role)
+    }
+
+    pub fn staff_bonus(&self, bonus: TeamBonus) -> f32 {
+        self.staff
+            .iter()
+            .filter(|member| member.role.improves(bonus))
+            .map(|member| member.bonus())
+            .sum()
+    }
+
+    pub fn staff_salaries(&self) -> u32 {
+        self.staff.iter().map(|member| member.salary()).sum()
+    }
+
+    pub fn can_hire_staff(&self, candidate: &StaffMember) -> AppResult<()> {
+        if self.staff_member(candidate.role).is_some() {
+            return Err(anyhow!(
+                "A {} is already hired",
+                candidate.role.to_string().to_lowercase()
+            ));
+        }
+
+        if self.is_on_planet() != Some(candidate.home_planet_id) {
+            return Err(anyhow!("Not on the same planet"));
+        }
+
+        if self.balance() < candidate.hiring_fee() {
+            return Err(anyhow!("Insufficient satoshi"));
+        }
+
+        Ok(())
+    }
+
+    pub fn can_fire_staff(&self, role: StaffRole) -> AppResult<()> {
+        if self.staff_member(role).is_none() {
+            return Err(anyhow!("No {} hired", role.to_string().to_lowercase()));
+        }
+
+        Ok(())
+    }
+
+    pub fn can_release_prospect(&self, player: &Player) -> AppResult<()> {
+        if academy::academy_location(player).is_none() {
+            return Err(anyhow!("Not in the academy"));
+        }
+
+        if player.team != Some(self.id) {
+            return Err(anyhow!("Not in the team academy"));
+        }
+
+        Ok(())
+    }
+
+    pub fn can_build_asteroid_facility(
+        &self,
+        asteroid: &Planet,
+        facility: AsteroidFacility,
+    ) -> AppResult<()> {
+        if !self.asteroid_ids.contains(&asteroid.id) {
+            return Err(anyhow!("Asteroid is not owned by the team"));
+        }
+
+        if self.is_on_planet() != Some(asteroid.id) {
+            return Err(anyhow!("Can only build facilities on the asteroid"));
+        }
+
+        if asteroid.has_facility(facility) {
+            return Err(anyhow!("Facility already built"));
+        }
+
+        for (resource, amount) in facility.cost().iter() {
+            if self.resources.value(resource) < *amount {
+                return Err(anyhow!("Insufficient resources"));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn crew_bonus_cost(&self) -> u32 {
+        self.player_ids.len() as u32 * CREW_BONUS_COST_PER_PLAYER
+    }
+
+    fn can_address_crew(&self) -> AppResult<()> {
+        if self.crew_roles.captain.is_none() {
+            return Err(anyhow!("No captain to address the crew"));
+        }
+
+        if self.player_ids.len() < 1 {
+            return Err(anyhow!("No pirate to address"));
+        }
+
+        if self.current_game.is_some() {
+            return Err(anyhow!("Team is playing"));
+        }
+
+        Ok(())
+    }
+
+    pub fn can_pay_crew_bonus(&self) -> AppResult<()> {
+        self.can_address_crew()?;
+
+        if self.balance() < self.crew_bonus_cost() {
+            return Err(anyhow!("Not enough satoshi"));
+        }
+
+        Ok(())
+    }
+
+    pub fn can_share_rum(&self) -> AppResult<()> {
+        self.can_address_crew()?;
+
+        if self.resources.value(&Resource::RUM) < self.player_ids.len() as u32 {
+            return Err(anyhow!("Not enough rum for everyone"));
+        }
+
+        Ok(())
+    }
+
+    pub fn is_on_strike(&self) -> bool {
+        self.on_strike_until.is_some()
+    }
+
+    pub fn can_set_fuel_grade(&self, grade: FuelGrade) -> AppResult<()> {
+        if self.is_on_planet().is_none() {
+            return Err(anyhow!("Can only change fuel on a planet"));
+        }
+
+        if self.spaceship.fuel_grade == grade {
+            return Err(anyhow!("Already using {} fuel", grade));
+        }
+
+        // Fuel cannot be refined in the tank: switching to a more expensive grade
+        // requires an empty tank, otherwise it would be free to upgrade cheap fuel.
+        if grade.price_modifier() > self.spaceship.fuel_grade.price_modifier() && self.fuel() > 0 {
+            return Err(anyhow!("Empty the tank first"));
+        }
+
+        Ok(())
+    }
+
+    pub fn can_set_engine_tuning(&self, tuning: i8) -> AppResult<()> {
+        if self.is_on_planet().is_none() {
+            return Err(anyhow!("Can only tune the engine on a planet"));
+        }
+
+        if tuning < MIN_ENGINE_TUNING || tuning > MAX_ENGINE_TUNING {
+            return Err(anyhow!("Invalid engine tuning"));
+        }
+
+        Ok(())
+    }
+
+    pub fn max_resource_storage_capacity(&self, resource: Resource) -> u32 {
         if resource == Resource::FUEL {
             self.spaceship.fuel_capacity() - self.fuel()
         } else {
@@ -616,8 +1680,22 @@ impl Team {
 #[cfg(test)]
 mod tests {
     use crate::{
-        types::TeamId,
-        world::{planet::Planet, utils::TEAM_DATA},
+        types::{PlanetId, TeamId},
+        world::{
+            constants::{
+                APPEARANCE_CHANGE_COST, BASE_DOCKING_FEE, EMBLEM_CHANGE_COST, MAX_FLEET_SIZE,
+                MAX_PLANET_STANDING,
+            },
+            emblem::Emblem,
+            jersey::JerseyStyle,
+            planet::Planet,
+            resources::Resource,
+            skill::MAX_SKILL,
+            spaceship::SpaceshipPrefab,
+            types::Population,
+            types::TeamLocation,
+            utils::TEAM_DATA,
+        },
     };
 
     #[test]
@@ -626,4 +1704,153 @@ mod tests {
         let team = super::Team::random(TeamId::new_v4(), Planet::default().id, name, "test".into());
         println!("{:?}", team);
     }
+
+    #[test]
+    fn test_can_change_appearance() {
+        let (name, _) = TEAM_DATA[0].clone();
+        let mut team =
+            super::Team::random(TeamId::new_v4(), Planet::default().id, name, "test".into());
+        team.resources
+            .insert(Resource::SATOSHI, APPEARANCE_CHANGE_COST);
+
+        let mut jersey = team.jersey.clone();
+        jersey.style = jersey.style.next();
+        assert!(team.can_change_appearance(&jersey).is_ok());
+
+        assert!(team.can_change_appearance(&team.jersey.clone()).is_err());
+
+        jersey.style = JerseyStyle::Pirate;
+        assert!(team.can_change_appearance(&jersey).is_err());
+
+        jersey.style = team.jersey.style.next();
+        team.resources
+            .insert(Resource::SATOSHI, APPEARANCE_CHANGE_COST - 1);
+        assert!(team.can_change_appearance(&jersey).is_err());
+    }
+
+    #[test]
+    fn test_can_change_emblem() {
+        let (name, _) = TEAM_DATA[0].clone();
+        let mut team =
+            super::Team::random(TeamId::new_v4(), Planet::default().id, name, "test".into());
+        team.resources.insert(Resource::SATOSHI, EMBLEM_CHANGE_COST);
+
+        // Without a custom emblem the team flies the jersey colors.
+        assert_eq!(team.emblem(), Emblem::from_jersey(&team.jersey));
+        assert!(team.can_change_emblem(&team.emblem()).is_err());
+
+        let mut emblem = team.emblem();
+        emblem.symbol = emblem.symbol.next();
+        assert!(team.can_change_emblem(&emblem).is_ok());
+
+        team.resources
+            .insert(Resource::SATOSHI, EMBLEM_CHANGE_COST - 1);
+        assert!(team.can_change_emblem(&emblem).is_err());
+    }
+
+    #[test]
+    fn test_planet_reputation() {
+        let (name, _) = TEAM_DATA[0].clone();
+        let mut team =
+            super::Team::random(TeamId::new_v4(), Planet::default().id, name, "test".into());
+        team.reputation = 10.0;
+        let planet_id = PlanetId::new_v4();
+        assert_eq!(team.reputation_on_planet(planet_id), 10.0);
+        assert_eq!(team.merchant_modifier(planet_id), 1.0);
+
+        team.planet_reputation.insert(planet_id, -4.0);
+        assert_eq!(team.reputation_on_planet(planet_id), 6.0);
+        assert!(team.merchant_modifier(planet_id) < 1.0);
+
+        team.planet_reputation.insert(planet_id, -100.0);
+        assert_eq!(team.reputation_on_planet(planet_id), 0.0);
+        assert_eq!(team.merchant_modifier(planet_id), 0.5);
+    }
+
+    #[test]
+    fn test_planet_standing_and_docking_fee() {
+        let (name, _) = TEAM_DATA[0].clone();
+        let mut team =
+            super::Team::random(TeamId::new_v4(), Planet::default().id, name, "test".into());
+        let mut planet = Planet::default();
+        planet.id = PlanetId::new_v4();
+        planet.populations.insert(Population::Yardalaim, 10);
+
+        let base_fee = team.docking_fee(&planet);
+        assert_eq!(base_fee, BASE_DOCKING_FEE);
+
+        team.add_planet_standing(planet.id, 100.0);
+        assert_eq!(team.planet_reputation[&planet.id], MAX_PLANET_STANDING);
+        assert!(team.merchant_modifier(planet.id) > 1.0);
+        assert!(team.docking_fee(&planet) < base_fee);
+
+        team.add_planet_standing(planet.id, -100.0);
+        assert_eq!(team.planet_reputation[&planet.id], -MAX_SKILL);
+        assert!(team.docking_fee(&planet) > base_fee);
+
+        // Landing at home is free.
+        team.home_planet_id = planet.id;
+        assert_eq!(team.docking_fee(&planet), 0);
+    }
+
+    #[test]
+    fn test_spaceship_fleet() {
+        let (name, _) = TEAM_DATA[0].clone();
+        let mut team =
+            super::Team::random(TeamId::new_v4(), Planet::default().id, name, "test".into());
+        team.spaceship = SpaceshipPrefab::Bresci.spaceship("Bresci".into());
+        team.resources.insert(Resource::SATOSHI, 1_000_000);
+
+        // Spaceships can only be bought and switched on an own asteroid.
+        assert!(team.can_buy_spaceship(SpaceshipPrefab::Cafiero).is_err());
+        let asteroid_id = PlanetId::new_v4();
+        team.asteroid_ids.push(asteroid_id);
+        team.current_location = TeamLocation::OnPlanet {
+            planet_id: asteroid_id,
+        };
+        assert!(team.can_buy_spaceship(SpaceshipPrefab::Cafiero).is_ok());
+        assert!(team.can_switch_spaceship().is_err());
+
+        while team.fleet_size() < MAX_FLEET_SIZE {
+            team.docked_spaceships.push((
+                asteroid_id,
+                SpaceshipPrefab::Cafiero.spaceship("Cafiero".into()),
+            ));
+        }
+        assert!(team.can_buy_spaceship(SpaceshipPrefab::Cafiero).is_err());
+
+        assert!(team.switch_spaceship().is_ok());
+        assert_eq!(team.spaceship.name, "Cafiero");
+        assert_eq!(team.fleet_size(), MAX_FLEET_SIZE);
+        assert_eq!(
+            team.docked_spaceships.last().map(|(_, s)| s.name.as_str()),
+            Some("Bresci")
+        );
+
+        // Switching cycles through all the docked spaceships.
+        for _ in 1..MAX_FLEET_SIZE {
+            assert!(team.switch_spaceship().is_ok());
+        }
+        assert_eq!(team.spaceship.name, "Bresci");
+    }
+
+    #[test]
+    fn test_auto_refuel_amount() {
+        let (name, _) = TEAM_DATA[0].clone();
+        let mut team =
+            super::Team::random(TeamId::new_v4(), Planet::default().id, name, "test".into());
+        let capacity = team.fuel_capacity();
+        assert_eq!(team.auto_refuel_amount(), 0);
+
+        team.auto_refuel = true;
+        team.auto_refuel_target = 50;
+        assert_eq!(team.auto_refuel_amount(), capacity / 2);
+
+        team.resources.insert(Resource::FUEL, capacity);
+        assert_eq!(team.auto_refuel_amount(), 0);
+
+        assert!(team.can_set_auto_refuel_target(0).is_err());
+        assert!(team.can_set_auto_refuel_target(110).is_err());
+        assert!(team.can_set_auto_refuel_target(80).is_ok());
+    }
 }
@@ -0,0 +1,337 @@
+use super::{
+    constants::{HOURS, MIN_PLAYERS_PER_GAME},
+    resources::Resource,
+    team::Team,
+};
+use crate::types::{AppResult, GameId, PlanetId, StorableResourceMap, TeamId, Tick, TournamentId};
+use anyhow::anyhow;
+use rand::{seq::SliceRandom, Rng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+pub const TOURNAMENT_MAX_PARTICIPANTS: usize = 8;
+pub const TOURNAMENT_MIN_PARTICIPANTS: usize = 3;
+pub const TOURNAMENT_REGISTRATION_DURATION: Tick = 6 * HOURS;
+// Time between the end of a cup and the announcement of the next one.
+pub const TOURNAMENT_INTERVAL: Tick = 12 * HOURS;
+pub const TOURNAMENT_SPONSOR_PRIZE: u32 = 5000;
+pub const TOURNAMENT_REPUTATION_BONUS: f32 = 5.0;
+pub const MAX_PAST_TOURNAMENTS: usize = 5;
+// Share of the prize pool going to the winner, the rest goes to the runner-up.
+const TOURNAMENT_WINNER_SHARE: f32 = 0.7;
+const TOURNAMENT_NAMES: [&str; 6] = [
+    "Cup",
+    "Trophy",
+    "Shield",
+    "Open",
+    "Invitational",
+    "Challenge",
+];
+
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq)]
+#[repr(u8)]
+pub enum TournamentStatus {
+    Registration,
+    Ongoing,
+    Ended,
+}
+
+/// A single game of the bracket. A missing team is a bye.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub struct TournamentMatch {
+    pub home: Option<TeamId>,
+    pub away: Option<TeamId>,
+    pub game_id: Option<GameId>,
+    pub winner: Option<TeamId>,
+}
+
+impl TournamentMatch {
+    pub fn new(home: Option<TeamId>, away: Option<TeamId>) -> Self {
+        Self {
+            home,
+            away,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_bye(&self) -> bool {
+        self.home.is_none() || self.away.is_none()
+    }
+
+    pub fn loser(&self) -> Option<TeamId> {
+        let winner = self.winner?;
+        if self.home == Some(winner) {
+            self.away
+        } else {
+            self.home
+        }
+    }
+}
+
+/// Knockout cup hosted on a planet: teams register paying an entry fee,
+/// then play a single elimination bracket for the prize pool.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Tournament {
+    pub id: TournamentId,
+    pub name: String,
+    pub planet_id: PlanetId,
+    pub entry_fee: u32,
+    pub registration_deadline: Tick,
+    pub participants: Vec<TeamId>,
+    pub rounds: Vec<Vec<TournamentMatch>>,
+    pub prize_pool: u32,
+    pub status: TournamentStatus,
+    pub winner: Option<TeamId>,
+    pub ended_at: Option<Tick>,
+}
+
+impl Tournament {
+    pub fn random(
+        rng: &mut ChaCha8Rng,
+        planet_id: PlanetId,
+        planet_name: &str,
+        current_tick: Tick,
+    ) -> Self {
+        let name = format!(
+            "{} {}",
+            planet_name,
+            TOURNAMENT_NAMES.choose(rng).expect("Should choose a name")
+        );
+        Self {
+            id: TournamentId::new_v4(),
+            name,
+            planet_id,
+            entry_fee: rng.gen_range(2..=6) * 500,
+            registration_deadline: current_tick + TOURNAMENT_REGISTRATION_DURATION,
+            participants: vec![],
+            rounds: vec![],
+            prize_pool: TOURNAMENT_SPONSOR_PRIZE,
+            status: TournamentStatus::Registration,
+            winner: None,
+            ended_at: None,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status != TournamentStatus::Ended
+    }
+
+    pub fn is_participant(&self, team_id: TeamId) -> bool {
+        self.participants.contains(&team_id)
+    }
+
+    pub fn can_register(&self, team: &Team, current_tick: Tick) -> AppResult<()> {
+        if self.status != TournamentStatus::Registration
+            || current_tick > self.registration_deadline
+        {
+            return Err(anyhow!("Registration is closed"));
+        }
+
+        if self.is_participant(team.id) {
+            return Err(anyhow!("Already registered"));
+        }
+
+        if self.participants.len() >= TOURNAMENT_MAX_PARTICIPANTS {
+            return Err(anyhow!("Tournament is full"));
+        }
+
+        if team.is_on_planet() != Some(self.planet_id) {
+            return Err(anyhow!("Must be on the hosting planet"));
+        }
+
+        if team.player_ids.len() < MIN_PLAYERS_PER_GAME {
+            return Err(anyhow!("Not enough pirates"));
+        }
+
+        if team.balance() < self.entry_fee {
+            return Err(anyhow!("Insufficient funds"));
+        }
+
+        Ok(())
+    }
+
+    pub fn register(&mut self, team: &mut Team) -> AppResult<()> {
+        team.resources.sub(Resource::SATOSHI, self.entry_fee)?;
+        self.participants.push(team.id);
+        self.prize_pool += self.entry_fee;
+        Ok(())
+    }
+
+    // Draws the first round. The bracket is filled up to the next power of two with byes,
+    // which are assigned to the first teams of the draw so that no match has two byes.
+    pub fn start(&mut self, rng: &mut ChaCha8Rng) {
+        let mut slots = self
+            .participants
+            .iter()
+            .map(|&team_id| Some(team_id))
+            .collect::<Vec<Option<TeamId>>>();
+        slots.shuffle(rng);
+        let size = slots.len().next_power_of_two();
+        slots.resize(size, None);
+
+        let first_round = (0..size / 2)
+            .map(|idx| TournamentMatch::new(slots[idx], slots[size - 1 - idx]))
+            .collect();
+        self.rounds = vec![first_round];
+        self.status = TournamentStatus::Ongoing;
+        self.resolve_byes();
+    }
+
+    pub fn current_round(&self) -> Option<&Vec<TournamentMatch>> {
+        if self.status != TournamentStatus::Ongoing {
+            return None;
+        }
+        self.rounds.last()
+    }
+
+    // Returns the index and match of games still to be scheduled in the current round.
+    pub fn pending_matches(&self) -> Vec<(usize, TournamentMatch)> {
+        self.current_round()
+            .map(|round| {
+                round
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, m)| m.winner.is_none() && m.game_id.is_none())
+                    .map(|(idx, m)| (idx, *m))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn set_game(&mut self, match_index: usize, game_id: GameId) {
+        if let Some(m) = self
+            .rounds
+            .last_mut()
+            .and_then(|round| round.get_mut(match_index))
+        {
+            m.game_id = Some(game_id);
+        }
+    }
+
+    pub fn set_winner(&mut self, match_index: usize, winner: TeamId) {
+        if let Some(m) = self
+            .rounds
+            .last_mut()
+            .and_then(|round| round.get_mut(match_index))
+        {
+            m.winner = Some(winner);
+        }
+    }
+
+    // Stores the result of a tournament game, returns true if the game was part of the bracket.
+    pub fn record_result(&mut self, game_id: GameId, winner: TeamId) -> bool {
+        if self.status != TournamentStatus::Ongoing {
+            return false;
+        }
+        if let Some(m) = self
+            .rounds
+            .last_mut()
+            .and_then(|round| round.iter_mut().find(|m| m.game_id == Some(game_id)))
+        {
+            m.winner = Some(winner);
+            return true;
+        }
+        false
+    }
+
+    fn resolve_byes(&mut self) {
+        if let Some(round) = self.rounds.last_mut() {
+            for m in round.iter_mut().filter(|m| m.is_bye()) {
+                m.winner = m.home.or(m.away);
+            }
+        }
+    }
+
+    // Moves to the next round once all matches of the current one have a winner.
+    // Returns true if the tournament has ended.
+    pub fn advance(&mut self, current_tick: Tick) -> bool {
+        let winners = match self.current_round() {
+            Some(round) if round.iter().all(|m| m.winner.is_some()) => round
+                .iter()
+                .map(|m| m.winner.expect("Should have a winner"))
+                .collect::<Vec<TeamId>>(),
+            _ => return false,
+        };
+
+        if winners.len() == 1 {
+            self.winner = Some(winners[0]);
+            self.status = TournamentStatus::Ended;
+            self.ended_at = Some(current_tick);
+            return true;
+        }
+
+        let next_round = winners
+            .chunks(2)
+            .map(|pair| TournamentMatch::new(Some(pair[0]), pair.get(1).copied()))
+            .collect();
+        self.rounds.push(next_round);
+        self.resolve_byes();
+        false
+    }
+
+    pub fn cancel(&mut self, current_tick: Tick) {
+        self.status = TournamentStatus::Ended;
+        self.ended_at = Some(current_tick);
+    }
+
+    pub fn runner_up(&self) -> Option<TeamId> {
+        if self.winner.is_none() {
+            return None;
+        }
+        self.rounds
+            .last()
+            .and_then(|round| round.first())
+            .and_then(|m| m.loser())
+    }
+
+    // Returns the prizes for the winner and the runner-up.
+    pub fn prizes(&self) -> (u32, u32) {
+        let winner_prize = (self.prize_pool as f32 * TOURNAMENT_WINNER_SHARE).round() as u32;
+        (winner_prize, self.prize_pool - winner_prize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Tournament, TournamentStatus};
+    use crate::types::{GameId, PlanetId, TeamId};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_tournament_bracket() {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let mut tournament = Tournament::random(rng, PlanetId::new_v4(), "Planet", 0);
+        tournament.participants = (0..5).map(|_| TeamId::new_v4()).collect();
+        tournament.start(rng);
+
+        // 5 teams are played in a bracket of 8, with 3 byes.
+        let first_round = &tournament.rounds[0];
+        assert_eq!(first_round.len(), 4);
+        assert_eq!(first_round.iter().filter(|m| m.is_bye()).count(), 3);
+        assert!(first_round
+            .iter()
+            .all(|m| m.home.is_some() || m.away.is_some()));
+
+        let mut current_tick = 0;
+        while tournament.status == TournamentStatus::Ongoing {
+            for (idx, m) in tournament.pending_matches() {
+                let game_id = GameId::new_v4();
+                tournament.set_game(idx, game_id);
+                assert!(tournament.record_result(game_id, m.home.unwrap()));
+            }
+            current_tick += 1;
+            tournament.advance(current_tick);
+        }
+
+        assert_eq!(tournament.rounds.len(), 3);
+        assert!(tournament.winner.is_some());
+        assert!(tournament.runner_up().is_some());
+        assert_ne!(tournament.winner, tournament.runner_up());
+
+        let (winner_prize, runner_up_prize) = tournament.prizes();
+        assert_eq!(winner_prize + runner_up_prize, tournament.prize_pool);
+        assert!(winner_prize > runner_up_prize);
+    }
+}
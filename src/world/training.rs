@@ -0,0 +1,156 @@
+use super::{
+    constants::{TirednessCost, DAYS, MAX_SKILL_INCREASE_PER_LONG_TICK, MAX_TIREDNESS},
+    player::Player,
+    spaceship::{Spaceship, SpaceshipModule},
+    types::TrainingFocus,
+};
+use crate::types::Tick;
+use serde::{Deserialize, Serialize};
+
+pub const TRAINING_DAYS: usize = 7;
+// Skill increase of a session for a fully rested player with no bonus.
+const TRAINING_SESSION_SKILL_INCREASE: f32 = 0.05;
+pub const TRAINING_SESSION_TIREDNESS: f32 = TirednessCost::CRITICAL;
+
+/// Weekly training plan of the crew: each day is either a training session
+/// on a skill group or a rest day.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub struct TrainingSchedule {
+    pub sessions: [Option<TrainingFocus>; TRAINING_DAYS],
+}
+
+impl TrainingSchedule {
+    pub fn day_index(tick: Tick) -> usize {
+        ((tick / DAYS) % TRAINING_DAYS as Tick) as usize
+    }
+
+    pub fn session_at(&self, tick: Tick) -> Option<TrainingFocus> {
+        self.sessions[Self::day_index(tick)]
+    }
+
+    pub fn set_session(&mut self, day: usize, session: Option<TrainingFocus>) {
+        if day < TRAINING_DAYS {
+            self.sessions[day] = session;
+        }
+    }
+
+    pub fn sessions_for(&self, focus: TrainingFocus) -> usize {
+        self.sessions
+            .iter()
+            .filter(|session| **session == Some(focus))
+            .count()
+    }
+
+    // Training is more effective with the right equipment on board.
+    pub fn facility_modifier(spaceship: &Spaceship) -> f32 {
+        if spaceship.has_module(SpaceshipModule::TrainingRoom) {
+            1.5
+        } else {
+            1.0
+        }
+    }
+
+    // Tired pirates do not get much out of training.
+    pub fn tiredness_modifier(player: &Player) -> f32 {
+        (1.0 - player.tiredness / MAX_TIREDNESS).max(0.0)
+    }
+
+    pub fn apply_session(player: &mut Player, focus: TrainingFocus, modifier: f32) {
+        if player.is_injured() || player.is_knocked_out() {
+            return;
+        }
+
+        let potential_modifier = 1.0 + (player.potential - player.average_skill()) / 20.0;
+        let increase = TRAINING_SESSION_SKILL_INCREASE
+            * modifier
+            * Self::tiredness_modifier(player)
+            * potential_modifier.max(0.0);
+
+        for idx in 0..player.skills_training.len() {
+            if focus.is_focus(idx) {
+                player.skills_training[idx] =
+                    (player.skills_training[idx] + increase).min(MAX_SKILL_INCREASE_PER_LONG_TICK);
+            }
+        }
+        player.add_tiredness(TRAINING_SESSION_TIREDNESS);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TrainingSchedule, TRAINING_DAYS};
+    use crate::game_engine::types::GameStats;
+    use crate::types::PlayerId;
+    use crate::world::{
+        constants::{DAYS, MAX_TIREDNESS},
+        player::Player,
+        types::TrainingFocus,
+        utils::PLANET_DATA,
+    };
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_training_schedule() {
+        let mut schedule = TrainingSchedule::default();
+        schedule.set_session(1, Some(TrainingFocus::Offense));
+        schedule.set_session(3, Some(TrainingFocus::Offense));
+        schedule.set_session(TRAINING_DAYS, Some(TrainingFocus::Mental));
+        assert_eq!(schedule.sessions_for(TrainingFocus::Offense), 2);
+        assert_eq!(schedule.sessions_for(TrainingFocus::Mental), 0);
+
+        assert_eq!(schedule.session_at(0), None);
+        assert_eq!(schedule.session_at(DAYS), Some(TrainingFocus::Offense));
+        assert_eq!(
+            schedule.session_at((TRAINING_DAYS as u128 + 1) * DAYS),
+            Some(TrainingFocus::Offense)
+        );
+
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let mut player = Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 0.0);
+        player.potential = 20.0;
+        player.tiredness = 0.0;
+        TrainingSchedule::apply_session(&mut player, TrainingFocus::Offense, 1.0);
+        for idx in 0..player.skills_training.len() {
+            assert_eq!(
+                player.skills_training[idx] > 0.0,
+                TrainingFocus::Offense.is_focus(idx)
+            );
+        }
+        assert!(player.tiredness > 0.0);
+
+        // Exhausted players do not improve.
+        let mut tired_player = Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 0.0);
+        tired_player.tiredness = MAX_TIREDNESS;
+        TrainingSchedule::apply_session(&mut tired_player, TrainingFocus::Offense, 1.0);
+        assert!(tired_player.skills_training.iter().all(|&s| s == 0.0));
+    }
+
+    #[test]
+    fn test_game_action_experience() {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let mut player = Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 0.0);
+        player.potential = 20.0;
+
+        let mut stats = GameStats::default();
+        stats.attempted_3pt = 4;
+        stats.blocks = 2;
+        let experience = stats.action_experience();
+        // Long range shooting and block.
+        assert!(experience[7] > 0.0 && experience[9] > 0.0);
+        assert_eq!(experience[12], 0.0);
+
+        let gains = player.update_skills_training([0; 5], experience, 1.0, None);
+        assert_eq!(gains, player.skills_training);
+        assert!(gains[7] > 0.0);
+        assert_eq!(gains[12], 0.0);
+
+        // The training focus doubles the gains of its skills.
+        let mut focused = player.clone();
+        focused.skills_training = [0.0; 20];
+        let focused_gains =
+            focused.update_skills_training([0; 5], experience, 1.0, Some(TrainingFocus::Offense));
+        assert!(focused_gains[7] > gains[7]);
+        assert!(focused_gains[9] < gains[9]);
+    }
+}
@@ -0,0 +1,279 @@
+use super::{
+    constants::{
+        MAX_TIREDNESS, MORALE_DRINK_BONUS, TIREDNESS_DRINK_MALUS, TIREDNESS_DRINK_MALUS_SPUGNA,
+    },
+    player::Player,
+    skill::{GameSkill, MAX_SKILL},
+};
+use crate::game_engine::types::GameStats;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumIter};
+
+// Progress needed to acquire a trait.
+pub const TRAIT_ACQUISITION_THRESHOLD: f32 = 20.0;
+pub const TRAIT_PROGRESS_DECAY_PER_LONG_TICK: f32 = 0.5;
+// Long ticks without any progress after which a trait is lost.
+pub const TRAIT_LOSS_IDLE_LONG_TICKS: u8 = 30;
+
+pub const TRAIT_PROGRESS_PER_DRINK: f32 = 1.0;
+pub const TRAIT_PROGRESS_PER_CLUTCH_SHOT: f32 = 2.0;
+pub const TRAIT_PROGRESS_PER_BIG_GAME: f32 = 4.0;
+pub const TRAIT_PROGRESS_PER_FULL_GAME: f32 = 2.0;
+// Points scored in a game to count as a big game.
+pub const BIG_GAME_POINTS: u8 = 20;
+// Seconds played in a game to count as a full game.
+pub const FULL_GAME_SECONDS: u16 = 36 * 60;
+// A made shot is clutch if it happens in the last seconds of the game
+// with the score within this margin.
+pub const CLUTCH_TIME_SECONDS: u16 = 2 * 60;
+pub const CLUTCH_SCORE_MARGIN: u16 = 5;
+
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Display, EnumIter)]
+#[repr(u8)]
+pub enum Trait {
+    Killer,
+    Relentless,
+    Showpirate,
+    Spugna,
+}
+
+impl Trait {
+    pub fn description(&self, player: &Player) -> String {
+        match self {
+            Trait::Killer => format!(
+                "Better at brawling during games. Bonus is based on reputation (+{}).",
+                player.reputation.value()
+            ),
+            Trait::Relentless => format!("Cannot get exhausted"),
+            Trait::Showpirate => {
+                format!(
+                    "Increase games attendance based on reputation (+{}%)",
+                    player.reputation.value()
+                )
+            }
+            Trait::Spugna => format!("Immediately maximizes morale when drinking. It is said that a drunk pilot could bring you somewhere unexpected...",),
+        }
+    }
+
+    // How the trait is earned, shown to help players work towards it.
+    pub fn acquisition_hint(&self) -> &'static str {
+        match self {
+            Trait::Killer => "scoring clutch shots",
+            Trait::Relentless => "playing full games",
+            Trait::Showpirate => "having big scoring games",
+            Trait::Spugna => "drinking a lot",
+        }
+    }
+}
+
+// Trait effects, consulted by the game engine and the world.
+
+// Weight of the player when picking who gets involved in a brawl.
+pub fn brawl_weight(player: &Player) -> u8 {
+    if player.special_trait == Some(Trait::Killer) {
+        player.mental.aggression.value() * 2
+    } else {
+        player.mental.aggression.value()
+    }
+}
+
+pub fn brawl_bonus(player: &Player) -> u8 {
+    if player.special_trait == Some(Trait::Killer) {
+        player.reputation.value()
+    } else {
+        0
+    }
+}
+
+// Extra game attendance, in percent.
+pub fn attendance_bonus(player: &Player) -> u8 {
+    if player.special_trait == Some(Trait::Showpirate) {
+        player.reputation.value()
+    } else {
+        0
+    }
+}
+
+pub fn max_tiredness(player: &Player) -> f32 {
+    if player.special_trait == Some(Trait::Relentless) {
+        MAX_TIREDNESS - 1.0
+    } else {
+        MAX_TIREDNESS
+    }
+}
+
+pub fn can_drink_at_max_morale(player: &Player) -> bool {
+    player.special_trait == Some(Trait::Spugna)
+}
+
+pub fn drink_morale_bonus(player: &Player) -> f32 {
+    if player.special_trait == Some(Trait::Spugna) {
+        MAX_SKILL
+    } else {
+        MORALE_DRINK_BONUS
+    }
+}
+
+pub fn drink_tiredness_malus(player: &Player) -> f32 {
+    if player.special_trait == Some(Trait::Spugna) {
+        TIREDNESS_DRINK_MALUS_SPUGNA
+    } else {
+        TIREDNESS_DRINK_MALUS
+    }
+}
+
+/// Events accumulated by a player towards each trait. Progress slowly fades,
+/// so traits are acquired by repeating an activity and lost when neglecting it.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct TraitProgress {
+    #[serde(default)]
+    pub killer: f32,
+    #[serde(default)]
+    pub relentless: f32,
+    #[serde(default)]
+    pub showpirate: f32,
+    #[serde(default)]
+    pub spugna: f32,
+    // Long ticks since the current trait had any progress.
+    #[serde(default)]
+    pub idle_long_ticks: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TraitChange {
+    Acquired(Trait),
+    Lost(Trait),
+}
+
+impl TraitProgress {
+    // Players starting with a trait have the progress to keep it for a while.
+    pub fn with_trait(special_trait: Trait) -> Self {
+        let mut progress = Self::default();
+        *progress.value_mut(special_trait) = TRAIT_ACQUISITION_THRESHOLD;
+        progress
+    }
+
+    pub fn value(&self, special_trait: Trait) -> f32 {
+        match special_trait {
+            Trait::Killer => self.killer,
+            Trait::Relentless => self.relentless,
+            Trait::Showpirate => self.showpirate,
+            Trait::Spugna => self.spugna,
+        }
+    }
+
+    fn value_mut(&mut self, special_trait: Trait) -> &mut f32 {
+        match special_trait {
+            Trait::Killer => &mut self.killer,
+            Trait::Relentless => &mut self.relentless,
+            Trait::Showpirate => &mut self.showpirate,
+            Trait::Spugna => &mut self.spugna,
+        }
+    }
+
+    pub fn add(&mut self, special_trait: Trait, amount: f32) {
+        let value = self.value_mut(special_trait);
+        *value = (*value + amount).min(2.0 * TRAIT_ACQUISITION_THRESHOLD);
+    }
+
+    pub fn record_game(&mut self, stats: &GameStats) {
+        self.add(
+            Trait::Killer,
+            stats.clutch_shots as f32 * TRAIT_PROGRESS_PER_CLUTCH_SHOT,
+        );
+        if stats.points >= BIG_GAME_POINTS {
+            self.add(Trait::Showpirate, TRAIT_PROGRESS_PER_BIG_GAME);
+        }
+        if stats.seconds_played >= FULL_GAME_SECONDS {
+            self.add(Trait::Relentless, TRAIT_PROGRESS_PER_FULL_GAME);
+        }
+    }
+}
+
+// Called once every long tick: progress decays, pirates without a trait acquire
+// the one they worked the most towards, and traits left idle for too long are lost.
+pub fn tick_trait_progress(player: &mut Player) -> Option<TraitChange> {
+    for special_trait in Trait::iter() {
+        let value = player.trait_progress.value_mut(special_trait);
+        *value = (*value - TRAIT_PROGRESS_DECAY_PER_LONG_TICK).max(0.0);
+    }
+
+    match player.special_trait {
+        Some(special_trait) => {
+            if player.trait_progress.value(special_trait) > 0.0 {
+                player.trait_progress.idle_long_ticks = 0;
+                return None;
+            }
+
+            player.trait_progress.idle_long_ticks += 1;
+            if player.trait_progress.idle_long_ticks < TRAIT_LOSS_IDLE_LONG_TICKS {
+                return None;
+            }
+
+            player.special_trait = None;
+            player.trait_progress.idle_long_ticks = 0;
+            Some(TraitChange::Lost(special_trait))
+        }
+        None => {
+            let special_trait = Trait::iter()
+                .filter(|t| player.trait_progress.value(*t) >= TRAIT_ACQUISITION_THRESHOLD)
+                .max_by(|a, b| {
+                    player
+                        .trait_progress
+                        .value(*a)
+                        .total_cmp(&player.trait_progress.value(*b))
+                })?;
+            player.special_trait = Some(special_trait);
+            player.trait_progress.idle_long_ticks = 0;
+            Some(TraitChange::Acquired(special_trait))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        tick_trait_progress, Trait, TraitChange, TRAIT_ACQUISITION_THRESHOLD,
+        TRAIT_LOSS_IDLE_LONG_TICKS, TRAIT_PROGRESS_PER_DRINK,
+    };
+    use crate::types::PlayerId;
+    use crate::world::{player::Player, utils::PLANET_DATA};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_trait_acquisition_and_loss() {
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let mut player = Player::random(rng, PlayerId::new_v4(), None, &PLANET_DATA[0], 0.0);
+        player.special_trait = None;
+        player.trait_progress = Default::default();
+
+        assert_eq!(tick_trait_progress(&mut player), None);
+
+        let drinks = (TRAIT_ACQUISITION_THRESHOLD / TRAIT_PROGRESS_PER_DRINK) as usize + 1;
+        for _ in 0..drinks {
+            player
+                .trait_progress
+                .add(Trait::Spugna, TRAIT_PROGRESS_PER_DRINK);
+        }
+        assert_eq!(
+            tick_trait_progress(&mut player),
+            Some(TraitChange::Acquired(Trait::Spugna))
+        );
+        assert_eq!(player.special_trait, Some(Trait::Spugna));
+
+        // Without drinking, the progress fades and the trait is eventually lost.
+        let mut change = None;
+        for _ in 0..(4 * TRAIT_ACQUISITION_THRESHOLD as usize + TRAIT_LOSS_IDLE_LONG_TICKS as usize)
+        {
+            if let Some(c) = tick_trait_progress(&mut player) {
+                change = Some(c);
+                break;
+            }
+        }
+        assert_eq!(change, Some(TraitChange::Lost(Trait::Spugna)));
+        assert_eq!(player.special_trait, None);
+    }
+}
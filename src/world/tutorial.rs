@@ -0,0 +1,148 @@
+use super::{resources::Resource, spaceship::Engine, team::Team};
+use crate::types::Tick;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::fmt::Display;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Eq, Hash, EnumIter)]
+#[repr(u8)]
+pub enum TutorialQuest {
+    HirePirate,
+    SetTrainingFocus,
+    WinChallenge,
+    VisitPlanet,
+    UpgradeEngine,
+}
+
+impl Display for TutorialQuest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::HirePirate => write!(f, "Hire a new pirate"),
+            Self::SetTrainingFocus => write!(f, "Choose a training focus"),
+            Self::WinChallenge => write!(f, "Win your first challenge"),
+            Self::VisitPlanet => write!(f, "Land on another planet"),
+            Self::UpgradeEngine => write!(f, "Upgrade your engine"),
+        }
+    }
+}
+
+impl TutorialQuest {
+    pub fn hint(&self) -> &'static str {
+        match self {
+            Self::HirePirate => "Free pirates can be hired from the Players tab.",
+            Self::SetTrainingFocus => {
+                "Use the training button in the Info view to focus the training."
+            }
+            Self::WinChallenge => "Challenge a team on your planet from the Games view.",
+            Self::VisitPlanet => "Pick a destination in the Galaxy tab and travel there.",
+            Self::UpgradeEngine => "Faster engines can be bought in the Shipyard view.",
+        }
+    }
+
+    pub fn rewards(&self) -> Vec<(Resource, u32)> {
+        match self {
+            Self::HirePirate => vec![(Resource::RUM, 5)],
+            Self::SetTrainingFocus => vec![(Resource::SATOSHI, 1_000)],
+            Self::WinChallenge => vec![(Resource::SATOSHI, 3_000)],
+            Self::VisitPlanet => vec![(Resource::FUEL, 30)],
+            Self::UpgradeEngine => vec![(Resource::SATOSHI, 5_000), (Resource::SCRAPS, 10)],
+        }
+    }
+
+    pub fn rewards_description(&self) -> String {
+        self.rewards()
+            .iter()
+            .map(|(resource, amount)| format!("{} {}", amount, resource))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
+
+/// Quest-line guiding new captains through the basics of the game.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Tutorial {
+    // Crew and engine when the tutorial started, to detect hires and upgrades.
+    pub initial_crew_size: usize,
+    pub initial_engine: Engine,
+    pub completed_quests: Vec<(TutorialQuest, Tick)>,
+}
+
+impl Tutorial {
+    pub fn new(team: &Team) -> Self {
+        Self {
+            initial_crew_size: team.player_ids.len(),
+            initial_engine: team.spaceship.engine,
+            completed_quests: vec![],
+        }
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.completed_quests.len() == TutorialQuest::iter().count()
+    }
+
+    pub fn is_quest_completed(&self, quest: TutorialQuest) -> bool {
+        self.completed_quests.iter().any(|(q, _)| *q == quest)
+    }
+
+    pub fn is_quest_achieved(&self, quest: TutorialQuest, team: &Team) -> bool {
+        match quest {
+            TutorialQuest::HirePirate => team.player_ids.len() > self.initial_crew_size,
+            TutorialQuest::SetTrainingFocus => team.training_focus.is_some(),
+            TutorialQuest::WinChallenge => team.game_record[0] + team.network_game_record[0] > 0,
+            TutorialQuest::VisitPlanet => match team.is_on_planet() {
+                Some(planet_id) => planet_id != team.home_planet_id,
+                None => false,
+            },
+            TutorialQuest::UpgradeEngine => team.spaceship.engine != self.initial_engine,
+        }
+    }
+
+    // Quests achieved by the team since the last check, which are marked as completed.
+    pub fn complete_achieved_quests(&mut self, team: &Team, tick: Tick) -> Vec<TutorialQuest> {
+        let achieved = TutorialQuest::iter()
+            .filter(|quest| !self.is_quest_completed(*quest))
+            .filter(|quest| self.is_quest_achieved(*quest, team))
+            .collect::<Vec<TutorialQuest>>();
+        for quest in achieved.iter() {
+            self.completed_quests.push((*quest, tick));
+        }
+        achieved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Tutorial, TutorialQuest};
+    use crate::types::{PlanetId, PlayerId};
+    use crate::world::{team::Team, types::TeamLocation, types::TrainingFocus};
+
+    #[test]
+    fn test_tutorial_quests() {
+        let mut team = Team::default();
+        team.home_planet_id = PlanetId::new_v4();
+        team.current_location = TeamLocation::OnPlanet {
+            planet_id: team.home_planet_id,
+        };
+        team.player_ids = (0..5).map(|_| PlayerId::new_v4()).collect();
+        let mut tutorial = Tutorial::new(&team);
+        assert!(tutorial.complete_achieved_quests(&team, 0).is_empty());
+
+        team.player_ids.push(PlayerId::new_v4());
+        team.training_focus = Some(TrainingFocus::Offense);
+        assert_eq!(
+            tutorial.complete_achieved_quests(&team, 1),
+            vec![TutorialQuest::HirePirate, TutorialQuest::SetTrainingFocus]
+        );
+        // Completed quests are not rewarded twice.
+        assert!(tutorial.complete_achieved_quests(&team, 2).is_empty());
+
+        team.game_record = [1, 0, 0];
+        team.current_location = TeamLocation::OnPlanet {
+            planet_id: PlanetId::new_v4(),
+        };
+        assert_eq!(tutorial.complete_achieved_quests(&team, 3).len(), 2);
+        assert!(!tutorial.is_completed());
+    }
+}
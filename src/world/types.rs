@@ -1,14 +1,15 @@
 use std::fmt::Display;
 
 use super::{
-    constants::DEFAULT_PLANET_ID,
+    constants::{DEFAULT_PLANET_ID, NICKNAME_COST, RENAME_SPACESHIP_COST, RENAME_TEAM_COST},
+    generation::DifficultyModifier,
     player::{InfoStats, Player},
     skill::MAX_SKILL,
     world::World,
 };
 use crate::{
     image::color_map::SkinColorMap,
-    types::{AppResult, PlanetId, TeamId, Tick},
+    types::{AppResult, PlanetId, PlayerId, TeamId, Tick},
 };
 use rand_chacha::ChaCha8Rng;
 use rand_distr::{Distribution, WeightedIndex};
@@ -276,7 +277,14 @@ impl Population {
 #[derive(Debug, Clone, Copy, Display, Serialize, Deserialize)]
 pub enum PlayerLocation {
     WithTeam,
-    OnPlanet { planet_id: PlanetId },
+    OnPlanet {
+        planet_id: PlanetId,
+    },
+    // Prospect developing in the academy of a team asteroid, outside the roster.
+    InAcademy {
+        asteroid_id: PlanetId,
+        enrolled: Tick,
+    },
 }
 
 #[derive(Debug, Clone, Copy, Display, Serialize, Deserialize, PartialEq)]
@@ -289,6 +297,14 @@ impl PartialEq for PlayerLocation {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::OnPlanet { planet_id: p1 }, Self::OnPlanet { planet_id: p2 }) => p1 == p2,
+            (
+                Self::InAcademy {
+                    asteroid_id: a1, ..
+                },
+                Self::InAcademy {
+                    asteroid_id: a2, ..
+                },
+            ) => a1 == a2,
             _ => false,
         }
     }
@@ -302,6 +318,34 @@ impl Default for PlayerLocation {
     }
 }
 
+// What the crew can rename after the team creation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RenameTarget {
+    Team,
+    Spaceship,
+    Player { player_id: PlayerId },
+}
+
+impl Display for RenameTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Team => write!(f, "Team name"),
+            Self::Spaceship => write!(f, "Ship name"),
+            Self::Player { .. } => write!(f, "Nickname"),
+        }
+    }
+}
+
+impl RenameTarget {
+    pub fn cost(&self) -> u32 {
+        match self {
+            Self::Team => RENAME_TEAM_COST,
+            Self::Spaceship => RENAME_SPACESHIP_COST,
+            Self::Player { .. } => NICKNAME_COST,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Display, Serialize, Deserialize)]
 pub enum TeamLocation {
     Travelling {
@@ -433,6 +477,40 @@ impl TrainingFocus {
     }
 }
 
+// Speed of the world clock, only adjustable in single-player.
+#[derive(Debug, Clone, Copy, Display, PartialEq, Default)]
+pub enum TimeSpeed {
+    #[default]
+    #[strum(to_string = "1x")]
+    Normal,
+    #[strum(to_string = "2x")]
+    Double,
+    #[strum(to_string = "4x")]
+    Quadruple,
+    Paused,
+}
+
+impl TimeSpeed {
+    pub fn multiplier(&self) -> i64 {
+        match self {
+            Self::Normal => 1,
+            Self::Double => 2,
+            Self::Quadruple => 4,
+            Self::Paused => 0,
+        }
+    }
+
+    // Cycles through the fast-forward speeds, resuming if paused.
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Normal => Self::Double,
+            Self::Double => Self::Quadruple,
+            Self::Quadruple => Self::Normal,
+            Self::Paused => Self::Normal,
+        }
+    }
+}
+
 const BASE_BONUS: f32 = 1.0;
 const BONUS_PER_SKILL: f32 = 1.0 / MAX_SKILL;
 #[derive(Clone, Copy, Debug)]
@@ -443,6 +521,10 @@ pub enum TeamBonus {
     TirednessRecovery, //doctor
     TradePrice,        //captain
     Training,          //doctor
+    HullCare,          //engineer
+    Repairs,           //engineer
+    GameStamina,       //cook
+    Provisions,        //cook
 }
 
 impl Display for TeamBonus {
@@ -454,6 +536,10 @@ impl Display for TeamBonus {
             TeamBonus::TirednessRecovery => write!(f, "Recovery"),
             TeamBonus::TradePrice => write!(f, "Trading"),
             TeamBonus::Training => write!(f, "Training"),
+            TeamBonus::HullCare => write!(f, "Hull care"),
+            TeamBonus::Repairs => write!(f, "Repairs"),
+            TeamBonus::GameStamina => write!(f, "Stamina"),
+            TeamBonus::Provisions => write!(f, "Provisions"),
         }
     }
 }
@@ -468,6 +554,10 @@ impl TeamBonus {
             TeamBonus::TirednessRecovery => team.crew_roles.doctor,
             TeamBonus::TradePrice => team.crew_roles.captain,
             TeamBonus::Training => team.crew_roles.doctor,
+            TeamBonus::HullCare => team.crew_roles.engineer,
+            TeamBonus::Repairs => team.crew_roles.engineer,
+            TeamBonus::GameStamina => team.crew_roles.cook,
+            TeamBonus::Provisions => team.crew_roles.cook,
         };
 
         let skill = if let Some(id) = player_id {
@@ -477,7 +567,13 @@ impl TeamBonus {
             0.0
         };
 
-        Ok(BASE_BONUS + BONUS_PER_SKILL * skill)
+        // Hired staff add to the bonus of the crew role.
+        let bonus = BASE_BONUS + BONUS_PER_SKILL * skill + team.staff_bonus(*self);
+        // Harder difficulties widen the market margins for the own team.
+        if matches!(self, TeamBonus::TradePrice) && team_id == world.own_team_id {
+            return Ok(bonus / world.difficulty_modifier(DifficultyModifier::MarketMargin));
+        }
+        Ok(bonus)
     }
 
     pub fn current_player_bonus(&self, player: &Player) -> AppResult<f32> {
@@ -505,6 +601,16 @@ impl TeamBonus {
             TeamBonus::Training => Ok(0.25 * player.athletics.strength
                 + 0.25 * player.athletics.vertical
                 + 0.5 * player.mental.intuition),
+            TeamBonus::HullCare => Ok(0.6 * player.mental.vision + 0.4 * player.mental.intuition),
+            TeamBonus::Repairs => Ok(0.5 * player.mental.intuition
+                + 0.3 * player.athletics.strength
+                + 0.2 * player.technical.ball_handling),
+            TeamBonus::GameStamina => {
+                Ok(0.7 * player.mental.intuition + 0.3 * player.athletics.stamina)
+            }
+            TeamBonus::Provisions => {
+                Ok(0.5 * player.mental.intuition + 0.5 * player.mental.charisma)
+            }
         }
     }
 }
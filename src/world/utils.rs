@@ -1,7 +1,10 @@
 use std::collections::HashMap;
 
-use super::{planet::Planet, skill::GameSkill, types::Population};
-use crate::store::ASSETS_DIR;
+use super::{custom_names::CustomNames, planet::Planet, skill::GameSkill, types::Population};
+use crate::{
+    assets::{load_json_asset, load_json_asset_or_default},
+    store::load_custom_names,
+};
 use once_cell::sync::Lazy;
 use serde::Deserialize;
 
@@ -12,6 +15,30 @@ pub struct PlayerData {
     pub last_names: Vec<String>,
 }
 
+impl PlayerData {
+    // Used for populations missing from the players data asset.
+    fn fallback() -> Self {
+        Self {
+            first_names_he: vec!["Pietro".to_string(), "Juan".to_string()],
+            first_names_she: vec!["Maria".to_string(), "Ada".to_string()],
+            last_names: vec!["Rossi".to_string(), "Pirata".to_string()],
+        }
+    }
+
+    fn with_custom_names(mut self, custom_names: &CustomNames) -> Self {
+        if !custom_names.first_names_he.is_empty() {
+            self.first_names_he = custom_names.first_names_he.clone();
+        }
+        if !custom_names.first_names_she.is_empty() {
+            self.first_names_she = custom_names.first_names_she.clone();
+        }
+        if !custom_names.last_names.is_empty() {
+            self.last_names = custom_names.last_names.clone();
+        }
+        self
+    }
+}
+
 pub fn linear_interpolation(x: f32, coords: [f32; 4]) -> f32 {
     coords[1] + (coords[3] - coords[1]) / (coords[2] - coords[0]) * (x - coords[0])
 }
@@ -28,38 +55,39 @@ pub fn skill_linear_interpolation(base_skill: f32, mod_skill: f32, coords: [f32;
     (base_skill * modifier).bound()
 }
 
+// Loaded once from the store directory, see store::load_custom_names.
+pub static CUSTOM_NAMES: Lazy<CustomNames> = Lazy::new(|| load_custom_names());
+
 pub static PLAYER_DATA: Lazy<HashMap<Population, PlayerData>> = Lazy::new(|| {
-    let file = ASSETS_DIR
-        .get_file("data/players_data.json")
-        .expect("Could not find players_data.json");
-    let data = file
-        .contents_utf8()
-        .expect("Could not read players_data.json");
-    serde_json::from_str(&data)
-        .unwrap_or_else(|e| panic!("Could not parse players_data.json: {}", e))
+    let data: HashMap<Population, PlayerData> =
+        load_json_asset_or_default("data/players_data.json");
+    data.into_iter()
+        .map(|(population, p_data)| (population, p_data.with_custom_names(&CUSTOM_NAMES)))
+        .collect()
 });
 
+pub static FALLBACK_PLAYER_DATA: Lazy<PlayerData> =
+    Lazy::new(|| PlayerData::fallback().with_custom_names(&CUSTOM_NAMES));
+
+// Without team data no local teams are generated.
 pub static TEAM_DATA: Lazy<Vec<(String, String)>> = Lazy::new(|| {
-    let file = ASSETS_DIR
-        .get_file("data/teams_data.json")
-        .expect("Could not find teams_data.json");
-
-    let data = file
-        .contents_utf8()
-        .expect("Could not read teams_data.json");
-    serde_json::from_str(&data).unwrap_or_else(|e| panic!("Could not parse teams_data.json: {}", e))
+    if !CUSTOM_NAMES.team_names.is_empty() {
+        return CUSTOM_NAMES.team_names.clone();
+    }
+    load_json_asset_or_default("data/teams_data.json")
 });
 
+// The galaxy cannot be built without planets, so this is the only asset we cannot do without.
 pub static PLANET_DATA: Lazy<Vec<Planet>> = Lazy::new(|| {
-    let file = ASSETS_DIR
-        .get_file("data/planets_data.json")
-        .expect("Could not find planets_data.json");
-    let data = file
-        .contents_utf8()
-        .expect("Could not read planets_data.json");
-    serde_json::from_str(&data).unwrap_or_else(|e| {
-        panic!("Could not parse planets_data.json: {}", e);
-    })
+    let mut planets: Vec<Planet> = load_json_asset("data/planets_data.json").unwrap_or_else(|e| {
+        panic!("Could not load planets_data.json: {}", e);
+    });
+    for planet in planets.iter_mut() {
+        if let Some(name) = CUSTOM_NAMES.planet_names.get(&planet.name) {
+            planet.name = name.clone();
+        }
+    }
+    planets
 });
 
 pub fn ellipse_coords(axis: (f32, f32), theta: f32) -> (f32, f32) {
@@ -78,7 +106,8 @@ pub fn is_default<T: Default + PartialEq>(v: &T) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use super::skill_linear_interpolation;
+    use super::{skill_linear_interpolation, PlayerData};
+    use crate::world::custom_names::CustomNames;
 
     //test linear interopolation
     #[test]
@@ -94,6 +123,18 @@ mod tests {
         assert_eq!(skill_linear_interpolation(base, 37.0, coords), 5.0);
     }
 
+    #[test]
+    fn test_player_data_with_custom_names() {
+        let custom_names = CustomNames {
+            last_names: vec!["Skywalker".to_string()],
+            ..Default::default()
+        };
+        let data = PlayerData::fallback().with_custom_names(&custom_names);
+        assert_eq!(data.last_names, vec!["Skywalker".to_string()]);
+        // Empty pools keep the built-in names.
+        assert_eq!(data.first_names_he, PlayerData::fallback().first_names_he);
+    }
+
     #[test]
     fn test_ellipse_coords() {
         let axis = (100.0, 50.0);
@@ -1,21 +1,83 @@
+use super::academy::{self, ACADEMY_DAILY_FEE};
+use super::action_queue::QueuedAction;
+use super::ai_management;
+use super::calendar::{upcoming, CalendarEvent, CalendarEventKind};
+use super::campaign::Campaign;
+use super::catch_up::{CatchUp, MAX_CATCH_UP_TIME};
 use super::constants::*;
+use super::contract::{
+    Contract, ContractStatus, CONTRACT_FAILURE_REPUTATION_MALUS, MAX_CONTRACT_OFFERS_PER_PLANET,
+};
+use super::discovery::{self, Discovery};
+use super::duel::{
+    simulate_duel, DuelResult, DUEL_LOSER_TIREDNESS, DUEL_WINNER_MORALE_BONUS,
+    DUEL_WINNER_REPUTATION_BONUS,
+};
+use super::elo::{updated_ratings, GameOutcome, INITIAL_ELO_RATING};
+use super::emblem::Emblem;
+use super::epithet;
+use super::events::{
+    WorldEvent, WorldEventKind, MAX_WORLD_EVENTS, METEOR_SHOWER_DAMAGE, RUM_FESTIVAL_MORALE_BONUS,
+    WORLD_EVENT_PROBABILITY,
+};
+use super::finances::{FinanceCategory, Finances};
+use super::game_over::{
+    auction_assets, GameOver, GameOverReason, GameOverWatch, GAME_OVER_GRACE_PERIOD,
+};
+use super::generation::{DifficultyModifier, GenerationOptions};
+use super::gossip::Gossip;
+use super::hazards::{
+    roll_hazard_outcome, HazardChoice, TravelHazard, TravelHazardKind,
+    CUSTOMS_RUM_ALLOWANCE_PER_PLAYER, TRAVEL_HAZARD_MIN_TRAVEL_TIME,
+    TRAVEL_HAZARD_PROBABILITY_PER_HOUR,
+};
+use super::history::History;
+use super::hooks::{HookEvent, MAX_QUEUED_HOOK_EVENTS};
+use super::injury::Injury;
+use super::insurance::{hull_loss_value, insurance_payout, InsuranceKind, InsurancePolicy};
 use super::jersey::{Jersey, JerseyStyle};
+use super::kartoffel::{Kartoffel, KARTOFFEL_DISCOVERY_PROBABILITY, MAX_KARTOFFELN_PER_TEAM};
+use super::loan::{repossess, Loan, LOAN_DEFAULT_REPUTATION_MALUS};
+use super::market::{MARKET_RESOURCES, MARKET_UPDATE_INTERVAL};
+use super::mutiny::{CrewLogEntry, MutinyKind};
+use super::notifications::{Notification, NotificationCategory, MAX_NOTIFICATIONS};
 use super::planet::{Planet, PlanetType};
 use super::player::Player;
 use super::position::{Position, MAX_POSITION};
 use super::resources::Resource;
 use super::role::CrewRole;
-use super::skill::{GameSkill, Rated, MAX_SKILL};
-use super::spaceship::Spaceship;
+use super::route::{RouteLeg, TravelRoute};
+use super::salvage::{
+    roll_derelict_outcome, Derelict, DerelictChoice, DERELICT_DISCOVERY_PROBABILITY, TOW_FUEL_COST,
+};
+use super::scanner::{ScanResult, MAX_SCAN_REPORT_LINES};
+use super::scouting::{ScoutingReport, SkillVisibility, SCOUTING_COST};
+use super::skill::{GameSkill, Rated, MAX_SKILL, SKILL_NAMES};
+use super::spaceship::{Spaceship, SpaceshipModule, SpaceshipUpgradeTarget};
+use super::staff::{StaffMember, StaffRole};
 use super::team::Team;
-use super::types::{PlayerLocation, TeamBonus, TeamLocation};
+use super::tournament::{
+    Tournament, TournamentStatus, MAX_PAST_TOURNAMENTS, TOURNAMENT_INTERVAL,
+    TOURNAMENT_MAX_PARTICIPANTS, TOURNAMENT_MIN_PARTICIPANTS, TOURNAMENT_REPUTATION_BONUS,
+};
+use super::training::TrainingSchedule;
+use super::traits::{self, TraitChange};
+use super::tutorial::{Tutorial, TutorialQuest};
+use super::types::{
+    KartoffelLocation, PlayerLocation, RenameTarget, TeamBonus, TeamLocation, TimeSpeed,
+};
 use super::utils::{PLANET_DATA, TEAM_DATA};
+use super::wormhole::{
+    Wormhole, MIN_WORMHOLE_DISTANCE, NATURAL_WORMHOLES, WORMHOLE_GATE_COST, WORMHOLE_TRANSIT_TIME,
+};
 use crate::game_engine::constants::RECOVERING_TIREDNESS_PER_SHORT_TICK;
 use crate::game_engine::game::{Game, GameSummary};
-use crate::game_engine::types::{Possession, TeamInGame};
+use crate::game_engine::rules::MatchRules;
+use crate::game_engine::types::{CrowdChatter, GameCommand, Possession, TeamInGame};
 use crate::image::color_map::ColorMap;
+use crate::network::co_captain::CoCaptainOrder;
 use crate::network::types::{NetworkGame, NetworkTeam};
-use crate::space_adventure::SpaceAdventure;
+use crate::space_adventure::{SpaceAdventure, SpaceAdventureSnapshot};
 use crate::store::save_game;
 use crate::types::*;
 use crate::ui::popup_message::PopupMessage;
@@ -29,8 +91,9 @@ use rand::seq::{IteratorRandom, SliceRandom};
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::u64;
+use strum::IntoEnumIterator;
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct World {
@@ -41,6 +104,9 @@ pub struct World {
     pub seed: u64,
     #[serde(skip_serializing_if = "is_default")]
     #[serde(default)]
+    pub generation_options: GenerationOptions,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
     pub last_tick_min_interval: Tick,
     #[serde(skip_serializing_if = "is_default")]
     #[serde(default)]
@@ -70,8 +136,78 @@ pub struct World {
     #[serde(skip_serializing_if = "is_default")]
     #[serde(default)]
     pub past_games: GameSummaryMap,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub crew_log: Vec<CrewLogEntry>,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub notifications: Vec<Notification>,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub finances: Finances,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub campaign: Option<Campaign>,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub tutorial: Option<Tutorial>,
+    // Planets charted by each team. Teams without an entry know the whole galaxy.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub discoveries: HashMap<TeamId, Discovery>,
+    // Latest scan of each planet by the own team spaceship scanner.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub scan_results: HashMap<PlanetId, ScanResult>,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub game_over_watch: GameOverWatch,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub wormholes: Vec<Wormhole>,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub game_over: Option<GameOver>,
+    // Set when the player keeps playing after the game over, with no more losing condition.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub sandbox: bool,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub contracts: Vec<Contract>,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub last_npc_challenge: Tick,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub scouting_reports: Vec<ScoutingReport>,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub tournaments: Vec<Tournament>,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub history: History,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub world_events: Vec<WorldEvent>,
     #[serde(skip)]
     pub space_adventure: Option<SpaceAdventure>,
+    #[serde(skip)]
+    pub time_speed: TimeSpeed,
+    // Events waiting to be dispatched to the registered hooks.
+    #[serde(skip)]
+    pub hook_events: VecDeque<HookEvent>,
+    // Set while simulating the time passed since the world was saved.
+    #[serde(skip)]
+    pub catch_up: Option<CatchUp>,
+    // Milliseconds the world clock is ahead (or behind) of the real one,
+    // accumulated by fast-forwarding or pausing.
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub time_offset: i64,
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub space_adventure_snapshot: Option<SpaceAdventureSnapshot>,
 }
 
 impl World {
@@ -91,10 +227,25 @@ impl World {
 
     pub fn initialize(&mut self, generate_local_world: bool) -> AppResult<()> {
         let rng = &mut ChaCha8Rng::seed_from_u64(self.seed);
+
+        let resource_multiplier = self
+            .generation_options
+            .resource_richness
+            .resource_multiplier();
+        for planet in self.planets.values_mut() {
+            for amount in planet.resources.values_mut() {
+                *amount = (*amount as f32 * resource_multiplier).round() as u32;
+            }
+        }
+
         for planet in PLANET_DATA.iter() {
             self.populate_planet(rng, planet)?;
         }
 
+        if self.wormholes.is_empty() {
+            self.generate_natural_wormholes(rng);
+        }
+
         if generate_local_world {
             self.generate_local_world(rng)?;
         }
@@ -111,20 +262,175 @@ impl World {
         Ok(())
     }
 
+    // Natural wormholes link random inhabited planets of different star systems.
+    fn generate_natural_wormholes(&mut self, rng: &mut ChaCha8Rng) {
+        let mut candidates = self
+            .planets
+            .values()
+            .filter(|planet| planet.total_population() > 0 && planet.peer_id.is_none())
+            .map(|planet| planet.id)
+            .sorted()
+            .collect_vec();
+        candidates.shuffle(rng);
+
+        let mut wormholes: Vec<Wormhole> = vec![];
+        let is_linked = |wormholes: &Vec<Wormhole>, planet_id: PlanetId| {
+            wormholes
+                .iter()
+                .any(|wormhole| wormhole.other_end(planet_id).is_some())
+        };
+        for (idx, &from) in candidates.iter().enumerate() {
+            if wormholes.len() >= NATURAL_WORMHOLES {
+                break;
+            }
+            if is_linked(&wormholes, from) {
+                continue;
+            }
+            if let Some(&to) = candidates[idx + 1..].iter().find(|&&to| {
+                !is_linked(&wormholes, to)
+                    && self.distance_between_planets(from, to).unwrap_or_default()
+                        >= MIN_WORMHOLE_DISTANCE
+            }) {
+                wormholes.push(Wormhole::natural(from, to));
+            }
+        }
+        self.wormholes = wormholes;
+    }
+
     pub fn has_own_team(&self) -> bool {
         self.own_team_id != TeamId::default()
     }
 
+    pub fn log_crew_event(&mut self, tick: Tick, message: String) {
+        self.crew_log.push(CrewLogEntry::new(tick, message));
+        if self.crew_log.len() > MAX_CREW_LOG_ENTRIES {
+            self.crew_log.remove(0);
+        }
+        self.dirty = true;
+        self.dirty_ui = true;
+    }
+
+    pub fn notify(&mut self, tick: Tick, category: NotificationCategory, message: String) {
+        self.notifications
+            .push(Notification::new(tick, category, message));
+        if self.notifications.len() > MAX_NOTIFICATIONS {
+            self.notifications.remove(0);
+        }
+        self.dirty = true;
+        self.dirty_ui = true;
+    }
+
+    // Clears the notifications in the given category, or all of them if None.
+    pub fn clear_notifications(&mut self, category: Option<NotificationCategory>) {
+        self.notifications
+            .retain(|notification| category.is_some_and(|c| notification.category != c));
+        self.dirty = true;
+        self.dirty_ui = true;
+    }
+
+    // Only the own team finances are recorded.
+    pub fn record_transaction(
+        &mut self,
+        team_id: TeamId,
+        tick: Tick,
+        category: FinanceCategory,
+        amount: i64,
+        description: String,
+    ) {
+        if team_id != self.own_team_id {
+            return;
+        }
+        self.finances.record(tick, category, amount, description);
+        self.dirty = true;
+        self.dirty_ui = true;
+    }
+
+    // The offended team, if any, takes the gossip to heart the most.
+    pub fn spread_gossip(
+        &mut self,
+        team_id: TeamId,
+        planet_id: PlanetId,
+        gossip: Gossip,
+        offended_team_id: Option<TeamId>,
+        tick: Tick,
+    ) -> AppResult<()> {
+        let mut team = self.get_team_or_err(team_id)?.clone();
+        let strikes = team.gossip_strikes.entry(gossip).or_default();
+        *strikes += 1;
+        if *strikes < gossip.strikes_to_spread() {
+            self.teams.insert(team.id, team);
+            self.dirty = true;
+            return Ok(());
+        }
+        team.gossip_strikes.remove(&gossip);
+
+        let planet = self.get_planet_or_err(planet_id)?;
+        let malus = gossip.reputation_malus();
+
+        // Gossip travels to the parent planet and to the other satellites around it.
+        let mut neighbour_ids = planet.satellites.clone();
+        if let Some(parent_id) = planet.satellite_of {
+            neighbour_ids.push(parent_id);
+            neighbour_ids.append(&mut self.get_planet_or_err(parent_id)?.satellites.clone());
+        }
+        let planet_name = planet.name.clone();
+
+        // Crews docked around hear it too.
+        let mut witness_ids = planet.team_ids.clone();
+        for id in neighbour_ids.iter() {
+            witness_ids.extend(self.get_planet_or_err(*id)?.team_ids.iter());
+        }
+        for witness_id in witness_ids.iter().unique() {
+            if *witness_id != team_id && Some(*witness_id) != offended_team_id {
+                team.add_team_standing(*witness_id, -malus * GOSSIP_NEIGHBOUR_MODIFIER);
+            }
+        }
+        if let Some(offended_team_id) = offended_team_id {
+            team.add_team_standing(offended_team_id, -malus);
+        }
+
+        *team.planet_reputation.entry(planet_id).or_default() -= malus;
+        for id in neighbour_ids.iter().unique() {
+            if *id == planet_id {
+                continue;
+            }
+            *team.planet_reputation.entry(*id).or_default() -= malus * GOSSIP_NEIGHBOUR_MODIFIER;
+        }
+        for offset in team.planet_reputation.values_mut() {
+            *offset = offset.max(-MAX_SKILL);
+        }
+        team.reputation = (team.reputation - malus * GOSSIP_GLOBAL_MODIFIER).bound();
+
+        let rumor = gossip.rumor(&team.name);
+        self.teams.insert(team.id, team);
+        if team_id == self.own_team_id {
+            self.log_crew_event(tick, format!("Gossip on {planet_name}: {rumor}"));
+            self.dirty_network = true;
+        }
+        self.dirty = true;
+        self.dirty_ui = true;
+
+        Ok(())
+    }
+
     fn populate_planet(&mut self, rng: &mut ChaCha8Rng, planet: &Planet) -> AppResult<()> {
         // generate free pirates per each planet
-        let number_free_pirates = planet.total_population();
+        let number_free_pirates = (planet.total_population() as f32
+            * self.generation_options.galaxy_size.population_multiplier())
+        .round() as u32;
         let mut position = 0 as Position;
         let own_team_base_level = if let Ok(own_team) = self.get_own_team() {
             own_team.reputation / 4.0
         } else {
             0.0
         };
-        let base_level = rng.gen_range(0.0..4.0) + own_team_base_level;
+        let base_level = (rng.gen_range(0.0..4.0)
+            + own_team_base_level
+            + self
+                .generation_options
+                .difficulty
+                .free_pirates_level_bonus())
+        .max(0.0);
         for _ in 0..number_free_pirates {
             self.generate_random_player(rng, Some(position), planet.id, base_level)?;
             position = (position + 1) % MAX_POSITION;
@@ -136,6 +442,7 @@ impl World {
     pub fn generate_local_world(&mut self, rng: &mut ChaCha8Rng) -> AppResult<()> {
         let mut team_data = TEAM_DATA.clone();
         team_data.shuffle(rng);
+        team_data.truncate(self.generation_options.ai_teams);
 
         let home_planet_ids = self
             .planets
@@ -159,6 +466,25 @@ impl World {
         home_planet_id: PlanetId,
         team_name: String,
         ship_name: String,
+    ) -> AppResult<TeamId> {
+        let team_base_level =
+            rng.gen_range(self.generation_options.difficulty.ai_team_level_range()) as f32;
+        self.generate_random_team_with_level(
+            rng,
+            home_planet_id,
+            team_name,
+            ship_name,
+            team_base_level,
+        )
+    }
+
+    pub fn generate_random_team_with_level(
+        &mut self,
+        rng: &mut ChaCha8Rng,
+        home_planet_id: PlanetId,
+        team_name: String,
+        ship_name: String,
+        team_base_level: f32,
     ) -> AppResult<TeamId> {
         let team_id = TeamId::new_v4();
         let team = Team::random(team_id, home_planet_id, team_name, ship_name);
@@ -169,7 +495,6 @@ impl World {
         planet.team_ids.push(team_id);
         self.planets.insert(planet.id, planet);
 
-        let team_base_level = rng.gen_range(0..=8) as f32;
         for position in 0..MAX_POSITION {
             let player_id =
                 self.generate_random_player(rng, Some(position), home_planet_id, team_base_level)?;
@@ -181,7 +506,7 @@ impl World {
                 self.generate_random_player(rng, None, home_planet_id, team_base_level)?;
             self.add_player_to_team(player_id, team_id)?;
             let team = self.get_team_or_err(team_id)?;
-            if team.player_ids.len() == team.spaceship.crew_capacity() as usize {
+            if team.player_ids.len() >= team.spaceship.crew_capacity() as usize {
                 break;
             }
         }
@@ -238,12 +563,116 @@ impl World {
         planet.team_ids.push(team_id);
         self.planets.insert(planet.id, planet);
 
+        // The rest of the galaxy is uncharted.
+        self.discoveries.insert(team_id, Discovery::default());
+        self.discover_surroundings(team_id, home_planet_id)?;
+
         self.dirty = true;
         self.dirty_network = true;
         self.dirty_ui = true;
         Ok(team_id)
     }
 
+    pub fn is_planet_discovered(&self, team_id: TeamId, planet_id: PlanetId) -> bool {
+        match self.discoveries.get(&team_id) {
+            Some(discovery) => planet_id == *GALAXY_ROOT_ID || discovery.is_discovered(planet_id),
+            None => true,
+        }
+    }
+
+    pub fn discover_planets(&mut self, team_id: TeamId, planet_ids: Vec<PlanetId>) -> usize {
+        let discovered = match self.discoveries.get_mut(&team_id) {
+            Some(discovery) => discovery.discover(planet_ids).len(),
+            None => 0,
+        };
+        if discovered > 0 {
+            self.dirty = true;
+            self.dirty_ui = true;
+        }
+        discovered
+    }
+
+    // Charts the planet, the planets it orbits and its satellites, returning how many were new.
+    pub fn discover_surroundings(
+        &mut self,
+        team_id: TeamId,
+        planet_id: PlanetId,
+    ) -> AppResult<usize> {
+        let planet = self.get_planet_or_err(planet_id)?;
+        let planet_ids = discovery::surroundings(&self.planets, planet);
+        Ok(self.discover_planets(team_id, planet_ids))
+    }
+
+    // Charts the whole system of the planet, returning how many planets were new.
+    pub fn discover_system(&mut self, team_id: TeamId, planet_id: PlanetId) -> AppResult<usize> {
+        let planet = self.get_planet_or_err(planet_id)?;
+        let planet_ids = discovery::system(&self.planets, planet);
+        Ok(self.discover_planets(team_id, planet_ids))
+    }
+
+    // Scans the planets in range of the own team scanner, charting them and
+    // recording their free pirates and resources. Returns the scan report.
+    pub fn scan_nearby_planets(&mut self, current_tick: Tick) -> AppResult<String> {
+        let mut own_team = self.get_own_team()?.clone();
+        own_team.can_scan_planets()?;
+        let planet_id = own_team
+            .is_on_planet()
+            .ok_or(anyhow!("Scanning is only possible on a planet"))?;
+
+        let scanner = own_team.spaceship.scanner;
+        let mut in_range = vec![];
+        for planet in self.planets.values() {
+            if planet.id == planet_id || planet.id == *GALAXY_ROOT_ID {
+                continue;
+            }
+            if let Ok(distance) = self.distance_between_planets(planet_id, planet.id) {
+                if distance <= scanner.range() {
+                    in_range.push((distance, planet.id));
+                }
+            }
+        }
+        in_range.sort_by_key(|(distance, _)| *distance);
+
+        let mut lines = vec![];
+        for (_, scanned_id) in in_range.iter() {
+            let planet = self.get_planet_or_err(*scanned_id)?;
+            let free_pirates = self
+                .players
+                .values()
+                .filter(|player| {
+                    player.team.is_none()
+                        && player.current_location
+                            == PlayerLocation::OnPlanet {
+                                planet_id: *scanned_id,
+                            }
+                })
+                .collect_vec();
+            let result = ScanResult::new(planet, &free_pirates, current_tick);
+            lines.push(format!("{}: {}", planet.name, result.description()));
+            self.scan_results.insert(*scanned_id, result);
+        }
+        self.discover_planets(own_team.id, in_range.iter().map(|(_, id)| *id).collect());
+
+        own_team
+            .resources
+            .saturating_sub(Resource::FUEL, scanner.fuel_cost());
+        own_team.version += 1;
+        self.teams.insert(own_team.id, own_team);
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+
+        if lines.is_empty() {
+            return Ok("Scan results\nNo planets in range of the scanner.".to_string());
+        }
+        if lines.len() > MAX_SCAN_REPORT_LINES {
+            let hidden = lines.len() - MAX_SCAN_REPORT_LINES;
+            lines.truncate(MAX_SCAN_REPORT_LINES);
+            lines.push(format!("...and {} more in the galaxy view.", hidden));
+        }
+        Ok(format!("Scan results\n{}", lines.join("\n")))
+    }
+
     pub fn generate_team_asteroid(
         &mut self,
         name: String,
@@ -283,58 +712,42 @@ impl World {
             return Ok(());
         }
 
-        // Each player has a tuple in the vec, each tuple represents the toal bonus as captain, pilot, and doctor.
-        let mut team_bonus: Vec<(f32, f32, f32)> = vec![];
-        for &player_id in player_ids.iter() {
-            let player = self.get_player_or_err(player_id)?;
-            let captain_bonus = TeamBonus::Reputation.current_player_bonus(player)?
-                + TeamBonus::TradePrice.current_player_bonus(player)?;
-            let pilot_bonus = TeamBonus::Exploration.current_player_bonus(player)?
-                + TeamBonus::SpaceshipSpeed.current_player_bonus(player)?;
-            let doctor_bonus = TeamBonus::Training.current_player_bonus(player)?
-                + TeamBonus::TirednessRecovery.current_player_bonus(player)?;
-
-            team_bonus.push((captain_bonus, pilot_bonus, doctor_bonus));
-        }
-
-        // Assign roles to best player for role, starting from captain, then pilot, then doctor.
-        let (captain_idx, _) = team_bonus
-            .iter()
-            .enumerate()
-            .max_by(|(_, a), (_, b)| a.0.partial_cmp(&b.0).expect("Bonus should exist"))
-            .expect("There should be a max");
-
-        self.set_team_crew_role(CrewRole::Captain, player_ids[captain_idx])?;
-
-        let (pilot_idx, _) = team_bonus
-            .iter()
-            .enumerate()
-            .map(|(idx, value)| {
-                if idx == captain_idx {
-                    (idx, (0.0, 0.0, 0.0))
-                } else {
-                    (idx, *value)
+        // Assign roles to the best player for the role, in order of importance.
+        // Engineer and cook are only assigned if there are enough players.
+        let mut assigned: Vec<PlayerId> = vec![];
+        for role in [
+            CrewRole::Captain,
+            CrewRole::Pilot,
+            CrewRole::Doctor,
+            CrewRole::Engineer,
+            CrewRole::Cook,
+        ]
+        .iter()
+        .take(player_ids.len())
+        {
+            let mut best: Option<(PlayerId, f32)> = None;
+            for &player_id in player_ids.iter() {
+                if assigned.contains(&player_id) {
+                    continue;
                 }
-            })
-            .max_by(|(_, a), (_, b)| a.1.partial_cmp(&b.1).expect("Bonus should exist"))
-            .expect("There should be a max");
-
-        self.set_team_crew_role(CrewRole::Pilot, player_ids[pilot_idx])?;
-
-        let (doctor_idx, _) = team_bonus
-            .iter()
-            .enumerate()
-            .map(|(idx, value)| {
-                if idx == captain_idx || idx == pilot_idx {
-                    (idx, (0.0, 0.0, 0.0))
-                } else {
-                    (idx, *value)
+                let player = self.get_player_or_err(player_id)?;
+                let mut bonus = 0.0;
+                for team_bonus in role.bonuses() {
+                    bonus += team_bonus.current_player_bonus(player)?;
                 }
-            })
-            .max_by(|(_, a), (_, b)| a.2.partial_cmp(&b.2).expect("Bonus should exist"))
-            .expect("There should be a max");
+                if best
+                    .map(|(_, best_bonus)| bonus > best_bonus)
+                    .unwrap_or(true)
+                {
+                    best = Some((player_id, bonus));
+                }
+            }
 
-        self.set_team_crew_role(CrewRole::Doctor, player_ids[doctor_idx])?;
+            if let Some((player_id, _)) = best {
+                self.set_team_crew_role(*role, player_id)?;
+                assigned.push(player_id);
+            }
+        }
 
         Ok(())
     }
@@ -357,6 +770,8 @@ impl World {
             CrewRole::Captain => team.crew_roles.captain,
             CrewRole::Pilot => team.crew_roles.pilot,
             CrewRole::Doctor => team.crew_roles.doctor,
+            CrewRole::Engineer => team.crew_roles.engineer,
+            CrewRole::Cook => team.crew_roles.cook,
             //We don't need to check for mozzo because we can have several mozzos.
             CrewRole::Mozzo => None,
         };
@@ -408,6 +823,12 @@ impl World {
             CrewRole::Mozzo => {
                 team.crew_roles.mozzo.retain(|&id| id != player.id);
             }
+            CrewRole::Engineer => {
+                team.crew_roles.engineer = None;
+            }
+            CrewRole::Cook => {
+                team.crew_roles.cook = None;
+            }
         }
 
         // Set new crew role player.
@@ -424,6 +845,12 @@ impl World {
             CrewRole::Mozzo => {
                 team.crew_roles.mozzo.push(player_id);
             }
+            CrewRole::Engineer => {
+                team.crew_roles.engineer = Some(player_id);
+            }
+            CrewRole::Cook => {
+                team.crew_roles.cook = Some(player_id);
+            }
         }
         player.info.crew_role = role;
         player.set_jersey(&jersey);
@@ -437,12 +864,13 @@ impl World {
                 duration,
                 distance,
             } => {
-                let new_start = Tick::now();
-                let time_elapsed = new_start - started;
+                let new_start = self.now();
+                let time_elapsed = new_start.saturating_sub(started);
                 let bonus = TeamBonus::SpaceshipSpeed.current_player_bonus(&player)?;
 
-                let new_duration =
-                    (duration - time_elapsed) as f32 * previous_spaceship_speed_bonus / bonus;
+                let new_duration = duration.saturating_sub(time_elapsed) as f32
+                    * previous_spaceship_speed_bonus
+                    / bonus;
 
                 info!(
                     "Update {role}: old speed {previous_spaceship_speed_bonus}, new speed {bonus}"
@@ -472,1377 +900,5388 @@ impl World {
         Ok(())
     }
 
-    pub fn next_free_pirates_refresh(&self) -> Tick {
-        // Returns the time to the next FA refresh in milliseconds
-        let next_refresh = self.last_tick_long_interval + TickInterval::LONG;
-        if next_refresh > self.last_tick_short_interval {
-            next_refresh - self.last_tick_short_interval
-        } else {
-            0
-        }
+    // The fairest duel: the crew mate with the closest rating who is fit to play.
+    pub fn duel_opponent(&self, player_id: PlayerId) -> AppResult<PlayerId> {
+        let player = self.get_player_or_err(player_id)?;
+        let team_id = player.team.ok_or(anyhow!("Player is not in a team"))?;
+        let team = self.get_team_or_err(team_id)?;
+
+        team.player_ids
+            .iter()
+            .filter(|&&id| id != player_id)
+            .filter_map(|id| self.get_player(*id))
+            .filter(|other| !other.is_knocked_out() && !other.is_injured())
+            .min_by_key(|other| (other.rating() as i16 - player.rating() as i16).abs())
+            .map(|other| other.id)
+            .ok_or(anyhow!("No crew mate fit for a duel"))
     }
 
-    fn add_player_to_team(&mut self, player_id: PlayerId, team_id: TeamId) -> AppResult<()> {
-        let mut player = self.get_player(player_id).unwrap().clone();
-        let mut team = self.get_team_or_err(team_id)?.clone();
-        team.can_add_player(&player)?;
+    pub fn play_duel(
+        &mut self,
+        challenger_id: PlayerId,
+        opponent_id: PlayerId,
+        current_tick: Tick,
+    ) -> AppResult<DuelResult> {
+        let challenger = self.get_player_or_err(challenger_id)?;
+        let opponent = self.get_player_or_err(opponent_id)?;
+        let mut team = self.get_own_team()?.clone();
+        team.can_duel(challenger, opponent, current_tick)?;
 
-        team.player_ids.push(player.id);
-        team.player_ids = Team::best_position_assignment(
-            team.player_ids
-                .iter()
-                .map(|&id| self.get_player(id).unwrap())
-                .collect(),
+        let rng = &mut ChaCha8Rng::from_entropy();
+        let result = simulate_duel(challenger, opponent, rng);
+
+        let mut winner = self.get_player_or_err(result.winner_id)?.clone();
+        winner.add_morale(DUEL_WINNER_MORALE_BONUS);
+        winner.reputation = (winner.reputation + DUEL_WINNER_REPUTATION_BONUS).bound();
+        let mut loser = self.get_player_or_err(result.loser_id)?.clone();
+        loser.add_tiredness(DUEL_LOSER_TIREDNESS);
+
+        self.log_crew_event(
+            current_tick,
+            format!(
+                "{} beat {} {}-{} in an onboard duel.",
+                winner.info.shortened_name(),
+                loser.info.shortened_name(),
+                result.winner_score,
+                result.loser_score
+            ),
         );
-        team.version += 1;
-
-        player.team = Some(team.id);
-        player.current_location = PlayerLocation::WithTeam;
-        player.set_jersey(&team.jersey);
-        player.peer_id = team.peer_id;
-        player.current_location = PlayerLocation::WithTeam;
-        player.info.crew_role = CrewRole::Mozzo;
-        player.version += 1;
 
-        self.players.insert(player.id, player);
+        team.last_duel = current_tick;
+        self.players.insert(winner.id, winner);
+        self.players.insert(loser.id, loser);
         self.teams.insert(team.id, team);
         self.dirty = true;
-        if team_id == self.own_team_id {
-            self.dirty_network = true;
-        }
+        self.dirty_network = true;
         self.dirty_ui = true;
-
-        Ok(())
+        Ok(result)
     }
 
-    pub fn hire_player_for_team(&mut self, player_id: PlayerId, team_id: TeamId) -> AppResult<()> {
-        let player = self.get_player(player_id).unwrap().clone();
-        let mut team = self.get_team_or_err(team_id)?.clone();
-        team.can_hire_player(&player)?;
-        team.resources
-            .sub(Resource::SATOSHI, player.hire_cost(team.reputation))?;
-
-        self.players.insert(player.id, player);
-        self.teams.insert(team.id, team);
+    // Resolves the choice about the derelict found while exploring, returning a description of the outcome.
+    pub fn resolve_derelict(
+        &mut self,
+        choice: DerelictChoice,
+        current_tick: Tick,
+    ) -> AppResult<String> {
+        let mut team = self.get_own_team()?.clone();
+        team.can_resolve_derelict(choice)?;
+        let derelict = team
+            .derelict
+            .take()
+            .ok_or(anyhow!("No derelict to board"))?;
+
+        let mut crew = team
+            .player_ids
+            .iter()
+            .map(|id| self.get_player_or_err(*id).cloned())
+            .collect::<AppResult<Vec<Player>>>()?;
+        let rng = &mut ChaCha8Rng::from_entropy();
+        let outcome = roll_derelict_outcome(
+            choice,
+            &crew.iter().filter(|p| !p.is_knocked_out()).collect_vec(),
+            rng,
+        );
 
-        self.add_player_to_team(player_id, team_id)?;
+        for (&resource, &amount) in outcome.resources.iter() {
+            let max_capacity = if resource == Resource::FUEL {
+                team.fuel_capacity()
+            } else {
+                team.storage_capacity()
+            };
+            team.resources
+                .saturating_add(resource, amount, max_capacity);
+        }
+        if choice == DerelictChoice::Tow {
+            team.resources.sub(Resource::FUEL, TOW_FUEL_COST)?;
+        }
+        team.reputation = (team.reputation + outcome.reputation).bound();
+        if outcome.tiredness > 0.0 {
+            let provisions_bonus = TeamBonus::Provisions.current_team_bonus(self, team.id)?;
+            for player in crew.iter_mut() {
+                player.add_tiredness(outcome.tiredness / provisions_bonus);
+            }
+        }
 
-        Ok(())
-    }
+        let message = match choice {
+            DerelictChoice::Salvage => format!(
+                "Salvaged {} scraps from the {}.",
+                outcome.resources.value(&Resource::SCRAPS),
+                derelict.ship_name
+            ),
+            DerelictChoice::Board if outcome.success => format!(
+                "Boarded the {} and found {} gold.",
+                derelict.ship_name,
+                outcome.resources.value(&Resource::GOLD)
+            ),
+            DerelictChoice::Board => format!(
+                "Boarding the {} went wrong, the crew came back exhausted.",
+                derelict.ship_name
+            ),
+            DerelictChoice::Tow => format!(
+                "Towed the {} to port. Reputation +{:.2}.",
+                derelict.ship_name, outcome.reputation
+            ),
+            DerelictChoice::Leave => format!("Left the {} to drift.", derelict.ship_name),
+        };
+        self.log_crew_event(current_tick, message.clone());
 
-    pub fn swap_players_team(
-        &mut self,
-        player_id1: PlayerId,
-        player_id2: PlayerId,
-    ) -> AppResult<()> {
-        let team_id1 = self
-            .get_player_or_err(player_id1)?
-            .team
-            .ok_or(anyhow!("Player swapped should have a team"))?;
-        let team_id2 = self
-            .get_player_or_err(player_id2)?
-            .team
-            .ok_or(anyhow!("Player swapped should have a team"))?;
-
-        self.release_player_from_team(player_id1)?;
-        self.release_player_from_team(player_id2)?;
-        self.add_player_to_team(player_id1, team_id2)?;
-        self.add_player_to_team(player_id2, team_id1)?;
-        Ok(())
-    }
-
-    pub fn release_player_from_team(&mut self, player_id: PlayerId) -> AppResult<()> {
-        let mut player = self.get_player_or_err(player_id)?.clone();
-        if player.team.is_none() {
-            return Err(anyhow!("Cannot release player with no team"));
+        for player in crew {
+            self.players.insert(player.id, player);
         }
-        let mut team = self.get_team_or_err(player.team.unwrap())?.clone();
-        team.can_release_player(&player)?;
+        self.teams.insert(team.id, team);
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+        Ok(message)
+    }
 
-        team.player_ids.retain(|&p| p != player.id);
-        team.player_ids = Team::best_position_assignment(
-            team.player_ids
-                .iter()
-                .map(|&id| self.get_player(id).unwrap())
-                .collect(),
+    // Resolves the choice about the hazard halting the travel, returning a description of the outcome.
+    pub fn resolve_travel_hazard(
+        &mut self,
+        choice: HazardChoice,
+        current_tick: Tick,
+    ) -> AppResult<String> {
+        let mut team = self.get_own_team()?.clone();
+        team.can_resolve_travel_hazard(choice)?;
+        let hazard = team
+            .travel_hazard
+            .take()
+            .ok_or(anyhow!("No hazard to deal with"))?;
+
+        let mut crew = team
+            .player_ids
+            .iter()
+            .map(|id| self.get_player_or_err(*id).cloned())
+            .collect::<AppResult<Vec<Player>>>()?;
+        let rng = &mut ChaCha8Rng::from_entropy();
+        let mut outcome = roll_hazard_outcome(
+            choice,
+            &crew.iter().filter(|p| !p.is_knocked_out()).collect_vec(),
+            rng,
         );
-        team.version += 1;
 
-        player.team = None;
-        match player.info.crew_role {
-            CrewRole::Captain => team.crew_roles.captain = None,
-            CrewRole::Doctor => team.crew_roles.doctor = None,
-            CrewRole::Pilot => team.crew_roles.pilot = None,
-            CrewRole::Mozzo => team.crew_roles.mozzo.retain(|&p| p != player.id),
+        // A search turns up any rum carried beyond the crew ration.
+        let smuggled_rum = if choice == HazardChoice::Submit {
+            team.resources
+                .value(&Resource::RUM)
+                .saturating_sub(CUSTOMS_RUM_ALLOWANCE_PER_PLAYER * team.player_ids.len() as u32)
+        } else {
+            0
+        };
+        if smuggled_rum > 0 {
+            team.resources.sub(Resource::RUM, smuggled_rum)?;
+            outcome.message = format!(
+                "The inspection found {} rum of contraband and seized it.",
+                smuggled_rum
+            );
         }
-        player.info.crew_role = CrewRole::Mozzo;
-        player.morale = (player.morale + MORALE_RELEASE_MALUS).bound();
-        player.image.remove_jersey();
-        player.compose_image()?;
-        match team.current_location {
-            TeamLocation::OnPlanet { planet_id } => {
-                player.current_location = PlayerLocation::OnPlanet { planet_id };
+
+        if outcome.fuel < 0 {
+            team.resources
+                .sub(Resource::FUEL, outcome.fuel.unsigned_abs())?;
+        }
+        if outcome.satoshi < 0 {
+            team.resources
+                .sub(Resource::SATOSHI, outcome.satoshi.unsigned_abs())?;
+        }
+        if outcome.rum > 0 {
+            let storage_capacity = team.storage_capacity();
+            team.resources
+                .saturating_add(Resource::RUM, outcome.rum, storage_capacity);
+        }
+        // The engineer limits the hull damage.
+        let hazard_damage = (outcome.durability.min(0).unsigned_abs() as f32
+            * self.difficulty_modifier(DifficultyModifier::EventHarshness))
+        .round() as u32;
+        let damage = (hazard_damage as f32
+            / TeamBonus::HullCare.current_team_bonus(self, team.id)?)
+        .round() as u32;
+        if damage > 0 {
+            let durability = team.spaceship.current_durability().saturating_sub(damage);
+            team.spaceship.set_current_durability(durability);
+        }
+        if outcome.morale != 0.0 {
+            for player in crew.iter_mut() {
+                player.add_morale(outcome.morale);
             }
-            _ => return Err(anyhow!("Cannot release player while travelling")),
         }
-        player.version += 1;
-
-        self.players.insert(player.id, player.clone());
-        self.teams.insert(team.id, team.clone());
 
-        self.dirty = true;
-        if team.id == self.own_team_id {
-            self.dirty_network = true;
+        // Resume the travel, shifting it by the time spent halted plus any delay.
+        if let TeamLocation::Travelling {
+            from,
+            to,
+            started,
+            duration,
+            distance,
+        } = team.current_location
+        {
+            let halted_for = current_tick.saturating_sub(hazard.started);
+            team.current_location = TeamLocation::Travelling {
+                from,
+                to,
+                started: started + halted_for + outcome.delay,
+                duration,
+                distance,
+            };
         }
-        self.dirty_ui = true;
-
-        Ok(())
-    }
-
-    fn generate_game_no_checks(
-        &mut self,
-        mut home_team_in_game: TeamInGame,
-        mut away_team_in_game: TeamInGame,
-        starting_at: Tick,
-        location: PlanetId,
-    ) -> AppResult<GameId> {
-        // Generate deterministic game id from team IDs and starting time.
-        // Two games starting at u64::MAX milliseconds apart ~ 584_942_417 years
-        // would have the same ID, we assume this can't happen.
-        let mut rng_seed = ((home_team_in_game.team_id.as_u64_pair().0 as u128
-            + away_team_in_game.team_id.as_u64_pair().0 as u128)
-            % (u64::MAX as u128)) as u64;
-        rng_seed = ((rng_seed as u128 + starting_at) % (u64::MAX as u128)) as u64;
-        let rng = &mut ChaCha8Rng::seed_from_u64(rng_seed);
-        let game_id = GameId::from_u128(rng.gen());
-
-        let planet = self.get_planet_or_err(location)?;
 
-        // Give morale bonus to players based on planet populations
-        for (_, player) in home_team_in_game.players.iter_mut() {
-            let morale_bonus = planet
-                .populations
-                .get(&player.info.population)
-                .copied()
-                .unwrap_or_default() as f32
-                * MORALE_GAME_POPULATION_MODIFIER;
-            player.add_morale(morale_bonus);
+        let team_id = team.id;
+        let destination = match team.current_location {
+            TeamLocation::Travelling { to, .. } => Some(to),
+            _ => None,
+        };
+        for player in crew {
+            self.players.insert(player.id, player);
         }
+        self.teams.insert(team.id, team);
 
-        for (_, player) in away_team_in_game.players.iter_mut() {
-            let morale_bonus = planet
-                .populations
-                .get(&player.info.population)
-                .copied()
-                .unwrap_or_default() as f32
-                * MORALE_GAME_POPULATION_MODIFIER;
-            player.add_morale(morale_bonus);
+        // Word of the bust reaches the destination before the crew does.
+        if let Some(planet_id) = destination.filter(|_| smuggled_rum > 0) {
+            self.spread_gossip(team_id, planet_id, Gossip::Smuggling, None, current_tick)?;
         }
 
-        let game = Game::new(
-            game_id,
-            home_team_in_game,
-            away_team_in_game,
-            starting_at,
-            planet,
-        );
-        self.games.insert(game.id, game);
+        if outcome.satoshi < 0 {
+            let (category, description) = if choice == HazardChoice::PayFine {
+                (FinanceCategory::Fines, "Customs patrol fine".to_string())
+            } else {
+                (
+                    FinanceCategory::Trading,
+                    "Rum from stranded traders".to_string(),
+                )
+            };
+            self.record_transaction(
+                team_id,
+                current_tick,
+                category,
+                outcome.satoshi as i64,
+                description,
+            );
+        }
+        let mut message = format!("{}: {}", hazard.kind, outcome.message);
+        if damage < hazard_damage {
+            message = format!("{} The engineer limited the damage to {}.", message, damage);
+        }
+        if damage > 0 {
+            if let Some(claim) =
+                self.claim_insurance(InsuranceKind::Hull, hull_loss_value(damage), current_tick)?
+            {
+                message = format!("{} {}", message, claim);
+            }
+        }
+        self.log_crew_event(current_tick, message.clone());
 
-        Ok(game_id)
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+        Ok(message)
     }
 
-    pub fn generate_network_game(
+    // Assigns the kartoffel as companion to the player, or sends it back to the hold if player_id is None.
+    pub fn assign_kartoffel(
         &mut self,
-        home_team_in_game: TeamInGame,
-        away_team_in_game: TeamInGame,
-        starting_at: Tick,
-    ) -> AppResult<GameId> {
-        let mut home_team = self.get_team_or_err(home_team_in_game.team_id)?.clone();
-        let mut away_team = self.get_team_or_err(away_team_in_game.team_id)?.clone();
-
-        // For a network game we run different checks.
-        // In particular, we do not check if the teams are already playing a game
-        // as this is done with an extra check if the current game is THIS game.
-        // This is necessary because of a race condition when a team can be received
-        // over the network before the challenge confirmation message.
-        home_team.can_challenge_team_over_network(&away_team)?;
+        kartoffel_id: KartoffelId,
+        player_id: Option<PlayerId>,
+    ) -> AppResult<()> {
+        let mut kartoffel = self.get_kartoffel_or_err(kartoffel_id)?.clone();
+        if kartoffel.team != Some(self.own_team_id) {
+            return Err(anyhow!("Kartoffel is not in the team"));
+        }
 
-        let location = match home_team.current_location {
-            TeamLocation::OnPlanet { planet_id } => planet_id,
-            _ => {
-                panic!("Should have failed in can_challenge_team")
+        if let Some(player_id) = player_id {
+            let player = self.get_player_or_err(player_id)?;
+            if player.team != Some(self.own_team_id) {
+                return Err(anyhow!("Player is not in the team"));
             }
-        };
+        }
 
-        let game_id = self.generate_game_no_checks(
-            home_team_in_game,
-            away_team_in_game,
-            starting_at,
-            location,
-        )?;
+        // A player has at most one companion, and a kartoffel follows at most one player.
+        let previous_companions = self
+            .kartoffeln
+            .values()
+            .filter(|k| k.id != kartoffel_id && k.assigned_to.is_some())
+            .filter(|k| k.assigned_to == player_id)
+            .map(|k| k.id)
+            .collect_vec();
+        for id in previous_companions {
+            let mut other = self.get_kartoffel_or_err(id)?.clone();
+            other.assigned_to = None;
+            self.kartoffeln.insert(other.id, other);
+        }
 
-        if let Some(previous_game_id) = home_team.current_game {
-            if game_id != previous_game_id {
-                return Err(anyhow!("Team is already playing another game"));
-            }
+        for id in kartoffel.assigned_to.iter().chain(player_id.iter()) {
+            let mut player = self.get_player_or_err(*id)?.clone();
+            player.companion_bonus = None;
+            player.version += 1;
+            self.players.insert(player.id, player);
         }
 
-        if let Some(previous_game_id) = away_team.current_game {
-            if game_id != previous_game_id {
-                return Err(anyhow!("Opponent is already playing another game"));
-            }
+        kartoffel.assigned_to = player_id;
+        if let Some(player_id) = player_id {
+            let mut player = self.get_player_or_err(player_id)?.clone();
+            player.companion_bonus = Some(kartoffel.companion_bonus());
+            self.players.insert(player.id, player);
         }
+        kartoffel.version += 1;
+        self.kartoffeln.insert(kartoffel.id, kartoffel);
 
-        home_team.current_game = Some(game_id);
-        away_team.current_game = Some(game_id);
         self.dirty = true;
+        self.dirty_network = true;
         self.dirty_ui = true;
-
-        if home_team.id == self.own_team_id || away_team.id == self.own_team_id {
-            // Update network that game has started.
-            self.dirty_network = true;
-        }
-
-        self.teams.insert(home_team.id, home_team);
-        self.teams.insert(away_team.id, away_team);
-
-        Ok(game_id)
+        Ok(())
     }
 
-    pub fn generate_game(
+    pub fn feed_kartoffel(
         &mut self,
-        home_team_in_game: TeamInGame,
-        away_team_in_game: TeamInGame,
-    ) -> AppResult<GameId> {
-        let starting_at = Tick::now() + GAME_START_DELAY;
-        let mut home_team = self.get_team_or_err(home_team_in_game.team_id)?.clone();
-        let mut away_team = self.get_team_or_err(away_team_in_game.team_id)?.clone();
+        kartoffel_id: KartoffelId,
+        current_tick: Tick,
+    ) -> AppResult<()> {
+        let mut kartoffel = self.get_kartoffel_or_err(kartoffel_id)?.clone();
+        let mut team = self.get_own_team()?.clone();
+        kartoffel.can_be_fed_by(&team, current_tick)?;
 
-        home_team.can_challenge_team(&away_team)?;
+        team.resources.sub(Resource::RUM, 1)?;
+        kartoffel.feed(current_tick);
+        kartoffel.version += 1;
 
-        let location = home_team
-            .is_on_planet()
-            .expect("Should have failed in can_challenge_team");
-        let game_id = self.generate_game_no_checks(
-            home_team_in_game,
-            away_team_in_game,
-            starting_at,
-            location,
-        )?;
+        if let Some(player_id) = kartoffel.assigned_to {
+            let mut player = self.get_player_or_err(player_id)?.clone();
+            player.companion_bonus = Some(kartoffel.companion_bonus());
+            self.players.insert(player.id, player);
+        }
 
-        home_team.current_game = Some(game_id);
-        away_team.current_game = Some(game_id);
+        self.kartoffeln.insert(kartoffel.id, kartoffel);
+        self.teams.insert(team.id, team);
         self.dirty = true;
+        self.dirty_network = true;
         self.dirty_ui = true;
+        Ok(())
+    }
 
-        if home_team.id == self.own_team_id || away_team.id == self.own_team_id {
-            // Update network that game has started.
-            self.dirty_network = true;
+    pub fn next_free_pirates_refresh(&self) -> Tick {
+        // Returns the time to the next FA refresh in milliseconds
+        let next_refresh = self.last_tick_long_interval + TickInterval::LONG;
+        if next_refresh > self.last_tick_short_interval {
+            next_refresh - self.last_tick_short_interval
+        } else {
+            0
         }
-
-        self.teams.insert(home_team.id, home_team);
-        self.teams.insert(away_team.id, away_team);
-
-        Ok(game_id)
     }
 
-    pub fn add_network_game(&mut self, network_game: NetworkGame) -> AppResult<()> {
-        // Check that the game does not involve the own team (otherwise we would have generated it).
-        if network_game.home_team_in_game.team_id == self.own_team_id
-            || network_game.away_team_in_game.team_id == self.own_team_id
-        {
-            return Err(anyhow!(
-                "Cannot receive game involving own team over the network."
+    // Collects the timed events concerning the own team, soonest first.
+    pub fn calendar_events(&self) -> AppResult<Vec<CalendarEvent>> {
+        let own_team = self.get_own_team()?;
+        let mut events = vec![];
+
+        if let Some(game) = own_team.current_game.and_then(|id| self.get_game(id)) {
+            events.push(CalendarEvent::new(
+                game.starting_at,
+                CalendarEventKind::Game,
+                format!(
+                    "{} vs {}",
+                    game.home_team_in_game.name, game.away_team_in_game.name
+                ),
             ));
         }
 
-        if network_game.timer.has_ended() {
-            return Err(anyhow!(
-                "Cannot receive game that has ended over the network."
-            ));
+        for challenge in own_team
+            .sent_challenges
+            .values()
+            .chain(own_team.received_challenges.values())
+        {
+            if let Some(starting_at) = challenge.starting_at {
+                events.push(CalendarEvent::new(
+                    starting_at,
+                    CalendarEventKind::Challenge,
+                    format!(
+                        "{} vs {}",
+                        challenge.home_team_in_game.name, challenge.away_team_in_game.name
+                    ),
+                ));
+            }
         }
 
-        if self.get_game(network_game.id).is_none() {
-            let mut game = Game::new(
-                network_game.id,
-                network_game.home_team_in_game,
-                network_game.away_team_in_game,
-                network_game.starting_at,
-                self.get_planet_or_err(network_game.location)?,
-            );
-
-            while game.timer.value < network_game.timer.value && !game.timer.has_ended() {
-                game.tick(Tick::now());
-            }
-
-            self.games.insert(game.id, game);
-            self.dirty_ui = true;
+        match own_team.current_location {
+            TeamLocation::Travelling {
+                to,
+                started,
+                duration,
+                ..
+            } => events.push(CalendarEvent::new(
+                started + duration,
+                CalendarEventKind::Arrival,
+                format!("Arrival on {}", self.get_planet_or_err(to)?.name),
+            )),
+            TeamLocation::Exploring {
+                around,
+                started,
+                duration,
+            } => events.push(CalendarEvent::new(
+                started + duration,
+                CalendarEventKind::Exploration,
+                format!(
+                    "Exploration around {} ends",
+                    self.get_planet_or_err(around)?.name
+                ),
+            )),
+            _ => {}
         }
 
-        Ok(())
-    }
-
-    pub fn add_network_team(&mut self, network_team: NetworkTeam) -> AppResult<()> {
-        let NetworkTeam {
-            team,
-            players,
-            home_planet,
-        } = network_team;
-        if team.peer_id.is_none() {
-            return Err(anyhow!(
-                "Cannot receive team without peer_id over the network."
+        if let Some(upgrade) = own_team.spaceship.pending_upgrade.as_ref() {
+            events.push(CalendarEvent::new(
+                upgrade.started + upgrade.duration,
+                CalendarEventKind::SpaceshipUpgrade,
+                format!("{} upgrade completed", upgrade.target),
             ));
         }
-        if team.id == self.own_team_id {
-            return Err(anyhow!("Cannot receive own team over the network."));
-        }
 
-        // Check if we are receiving a team with which we have an open challenge.
-        // Note: there could be a race condition where we receive a team over the network right after
-        //       accepting the challenge but before the challenge has been finalized on our side.
-        //       In this case, the received team would have current_game set to some (set to the challenge game
-        //       they just started) and the challenge would fail on our hand since the challenge team must have no game.
-        let own_team = self.get_own_team()?;
-        for player in &players {
-            // Check if any player in the team is part of own team, in which case fail.
-            // This check guarantees that the own team state gets precedence over
-            // what we receive from the network.
-            // Note: finalizing a trade in handle_trade_topic assumes that this check is in place
-            //       to ensure that there is no race condition between receiving the trade
-            //       syn_ack state and the network team from the trade proposer.
-            if own_team.player_ids.contains(&player.id) {
-                return Err(anyhow!(
-                    "Cannot receive over the network a player which is part of own team."
-                ));
-            }
+        if let Some(until) = own_team.on_strike_until {
+            events.push(CalendarEvent::new(
+                until,
+                CalendarEventKind::StrikeEnd,
+                "Crew strike ends".to_string(),
+            ));
         }
 
-        let db_team = self.get_team(team.id).cloned();
+        events.push(CalendarEvent::new(
+            self.last_tick_short_interval + self.next_free_pirates_refresh(),
+            CalendarEventKind::FreePiratesRefresh,
+            "New free pirates available".to_string(),
+        ));
 
-        // Remove team from previous planet
-        if let Some(previous_version_team) = db_team.as_ref() {
-            match previous_version_team.current_location {
-                TeamLocation::OnPlanet { planet_id } => {
-                    let mut planet = self.get_planet_or_err(planet_id)?.clone();
-                    planet.team_ids.retain(|&id| id != team.id);
-                    self.planets.insert(planet.id, planet);
-                }
-                _ => {}
-            }
+        Ok(upcoming(events, self.last_tick_short_interval))
+    }
 
-            // Remove players from db_team that are not in the new team to clean up fired players
-            for player_id in &previous_version_team.player_ids {
-                self.players.remove(&player_id);
-            }
-        }
+    fn add_player_to_team(&mut self, player_id: PlayerId, team_id: TeamId) -> AppResult<()> {
+        let mut player = self.get_player(player_id).unwrap().clone();
+        let mut team = self.get_team_or_err(team_id)?.clone();
+        team.can_add_player(&player)?;
 
-        // When adding a network planet, we do not update the parent planet satellites on purpose,
-        // to avoid complications when cleaning up to store the world.
-        // This means that the network satellite will not appear in the galaxy.
-        if let Some(planet) = home_planet {
-            if planet.peer_id.is_none() {
-                return Err(anyhow!(
-                    "Cannot receive planet without peer_id over the network."
-                ));
-            }
-            self.planets.insert(planet.id, planet);
-        }
+        team.player_ids.push(player.id);
+        team.player_ids = Team::best_position_assignment(
+            team.player_ids
+                .iter()
+                .map(|&id| self.get_player(id).unwrap())
+                .collect(),
+        );
+        team.version += 1;
 
-        // Add team to new planet
-        match team.current_location {
-            TeamLocation::OnPlanet { planet_id } => {
-                let mut planet = self.get_planet_or_err(planet_id)?.clone();
-                planet.team_ids.push(team.id);
-                self.planets.insert(planet.id, planet);
-            }
-            _ => {}
+        player.team = Some(team.id);
+        player.current_location = PlayerLocation::WithTeam;
+        player.set_jersey(&team.jersey);
+        player.peer_id = team.peer_id;
+        player.current_location = PlayerLocation::WithTeam;
+        player.info.crew_role = CrewRole::Mozzo;
+        player.version += 1;
+
+        self.players.insert(player.id, player);
+        self.teams.insert(team.id, team);
+        self.dirty = true;
+        if team_id == self.own_team_id {
+            self.dirty_network = true;
         }
+        self.dirty_ui = true;
 
-        for player in players {
-            if player.peer_id.is_none() || player.peer_id.unwrap() != team.peer_id.unwrap() {
-                return Err(anyhow!(
-                    "Cannot receive player with wrong peer_id over the network."
-                ));
-            }
+        Ok(())
+    }
+
+    pub fn change_own_team_appearance(&mut self, jersey: Jersey, tick: Tick) -> AppResult<()> {
+        let mut team = self.get_own_team()?.clone();
+        team.can_change_appearance(&jersey)?;
+
+        team.resources
+            .sub(Resource::SATOSHI, APPEARANCE_CHANGE_COST)?;
+        self.record_transaction(
+            team.id,
+            tick,
+            FinanceCategory::Appearance,
+            -(APPEARANCE_CHANGE_COST as i64),
+            format!("New {} jersey", jersey.style.to_string().to_lowercase()),
+        );
+
+        // The spaceship always shares the jersey colors.
+        team.spaceship.image.set_color_map(jersey.color);
+        team.jersey = jersey;
+        for player_id in team.player_ids.iter() {
+            let mut player = self.get_player_or_err(*player_id)?.clone();
+            player.set_jersey(&team.jersey);
             self.players.insert(player.id, player);
         }
+        team.version += 1;
 
         self.teams.insert(team.id, team);
+        self.dirty = true;
+        self.dirty_network = true;
         self.dirty_ui = true;
 
         Ok(())
     }
 
-    pub fn get_team(&self, id: TeamId) -> Option<&Team> {
-        self.teams.get(&id)
-    }
+    pub fn change_own_team_emblem(&mut self, emblem: Emblem, tick: Tick) -> AppResult<()> {
+        let mut team = self.get_own_team()?.clone();
+        team.can_change_emblem(&emblem)?;
+
+        team.resources.sub(Resource::SATOSHI, EMBLEM_CHANGE_COST)?;
+        self.record_transaction(
+            team.id,
+            tick,
+            FinanceCategory::Appearance,
+            -(EMBLEM_CHANGE_COST as i64),
+            format!("New {} emblem", emblem.symbol.to_string().to_lowercase()),
+        );
 
-    pub fn get_team_or_err(&self, id: TeamId) -> AppResult<&Team> {
-        self.get_team(id)
-            .ok_or(anyhow!("Team {:?} not found", id).into())
-    }
+        team.emblem = Some(emblem);
+        team.version += 1;
 
-    pub fn get_own_team(&self) -> AppResult<&Team> {
-        self.get_team_or_err(self.own_team_id)
-    }
+        self.teams.insert(team.id, team);
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
 
-    pub fn get_own_team_mut(&mut self) -> AppResult<&mut Team> {
-        self.teams
-            .get_mut(&self.own_team_id)
-            .ok_or(anyhow!("Team {:?} not found", self.own_team_id))
+        Ok(())
     }
 
-    pub fn get_planet(&self, id: PlanetId) -> Option<&Planet> {
-        self.planets.get(&id)
+    pub fn rename(&mut self, target: RenameTarget, name: String, tick: Tick) -> AppResult<String> {
+        let mut team = self.get_own_team()?.clone();
+        team.can_rename(target, &name)?;
+
+        let message = match target {
+            RenameTarget::Team => {
+                let message = format!("{} is now known as {}.", team.name, name);
+                team.name = name;
+                message
+            }
+            RenameTarget::Spaceship => {
+                let message = format!("The {} is now the {}.", team.spaceship.name, name);
+                team.spaceship.name = name;
+                message
+            }
+            RenameTarget::Player { player_id } => {
+                let mut player = self.get_player_or_err(player_id)?.clone();
+                let message = format!("{} is now called {}.", player.info.full_name(), name);
+                player.info.nickname = Some(name);
+                player.version += 1;
+                self.players.insert(player.id, player);
+                message
+            }
+        };
+
+        team.resources.sub(Resource::SATOSHI, target.cost())?;
+        self.record_transaction(
+            team.id,
+            tick,
+            FinanceCategory::Appearance,
+            -(target.cost() as i64),
+            format!("New {}", target.to_string().to_lowercase()),
+        );
+        team.version += 1;
+
+        self.teams.insert(team.id, team);
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+
+        Ok(message)
     }
 
-    pub fn get_planet_or_err(&self, id: PlanetId) -> AppResult<&Planet> {
-        self.get_planet(id)
-            .ok_or(anyhow!("Planet {:?} not found", id))
+    pub fn difficulty_modifier(&self, kind: DifficultyModifier) -> f32 {
+        self.generation_options.difficulty.modifier(kind)
     }
 
-    pub fn get_player(&self, id: PlayerId) -> Option<&Player> {
-        self.players.get(&id)
+    pub fn hire_cost(&self, team: &Team, player: &Player) -> u32 {
+        let hire_cost = player.hire_cost(team.hiring_reputation(player));
+        if team.id != self.own_team_id {
+            return hire_cost;
+        }
+        (hire_cost as f32 * self.difficulty_modifier(DifficultyModifier::HireCost)).max(1.0) as u32
     }
 
-    pub fn get_player_or_err(&self, id: PlayerId) -> AppResult<&Player> {
-        self.get_player(id)
-            .ok_or(anyhow!("Player {:?} not found", id))
+    pub fn hire_player_for_team(&mut self, player_id: PlayerId, team_id: TeamId) -> AppResult<()> {
+        let player = self.get_player(player_id).unwrap().clone();
+        let mut team = self.get_team_or_err(team_id)?.clone();
+        let hire_cost = self.hire_cost(&team, &player);
+        team.can_hire_player(&player, hire_cost)?;
+        team.resources.sub(Resource::SATOSHI, hire_cost)?;
+        self.record_transaction(
+            team_id,
+            self.now(),
+            FinanceCategory::Hiring,
+            -(hire_cost as i64),
+            format!("Hired {}", player.info.shortened_name()),
+        );
+
+        self.players.insert(player.id, player);
+        self.teams.insert(team.id, team);
+
+        self.add_player_to_team(player_id, team_id)?;
+
+        Ok(())
     }
 
-    pub fn get_players_by_team(&self, team: &Team) -> AppResult<Vec<Player>> {
-        Ok(team
-            .player_ids
-            .iter()
-            .map(|&id| {
-                self.get_player(id)
-                    .ok_or(anyhow!("Player {:?} not found", id))
+    pub fn academy_prospects(&self, team_id: TeamId) -> Vec<&Player> {
+        self.players
+            .values()
+            .filter(|player| {
+                player.team == Some(team_id) && academy::academy_location(player).is_some()
             })
-            .collect::<Result<Vec<&Player>, _>>()?
-            .iter()
-            .map(|&p| p.clone())
-            .collect::<Vec<Player>>())
+            .sorted_by_key(|player| player.info.full_name())
+            .collect()
     }
 
-    pub fn get_game(&self, id: GameId) -> Option<&Game> {
-        self.games.get(&id)
-    }
+    pub fn enroll_in_academy(
+        &mut self,
+        player_id: PlayerId,
+        current_tick: Tick,
+    ) -> AppResult<String> {
+        let own_team = self.get_own_team()?;
+        let mut player = self.get_player_or_err(player_id)?.clone();
+        own_team.can_enroll_in_academy(&player, self.academy_prospects(own_team.id).len())?;
+
+        let asteroid = self.get_planet_or_err(own_team.asteroid_ids[0])?;
+        player.team = Some(own_team.id);
+        player.peer_id = own_team.peer_id;
+        player.current_location = PlayerLocation::InAcademy {
+            asteroid_id: asteroid.id,
+            enrolled: current_tick,
+        };
+        player.version += 1;
+        let message = format!(
+            "{} enrolled in the academy on {}.\nThe fee is {} satoshi per day.",
+            player.info.shortened_name(),
+            asteroid.name,
+            ACADEMY_DAILY_FEE
+        );
 
-    pub fn get_game_or_err(&self, id: GameId) -> AppResult<&Game> {
-        self.get_game(id).ok_or(anyhow!("Game {:?} not found", id))
+        self.players.insert(player.id, player);
+        self.dirty = true;
+        self.dirty_ui = true;
+        Ok(message)
     }
 
-    pub fn team_rating(&self, team_id: TeamId) -> AppResult<f32> {
-        let team = self.get_team_or_err(team_id)?;
-        if team.player_ids.len() == 0 {
-            return Ok(0.0);
+    // Every populated planet offers one candidate per staff role, renewed daily.
+    pub fn staff_candidates(
+        &self,
+        planet_id: PlanetId,
+        current_tick: Tick,
+    ) -> AppResult<Vec<StaffMember>> {
+        let planet = self.get_planet_or_err(planet_id)?;
+        if planet.total_population() == 0 {
+            return Ok(vec![]);
         }
-        Ok(team
-            .player_ids
-            .iter()
-            .filter(|&&id| self.get_player(id).is_some())
-            .map(|&id| self.get_player(id).unwrap().rating())
-            .sum::<u8>() as f32
-            / team.player_ids.len().max(MIN_PLAYERS_PER_GAME) as f32)
+
+        let seed = self.seed ^ planet.id.as_u64_pair().0 ^ (current_tick / DAYS) as u64;
+        let rng = &mut ChaCha8Rng::seed_from_u64(seed);
+        Ok(StaffRole::iter()
+            .map(|role| StaffMember::random(rng, role, planet))
+            .collect())
     }
 
-    pub fn is_simulating(&self) -> bool {
-        if !self.has_own_team() {
-            return false;
-        }
+    pub fn hire_staff(&mut self, role: StaffRole, current_tick: Tick) -> AppResult<String> {
+        let mut team = self.get_own_team()?.clone();
+        let planet_id = team.is_on_planet().ok_or(anyhow!("Not on a planet"))?;
+        let mut candidate = self
+            .staff_candidates(planet_id, current_tick)?
+            .into_iter()
+            .find(|candidate| candidate.role == role)
+            .ok_or(anyhow!("Nobody to hire here"))?;
+        team.can_hire_staff(&candidate)?;
+
+        let fee = candidate.hiring_fee();
+        team.resources.sub(Resource::SATOSHI, fee)?;
+        candidate.hired = current_tick;
+        let message = format!(
+            "{} joined as {}.\nThe salary is {} satoshi per day.",
+            candidate.name,
+            role.to_string().to_lowercase(),
+            candidate.salary()
+        );
+        team.staff.push(candidate);
+        team.version += 1;
+        self.teams.insert(team.id, team.clone());
+        self.record_transaction(
+            team.id,
+            current_tick,
+            FinanceCategory::Staff,
+            -(fee as i64),
+            format!("Hired {}", role.to_string().to_lowercase()),
+        );
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+        Ok(message)
+    }
 
-        // This works if we assume that we can't lag behind more than a SHORT interval (1 second).
-        // DEBUG_TIME_MULTIPLIER than cannot be too large or due to finite FPS this condition
-        // would always return true.
-        Tick::now() > self.last_tick_short_interval + TickInterval::SHORT
+    pub fn fire_staff(&mut self, role: StaffRole) -> AppResult<String> {
+        let mut team = self.get_own_team()?.clone();
+        team.can_fire_staff(role)?;
+
+        let member = team
+            .staff_member(role)
+            .ok_or(anyhow!("No {} hired", role.to_string().to_lowercase()))?;
+        let message = format!("{} left the crew.", member.name);
+        team.staff.retain(|member| member.role != role);
+        team.version += 1;
+        self.teams.insert(team.id, team);
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+        Ok(message)
     }
 
-    fn resources_found_after_exploration(
-        &self,
-        team: &Team,
-        planet: &Planet,
-        duration: u128,
-    ) -> AppResult<ResourceMap> {
-        let mut rng = ChaCha8Rng::from_entropy();
-        let mut resources = HashMap::new();
-
-        let bonus = TeamBonus::Exploration.current_team_bonus(&self, team.id)?;
+    // Promoted prospects join the crew like any other hired pirate.
+    pub fn promote_academy_prospect(&mut self, player_id: PlayerId) -> AppResult<String> {
+        let mut team = self.get_own_team()?.clone();
+        let mut player = self.get_player_or_err(player_id)?.clone();
+        team.can_promote_prospect(&player)?;
 
-        for (&resource, &amount) in planet.resources.iter() {
-            let mut found_amount = 0;
-            // The exploration bonus makes the random range larger, which is positive in expectation
-            // since we clamp at 0.
-            let base = ((2.0_f32).powf(amount as f32 / 2.0) * bonus) as i32;
-            for _ in 0..(duration / QUICK_EXPLORATION_TIME) {
-                found_amount += rng.gen_range(-base / 2..base).max(0) as u32;
-            }
-            resources.insert(resource, found_amount);
-        }
+        player.current_location = PlayerLocation::WithTeam;
+        player.set_jersey(&team.jersey);
+        player.info.crew_role = CrewRole::Mozzo;
+        player.version += 1;
+        let message = format!("{} joined the crew!", player.info.shortened_name());
+        self.players.insert(player.id, player);
 
-        Ok(resources)
+        team.player_ids.push(player_id);
+        team.player_ids = Team::best_position_assignment(
+            team.player_ids
+                .iter()
+                .map(|&id| self.get_player(id).unwrap())
+                .collect(),
+        );
+        team.version += 1;
+        self.teams.insert(team.id, team);
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+        Ok(message)
     }
 
-    fn free_pirates_found_after_exploration(
-        &mut self,
-        planet: &Planet,
-        duration: u128,
-    ) -> AppResult<Vec<PlayerId>> {
-        let rng = &mut ChaCha8Rng::from_entropy();
-        let mut free_pirates = vec![];
-
-        let duration_bonus = (duration as f32 / (1 * HOURS) as f32).powf(1.3);
-        let population_bonus = planet.total_population() as f32;
+    // Released prospects become free pirates on the planet the academy orbits.
+    pub fn release_academy_prospect(&mut self, player_id: PlayerId) -> AppResult<String> {
+        let team = self.get_own_team()?;
+        let mut player = self.get_player_or_err(player_id)?.clone();
+        team.can_release_prospect(&player)?;
 
-        let amount = rng
-            .gen_range((-32 + (population_bonus + duration_bonus) as i32).min(0)..3)
-            .max(0);
+        let (asteroid_id, _) =
+            academy::academy_location(&player).ok_or(anyhow!("Not in the academy"))?;
+        let asteroid = self.get_planet_or_err(asteroid_id)?;
+        let planet_id = asteroid.satellite_of.unwrap_or(asteroid.id);
+        player.team = None;
+        player.peer_id = None;
+        player.current_location = PlayerLocation::OnPlanet { planet_id };
+        player.version += 1;
+        let message = format!(
+            "{} left the academy for {}.",
+            player.info.shortened_name(),
+            self.get_planet_or_err(planet_id)?.name
+        );
 
-        if amount > 0 {
-            for _ in 0..amount {
-                let base_level = rng.gen_range(0.0..7.0);
-                let player_id = self.generate_random_player(rng, None, planet.id, base_level)?;
+        self.players.insert(player.id, player);
+        self.dirty = true;
+        self.dirty_ui = true;
+        Ok(message)
+    }
 
-                free_pirates.push(player_id);
-            }
+    pub fn take_loan(&mut self, amount: u32, current_tick: Tick) -> AppResult<()> {
+        let mut own_team = self.get_own_team()?.clone();
+        let planet_id = own_team
+            .is_on_planet()
+            .ok_or(anyhow!("Team is not on a planet"))?;
+        let planet = self.get_planet_or_err(planet_id)?;
+        own_team.can_take_loan(planet, amount)?;
+        let description = format!("Loan from {}", planet.name);
+
+        own_team
+            .resources
+            .add(Resource::SATOSHI, amount, own_team.storage_capacity())?;
+        match own_team.loan.as_mut() {
+            Some(loan) => loan.increase(amount),
+            None => own_team.loan = Some(Loan::new(planet_id, amount, current_tick)),
         }
+        own_team.version += 1;
+        self.teams.insert(own_team.id, own_team);
+        self.record_transaction(
+            self.own_team_id,
+            current_tick,
+            FinanceCategory::Loans,
+            amount as i64,
+            description,
+        );
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+        Ok(())
+    }
 
-        Ok(free_pirates)
+    // Repays at most the outstanding debt.
+    pub fn repay_loan(&mut self, amount: u32, current_tick: Tick) -> AppResult<()> {
+        let mut own_team = self.get_own_team()?.clone();
+        own_team.can_repay_loan(amount)?;
+        let mut loan = own_team.loan.take().ok_or(anyhow!("No loan to repay"))?;
+        let amount = amount.min(loan.debt);
+
+        own_team.resources.sub(Resource::SATOSHI, amount)?;
+        loan.debt -= amount;
+        let description = if loan.debt == 0 {
+            "Loan repaid".to_string()
+        } else {
+            "Loan repayment".to_string()
+        };
+        if loan.debt > 0 {
+            own_team.loan = Some(loan);
+        }
+        own_team.version += 1;
+        self.teams.insert(own_team.id, own_team);
+        self.record_transaction(
+            self.own_team_id,
+            current_tick,
+            FinanceCategory::Loans,
+            -(amount as i64),
+            description,
+        );
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+        Ok(())
     }
 
-    pub fn handle_tick_events(&mut self, current_tick: Tick) -> AppResult<Vec<UiCallback>> {
-        let mut callbacks: Vec<UiCallback> = vec![];
-        let is_simulating = self.is_simulating();
+    pub fn buy_insurance(&mut self, kind: InsuranceKind, current_tick: Tick) -> AppResult<()> {
+        let mut own_team = self.get_own_team()?.clone();
+        own_team.can_buy_insurance(kind, current_tick)?;
+        let premium = kind.premium(&own_team.spaceship);
+
+        own_team.resources.sub(Resource::SATOSHI, premium)?;
+        own_team
+            .insurance
+            .retain(|policy| policy.is_active(current_tick));
+        own_team
+            .insurance
+            .push(InsurancePolicy::new(kind, current_tick));
+        own_team.version += 1;
+        self.teams.insert(own_team.id, own_team);
+        self.record_transaction(
+            self.own_team_id,
+            current_tick,
+            FinanceCategory::Insurance,
+            -(premium as i64),
+            format!("{} insurance premium", kind),
+        );
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+        Ok(())
+    }
 
-        if let Some(space) = self.space_adventure.as_mut() {
-            let deltatime = (current_tick - self.last_tick_min_interval) as f32 / SECONDS as f32;
+    // Pays out the loss if the own team has an active policy covering it.
+    // Returns a description of the payout, if any.
+    pub fn claim_insurance(
+        &mut self,
+        kind: InsuranceKind,
+        loss_value: u32,
+        current_tick: Tick,
+    ) -> AppResult<Option<String>> {
+        let mut own_team = self.get_own_team()?.clone();
+        if own_team.active_insurance(kind, current_tick).is_none() {
+            return Ok(None);
+        }
 
-            callbacks.append(&mut space.update(deltatime)?);
+        let payout = insurance_payout(loss_value);
+        if payout == 0 {
+            return Ok(None);
         }
 
-        self.last_tick_min_interval = current_tick;
+        own_team
+            .resources
+            .saturating_add(Resource::SATOSHI, payout, own_team.storage_capacity());
+        own_team.version += 1;
+        self.teams.insert(own_team.id, own_team);
+        self.record_transaction(
+            self.own_team_id,
+            current_tick,
+            FinanceCategory::Insurance,
+            payout as i64,
+            format!("{} insurance claim", kind),
+        );
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+        Ok(Some(format!(
+            "The {} insurance paid out {} satoshi.",
+            kind.to_string().to_lowercase(),
+            payout
+        )))
+    }
 
-        if current_tick >= self.last_tick_short_interval + TickInterval::SHORT {
-            self.tick_games(current_tick)?;
-            self.cleanup_games(current_tick)?;
+    pub fn swap_players_team(
+        &mut self,
+        player_id1: PlayerId,
+        player_id2: PlayerId,
+    ) -> AppResult<()> {
+        let team_id1 = self
+            .get_player_or_err(player_id1)?
+            .team
+            .ok_or(anyhow!("Player swapped should have a team"))?;
+        let team_id2 = self
+            .get_player_or_err(player_id2)?
+            .team
+            .ok_or(anyhow!("Player swapped should have a team"))?;
 
-            if let Some(callback) = self.tick_travel(current_tick)? {
-                callbacks.push(callback);
-            }
-            if let Some(callback) = self.tick_spaceship_upgrade(current_tick)? {
-                callbacks.push(callback);
+        self.release_player_from_team(player_id1)?;
+        self.release_player_from_team(player_id2)?;
+        self.add_player_to_team(player_id1, team_id2)?;
+        self.add_player_to_team(player_id2, team_id1)?;
+        Ok(())
+    }
+
+    // Swaps all the players of a bundle trade, the two groups must belong to different teams.
+    pub fn swap_player_groups(
+        &mut self,
+        player_ids1: &[PlayerId],
+        player_ids2: &[PlayerId],
+    ) -> AppResult<()> {
+        let team_id = |world: &World, player_ids: &[PlayerId]| -> AppResult<TeamId> {
+            let team_ids = player_ids
+                .iter()
+                .map(|id| world.get_player_or_err(*id).map(|player| player.team))
+                .collect::<AppResult<Vec<_>>>()?;
+            match team_ids.first() {
+                Some(Some(team_id)) if team_ids.iter().all(|id| *id == Some(*team_id)) => {
+                    Ok(*team_id)
+                }
+                _ => Err(anyhow!("Players swapped should be in the same team")),
             }
+        };
+        let team_id1 = team_id(self, player_ids1)?;
+        let team_id2 = team_id(self, player_ids2)?;
 
-            self.last_tick_short_interval += TickInterval::SHORT;
-            // Round up to the TickInterval::SHORT to keep these ticks synchronous across network.
-            self.last_tick_short_interval -= self.last_tick_short_interval % TickInterval::SHORT;
+        for player_id in player_ids1.iter().chain(player_ids2.iter()) {
+            self.release_player_from_team(*player_id)?;
+        }
+        for player_id in player_ids1.iter() {
+            self.add_player_to_team(*player_id, team_id2)?;
+        }
+        for player_id in player_ids2.iter() {
+            self.add_player_to_team(*player_id, team_id1)?;
         }
+        Ok(())
+    }
 
-        if current_tick >= self.last_tick_medium_interval + TickInterval::MEDIUM {
-            self.tick_tiredness_recovery()?;
+    // Exchanges the resources of a bundle trade for one team only,
+    // recording the satoshi balance of the exchange.
+    pub fn settle_trade_resources(
+        &mut self,
+        team_id: TeamId,
+        given: &ResourceMap,
+        received: &ResourceMap,
+        tick: Tick,
+        description: String,
+    ) -> AppResult<()> {
+        if given.is_empty() && received.is_empty() {
+            return Ok(());
+        }
 
-            for cb in self.tick_player_leaving_team(current_tick)? {
-                callbacks.push(cb);
-            }
+        let mut team = self.get_team_or_err(team_id)?.clone();
+        team.exchange_resource_bundle(given, received)?;
+        team.version += 1;
+        self.teams.insert(team.id, team);
 
-            if self.games.len() < AUTO_GENERATE_GAMES_NUMBER {
-                self.generate_random_games()?;
-                log::info!("Generated games: {}", self.games.len());
-            }
+        let satoshi_balance =
+            received.value(&Resource::SATOSHI) as i64 - given.value(&Resource::SATOSHI) as i64;
+        if satoshi_balance != 0 {
+            self.record_transaction(
+                team_id,
+                tick,
+                FinanceCategory::Trading,
+                satoshi_balance,
+                description,
+            );
+        }
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+        Ok(())
+    }
 
-            // Once every MEDIUM interval, set dirty_network flag,
-            // so that we send our team to the network.
-            if !is_simulating {
-                self.dirty_network = true;
-            }
+    // Receives (positive amount) or pays the satoshi sweetener of a player trade.
+    // Each side of a network trade settles its own team only.
+    pub fn settle_trade_satoshis(
+        &mut self,
+        team_id: TeamId,
+        amount: i64,
+        tick: Tick,
+        description: String,
+    ) -> AppResult<()> {
+        if amount == 0 {
+            return Ok(());
+        }
 
-            self.last_tick_medium_interval += TickInterval::MEDIUM;
+        let mut team = self.get_team_or_err(team_id)?.clone();
+        if amount > 0 {
+            team.resources
+                .add(Resource::SATOSHI, amount as u32, team.storage_capacity())?;
+        } else {
+            team.resources
+                .sub(Resource::SATOSHI, amount.unsigned_abs() as u32)?;
         }
+        team.version += 1;
+        self.teams.insert(team.id, team);
+        self.record_transaction(team_id, tick, FinanceCategory::Trading, amount, description);
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+        Ok(())
+    }
 
-        if current_tick >= self.last_tick_long_interval + TickInterval::LONG {
-            log::info!(
-                "Long tick: {} >= {}",
-                current_tick,
-                self.last_tick_long_interval + TickInterval::LONG
-            );
-            self.tick_players_update();
-            self.tick_teams_reputation()?;
-            // Create free pirates only if this is the last time window to do so.
-            // This will run also during a simulation, but only once.
-            if Tick::now() < current_tick + TickInterval::LONG {
-                callbacks.push(self.tick_free_pirates(current_tick)?);
-            }
+    pub fn release_player_from_team(&mut self, player_id: PlayerId) -> AppResult<()> {
+        let mut player = self.get_player_or_err(player_id)?.clone();
+        if player.team.is_none() {
+            return Err(anyhow!("Cannot release player with no team"));
+        }
+        let mut team = self.get_team_or_err(player.team.unwrap())?.clone();
+        team.can_release_player(&player)?;
 
-            self.tick_auto_hire_free_pirates()?;
+        team.player_ids.retain(|&p| p != player.id);
+        team.player_ids = Team::best_position_assignment(
+            team.player_ids
+                .iter()
+                .map(|&id| self.get_player(id).unwrap())
+                .collect(),
+        );
+        team.version += 1;
 
-            self.last_tick_long_interval += TickInterval::LONG;
+        player.team = None;
+        match player.info.crew_role {
+            CrewRole::Captain => team.crew_roles.captain = None,
+            CrewRole::Doctor => team.crew_roles.doctor = None,
+            CrewRole::Pilot => team.crew_roles.pilot = None,
+            CrewRole::Mozzo => team.crew_roles.mozzo.retain(|&p| p != player.id),
+            CrewRole::Engineer => team.crew_roles.engineer = None,
+            CrewRole::Cook => team.crew_roles.cook = None,
+        }
+        player.info.crew_role = CrewRole::Mozzo;
+        player.morale = (player.morale + MORALE_RELEASE_MALUS).bound();
+        // Kartoffel companions stay with the crew.
+        if player.companion_bonus.take().is_some() {
+            for kartoffel in self.kartoffeln.values_mut() {
+                if kartoffel.assigned_to == Some(player.id) {
+                    kartoffel.assigned_to = None;
+                }
+            }
+        }
+        player.image.remove_jersey();
+        player.compose_image()?;
+        match team.current_location {
+            TeamLocation::OnPlanet { planet_id } => {
+                player.current_location = PlayerLocation::OnPlanet { planet_id };
+            }
+            _ => return Err(anyhow!("Cannot release player while travelling")),
         }
+        player.version += 1;
 
-        Ok(callbacks)
-    }
+        self.players.insert(player.id, player.clone());
+        self.teams.insert(team.id, team.clone());
 
-    fn cleanup_games(&mut self, current_tick: Tick) -> AppResult<()> {
-        for game in self.games.values() {
-            if !game.has_ended() {
-                continue;
-            }
+        self.dirty = true;
+        if team.id == self.own_team_id {
+            self.dirty_network = true;
+        }
+        self.dirty_ui = true;
 
-            // This check ensures that we run the cleanup logic only once.
-            if self.past_games.contains_key(&game.id) {
-                continue;
-            }
+        Ok(())
+    }
 
-            info!(
-                "Game {} vs {}: started at {}, ended at {} and is being removed at {}",
-                game.home_team_in_game.name,
-                game.away_team_in_game.name,
-                game.starting_at.formatted_as_time(),
-                game.ended_at.unwrap().formatted_as_time(),
-                current_tick.formatted_as_time()
+    fn generate_game_no_checks(
+        &mut self,
+        mut home_team_in_game: TeamInGame,
+        mut away_team_in_game: TeamInGame,
+        starting_at: Tick,
+        location: PlanetId,
+        rules: MatchRules,
+        network_seed: Option<u64>,
+    ) -> AppResult<GameId> {
+        // Generate deterministic game id from team IDs and starting time.
+        // Two games starting at u64::MAX milliseconds apart ~ 584_942_417 years
+        // would have the same ID, we assume this can't happen.
+        let mut rng_seed = ((home_team_in_game.team_id.as_u64_pair().0 as u128
+            + away_team_in_game.team_id.as_u64_pair().0 as u128)
+            % (u64::MAX as u128)) as u64;
+        rng_seed = ((rng_seed as u128 + starting_at) % (u64::MAX as u128)) as u64;
+        // The game simulation is seeded by the game id, so network games
+        // mix in the seed agreed by the peers.
+        if let Some(network_seed) = network_seed {
+            rng_seed ^= network_seed;
+        }
+        let rng = &mut ChaCha8Rng::seed_from_u64(rng_seed);
+        let game_id = GameId::from_u128(rng.gen());
+
+        let planet = self.get_planet_or_err(location)?;
+
+        // Give morale bonus to players based on planet populations
+        for (_, player) in home_team_in_game.players.iter_mut() {
+            let morale_bonus = planet
+                .populations
+                .get(&player.info.population)
+                .copied()
+                .unwrap_or_default() as f32
+                * MORALE_GAME_POPULATION_MODIFIER;
+            player.add_morale(morale_bonus);
+        }
+
+        for (_, player) in away_team_in_game.players.iter_mut() {
+            let morale_bonus = planet
+                .populations
+                .get(&player.info.population)
+                .copied()
+                .unwrap_or_default() as f32
+                * MORALE_GAME_POPULATION_MODIFIER;
+            player.add_morale(morale_bonus);
+        }
+
+        let game = Game::new(
+            game_id,
+            home_team_in_game,
+            away_team_in_game,
+            starting_at,
+            planet,
+            rules,
+        );
+        self.games.insert(game.id, game);
+
+        Ok(game_id)
+    }
+
+    pub fn generate_network_game(
+        &mut self,
+        home_team_in_game: TeamInGame,
+        away_team_in_game: TeamInGame,
+        starting_at: Tick,
+        seed: u64,
+    ) -> AppResult<GameId> {
+        let mut home_team = self.get_team_or_err(home_team_in_game.team_id)?.clone();
+        let mut away_team = self.get_team_or_err(away_team_in_game.team_id)?.clone();
+
+        // For a network game we run different checks.
+        // In particular, we do not check if the teams are already playing a game
+        // as this is done with an extra check if the current game is THIS game.
+        // This is necessary because of a race condition when a team can be received
+        // over the network before the challenge confirmation message.
+        home_team.can_challenge_team_over_network(&away_team)?;
+
+        let location = match home_team.current_location {
+            TeamLocation::OnPlanet { planet_id } => planet_id,
+            _ => {
+                panic!("Should have failed in can_challenge_team")
+            }
+        };
+
+        // Peers simulate network games on their own, so they must agree on the rules:
+        // these are always played with the default ones.
+        let game_id = self.generate_game_no_checks(
+            home_team_in_game,
+            away_team_in_game,
+            starting_at,
+            location,
+            MatchRules::default(),
+            Some(seed),
+        )?;
+
+        if let Some(previous_game_id) = home_team.current_game {
+            if game_id != previous_game_id {
+                return Err(anyhow!("Team is already playing another game"));
+            }
+        }
+
+        if let Some(previous_game_id) = away_team.current_game {
+            if game_id != previous_game_id {
+                return Err(anyhow!("Opponent is already playing another game"));
+            }
+        }
+
+        home_team.current_game = Some(game_id);
+        away_team.current_game = Some(game_id);
+        self.dirty = true;
+        self.dirty_ui = true;
+
+        if home_team.id == self.own_team_id || away_team.id == self.own_team_id {
+            // Update network that game has started.
+            self.dirty_network = true;
+        }
+
+        self.teams.insert(home_team.id, home_team);
+        self.teams.insert(away_team.id, away_team);
+
+        Ok(game_id)
+    }
+
+    pub fn generate_game(
+        &mut self,
+        home_team_in_game: TeamInGame,
+        away_team_in_game: TeamInGame,
+    ) -> AppResult<GameId> {
+        let starting_at = self.now() + GAME_START_DELAY;
+        let mut home_team = self.get_team_or_err(home_team_in_game.team_id)?.clone();
+        let mut away_team = self.get_team_or_err(away_team_in_game.team_id)?.clone();
+
+        home_team.can_challenge_team(&away_team)?;
+
+        let location = home_team
+            .is_on_planet()
+            .expect("Should have failed in can_challenge_team");
+        let game_id = self.generate_game_no_checks(
+            home_team_in_game,
+            away_team_in_game,
+            starting_at,
+            location,
+            self.generation_options.match_rules,
+            None,
+        )?;
+
+        home_team.current_game = Some(game_id);
+        away_team.current_game = Some(game_id);
+        self.dirty = true;
+        self.dirty_ui = true;
+
+        if home_team.id == self.own_team_id || away_team.id == self.own_team_id {
+            // Update network that game has started.
+            self.dirty_network = true;
+        }
+
+        self.teams.insert(home_team.id, home_team);
+        self.teams.insert(away_team.id, away_team);
+
+        Ok(game_id)
+    }
+
+    pub fn add_crowd_chatter(&mut self, chatter: CrowdChatter) -> AppResult<()> {
+        let game = self
+            .games
+            .get_mut(&chatter.game_id)
+            .ok_or(anyhow!("Game {:?} not found", chatter.game_id))?;
+        game.add_crowd_chatter(chatter)?;
+        self.dirty_ui = true;
+        Ok(())
+    }
+
+    pub fn queue_game_command(&mut self, game_id: GameId, command: GameCommand) -> AppResult<()> {
+        let own_team_id = self.own_team_id;
+        let game = self
+            .games
+            .get_mut(&game_id)
+            .ok_or(anyhow!("Game {:?} not found", game_id))?;
+        game.queue_command(own_team_id, command)?;
+        self.dirty = true;
+        self.dirty_ui = true;
+        Ok(())
+    }
+
+    pub fn add_network_game(&mut self, network_game: NetworkGame) -> AppResult<()> {
+        // Check that the game does not involve the own team (otherwise we would have generated it).
+        if network_game.home_team_in_game.team_id == self.own_team_id
+            || network_game.away_team_in_game.team_id == self.own_team_id
+        {
+            return Err(anyhow!(
+                "Cannot receive game involving own team over the network."
+            ));
+        }
+
+        if network_game.timer.has_ended() {
+            return Err(anyhow!(
+                "Cannot receive game that has ended over the network."
+            ));
+        }
+
+        if self.get_game(network_game.id).is_none() {
+            let mut game = Game::new(
+                network_game.id,
+                network_game.home_team_in_game,
+                network_game.away_team_in_game,
+                network_game.starting_at,
+                self.get_planet_or_err(network_game.location)?,
+                MatchRules::default(),
             );
-            for team in [&game.home_team_in_game, &game.away_team_in_game] {
-                //we do not apply end of game logic to peer teams
-                //TODO: once we remove local teams, we can remove this loop and only apply to own_team
-                if team.peer_id.is_some() && team.team_id != self.own_team_id {
-                    continue;
+
+            while game.timer.value < network_game.timer.value && !game.timer.has_ended() {
+                game.tick(Tick::now());
+            }
+
+            self.games.insert(game.id, game);
+            self.dirty_ui = true;
+        }
+
+        Ok(())
+    }
+
+    pub fn add_network_team(&mut self, network_team: NetworkTeam) -> AppResult<()> {
+        let NetworkTeam {
+            team,
+            players,
+            home_planet,
+        } = network_team;
+        if team.peer_id.is_none() {
+            return Err(anyhow!(
+                "Cannot receive team without peer_id over the network."
+            ));
+        }
+        if team.id == self.own_team_id {
+            return Err(anyhow!("Cannot receive own team over the network."));
+        }
+
+        // Check if we are receiving a team with which we have an open challenge.
+        // Note: there could be a race condition where we receive a team over the network right after
+        //       accepting the challenge but before the challenge has been finalized on our side.
+        //       In this case, the received team would have current_game set to some (set to the challenge game
+        //       they just started) and the challenge would fail on our hand since the challenge team must have no game.
+        let own_team = self.get_own_team()?;
+        for player in &players {
+            // Check if any player in the team is part of own team, in which case fail.
+            // This check guarantees that the own team state gets precedence over
+            // what we receive from the network.
+            // Note: finalizing a trade in handle_trade_topic assumes that this check is in place
+            //       to ensure that there is no race condition between receiving the trade
+            //       syn_ack state and the network team from the trade proposer.
+            if own_team.player_ids.contains(&player.id) {
+                return Err(anyhow!(
+                    "Cannot receive over the network a player which is part of own team."
+                ));
+            }
+        }
+
+        let db_team = self.get_team(team.id).cloned();
+
+        // Remove team from previous planet
+        if let Some(previous_version_team) = db_team.as_ref() {
+            match previous_version_team.current_location {
+                TeamLocation::OnPlanet { planet_id } => {
+                    let mut planet = self.get_planet_or_err(planet_id)?.clone();
+                    planet.team_ids.retain(|&id| id != team.id);
+                    self.planets.insert(planet.id, planet);
                 }
-                for game_player in team.players.values() {
-                    // Set tiredness and morale to the value in game.
-                    // We do not clone the game_player as other changes may have occured to the player
-                    // during the game (such as skill update).
-                    let mut player = self.get_player_or_err(game_player.id)?.clone();
-                    player.tiredness = game_player.tiredness;
-                    player.morale = game_player.morale;
+                _ => {}
+            }
+
+            // Remove players from db_team that are not in the new team to clean up fired players
+            for player_id in &previous_version_team.player_ids {
+                self.players.remove(&player_id);
+            }
+        }
+
+        // When adding a network planet, we do not update the parent planet satellites on purpose,
+        // to avoid complications when cleaning up to store the world.
+        // This means that the network satellite will not appear in the galaxy.
+        if let Some(planet) = home_planet {
+            if planet.peer_id.is_none() {
+                return Err(anyhow!(
+                    "Cannot receive planet without peer_id over the network."
+                ));
+            }
+            self.planets.insert(planet.id, planet);
+        }
+
+        // Add team to new planet
+        match team.current_location {
+            TeamLocation::OnPlanet { planet_id } => {
+                let mut planet = self.get_planet_or_err(planet_id)?.clone();
+                planet.team_ids.push(team.id);
+                self.planets.insert(planet.id, planet);
+            }
+            _ => {}
+        }
+
+        for player in players {
+            if player.peer_id.is_none() || player.peer_id.unwrap() != team.peer_id.unwrap() {
+                return Err(anyhow!(
+                    "Cannot receive player with wrong peer_id over the network."
+                ));
+            }
+            self.players.insert(player.id, player);
+        }
+
+        self.teams.insert(team.id, team);
+        self.dirty_ui = true;
+
+        Ok(())
+    }
+
+    pub fn get_team(&self, id: TeamId) -> Option<&Team> {
+        self.teams.get(&id)
+    }
+
+    pub fn get_team_or_err(&self, id: TeamId) -> AppResult<&Team> {
+        self.get_team(id)
+            .ok_or(anyhow!("Team {:?} not found", id).into())
+    }
+
+    pub fn get_own_team(&self) -> AppResult<&Team> {
+        self.get_team_or_err(self.own_team_id)
+    }
+
+    pub fn get_own_team_mut(&mut self) -> AppResult<&mut Team> {
+        self.teams
+            .get_mut(&self.own_team_id)
+            .ok_or(anyhow!("Team {:?} not found", self.own_team_id))
+    }
+
+    pub fn get_planet(&self, id: PlanetId) -> Option<&Planet> {
+        self.planets.get(&id)
+    }
+
+    pub fn get_planet_or_err(&self, id: PlanetId) -> AppResult<&Planet> {
+        self.get_planet(id)
+            .ok_or(anyhow!("Planet {:?} not found", id))
+    }
+
+    pub fn get_player(&self, id: PlayerId) -> Option<&Player> {
+        self.players.get(&id)
+    }
+
+    pub fn get_kartoffel(&self, id: KartoffelId) -> Option<&Kartoffel> {
+        self.kartoffeln.get(&id)
+    }
+
+    pub fn get_kartoffel_or_err(&self, id: KartoffelId) -> AppResult<&Kartoffel> {
+        self.get_kartoffel(id)
+            .ok_or(anyhow!("Kartoffel {:?} not found", id))
+    }
+
+    pub fn get_player_or_err(&self, id: PlayerId) -> AppResult<&Player> {
+        self.get_player(id)
+            .ok_or(anyhow!("Player {:?} not found", id))
+    }
+
+    pub fn get_players_by_team(&self, team: &Team) -> AppResult<Vec<Player>> {
+        Ok(team
+            .player_ids
+            .iter()
+            .map(|&id| {
+                self.get_player(id)
+                    .ok_or(anyhow!("Player {:?} not found", id))
+            })
+            .collect::<Result<Vec<&Player>, _>>()?
+            .iter()
+            .map(|&p| p.clone())
+            .collect::<Vec<Player>>())
+    }
+
+    pub fn get_game(&self, id: GameId) -> Option<&Game> {
+        self.games.get(&id)
+    }
+
+    pub fn get_game_or_err(&self, id: GameId) -> AppResult<&Game> {
+        self.get_game(id).ok_or(anyhow!("Game {:?} not found", id))
+    }
+
+    pub fn team_rating(&self, team_id: TeamId) -> AppResult<f32> {
+        let team = self.get_team_or_err(team_id)?;
+        if team.player_ids.len() == 0 {
+            return Ok(0.0);
+        }
+        Ok(team
+            .player_ids
+            .iter()
+            .filter(|&&id| self.get_player(id).is_some())
+            .map(|&id| self.get_player(id).unwrap().rating())
+            .sum::<u8>() as f32
+            / team.player_ids.len().max(MIN_PLAYERS_PER_GAME) as f32)
+    }
+
+    pub fn is_simulating(&self) -> bool {
+        if !self.has_own_team() {
+            return false;
+        }
+
+        if self.game_over.is_some() {
+            return false;
+        }
+
+        // This works if we assume that we can't lag behind more than a SHORT interval (1 second).
+        // DEBUG_TIME_MULTIPLIER than cannot be too large or due to finite FPS this condition
+        // would always return true.
+        self.now() > self.last_tick_short_interval + TickInterval::SHORT
+    }
+
+    // Skips the time away exceeding MAX_CATCH_UP_TIME, as if the world had been
+    // saved later. Returns the skipped time.
+    pub fn cap_catch_up_time(&mut self) -> Tick {
+        let elapsed = self.now().saturating_sub(self.last_tick_short_interval);
+        if elapsed <= MAX_CATCH_UP_TIME {
+            return 0;
+        }
+        let mut skipped = elapsed - MAX_CATCH_UP_TIME;
+        // Keep the short ticks aligned to stay in sync with the network.
+        skipped -= skipped % TickInterval::SHORT;
+
+        self.last_tick_min_interval += skipped;
+        self.last_tick_short_interval += skipped;
+        self.last_tick_medium_interval += skipped;
+        self.last_tick_long_interval += skipped;
+        skipped
+    }
+
+    // Current tick of the world clock, which drifts from the real one when pausing or fast-forwarding.
+    pub fn now(&self) -> Tick {
+        (Tick::now() as i128 + self.time_offset as i128).max(0) as Tick
+    }
+
+    pub fn can_set_time_speed(
+        &self,
+        speed: TimeSpeed,
+        is_network_active: bool,
+        is_network_enabled: bool,
+    ) -> AppResult<()> {
+        if speed == TimeSpeed::Normal {
+            return Ok(());
+        }
+
+        if is_network_active {
+            return Err(anyhow!("Time controls are disabled during network play"));
+        }
+
+        // A world clock ahead of the real one cannot go back online,
+        // see can_go_online.
+        if speed.multiplier() > 1 && is_network_enabled {
+            return Err(anyhow!(
+                "Fast-forward is only available with the network disabled"
+            ));
+        }
+
+        if self.space_adventure.is_some() {
+            return Err(anyhow!(
+                "Time controls are disabled during a space adventure"
+            ));
+        }
+
+        Ok(())
+    }
+
+    // Timestamps stored by a world clock ahead of the real one would still be in the
+    // future after resyncing, so the world stays offline until the real clock catches up.
+    pub fn can_go_online(&self) -> AppResult<()> {
+        if self.time_offset > 0 {
+            return Err(anyhow!(
+                "The world clock is {} ahead of the real one, pause until it catches up",
+                (self.time_offset as Tick).formatted()
+            ));
+        }
+        Ok(())
+    }
+
+    // Drops the drift of the world clock so that it agrees with the one of the peers.
+    // The tick markers are moved along, so the world neither stalls nor jumps ahead.
+    // Only a clock behind the real one can be dropped, see can_go_online.
+    pub fn resync_clock(&mut self) {
+        if self.time_offset == 0 {
+            return;
+        }
+        let offset = self.time_offset as i128;
+        let shift = |tick: Tick| (tick as i128 - offset).max(0) as Tick;
+
+        self.last_tick_min_interval = shift(self.last_tick_min_interval);
+        self.last_tick_short_interval = shift(self.last_tick_short_interval);
+        self.last_tick_medium_interval = shift(self.last_tick_medium_interval);
+        self.last_tick_long_interval = shift(self.last_tick_long_interval);
+        // Keep the short ticks aligned to stay in sync with the network.
+        self.last_tick_short_interval -= self.last_tick_short_interval % TickInterval::SHORT;
+
+        self.time_offset = 0;
+        self.time_speed = TimeSpeed::Normal;
+        self.dirty = true;
+    }
+
+    // Advances the world clock by the given real time, scaled by the current speed.
+    pub fn advance_clock(&mut self, real_elapsed: Tick) {
+        let real_elapsed = real_elapsed.min(i64::MAX as Tick) as i64;
+        self.time_offset += real_elapsed * (self.time_speed.multiplier() - 1);
+    }
+
+    fn resources_found_after_exploration(
+        &self,
+        team: &Team,
+        planet: &Planet,
+        duration: u128,
+    ) -> AppResult<ResourceMap> {
+        let mut rng = ChaCha8Rng::from_entropy();
+        let mut resources = HashMap::new();
+
+        let bonus = TeamBonus::Exploration.current_team_bonus(&self, team.id)?;
+
+        for (&resource, &amount) in planet.resources.iter() {
+            let mut found_amount = 0;
+            // The exploration bonus makes the random range larger, which is positive in expectation
+            // since we clamp at 0.
+            let base = ((2.0_f32).powf(amount as f32 / 2.0) * bonus) as i32;
+            for _ in 0..(duration / QUICK_EXPLORATION_TIME) {
+                found_amount += rng.gen_range(-base / 2..base).max(0) as u32;
+            }
+            resources.insert(resource, found_amount);
+        }
+
+        Ok(resources)
+    }
+
+    fn free_pirates_found_after_exploration(
+        &mut self,
+        planet: &Planet,
+        duration: u128,
+        scout_bonus: f32,
+    ) -> AppResult<Vec<PlayerId>> {
+        let rng = &mut ChaCha8Rng::from_entropy();
+        let mut free_pirates = vec![];
+
+        let duration_bonus = (duration as f32 / (1 * HOURS) as f32).powf(1.3);
+        let population_bonus = planet.total_population() as f32;
+        // A scout knows where free pirates hang out.
+        let discovery_bonus = (population_bonus + duration_bonus) * (1.0 + scout_bonus);
+
+        let amount = rng
+            .gen_range((-32 + discovery_bonus as i32).min(0)..3)
+            .max(0);
+
+        if amount > 0 {
+            for _ in 0..amount {
+                let base_level = rng.gen_range(0.0..7.0);
+                let player_id = self.generate_random_player(rng, None, planet.id, base_level)?;
+
+                free_pirates.push(player_id);
+            }
+        }
+
+        Ok(free_pirates)
+    }
+
+    fn boss_rumor_found_after_exploration(&self, team: &Team, duration: u128) -> bool {
+        if team.has_boss_encounter() {
+            return false;
+        }
+        let rng = &mut ChaCha8Rng::from_entropy();
+        let duration_bonus = duration as f64 / (1 * HOURS) as f64;
+        rng.gen_bool((BOSS_RUMOR_DISCOVERY_PROBABILITY * duration_bonus).min(1.0))
+    }
+
+    // A new derelict replaces any one the crew never got around to deciding about.
+    fn derelict_found_after_exploration(&self, duration: u128) -> bool {
+        let rng = &mut ChaCha8Rng::from_entropy();
+        let duration_bonus = duration as f64 / (1 * HOURS) as f64;
+        rng.gen_bool((DERELICT_DISCOVERY_PROBABILITY * duration_bonus).min(1.0))
+    }
+
+    fn kartoffel_found_after_exploration(&self, team: &Team, duration: u128) -> bool {
+        if team.kartoffel_ids.len() >= MAX_KARTOFFELN_PER_TEAM {
+            return false;
+        }
+        let rng = &mut ChaCha8Rng::from_entropy();
+        let duration_bonus = duration as f64 / (1 * HOURS) as f64;
+        rng.gen_bool((KARTOFFEL_DISCOVERY_PROBABILITY * duration_bonus).min(1.0))
+    }
+
+    // Stores the ongoing space adventure, so that it is kept in the save file.
+    pub fn store_space_adventure(&mut self) -> AppResult<()> {
+        if let Some(space) = self.space_adventure.as_ref() {
+            self.space_adventure_snapshot = Some(space.snapshot()?);
+            self.dirty = true;
+        }
+        Ok(())
+    }
+
+    pub fn resume_space_adventure(&mut self) -> AppResult<()> {
+        let snapshot = self
+            .space_adventure_snapshot
+            .clone()
+            .ok_or(anyhow!("No space adventure to resume"))?;
+        let own_team = self.get_own_team()?;
+
+        let mut space = SpaceAdventure::new()?
+            .with_spaceship(
+                &own_team.spaceship,
+                snapshot.resources.clone(),
+                snapshot.fuel,
+                &own_team.space_adventure_progression,
+            )?
+            .with_hull_care(TeamBonus::HullCare.current_team_bonus(self, own_team.id)?)?;
+        if let Some(pilot_id) = snapshot.pilot_id {
+            if let Ok(pilot) = self.get_player_or_err(pilot_id) {
+                space = space.with_pilot(pilot)?;
+            }
+        }
+
+        self.space_adventure = Some(space.with_snapshot(snapshot)?);
+        Ok(())
+    }
+
+    // Rewards for destroying the Kartoffel mothership: a legendary pirate joins the crew
+    // and a free module is installed on the spaceship. Returns the rewards description.
+    pub fn reward_boss_defeat(&mut self, tick: Tick) -> AppResult<String> {
+        let mut team = self.get_own_team()?.clone();
+        let planet_id = team
+            .is_on_planet()
+            .ok_or(anyhow!("Team should be on a planet"))?;
+        let rng = &mut ChaCha8Rng::from_entropy();
+        let mut rewards = vec![];
+
+        team.boss_rumors = 0;
+
+        let free_modules = SpaceshipModule::iter()
+            .filter(|&module| !team.spaceship.has_module(module))
+            .collect_vec();
+        if let Some(&module) = free_modules.choose(rng) {
+            if team.spaceship.modules.len() < team.spaceship.module_slots() {
+                team.spaceship.modules.push(module);
+                rewards.push(format!("{} installed on the spaceship", module));
+            } else {
+                // No free slot, the module is sold instead.
+                let value = module
+                    .cost()
+                    .iter()
+                    .find(|(resource, _)| *resource == Resource::SATOSHI)
+                    .map(|(_, amount)| *amount)
+                    .unwrap_or_default();
+                team.resources
+                    .add(Resource::SATOSHI, value, team.storage_capacity())?;
+                self.record_transaction(
+                    team.id,
+                    tick,
+                    FinanceCategory::Spaceship,
+                    value as i64,
+                    format!("Sold salvaged {}", module),
+                );
+                rewards.push(format!(
+                    "{} salvaged and sold for {} satoshi",
+                    module, value
+                ));
+            }
+        }
+        let team_id = team.id;
+        self.teams.insert(team.id, team);
+
+        let player_id =
+            self.generate_random_player(rng, None, planet_id, LEGENDARY_PIRATE_BASE_LEVEL)?;
+        let player_name = self.get_player_or_err(player_id)?.info.shortened_name();
+        match self.add_player_to_team(player_id, team_id) {
+            Ok(_) => rewards.push(format!(
+                "The legendary pirate {} joined the crew",
+                player_name
+            )),
+            Err(_) => rewards.push(format!(
+                "The legendary pirate {} is waiting to be hired on {}",
+                player_name,
+                self.get_planet_or_err(planet_id)?.name
+            )),
+        }
+
+        self.log_crew_event(
+            tick,
+            format!("Kartoffel mothership destroyed! {}.", rewards.join(". ")),
+        );
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+
+        Ok(format!(
+            "Kartoffel mothership destroyed!\n{}",
+            rewards.join("\n")
+        ))
+    }
+
+    pub fn handle_tick_events(&mut self, current_tick: Tick) -> AppResult<Vec<UiCallback>> {
+        let mut callbacks: Vec<UiCallback> = vec![];
+        // The world stops once the own team is game over.
+        if self.game_over.is_some() {
+            return Ok(callbacks);
+        }
+        let is_simulating = self.is_simulating();
+
+        if let Some(space) = self.space_adventure.as_mut() {
+            let deltatime = (current_tick - self.last_tick_min_interval) as f32 / SECONDS as f32;
+
+            callbacks.append(&mut space.update(deltatime)?);
+        }
+
+        self.last_tick_min_interval = current_tick;
+
+        if current_tick >= self.last_tick_short_interval + TickInterval::SHORT {
+            self.tick_games(current_tick)?;
+            callbacks.append(&mut self.cleanup_games(current_tick)?);
+
+            if let Some(callback) = self.tick_travel(current_tick)? {
+                callbacks.push(callback);
+            }
+            if let Some(callback) = self.tick_spaceship_upgrade(current_tick)? {
+                callbacks.push(callback);
+            }
+            if let Some(callback) = self.tick_action_queue(current_tick)? {
+                callbacks.push(callback);
+            }
+            if let Some(callback) = self.tick_campaign(current_tick)? {
+                callbacks.push(callback);
+            }
+            if let Some(callback) = self.tick_tutorial(current_tick)? {
+                callbacks.push(callback);
+            }
+
+            self.last_tick_short_interval += TickInterval::SHORT;
+            // Round up to the TickInterval::SHORT to keep these ticks synchronous across network.
+            self.last_tick_short_interval -= self.last_tick_short_interval % TickInterval::SHORT;
+        }
+
+        if current_tick >= self.last_tick_medium_interval + TickInterval::MEDIUM {
+            self.tick_tiredness_recovery(current_tick)?;
+            self.tick_markets(current_tick)?;
+
+            for cb in self.tick_player_leaving_team(current_tick)? {
+                callbacks.push(cb);
+            }
+
+            if let Some(callback) = self.tick_crew_mutiny(current_tick)? {
+                callbacks.push(callback);
+            }
+
+            if let Some(callback) = self.tick_contracts(current_tick)? {
+                callbacks.push(callback);
+            }
+
+            if let Some(callback) = self.tick_loans(current_tick)? {
+                callbacks.push(callback);
+            }
+            if let Some(callback) = self.tick_game_over(current_tick)? {
+                callbacks.push(callback);
+            }
+
+            if let Some(callback) = self.tick_tournaments(current_tick)? {
+                callbacks.push(callback);
+            }
+
+            if let Some(callback) = self.tick_world_events(current_tick)? {
+                callbacks.push(callback);
+            }
+
+            if !is_simulating {
+                if let Some(callback) = self.tick_npc_challenges(current_tick)? {
+                    callbacks.push(callback);
+                }
+            }
+
+            if self.games.len() < AUTO_GENERATE_GAMES_NUMBER {
+                self.generate_random_games()?;
+                log::info!("Generated games: {}", self.games.len());
+            }
+
+            // Once every MEDIUM interval, set dirty_network flag,
+            // so that we send our team to the network.
+            if !is_simulating {
+                self.dirty_network = true;
+            }
+
+            self.last_tick_medium_interval += TickInterval::MEDIUM;
+        }
+
+        if current_tick >= self.last_tick_long_interval + TickInterval::LONG {
+            log::info!(
+                "Long tick: {} >= {}",
+                current_tick,
+                self.last_tick_long_interval + TickInterval::LONG
+            );
+            self.tick_training_schedules(current_tick)?;
+            if let Some(callback) = self.tick_academy(current_tick)? {
+                callbacks.push(callback);
+            }
+            if let Some(callback) = self.tick_staff_salaries(current_tick)? {
+                callbacks.push(callback);
+            }
+            self.tick_players_update();
+            self.tick_teams_reputation()?;
+            self.tick_history_records(current_tick);
+            self.tick_asteroid_production(current_tick)?;
+            self.tick_kartoffeln()?;
+            if let Some(callback) = self.tick_trait_progression(current_tick)? {
+                callbacks.push(callback);
+            }
+            // Create free pirates only if this is the last time window to do so.
+            // This will run also during a simulation, but only once.
+            if self.now() < current_tick + TickInterval::LONG {
+                callbacks.push(self.tick_free_pirates(current_tick)?);
+            }
+
+            self.tick_ai_teams_management()?;
+            self.tick_auto_hire_free_pirates()?;
+
+            self.last_tick_long_interval += TickInterval::LONG;
+        }
+
+        Ok(callbacks)
+    }
+
+    fn cleanup_games(&mut self, current_tick: Tick) -> AppResult<Vec<UiCallback>> {
+        let rng = &mut ChaCha8Rng::from_entropy();
+        let mut game_incomes = vec![];
+        let mut game_results = vec![];
+        let mut callbacks = vec![];
+        let mut epithet_messages = vec![];
+        let mut ended_games = vec![];
+        for game in self.games.values() {
+            if !game.has_ended() {
+                continue;
+            }
+
+            // This check ensures that we run the cleanup logic only once.
+            if self.past_games.contains_key(&game.id) {
+                continue;
+            }
+
+            info!(
+                "Game {} vs {}: started at {}, ended at {} and is being removed at {}",
+                game.home_team_in_game.name,
+                game.away_team_in_game.name,
+                game.starting_at.formatted_as_time(),
+                game.ended_at.unwrap().formatted_as_time(),
+                current_tick.formatted_as_time()
+            );
+            ended_games.push(HookEvent::GameEnded {
+                game_id: game.id,
+                home_team_id: game.home_team_in_game.team_id,
+                away_team_id: game.away_team_in_game.team_id,
+                winner: game.winner,
+            });
+            let mut skill_gains = vec![];
+            for team in [&game.home_team_in_game, &game.away_team_in_game] {
+                //we do not apply end of game logic to peer teams
+                //TODO: once we remove local teams, we can remove this loop and only apply to own_team
+                if team.peer_id.is_some() && team.team_id != self.own_team_id {
+                    continue;
+                }
+                for game_player in team.players.values() {
+                    // Set tiredness and morale to the value in game.
+                    // We do not clone the game_player as other changes may have occured to the player
+                    // during the game (such as skill update).
+                    let mut player = self.get_player_or_err(game_player.id)?.clone();
+                    player.tiredness = game_player.tiredness;
+                    player.morale = game_player.morale;
+
+                    player.version += 1;
+                    player.add_morale(MORALE_INCREASE_PER_GAME);
+
+                    let stats = team
+                        .stats
+                        .get(&player.id)
+                        .ok_or(anyhow!("Player {:?} not found in team stats", player.id))?;
+
+                    if let Some(kind) = stats.injury {
+                        player.injury = Some(Injury::new(kind));
+                    }
+                    player.trait_progress.record_game(stats);
+
+                    if player.info.epithet.is_none() {
+                        if let Some(feat) = epithet::notable_feat(stats) {
+                            let planet_name =
+                                &self.get_planet_or_err(player.info.home_planet_id)?.name;
+                            let epithet = epithet::generate_epithet(rng, feat, planet_name);
+                            if team.team_id == self.own_team_id {
+                                epithet_messages.push(format!(
+                                    "After {}, {} is now known as {}.",
+                                    feat.description(),
+                                    player.info.shortened_name(),
+                                    epithet
+                                ));
+                            }
+                            player.info.epithet = Some(epithet);
+                        }
+                    }
+
+                    player.reputation = (player.reputation
+                        + REPUTATION_PER_EXPERIENCE
+                            * stats.seconds_played as f32
+                            * TeamBonus::Reputation.current_team_bonus(
+                                self,
+                                player.team.expect("Player should have a team"),
+                            )?)
+                    .bound();
+
+                    let training_bonus =
+                        TeamBonus::Training.current_team_bonus(&self, team.team_id)?;
+                    let training_focus = team.training_focus;
+                    let gains = player.update_skills_training(
+                        stats.experience_at_position,
+                        stats.action_experience(),
+                        training_bonus,
+                        training_focus,
+                    );
+                    if team.team_id == self.own_team_id {
+                        if let Some(summary) = Self::skill_gains_summary(&player, gains) {
+                            skill_gains.push(summary);
+                        }
+                    }
+                    self.players.insert(player.id, player);
+                }
+            }
+
+            if !skill_gains.is_empty() {
+                let opponent_name = if game.home_team_in_game.team_id == self.own_team_id {
+                    &game.away_team_in_game.name
+                } else {
+                    &game.home_team_in_game.name
+                };
+                callbacks.push(UiCallback::PushUiPopup {
+                    popup_message: PopupMessage::Ok {
+                        message: format!(
+                            "Training from the game vs {}\n\n{}",
+                            opponent_name,
+                            skill_gains.join("\n")
+                        ),
+                        is_skippable: true,
+                        tick: current_tick,
+                    },
+                });
+            }
+
+            // Past games of the own team are persisted in the store.
+            if game.home_team_in_game.team_id == self.own_team_id
+                || game.away_team_in_game.team_id == self.own_team_id
+            {
+                let game_summary = GameSummary::from_game(&game);
+                self.past_games.insert(game_summary.id, game_summary);
+                let (home_score, away_score) = game.get_score();
+                game_results.push(format!(
+                    "{} {} - {} {}",
+                    game.home_team_in_game.name,
+                    home_score,
+                    away_score,
+                    game.away_team_in_game.name
+                ));
+                save_game(&game)?;
+                // Update network that game has ended.
+                self.dirty_network = true;
+            }
+
+            // Teams get money depending on game attendance.
+            // Home team gets a bonus for playing at home.
+            // If a team is knocked out, money goes to the other team.
+            // If both are knocked out, they get no money.
+            let mut home_team_income = 100 + game.attendance * INCOME_PER_ATTENDEE_HOME;
+            let mut away_team_income = 100 + game.attendance * INCOME_PER_ATTENDEE_AWAY;
+            let home_knocked_out = game.is_team_knocked_out(Possession::Home);
+            let away_knocked_out = game.is_team_knocked_out(Possession::Away);
+
+            match (home_knocked_out, away_knocked_out) {
+                (true, false) => {
+                    away_team_income += home_team_income;
+                    home_team_income = 0;
+                }
+                (false, true) => {
+                    home_team_income += away_team_income;
+                    away_team_income = 0;
+                }
+                (true, true) => {
+                    home_team_income = 0;
+                    away_team_income = 0;
+                }
+                _ => {}
+            }
+
+            // Winner team gets reputation bonus
+            let (home_team_reputation, away_team_reputation) = match game.winner {
+                Some(winner) => {
+                    if winner == game.home_team_in_game.team_id {
+                        (REPUTATION_BONUS_WINNER, REPUTATION_BONUS_LOSER)
+                    } else {
+                        (REPUTATION_BONUS_LOSER, REPUTATION_BONUS_WINNER)
+                    }
+                }
+                None => (REPUTATION_BONUS_DRAW, REPUTATION_BONUS_DRAW),
+            };
+
+            // Winner team gets 1 rum per player, Loser team gets 1 rum in total
+            let (home_team_rum, away_team_rum) = match game.winner {
+                Some(winner) => {
+                    if winner == game.home_team_in_game.team_id {
+                        (game.home_team_in_game.players.len() as u32, 1)
+                    } else {
+                        (1, game.away_team_in_game.players.len() as u32)
+                    }
+                }
+                None => (1, 1),
+            };
+
+            // Keep track of the teams beaten by the own team for the campaign milestones.
+            if let (Some(winner), Some(campaign)) = (game.winner, self.campaign.as_mut()) {
+                if winner == self.own_team_id {
+                    let loser = if winner == game.home_team_in_game.team_id {
+                        game.away_team_in_game.team_id
+                    } else {
+                        game.home_team_in_game.team_id
+                    };
+                    if !campaign.defeated_team_ids.contains(&loser) {
+                        campaign.defeated_team_ids.push(loser);
+                    }
+                }
+            }
+
+            // Network games update the ladder rating of both teams.
+            let elo_ratings = if game.home_team_in_game.peer_id.is_some()
+                && game.away_team_in_game.peer_id.is_some()
+            {
+                let rating = |team_id| {
+                    self.get_team(team_id)
+                        .map(|team| team.elo_rating())
+                        .unwrap_or(INITIAL_ELO_RATING)
+                };
+                let outcome = match game.winner {
+                    Some(winner) if winner == game.home_team_in_game.team_id => GameOutcome::Win,
+                    Some(_) => GameOutcome::Loss,
+                    None => GameOutcome::Draw,
+                };
+                Some(updated_ratings(
+                    rating(game.home_team_in_game.team_id),
+                    rating(game.away_team_in_game.team_id),
+                    outcome,
+                ))
+            } else {
+                None
+            };
+
+            // Update to team game records.
+            let (home_team_record, away_team_record) = match game.winner {
+                Some(winner) => {
+                    if winner == game.home_team_in_game.team_id {
+                        ([1, 0, 0], [0, 1, 0])
+                    } else {
+                        ([0, 1, 0], [1, 0, 0])
+                    }
+                }
+                None => ([0, 0, 1], [0, 0, 1]),
+            };
+
+            // Set playing teams current game to None and assign income, reputation, and rum.
+            if let Ok(res) = self.get_team_or_err(game.home_team_in_game.team_id) {
+                let mut home_team = res.clone();
+                home_team.current_game = None;
+
+                home_team.resources.saturating_add(
+                    Resource::SATOSHI,
+                    home_team_income,
+                    home_team.storage_capacity(),
+                );
+                home_team.reputation = (home_team.reputation + home_team_reputation).bound();
+                if game.winner == Some(home_team.id) {
+                    home_team.add_planet_standing(game.location, PLANET_STANDING_WIN_BONUS);
+                }
+                home_team.resources.saturating_add(
+                    Resource::RUM,
+                    home_team_rum,
+                    home_team.storage_capacity(),
+                );
+                if game.home_team_in_game.peer_id.is_some()
+                    && game.away_team_in_game.peer_id.is_some()
+                {
+                    // If it's a network game, update the network game record,
+                    home_team.network_game_record = [
+                        home_team.network_game_record[0] + home_team_record[0],
+                        home_team.network_game_record[1] + home_team_record[1],
+                        home_team.network_game_record[2] + home_team_record[2],
+                    ];
+                    if let Some((home_elo, _)) = elo_ratings {
+                        home_team.elo = Some(home_elo);
+                    }
+                } else {
+                    // else update the game record.
+                    home_team.game_record = [
+                        home_team.game_record[0] + home_team_record[0],
+                        home_team.game_record[1] + home_team_record[1],
+                        home_team.game_record[2] + home_team_record[2],
+                    ];
+                }
+                self.teams.insert(home_team.id, home_team.clone());
+                game_incomes.push((
+                    home_team.id,
+                    home_team_income,
+                    format!("Home game vs {}", game.away_team_in_game.name),
+                ));
+            }
+
+            if let Ok(res) = self.get_team_or_err(game.away_team_in_game.team_id) {
+                let mut away_team = res.clone();
+                away_team.current_game = None;
+                away_team.resources.saturating_add(
+                    Resource::SATOSHI,
+                    away_team_income,
+                    away_team.storage_capacity(),
+                );
+                away_team.reputation = (away_team.reputation + away_team_reputation).bound();
+                if game.winner == Some(away_team.id) {
+                    away_team.add_planet_standing(game.location, PLANET_STANDING_WIN_BONUS);
+                }
+                away_team.resources.saturating_add(
+                    Resource::RUM,
+                    away_team_rum,
+                    away_team.storage_capacity(),
+                );
+
+                if game.home_team_in_game.peer_id.is_some()
+                    && game.away_team_in_game.peer_id.is_some()
+                {
+                    // If it's a network game, update the network game record,
+                    away_team.network_game_record = [
+                        away_team.network_game_record[0] + away_team_record[0],
+                        away_team.network_game_record[1] + away_team_record[1],
+                        away_team.network_game_record[2] + away_team_record[2],
+                    ];
+                    if let Some((_, away_elo)) = elo_ratings {
+                        away_team.elo = Some(away_elo);
+                    }
+                } else {
+                    // else update the game record.
+                    away_team.game_record = [
+                        away_team.game_record[0] + away_team_record[0],
+                        away_team.game_record[1] + away_team_record[1],
+                        away_team.game_record[2] + away_team_record[2],
+                    ];
+                }
+                self.teams.insert(away_team.id, away_team.clone());
+                game_incomes.push((
+                    away_team.id,
+                    away_team_income,
+                    format!("Away game vs {}", game.home_team_in_game.name),
+                ));
+            }
+
+            // Tournament games cannot end in a draw, ties are decided by a coin toss.
+            let winner = game.winner.unwrap_or_else(|| {
+                if rng.gen_bool(0.5) {
+                    game.home_team_in_game.team_id
+                } else {
+                    game.away_team_in_game.team_id
+                }
+            });
+            for tournament in self.tournaments.iter_mut() {
+                tournament.record_result(game.id, winner);
+            }
+            self.history.record_game(game);
+
+            self.dirty = true;
+            self.dirty_ui = true;
+        }
+
+        for (team_id, income, description) in game_incomes {
+            self.record_transaction(
+                team_id,
+                current_tick,
+                FinanceCategory::GameIncome,
+                income as i64,
+                description,
+            );
+        }
+        for result in game_results {
+            self.notify(current_tick, NotificationCategory::Games, result);
+        }
+        for message in epithet_messages {
+            self.log_crew_event(current_tick, message);
+        }
+        for event in ended_games {
+            self.emit_hook_event(event);
+        }
+
+        // We wait an extra GAME_CLEANUP_TIME before removing the game from the games
+        // collection so that we can leave it up for the UI to visualize.
+        self.games.retain(|_, game| {
+            !game.has_ended() || current_tick <= game.ended_at.unwrap() + GAME_CLEANUP_TIME
+        });
+
+        Ok(callbacks)
+    }
+
+    // Lists the skills that improved the most in the game.
+    fn skill_gains_summary(player: &Player, gains: [f32; 20]) -> Option<String> {
+        let best = gains
+            .iter()
+            .enumerate()
+            .filter(|(_, &gain)| gain > 0.0)
+            .sorted_by(|(_, a), (_, b)| b.total_cmp(a))
+            .take(3)
+            .map(|(idx, gain)| format!("{} +{:.3}", SKILL_NAMES[idx], gain))
+            .collect_vec();
+        if best.is_empty() {
+            return None;
+        }
+        Some(format!(
+            "{}: {}",
+            player.info.shortened_name(),
+            best.join(", ")
+        ))
+    }
+
+    fn tick_games(&mut self, current_tick: Tick) -> AppResult<()> {
+        // NOTE!!: we do not set the world to dirty so we don't save on every tick.
+        //         the idea is that the game is completely determined at the beginning,
+        //         so we can similuate it through.
+        for game in self.games.values_mut() {
+            if game.has_started(current_tick) && !game.has_ended() {
+                game.tick(current_tick);
+            }
+        }
+        Ok(())
+    }
+
+    fn team_reputation_bonus_per_distance(distance: u128) -> f32 {
+        ((distance as f32 + 1.0).ln()).powf(4.0) * TEAM_REPUTATION_BONUS_MODIFIER
+    }
+
+    pub fn tick_travel(&mut self, current_tick: Tick) -> AppResult<Option<UiCallback>> {
+        let own_team = self.get_own_team()?;
+        match own_team.current_location {
+            TeamLocation::Travelling {
+                from: _,
+                to,
+                started,
+                duration,
+                distance,
+            } => {
+                // Travel is halted until the crew deals with the hazard.
+                if own_team.travel_hazard.is_some() {
+                    return Ok(None);
+                }
+
+                if current_tick <= started + duration
+                    && duration >= TRAVEL_HAZARD_MIN_TRAVEL_TIME
+                    && !self.is_simulating()
+                {
+                    let rng = &mut ChaCha8Rng::from_entropy();
+                    if rng.gen_bool(
+                        TRAVEL_HAZARD_PROBABILITY_PER_HOUR * TickInterval::SHORT as f64
+                            / HOURS as f64,
+                    ) {
+                        let kind = TravelHazardKind::iter()
+                            .choose(rng)
+                            .expect("There should be a hazard kind");
+                        let mut team = own_team.clone();
+                        team.travel_hazard = Some(TravelHazard {
+                            kind,
+                            started: current_tick,
+                        });
+                        self.teams.insert(team.id, team);
+                        self.dirty = true;
+                        self.dirty_ui = true;
+                        return Ok(Some(UiCallback::PushUiPopup {
+                            popup_message: PopupMessage::TravelHazard {
+                                kind,
+                                tick: current_tick,
+                            },
+                        }));
+                    }
+                }
+
+                if current_tick > started + duration {
+                    let mut team = own_team.clone();
+                    let team_name = team.name.clone();
+                    team.current_location = TeamLocation::OnPlanet { planet_id: to };
+                    self.emit_hook_event(HookEvent::PlanetVisited {
+                        team_id: team.id,
+                        planet_id: to,
+                    });
+                    let mut planet = self.get_planet_or_err(to)?.clone();
+                    let planet_name = planet.name.clone();
+                    let planet_filename = planet.filename.clone();
+
+                    planet.team_ids.push(team.id);
+
+                    for player in team.player_ids.iter() {
+                        let mut player = self.get_player_or_err(*player)?.clone();
+                        player.set_jersey(&team.jersey);
+                        self.players.insert(player.id, player);
+                    }
+
+                    team.spaceship.total_travelled += distance;
+
+                    // Dirty fuel can clog the engine during long journeys.
+                    let breakdown_probability = team.spaceship.fuel_grade.breakdown_probability()
+                        * duration as f64
+                        / HOURS as f64;
+                    let rng = &mut ChaCha8Rng::from_entropy();
+                    if breakdown_probability > 0.0 && rng.gen_bool(breakdown_probability.min(1.0)) {
+                        let durability = team
+                            .spaceship
+                            .current_durability()
+                            .saturating_sub(ENGINE_BREAKDOWN_DURABILITY_MALUS);
+                        team.spaceship.set_current_durability(durability);
+                        self.log_crew_event(
+                            current_tick,
+                            format!(
+                                "Dirty fuel clogged the engine on the way to {planet_name}: durability -{ENGINE_BREAKDOWN_DURABILITY_MALUS}."
+                            ),
+                        );
+                    }
+
+                    // Increase team reputation based on the travel distance
+                    let reputation_bonus = Self::team_reputation_bonus_per_distance(distance);
+                    team.reputation = (team.reputation + reputation_bonus).bound();
+
+                    // Landing on an inhabited planet costs a docking fee,
+                    // leaving without paying it in full is a crime.
+                    let docking_fee = team.docking_fee(&planet);
+                    let paid_fee = docking_fee.min(team.balance());
+                    if paid_fee > 0 {
+                        team.resources.sub(Resource::SATOSHI, paid_fee)?;
+                    }
+                    let team_id = team.id;
+
+                    let next_stop = team.travel_route.first().copied();
+                    if next_stop.is_some() {
+                        team.travel_route.remove(0);
+                    }
+
+                    let is_own_asteroid = team.asteroid_ids.contains(&to);
+                    self.teams.insert(team.id, team);
+                    self.planets.insert(planet.id, planet);
+                    let charted = self.discover_surroundings(team_id, to)?;
+                    if charted > 0 {
+                        self.log_crew_event(
+                            current_tick,
+                            format!("Charted {charted} new world(s) around {planet_name}."),
+                        );
+                    }
+                    if is_own_asteroid {
+                        self.collect_asteroid_production(to, current_tick)?;
+                    }
+                    if paid_fee > 0 {
+                        self.record_transaction(
+                            team_id,
+                            current_tick,
+                            FinanceCategory::DockingFees,
+                            -(paid_fee as i64),
+                            format!("Docking fee on {planet_name}"),
+                        );
+                    }
+                    if paid_fee < docking_fee {
+                        self.spread_gossip(
+                            team_id,
+                            to,
+                            Gossip::UnpaidDockingFee,
+                            None,
+                            current_tick,
+                        )?;
+                    }
+                    match self.auto_refuel(current_tick) {
+                        Ok(0) => {}
+                        Ok(amount) => self.log_crew_event(
+                            current_tick,
+                            format!("Refueled {amount} fuel on {planet_name}."),
+                        ),
+                        Err(e) => self.log_crew_event(
+                            current_tick,
+                            format!("Could not refuel on {planet_name}: {e}."),
+                        ),
+                    }
+                    self.dirty = true;
+                    self.dirty_network = true;
+                    self.dirty_ui = true;
+
+                    if let Some(next_planet_id) = next_stop {
+                        let team = self.get_own_team()?.clone();
+                        let next_planet_name = self.get_planet_or_err(next_planet_id)?.name.clone();
+                        match self.depart_on_route_leg(&team, next_planet_id, current_tick) {
+                            Ok(()) => {
+                                self.log_crew_event(
+                                    current_tick,
+                                    format!("Stopped on {planet_name}, now heading to {next_planet_name}."),
+                                );
+                                return Ok(None);
+                            }
+                            Err(e) => {
+                                let mut team = team;
+                                team.travel_route.clear();
+                                self.teams.insert(team.id, team);
+                                self.log_crew_event(
+                                    current_tick,
+                                    format!("Route interrupted on {planet_name}: {e}."),
+                                );
+                            }
+                        }
+                    }
+
+                    return Ok(Some(UiCallback::PushUiPopup {
+                        popup_message: PopupMessage::TeamLanded {
+                            team_name,
+                            planet_name,
+                            planet_filename,
+                            tick: current_tick,
+                        },
+                    }));
+                }
+            }
+            TeamLocation::Exploring {
+                around,
+                started,
+                duration,
+            } => {
+                if current_tick > started + duration {
+                    let mut team = own_team.clone();
+
+                    for player in team.player_ids.iter() {
+                        let mut player = self.get_player_or_err(*player)?.clone();
+                        player.set_jersey(&team.jersey);
+                        self.players.insert(player.id, player);
+                    }
+
+                    let home_planet = self.get_planet_or_err(team.home_planet_id)?;
+                    let mut around_planet = self.get_planet_or_err(around)?.clone();
+
+                    let mut rng = ChaCha8Rng::from_entropy();
+
+                    // If the home planet is an asteroid, it means an asteroid has already been found.
+                    let duration_bonus = duration as f64 / (1.0 * HOURS as f64);
+                    // If team has already MAX_NUM_ASTEROID_PER_TEAM, it cannot find another one. Finding asteroids
+                    // becomes progressively more difficult.
+                    let team_asteroid_modifier =
+                        (MAX_NUM_ASTEROID_PER_TEAM - team.asteroid_ids.len()) as f64
+                            / MAX_NUM_ASTEROID_PER_TEAM as f64;
+
+                    if home_planet.planet_type != PlanetType::Asteroid
+                        && rng.gen_bool(
+                            (ASTEROID_DISCOVERY_PROBABILITY
+                                * around_planet.asteroid_probability
+                                * duration_bonus
+                                * team_asteroid_modifier)
+                                .min(1.0),
+                        )
+                    {
+                        // We temporarily set the team back on the exploration base planet,
+                        // until the asteroid is accepted and generated.
+                        team.current_location = TeamLocation::OnPlanet { planet_id: around };
+                        self.teams.insert(team.id, team);
+                        self.dirty = true;
+                        self.dirty_network = true;
+                        self.dirty_ui = true;
+
+                        return Ok(Some(UiCallback::PushUiPopup {
+                            popup_message: PopupMessage::AsteroidNameDialog { tick: current_tick },
+                        }));
+                    }
+
+                    team.current_location = TeamLocation::OnPlanet { planet_id: around };
+                    around_planet.team_ids.push(team.id);
+
+                    let found_resources =
+                        self.resources_found_after_exploration(&team, &around_planet, duration)?;
+                    // Try to add resources starting from the most expensive one,
+                    // but still trying to add the others if they fit (notice that resources occupy a different amount of space).
+                    for (&resource, &amount) in found_resources
+                        .iter()
+                        .sorted_by(|(a, _), (b, _)| b.base_price().total_cmp(&a.base_price()))
+                    {
+                        let max_capacity = if resource == Resource::FUEL {
+                            team.fuel_capacity()
+                        } else {
+                            team.storage_capacity()
+                        };
+                        team.resources
+                            .saturating_add(resource, amount, max_capacity);
+                    }
+
+                    if self.boss_rumor_found_after_exploration(&team, duration) {
+                        team.boss_rumors += 1;
+                        let message = if team.has_boss_encounter() {
+                            "The crew pieced the rumors together: the Kartoffel mothership is hiding nearby. Go on a space adventure to hunt it down!".to_string()
+                        } else {
+                            format!(
+                                "Heard a rumor about the Kartoffel mothership while exploring around {} ({}/{}).",
+                                around_planet.name, team.boss_rumors, BOSS_RUMORS_FOR_ENCOUNTER
+                            )
+                        };
+                        self.log_crew_event(current_tick, message);
+                    }
+
+                    if self.kartoffel_found_after_exploration(&team, duration) {
+                        let mut kartoffel =
+                            Kartoffel::random(&mut rng, KartoffelId::new_v4(), &around_planet);
+                        kartoffel.team = Some(team.id);
+                        kartoffel.current_location = KartoffelLocation::WithTeam;
+                        team.kartoffel_ids.push(kartoffel.id);
+                        self.log_crew_event(
+                            current_tick,
+                            format!(
+                                "Found {}, a {} kartoffel, while exploring around {}.",
+                                kartoffel.name,
+                                kartoffel.rarity.to_string().to_lowercase(),
+                                around_planet.name
+                            ),
+                        );
+                        self.kartoffeln.insert(kartoffel.id, kartoffel);
+                    }
+
+                    if self.derelict_found_after_exploration(duration) {
+                        team.derelict = Some(Derelict::random(&mut rng, around_planet.id));
+                    }
+
+                    let found_pirates = self
+                        .free_pirates_found_after_exploration(
+                            &around_planet,
+                            duration,
+                            team.staff_bonus(TeamBonus::Exploration),
+                        )?
+                        .iter()
+                        .map(|&player_id| {
+                            self.get_player_or_err(player_id)
+                                .expect("Player should be part of world")
+                                .clone()
+                        })
+                        .collect_vec();
+
+                    let team_derelict = team.derelict.clone();
+                    let mut found = found_resources
+                        .iter()
+                        .filter(|(_, amount)| **amount > 0)
+                        .map(|(resource, amount)| {
+                            format!("{} {}", amount, resource.to_string().to_lowercase())
+                        })
+                        .collect_vec();
+                    if found_pirates.len() > 0 {
+                        found.push(format!("{} stranded pirate(s)", found_pirates.len()));
+                    }
+                    let charted = self.discover_system(team.id, around_planet.id)?;
+                    if charted > 0 {
+                        found.push(format!("{} uncharted world(s)", charted));
+                    }
+                    if let Some(derelict) = team_derelict.as_ref() {
+                        found.push(format!("the wreck of the {}", derelict.ship_name));
+                    }
+                    let message = if found.is_empty() {
+                        format!("Explored around {}: nothing found.", around_planet.name)
+                    } else {
+                        format!(
+                            "Explored around {}: found {}.",
+                            around_planet.name,
+                            found.join(", ")
+                        )
+                    };
+                    self.planets.insert(around_planet.id, around_planet);
+                    self.teams.insert(team.id, team);
+                    self.notify(current_tick, NotificationCategory::Exploration, message);
+
+                    self.dirty = true;
+                    self.dirty_network = true;
+                    self.dirty_ui = true;
+
+                    return Ok(Some(UiCallback::PushUiPopup {
+                        popup_message: PopupMessage::ExplorationResult {
+                            resources: found_resources,
+                            players: found_pirates,
+                            derelict: team_derelict,
+                            tick: current_tick,
+                        },
+                    }));
+                }
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn tick_spaceship_upgrade(&mut self, current_tick: Tick) -> AppResult<Option<UiCallback>> {
+        let own_team = self.get_own_team()?;
+        if let Some(upgrade) = own_team.spaceship.pending_upgrade.clone() {
+            if current_tick > upgrade.started + upgrade.duration {
+                return Ok(Some(UiCallback::UpgradeSpaceship { upgrade }));
+            }
+        }
+        Ok(None)
+    }
+
+    fn tick_tiredness_recovery(&mut self, current_tick: Tick) -> AppResult<()> {
+        let teams = self
+            .teams
+            .values()
+            .filter(|team| team.current_game.is_none() && team.peer_id.is_none())
+            .collect::<Vec<&Team>>();
+
+        let mut healed_messages = vec![];
+        for team in teams {
+            let mut bonus = TeamBonus::TirednessRecovery.current_team_bonus(&self, team.id)?;
+            // The cook keeps the crew in shape during long travels.
+            if team.is_travelling() {
+                bonus *= TeamBonus::Provisions.current_team_bonus(&self, team.id)?;
+            }
+            for player_id in team.player_ids.iter() {
+                let db_player = self
+                    .get_player(*player_id)
+                    .ok_or(anyhow!("Player {:?} not found", player_id))?;
+                if db_player.tiredness > 0.0 && db_player.tiredness <= MAX_TIREDNESS
+                    || db_player.is_injured()
+                {
+                    let mut player = db_player.clone();
+                    // Recovery outside of games is slower by a factor TICK_SHORT_INTERVAL/TICK_MEDIUM_INTERVAL
+                    // so that it takes 1 minute * 10 * 100 ~ 18 hours to recover from 100% tiredness.
+                    player.tiredness =
+                        (player.tiredness - bonus * RECOVERING_TIREDNESS_PER_SHORT_TICK).max(0.0);
+
+                    // The doctor bonus also shortens the recovery from injuries.
+                    if let Some(mut injury) = player.injury {
+                        if injury.recover(TickInterval::MEDIUM, bonus) {
+                            player.injury = None;
+                            if team.id == self.own_team_id {
+                                healed_messages.push(format!(
+                                    "{} recovered from a {}.",
+                                    player.info.shortened_name(),
+                                    injury.kind.to_string().to_lowercase()
+                                ));
+                            }
+                        } else {
+                            player.injury = Some(injury);
+                        }
+                    }
+                    self.players.insert(player.id, player);
+                }
+            }
+        }
+
+        for message in healed_messages {
+            self.log_crew_event(current_tick, message);
+        }
+
+        Ok(())
+    }
+
+    fn tick_free_pirates(&mut self, current_tick: Tick) -> AppResult<UiCallback> {
+        self.players.retain(|_, player| player.team.is_some());
+        // Reports are about pirates that are not around anymore.
+        self.scouting_reports.clear();
+
+        let rng = &mut ChaCha8Rng::seed_from_u64(rand::random());
+        for planet in PLANET_DATA.iter() {
+            self.populate_planet(rng, planet)?;
+        }
+        Ok(UiCallback::PushUiPopup {
+            popup_message: PopupMessage::Ok {
+                message: "Free pirates refreshed".into(),
+                is_skippable: false,
+                tick: current_tick,
+            },
+        })
+    }
+
+    fn tick_ai_teams_management(&mut self) -> AppResult<()> {
+        let rng = &mut ChaCha8Rng::from_entropy();
+        let team_ids = self
+            .teams
+            .values()
+            .filter(|team| {
+                team.id != self.own_team_id
+                    && team.peer_id.is_none()
+                    && team.current_game.is_none()
+                    && team.is_on_planet().is_some()
+            })
+            .map(|team| team.id)
+            .collect_vec();
+
+        for team_id in team_ids {
+            let team = self.get_team_or_err(team_id)?;
+            let players = team
+                .player_ids
+                .iter()
+                .filter_map(|&player_id| self.get_player(player_id))
+                .collect_vec();
+            if let Some(player_id) = ai_management::player_to_release(team, &players) {
+                self.release_player_from_team(player_id)?;
+            }
+
+            let mut team = self.get_team_or_err(team_id)?.clone();
+
+            // AI crews look after their morale between games like the own one does,
+            // first with rum and then with a bonus. Their tiredness and injuries already
+            // recover in tick_tiredness_recovery, which runs for every local team.
+            let players = team
+                .player_ids
+                .iter()
+                .filter_map(|&player_id| self.get_player(player_id))
+                .collect_vec();
+            for player_id in ai_management::players_to_drink(&team, &players) {
+                let mut player = self.get_player_or_err(player_id)?.clone();
+                player.drink();
+                player.version += 1;
+                team.resources.sub(Resource::RUM, 1)?;
+                self.players.insert(player.id, player);
+            }
+
+            let players = team
+                .player_ids
+                .iter()
+                .filter_map(|&player_id| self.get_player(player_id))
+                .collect_vec();
+            if ai_management::should_pay_crew_bonus(&team, &players) {
+                team.resources
+                    .sub(Resource::SATOSHI, team.crew_bonus_cost())?;
+                for player_id in team.player_ids.iter() {
+                    let mut player = self.get_player_or_err(*player_id)?.clone();
+                    player.add_morale(MORALE_CREW_BONUS);
+                    player.version += 1;
+                    self.players.insert(player.id, player);
+                }
+                team.on_strike_until = None;
+                team.version += 1;
+            }
+
+            if rng.gen_bool(ai_management::AI_UPGRADE_PROBABILITY) {
+                if let Some(upgrade) = ai_management::spaceship_upgrade(&team, self.now()) {
+                    for (resource, amount) in upgrade.cost() {
+                        team.resources.sub(resource, amount)?;
+                    }
+                    // AI teams do not wait for the upgrade to be completed.
+                    match upgrade.target {
+                        SpaceshipUpgradeTarget::Hull { component } => {
+                            team.spaceship.hull = component
+                        }
+                        SpaceshipUpgradeTarget::Engine { component } => {
+                            team.spaceship.engine = component
+                        }
+                        SpaceshipUpgradeTarget::Storage { component } => {
+                            team.spaceship.storage = component
+                        }
+                        SpaceshipUpgradeTarget::LifeSupport { component } => {
+                            team.spaceship.life_support = component
+                        }
+                        SpaceshipUpgradeTarget::Scanner { component } => {
+                            team.spaceship.scanner = component
+                        }
+                        SpaceshipUpgradeTarget::Repairs { .. } => {}
+                    }
+                    team.spaceship.reset_durability();
+                    team.version += 1;
+                }
+            }
+
+            // AI teams move directly to the destination, their travel is not simulated.
+            // Teams registered to a tournament stay on the hosting planet.
+            let planets = self.planets.values().collect_vec();
+            let is_in_tournament = self
+                .tournaments
+                .iter()
+                .any(|tournament| tournament.is_active() && tournament.is_participant(team.id));
+            if is_in_tournament {
+                self.teams.insert(team.id, team);
+                continue;
+            }
+            if let Some(planet_id) = ai_management::travel_destination(rng, &team, &planets) {
+                if let Some(current_planet_id) = team.is_on_planet() {
+                    let mut current_planet = self.get_planet_or_err(current_planet_id)?.clone();
+                    current_planet.team_ids.retain(|&id| id != team.id);
+                    self.planets.insert(current_planet.id, current_planet);
+                }
+                let mut planet = self.get_planet_or_err(planet_id)?.clone();
+                planet.team_ids.push(team.id);
+                self.planets.insert(planet.id, planet);
+                team.current_location = TeamLocation::OnPlanet { planet_id };
+                team.version += 1;
+            }
+
+            self.teams.insert(team.id, team);
+        }
+
+        self.dirty = true;
+        self.dirty_ui = true;
+        Ok(())
+    }
+
+    fn tick_auto_hire_free_pirates(&mut self) -> AppResult<()> {
+        let free_pirates = self
+            .players
+            .values()
+            .filter(|p| p.team.is_none())
+            .collect_vec();
+
+        let rng = &mut ChaCha8Rng::from_entropy();
+
+        let mut hired_player_ids: Vec<PlayerId> = vec![];
+        let mut hiring_team_ids: Vec<TeamId> = vec![];
+
+        for (&team_id, team) in self.teams.iter() {
+            if team.player_ids.len() >= team.spaceship.crew_capacity() as usize {
+                continue;
+            }
+
+            if team_id == self.own_team_id {
+                continue;
+            }
+
+            let available_free_pirates = free_pirates
+                .iter()
+                .filter(|&player| {
+                    !hired_player_ids.contains(&player.id)
+                        && team
+                            .can_hire_player(player, self.hire_cost(team, player))
+                            .is_ok()
+                })
+                .map(|player| player.id)
+                .collect_vec();
+
+            if let Some(player_id) = available_free_pirates.choose(rng) {
+                hired_player_ids.push(player_id.clone());
+                hiring_team_ids.push(team.id);
+            }
+        }
+
+        assert!(hired_player_ids.len() == hiring_team_ids.len());
+        for idx in 0..hired_player_ids.len() {
+            let player_id = hired_player_ids[idx];
+            let team_id = hiring_team_ids[idx];
+            self.hire_player_for_team(player_id, team_id)?;
+        }
+
+        Ok(())
+    }
+
+    fn tick_players_update(&mut self) {
+        let own_team_id = self.own_team_id;
+        let ai_growth = self.difficulty_modifier(DifficultyModifier::AiGrowth);
+        let overcrowded_teams = self
+            .teams
+            .values()
+            .filter(|team| team.is_overcrowded())
+            .map(|team| team.id)
+            .collect::<Vec<TeamId>>();
+
+        for (_, player) in self.players.iter_mut() {
+            //TODO: once we remove local teams, we can remove this loop and only apply to own_team
+            if player.peer_id.is_some() {
+                continue;
+            }
+            player.version += 1;
+            // Reset player improvements for UI.
+            player.previous_skills = player.current_skill_array();
+            player.info.age = player.info.age + AGE_INCREASE_PER_LONG_TICK;
+
+            // Pirates slightly dislike being part of a team.
+            // This is counteracted by the morale boost pirates get by playing games.
+            // Computer teams play few games during the simulation, and would end up
+            // completely demoralized: they make up for it with rum and crew bonuses
+            // in tick_ai_teams_management.
+            player.morale = (player.morale + MORALE_DECREASE_PER_LONG_TICK).bound();
+            if let Some(team_id) = player.team {
+                if overcrowded_teams.contains(&team_id) {
+                    player.morale = (player.morale + MORALE_OVERCROWDING_MALUS).bound();
+                }
+            }
+            player.reputation = (player.reputation - REPUTATION_DECREASE_PER_LONG_TICK).bound();
+
+            // Players of AI crews grow faster or slower depending on the difficulty.
+            let training_modifier = match player.team {
+                Some(team_id) if team_id != own_team_id => ai_growth,
+                _ => 1.0,
+            };
+            for idx in 0..player.skills_training.len() {
+                // Increase player skills from training
+                assert!(player.skills_training[idx] >= 0.0);
+                player.modify_skill(idx, player.skills_training[idx] * training_modifier);
+
+                // Reduce player skills. This is planned to counteract the effect of training by playing games.
+                // Mental abilities don't decrease for mature players.
+                if idx > 15 && player.info.relative_age() > 0.15 {
+                    continue;
+                }
+
+                // The potential_modifier has a value ranging from 0.0 to 4.0.
+                // Players with skills below their potential decrease slower, above their potential decrease faster.
+                let potential_modifier =
+                    (1.0 + (player.average_skill() - player.potential) / 20.0).powf(2.0);
+                player.modify_skill(
+                    idx,
+                    SKILL_DECREMENT_PER_LONG_TICK
+                        * potential_modifier
+                        * player.info.relative_age().max(0.1),
+                );
+
+                // Reduce athletics skills even more if relative_age is more than 0.75.
+                if idx < 4 && player.info.relative_age() > 0.75 {
+                    player.modify_skill(
+                        idx,
+                        SKILL_DECREMENT_PER_LONG_TICK
+                            * potential_modifier
+                            * player.info.relative_age(),
+                    );
+                }
+            }
+            player.skills_training = [0.0; 20];
+        }
+    }
+
+    fn tick_training_schedules(&mut self, current_tick: Tick) -> AppResult<()> {
+        let mut sessions = vec![];
+        for team in self.teams.values() {
+            if team.peer_id.is_some() || team.current_game.is_some() {
+                continue;
+            }
+            if let Some(focus) = team.training_schedule.session_at(current_tick) {
+                let modifier = TeamBonus::Training.current_team_bonus(&self, team.id)?
+                    * TrainingSchedule::facility_modifier(&team.spaceship);
+                sessions.push((team.player_ids.clone(), focus, modifier));
+            }
+        }
+
+        for (player_ids, focus, modifier) in sessions {
+            for player_id in player_ids {
+                if let Some(player) = self.players.get_mut(&player_id) {
+                    TrainingSchedule::apply_session(player, focus, modifier);
+                    player.version += 1;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn tick_history_records(&mut self, current_tick: Tick) {
+        for team in self.teams.values() {
+            self.history.record_balance(team, current_tick);
+        }
+        self.dirty = true;
+    }
+
+    fn tick_teams_reputation(&mut self) -> AppResult<()> {
+        let mut reputation_update: Vec<(TeamId, f32)> = vec![];
+        for (_, team) in self.teams.iter() {
+            //TODO: once we remove local teams, we can remove this loop and only apply to own_team
+            if team.peer_id.is_some() {
+                continue;
+            }
+            let bonus = TeamBonus::Reputation.current_team_bonus(&self, team.id)?;
+            let players_reputation = team
+                .player_ids
+                .iter()
+                .map(|id| self.players.get(id).unwrap().reputation)
+                .sum::<f32>()
+                / MAX_POSITION as f32
+                / 100.0
+                * bonus;
+
+            let new_reputation = if team.reputation > players_reputation {
+                (team.reputation - players_reputation).bound()
+            } else if team.reputation < players_reputation {
+                (team.reputation + players_reputation).bound()
+            } else {
+                team.reputation
+            };
+            reputation_update.push((team.id, new_reputation));
+        }
+
+        for (team_id, new_reputation) in reputation_update {
+            let mut team = self.get_team_or_err(team_id)?.clone();
+            team.reputation = new_reputation;
+            // Gossip is slowly forgotten, and so are past favours.
+            team.planet_reputation.retain(|_, offset| {
+                *offset = if *offset < 0.0 {
+                    (*offset + GOSSIP_RECOVERY_PER_LONG_TICK).min(0.0)
+                } else {
+                    (*offset - GOSSIP_RECOVERY_PER_LONG_TICK).max(0.0)
+                };
+                *offset != 0.0
+            });
+            team.team_reputation.retain(|_, offset| {
+                *offset = (*offset + GOSSIP_RECOVERY_PER_LONG_TICK).min(0.0);
+                *offset != 0.0
+            });
+            team.gossip_strikes.clear();
+            self.teams.insert(team.id, team);
+        }
+        Ok(())
+    }
+
+    fn tick_player_leaving_team(&mut self, current_tick: Tick) -> AppResult<Vec<UiCallback>> {
+        let mut messages = vec![];
+
+        let mut releasing_player_ids = vec![];
+        let mut retiring_player_ids = vec![];
+
+        for &player_id in self.players.keys() {
+            let player = self.get_player_or_err(player_id)?;
+            // Academy prospects are not part of the crew yet.
+            if player.team.is_none() || academy::academy_location(player).is_some() {
+                continue;
+            }
+
+            let team = self.get_team_or_err(player.team.expect("Player should have a team"))?;
+            if team.current_game.is_some() {
+                continue;
+            }
+
+            let rng = &mut ChaCha8Rng::from_entropy();
+
+            if player.morale < MORALE_THRESHOLD_FOR_LEAVING {
+                if rng.gen_bool(
+                    (1.0 - player.morale / MAX_SKILL) as f64 * LEAVING_PROBABILITY_MORALE_MODIFIER,
+                ) {
+                    releasing_player_ids.push(player_id);
+
+                    if player.team.expect("Team should be some") == self.own_team_id {
+                        messages.push(UiCallback::PushUiPopup {
+                            popup_message: PopupMessage::Ok {
+                                message: format!(
+                                    "{} {} left the crew!\n{} morale was too low...",
+                                    player.info.first_name,
+                                    player.info.last_name,
+                                    player.info.pronouns.as_possessive()
+                                ),
+                                is_skippable: false,
+                                tick: current_tick,
+                            },
+                        })
+                    }
+                }
+            } else if player.info.relative_age() > MIN_RELATIVE_RETIREMENT_AGE {
+                // Add extra check to avoid running rng call unnecessarily.
+                if player.info.relative_age() > rng.gen_range(MIN_RELATIVE_RETIREMENT_AGE..1.0) {
+                    releasing_player_ids.push(player_id);
+                    retiring_player_ids.push(player_id);
+
+                    if player.team.expect("Team should be some") == self.own_team_id {
+                        messages.push(UiCallback::PushUiPopup {
+                    popup_message: PopupMessage::Ok{
+                        message:format!(
+                            "{} {} left the crew and retired to cultivate turnips\n{} {} been a great pirate...",
+                            player.info.first_name,
+                            player.info.last_name,
+                            player.info.pronouns.as_subject(),
+                            player.info.pronouns.to_have(),
+                        ),
+                        is_skippable:false,
+                        tick:current_tick
+                    },
+                })
+                    }
+                }
+            }
+        }
+
+        for &player_id in retiring_player_ids.iter() {
+            let player = self.get_player_or_err(player_id)?.clone();
+            let team_name = match player.team {
+                Some(team_id) => self.get_team_or_err(team_id)?.name.clone(),
+                None => continue,
+            };
+            self.history
+                .record_retirement(&player, &team_name, current_tick);
+        }
+
+        for &player_id in releasing_player_ids.iter() {
+            self.release_player_from_team(player_id)?;
+        }
+
+        Ok(messages)
+    }
+
+    fn tick_crew_mutiny(&mut self, current_tick: Tick) -> AppResult<Option<UiCallback>> {
+        let mut team = self.get_own_team()?.clone();
+        if team.current_game.is_some() || team.player_ids.len() == 0 {
+            return Ok(None);
+        }
+
+        if let Some(until) = team.on_strike_until {
+            if current_tick > until {
+                team.on_strike_until = None;
+                self.teams.insert(team.id, team);
+                self.log_crew_event(
+                    current_tick,
+                    "The strike is over, the crew is back to work.".into(),
+                );
+                self.dirty_network = true;
+            }
+            return Ok(None);
+        }
+
+        let players = self.get_players_by_team(&team)?;
+        let average_morale = players.iter().map(|p| p.morale).sum::<f32>() / players.len() as f32;
+        if average_morale >= MORALE_THRESHOLD_FOR_MUTINY {
+            return Ok(None);
+        }
+
+        let rng = &mut ChaCha8Rng::from_entropy();
+        if !rng.gen_bool(
+            (1.0 - average_morale / MORALE_THRESHOLD_FOR_MUTINY) as f64
+                * MUTINY_PROBABILITY_MORALE_MODIFIER,
+        ) {
+            return Ok(None);
+        }
+
+        // The captain never joins a mutiny, everybody else below the threshold does.
+        let mutineers = players
+            .iter()
+            .filter(|p| {
+                p.morale < MORALE_THRESHOLD_FOR_MUTINY && Some(p.id) != team.crew_roles.captain
+            })
+            .collect_vec();
+        if mutineers.len() == 0 {
+            return Ok(None);
+        }
+
+        let mut kind = [MutinyKind::Strike, MutinyKind::Theft, MutinyKind::Desertion]
+            .choose(rng)
+            .copied()
+            .expect("There should be a mutiny kind");
+        // Pirates can only desert while on a planet.
+        if kind == MutinyKind::Desertion && team.can_release_player(mutineers[0]).is_err() {
+            kind = MutinyKind::Strike;
+        }
+
+        let message = match kind {
+            MutinyKind::Strike => {
+                team.on_strike_until = Some(current_tick + MUTINY_STRIKE_DURATION);
+                self.teams.insert(team.id, team);
+                format!(
+                    "Mutiny! {} pirates are on strike and refuse to play.",
+                    mutineers.len()
+                )
+            }
+            MutinyKind::Theft => {
+                let mut stolen = vec![];
+                let mut stolen_satoshi = 0;
+                for resource in [Resource::SATOSHI, Resource::GOLD, Resource::RUM] {
+                    let amount = (team.resources.value(&resource) as f32
+                        * MUTINY_THEFT_FRACTION
+                        * mutineers.len() as f32
+                        / players.len() as f32) as u32;
+                    if amount > 0 {
+                        team.resources.sub(resource, amount)?;
+                        stolen.push(format!("{amount} {resource}"));
+                        if resource == Resource::SATOSHI {
+                            stolen_satoshi = amount;
+                        }
+                    }
+                }
+                self.teams.insert(team.id, team);
+                self.record_transaction(
+                    self.own_team_id,
+                    current_tick,
+                    FinanceCategory::Theft,
+                    -(stolen_satoshi as i64),
+                    "Stolen by mutineers".to_string(),
+                );
+                if stolen.len() == 0 {
+                    "Mutiny! The pirates raided the hold, but it was empty.".to_string()
+                } else {
+                    format!("Mutiny! The pirates stole {}.", stolen.join(", "))
+                }
+            }
+            MutinyKind::Desertion => {
+                let names = mutineers
+                    .iter()
+                    .map(|p| p.info.shortened_name())
+                    .collect_vec();
+                let mutineer_ids = mutineers.iter().map(|p| p.id).collect_vec();
+                for player_id in mutineer_ids {
+                    self.release_player_from_team(player_id)?;
+                }
+                format!("Mutiny! {} deserted the crew.", names.join(", "))
+            }
+        };
+
+        self.log_crew_event(current_tick, message.clone());
+        self.dirty_network = true;
+
+        Ok(Some(UiCallback::PushUiPopup {
+            popup_message: PopupMessage::Ok {
+                message: format!("{message}\n{}", kind.description()),
+                is_skippable: false,
+                tick: current_tick,
+            },
+        }))
+    }
+
+    pub fn can_trade_on_planet(&self, planet_id: PlanetId, tick: Tick) -> AppResult<()> {
+        if self.world_events.iter().any(|event| {
+            event.kind == WorldEventKind::Plague
+                && event.planet_id == planet_id
+                && event.is_active(tick)
+        }) {
+            return Err(anyhow!("The market is closed because of the plague"));
+        }
+        Ok(())
+    }
+
+    fn tick_world_events(&mut self, current_tick: Tick) -> AppResult<Option<UiCallback>> {
+        // Planets recover from the plague once the quarantine is over.
+        for event in self.world_events.iter_mut() {
+            if event.is_active(current_tick) || event.drained_resources.is_empty() {
+                continue;
+            }
+            if let Some(planet) = self.planets.get_mut(&event.planet_id) {
+                for (resource, amount) in event.drained_resources.drain() {
+                    *planet.resources.entry(resource).or_default() += amount;
+                }
+            }
+            self.dirty = true;
+        }
+
+        let rng = &mut ChaCha8Rng::from_entropy();
+        if !rng.gen_bool(WORLD_EVENT_PROBABILITY) {
+            return Ok(None);
+        }
+
+        let planet_id = match self
+            .planets
+            .values()
+            .filter(|planet| planet.total_population() > 0)
+            .map(|planet| planet.id)
+            .choose(rng)
+        {
+            Some(id) => id,
+            None => return Ok(None),
+        };
+        let planet_name = self.get_planet_or_err(planet_id)?.name.clone();
+        let kind = WorldEventKind::random(rng);
+
+        // Teams from other peers are updated by their own world.
+        let team_ids = self
+            .teams
+            .values()
+            .filter(|team| {
+                team.is_on_planet() == Some(planet_id)
+                    && (team.peer_id.is_none() || team.id == self.own_team_id)
+            })
+            .map(|team| team.id)
+            .collect_vec();
+        let is_own_team_affected = team_ids.contains(&self.own_team_id);
+
+        let mut event = WorldEvent::new(
+            kind,
+            planet_id,
+            current_tick,
+            format!("{} on {}!", kind, planet_name),
+        );
+
+        match kind {
+            WorldEventKind::MeteorShower => {
+                let damage = (METEOR_SHOWER_DAMAGE as f32
+                    * self.difficulty_modifier(DifficultyModifier::EventHarshness))
+                .round() as u32;
+                for team_id in team_ids.iter() {
+                    let mut team = self.get_team_or_err(*team_id)?.clone();
+                    let durability = team.spaceship.current_durability();
+                    team.spaceship
+                        .set_current_durability(durability.saturating_sub(damage));
+                    self.teams.insert(team.id, team);
+                }
+            }
+            WorldEventKind::RumFestival => {
+                for team_id in team_ids.iter() {
+                    let player_ids = self.get_team_or_err(*team_id)?.player_ids.clone();
+                    for player_id in player_ids {
+                        let mut player = self.get_player_or_err(player_id)?.clone();
+                        player.add_morale(RUM_FESTIVAL_MORALE_BONUS);
+                        self.players.insert(player.id, player);
+                    }
+                }
+            }
+            WorldEventKind::Plague => {
+                let mut planet = self.get_planet_or_err(planet_id)?.clone();
+                for resource in MARKET_RESOURCES {
+                    if let Some(amount) = planet.resources.remove(&resource) {
+                        event.drained_resources.insert(resource, amount);
+                    }
+                }
+                self.planets.insert(planet.id, planet);
+            }
+        }
+
+        let message = event.message.clone();
+        self.world_events.push(event);
+        if self.world_events.len() > MAX_WORLD_EVENTS {
+            self.world_events.remove(0);
+        }
+        self.dirty = true;
+        self.dirty_ui = true;
+
+        if !is_own_team_affected {
+            return Ok(None);
+        }
+
+        self.log_crew_event(current_tick, message.clone());
+        self.dirty_network = true;
+
+        Ok(Some(UiCallback::PushUiPopup {
+            popup_message: PopupMessage::Ok {
+                message: format!(
+                    "{message}
+{}",
+                    kind.description()
+                ),
+                is_skippable: true,
+                tick: current_tick,
+            },
+        }))
+    }
+
+    fn generate_random_games(&mut self) -> AppResult<()> {
+        let rng = &mut ChaCha8Rng::from_entropy();
+        for planet in self.planets.values() {
+            if planet.team_ids.len() < 2 {
+                continue;
+            }
+
+            let candidate_teams = planet
+                .team_ids
+                .iter()
+                .map(|&id| self.get_team_or_err(id))
+                .filter(|team_res| {
+                    if let Ok(team) = team_res {
+                        if team.player_ids.len() < MIN_PLAYERS_PER_GAME {
+                            return false;
+                        }
+                        let avg_tiredness = team
+                            .player_ids
+                            .iter()
+                            .map(|&id| {
+                                if let Ok(player) = self.get_player_or_err(id) {
+                                    player.tiredness
+                                } else {
+                                    0.0
+                                }
+                            })
+                            .sum::<f32>()
+                            / team.player_ids.len() as f32;
+                        return team.current_game.is_none()
+                            && team.id != self.own_team_id
+                            && team.peer_id.is_none()
+                            && avg_tiredness <= MAX_AVG_TIREDNESS_PER_AUTO_GAME;
+                    }
+                    false
+                })
+                .collect::<AppResult<Vec<&Team>>>()?;
+
+            if candidate_teams.len() < 2 {
+                continue;
+            }
+            let teams = candidate_teams.iter().choose_multiple(rng, 2);
+            let home_team_in_game =
+                TeamInGame::from_team_id(teams[0].id, &self.teams, &self.players)
+                    .ok_or(anyhow!("Team {:?} not found in world", teams[0].id))?;
+
+            let away_team_in_game =
+                TeamInGame::from_team_id(teams[1].id, &self.teams, &self.players)
+                    .ok_or(anyhow!("Team {:?} not found in world", teams[1].id))?;
+
+            self.generate_game(home_team_in_game, away_team_in_game)?;
+            return Ok(());
+        }
+
+        Ok(())
+    }
+
+    pub fn filter_peer_data(&mut self, peer_id: Option<PeerId>) -> AppResult<()> {
+        let mut own_team = self.get_own_team()?.clone();
+        if let Some(peer_id) = peer_id {
+            // Filter all data that has a specific peer_id
+            self.teams
+                .retain(|_, team| team.peer_id.is_none() || team.peer_id.unwrap() != peer_id);
+            self.players
+                .retain(|_, player| player.peer_id.is_none() || player.peer_id.unwrap() != peer_id);
+            self.planets
+                .retain(|_, planet| planet.peer_id.is_none() || planet.peer_id.unwrap() != peer_id);
+            own_team
+                .sent_challenges
+                .retain(|_, challenge| challenge.target_peer_id != peer_id);
+            own_team
+                .received_challenges
+                .retain(|_, challenge| challenge.proposer_peer_id != peer_id);
+            own_team
+                .sent_trades
+                .retain(|_, trade| trade.target_peer_id != peer_id);
+            own_team
+                .received_trades
+                .retain(|_, trade| trade.proposer_peer_id != peer_id);
+            own_team
+                .sent_resource_trades
+                .retain(|_, trade| trade.target_peer_id != peer_id);
+            own_team
+                .received_resource_trades
+                .retain(|_, trade| trade.proposer_peer_id != peer_id);
+        } else {
+            // Filter all data that has a peer_id (i.e. keep only local data)
+            self.teams.retain(|_, team| team.peer_id.is_none());
+            self.players.retain(|_, player| player.peer_id.is_none());
+            self.planets.retain(|_, planet| planet.peer_id.is_none());
+            own_team.clear_challenges();
+            own_team.clear_trades();
+            own_team.clear_resource_trades();
+        }
+        // Remove teams from planet teams vector.
+        for (_, planet) in self.planets.iter_mut() {
+            planet
+                .team_ids
+                .retain(|&team_id| self.teams.contains_key(&team_id));
+        }
+
+        // Set current game to None for teams playing a game not stored in games.
+        for team in self.teams.values_mut() {
+            if team.current_game.is_some() && !self.games.contains_key(&team.current_game.unwrap())
+            {
+                team.current_game = None;
+            }
+        }
+
+        self.teams.insert(own_team.id, own_team);
+        self.dirty = true;
+        self.dirty_ui = true;
+        Ok(())
+    }
 
-                    player.version += 1;
-                    player.add_morale(MORALE_INCREASE_PER_GAME);
+    pub fn travel_time_to_planet(&self, team_id: TeamId, to: PlanetId) -> AppResult<Tick> {
+        let team = self.get_team_or_err(team_id)?;
+        let from = match team.current_location {
+            TeamLocation::OnPlanet { planet_id } => planet_id,
+            TeamLocation::Travelling { .. } => return Err(anyhow!("Team is travelling")),
+            TeamLocation::Exploring { .. } => return Err(anyhow!("Team is exploring")),
+            TeamLocation::OnSpaceAdventure { .. } => {
+                return Err(anyhow!("Team is on space adventure"))
+            }
+        };
 
-                    let stats = team
-                        .stats
-                        .get(&player.id)
-                        .ok_or(anyhow!("Player {:?} not found in team stats", player.id))?;
+        self.travel_time_between_planets(team_id, from, to)
+    }
 
-                    player.reputation = (player.reputation
-                        + REPUTATION_PER_EXPERIENCE
-                            * stats.seconds_played as f32
-                            * TeamBonus::Reputation.current_team_bonus(
-                                self,
-                                player.team.expect("Player should have a team"),
-                            )?)
-                    .bound();
+    pub fn travel_time_between_planets(
+        &self,
+        team_id: TeamId,
+        from: PlanetId,
+        to: PlanetId,
+    ) -> AppResult<Tick> {
+        let team = self.get_team_or_err(team_id)?;
+        let distance = self.distance_between_planets(from, to)?;
+        let bonus = TeamBonus::SpaceshipSpeed.current_team_bonus(&self, team.id)?;
+        let mut flight_time = distance as f32 / team.spaceship_speed();
+        if self.wormhole_between(from, to).is_some() {
+            flight_time = flight_time.min(WORMHOLE_TRANSIT_TIME as f32);
+        }
+        Ok(((LANDING_TIME_OVERHEAD as f32 + flight_time) / bonus) as Tick)
+    }
 
-                    let training_bonus =
-                        TeamBonus::Training.current_team_bonus(&self, team.team_id)?;
-                    let training_focus = team.training_focus;
-                    player.update_skills_training(
-                        stats.experience_at_position,
-                        training_bonus,
-                        training_focus,
-                    );
-                    self.players.insert(player.id, player);
-                }
+    pub fn wormhole_between(&self, from: PlanetId, to: PlanetId) -> Option<&Wormhole> {
+        self.wormholes
+            .iter()
+            .find(|wormhole| wormhole.connects(from, to))
+    }
+
+    pub fn wormholes_at(&self, planet_id: PlanetId) -> Vec<&Wormhole> {
+        self.wormholes
+            .iter()
+            .filter(|wormhole| wormhole.other_end(planet_id).is_some())
+            .collect()
+    }
+
+    pub fn can_build_wormhole_gate(&self, team: &Team, to: PlanetId) -> AppResult<()> {
+        let from = team
+            .is_on_planet()
+            .ok_or(anyhow!("Can only build a gate on a planet"))?;
+
+        for (resource, amount) in WORMHOLE_GATE_COST.iter() {
+            if team.resources.value(resource) < *amount {
+                return Err(anyhow!("Insufficient resources"));
             }
+        }
 
-            // Past games of the own team are persisted in the store.
-            if game.home_team_in_game.team_id == self.own_team_id
-                || game.away_team_in_game.team_id == self.own_team_id
-            {
-                let game_summary = GameSummary::from_game(&game);
-                self.past_games.insert(game_summary.id, game_summary);
-                save_game(&game)?;
-                // Update network that game has ended.
-                self.dirty_network = true;
+        let target = self.get_planet_or_err(to)?;
+        if target.peer_id.is_some() {
+            return Err(anyhow!("Cannot build a gate to an asteroid"));
+        }
+
+        if !self.is_planet_discovered(team.id, to) {
+            return Err(anyhow!("Destination is uncharted"));
+        }
+
+        if self.wormhole_between(from, to).is_some() {
+            return Err(anyhow!("Already linked by a wormhole"));
+        }
+
+        if self.distance_between_planets(from, to)? < MIN_WORMHOLE_DISTANCE {
+            return Err(anyhow!("Destination is too close"));
+        }
+
+        Ok(())
+    }
+
+    // Opens a wormhole gate between the current planet of the team and the destination.
+    pub fn build_wormhole_gate(
+        &mut self,
+        team_id: TeamId,
+        to: PlanetId,
+        current_tick: Tick,
+    ) -> AppResult<String> {
+        let mut team = self.get_team_or_err(team_id)?.clone();
+        self.can_build_wormhole_gate(&team, to)?;
+        let from = team
+            .is_on_planet()
+            .ok_or(anyhow!("Can only build a gate on a planet"))?;
+
+        for (resource, amount) in WORMHOLE_GATE_COST.iter() {
+            team.resources.sub(*resource, *amount)?;
+        }
+        team.version += 1;
+
+        let message = format!(
+            "Wormhole gate opened between {} and {}",
+            self.get_planet_or_err(from)?.name,
+            self.get_planet_or_err(to)?.name
+        );
+        self.wormholes
+            .push(Wormhole::gate(from, to, team.id, current_tick));
+        self.teams.insert(team.id, team);
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+
+        Ok(message)
+    }
+
+    fn route_leg(&self, team: &Team, from: PlanetId, to: PlanetId) -> AppResult<RouteLeg> {
+        let duration = self.travel_time_between_planets(team.id, from, to)?;
+        Ok(RouteLeg {
+            from,
+            to,
+            distance: self.distance_between_planets(from, to)?,
+            duration,
+            fuel: (duration as f32 * team.spaceship_fuel_consumption()).max(1.0) as u32,
+        })
+    }
+
+    // Finds the fastest route to the target planet, stopping to refuel
+    // on populated planets when the destination is out of reach.
+    pub fn plan_travel_route(&self, team_id: TeamId, to: PlanetId) -> AppResult<TravelRoute> {
+        let team = self.get_team_or_err(team_id)?;
+        let from = match team.current_location {
+            TeamLocation::OnPlanet { planet_id } => planet_id,
+            TeamLocation::Travelling { .. } => return Err(anyhow!("Team is travelling")),
+            TeamLocation::Exploring { .. } => return Err(anyhow!("Team is exploring")),
+            TeamLocation::OnSpaceAdventure { .. } => {
+                return Err(anyhow!("Team is on space adventure"))
             }
+        };
 
-            // Teams get money depending on game attendance.
-            // Home team gets a bonus for playing at home.
-            // If a team is knocked out, money goes to the other team.
-            // If both are knocked out, they get no money.
-            let mut home_team_income = 100 + game.attendance * INCOME_PER_ATTENDEE_HOME;
-            let mut away_team_income = 100 + game.attendance * INCOME_PER_ATTENDEE_AWAY;
-            let home_knocked_out = game.is_team_knocked_out(Possession::Home);
-            let away_knocked_out = game.is_team_knocked_out(Possession::Away);
+        if from == to {
+            return Err(anyhow!("Already on this planet"));
+        }
 
-            match (home_knocked_out, away_knocked_out) {
-                (true, false) => {
-                    away_team_income += home_team_income;
-                    home_team_income = 0;
+        let target = self.get_planet_or_err(to)?;
+        if target.peer_id.is_some() {
+            return Err(anyhow!("Cannot travel to asteroid"));
+        }
+        if target.total_population() == 0 && team.home_planet_id != to {
+            return Err(anyhow!("This place is inhabitable"));
+        }
+
+        let can_refuel_at_start = self.get_planet_or_err(from)?.total_population() > 0;
+        let stops = self
+            .planets
+            .values()
+            .filter(|planet| {
+                planet.peer_id.is_none()
+                    && planet.total_population() > 0
+                    && planet.id != from
+                    && planet.id != to
+            })
+            .map(|planet| planet.id)
+            .chain(std::iter::once(to))
+            .collect_vec();
+
+        // Dijkstra on the travel duration. For each planet we store the arrival time
+        // and the leg used to get there, so that the route can be reconstructed.
+        let mut best: HashMap<PlanetId, (Tick, Option<RouteLeg>)> = HashMap::new();
+        best.insert(from, (0, None));
+        let mut visited: Vec<PlanetId> = vec![];
+
+        loop {
+            let next = best
+                .iter()
+                .filter(|(id, _)| !visited.contains(id))
+                .min_by_key(|(_, (arrival, _))| *arrival)
+                .map(|(id, (arrival, _))| (*id, *arrival));
+
+            let (current_id, elapsed) = match next {
+                Some(next) => next,
+                None => break,
+            };
+
+            if current_id == to {
+                break;
+            }
+            visited.push(current_id);
+
+            let available_fuel = if current_id != from || can_refuel_at_start {
+                team.fuel_capacity()
+            } else {
+                team.fuel()
+            };
+
+            for &stop_id in stops.iter() {
+                if visited.contains(&stop_id) {
+                    continue;
                 }
-                (false, true) => {
-                    home_team_income += away_team_income;
-                    away_team_income = 0;
+                let leg = self.route_leg(team, current_id, stop_id)?;
+                if leg.fuel > available_fuel {
+                    continue;
                 }
-                (true, true) => {
-                    home_team_income = 0;
-                    away_team_income = 0;
+                let arrival = elapsed + leg.duration;
+                let is_faster = match best.get(&stop_id) {
+                    Some((best_arrival, _)) => arrival < *best_arrival,
+                    None => true,
+                };
+                if is_faster {
+                    best.insert(stop_id, (arrival, Some(leg)));
+                }
+            }
+        }
+
+        let mut legs = vec![];
+        let mut current_id = to;
+        while let Some((_, Some(leg))) = best.get(&current_id) {
+            legs.push(*leg);
+            current_id = leg.from;
+        }
+
+        if legs.is_empty() {
+            return Err(anyhow!("No route to this planet"));
+        }
+        legs.reverse();
+
+        Ok(TravelRoute::new(legs))
+    }
+
+    // Buys the fuel missing for the leg, if possible, and departs.
+    // The world is modified only if the team can actually depart.
+    pub fn depart_on_route_leg(
+        &mut self,
+        team: &Team,
+        to: PlanetId,
+        current_tick: Tick,
+    ) -> AppResult<()> {
+        let mut team = team.clone();
+        let from = team
+            .is_on_planet()
+            .ok_or(anyhow!("Team is not on a planet"))?;
+        let mut from_planet = self.get_planet_or_err(from)?.clone();
+        let duration = self.travel_time_between_planets(team.id, from, to)?;
+        let distance = self.distance_between_planets(from, to)?;
+
+        let fuel_needed = (duration as f32 * team.spaceship_fuel_consumption()).max(1.0) as u32;
+        let missing_fuel = fuel_needed.saturating_sub(team.fuel());
+        let mut refuel_cost = 0;
+        if missing_fuel > 0 {
+            if from_planet.total_population() == 0 {
+                return Err(anyhow!("Cannot refuel on {}", from_planet.name));
+            }
+            let merchant_bonus = TeamBonus::TradePrice.current_team_bonus(self, team.id)?
+                * team.merchant_modifier(from);
+            let unit_cost = (from_planet.resource_buy_price(Resource::FUEL, merchant_bonus) as f32
+                * team.spaceship.fuel_grade.price_modifier()) as u32;
+            refuel_cost = unit_cost * missing_fuel;
+            if team.balance() < refuel_cost {
+                return Err(anyhow!("Not enough satoshi to refuel"));
+            }
+            team.resources.sub(Resource::SATOSHI, refuel_cost)?;
+            team.resources
+                .add(Resource::FUEL, missing_fuel, team.fuel_capacity())?;
+        }
+
+        team.can_travel_to_planet(self.get_planet_or_err(to)?, duration)?;
+        team.resources.sub(Resource::FUEL, fuel_needed)?;
+        team.current_location = TeamLocation::Travelling {
+            from,
+            to,
+            started: current_tick,
+            duration,
+            distance,
+        };
+
+        if refuel_cost > 0 {
+            self.record_transaction(
+                team.id,
+                current_tick,
+                FinanceCategory::Trading,
+                -(refuel_cost as i64),
+                format!("Bought {} {}", missing_fuel, Resource::FUEL),
+            );
+        }
+
+        from_planet.team_ids.retain(|&x| x != team.id);
+        self.planets.insert(from_planet.id, from_planet);
+
+        let pirate_jersey = Jersey {
+            style: JerseyStyle::Pirate,
+            color: team.jersey.color.clone(),
+        };
+        for player_id in team.player_ids.iter() {
+            let mut player = self.get_player_or_err(*player_id)?.clone();
+            player.set_jersey(&pirate_jersey);
+            self.players.insert(player.id, player);
+        }
+
+        self.teams.insert(team.id, team);
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+
+        Ok(())
+    }
+
+    // Plans the route to the planet and departs on the first leg,
+    // the remaining stops are followed automatically in tick_travel.
+    pub fn travel_along_route(
+        &mut self,
+        team: &Team,
+        planet_id: PlanetId,
+        current_tick: Tick,
+    ) -> AppResult<()> {
+        let route = self.plan_travel_route(team.id, planet_id)?;
+        let stops = route.stops();
+        self.depart_on_route_leg(team, stops[0], current_tick)?;
+
+        let mut team = self.get_team_or_err(team.id)?.clone();
+        team.travel_route = stops[1..].to_vec();
+        self.teams.insert(team.id, team);
+
+        Ok(())
+    }
+
+    pub fn start_exploration(
+        &mut self,
+        team: &Team,
+        duration: Tick,
+        current_tick: Tick,
+    ) -> AppResult<()> {
+        let mut team = team.clone();
+        let planet_id = match team.current_location {
+            TeamLocation::OnPlanet { planet_id } => planet_id,
+            TeamLocation::Travelling { .. } => return Err(anyhow!("Team is travelling")),
+            TeamLocation::Exploring { .. } => return Err(anyhow!("Team is already exploring")),
+            TeamLocation::OnSpaceAdventure { .. } => {
+                return Err(anyhow!("Team is on a space adventure"))
+            }
+        };
+
+        let mut around_planet = self.get_planet_or_err(planet_id)?.clone();
+        team.can_explore_around_planet(&around_planet, duration)?;
+
+        team.current_location = TeamLocation::Exploring {
+            around: planet_id,
+            started: current_tick,
+            duration,
+        };
+
+        // For simplicity we just subtract the fuel upfront, maybe would be nicer on UI to
+        // show the fuel consumption as the team travels in world.tick_travel,
+        // but this would require more operations and checks in the tick function.
+        team.resources.sub(
+            Resource::FUEL,
+            (duration as f32 * team.spaceship_fuel_consumption()).max(1.0) as u32,
+        )?;
+
+        around_planet.team_ids.retain(|&x| x != team.id);
+        self.planets.insert(around_planet.id, around_planet);
+
+        let pirate_jersey = Jersey {
+            style: JerseyStyle::Pirate,
+            color: team.jersey.color.clone(),
+        };
+
+        for player_id in team.player_ids.iter() {
+            let mut player = self.get_player_or_err(*player_id)?.clone();
+            player.set_jersey(&pirate_jersey);
+            self.players.insert(player.id, player);
+        }
+
+        self.teams.insert(team.id, team);
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+
+        Ok(())
+    }
+
+    // Buys the resource on the current planet, up to the available capacity.
+    pub fn resource_buy_unit_cost(
+        &self,
+        team: &Team,
+        planet: &Planet,
+        resource: Resource,
+    ) -> AppResult<u32> {
+        let merchant_bonus = TeamBonus::TradePrice.current_team_bonus(self, team.id)?
+            * team.merchant_modifier(planet.id);
+        let mut unit_cost = planet.resource_buy_price(resource, merchant_bonus);
+        if resource == Resource::FUEL {
+            unit_cost = (unit_cost as f32 * team.spaceship.fuel_grade.price_modifier()) as u32;
+        }
+        Ok(unit_cost)
+    }
+
+    // Buys fuel up to the auto-refuel target, as much as the treasury allows.
+    // Returns the amount of fuel bought.
+    fn auto_refuel(&mut self, current_tick: Tick) -> AppResult<u32> {
+        let team = self.get_own_team()?.clone();
+        let amount = team.auto_refuel_amount();
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        let planet_id = team
+            .is_on_planet()
+            .ok_or(anyhow!("Team is not on a planet"))?;
+        let planet = self.get_planet_or_err(planet_id)?;
+        if planet.total_population() == 0 {
+            return Ok(0);
+        }
+
+        let unit_cost = self.resource_buy_unit_cost(&team, planet, Resource::FUEL)?;
+        let amount = amount.min(team.max_resource_buy_amount(Resource::FUEL, unit_cost));
+        if amount == 0 {
+            return Err(anyhow!("Insufficient satoshi"));
+        }
+
+        self.buy_resource(&team, Resource::FUEL, amount, current_tick)?;
+        Ok(amount)
+    }
+
+    pub fn buy_resource(
+        &mut self,
+        team: &Team,
+        resource: Resource,
+        amount: u32,
+        current_tick: Tick,
+    ) -> AppResult<()> {
+        let mut team = team.clone();
+        let planet_id = team
+            .is_on_planet()
+            .ok_or(anyhow!("Team is not on a planet"))?;
+        let planet = self.get_planet_or_err(planet_id)?;
+        if planet.total_population() == 0 {
+            return Err(anyhow!("There is no market on {}", planet.name));
+        }
+        self.can_trade_on_planet(planet_id, current_tick)?;
+        let unit_cost = self.resource_buy_unit_cost(&team, planet, resource)?;
+
+        let free_capacity = if resource == Resource::FUEL {
+            team.fuel_capacity().saturating_sub(team.fuel())
+        } else {
+            team.storage_capacity()
+                .saturating_sub(team.used_storage_capacity())
+                / resource.to_storing_space().max(1)
+        };
+        let amount = amount.min(free_capacity);
+        if amount == 0 {
+            return Err(anyhow!("Not enough storage capacity"));
+        }
+
+        team.can_trade_resource(resource, amount as i32, unit_cost)?;
+        let max_capacity = if resource == Resource::FUEL {
+            team.fuel_capacity()
+        } else {
+            team.storage_capacity()
+        };
+        team.resources.add(resource, amount, max_capacity)?;
+        team.resources.sub(Resource::SATOSHI, unit_cost * amount)?;
+
+        self.record_transaction(
+            team.id,
+            current_tick,
+            FinanceCategory::Trading,
+            -((unit_cost * amount) as i64),
+            format!("Bought {} {}", amount, resource),
+        );
+        self.register_market_trade(team.id, planet_id, resource, amount as i32)?;
+        team.add_planet_standing(planet_id, PLANET_STANDING_TRADE_BONUS);
+        self.teams.insert(team.id, team);
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+
+        Ok(())
+    }
+
+    fn execute_queued_action(
+        &mut self,
+        team: &Team,
+        action: QueuedAction,
+        current_tick: Tick,
+    ) -> AppResult<()> {
+        match action {
+            QueuedAction::TravelTo { planet_id } => {
+                self.travel_along_route(team, planet_id, current_tick)
+            }
+            QueuedAction::ReturnHome => {
+                if team.is_on_planet() == Some(team.home_planet_id) {
+                    return Ok(());
                 }
-                _ => {}
+                self.travel_along_route(team, team.home_planet_id, current_tick)
+            }
+            QueuedAction::Buy { resource, amount } => {
+                self.buy_resource(team, resource, amount, current_tick)
+            }
+            QueuedAction::Explore { duration } => {
+                self.start_exploration(team, duration, current_tick)
             }
+        }
+    }
 
-            // Winner team gets reputation bonus
-            let (home_team_reputation, away_team_reputation) = match game.winner {
-                Some(winner) => {
-                    if winner == game.home_team_in_game.team_id {
-                        (REPUTATION_BONUS_WINNER, REPUTATION_BONUS_LOSER)
-                    } else {
-                        (REPUTATION_BONUS_LOSER, REPUTATION_BONUS_WINNER)
-                    }
-                }
-                None => (REPUTATION_BONUS_DRAW, REPUTATION_BONUS_DRAW),
-            };
+    fn tick_action_queue(&mut self, current_tick: Tick) -> AppResult<Option<UiCallback>> {
+        let own_team = self.get_own_team()?;
+        let action = match own_team.action_queue.first() {
+            Some(action) => *action,
+            None => return Ok(None),
+        };
 
-            // Winner team gets 1 rum per player, Loser team gets 1 rum in total
-            let (home_team_rum, away_team_rum) = match game.winner {
-                Some(winner) => {
-                    if winner == game.home_team_in_game.team_id {
-                        (game.home_team_in_game.players.len() as u32, 1)
-                    } else {
-                        (1, game.away_team_in_game.players.len() as u32)
-                    }
-                }
-                None => (1, 1),
-            };
+        // Wait until the team is idle on a planet.
+        if own_team.is_on_planet().is_none()
+            || !own_team.travel_route.is_empty()
+            || own_team.current_game.is_some()
+            || own_team.spaceship.pending_upgrade.is_some()
+        {
+            return Ok(None);
+        }
 
-            // Update to team game records.
-            let (home_team_record, away_team_record) = match game.winner {
-                Some(winner) => {
-                    if winner == game.home_team_in_game.team_id {
-                        ([1, 0, 0], [0, 1, 0])
-                    } else {
-                        ([0, 1, 0], [1, 0, 0])
-                    }
-                }
-                None => ([0, 0, 1], [0, 0, 1]),
-            };
+        let mut team = own_team.clone();
+        team.action_queue.remove(0);
+        self.teams.insert(team.id, team.clone());
+        self.dirty = true;
+        self.dirty_ui = true;
 
-            // Set playing teams current game to None and assign income, reputation, and rum.
-            if let Ok(res) = self.get_team_or_err(game.home_team_in_game.team_id) {
-                let mut home_team = res.clone();
-                home_team.current_game = None;
+        let description = action.description(self);
+        if let Err(e) = self.execute_queued_action(&team, action, current_tick) {
+            let mut team = self.get_own_team()?.clone();
+            let cancelled = team.action_queue.len();
+            team.action_queue.clear();
+            self.teams.insert(team.id, team);
 
-                home_team.resources.saturating_add(
-                    Resource::SATOSHI,
-                    home_team_income,
-                    home_team.storage_capacity(),
-                );
-                home_team.reputation = (home_team.reputation + home_team_reputation).bound();
-                home_team.resources.saturating_add(
-                    Resource::RUM,
-                    home_team_rum,
-                    home_team.storage_capacity(),
-                );
-                if game.home_team_in_game.peer_id.is_some()
-                    && game.away_team_in_game.peer_id.is_some()
-                {
-                    // If it's a network game, update the network game record,
-                    home_team.network_game_record = [
-                        home_team.network_game_record[0] + home_team_record[0],
-                        home_team.network_game_record[1] + home_team_record[1],
-                        home_team.network_game_record[2] + home_team_record[2],
-                    ];
-                } else {
-                    // else update the game record.
-                    home_team.game_record = [
-                        home_team.game_record[0] + home_team_record[0],
-                        home_team.game_record[1] + home_team_record[1],
-                        home_team.game_record[2] + home_team_record[2],
-                    ];
-                }
-                self.teams.insert(home_team.id, home_team.clone());
-            }
+            let message = format!(
+                "Could not {}: {}.\n{} more orders have been cancelled.",
+                description.to_lowercase(),
+                e,
+                cancelled
+            );
+            self.log_crew_event(current_tick, message.replace("\n", " "));
+            return Ok(Some(UiCallback::PushUiPopup {
+                popup_message: PopupMessage::Ok {
+                    message,
+                    is_skippable: true,
+                    tick: current_tick,
+                },
+            }));
+        }
 
-            if let Ok(res) = self.get_team_or_err(game.away_team_in_game.team_id) {
-                let mut away_team = res.clone();
-                away_team.current_game = None;
-                away_team.resources.saturating_add(
-                    Resource::SATOSHI,
-                    away_team_income,
-                    away_team.storage_capacity(),
-                );
-                away_team.reputation = (away_team.reputation + away_team_reputation).bound();
-                away_team.resources.saturating_add(
-                    Resource::RUM,
-                    away_team_rum,
-                    away_team.storage_capacity(),
-                );
+        Ok(None)
+    }
 
-                if game.home_team_in_game.peer_id.is_some()
-                    && game.away_team_in_game.peer_id.is_some()
-                {
-                    // If it's a network game, update the network game record,
-                    away_team.network_game_record = [
-                        away_team.network_game_record[0] + away_team_record[0],
-                        away_team.network_game_record[1] + away_team_record[1],
-                        away_team.network_game_record[2] + away_team_record[2],
-                    ];
-                } else {
-                    // else update the game record.
-                    away_team.game_record = [
-                        away_team.game_record[0] + away_team_record[0],
-                        away_team.game_record[1] + away_team_record[1],
-                        away_team.game_record[2] + away_team_record[2],
-                    ];
-                }
-                self.teams.insert(away_team.id, away_team.clone());
-            }
+    pub fn start_campaign(&mut self, tick: Tick) -> AppResult<String> {
+        self.campaign = Some(Campaign::default());
+        self.start_campaign_chapter(tick)
+    }
 
-            self.dirty = true;
-            self.dirty_ui = true;
+    // Runs the scripted events of the current chapter and returns its story.
+    fn start_campaign_chapter(&mut self, tick: Tick) -> AppResult<String> {
+        let mut campaign = self
+            .campaign
+            .clone()
+            .ok_or(anyhow!("Campaign mode is not active"))?;
+        let chapter = campaign.chapter;
+
+        if let Some((team_name, ship_name, base_level)) = chapter.rival() {
+            let own_team = self.get_own_team()?;
+            let home_planet_id = own_team.home_planet_id;
+            let rng = &mut ChaCha8Rng::from_entropy();
+            // The rival waits on a populated planet far from the own team home.
+            let planet_id = self
+                .planets
+                .values()
+                .filter(|planet| planet.total_population() > 0 && planet.id != home_planet_id)
+                .filter(|planet| planet.peer_id.is_none())
+                .map(|planet| planet.id)
+                .choose(rng)
+                .unwrap_or(home_planet_id);
+
+            let rival_id = self.generate_random_team_with_level(
+                rng,
+                planet_id,
+                team_name.to_string(),
+                ship_name.to_string(),
+                base_level,
+            )?;
+            campaign.rival_id = Some(rival_id);
         }
 
-        // We wait an extra GAME_CLEANUP_TIME before removing the game from the games
-        // collection so that we can leave it up for the UI to visualize.
-        self.games.retain(|_, game| {
-            !game.has_ended() || current_tick <= game.ended_at.unwrap() + GAME_CLEANUP_TIME
-        });
+        self.campaign = Some(campaign);
 
-        Ok(())
+        let mut message = format!(
+            "Chapter {}: {}\n\n{}\n\nObjective: {}.",
+            chapter as u8 + 1,
+            chapter,
+            chapter.story(),
+            chapter.objective()
+        );
+        if let Some(rival_id) = self.campaign.as_ref().and_then(|c| c.rival_id) {
+            let rival = self.get_team_or_err(rival_id)?;
+            let planet = self.get_planet_or_err(rival.home_planet_id)?;
+            message.push_str(&format!("\n{} are waiting on {}.", rival.name, planet.name));
+        }
+
+        self.log_crew_event(tick, format!("Campaign: {} started", chapter));
+        Ok(message)
     }
 
-    fn tick_games(&mut self, current_tick: Tick) -> AppResult<()> {
-        // NOTE!!: we do not set the world to dirty so we don't save on every tick.
-        //         the idea is that the game is completely determined at the beginning,
-        //         so we can similuate it through.
-        for game in self.games.values_mut() {
-            if game.has_started(current_tick) && !game.has_ended() {
-                game.tick(current_tick);
-            }
+    fn tick_campaign(&mut self, current_tick: Tick) -> AppResult<Option<UiCallback>> {
+        let campaign = match self.campaign.as_ref() {
+            Some(campaign) => campaign,
+            None => return Ok(None),
+        };
+
+        if campaign.is_completed() || !campaign.is_chapter_completed(self.get_own_team()?) {
+            return Ok(None);
         }
-        Ok(())
+
+        let chapter = campaign.chapter;
+        let mut own_team = self.get_own_team()?.clone();
+        let storage_capacity = own_team.storage_capacity();
+        for (resource, amount) in chapter.rewards() {
+            own_team
+                .resources
+                .saturating_add(resource, amount, storage_capacity);
+        }
+        let satoshi_reward = chapter
+            .rewards()
+            .iter()
+            .filter(|(resource, _)| *resource == Resource::SATOSHI)
+            .map(|(_, amount)| *amount as i64)
+            .sum::<i64>();
+        self.teams.insert(own_team.id, own_team);
+        if satoshi_reward > 0 {
+            self.record_transaction(
+                self.own_team_id,
+                current_tick,
+                FinanceCategory::Campaign,
+                satoshi_reward,
+                format!("{} completed", chapter),
+            );
+        }
+
+        if let Some(campaign) = self.campaign.as_mut() {
+            campaign.complete_chapter(current_tick);
+        }
+        self.log_crew_event(current_tick, format!("Campaign: {} completed", chapter));
+
+        let next_chapter_message = self.start_campaign_chapter(current_tick)?;
+        let message = format!(
+            "{} completed!\nRewards: {}\n\n{}",
+            chapter,
+            chapter.rewards_description(),
+            next_chapter_message
+        );
+        self.dirty = true;
+        self.dirty_ui = true;
+
+        Ok(Some(UiCallback::PushUiPopup {
+            popup_message: PopupMessage::Ok {
+                message,
+                is_skippable: false,
+                tick: current_tick,
+            },
+        }))
     }
 
-    fn team_reputation_bonus_per_distance(distance: u128) -> f32 {
-        ((distance as f32 + 1.0).ln()).powf(4.0) * TEAM_REPUTATION_BONUS_MODIFIER
+    pub fn start_tutorial(&mut self) -> AppResult<String> {
+        self.tutorial = Some(Tutorial::new(self.get_own_team()?));
+        Ok(format!(
+            "Hello pirate! This is your team page.\nHere you can check your pirates and ship and interact with the market.\n\nComplete the quests in the tutorial checklist to learn the basics and earn some rewards. First quest: {}.",
+            TutorialQuest::HirePirate.to_string().to_lowercase()
+        ))
     }
 
-    pub fn tick_travel(&mut self, current_tick: Tick) -> AppResult<Option<UiCallback>> {
-        let own_team = self.get_own_team()?;
-        match own_team.current_location {
-            TeamLocation::Travelling {
-                from: _,
-                to,
-                started,
-                duration,
-                distance,
-            } => {
-                if current_tick > started + duration {
-                    let mut team = own_team.clone();
-                    let team_name = team.name.clone();
-                    team.current_location = TeamLocation::OnPlanet { planet_id: to };
-                    let mut planet = self.get_planet_or_err(to)?.clone();
-                    let planet_name = planet.name.clone();
-                    let planet_filename = planet.filename.clone();
+    fn tick_tutorial(&mut self, current_tick: Tick) -> AppResult<Option<UiCallback>> {
+        let mut tutorial = match self.tutorial.as_ref() {
+            Some(tutorial) if !tutorial.is_completed() => tutorial.clone(),
+            _ => return Ok(None),
+        };
 
-                    planet.team_ids.push(team.id);
+        let mut own_team = self.get_own_team()?.clone();
+        let completed_quests = tutorial.complete_achieved_quests(&own_team, current_tick);
+        if completed_quests.is_empty() {
+            return Ok(None);
+        }
 
-                    for player in team.player_ids.iter() {
-                        let mut player = self.get_player_or_err(*player)?.clone();
-                        player.set_jersey(&team.jersey);
-                        self.players.insert(player.id, player);
-                    }
+        let storage_capacity = own_team.storage_capacity();
+        let fuel_capacity = own_team.fuel_capacity();
+        let mut satoshi_reward = 0;
+        for quest in completed_quests.iter() {
+            for (resource, amount) in quest.rewards() {
+                let max_capacity = match resource {
+                    Resource::FUEL => fuel_capacity,
+                    _ => storage_capacity,
+                };
+                own_team
+                    .resources
+                    .saturating_add(resource, amount, max_capacity);
+                if resource == Resource::SATOSHI {
+                    satoshi_reward += amount as i64;
+                }
+            }
+            self.log_crew_event(current_tick, format!("Tutorial: {}", quest));
+        }
+        self.teams.insert(own_team.id, own_team);
+        if satoshi_reward > 0 {
+            self.record_transaction(
+                self.own_team_id,
+                current_tick,
+                FinanceCategory::Tutorial,
+                satoshi_reward,
+                "Tutorial quests".to_string(),
+            );
+        }
 
-                    team.spaceship.total_travelled += distance;
+        let mut message = completed_quests
+            .iter()
+            .map(|quest| {
+                format!(
+                    "{} completed!\nRewards: {}",
+                    quest,
+                    quest.rewards_description()
+                )
+            })
+            .join("\n\n");
+        if tutorial.is_completed() {
+            message.push_str("\n\nTutorial completed, have fun!");
+        } else if let Some(next_quest) =
+            TutorialQuest::iter().find(|quest| !tutorial.is_quest_completed(*quest))
+        {
+            message.push_str(&format!("\n\nNext: {}.\n{}", next_quest, next_quest.hint()));
+        }
+        self.tutorial = Some(tutorial);
+        self.dirty = true;
+        self.dirty_ui = true;
 
-                    // Increase team reputation based on the travel distance
-                    let reputation_bonus = Self::team_reputation_bonus_per_distance(distance);
-                    team.reputation = (team.reputation + reputation_bonus).bound();
+        Ok(Some(UiCallback::PushUiPopup {
+            popup_message: PopupMessage::Ok {
+                message,
+                is_skippable: true,
+                tick: current_tick,
+            },
+        }))
+    }
 
-                    self.teams.insert(team.id, team);
-                    self.planets.insert(planet.id, planet);
-                    self.dirty = true;
-                    self.dirty_network = true;
-                    self.dirty_ui = true;
-                    return Ok(Some(UiCallback::PushUiPopup {
-                        popup_message: PopupMessage::TeamLanded {
-                            team_name,
-                            planet_name,
-                            planet_filename,
-                            tick: current_tick,
-                        },
-                    }));
+    fn tick_markets(&mut self, current_tick: Tick) -> AppResult<()> {
+        let planet_ids = self
+            .planets
+            .values()
+            .filter(|planet| {
+                planet.total_population() > 0
+                    && current_tick >= planet.market.last_update + MARKET_UPDATE_INTERVAL
+            })
+            .map(|planet| planet.id)
+            .collect::<Vec<PlanetId>>();
+        if planet_ids.is_empty() {
+            return Ok(());
+        }
+
+        let rng = &mut ChaCha8Rng::from_entropy();
+        let mut news = vec![];
+        for planet_id in planet_ids {
+            let mut planet = self.get_planet_or_err(planet_id)?.clone();
+            planet.market.drift(rng);
+            if let Some(event) = planet.market.tick_event(current_tick, rng) {
+                news.push(format!("News: {} on {}!", event, planet.name));
+            }
+            for resource in MARKET_RESOURCES {
+                let price = planet.resource_buy_price(resource, 1.0);
+                planet.market.record_price(resource, price);
+            }
+            planet.market.last_update = current_tick;
+            self.planets.insert(planet.id, planet);
+        }
+
+        for message in news {
+            self.log_crew_event(current_tick, message.clone());
+            self.notify(current_tick, NotificationCategory::Market, message);
+        }
+        self.dirty = true;
+        self.dirty_ui = true;
+        Ok(())
+    }
+
+    fn tick_contracts(&mut self, current_tick: Tick) -> AppResult<Option<UiCallback>> {
+        // Withdraw expired offers and let planets without offers publish new ones.
+        self.contracts.retain(|contract| {
+            contract.status != ContractStatus::Offered || !contract.is_expired(current_tick)
+        });
+
+        let populated_planet_ids = self
+            .planets
+            .values()
+            .filter(|planet| planet.total_population() > 0)
+            .map(|planet| planet.id)
+            .collect::<Vec<PlanetId>>();
+        if populated_planet_ids.len() > 1 {
+            let rng = &mut ChaCha8Rng::from_entropy();
+            for origin_id in populated_planet_ids.iter() {
+                if self.contracts.iter().any(|contract| {
+                    contract.status == ContractStatus::Offered && contract.origin_id == *origin_id
+                }) {
+                    continue;
+                }
+
+                for _ in 0..MAX_CONTRACT_OFFERS_PER_PLANET {
+                    let destination_id = populated_planet_ids
+                        .iter()
+                        .filter(|&id| id != origin_id)
+                        .choose(rng)
+                        .copied()
+                        .expect("There should be another populated planet");
+                    self.contracts.push(Contract::random(
+                        rng,
+                        *origin_id,
+                        destination_id,
+                        current_tick,
+                    ));
                 }
             }
-            TeamLocation::Exploring {
-                around,
-                started,
-                duration,
-            } => {
-                if current_tick > started + duration {
-                    let mut team = own_team.clone();
+        }
 
-                    for player in team.player_ids.iter() {
-                        let mut player = self.get_player_or_err(*player)?.clone();
-                        player.set_jersey(&team.jersey);
-                        self.players.insert(player.id, player);
-                    }
+        let failed_contracts = self
+            .contracts
+            .iter()
+            .filter(|contract| {
+                contract.status == ContractStatus::Accepted && contract.is_expired(current_tick)
+            })
+            .cloned()
+            .collect::<Vec<Contract>>();
+        if failed_contracts.is_empty() {
+            return Ok(None);
+        }
 
-                    let home_planet = self.get_planet_or_err(team.home_planet_id)?;
-                    let mut around_planet = self.get_planet_or_err(around)?.clone();
+        self.contracts
+            .retain(|contract| !failed_contracts.iter().any(|c| c.id == contract.id));
+        let mut own_team = self.get_own_team()?.clone();
+        own_team.reputation = (own_team.reputation
+            - CONTRACT_FAILURE_REPUTATION_MALUS * failed_contracts.len() as f32)
+            .bound();
+        self.teams.insert(own_team.id, own_team);
 
-                    let mut rng = ChaCha8Rng::from_entropy();
+        let mut messages = vec![];
+        for contract in failed_contracts {
+            let destination_name = self
+                .get_planet_or_err(contract.destination_id)?
+                .name
+                .clone();
+            let message = format!(
+                "Failed to deliver {} {} to {} in time.",
+                contract.amount, contract.resource, destination_name
+            );
+            self.log_crew_event(current_tick, message.clone());
+            messages.push(message);
+        }
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
 
-                    // If the home planet is an asteroid, it means an asteroid has already been found.
-                    let duration_bonus = duration as f64 / (1.0 * HOURS as f64);
-                    // If team has already MAX_NUM_ASTEROID_PER_TEAM, it cannot find another one. Finding asteroids
-                    // becomes progressively more difficult.
-                    let team_asteroid_modifier =
-                        (MAX_NUM_ASTEROID_PER_TEAM - team.asteroid_ids.len()) as f64
-                            / MAX_NUM_ASTEROID_PER_TEAM as f64;
+        Ok(Some(UiCallback::PushUiPopup {
+            popup_message: PopupMessage::Ok {
+                message: format!(
+                    "{}\nReputation -{}",
+                    messages.join("\n"),
+                    CONTRACT_FAILURE_REPUTATION_MALUS * messages.len() as f32
+                ),
+                is_skippable: true,
+                tick: current_tick,
+            },
+        }))
+    }
 
-                    if home_planet.planet_type != PlanetType::Asteroid
-                        && rng.gen_bool(
-                            (ASTEROID_DISCOVERY_PROBABILITY
-                                * around_planet.asteroid_probability
-                                * duration_bonus
-                                * team_asteroid_modifier)
-                                .min(1.0),
-                        )
-                    {
-                        // We temporarily set the team back on the exploration base planet,
-                        // until the asteroid is accepted and generated.
-                        team.current_location = TeamLocation::OnPlanet { planet_id: around };
-                        self.teams.insert(team.id, team);
-                        self.dirty = true;
-                        self.dirty_network = true;
-                        self.dirty_ui = true;
+    // The own team goes bankrupt when the treasury stays in the red too long.
+    // In ironman mode the world also ends when the crew stays understaffed.
+    fn tick_game_over(&mut self, current_tick: Tick) -> AppResult<Option<UiCallback>> {
+        if self.sandbox {
+            return Ok(None);
+        }
 
-                        return Ok(Some(UiCallback::PushUiPopup {
-                            popup_message: PopupMessage::AsteroidNameDialog { tick: current_tick },
-                        }));
-                    }
+        let was_warning = self.game_over_watch.is_warning();
+        let own_team = self.get_own_team()?.clone();
+        let is_ironman = self.generation_options.ironman;
+        if let Some(reason) = self
+            .game_over_watch
+            .check(&own_team, current_tick, is_ironman)
+        {
+            // The creditors wait for the ship to land and the game to end.
+            if own_team.is_on_planet().is_none() || own_team.current_game.is_some() {
+                return Ok(None);
+            }
+            let mut game_over = GameOver::new(reason, &own_team, current_tick);
+            if reason == GameOverReason::Bankruptcy {
+                game_over.auction_proceeds = self.declare_bankruptcy(current_tick)?;
+            }
+            self.game_over = Some(game_over);
+            self.log_crew_event(current_tick, format!("Game over: {}", reason));
+            self.dirty = true;
+            self.dirty_network = true;
+            self.dirty_ui = true;
+            return Ok(None);
+        }
 
-                    team.current_location = TeamLocation::OnPlanet { planet_id: around };
-                    around_planet.team_ids.push(team.id);
+        if was_warning || !self.game_over_watch.is_warning() {
+            return Ok(None);
+        }
+        self.dirty = true;
+        let message = if self.game_over_watch.insolvent_since.is_some() {
+            "The treasury cannot pay the interest on the debts."
+        } else {
+            "The crew is too small to play a game."
+        };
+        Ok(Some(UiCallback::PushUiPopup {
+            popup_message: PopupMessage::Ok {
+                message: format!(
+                    "Game over warning!\n{} Fix it within {} or the game is over.",
+                    message,
+                    GAME_OVER_GRACE_PERIOD.formatted()
+                ),
+                is_skippable: false,
+                tick: current_tick,
+            },
+        }))
+    }
 
-                    let found_resources =
-                        self.resources_found_after_exploration(&team, &around_planet, duration)?;
-                    // Try to add resources starting from the most expensive one,
-                    // but still trying to add the others if they fit (notice that resources occupy a different amount of space).
-                    for (&resource, &amount) in found_resources
-                        .iter()
-                        .sorted_by(|(a, _), (b, _)| b.base_price().total_cmp(&a.base_price()))
-                    {
-                        let max_capacity = if resource == Resource::FUEL {
-                            team.fuel_capacity()
-                        } else {
-                            team.storage_capacity()
-                        };
-                        team.resources
-                            .saturating_add(resource, amount, max_capacity);
-                    }
+    // The cargo is auctioned to pay the creditors and the pirates leave the crew,
+    // except for the most loyal ones so that a sandbox world can go on.
+    // Returns the satoshi fetched at the auction.
+    fn declare_bankruptcy(&mut self, current_tick: Tick) -> AppResult<u32> {
+        let mut own_team = self.get_own_team()?.clone();
+        let proceeds = auction_assets(&mut own_team.resources);
+        if let Some(loan) = own_team.loan.take() {
+            // What cannot be repaid is written off.
+            let repaid = loan.debt.min(own_team.balance());
+            own_team.resources.saturating_sub(Resource::SATOSHI, repaid);
+            self.record_transaction(
+                own_team.id,
+                current_tick,
+                FinanceCategory::Loans,
+                -(repaid as i64),
+                "Bankruptcy settlement".to_string(),
+            );
+        }
+        self.record_transaction(
+            own_team.id,
+            current_tick,
+            FinanceCategory::Trading,
+            proceeds as i64,
+            "Bankruptcy auction".to_string(),
+        );
+        own_team.version += 1;
+        let leaving_ids = own_team
+            .player_ids
+            .iter()
+            .filter_map(|&id| self.get_player(id))
+            .sorted_by(|a, b| b.morale.total_cmp(&a.morale))
+            .skip(MIN_PLAYERS_PER_GAME)
+            .map(|player| player.id)
+            .collect_vec();
+        self.teams.insert(own_team.id, own_team);
 
-                    let found_pirates = self
-                        .free_pirates_found_after_exploration(&around_planet, duration)?
-                        .iter()
-                        .map(|&player_id| {
-                            self.get_player_or_err(player_id)
-                                .expect("Player should be part of world")
-                                .clone()
-                        })
-                        .collect_vec();
+        for player_id in leaving_ids {
+            self.release_player_from_team(player_id)?;
+        }
+        Ok(proceeds)
+    }
 
-                    self.planets.insert(around_planet.id, around_planet);
-                    self.teams.insert(team.id, team);
+    pub fn continue_in_sandbox(&mut self) -> AppResult<()> {
+        if self.generation_options.ironman {
+            return Err(anyhow!(
+                "Ironman worlds cannot continue after the game over"
+            ));
+        }
+        if self.game_over.take().is_none() {
+            return Err(anyhow!("The game is not over"));
+        }
+        self.sandbox = true;
+        self.game_over_watch = GameOverWatch::default();
+        self.dirty = true;
+        self.dirty_ui = true;
+        Ok(())
+    }
 
-                    self.dirty = true;
-                    self.dirty_network = true;
-                    self.dirty_ui = true;
+    fn tick_loans(&mut self, current_tick: Tick) -> AppResult<Option<UiCallback>> {
+        let mut own_team = self.get_own_team()?.clone();
+        let mut loan = match own_team.loan.take() {
+            Some(loan) => loan,
+            None => return Ok(None),
+        };
 
-                    return Ok(Some(UiCallback::PushUiPopup {
-                        popup_message: PopupMessage::ExplorationResult {
-                            resources: found_resources,
-                            players: found_pirates,
-                            tick: current_tick,
-                        },
-                    }));
-                }
-            }
-            _ => {}
+        // Interest is paid from the balance, what cannot be paid is added to the debt.
+        let interest_days = loan.interest_days(current_tick);
+        let mut interest_paid = 0;
+        for _ in 0..interest_days {
+            let interest = loan.daily_interest();
+            let paid = interest.min(own_team.balance());
+            own_team.resources.saturating_sub(Resource::SATOSHI, paid);
+            loan.debt += interest - paid;
+            loan.last_interest_at += DAYS;
+            interest_paid += paid;
         }
-        Ok(None)
-    }
+        self.record_transaction(
+            own_team.id,
+            current_tick,
+            FinanceCategory::Loans,
+            -(interest_paid as i64),
+            "Loan interest".to_string(),
+        );
 
-    fn tick_spaceship_upgrade(&mut self, current_tick: Tick) -> AppResult<Option<UiCallback>> {
-        let own_team = self.get_own_team()?;
-        if let Some(upgrade) = own_team.spaceship.pending_upgrade.clone() {
-            if current_tick > upgrade.started + upgrade.duration {
-                return Ok(Some(UiCallback::UpgradeSpaceship { upgrade }));
+        if !loan.is_overdue(current_tick) {
+            if interest_days > 0 {
+                own_team.loan = Some(loan);
+                own_team.version += 1;
+                self.teams.insert(own_team.id, own_team);
+                self.dirty = true;
+                self.dirty_network = true;
+                self.dirty_ui = true;
             }
+            return Ok(None);
         }
-        Ok(None)
+
+        // On default the bank repossesses what it can and the debt is written off.
+        let (seized, unpaid) = repossess(&mut own_team.resources, loan.debt);
+        own_team.reputation = (own_team.reputation - LOAN_DEFAULT_REPUTATION_MALUS).bound();
+        own_team.add_planet_standing(loan.planet_id, -LOAN_DEFAULT_REPUTATION_MALUS);
+        own_team.version += 1;
+        let own_team_id = own_team.id;
+        self.teams.insert(own_team.id, own_team);
+
+        let planet_name = self.get_planet_or_err(loan.planet_id)?.name.clone();
+        self.record_transaction(
+            own_team_id,
+            current_tick,
+            FinanceCategory::Loans,
+            -(seized.value(&Resource::SATOSHI) as i64),
+            format!("Loan default on {}", planet_name),
+        );
+
+        let seized_text = seized
+            .iter()
+            .sorted_by_key(|(resource, _)| **resource as u8)
+            .map(|(resource, amount)| format!("{} {}", amount, resource))
+            .join(", ");
+        let mut message = format!("Defaulted on the loan from {}.", planet_name);
+        if !seized_text.is_empty() {
+            message.push_str(&format!("\nThe bank repossessed {}.", seized_text));
+        }
+        if unpaid > 0 {
+            message.push_str(&format!("\n{} satoshi were written off.", unpaid));
+        }
+        self.log_crew_event(current_tick, message.clone());
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+
+        Ok(Some(UiCallback::PushUiPopup {
+            popup_message: PopupMessage::Ok {
+                message: format!("{}\nReputation -{}", message, LOAN_DEFAULT_REPUTATION_MALUS),
+                is_skippable: false,
+                tick: current_tick,
+            },
+        }))
     }
 
-    fn tick_tiredness_recovery(&mut self) -> AppResult<()> {
-        let teams = self
+    fn tick_npc_challenges(&mut self, current_tick: Tick) -> AppResult<Option<UiCallback>> {
+        if current_tick < self.last_npc_challenge + NPC_CHALLENGE_COOLDOWN {
+            return Ok(None);
+        }
+
+        let rng = &mut ChaCha8Rng::from_entropy();
+        if !rng.gen_bool(NPC_CHALLENGE_PROBABILITY) {
+            return Ok(None);
+        }
+
+        let own_team = self.get_own_team()?;
+        let challenger = self
             .teams
             .values()
-            .filter(|team| team.current_game.is_none() && team.peer_id.is_none())
-            .collect::<Vec<&Team>>();
+            .filter(|team| {
+                team.id != own_team.id
+                    && team.peer_id.is_none()
+                    && team.reputation <= own_team.reputation + NPC_CHALLENGE_REPUTATION_RANGE
+                    && team.can_challenge_team(own_team).is_ok()
+            })
+            .choose(rng);
 
-        for team in teams {
-            let bonus = TeamBonus::TirednessRecovery.current_team_bonus(&self, team.id)?;
-            for player_id in team.player_ids.iter() {
-                let db_player = self
-                    .get_player(*player_id)
-                    .ok_or(anyhow!("Player {:?} not found", player_id))?;
-                if db_player.tiredness > 0.0 && db_player.tiredness <= MAX_TIREDNESS {
-                    let mut player = db_player.clone();
-                    // Recovery outside of games is slower by a factor TICK_SHORT_INTERVAL/TICK_MEDIUM_INTERVAL
-                    // so that it takes 1 minute * 10 * 100 ~ 18 hours to recover from 100% tiredness.
-                    player.tiredness =
-                        (player.tiredness - bonus * RECOVERING_TIREDNESS_PER_SHORT_TICK).max(0.0);
-                    self.players.insert(player.id, player);
+        let (team_id, team_name) = match challenger {
+            Some(team) => (team.id, team.name.clone()),
+            None => return Ok(None),
+        };
+        self.last_npc_challenge = current_tick;
+        self.notify(
+            current_tick,
+            NotificationCategory::Challenges,
+            format!("{} challenged the crew to a game.", team_name),
+        );
+
+        Ok(Some(UiCallback::PushUiPopup {
+            popup_message: PopupMessage::LocalChallenge {
+                team_name,
+                team_id,
+                tick: current_tick,
+            },
+        }))
+    }
+
+    fn tick_tournaments(&mut self, current_tick: Tick) -> AppResult<Option<UiCallback>> {
+        let rng = &mut ChaCha8Rng::from_entropy();
+
+        // Announce a new cup on one of the most populated planets.
+        if !self
+            .tournaments
+            .iter()
+            .any(|tournament| tournament.is_active())
+        {
+            let last_ended_at = self
+                .tournaments
+                .iter()
+                .filter_map(|tournament| tournament.ended_at)
+                .max();
+            if last_ended_at.is_none()
+                || current_tick >= last_ended_at.unwrap_or_default() + TOURNAMENT_INTERVAL
+            {
+                let host = self
+                    .planets
+                    .values()
+                    .filter(|planet| {
+                        planet.peer_id.is_none()
+                            && planet.planet_type != PlanetType::Asteroid
+                            && planet.total_population() > 0
+                    })
+                    .sorted_by_key(|planet| std::cmp::Reverse(planet.total_population()))
+                    .take(3)
+                    .choose(rng)
+                    .map(|planet| (planet.id, planet.name.clone()));
+                if let Some((planet_id, planet_name)) = host {
+                    let tournament = Tournament::random(rng, planet_id, &planet_name, current_tick);
+                    self.log_crew_event(
+                        current_tick,
+                        format!(
+                            "The {} has been announced: entry fee {} BTC, registrations close on {}.",
+                            tournament.name,
+                            tournament.entry_fee,
+                            tournament.registration_deadline.formatted_as_date()
+                        ),
+                    );
+                    self.tournaments.push(tournament);
                 }
             }
         }
 
-        Ok(())
-    }
+        let mut messages = vec![];
+        for idx in 0..self.tournaments.len() {
+            let mut tournament = self.tournaments[idx].clone();
+            let is_own_tournament = tournament.is_participant(self.own_team_id);
+            match tournament.status {
+                TournamentStatus::Registration => {
+                    if current_tick <= tournament.registration_deadline {
+                        self.register_ai_teams_to_tournament(&mut tournament, current_tick)?;
+                    } else if tournament.participants.len() < TOURNAMENT_MIN_PARTICIPANTS {
+                        for &team_id in tournament.participants.iter() {
+                            let mut team = self.get_team_or_err(team_id)?.clone();
+                            team.resources.add(
+                                Resource::SATOSHI,
+                                tournament.entry_fee,
+                                team.storage_capacity(),
+                            )?;
+                            self.teams.insert(team.id, team);
+                            self.record_transaction(
+                                team_id,
+                                current_tick,
+                                FinanceCategory::Tournaments,
+                                tournament.entry_fee as i64,
+                                format!("{} refund", tournament.name),
+                            );
+                        }
+                        tournament.cancel(current_tick);
+                        let message = format!(
+                            "The {} has been cancelled for lack of participants.",
+                            tournament.name
+                        );
+                        self.log_crew_event(current_tick, message.clone());
+                        if is_own_tournament {
+                            messages.push(message);
+                        }
+                    } else {
+                        tournament.start(rng);
+                        let message = format!(
+                            "The {} has started with {} teams.",
+                            tournament.name,
+                            tournament.participants.len()
+                        );
+                        self.log_crew_event(current_tick, message.clone());
+                        if is_own_tournament {
+                            messages.push(message);
+                        }
+                    }
+                }
+                TournamentStatus::Ongoing => {
+                    self.schedule_tournament_games(&mut tournament, rng)?;
+                    if tournament.advance(current_tick) {
+                        let message = self.award_tournament_prizes(&tournament, current_tick)?;
+                        if is_own_tournament {
+                            messages.push(message);
+                        }
+                    }
+                }
+                TournamentStatus::Ended => {}
+            }
+            self.tournaments[idx] = tournament;
+        }
 
-    fn tick_free_pirates(&mut self, current_tick: Tick) -> AppResult<UiCallback> {
-        self.players.retain(|_, player| player.team.is_some());
+        // Only the most recent past tournaments are kept.
+        let mut excess = self
+            .tournaments
+            .iter()
+            .filter(|tournament| !tournament.is_active())
+            .count()
+            .saturating_sub(MAX_PAST_TOURNAMENTS);
+        self.tournaments.retain(|tournament| {
+            if excess > 0 && !tournament.is_active() {
+                excess -= 1;
+                return false;
+            }
+            true
+        });
 
-        let rng = &mut ChaCha8Rng::seed_from_u64(rand::random());
-        for planet in PLANET_DATA.iter() {
-            self.populate_planet(rng, planet)?;
+        self.dirty = true;
+        self.dirty_ui = true;
+
+        if messages.is_empty() {
+            return Ok(None);
         }
-        Ok(UiCallback::PushUiPopup {
+
+        self.dirty_network = true;
+        Ok(Some(UiCallback::PushUiPopup {
             popup_message: PopupMessage::Ok {
-                message: "Free pirates refreshed".into(),
-                is_skippable: false,
+                message: messages.join("\n"),
+                is_skippable: true,
                 tick: current_tick,
             },
-        })
+        }))
     }
 
-    fn tick_auto_hire_free_pirates(&mut self) -> AppResult<()> {
-        let free_pirates = self
-            .players
+    fn register_ai_teams_to_tournament(
+        &mut self,
+        tournament: &mut Tournament,
+        current_tick: Tick,
+    ) -> AppResult<()> {
+        let team_ids = self
+            .teams
             .values()
-            .filter(|p| p.team.is_none())
+            .filter(|team| {
+                team.id != self.own_team_id
+                    && team.peer_id.is_none()
+                    && !team.is_on_strike()
+                    && tournament.can_register(team, current_tick).is_ok()
+            })
+            .map(|team| team.id)
             .collect_vec();
 
-        let rng = &mut ChaCha8Rng::from_entropy();
+        for team_id in team_ids {
+            // AI teams always leave a spot free for the own team.
+            if tournament.participants.len() >= TOURNAMENT_MAX_PARTICIPANTS - 1 {
+                break;
+            }
+            let mut team = self.get_team_or_err(team_id)?.clone();
+            tournament.register(&mut team)?;
+            team.version += 1;
+            self.teams.insert(team.id, team);
+        }
 
-        let mut hired_player_ids: Vec<PlayerId> = vec![];
-        let mut hiring_team_ids: Vec<TeamId> = vec![];
+        Ok(())
+    }
+
+    fn schedule_tournament_games(
+        &mut self,
+        tournament: &mut Tournament,
+        rng: &mut ChaCha8Rng,
+    ) -> AppResult<()> {
+        let planet_id = tournament.planet_id;
+        for (match_index, tournament_match) in tournament.pending_matches() {
+            let (home_id, away_id) = match (tournament_match.home, tournament_match.away) {
+                (Some(home_id), Some(away_id)) => (home_id, away_id),
+                _ => continue,
+            };
 
-        for (&team_id, team) in self.teams.iter() {
-            if team.player_ids.len() == team.spaceship.crew_capacity() as usize {
-                continue;
+            // Teams that left the hosting planet or cannot field a crew forfeit the game.
+            let can_play = |team: &Team| {
+                team.is_on_planet() == Some(planet_id)
+                    && team.player_ids.len() >= MIN_PLAYERS_PER_GAME
+                    && !team.is_on_strike()
+            };
+            let home_team = self.get_team_or_err(home_id)?;
+            let away_team = self.get_team_or_err(away_id)?;
+            match (can_play(home_team), can_play(away_team)) {
+                (true, true) => {}
+                (true, false) => {
+                    tournament.set_winner(match_index, home_id);
+                    continue;
+                }
+                (false, true) => {
+                    tournament.set_winner(match_index, away_id);
+                    continue;
+                }
+                (false, false) => {
+                    let winner = if rng.gen_bool(0.5) { home_id } else { away_id };
+                    tournament.set_winner(match_index, winner);
+                    continue;
+                }
             }
 
-            if team_id == self.own_team_id {
+            // Teams busy with another game are scheduled at a later tick.
+            if home_team.current_game.is_some() || away_team.current_game.is_some() {
                 continue;
             }
 
-            let available_free_pirates = free_pirates
-                .iter()
-                .filter(|&player| {
-                    !hired_player_ids.contains(&player.id) && team.can_hire_player(player).is_ok()
-                })
-                .map(|player| player.id)
-                .collect_vec();
-
-            if let Some(player_id) = available_free_pirates.choose(rng) {
-                hired_player_ids.push(player_id.clone());
-                hiring_team_ids.push(team.id);
-            }
-        }
-
-        assert!(hired_player_ids.len() == hiring_team_ids.len());
-        for idx in 0..hired_player_ids.len() {
-            let player_id = hired_player_ids[idx];
-            let team_id = hiring_team_ids[idx];
-            self.hire_player_for_team(player_id, team_id)?;
+            let home_team_in_game = TeamInGame::from_team_id(home_id, &self.teams, &self.players)
+                .ok_or(anyhow!("Team {:?} not found", home_id))?;
+            let away_team_in_game = TeamInGame::from_team_id(away_id, &self.teams, &self.players)
+                .ok_or(anyhow!("Team {:?} not found", away_id))?;
+            let game_id = self.generate_game(home_team_in_game, away_team_in_game)?;
+            tournament.set_game(match_index, game_id);
         }
 
         Ok(())
     }
 
-    fn tick_players_update(&mut self) {
-        for (_, player) in self.players.iter_mut() {
-            //TODO: once we remove local teams, we can remove this loop and only apply to own_team
-            if player.peer_id.is_some() {
-                continue;
+    fn award_tournament_prizes(
+        &mut self,
+        tournament: &Tournament,
+        current_tick: Tick,
+    ) -> AppResult<String> {
+        let winner_id = tournament
+            .winner
+            .ok_or(anyhow!("Tournament has no winner"))?;
+        let (winner_prize, runner_up_prize) = tournament.prizes();
+
+        let mut prizes = vec![(winner_id, winner_prize)];
+        if let Some(runner_up_id) = tournament.runner_up() {
+            prizes.push((runner_up_id, runner_up_prize));
+        }
+
+        for (team_id, prize) in prizes {
+            let mut team = self.get_team_or_err(team_id)?.clone();
+            team.resources
+                .add(Resource::SATOSHI, prize, team.storage_capacity())?;
+            if team_id == winner_id {
+                team.reputation = (team.reputation + TOURNAMENT_REPUTATION_BONUS).bound();
             }
-            player.version += 1;
-            // Reset player improvements for UI.
-            player.previous_skills = player.current_skill_array();
-            player.info.age = player.info.age + AGE_INCREASE_PER_LONG_TICK;
+            team.version += 1;
+            self.teams.insert(team.id, team);
+            self.record_transaction(
+                team_id,
+                current_tick,
+                FinanceCategory::Tournaments,
+                prize as i64,
+                format!("{} prize", tournament.name),
+            );
+        }
 
-            // Pirates slightly dislike being part of a team.
-            // This is counteracted by the morale boost pirates get by playing games.
-            // We do not apply this to computer teams since no games are played during simulation,
-            // and hence it would result in all computer players to be completely demoralized.
-            // player.morale = (player.morale + MORALE_DECREASE_PER_LONG_TICK).bound();
-            player.morale = (player.morale + MORALE_DECREASE_PER_LONG_TICK).bound();
-            player.reputation = (player.reputation - REPUTATION_DECREASE_PER_LONG_TICK).bound();
+        let winner_name = self.get_team_or_err(winner_id)?.name.clone();
+        let message = format!(
+            "{} won the {} and a prize of {} BTC!",
+            winner_name, tournament.name, winner_prize
+        );
+        self.log_crew_event(current_tick, message.clone());
 
-            for idx in 0..player.skills_training.len() {
-                // Increase player skills from training
-                assert!(player.skills_training[idx] >= 0.0);
-                player.modify_skill(idx, player.skills_training[idx]);
+        Ok(message)
+    }
 
-                // Reduce player skills. This is planned to counteract the effect of training by playing games.
-                // Mental abilities don't decrease for mature players.
-                if idx > 15 && player.info.relative_age() > 0.15 {
-                    continue;
-                }
+    pub fn register_for_tournament(
+        &mut self,
+        tournament_id: TournamentId,
+        current_tick: Tick,
+    ) -> AppResult<()> {
+        let mut team = self.get_own_team()?.clone();
+        let tournament = self
+            .tournaments
+            .iter_mut()
+            .find(|tournament| tournament.id == tournament_id)
+            .ok_or(anyhow!("Tournament not found"))?;
+        tournament.can_register(&team, current_tick)?;
+        tournament.register(&mut team)?;
+
+        let entry_fee = tournament.entry_fee;
+        let description = format!("{} entry fee", tournament.name);
+        self.teams.insert(team.id, team);
+        self.record_transaction(
+            self.own_team_id,
+            current_tick,
+            FinanceCategory::Tournaments,
+            -(entry_fee as i64),
+            description,
+        );
 
-                // The potential_modifier has a value ranging from 0.0 to 4.0.
-                // Players with skills below their potential decrease slower, above their potential decrease faster.
-                let potential_modifier =
-                    (1.0 + (player.average_skill() - player.potential) / 20.0).powf(2.0);
-                player.modify_skill(
-                    idx,
-                    SKILL_DECREMENT_PER_LONG_TICK
-                        * potential_modifier
-                        * player.info.relative_age().max(0.1),
-                );
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+        Ok(())
+    }
 
-                // Reduce athletics skills even more if relative_age is more than 0.75.
-                if idx < 4 && player.info.relative_age() > 0.75 {
-                    player.modify_skill(
-                        idx,
-                        SKILL_DECREMENT_PER_LONG_TICK
-                            * potential_modifier
-                            * player.info.relative_age(),
-                    );
-                }
-            }
-            player.skills_training = [0.0; 20];
+    pub fn accepted_contracts(&self) -> Vec<&Contract> {
+        self.contracts
+            .iter()
+            .filter(|contract| contract.status == ContractStatus::Accepted)
+            .collect()
+    }
+
+    pub fn accept_contract(
+        &mut self,
+        contract_id: ContractId,
+        current_tick: Tick,
+    ) -> AppResult<()> {
+        let accepted_contracts = self.accepted_contracts().len();
+        let team = self.get_own_team()?;
+        let contract = self
+            .contracts
+            .iter()
+            .find(|contract| contract.id == contract_id)
+            .ok_or(anyhow!("Contract not found"))?;
+        contract.can_be_accepted_by(team, accepted_contracts, current_tick)?;
+
+        if let Some(contract) = self
+            .contracts
+            .iter_mut()
+            .find(|contract| contract.id == contract_id)
+        {
+            contract.accept(current_tick);
         }
+        self.dirty = true;
+        self.dirty_ui = true;
+        Ok(())
     }
 
-    fn tick_teams_reputation(&mut self) -> AppResult<()> {
-        let mut reputation_update: Vec<(TeamId, f32)> = vec![];
-        for (_, team) in self.teams.iter() {
-            //TODO: once we remove local teams, we can remove this loop and only apply to own_team
-            if team.peer_id.is_some() {
-                continue;
-            }
-            let bonus = TeamBonus::Reputation.current_team_bonus(&self, team.id)?;
-            let players_reputation = team
-                .player_ids
-                .iter()
-                .map(|id| self.players.get(id).unwrap().reputation)
-                .sum::<f32>()
-                / MAX_POSITION as f32
-                / 100.0
-                * bonus;
+    pub fn deliver_contract(
+        &mut self,
+        contract_id: ContractId,
+        current_tick: Tick,
+    ) -> AppResult<()> {
+        let mut team = self.get_own_team()?.clone();
+        let contract = self
+            .contracts
+            .iter()
+            .find(|contract| contract.id == contract_id)
+            .ok_or(anyhow!("Contract not found"))?
+            .clone();
+        contract.can_be_delivered_by(&team, current_tick)?;
 
-            let new_reputation = if team.reputation > players_reputation {
-                (team.reputation - players_reputation).bound()
-            } else if team.reputation < players_reputation {
-                (team.reputation + players_reputation).bound()
-            } else {
-                team.reputation
-            };
-            reputation_update.push((team.id, new_reputation));
-        }
+        team.resources.sub(contract.resource, contract.amount)?;
+        team.resources
+            .add(Resource::SATOSHI, contract.reward, team.storage_capacity())?;
+        team.reputation = (team.reputation + contract.reputation_bonus).bound();
+        team.add_planet_standing(contract.destination_id, PLANET_STANDING_CONTRACT_BONUS);
+        let destination_name = self
+            .get_planet_or_err(contract.destination_id)?
+            .name
+            .clone();
+        let description = format!(
+            "Delivered {} {} to {}",
+            contract.amount, contract.resource, destination_name
+        );
+        self.record_transaction(
+            team.id,
+            current_tick,
+            FinanceCategory::Contracts,
+            contract.reward as i64,
+            description.clone(),
+        );
+        self.teams.insert(team.id, team);
+        self.contracts.retain(|c| c.id != contract_id);
+        self.log_crew_event(current_tick, format!("{}.", description));
 
-        for (team_id, new_reputation) in reputation_update {
-            let mut team = self.get_team_or_err(team_id)?.clone();
-            team.reputation = new_reputation;
-            self.teams.insert(team.id, team);
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+        Ok(())
+    }
+
+    pub fn scout_planet(&mut self, planet_id: PlanetId, current_tick: Tick) -> AppResult<()> {
+        if self
+            .scouting_reports
+            .iter()
+            .any(|report| report.planet_id == planet_id)
+        {
+            return Err(anyhow!("Planet already scouted"));
         }
+
+        let planet = self.get_planet_or_err(planet_id)?;
+        let mut team = self.get_own_team()?.clone();
+        team.can_scout_planet(planet)?;
+        let planet_name = planet.name.clone();
+
+        let player_ids = self
+            .players
+            .values()
+            .filter(|player| {
+                player.team.is_none()
+                    && player.current_location == PlayerLocation::OnPlanet { planet_id }
+            })
+            .map(|player| player.id)
+            .collect_vec();
+        let captain_reputation = team
+            .crew_roles
+            .captain
+            .and_then(|captain_id| self.get_player(captain_id))
+            .map(|captain| captain.reputation);
+        let report = ScoutingReport {
+            planet_id,
+            tick: current_tick,
+            accuracy: ScoutingReport::accuracy_for_reputation(captain_reputation),
+            player_ids,
+        };
+
+        team.resources.sub(Resource::SATOSHI, SCOUTING_COST)?;
+        let description = format!(
+            "Scouted {} free pirates on {}",
+            report.player_ids.len(),
+            planet_name
+        );
+        self.record_transaction(
+            team.id,
+            current_tick,
+            FinanceCategory::Hiring,
+            -(SCOUTING_COST as i64),
+            description.clone(),
+        );
+        self.log_crew_event(current_tick, format!("{}.", description));
+        self.teams.insert(team.id, team);
+        self.scouting_reports.push(report);
+
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
         Ok(())
     }
 
-    fn tick_player_leaving_team(&mut self, current_tick: Tick) -> AppResult<Vec<UiCallback>> {
-        let mut messages = vec![];
+    pub fn skill_visibility(&self, player: &Player) -> SkillVisibility {
+        if player.team.is_some() {
+            return SkillVisibility::Full;
+        }
 
-        let mut releasing_player_ids = vec![];
+        self.scouting_reports
+            .iter()
+            .find(|report| report.player_ids.contains(&player.id))
+            .map(|report| SkillVisibility::Scouted {
+                accuracy: report.accuracy,
+            })
+            .unwrap_or(SkillVisibility::Hidden)
+    }
 
-        for &player_id in self.players.keys() {
-            let player = self.get_player_or_err(player_id)?;
-            if player.team.is_none() {
-                continue;
-            }
+    pub fn register_market_trade(
+        &mut self,
+        team_id: TeamId,
+        planet_id: PlanetId,
+        resource: Resource,
+        amount: i32,
+    ) -> AppResult<()> {
+        let mut planet = self.get_planet_or_err(planet_id)?.clone();
+        planet.market.register_trade(resource, amount);
+        self.planets.insert(planet.id, planet);
+        self.emit_hook_event(HookEvent::MarketTradeCompleted {
+            team_id,
+            planet_id,
+            resource,
+            amount,
+        });
+        Ok(())
+    }
 
-            let team = self.get_team_or_err(player.team.expect("Player should have a team"))?;
-            if team.current_game.is_some() {
-                continue;
+    pub fn emit_hook_event(&mut self, event: HookEvent) {
+        if self.hook_events.len() >= MAX_QUEUED_HOOK_EVENTS {
+            self.hook_events.pop_front();
+        }
+        self.hook_events.push_back(event);
+    }
+
+    // Crew members gain and lose traits based on what they have been up to.
+    fn tick_trait_progression(&mut self, current_tick: Tick) -> AppResult<Option<UiCallback>> {
+        let mut messages = vec![];
+        for player_id in self.get_own_team()?.player_ids.clone() {
+            let mut player = self.get_player_or_err(player_id)?.clone();
+            let change = traits::tick_trait_progress(&mut player);
+            if let Some(change) = change {
+                let message = match change {
+                    TraitChange::Acquired(special_trait) => format!(
+                        "{} became {} by {}.",
+                        player.info.shortened_name(),
+                        special_trait,
+                        special_trait.acquisition_hint()
+                    ),
+                    TraitChange::Lost(special_trait) => format!(
+                        "{} is not {} anymore, having stopped {}.",
+                        player.info.shortened_name(),
+                        special_trait,
+                        special_trait.acquisition_hint()
+                    ),
+                };
+                self.log_crew_event(current_tick, message.clone());
+                messages.push(message);
+                player.version += 1;
             }
+            self.players.insert(player.id, player);
+        }
 
-            let rng = &mut ChaCha8Rng::from_entropy();
+        if messages.is_empty() {
+            return Ok(None);
+        }
 
-            if player.morale < MORALE_THRESHOLD_FOR_LEAVING {
-                if rng.gen_bool(
-                    (1.0 - player.morale / MAX_SKILL) as f64 * LEAVING_PROBABILITY_MORALE_MODIFIER,
-                ) {
-                    releasing_player_ids.push(player_id);
+        self.dirty = true;
+        self.dirty_network = true;
+        self.dirty_ui = true;
+        Ok(Some(UiCallback::PushUiPopup {
+            popup_message: PopupMessage::Ok {
+                message: messages.join("\n"),
+                is_skippable: true,
+                tick: current_tick,
+            },
+        }))
+    }
 
-                    if player.team.expect("Team should be some") == self.own_team_id {
-                        messages.push(UiCallback::PushUiPopup {
-                            popup_message: PopupMessage::Ok {
-                                message: format!(
-                                    "{} {} left the crew!\n{} morale was too low...",
-                                    player.info.first_name,
-                                    player.info.last_name,
-                                    player.info.pronouns.as_possessive()
-                                ),
-                                is_skippable: false,
-                                tick: current_tick,
-                            },
-                        })
-                    }
-                }
-            } else if player.info.relative_age() > MIN_RELATIVE_RETIREMENT_AGE {
-                // Add extra check to avoid running rng call unnecessarily.
-                if player.info.relative_age() > rng.gen_range(MIN_RELATIVE_RETIREMENT_AGE..1.0) {
-                    releasing_player_ids.push(player_id);
+    // Own kartoffeln get hungry, and their companion bonus fades accordingly.
+    fn tick_kartoffeln(&mut self) -> AppResult<()> {
+        let own_team = self.get_own_team()?;
+        for kartoffel_id in own_team.kartoffel_ids.clone() {
+            let mut kartoffel = match self.get_kartoffel(kartoffel_id) {
+                Some(kartoffel) => kartoffel.clone(),
+                None => continue,
+            };
+            kartoffel.starve();
 
-                    if player.team.expect("Team should be some") == self.own_team_id {
-                        messages.push(UiCallback::PushUiPopup {
-                    popup_message: PopupMessage::Ok{
-                        message:format!(
-                            "{} {} left the crew and retired to cultivate turnips\n{} {} been a great pirate...",
-                            player.info.first_name,
-                            player.info.last_name,
-                            player.info.pronouns.as_subject(),
-                            player.info.pronouns.to_have(),
-                        ),
-                        is_skippable:false,
-                        tick:current_tick
-                    },
-                })
+            if let Some(player_id) = kartoffel.assigned_to {
+                match self.get_player(player_id) {
+                    Some(player) if player.team == Some(self.own_team_id) => {
+                        let mut player = player.clone();
+                        player.companion_bonus = Some(kartoffel.companion_bonus());
+                        self.players.insert(player.id, player);
                     }
+                    Some(player) => {
+                        // The player left the crew, the kartoffel stays.
+                        let mut player = player.clone();
+                        player.companion_bonus = None;
+                        self.players.insert(player.id, player);
+                        kartoffel.assigned_to = None;
+                    }
+                    None => kartoffel.assigned_to = None,
                 }
             }
+
+            self.kartoffeln.insert(kartoffel.id, kartoffel);
+        }
+        Ok(())
+    }
+
+    // Academy fees are paid daily, prospects whose fee cannot be paid are sent away.
+    fn tick_academy(&mut self, current_tick: Tick) -> AppResult<Option<UiCallback>> {
+        let mut own_team = self.get_own_team()?.clone();
+        let prospect_ids = self
+            .academy_prospects(own_team.id)
+            .iter()
+            .map(|player| player.id)
+            .collect_vec();
+        if prospect_ids.is_empty() {
+            return Ok(None);
+        }
+
+        let mut fees = 0;
+        let mut unpaid_ids = vec![];
+        for player_id in prospect_ids {
+            if own_team
+                .resources
+                .sub(Resource::SATOSHI, ACADEMY_DAILY_FEE)
+                .is_err()
+            {
+                unpaid_ids.push(player_id);
+                continue;
+            }
+            fees += ACADEMY_DAILY_FEE;
+            let mut player = self.get_player_or_err(player_id)?.clone();
+            academy::train_prospect(&mut player);
+            player.version += 1;
+            self.players.insert(player.id, player);
+        }
+        self.teams.insert(own_team.id, own_team.clone());
+        if fees > 0 {
+            self.record_transaction(
+                own_team.id,
+                current_tick,
+                FinanceCategory::Academy,
+                -(fees as i64),
+                "Academy fees".to_string(),
+            );
         }
+        self.dirty = true;
+        self.dirty_ui = true;
 
-        for &player_id in releasing_player_ids.iter() {
-            self.release_player_from_team(player_id)?;
+        if unpaid_ids.is_empty() {
+            return Ok(None);
         }
 
-        Ok(messages)
+        let mut names = vec![];
+        for player_id in unpaid_ids {
+            names.push(self.get_player_or_err(player_id)?.info.shortened_name());
+            self.release_academy_prospect(player_id)?;
+        }
+        Ok(Some(UiCallback::PushUiPopup {
+            popup_message: PopupMessage::Ok {
+                message: format!(
+                    "Academy fees could not be paid!\n{} left the academy.",
+                    names.join(", ")
+                ),
+                is_skippable: false,
+                tick: current_tick,
+            },
+        }))
     }
 
-    fn generate_random_games(&mut self) -> AppResult<()> {
-        let rng = &mut ChaCha8Rng::from_entropy();
-        for planet in self.planets.values() {
-            if planet.team_ids.len() < 2 {
-                continue;
-            }
-
-            let candidate_teams = planet
-                .team_ids
-                .iter()
-                .map(|&id| self.get_team_or_err(id))
-                .filter(|team_res| {
-                    if let Ok(team) = team_res {
-                        if team.player_ids.len() < MIN_PLAYERS_PER_GAME {
-                            return false;
-                        }
-                        let avg_tiredness = team
-                            .player_ids
-                            .iter()
-                            .map(|&id| {
-                                if let Ok(player) = self.get_player_or_err(id) {
-                                    player.tiredness
-                                } else {
-                                    0.0
-                                }
-                            })
-                            .sum::<f32>()
-                            / team.player_ids.len() as f32;
-                        return team.current_game.is_none()
-                            && team.id != self.own_team_id
-                            && team.peer_id.is_none()
-                            && avg_tiredness <= MAX_AVG_TIREDNESS_PER_AUTO_GAME;
-                    }
-                    false
-                })
-                .collect::<AppResult<Vec<&Team>>>()?;
+    // Staff salaries are paid daily, staff members who cannot be paid quit.
+    fn tick_staff_salaries(&mut self, current_tick: Tick) -> AppResult<Option<UiCallback>> {
+        let mut own_team = self.get_own_team()?.clone();
+        if own_team.staff.is_empty() {
+            return Ok(None);
+        }
 
-            if candidate_teams.len() < 2 {
+        let mut salaries = 0;
+        let mut unpaid = vec![];
+        for member in own_team.staff.iter() {
+            if own_team
+                .resources
+                .sub(Resource::SATOSHI, member.salary())
+                .is_err()
+            {
+                unpaid.push(member.clone());
                 continue;
             }
-            let teams = candidate_teams.iter().choose_multiple(rng, 2);
-            let home_team_in_game =
-                TeamInGame::from_team_id(teams[0].id, &self.teams, &self.players)
-                    .ok_or(anyhow!("Team {:?} not found in world", teams[0].id))?;
+            salaries += member.salary();
+        }
+        own_team
+            .staff
+            .retain(|member| !unpaid.iter().any(|quit| quit.role == member.role));
+        own_team.version += 1;
+        self.teams.insert(own_team.id, own_team.clone());
+        if salaries > 0 {
+            self.record_transaction(
+                own_team.id,
+                current_tick,
+                FinanceCategory::Staff,
+                -(salaries as i64),
+                "Staff salaries".to_string(),
+            );
+        }
+        self.dirty = true;
+        self.dirty_ui = true;
 
-            let away_team_in_game =
-                TeamInGame::from_team_id(teams[1].id, &self.teams, &self.players)
-                    .ok_or(anyhow!("Team {:?} not found in world", teams[1].id))?;
+        if unpaid.is_empty() {
+            return Ok(None);
+        }
 
-            self.generate_game(home_team_in_game, away_team_in_game)?;
+        Ok(Some(UiCallback::PushUiPopup {
+            popup_message: PopupMessage::Ok {
+                message: format!(
+                    "Staff salaries could not be paid!\n{} quit.",
+                    unpaid.iter().map(|member| member.name.clone()).join(", ")
+                ),
+                is_skippable: false,
+                tick: current_tick,
+            },
+        }))
+    }
+
+    fn tick_asteroid_production(&mut self, current_tick: Tick) -> AppResult<()> {
+        let own_team = self.get_own_team()?.clone();
+        if own_team.asteroid_ids.is_empty() {
             return Ok(());
         }
 
+        for asteroid_id in own_team.asteroid_ids.iter() {
+            let mut asteroid = self.get_planet_or_err(*asteroid_id)?.clone();
+            let parent_type = match asteroid.satellite_of {
+                Some(parent_id) => self.get_planet_or_err(parent_id)?.planet_type.clone(),
+                None => continue,
+            };
+            let production = asteroid.daily_production(&parent_type);
+            asteroid.accumulate_production(&production);
+            self.planets.insert(asteroid.id, asteroid);
+        }
+
+        // Production is loaded right away if the team is docked on the asteroid.
+        if let Some(planet_id) = own_team.is_on_planet() {
+            if own_team.asteroid_ids.contains(&planet_id) {
+                self.collect_asteroid_production(planet_id, current_tick)?;
+            }
+        }
+
+        self.dirty = true;
+        self.dirty_ui = true;
         Ok(())
     }
 
-    pub fn filter_peer_data(&mut self, peer_id: Option<PeerId>) -> AppResult<()> {
-        let mut own_team = self.get_own_team()?.clone();
-        if let Some(peer_id) = peer_id {
-            // Filter all data that has a specific peer_id
-            self.teams
-                .retain(|_, team| team.peer_id.is_none() || team.peer_id.unwrap() != peer_id);
-            self.players
-                .retain(|_, player| player.peer_id.is_none() || player.peer_id.unwrap() != peer_id);
-            self.planets
-                .retain(|_, planet| planet.peer_id.is_none() || planet.peer_id.unwrap() != peer_id);
-            own_team
-                .sent_challenges
-                .retain(|_, challenge| challenge.target_peer_id != peer_id);
-            own_team
-                .received_challenges
-                .retain(|_, challenge| challenge.proposer_peer_id != peer_id);
-            own_team
-                .sent_trades
-                .retain(|_, trade| trade.target_peer_id != peer_id);
-            own_team
-                .received_trades
-                .retain(|_, trade| trade.proposer_peer_id != peer_id);
-        } else {
-            // Filter all data that has a peer_id (i.e. keep only local data)
-            self.teams.retain(|_, team| team.peer_id.is_none());
-            self.players.retain(|_, player| player.peer_id.is_none());
-            self.planets.retain(|_, planet| planet.peer_id.is_none());
-            own_team.clear_challenges();
-            own_team.clear_trades();
-        }
-        // Remove teams from planet teams vector.
-        for (_, planet) in self.planets.iter_mut() {
-            planet
-                .team_ids
-                .retain(|&team_id| self.teams.contains_key(&team_id));
+    fn collect_asteroid_production(&mut self, asteroid_id: PlanetId, tick: Tick) -> AppResult<()> {
+        let mut asteroid = self.get_planet_or_err(asteroid_id)?.clone();
+        if asteroid
+            .pending_production
+            .values()
+            .all(|&amount| amount == 0)
+        {
+            return Ok(());
         }
 
-        // Set current game to None for teams playing a game not stored in games.
-        for team in self.teams.values_mut() {
-            if team.current_game.is_some() && !self.games.contains_key(&team.current_game.unwrap())
-            {
-                team.current_game = None;
+        let mut own_team = self.get_own_team()?.clone();
+        let storage_capacity = own_team.storage_capacity();
+        let mut collected = vec![];
+        for (resource, pending) in asteroid.pending_production.iter_mut() {
+            let current = own_team.resources.value(resource);
+            own_team
+                .resources
+                .saturating_add(*resource, *pending, storage_capacity);
+            let amount = own_team.resources.value(resource) - current;
+            // What does not fit in the storage stays on the asteroid.
+            *pending -= amount;
+            if amount > 0 {
+                collected.push(format!("{} {}", amount, resource));
             }
         }
+        asteroid.pending_production.retain(|_, amount| *amount > 0);
 
+        let asteroid_name = asteroid.name.clone();
         self.teams.insert(own_team.id, own_team);
+        self.planets.insert(asteroid.id, asteroid);
+
+        if collected.len() > 0 {
+            self.log_crew_event(
+                tick,
+                format!(
+                    "Collected {} produced on {}.",
+                    collected.join(", "),
+                    asteroid_name
+                ),
+            );
+        }
+
         self.dirty = true;
+        self.dirty_network = true;
         self.dirty_ui = true;
         Ok(())
     }
 
-    pub fn travel_time_to_planet(&self, team_id: TeamId, to: PlanetId) -> AppResult<Tick> {
-        let team = self.get_team_or_err(team_id)?;
-        let from = match team.current_location {
-            TeamLocation::OnPlanet { planet_id } => planet_id,
-            TeamLocation::Travelling { .. } => return Err(anyhow!("Team is travelling")),
-            TeamLocation::Exploring { .. } => return Err(anyhow!("Team is exploring")),
-            TeamLocation::OnSpaceAdventure { .. } => {
-                return Err(anyhow!("Team is on space adventure"))
-            }
-        };
+    pub fn apply_co_captain_order(
+        &mut self,
+        order: &CoCaptainOrder,
+        sender: Option<PeerId>,
+        tick: Tick,
+    ) -> AppResult<()> {
+        let captain_team = self.get_team_or_err(order.captain_team_id)?;
+        if sender.is_none() || captain_team.peer_id != sender {
+            return Err(anyhow!("Order was not sent by the co-captain"));
+        }
 
-        let distance = self.distance_between_planets(from, to)?;
-        let bonus = TeamBonus::SpaceshipSpeed.current_team_bonus(&self, team.id)?;
-        Ok(
-            ((LANDING_TIME_OVERHEAD as f32 + (distance as f32 / team.spaceship_speed())) / bonus)
-                as Tick,
-        )
+        let mut own_team = self.get_own_team()?.clone();
+        order.can_be_applied_to(&own_team)?;
+        own_team.action_queue.push(order.action);
+        own_team.action_queue_version += 1;
+        self.teams.insert(own_team.id, own_team);
+
+        let description = order.action.description(self);
+        self.log_crew_event(
+            tick,
+            format!(
+                "Co-captain {} ordered: {}",
+                order.captain_name,
+                description.to_lowercase()
+            ),
+        );
+        self.dirty_network = true;
+        Ok(())
     }
 
     fn planet_height(&self, planet_id: PlanetId) -> AppResult<usize> {
@@ -1927,6 +6366,8 @@ impl World {
             planets: self.planets.clone(),
             games: self.games.clone(),
             past_games: self.past_games.clone(),
+            crew_log: self.crew_log.clone(),
+            finances: self.finances.clone(),
             serialized_size: self.serialized_size,
             ..Default::default()
         };
@@ -1945,17 +6386,22 @@ mod test {
         types::{StorableResourceMap, SystemTimeTick, Tick},
         ui::ui_callback::UiCallback,
         world::{
+            gossip::Gossip,
+            notifications::{NotificationCategory, MAX_NOTIFICATIONS},
             planet::PlanetType,
-            player::Trait,
             resources::Resource,
             role::CrewRole,
             skill::Rated,
-            types::{TeamBonus, TeamLocation},
+            staff::StaffRole,
+            traits::Trait,
+            types::{RenameTarget, TeamBonus, TeamLocation, TimeSpeed},
             utils::PLANET_DATA,
             world::{
-                TickInterval, ASTEROID_DISCOVERY_PROBABILITY, AU, HOURS, LONG_EXPLORATION_TIME,
-                MAX_NUM_ASTEROID_PER_TEAM,
+                TickInterval, ASTEROID_DISCOVERY_PROBABILITY, AU, GOSSIP_STRIKES_FOR_DECLINES,
+                HOURS, LONG_EXPLORATION_TIME, MAX_NUM_ASTEROID_PER_TEAM, SECONDS,
+                TEAM_GRUDGE_STANDING,
             },
+            wormhole::MIN_WORMHOLE_DISTANCE,
         },
     };
     use itertools::Itertools;
@@ -2044,7 +6490,8 @@ mod test {
             println!("  {} {}", res.1, res.0);
         }
 
-        let found_free_pirates = world.free_pirates_found_after_exploration(&planet, duration)?;
+        let found_free_pirates =
+            world.free_pirates_found_after_exploration(&planet, duration, 0.0)?;
         for &player_id in found_free_pirates.iter() {
             let player = world.get_player_or_err(player_id)?;
             println!(
@@ -2077,7 +6524,8 @@ mod test {
             println!("  {} {}", res.1, res.0);
         }
 
-        let found_free_pirates = world.free_pirates_found_after_exploration(&planet, duration)?;
+        let found_free_pirates =
+            world.free_pirates_found_after_exploration(&planet, duration, 0.0)?;
         for &player_id in found_free_pirates.iter() {
             let player = world.get_player_or_err(player_id)?;
             println!(
@@ -2263,6 +6711,48 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_tick_crew_mutiny() -> AppResult<()> {
+        let mut app = App::new(None, true, true, true, false, None, None, None);
+        app.new_world();
+
+        let world = &mut app.world;
+        let rng = &mut ChaCha8Rng::from_entropy();
+        world.own_team_id = world.generate_random_team(
+            rng,
+            world.planets.keys().next().unwrap().clone(),
+            "team_name".into(),
+            "ship_name".into(),
+        )?;
+
+        let player_ids = world.get_own_team()?.player_ids.clone();
+        for player_id in player_ids.iter() {
+            let mut player = world.get_player_or_err(*player_id)?.clone();
+            player.morale = 0.0;
+            world.players.insert(player.id, player);
+        }
+
+        // Demoralized crews mutiny randomly
+        let mut idx = 0;
+        while world.crew_log.len() == 0 {
+            world.tick_crew_mutiny(Tick::now())?;
+            idx += 1;
+        }
+        println!("Crew mutinied after {idx} iterations: {:?}", world.crew_log);
+
+        let own_team = world.get_own_team()?;
+        if own_team.is_on_strike() {
+            let opponent = world
+                .teams
+                .values()
+                .find(|team| team.id != own_team.id)
+                .unwrap();
+            assert!(own_team.can_challenge_team(opponent).is_err());
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_is_simulating() -> AppResult<()> {
         let mut app = App::new(None, true, true, true, false, None, None, None);
@@ -2294,4 +6784,256 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_notifications() {
+        let mut world = World::new(None);
+        for idx in 0..MAX_NOTIFICATIONS + 2 {
+            let category = if idx % 2 == 0 {
+                NotificationCategory::Games
+            } else {
+                NotificationCategory::Market
+            };
+            world.notify(idx as Tick, category, format!("Notification {idx}"));
+        }
+        assert_eq!(world.notifications.len(), MAX_NOTIFICATIONS);
+        assert_eq!(world.notifications[0].tick, 2);
+
+        world.clear_notifications(Some(NotificationCategory::Games));
+        assert!(world
+            .notifications
+            .iter()
+            .all(|n| n.category == NotificationCategory::Market));
+
+        world.clear_notifications(None);
+        assert!(world.notifications.is_empty());
+    }
+
+    #[test]
+    fn test_time_controls() -> AppResult<()> {
+        let mut world = World::new(None);
+
+        world.time_speed = TimeSpeed::Quadruple;
+        world.advance_clock(10 * SECONDS);
+        assert_eq!(world.time_offset, 30 * SECONDS as i64);
+
+        world.time_speed = TimeSpeed::Paused;
+        world.advance_clock(40 * SECONDS);
+        assert_eq!(world.time_offset, -10 * SECONDS as i64);
+        assert!(world.now() < Tick::now());
+
+        assert!(world
+            .can_set_time_speed(TimeSpeed::Double, true, true)
+            .is_err());
+        assert!(world
+            .can_set_time_speed(TimeSpeed::Normal, true, true)
+            .is_ok());
+        assert!(world
+            .can_set_time_speed(TimeSpeed::Double, false, false)
+            .is_ok());
+        // Pausing is fine while waiting for peers, fast-forwarding is not.
+        assert!(world
+            .can_set_time_speed(TimeSpeed::Double, false, true)
+            .is_err());
+        assert!(world
+            .can_set_time_speed(TimeSpeed::Paused, false, true)
+            .is_ok());
+
+        // A clock behind the real one can go online, one ahead cannot.
+        assert!(world.can_go_online().is_ok());
+        world.time_offset = 10 * SECONDS as i64;
+        assert!(world.can_go_online().is_err());
+        world.time_offset = -10 * SECONDS as i64;
+
+        world.last_tick_short_interval = 10 * TickInterval::SHORT;
+        world.resync_clock();
+        assert_eq!(world.time_offset, 0);
+        assert_eq!(world.time_speed, TimeSpeed::Normal);
+        assert_eq!(
+            world.last_tick_short_interval,
+            10 * TickInterval::SHORT + 10 * SECONDS
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_wormhole_gate() -> AppResult<()> {
+        let mut app = App::new(None, true, true, false, false, None, None, None);
+        app.new_world();
+
+        let world = &mut app.world;
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let planet = PLANET_DATA[0].clone();
+        let team_id =
+            world.generate_random_team(rng, planet.id, "test".into(), "testship".into())?;
+        let target_id = world
+            .planets
+            .values()
+            .filter(|p| p.total_population() > 0)
+            .find(|p| {
+                world
+                    .distance_between_planets(planet.id, p.id)
+                    .unwrap_or_default()
+                    >= MIN_WORMHOLE_DISTANCE
+            })
+            .map(|p| p.id)
+            .expect("There should be a distant planet");
+        world.wormholes.clear();
+
+        let team = world.get_team_or_err(team_id)?.clone();
+        assert!(world.can_build_wormhole_gate(&team, target_id).is_err());
+        let travel_time = world.travel_time_to_planet(team_id, target_id)?;
+
+        let mut team = team;
+        team.resources.insert(Resource::SCRAPS, 2_000);
+        team.resources.insert(Resource::GOLD, 50);
+        world.teams.insert(team.id, team);
+        world.build_wormhole_gate(team_id, target_id, 0)?;
+
+        assert_eq!(world.wormholes_at(target_id).len(), 1);
+        assert!(world.travel_time_to_planet(team_id, target_id)? < travel_time);
+        let team = world.get_team_or_err(team_id)?;
+        assert_eq!(team.resources.value(&Resource::GOLD), 10);
+        assert!(world.can_build_wormhole_gate(team, target_id).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename() -> AppResult<()> {
+        let mut app = App::new(None, true, true, false, false, None, None, None);
+        app.new_world();
+
+        let world = &mut app.world;
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        world.own_team_id =
+            world.generate_random_team(rng, PLANET_DATA[0].id, "test".into(), "testship".into())?;
+        let mut team = world.get_own_team()?.clone();
+        team.resources.insert(Resource::SATOSHI, 10_000);
+        world.teams.insert(team.id, team);
+
+        assert!(world.rename(RenameTarget::Team, "test".into(), 0).is_err());
+        world.rename(RenameTarget::Team, "Rebels".into(), 0)?;
+        world.rename(RenameTarget::Spaceship, "Sloop".into(), 0)?;
+        let player_id = world.get_own_team()?.player_ids[0];
+        world.rename(RenameTarget::Player { player_id }, "Shorty".into(), 0)?;
+
+        let team = world.get_own_team()?;
+        assert_eq!(team.name, "Rebels");
+        assert_eq!(team.spaceship.name, "Sloop");
+        assert_eq!(
+            world.get_player_or_err(player_id)?.info.shortened_name(),
+            "Shorty"
+        );
+        assert!(world.rename(RenameTarget::Team, "Broke".into(), 0).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_staff_hiring() -> AppResult<()> {
+        let mut app = App::new(None, true, true, false, false, None, None, None);
+        app.new_world();
+
+        let world = &mut app.world;
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let planet_id = world
+            .planets
+            .values()
+            .find(|p| p.total_population() > 0)
+            .map(|p| p.id)
+            .expect("There should be a populated planet");
+        world.own_team_id =
+            world.generate_random_team(rng, planet_id, "test".into(), "testship".into())?;
+        let training_bonus = TeamBonus::Training.current_team_bonus(world, world.own_team_id)?;
+
+        let candidates = world.staff_candidates(planet_id, 0)?;
+        assert_eq!(candidates.len(), 3);
+        assert_eq!(candidates, world.staff_candidates(planet_id, 0)?);
+
+        let mut team = world.get_own_team()?.clone();
+        team.resources.insert(Resource::SATOSHI, 100_000);
+        world.teams.insert(team.id, team);
+        world.hire_staff(StaffRole::Coach, 0)?;
+        assert!(world.hire_staff(StaffRole::Coach, 0).is_err());
+        assert!(TeamBonus::Training.current_team_bonus(world, world.own_team_id)? > training_bonus);
+
+        // Unpaid staff quit.
+        let mut team = world.get_own_team()?.clone();
+        team.resources.insert(Resource::SATOSHI, 0);
+        world.teams.insert(team.id, team);
+        assert!(world.tick_staff_salaries(0)?.is_some());
+        assert!(world.get_own_team()?.staff.is_empty());
+        assert!(world.fire_staff(StaffRole::Coach).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gossip_strikes_and_grudges() -> AppResult<()> {
+        let mut app = App::new(None, true, true, false, false, None, None, None);
+        app.new_world();
+
+        let world = &mut app.world;
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+        let planet_id = world
+            .planets
+            .values()
+            .find(|p| p.total_population() > 0)
+            .map(|p| p.id)
+            .expect("There should be a populated planet");
+        world.own_team_id =
+            world.generate_random_team(rng, planet_id, "test".into(), "testship".into())?;
+        let opponent_id =
+            world.generate_random_team(rng, planet_id, "rivals".into(), "rivalship".into())?;
+
+        // Declining a couple of challenges goes unnoticed.
+        for _ in 1..GOSSIP_STRIKES_FOR_DECLINES {
+            world.spread_gossip(
+                world.own_team_id,
+                planet_id,
+                Gossip::DeclinedChallenge,
+                Some(opponent_id),
+                0,
+            )?;
+        }
+        assert!(world.get_own_team()?.planet_reputation.is_empty());
+
+        world.spread_gossip(
+            world.own_team_id,
+            planet_id,
+            Gossip::DeclinedChallenge,
+            Some(opponent_id),
+            0,
+        )?;
+        let own_team = world.get_own_team()?;
+        assert!(own_team.planet_reputation[&planet_id] < 0.0);
+        assert!(own_team.standing_with_team(opponent_id) < 0.0);
+        assert!(own_team.gossip_strikes.is_empty());
+
+        // A smuggling bust spreads at once and the rivals stop dealing with the crew.
+        world.spread_gossip(
+            world.own_team_id,
+            planet_id,
+            Gossip::Smuggling,
+            Some(opponent_id),
+            0,
+        )?;
+        let own_team = world.get_own_team()?;
+        let opponent = world.get_team_or_err(opponent_id)?;
+        assert!(own_team.standing_with_team(opponent_id) <= TEAM_GRUDGE_STANDING);
+        assert!(own_team
+            .can_challenge_team(opponent)
+            .unwrap_err()
+            .to_string()
+            .contains("grudge"));
+
+        // Gossip is slowly forgotten.
+        let standing = own_team.standing_with_team(opponent_id);
+        world.tick_teams_reputation()?;
+        assert!(world.get_own_team()?.standing_with_team(opponent_id) > standing);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,76 @@
+use super::{
+    constants::{AU, HOURS},
+    resources::Resource,
+};
+use crate::types::{PlanetId, TeamId, Tick};
+use serde::{Deserialize, Serialize};
+
+// Crossing a wormhole takes the same time whatever the distance between its ends.
+pub const WORMHOLE_TRANSIT_TIME: Tick = 2 * HOURS;
+// Wormholes only link planets in different star systems.
+pub const MIN_WORMHOLE_DISTANCE: u128 = 10 * AU;
+pub const NATURAL_WORMHOLES: usize = 2;
+pub const WORMHOLE_GATE_COST: [(Resource, u32); 2] =
+    [(Resource::SCRAPS, 1_200), (Resource::GOLD, 40)];
+
+/// A shortcut between two distant planets, either natural or a gate built by a team.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Wormhole {
+    pub ends: (PlanetId, PlanetId),
+    pub builder_id: Option<TeamId>,
+    pub tick: Tick,
+}
+
+impl Wormhole {
+    pub fn natural(from: PlanetId, to: PlanetId) -> Self {
+        Self {
+            ends: (from, to),
+            builder_id: None,
+            tick: 0,
+        }
+    }
+
+    pub fn gate(from: PlanetId, to: PlanetId, builder_id: TeamId, tick: Tick) -> Self {
+        Self {
+            ends: (from, to),
+            builder_id: Some(builder_id),
+            tick,
+        }
+    }
+
+    pub fn is_natural(&self) -> bool {
+        self.builder_id.is_none()
+    }
+
+    pub fn connects(&self, from: PlanetId, to: PlanetId) -> bool {
+        self.ends == (from, to) || self.ends == (to, from)
+    }
+
+    pub fn other_end(&self, planet_id: PlanetId) -> Option<PlanetId> {
+        if self.ends.0 == planet_id {
+            Some(self.ends.1)
+        } else if self.ends.1 == planet_id {
+            Some(self.ends.0)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Wormhole;
+    use crate::types::{PlanetId, TeamId};
+
+    #[test]
+    fn test_wormhole_ends() {
+        let from = PlanetId::new_v4();
+        let to = PlanetId::new_v4();
+        let wormhole = Wormhole::gate(from, to, TeamId::new_v4(), 10);
+        assert!(!wormhole.is_natural());
+        assert!(wormhole.connects(to, from));
+        assert!(!wormhole.connects(from, PlanetId::new_v4()));
+        assert_eq!(wormhole.other_end(to), Some(from));
+        assert_eq!(wormhole.other_end(PlanetId::new_v4()), None);
+    }
+}